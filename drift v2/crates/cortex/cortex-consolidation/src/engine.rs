@@ -134,10 +134,11 @@ impl ConsolidationEngine {
             ));
         }
 
-        let output = pipeline::run_pipeline(
+        let output = pipeline::run_pipeline_with_polisher(
             candidates,
             self.embedding_provider.as_ref(),
             existing_semantics,
+            self.polisher.as_ref(),
         );
 
         // Release the guard.
@@ -218,10 +219,11 @@ impl IConsolidator for ConsolidationEngine {
             ));
         }
 
-        let output = pipeline::run_pipeline(
+        let output = pipeline::run_pipeline_with_polisher(
             candidates,
             self.embedding_provider.as_ref(),
             &[], // No existing semantics in the basic trait interface.
+            self.polisher.as_ref(),
         );
 
         // Release the guard.
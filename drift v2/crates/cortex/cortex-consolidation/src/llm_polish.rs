@@ -2,6 +2,9 @@
 //! Tracks polished vs unpolished rates.
 
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use cortex_core::traits::{CompletionRequest, ICompletionProvider};
 
 /// Tracks LLM polish statistics.
 pub struct PolishTracker {
@@ -59,6 +62,12 @@ impl Default for PolishTracker {
 pub trait LlmPolisher: Send + Sync {
     /// Rephrase a summary for clarity. Returns None if LLM is unavailable.
     fn polish(&self, summary: &str) -> Option<String>;
+
+    /// Write an abstractive summary of a cluster's source summaries.
+    /// Returns None to keep the extractive (TextRank) summary.
+    fn abstract_summary(&self, _sources: &[String]) -> Option<String> {
+        None
+    }
 }
 
 /// No-op polisher that always returns None (LLM unavailable).
@@ -70,6 +79,56 @@ impl LlmPolisher for NoOpPolisher {
     }
 }
 
+/// Polisher backed by a completion provider.
+///
+/// Any provider error or empty answer yields None, so consolidation keeps
+/// the extractive summary.
+pub struct CompletionPolisher {
+    provider: Arc<dyn ICompletionProvider>,
+}
+
+impl CompletionPolisher {
+    /// Token cap for polished and abstractive summaries.
+    const MAX_TOKENS: usize = 200;
+
+    pub fn new(provider: Arc<dyn ICompletionProvider>) -> Self {
+        Self { provider }
+    }
+
+    fn run(&self, system: &str, prompt: String) -> Option<String> {
+        if !self.provider.is_available() {
+            return None;
+        }
+        let request = CompletionRequest::new(prompt, Self::MAX_TOKENS).with_system(system);
+        let text = self.provider.complete(&request).ok()?.text;
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+}
+
+impl LlmPolisher for CompletionPolisher {
+    fn polish(&self, summary: &str) -> Option<String> {
+        self.run(
+            "Rephrase the text for clarity in at most two sentences. \
+             Keep every fact; add nothing. Reply with the text only.",
+            summary.to_string(),
+        )
+    }
+
+    fn abstract_summary(&self, sources: &[String]) -> Option<String> {
+        if sources.is_empty() {
+            return None;
+        }
+        let notes: String = sources.iter().map(|s| format!("- {s}\n")).collect();
+        self.run(
+            "Merge these related engineering notes into one or two sentences \
+             stating the shared lesson. Use only facts from the notes. \
+             Reply with the summary only.",
+            notes,
+        )
+    }
+}
+
 /// Polish a summary using the provided polisher, tracking statistics.
 pub fn polish_summary(
     summary: &str,
@@ -91,6 +150,37 @@ pub fn polish_summary(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cortex_core::errors::{CompletionError, CortexResult};
+    use cortex_core::traits::Completion;
+
+    /// Echoes the prompt back, or fails when `fail` is set.
+    struct EchoProvider {
+        fail: bool,
+    }
+
+    impl ICompletionProvider for EchoProvider {
+        fn complete(&self, request: &CompletionRequest) -> CortexResult<Completion> {
+            if self.fail {
+                return Err(CompletionError::RequestFailed {
+                    reason: "down".to_string(),
+                }
+                .into());
+            }
+            Ok(Completion {
+                text: format!("merged: {}", request.prompt.trim()),
+                prompt_tokens: 0,
+                completion_tokens: 0,
+            })
+        }
+
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
 
     #[test]
     fn noop_polisher_returns_none() {
@@ -117,4 +207,22 @@ mod tests {
         assert_eq!(result, "original");
         assert_eq!(tracker.unpolished_count(), 1);
     }
+
+    #[test]
+    fn completion_polisher_writes_abstract_summary() {
+        let polisher = CompletionPolisher::new(Arc::new(EchoProvider { fail: false }));
+        let summary = polisher
+            .abstract_summary(&["a".to_string(), "b".to_string()])
+            .unwrap();
+        assert_eq!(summary, "merged: - a\n- b");
+        assert!(polisher.abstract_summary(&[]).is_none());
+    }
+
+    #[test]
+    fn completion_polisher_errors_keep_textrank() {
+        let polisher = CompletionPolisher::new(Arc::new(EchoProvider { fail: true }));
+        assert!(polisher.abstract_summary(&["a".to_string()]).is_none());
+        let tracker = PolishTracker::new();
+        assert_eq!(polish_summary("original", &polisher, &tracker), "original");
+    }
 }
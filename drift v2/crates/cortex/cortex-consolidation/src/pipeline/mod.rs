@@ -17,6 +17,7 @@ use cortex_core::traits::IEmbeddingProvider;
 use cortex_tokens::TokenCounter;
 use tracing::{debug, info};
 
+use crate::llm_polish::{LlmPolisher, NoOpPolisher};
 use phase5_integration::IntegrationAction;
 
 /// Extended pipeline output that includes actual created memories for persistence.
@@ -36,6 +37,22 @@ pub fn run_pipeline(
     candidates: &[BaseMemory],
     embedding_provider: &dyn IEmbeddingProvider,
     existing_semantics: &[(String, Vec<f32>)],
+) -> CortexResult<PipelineOutput> {
+    run_pipeline_with_polisher(
+        candidates,
+        embedding_provider,
+        existing_semantics,
+        &NoOpPolisher,
+    )
+}
+
+/// Run the pipeline, letting `polisher` replace the Phase 4 TextRank summary
+/// with an abstractive one (or a polished rephrase) when it can.
+pub fn run_pipeline_with_polisher(
+    candidates: &[BaseMemory],
+    embedding_provider: &dyn IEmbeddingProvider,
+    existing_semantics: &[(String, Vec<f32>)],
+    polisher: &dyn LlmPolisher,
 ) -> CortexResult<PipelineOutput> {
     // Phase 1: Selection.
     let selected = phase1_selection::select_candidates(candidates);
@@ -103,7 +120,14 @@ pub fn run_pipeline(
         clusters_passed += 1;
 
         // Phase 4: Abstraction.
-        let abstraction = phase4_abstraction::abstract_cluster(&cluster, &cluster_embeddings);
+        let mut abstraction = phase4_abstraction::abstract_cluster(&cluster, &cluster_embeddings);
+        let sources: Vec<String> = cluster.iter().map(|m| m.summary.clone()).collect();
+        if let Some(summary) = polisher
+            .abstract_summary(&sources)
+            .or_else(|| polisher.polish(&abstraction.summary))
+        {
+            abstraction.summary = summary;
+        }
         let new_memory = phase4_abstraction::build_semantic_memory(&abstraction)?;
 
         // Track token counts (A-07: use real tokenizer instead of len/4).
@@ -3,4 +3,4 @@
 /**
  * Actions that can appear in a provenance chain.
  */
-export type ProvenanceAction = "created" | "shared_to" | "projected_to" | "merged_with" | "consolidated_from" | "validated_by" | "used_in_decision" | "corrected_by" | "reclassified_from" | "retracted";
+export type ProvenanceAction = "created" | "shared_to" | "projected_to" | "merged_with" | "consolidated_from" | "validated_by" | "used_in_decision" | "corrected_by" | "reclassified_from" | "retracted" | "imported_from";
@@ -7,6 +7,8 @@ pub const DEFAULT_MMAP_SIZE: u64 = 268_435_456; // 256 MB
 pub const DEFAULT_CACHE_SIZE: i64 = -64_000; // 64 MB (negative = KB)
 pub const DEFAULT_BUSY_TIMEOUT_MS: u32 = 5_000;
 pub const DEFAULT_READ_POOL_SIZE: usize = 4;
+pub const DEFAULT_ENCRYPTION_ENABLED: bool = false;
+pub const DEFAULT_ENCRYPTION_KEY_ENV: &str = "CORTEX_DB_KEY";
 
 // --- Embeddings ---
 pub const DEFAULT_EMBEDDING_DIMENSIONS: usize = 1024;
@@ -27,6 +29,14 @@ pub const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.75;
 pub const DEFAULT_NOVELTY_THRESHOLD: f64 = 0.85;
 pub const DEFAULT_LLM_POLISH: bool = false;
 
+// --- LLM ---
+pub const DEFAULT_LLM_ENABLED: bool = false;
+pub const DEFAULT_LLM_BASE_URL: &str = "http://localhost:11434/v1";
+pub const DEFAULT_LLM_TIMEOUT_MS: u64 = 30_000;
+pub const DEFAULT_LLM_MAX_TOKENS: usize = 256;
+pub const DEFAULT_LLM_REQUESTS_PER_MINUTE: u32 = 30;
+pub const DEFAULT_LLM_TOKENS_PER_HOUR: usize = 200_000;
+
 // --- Decay ---
 pub const DEFAULT_ARCHIVAL_THRESHOLD: f64 = 0.15;
 pub const DEFAULT_DECAY_PROCESSING_INTERVAL_SECS: u64 = 3600; // 1 hour
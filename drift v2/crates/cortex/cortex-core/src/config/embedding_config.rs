@@ -20,6 +20,8 @@ pub struct EmbeddingConfig {
     pub l1_cache_size: u64,
     /// Enable L2 SQLite cache.
     pub l2_cache_enabled: bool,
+    /// Path to a precomputed L3 embedding file (memory-mapped at startup).
+    pub l3_cache_path: Option<String>,
 }
 
 impl Default for EmbeddingConfig {
@@ -32,6 +34,7 @@ impl Default for EmbeddingConfig {
             batch_size: defaults::DEFAULT_EMBEDDING_BATCH_SIZE,
             l1_cache_size: defaults::DEFAULT_L1_CACHE_SIZE,
             l2_cache_enabled: defaults::DEFAULT_L2_CACHE_ENABLED,
+            l3_cache_path: None,
         }
     }
 }
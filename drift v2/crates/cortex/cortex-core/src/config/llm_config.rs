@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::defaults;
+
+/// Local LLM backend configuration (OpenAI-compatible chat completions).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LlmConfig {
+    /// Use the LLM for extraction, HyDE and consolidation summaries.
+    /// When false, every caller uses its rule-based path.
+    pub enabled: bool,
+    /// Base URL up to and including the API version, e.g.
+    /// `http://localhost:11434/v1` (Ollama) or `http://localhost:8080/v1` (llama.cpp).
+    pub base_url: String,
+    /// Model name passed through to the server.
+    pub model: String,
+    /// Environment variable holding a bearer token, if the server needs one.
+    pub api_key_env: Option<String>,
+    /// Per-request timeout.
+    pub timeout_ms: u64,
+    /// Completion length cap per request; callers asking for more are clamped.
+    pub max_tokens: usize,
+    /// Request budget: at most this many requests per rolling minute.
+    pub requests_per_minute: u32,
+    /// Token budget: prompt + completion tokens per rolling hour.
+    pub tokens_per_hour: usize,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        Self {
+            enabled: defaults::DEFAULT_LLM_ENABLED,
+            base_url: defaults::DEFAULT_LLM_BASE_URL.to_string(),
+            model: String::new(),
+            api_key_env: None,
+            timeout_ms: defaults::DEFAULT_LLM_TIMEOUT_MS,
+            max_tokens: defaults::DEFAULT_LLM_MAX_TOKENS,
+            requests_per_minute: defaults::DEFAULT_LLM_REQUESTS_PER_MINUTE,
+            tokens_per_hour: defaults::DEFAULT_LLM_TOKENS_PER_HOUR,
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod consolidation_config;
 pub mod decay_config;
 pub mod defaults;
 pub mod embedding_config;
+pub mod llm_config;
 pub mod multiagent_config;
 pub mod observability_config;
 pub mod privacy_config;
@@ -16,6 +17,7 @@ pub use cloud_config::CloudConfig;
 pub use consolidation_config::ConsolidationConfig;
 pub use decay_config::DecayConfig;
 pub use embedding_config::EmbeddingConfig;
+pub use llm_config::LlmConfig;
 pub use multiagent_config::MultiAgentConfig;
 pub use observability_config::ObservabilityConfig;
 pub use privacy_config::PrivacyConfig;
@@ -37,6 +39,7 @@ pub struct CortexConfig {
     pub observability: ObservabilityConfig,
     pub temporal: TemporalConfig,
     pub multiagent: MultiAgentConfig,
+    pub llm: LlmConfig,
 }
 
 impl CortexConfig {
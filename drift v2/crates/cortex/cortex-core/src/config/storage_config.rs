@@ -18,6 +18,12 @@ pub struct StorageConfig {
     pub busy_timeout_ms: u32,
     /// Number of read connections in the pool.
     pub read_pool_size: usize,
+    /// Encrypt the database at rest (requires the `encryption` feature of cortex-storage).
+    pub encryption_enabled: bool,
+    /// Environment variable holding the database key or passphrase.
+    pub encryption_key_env: String,
+    /// Optional path to a keyfile. Takes precedence over the environment variable.
+    pub encryption_keyfile: Option<String>,
 }
 
 impl Default for StorageConfig {
@@ -29,6 +35,9 @@ impl Default for StorageConfig {
             cache_size: defaults::DEFAULT_CACHE_SIZE,
             busy_timeout_ms: defaults::DEFAULT_BUSY_TIMEOUT_MS,
             read_pool_size: defaults::DEFAULT_READ_POOL_SIZE,
+            encryption_enabled: defaults::DEFAULT_ENCRYPTION_ENABLED,
+            encryption_key_env: defaults::DEFAULT_ENCRYPTION_KEY_ENV.to_string(),
+            encryption_keyfile: None,
         }
     }
 }
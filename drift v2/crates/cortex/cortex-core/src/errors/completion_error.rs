@@ -0,0 +1,15 @@
+/// Text completion (LLM) subsystem errors.
+#[derive(Debug, thiserror::Error)]
+pub enum CompletionError {
+    #[error("provider unavailable: {provider}")]
+    ProviderUnavailable { provider: String },
+
+    #[error("request failed: {reason}")]
+    RequestFailed { reason: String },
+
+    #[error("budget exhausted: {reason}")]
+    BudgetExhausted { reason: String },
+
+    #[error("empty completion from {provider}")]
+    EmptyResponse { provider: String },
+}
@@ -1,4 +1,6 @@
-use super::{CloudError, ConsolidationError, EmbeddingError, StorageError, TemporalError};
+use super::{
+    CloudError, CompletionError, ConsolidationError, EmbeddingError, StorageError, TemporalError,
+};
 
 /// Top-level error type for the Cortex memory system.
 /// All subsystem errors convert into this via `From` impls.
@@ -54,6 +56,9 @@ pub enum CortexError {
 
     #[error("multi-agent error: {0}")]
     MultiAgentError(#[from] super::MultiAgentError),
+
+    #[error("completion error: {0}")]
+    CompletionError(#[from] CompletionError),
 }
 
 /// Convenience type alias.
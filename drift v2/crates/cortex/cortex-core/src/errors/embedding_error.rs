@@ -15,4 +15,7 @@ pub enum EmbeddingError {
 
     #[error("cache miss for hash: {hash}")]
     CacheMiss { hash: String },
+
+    #[error("precomputed cache {path}: {reason}")]
+    PrecomputedCache { path: String, reason: String },
 }
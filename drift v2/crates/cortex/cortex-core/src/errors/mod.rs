@@ -1,5 +1,6 @@
 mod causal_error;
 mod cloud_error;
+mod completion_error;
 mod consolidation_error;
 mod cortex_error;
 mod embedding_error;
@@ -10,6 +11,7 @@ mod temporal_error;
 
 pub use causal_error::CausalError;
 pub use cloud_error::CloudError;
+pub use completion_error::CompletionError;
 pub use consolidation_error::ConsolidationError;
 pub use cortex_error::{CortexError, CortexResult};
 pub use embedding_error::EmbeddingError;
@@ -12,4 +12,7 @@ pub enum StorageError {
 
     #[error("connection pool exhausted: {active_connections} active connections")]
     ConnectionPoolExhausted { active_connections: usize },
+
+    #[error("encryption error: {reason}")]
+    EncryptionError { reason: String },
 }
@@ -95,4 +95,6 @@ pub enum ProvenanceAction {
     ReclassifiedFrom,
     /// Memory was retracted (archived/tombstoned).
     Retracted,
+    /// Memory was imported from a portable bundle.
+    ImportedFrom,
 }
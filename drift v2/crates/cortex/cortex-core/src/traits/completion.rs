@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::CortexResult;
+
+/// A single-turn completion request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletionRequest {
+    /// Optional system instruction.
+    pub system: Option<String>,
+    pub prompt: String,
+    /// Upper bound on generated tokens.
+    pub max_tokens: usize,
+    pub temperature: f32,
+}
+
+impl CompletionRequest {
+    /// A low-temperature request, suitable for extraction and summarization.
+    pub fn new(prompt: impl Into<String>, max_tokens: usize) -> Self {
+        Self {
+            system: None,
+            prompt: prompt.into(),
+            max_tokens,
+            temperature: 0.2,
+        }
+    }
+
+    pub fn with_system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+}
+
+/// Generated text plus token usage (as reported by the server, or estimated).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Completion {
+    pub text: String,
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+}
+
+/// Text completion provider (local or remote LLM).
+///
+/// Callers treat every error as "no LLM" and fall back to their rule-based path.
+pub trait ICompletionProvider: Send + Sync {
+    /// Run a completion.
+    fn complete(&self, request: &CompletionRequest) -> CortexResult<Completion>;
+
+    /// Human-readable provider/model name.
+    fn name(&self) -> &str;
+
+    /// Whether this provider is currently available.
+    fn is_available(&self) -> bool;
+}
@@ -1,4 +1,5 @@
 mod causal_storage;
+mod completion;
 mod compressor;
 mod consolidator;
 mod decay_engine;
@@ -14,6 +15,7 @@ mod temporal_engine;
 mod validator;
 
 pub use causal_storage::{CausalEdge, CausalEvidence, ICausalStorage};
+pub use completion::{Completion, CompletionRequest, ICompletionProvider};
 pub use compressor::ICompressor;
 pub use consolidator::IConsolidator;
 pub use decay_engine::IDecayEngine;
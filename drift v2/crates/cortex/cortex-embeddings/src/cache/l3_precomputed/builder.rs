@@ -0,0 +1,195 @@
+//! L3 file builder: collects embeddings for hot content and writes the
+//! sorted, optionally quantized binary file.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use cortex_core::errors::{CortexResult, EmbeddingError};
+use cortex_core::memory::BaseMemory;
+use serde::{Deserialize, Serialize};
+
+use super::format::{self, L3Quantization, Layout, KEY_LEN};
+
+/// Summary of a written L3 file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L3BuildStats {
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+/// Accumulates `(content_hash, embedding)` pairs and writes an L3 file.
+pub struct L3CacheBuilder {
+    model_id: String,
+    dimensions: usize,
+    quantization: L3Quantization,
+    entries: BTreeMap<[u8; KEY_LEN], Vec<f32>>,
+}
+
+impl L3CacheBuilder {
+    pub fn new(
+        model_id: impl Into<String>,
+        dimensions: usize,
+        quantization: L3Quantization,
+    ) -> Self {
+        Self {
+            model_id: model_id.into(),
+            dimensions,
+            quantization,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Add an embedding. Re-inserting a hash replaces the earlier vector.
+    pub fn insert(&mut self, content_hash: &str, embedding: &[f32]) -> CortexResult<()> {
+        if embedding.len() != self.dimensions {
+            return Err(EmbeddingError::DimensionMismatch {
+                expected: self.dimensions,
+                actual: embedding.len(),
+            }
+            .into());
+        }
+        self.entries
+            .insert(format::key_for(content_hash), embedding.to_vec());
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialize to `writer`. Returns the number of bytes written.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<u64> {
+        let count = self.entries.len();
+        let model_id = self.model_id.as_bytes();
+        let layout = Layout::for_header(model_id.len(), self.dimensions, self.quantization, count)
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "L3 file too large")
+            })?;
+
+        let mut out = CountingWriter {
+            inner: writer,
+            written: 0,
+        };
+        out.write_all(&format::MAGIC)?;
+        out.write_all(&format::FORMAT_VERSION.to_le_bytes())?;
+        out.write_all(&[self.quantization.tag(), 0, 0, 0])?;
+        out.write_all(&(self.dimensions as u32).to_le_bytes())?;
+        out.write_all(&0u32.to_le_bytes())?;
+        out.write_all(&(count as u64).to_le_bytes())?;
+        out.write_all(&(model_id.len() as u32).to_le_bytes())?;
+        out.write_all(model_id)?;
+        out.pad_to(layout.index)?;
+
+        for key in self.entries.keys() {
+            out.write_all(key)?;
+        }
+
+        match self.quantization {
+            L3Quantization::F32 => {
+                out.pad_to(layout.vectors)?;
+                for vector in self.entries.values() {
+                    for v in vector {
+                        out.write_all(&v.to_le_bytes())?;
+                    }
+                }
+            }
+            L3Quantization::Int8 => {
+                let quantized: Vec<(Vec<i8>, f32)> = self
+                    .entries
+                    .values()
+                    .map(|v| format::quantize_i8(v))
+                    .collect();
+                out.pad_to(layout.scales)?;
+                for (_, scale) in &quantized {
+                    out.write_all(&scale.to_le_bytes())?;
+                }
+                out.pad_to(layout.vectors)?;
+                for (values, _) in &quantized {
+                    let bytes: Vec<u8> = values.iter().map(|q| *q as u8).collect();
+                    out.write_all(&bytes)?;
+                }
+            }
+        }
+        debug_assert_eq!(out.written as usize, layout.end);
+        out.flush()?;
+        Ok(out.written)
+    }
+
+    /// Write the file atomically (temp file + rename).
+    pub fn write(&self, path: &Path) -> CortexResult<L3BuildStats> {
+        let io_err = |e: std::io::Error| EmbeddingError::PrecomputedCache {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        };
+        let tmp = path.with_extension("l3.tmp");
+        let bytes = {
+            let mut writer = BufWriter::new(File::create(&tmp).map_err(io_err)?);
+            let bytes = self.write_to(&mut writer).map_err(io_err)?;
+            writer
+                .into_inner()
+                .map_err(|e| io_err(e.into_error()))?
+                .sync_all()
+                .map_err(io_err)?;
+            bytes
+        };
+        std::fs::rename(&tmp, path).map_err(io_err)?;
+        Ok(L3BuildStats {
+            entries: self.entries.len(),
+            bytes,
+        })
+    }
+}
+
+/// Pick the content worth precomputing: distinct content hashes ranked by
+/// total access count across the memories sharing them (shared boilerplate
+/// ranks high), then by recency. Archived memories are ignored.
+pub fn select_hot(memories: &[BaseMemory], limit: usize) -> Vec<&BaseMemory> {
+    let mut by_hash: HashMap<&str, (u64, &BaseMemory)> = HashMap::new();
+    for memory in memories.iter().filter(|m| !m.archived) {
+        let entry = by_hash
+            .entry(memory.content_hash.as_str())
+            .or_insert((0, memory));
+        entry.0 += memory.access_count.max(1);
+        if memory.last_accessed > entry.1.last_accessed {
+            entry.1 = memory;
+        }
+    }
+    let mut ranked: Vec<(u64, &BaseMemory)> = by_hash.into_values().collect();
+    ranked.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| b.1.last_accessed.cmp(&a.1.last_accessed))
+            .then_with(|| a.1.content_hash.cmp(&b.1.content_hash))
+    });
+    ranked.into_iter().take(limit).map(|(_, m)| m).collect()
+}
+
+/// Tracks bytes written so sections can be padded to their offsets.
+struct CountingWriter<'a, W: Write> {
+    inner: &'a mut W,
+    written: u64,
+}
+
+impl<W: Write> CountingWriter<'_, W> {
+    fn pad_to(&mut self, offset: usize) -> std::io::Result<()> {
+        let pad = offset.saturating_sub(self.written as usize);
+        self.write_all(&vec![0u8; pad])
+    }
+}
+
+impl<W: Write> Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,137 @@
+//! Binary layout of an L3 precomputed embedding file.
+//!
+//! All integers are little-endian. Sections start on 8-byte boundaries so
+//! f32 vectors can be read in place from a page-aligned mapping.
+//!
+//! ```text
+//! 0   magic            [u8; 8]  "CXL3EMB\0"
+//! 8   format_version   u32
+//! 12  quantization     u8       0 = f32, 1 = int8
+//! 13  reserved         [u8; 3]
+//! 16  dimensions       u32
+//! 20  reserved         u32
+//! 24  count            u64
+//! 32  model_id_len     u32
+//! 36  model_id         [u8; model_id_len]   (UTF-8), padded to 8
+//!     index            [[u8; 32]; count]    blake3(content_hash), sorted
+//!     scales           [f32; count]         int8 only, padded to 8
+//!     vectors          [f32; count * dims]  or [i8; count * dims]
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// File magic.
+pub const MAGIC: [u8; 8] = *b"CXL3EMB\0";
+
+/// Current format version. Readers reject newer versions.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Width of an index key (a blake3 digest).
+pub const KEY_LEN: usize = 32;
+
+/// Fixed header size before the model id.
+pub(crate) const FIXED_HEADER_LEN: usize = 36;
+
+/// How vectors are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum L3Quantization {
+    /// Full-precision vectors, read in place.
+    F32,
+    /// Symmetric per-vector int8 (`value = q * scale`), 4x smaller.
+    Int8,
+}
+
+impl L3Quantization {
+    pub(crate) fn tag(self) -> u8 {
+        match self {
+            Self::F32 => 0,
+            Self::Int8 => 1,
+        }
+    }
+
+    pub(crate) fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::F32),
+            1 => Some(Self::Int8),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded file header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L3Header {
+    pub format_version: u32,
+    /// Provider/model that produced the vectors.
+    pub model_id: String,
+    pub dimensions: usize,
+    pub quantization: L3Quantization,
+    pub count: usize,
+}
+
+/// Byte offsets of each section, derived from the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub index: usize,
+    pub scales: usize,
+    pub vectors: usize,
+    pub end: usize,
+}
+
+impl Layout {
+    pub fn for_header(
+        model_id_len: usize,
+        dimensions: usize,
+        quant: L3Quantization,
+        count: usize,
+    ) -> Option<Self> {
+        let index = align8(FIXED_HEADER_LEN.checked_add(model_id_len)?);
+        let index_end = index.checked_add(count.checked_mul(KEY_LEN)?)?;
+        let cells = count.checked_mul(dimensions)?;
+        let (scales, vectors, end) = match quant {
+            L3Quantization::F32 => {
+                let vectors = align8(index_end);
+                (
+                    vectors,
+                    vectors,
+                    vectors.checked_add(cells.checked_mul(4)?)?,
+                )
+            }
+            L3Quantization::Int8 => {
+                let scales = align8(index_end);
+                let vectors = align8(scales.checked_add(count.checked_mul(4)?)?);
+                (scales, vectors, vectors.checked_add(cells)?)
+            }
+        };
+        Some(Self {
+            index,
+            scales,
+            vectors,
+            end,
+        })
+    }
+}
+
+/// Index key for a content hash.
+pub fn key_for(content_hash: &str) -> [u8; KEY_LEN] {
+    *blake3::hash(content_hash.as_bytes()).as_bytes()
+}
+
+/// Quantize a vector to int8, returning the values and their scale.
+pub(crate) fn quantize_i8(vector: &[f32]) -> (Vec<i8>, f32) {
+    let max = vector.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    if max == 0.0 || !max.is_finite() {
+        return (vec![0; vector.len()], 0.0);
+    }
+    let scale = max / 127.0;
+    let values = vector
+        .iter()
+        .map(|v| (v / scale).round().clamp(-127.0, 127.0) as i8)
+        .collect();
+    (values, scale)
+}
+
+pub(crate) fn align8(n: usize) -> usize {
+    n.div_ceil(8) * 8
+}
@@ -0,0 +1,243 @@
+//! L3 precomputed embedding cache.
+//!
+//! Memory-mapped precomputed embeddings for frequently-accessed content.
+//! Opened at startup, zero-latency lookups. The binary layout is described
+//! in [`format`]; [`L3CacheBuilder`] writes it.
+
+pub mod builder;
+pub mod format;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use cortex_core::errors::{CortexResult, EmbeddingError};
+use memmap2::Mmap;
+
+pub use builder::{select_hot, L3BuildStats, L3CacheBuilder};
+pub use format::{L3Header, L3Quantization};
+
+use format::{Layout, FIXED_HEADER_LEN, KEY_LEN};
+
+/// L3 precomputed embedding cache.
+///
+/// Either a read-only memory-mapped L3 file (see [`L3PrecomputedCache::open`])
+/// or an in-memory map populated during initialization.
+pub struct L3PrecomputedCache {
+    backing: Backing,
+}
+
+enum Backing {
+    Memory(HashMap<String, Vec<f32>>),
+    Mapped(MappedFile),
+}
+
+struct MappedFile {
+    mmap: Mmap,
+    header: L3Header,
+    layout: Layout,
+}
+
+impl L3PrecomputedCache {
+    /// Create an empty L3 cache.
+    pub fn new() -> Self {
+        Self::load(HashMap::new())
+    }
+
+    /// Load precomputed embeddings from a map.
+    pub fn load(entries: HashMap<String, Vec<f32>>) -> Self {
+        Self {
+            backing: Backing::Memory(entries),
+        }
+    }
+
+    /// Memory-map an L3 file written by [`L3CacheBuilder`].
+    ///
+    /// The header and section sizes are validated up front; vectors are
+    /// only touched on lookup.
+    pub fn open(path: &Path) -> CortexResult<Self> {
+        let invalid = |reason: String| EmbeddingError::PrecomputedCache {
+            path: path.display().to_string(),
+            reason,
+        };
+        let file = File::open(path).map_err(|e| invalid(e.to_string()))?;
+        // SAFETY: the mapping is read-only and L3 files are replaced by
+        // rename, never modified in place.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|e| invalid(e.to_string()))?;
+        let (header, layout) = parse_header(&mmap).map_err(invalid)?;
+        Ok(Self {
+            backing: Backing::Mapped(MappedFile {
+                mmap,
+                header,
+                layout,
+            }),
+        })
+    }
+
+    /// Look up a precomputed embedding by content hash.
+    ///
+    /// Mapped f32 files are borrowed in place; int8 files are dequantized.
+    pub fn get(&self, content_hash: &str) -> Option<Cow<'_, [f32]>> {
+        match &self.backing {
+            Backing::Memory(map) => map.get(content_hash).map(|v| Cow::Borrowed(v.as_slice())),
+            Backing::Mapped(file) => file.get(content_hash),
+        }
+    }
+
+    /// Header of the mapped file, if this cache is file-backed.
+    pub fn header(&self) -> Option<&L3Header> {
+        match &self.backing {
+            Backing::Memory(_) => None,
+            Backing::Mapped(file) => Some(&file.header),
+        }
+    }
+
+    /// Number of precomputed embeddings.
+    pub fn len(&self) -> usize {
+        match &self.backing {
+            Backing::Memory(map) => map.len(),
+            Backing::Mapped(file) => file.header.count,
+        }
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for L3PrecomputedCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MappedFile {
+    fn get(&self, content_hash: &str) -> Option<Cow<'_, [f32]>> {
+        let slot = self.find(&format::key_for(content_hash))?;
+        let dims = self.header.dimensions;
+        match self.header.quantization {
+            L3Quantization::F32 => {
+                let start = self.layout.vectors + slot * dims * 4;
+                let bytes = &self.mmap[start..start + dims * 4];
+                if cfg!(target_endian = "little") {
+                    // SAFETY: any bit pattern is a valid f32; alignment is
+                    // checked by `align_to` (sections are 8-byte aligned).
+                    let (head, floats, tail) = unsafe { bytes.align_to::<f32>() };
+                    if head.is_empty() && tail.is_empty() {
+                        return Some(Cow::Borrowed(floats));
+                    }
+                }
+                Some(Cow::Owned(
+                    bytes
+                        .chunks_exact(4)
+                        .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                        .collect(),
+                ))
+            }
+            L3Quantization::Int8 => {
+                let at = self.layout.scales + slot * 4;
+                let scale = f32::from_le_bytes(self.mmap[at..at + 4].try_into().ok()?);
+                let start = self.layout.vectors + slot * dims;
+                Some(Cow::Owned(
+                    self.mmap[start..start + dims]
+                        .iter()
+                        .map(|b| (*b as i8) as f32 * scale)
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    /// Binary search the sorted key index.
+    fn find(&self, key: &[u8; KEY_LEN]) -> Option<usize> {
+        let index = &self.mmap[self.layout.index..self.layout.index + self.header.count * KEY_LEN];
+        let (mut lo, mut hi) = (0usize, self.header.count);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match index[mid * KEY_LEN..(mid + 1) * KEY_LEN].cmp(key.as_slice()) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+}
+
+fn parse_header(bytes: &[u8]) -> Result<(L3Header, Layout), String> {
+    if bytes.len() < FIXED_HEADER_LEN || bytes[..8] != format::MAGIC {
+        return Err("not an L3 embedding file".to_string());
+    }
+    let u32_at =
+        |at: usize| u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+    let format_version = u32_at(8);
+    if format_version > format::FORMAT_VERSION {
+        return Err(format!(
+            "format version {format_version} is newer than supported {}",
+            format::FORMAT_VERSION
+        ));
+    }
+    let quantization = L3Quantization::from_tag(bytes[12])
+        .ok_or_else(|| format!("unknown quantization tag {}", bytes[12]))?;
+    let dimensions = u32_at(16) as usize;
+    let count = u64::from_le_bytes(bytes[24..32].try_into().expect("8 bytes"));
+    let count = usize::try_from(count).map_err(|_| "entry count overflows usize".to_string())?;
+    let model_id_len = u32_at(32) as usize;
+    let model_id = bytes
+        .get(FIXED_HEADER_LEN..FIXED_HEADER_LEN + model_id_len)
+        .ok_or("truncated model id")?;
+    let model_id = String::from_utf8(model_id.to_vec()).map_err(|_| "model id is not UTF-8")?;
+
+    let layout = Layout::for_header(model_id_len, dimensions, quantization, count)
+        .ok_or("section sizes overflow")?;
+    if bytes.len() < layout.end {
+        return Err(format!(
+            "truncated: {} bytes, layout needs {}",
+            bytes.len(),
+            layout.end
+        ));
+    }
+
+    Ok((
+        L3Header {
+            format_version,
+            model_id,
+            dimensions,
+            quantization,
+            count,
+        },
+        layout,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_and_get() {
+        let mut entries = HashMap::new();
+        entries.insert("hash1".to_string(), vec![1.0, 2.0, 3.0]);
+        entries.insert("hash2".to_string(), vec![4.0, 5.0, 6.0]);
+
+        let cache = L3PrecomputedCache::load(entries);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("hash1").as_deref(), Some(&[1.0, 2.0, 3.0][..]));
+        assert!(cache.get("missing").is_none());
+    }
+
+    #[test]
+    fn empty_cache() {
+        let cache = L3PrecomputedCache::new();
+        assert!(cache.is_empty());
+        assert!(cache.get("anything").is_none());
+    }
+
+    #[test]
+    fn header_rejects_garbage() {
+        assert!(parse_header(b"not a cache file at all, definitely not").is_err());
+        assert!(parse_header(&format::MAGIC).is_err());
+    }
+}
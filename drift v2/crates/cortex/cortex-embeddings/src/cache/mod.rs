@@ -9,7 +9,7 @@ pub mod l3_precomputed;
 
 pub use l1_memory::L1MemoryCache;
 pub use l2_sqlite::L2SqliteCache;
-pub use l3_precomputed::L3PrecomputedCache;
+pub use l3_precomputed::{L3CacheBuilder, L3PrecomputedCache, L3Quantization};
 
 use tracing::debug;
 
@@ -58,9 +58,10 @@ impl CacheCoordinator {
         // L3: precomputed (zero-latency).
         if let Some(vec) = self.l3.get(content_hash) {
             debug!(hash = content_hash, tier = "L3", "cache hit");
+            let vec = vec.into_owned();
             // Promote to L1.
             self.l1.insert(content_hash.to_string(), vec.clone());
-            return (Some(vec.clone()), CacheHitTier::L3);
+            return (Some(vec), CacheHitTier::L3);
         }
 
         // L1: in-memory (sub-microsecond).
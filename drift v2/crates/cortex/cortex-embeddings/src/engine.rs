@@ -4,14 +4,17 @@
 //! enrichment, and Matryoshka dimension management.
 //! Implements `IEmbeddingProvider`.
 
+use std::path::Path;
+
 use cortex_core::config::EmbeddingConfig;
-use cortex_core::errors::CortexResult;
+use cortex_core::errors::{CortexResult, EmbeddingError};
 use cortex_core::memory::BaseMemory;
 use cortex_core::models::DegradationEvent;
 use cortex_core::traits::IEmbeddingProvider;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use crate::cache::CacheCoordinator;
+use crate::cache::l3_precomputed::{self, L3BuildStats};
+use crate::cache::{CacheCoordinator, L3CacheBuilder, L3PrecomputedCache, L3Quantization};
 use crate::degradation::DegradationChain;
 use crate::enrichment;
 use crate::matryoshka;
@@ -24,6 +27,8 @@ pub struct CacheStats {
     pub l1_count: usize,
     /// Number of entries in the L2 (SQLite) cache.
     pub l2_count: usize,
+    /// Number of entries in the L3 (precomputed) cache.
+    pub l3_count: usize,
     /// Total cached entries across tiers.
     pub total: usize,
 }
@@ -65,11 +70,13 @@ impl EmbeddingEngine {
             "EmbeddingEngine initialized"
         );
 
-        Self {
+        let mut engine = Self {
             chain,
             cache,
             config,
-        }
+        };
+        engine.attach_configured_l3();
+        engine
     }
 
     /// D-01: Create an engine with a file-backed L2 embedding cache.
@@ -90,11 +97,80 @@ impl EmbeddingEngine {
             "EmbeddingEngine initialized with persistent L2 cache"
         );
 
-        Self {
+        let mut engine = Self {
             chain,
             cache,
             config,
+        };
+        engine.attach_configured_l3();
+        engine
+    }
+
+    /// Map the L3 file from `config.l3_cache_path`, if any. A missing or
+    /// stale file is logged and skipped; the engine still works without it.
+    fn attach_configured_l3(&mut self) {
+        let Some(path) = self.config.l3_cache_path.clone() else {
+            return;
+        };
+        if let Err(e) = self.load_precomputed_cache(Path::new(&path)) {
+            warn!(path = %path, error = %e, "L3 precomputed cache not loaded");
+        }
+    }
+
+    /// Memory-map a precomputed L3 file as the first cache tier.
+    ///
+    /// Rejects files built by a different provider or with different
+    /// dimensions, since their vectors are not comparable. Returns the
+    /// number of precomputed entries.
+    pub fn load_precomputed_cache(&mut self, path: &Path) -> CortexResult<usize> {
+        let l3 = L3PrecomputedCache::open(path)?;
+        let header = l3.header().expect("opened from file");
+        let provider = self.chain.active_provider_name();
+        if header.model_id != provider || header.dimensions != self.config.dimensions {
+            return Err(EmbeddingError::PrecomputedCache {
+                path: path.display().to_string(),
+                reason: format!(
+                    "built for {} ({} dims), active provider is {} ({} dims)",
+                    header.model_id, header.dimensions, provider, self.config.dimensions
+                ),
+            }
+            .into());
         }
+        let count = l3.len();
+        info!(path = %path.display(), entries = count, "L3 precomputed cache mapped");
+        self.cache.l3 = l3;
+        Ok(count)
+    }
+
+    /// Precompute embeddings for the `limit` hottest memories and write an
+    /// L3 file for the active provider (see [`l3_precomputed::select_hot`]).
+    ///
+    /// Embeddings already in L1/L2 are reused, so rebuilding is cheap.
+    pub fn build_precomputed_cache(
+        &mut self,
+        memories: &[BaseMemory],
+        limit: usize,
+        quantization: L3Quantization,
+        path: &Path,
+    ) -> CortexResult<L3BuildStats> {
+        let hot = l3_precomputed::select_hot(memories, limit);
+        let mut builder = L3CacheBuilder::new(
+            self.chain.active_provider_name(),
+            self.config.dimensions,
+            quantization,
+        );
+        for memory in hot {
+            let embedding = self.embed_memory(memory)?;
+            builder.insert(&memory.content_hash, &embedding)?;
+        }
+        let stats = builder.write(path)?;
+        info!(
+            path = %path.display(),
+            entries = stats.entries,
+            bytes = stats.bytes,
+            "L3 precomputed cache written"
+        );
+        Ok(stats)
     }
 
     /// Embed a `BaseMemory` with enrichment and caching.
@@ -174,15 +250,16 @@ impl EmbeddingEngine {
         self.config.matryoshka_search_dims
     }
 
-    /// B-05: Get cache statistics (L1 size, L2 size, total cached entries).
-    /// Returns (l1_count, l2_count, total).
+    /// B-05: Get cache statistics (L1, L2 and L3 sizes, total cached entries).
     pub fn cache_stats(&self) -> CacheStats {
         let l1_count = self.cache.l1.len() as usize;
         let l2_count = self.cache.l2.len();
+        let l3_count = self.cache.l3.len();
         CacheStats {
             l1_count,
             l2_count,
-            total: l1_count + l2_count,
+            l3_count,
+            total: l1_count + l2_count + l3_count,
         }
     }
 
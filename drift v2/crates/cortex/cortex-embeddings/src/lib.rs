@@ -14,7 +14,7 @@
 //! │   ├── OllamaProvider (local, flexible)
 //! │   └── TfIdfFallback (always available)
 //! ├── CacheCoordinator (3-tier)
-//! │   ├── L3 Precomputed (zero-latency)
+//! │   ├── L3 Precomputed (mmap file, zero-latency)
 //! │   ├── L1 Memory (moka, sub-μs)
 //! │   └── L2 SQLite (persistent, ms)
 //! ├── Enrichment (metadata prefix)
@@ -0,0 +1,223 @@
+//! L3 precomputed cache: binary format round trips, quantization, header
+//! validation, hot-content selection and engine cold starts.
+
+use std::borrow::Cow;
+
+use chrono::{Duration, Utc};
+use cortex_core::config::EmbeddingConfig;
+use cortex_core::memory::types::InsightContent;
+use cortex_core::memory::*;
+use cortex_embeddings::cache::l3_precomputed::{select_hot, L3CacheBuilder, L3Quantization};
+use cortex_embeddings::cache::{CacheCoordinator, CacheHitTier, L3PrecomputedCache};
+use cortex_embeddings::EmbeddingEngine;
+
+fn make_memory(observation: &str, access_count: u64) -> BaseMemory {
+    let now = Utc::now();
+    let content = TypedContent::Insight(InsightContent {
+        observation: observation.to_string(),
+        evidence: vec![],
+    });
+    BaseMemory {
+        id: observation.to_string(),
+        memory_type: MemoryType::Insight,
+        content: content.clone(),
+        summary: observation.to_string(),
+        transaction_time: now,
+        valid_time: now,
+        valid_until: None,
+        confidence: Confidence::new(0.8),
+        importance: Importance::Normal,
+        last_accessed: now,
+        access_count,
+        linked_patterns: vec![],
+        linked_constraints: vec![],
+        linked_files: vec![],
+        linked_functions: vec![],
+        tags: vec![],
+        archived: false,
+        superseded_by: None,
+        supersedes: None,
+        namespace: Default::default(),
+        source_agent: Default::default(),
+        content_hash: BaseMemory::compute_content_hash(&content).unwrap(),
+    }
+}
+
+fn tfidf_config() -> EmbeddingConfig {
+    EmbeddingConfig {
+        provider: "tfidf".to_string(),
+        dimensions: 128,
+        matryoshka_search_dims: 64,
+        l2_cache_enabled: false,
+        ..Default::default()
+    }
+}
+
+fn build(quantization: L3Quantization, entries: &[(String, Vec<f32>)]) -> tempfile::TempPath {
+    let mut builder = L3CacheBuilder::new("model-x", 4, quantization);
+    for (hash, vector) in entries {
+        builder.insert(hash, vector).unwrap();
+    }
+    let path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    let stats = builder.write(&path).unwrap();
+    assert_eq!(stats.entries, builder.len());
+    assert_eq!(stats.bytes, std::fs::metadata(&path).unwrap().len());
+    path
+}
+
+fn sample() -> Vec<(String, Vec<f32>)> {
+    (0..50)
+        .map(|i| {
+            let x = i as f32;
+            (format!("hash-{i}"), vec![x, -x / 2.0, 0.25, 1.0 - x])
+        })
+        .collect()
+}
+
+#[test]
+fn f32_file_round_trips_and_borrows_in_place() {
+    let entries = sample();
+    let path = build(L3Quantization::F32, &entries);
+    let cache = L3PrecomputedCache::open(&path).unwrap();
+
+    let header = cache.header().unwrap();
+    assert_eq!(header.model_id, "model-x");
+    assert_eq!(header.dimensions, 4);
+    assert_eq!(header.quantization, L3Quantization::F32);
+    assert_eq!(cache.len(), 50);
+
+    for (hash, vector) in &entries {
+        let found = cache.get(hash).unwrap();
+        assert_eq!(&*found, vector.as_slice());
+        if cfg!(target_endian = "little") {
+            assert!(matches!(found, Cow::Borrowed(_)));
+        }
+    }
+    assert!(cache.get("hash-50").is_none());
+}
+
+#[test]
+fn int8_file_dequantizes_within_tolerance() {
+    let entries = sample();
+    let path = build(L3Quantization::Int8, &entries);
+    let f32_path = build(L3Quantization::F32, &entries);
+    assert!(std::fs::metadata(&path).unwrap().len() < std::fs::metadata(&f32_path).unwrap().len());
+
+    let cache = L3PrecomputedCache::open(&path).unwrap();
+    for (hash, vector) in &entries {
+        let found = cache.get(hash).unwrap();
+        let max = vector.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        for (a, b) in found.iter().zip(vector) {
+            assert!((a - b).abs() <= max / 127.0, "{a} vs {b}");
+        }
+    }
+}
+
+#[test]
+fn empty_file_is_valid() {
+    let path = build(L3Quantization::F32, &[]);
+    let cache = L3PrecomputedCache::open(&path).unwrap();
+    assert!(cache.is_empty());
+    assert!(cache.get("anything").is_none());
+}
+
+#[test]
+fn truncated_or_foreign_files_are_rejected() {
+    let path = build(L3Quantization::F32, &sample());
+    let bytes = std::fs::read(&path).unwrap();
+
+    let truncated = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&truncated, &bytes[..bytes.len() - 3]).unwrap();
+    assert!(L3PrecomputedCache::open(&truncated).is_err());
+
+    let foreign = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&foreign, b"SQLite format 3\0 and then some more bytes").unwrap();
+    assert!(L3PrecomputedCache::open(&foreign).is_err());
+
+    let mut future = bytes.clone();
+    future[8..12].copy_from_slice(&99u32.to_le_bytes());
+    let newer = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&newer, &future).unwrap();
+    assert!(L3PrecomputedCache::open(&newer).is_err());
+}
+
+#[test]
+fn builder_rejects_wrong_dimensions() {
+    let mut builder = L3CacheBuilder::new("model-x", 4, L3Quantization::F32);
+    assert!(builder.insert("h", &[1.0, 2.0]).is_err());
+    assert!(builder.is_empty());
+}
+
+#[test]
+fn coordinator_serves_mapped_l3_first() {
+    let path = build(L3Quantization::F32, &sample());
+    let mut coord = CacheCoordinator::new(100);
+    coord.l3 = L3PrecomputedCache::open(&path).unwrap();
+
+    let (found, tier) = coord.get("hash-3");
+    assert_eq!(tier, CacheHitTier::L3);
+    assert_eq!(found, Some(vec![3.0, -1.5, 0.25, -2.0]));
+    assert!(coord.l1.get("hash-3").is_some());
+}
+
+#[test]
+fn select_hot_ranks_shared_content_by_total_access() {
+    let boilerplate_a = make_memory("use the shared retry helper", 2);
+    let mut boilerplate_b = boilerplate_a.clone();
+    boilerplate_b.id = "dup".to_string();
+    boilerplate_b.access_count = 3;
+    let single = make_memory("one-off note", 4);
+    let mut archived = make_memory("archived note", 100);
+    archived.archived = true;
+    let mut cold = make_memory("cold note", 1);
+    cold.last_accessed = Utc::now() - Duration::days(30);
+
+    let memories = vec![
+        single.clone(),
+        boilerplate_a.clone(),
+        archived,
+        cold,
+        boilerplate_b,
+    ];
+    let hot = select_hot(&memories, 2);
+    assert_eq!(hot.len(), 2);
+    assert_eq!(hot[0].content_hash, boilerplate_a.content_hash);
+    assert_eq!(hot[1].content_hash, single.content_hash);
+}
+
+#[test]
+fn engine_cold_start_hits_precomputed_cache() {
+    let memories: Vec<BaseMemory> = (0..5)
+        .map(|i| make_memory(&format!("hot note {i}"), 10 - i))
+        .collect();
+    let path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+
+    let expected = {
+        let mut engine = EmbeddingEngine::new(tfidf_config());
+        let stats = engine
+            .build_precomputed_cache(&memories, 3, L3Quantization::F32, &path)
+            .unwrap();
+        assert_eq!(stats.entries, 3);
+        engine.embed_memory(&memories[0]).unwrap()
+    };
+
+    let config = EmbeddingConfig {
+        l3_cache_path: Some(path.to_string_lossy().into_owned()),
+        ..tfidf_config()
+    };
+    let mut engine = EmbeddingEngine::new(config);
+    assert_eq!(engine.cache_stats().l3_count, 3);
+    assert_eq!(engine.embed_memory(&memories[0]).unwrap(), expected);
+}
+
+#[test]
+fn engine_rejects_cache_from_other_model() {
+    let mut builder = L3CacheBuilder::new("some-other-model", 128, L3Quantization::F32);
+    builder.insert("h", &[0.5; 128]).unwrap();
+    let path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    builder.write(&path).unwrap();
+
+    let mut engine = EmbeddingEngine::new(tfidf_config());
+    assert!(engine.load_precomputed_cache(&path).is_err());
+    assert_eq!(engine.cache_stats().l3_count, 0);
+}
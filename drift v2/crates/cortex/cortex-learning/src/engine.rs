@@ -54,6 +54,11 @@ impl LearningEngine {
         }
     }
 
+    /// Replace the LLM extractor (can be called after construction).
+    pub fn set_llm_extractor(&mut self, llm_extractor: Box<dyn extraction::LlmExtractor>) {
+        self.llm_extractor = llm_extractor;
+    }
+
     /// Set the storage backend (can be called after construction).
     pub fn set_storage(&mut self, storage: Arc<dyn IMemoryStorage>) {
         self.storage = Some(storage);
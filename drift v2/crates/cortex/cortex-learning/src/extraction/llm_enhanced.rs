@@ -1,5 +1,10 @@
 //! Optional LLM-assisted extraction, falls back to rule_based if unavailable.
 
+use std::sync::Arc;
+
+use cortex_core::traits::{CompletionRequest, ICompletionProvider};
+use tracing::debug;
+
 use super::rule_based;
 
 /// Trait for LLM-based principle extraction.
@@ -17,6 +22,44 @@ impl LlmExtractor for NoOpExtractor {
     }
 }
 
+/// Extractor backed by a completion provider (local or remote LLM).
+///
+/// Any provider error, budget refusal or empty answer yields `None`, so
+/// `extract_with_fallback` drops to the rule-based extractor.
+pub struct CompletionExtractor {
+    provider: Arc<dyn ICompletionProvider>,
+}
+
+impl CompletionExtractor {
+    pub fn new(provider: Arc<dyn ICompletionProvider>) -> Self {
+        Self { provider }
+    }
+}
+
+const EXTRACTION_SYSTEM: &str = "You turn code review corrections into one reusable \
+engineering principle. Answer with a single imperative sentence, no preamble.";
+
+impl LlmExtractor for CompletionExtractor {
+    fn extract(&self, correction_text: &str, context: &str) -> Option<String> {
+        if !self.provider.is_available() {
+            return None;
+        }
+        let prompt = format!("Context: {context}\nCorrection: {correction_text}\nPrinciple:");
+        let request = CompletionRequest::new(prompt, 80).with_system(EXTRACTION_SYSTEM);
+        match self.provider.complete(&request) {
+            Ok(completion) => {
+                let line = completion.text.lines().next().unwrap_or("").trim();
+                let principle = line.trim_start_matches("Principle:").trim();
+                (!principle.is_empty()).then(|| principle.to_string())
+            }
+            Err(e) => {
+                debug!(error = %e, "LLM extraction unavailable, using rule-based");
+                None
+            }
+        }
+    }
+}
+
 /// Extract a principle, trying LLM first, falling back to rule-based.
 pub fn extract_with_fallback(
     correction_text: &str,
@@ -57,4 +100,46 @@ mod tests {
         let result = extract_with_fallback("anything", "ctx", &extractor);
         assert_eq!(result.unwrap(), "LLM-extracted principle");
     }
+
+    struct FixedProvider(Option<&'static str>);
+    impl ICompletionProvider for FixedProvider {
+        fn complete(
+            &self,
+            _request: &CompletionRequest,
+        ) -> cortex_core::errors::CortexResult<cortex_core::traits::Completion> {
+            match self.0 {
+                Some(text) => Ok(cortex_core::traits::Completion {
+                    text: text.to_string(),
+                    prompt_tokens: 10,
+                    completion_tokens: 5,
+                }),
+                None => Err(cortex_core::errors::CompletionError::RequestFailed {
+                    reason: "offline".to_string(),
+                }
+                .into()),
+            }
+        }
+        fn name(&self) -> &str {
+            "fixed"
+        }
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn completion_extractor_takes_first_line() {
+        let extractor = CompletionExtractor::new(Arc::new(FixedProvider(Some(
+            "Principle: Prefer dependency injection over globals\nbecause...",
+        ))));
+        let result = extract_with_fallback("Don't use global state", "architecture", &extractor);
+        assert_eq!(result.unwrap(), "Prefer dependency injection over globals");
+    }
+
+    #[test]
+    fn completion_extractor_errors_fall_back_to_rules() {
+        let extractor = CompletionExtractor::new(Arc::new(FixedProvider(None)));
+        let result = extract_with_fallback("Don't use global state", "architecture", &extractor);
+        assert!(result.unwrap().starts_with("Avoid:"));
+    }
 }
@@ -3,5 +3,5 @@
 pub mod llm_enhanced;
 pub mod rule_based;
 
-pub use llm_enhanced::{extract_with_fallback, LlmExtractor, NoOpExtractor};
+pub use llm_enhanced::{extract_with_fallback, CompletionExtractor, LlmExtractor, NoOpExtractor};
 pub use rule_based::extract_principle;
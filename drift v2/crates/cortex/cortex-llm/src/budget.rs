@@ -0,0 +1,162 @@
+//! Request budgeting: caps requests per rolling minute and tokens per
+//! rolling hour so background jobs (consolidation, learning) can't saturate
+//! a shared local model.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use cortex_core::errors::{CompletionError, CortexResult};
+use cortex_core::traits::{Completion, CompletionRequest, ICompletionProvider};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+const MINUTE: Duration = Duration::from_secs(60);
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// Budget limits. Zero disables the corresponding limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestBudget {
+    pub requests_per_minute: u32,
+    pub tokens_per_hour: usize,
+}
+
+/// Current consumption within the rolling windows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BudgetUsage {
+    pub requests_last_minute: u32,
+    pub tokens_last_hour: usize,
+    /// Requests refused because the budget was exhausted.
+    pub rejected: u64,
+}
+
+#[derive(Default)]
+struct Window {
+    requests: VecDeque<Instant>,
+    tokens: VecDeque<(Instant, usize)>,
+    rejected: u64,
+}
+
+impl Window {
+    fn prune(&mut self, now: Instant) {
+        while self
+            .requests
+            .front()
+            .is_some_and(|t| now.duration_since(*t) >= MINUTE)
+        {
+            self.requests.pop_front();
+        }
+        while self
+            .tokens
+            .front()
+            .is_some_and(|(t, _)| now.duration_since(*t) >= HOUR)
+        {
+            self.tokens.pop_front();
+        }
+    }
+
+    fn tokens_used(&self) -> usize {
+        self.tokens.iter().map(|(_, n)| n).sum()
+    }
+}
+
+/// Wraps a provider and refuses requests that would exceed the budget.
+///
+/// Token checks use a pessimistic estimate (prompt chars / 4 + `max_tokens`);
+/// the window records the usage the server actually reports.
+pub struct BudgetedProvider<P> {
+    inner: P,
+    budget: RequestBudget,
+    window: Mutex<Window>,
+}
+
+impl<P: ICompletionProvider> BudgetedProvider<P> {
+    pub fn new(inner: P, budget: RequestBudget) -> Self {
+        Self {
+            inner,
+            budget,
+            window: Mutex::new(Window::default()),
+        }
+    }
+
+    /// Consumption within the current windows.
+    pub fn usage(&self) -> BudgetUsage {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        window.prune(Instant::now());
+        BudgetUsage {
+            requests_last_minute: window.requests.len() as u32,
+            tokens_last_hour: window.tokens_used(),
+            rejected: window.rejected,
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Reserve a request slot, or explain why the budget refuses it.
+    fn admit(&self, request: &CompletionRequest) -> CortexResult<()> {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        window.prune(now);
+
+        let rpm = self.budget.requests_per_minute;
+        if rpm > 0 && window.requests.len() >= rpm as usize {
+            window.rejected += 1;
+            return Err(CompletionError::BudgetExhausted {
+                reason: format!("{rpm} requests/minute"),
+            }
+            .into());
+        }
+
+        let tph = self.budget.tokens_per_hour;
+        let estimate = estimate_tokens(request);
+        if tph > 0 && window.tokens_used() + estimate > tph {
+            window.rejected += 1;
+            return Err(CompletionError::BudgetExhausted {
+                reason: format!("{tph} tokens/hour"),
+            }
+            .into());
+        }
+
+        window.requests.push_back(now);
+        Ok(())
+    }
+
+    fn record(&self, completion: &Completion) {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        window.tokens.push_back((
+            Instant::now(),
+            completion.prompt_tokens + completion.completion_tokens,
+        ));
+    }
+}
+
+impl<P: ICompletionProvider> ICompletionProvider for BudgetedProvider<P> {
+    fn complete(&self, request: &CompletionRequest) -> CortexResult<Completion> {
+        self.admit(request)?;
+        let completion = self.inner.complete(request)?;
+        self.record(&completion);
+        debug!(
+            provider = self.inner.name(),
+            prompt_tokens = completion.prompt_tokens,
+            completion_tokens = completion.completion_tokens,
+            "completion within budget"
+        );
+        Ok(completion)
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn is_available(&self) -> bool {
+        self.inner.is_available()
+    }
+}
+
+/// Rough upper bound on a request's token cost.
+fn estimate_tokens(request: &CompletionRequest) -> usize {
+    let chars = request.prompt.len() + request.system.as_ref().map_or(0, String::len);
+    chars.div_ceil(4) + request.max_tokens
+}
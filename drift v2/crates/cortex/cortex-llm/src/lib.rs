@@ -0,0 +1,62 @@
+//! # cortex-llm
+//!
+//! Pluggable completion backend for the few places Cortex benefits from an
+//! LLM: principle extraction, HyDE documents and abstractive consolidation
+//! summaries. Every caller keeps its rule-based path as the fallback.
+//!
+//! ## Architecture
+//!
+//! ```text
+//! ICompletionProvider (cortex-core)
+//! ├── OpenAiCompatProvider (POST {base_url}/chat/completions)
+//! │   └── llama.cpp server, Ollama, vLLM, LM Studio, local mocks
+//! └── BudgetedProvider (requests/minute + tokens/hour, wraps any provider)
+//! ```
+
+pub mod budget;
+pub mod openai_compat;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cortex_core::config::LlmConfig;
+use cortex_core::traits::ICompletionProvider;
+use tracing::{info, warn};
+
+pub use budget::{BudgetUsage, BudgetedProvider, RequestBudget};
+pub use openai_compat::OpenAiCompatProvider;
+
+/// Build the configured completion provider, or `None` when the LLM is
+/// disabled, unconfigured or unreachable (callers then use rule-based paths).
+pub fn create_provider(config: &LlmConfig) -> Option<Arc<dyn ICompletionProvider>> {
+    if !config.enabled {
+        return None;
+    }
+    if config.model.is_empty() {
+        warn!("LLM enabled but no model configured; using rule-based fallbacks");
+        return None;
+    }
+
+    let api_key = config
+        .api_key_env
+        .as_ref()
+        .and_then(|var| std::env::var(var).ok());
+    let provider = OpenAiCompatProvider::new(
+        config.base_url.clone(),
+        config.model.clone(),
+        api_key,
+        Duration::from_millis(config.timeout_ms),
+    )
+    .with_max_tokens(config.max_tokens);
+    if !provider.health_check() {
+        warn!(base_url = %config.base_url, "LLM server unreachable; using rule-based fallbacks");
+        return None;
+    }
+
+    info!(model = %config.model, base_url = %config.base_url, "LLM completion provider connected");
+    let budget = RequestBudget {
+        requests_per_minute: config.requests_per_minute,
+        tokens_per_hour: config.tokens_per_hour,
+    };
+    Some(Arc::new(BudgetedProvider::new(provider, budget)))
+}
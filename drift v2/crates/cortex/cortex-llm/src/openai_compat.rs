@@ -0,0 +1,229 @@
+//! OpenAI-compatible chat completions client.
+//!
+//! Talks to any server exposing `POST {base_url}/chat/completions` and
+//! `GET {base_url}/models`: llama.cpp's server, Ollama (`/v1`), vLLM,
+//! LM Studio, or a local mock in tests.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use cortex_core::config::defaults::DEFAULT_LLM_MAX_TOKENS;
+use cortex_core::errors::{CompletionError, CortexResult};
+use cortex_core::traits::{Completion, CompletionRequest, ICompletionProvider};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// OpenAI-compatible completion provider.
+pub struct OpenAiCompatProvider {
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+    timeout: Duration,
+    /// Cap on `max_tokens` sent with any request.
+    max_tokens: usize,
+    available: AtomicBool,
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    max_tokens: usize,
+    temperature: f32,
+    stream: bool,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatChoiceMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatChoiceMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ChatUsage {
+    prompt_tokens: usize,
+    completion_tokens: usize,
+}
+
+impl OpenAiCompatProvider {
+    /// Create a provider. `base_url` includes the API version prefix,
+    /// e.g. `http://localhost:8080/v1`.
+    pub fn new(
+        base_url: String,
+        model: String,
+        api_key: Option<String>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model,
+            api_key,
+            timeout,
+            max_tokens: DEFAULT_LLM_MAX_TOKENS,
+            available: AtomicBool::new(false), // Must pass health check first.
+        }
+    }
+
+    /// Cap every request's completion length (`LlmConfig::max_tokens`).
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// Check if the server is reachable (`GET /models`).
+    pub fn health_check(&self) -> bool {
+        let url = format!("{}/models", self.base_url);
+        let result = self.block_on(async {
+            let mut req = reqwest::Client::new()
+                .get(&url)
+                .timeout(Duration::from_secs(5));
+            if let Some(key) = &self.api_key {
+                req = req.bearer_auth(key);
+            }
+            req.send().await.map_err(|e| e.to_string())
+        });
+
+        let ok = match result {
+            Ok(Ok(resp)) if resp.status().is_success() => true,
+            Ok(Ok(resp)) => {
+                warn!(status = %resp.status(), "LLM health check failed");
+                false
+            }
+            Ok(Err(e)) | Err(e) => {
+                warn!(error = %e, "LLM server unreachable");
+                false
+            }
+        };
+        self.available.store(ok, Ordering::Relaxed);
+        ok
+    }
+
+    /// Run a future on a private current-thread runtime; the provider trait
+    /// is synchronous.
+    fn block_on<F: std::future::Future>(&self, fut: F) -> Result<F::Output, String> {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map(|rt| rt.block_on(fut))
+            .map_err(|e| format!("runtime error: {e}"))
+    }
+
+    async fn send(&self, request: &CompletionRequest) -> CortexResult<Completion> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = &request.system {
+            messages.push(ChatMessage {
+                role: "system",
+                content: system,
+            });
+        }
+        messages.push(ChatMessage {
+            role: "user",
+            content: &request.prompt,
+        });
+        let body = ChatRequest {
+            model: &self.model,
+            messages,
+            max_tokens: request.max_tokens.min(self.max_tokens),
+            temperature: request.temperature,
+            stream: false,
+        };
+
+        let url = format!("{}/chat/completions", self.base_url);
+        let mut req = reqwest::Client::new()
+            .post(&url)
+            .timeout(self.timeout)
+            .json(&body);
+        if let Some(key) = &self.api_key {
+            req = req.bearer_auth(key);
+        }
+        let response = req
+            .send()
+            .await
+            .map_err(|e| request_failed(format!("HTTP error: {e}")))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(request_failed(format!("server returned {status}: {text}")));
+        }
+
+        let parsed: ChatResponse = response
+            .json()
+            .await
+            .map_err(|e| request_failed(format!("JSON parse error: {e}")))?;
+
+        let text = parsed
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|c| c.message.content)
+            .map(|t| t.trim().to_string())
+            .unwrap_or_default();
+        if text.is_empty() {
+            return Err(CompletionError::EmptyResponse {
+                provider: self.model.clone(),
+            }
+            .into());
+        }
+
+        // Some servers omit usage; fall back to a chars/4 estimate.
+        let (prompt_tokens, completion_tokens) = match parsed.usage {
+            Some(u) => (u.prompt_tokens, u.completion_tokens),
+            None => (
+                (request.prompt.len() + request.system.as_ref().map_or(0, String::len)).div_ceil(4),
+                text.len().div_ceil(4),
+            ),
+        };
+        Ok(Completion {
+            text,
+            prompt_tokens,
+            completion_tokens,
+        })
+    }
+}
+
+impl ICompletionProvider for OpenAiCompatProvider {
+    fn complete(&self, request: &CompletionRequest) -> CortexResult<Completion> {
+        if !self.available.load(Ordering::Relaxed) {
+            return Err(CompletionError::ProviderUnavailable {
+                provider: self.model.clone(),
+            }
+            .into());
+        }
+        let completion = self
+            .block_on(self.send(request))
+            .map_err(request_failed)??;
+        debug!(model = %self.model, chars = completion.text.len(), "completion received");
+        Ok(completion)
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+
+    fn is_available(&self) -> bool {
+        self.available.load(Ordering::Relaxed)
+    }
+}
+
+fn request_failed(reason: String) -> cortex_core::CortexError {
+    CompletionError::RequestFailed { reason }.into()
+}
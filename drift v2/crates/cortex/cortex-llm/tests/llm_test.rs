@@ -0,0 +1,258 @@
+//! Completion backend: OpenAI-compatible client against a local mock server,
+//! budget enforcement, and provider creation fallbacks.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use cortex_core::config::LlmConfig;
+use cortex_core::errors::{CompletionError, CortexError, CortexResult};
+use cortex_core::traits::{Completion, CompletionRequest, ICompletionProvider};
+use cortex_llm::{create_provider, BudgetedProvider, OpenAiCompatProvider, RequestBudget};
+
+/// Minimal HTTP/1.1 server: `GET /v1/models` answers 200, `POST
+/// /v1/chat/completions` answers `chat_body`. Request bodies are recorded.
+fn spawn_mock(chat_body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = seen.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { break };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0usize;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some(v) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = v.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).unwrap();
+            recorded
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&body).into_owned());
+
+            let payload = if request_line.starts_with("GET /v1/models") {
+                r#"{"data":[{"id":"mock"}]}"#
+            } else {
+                chat_body
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{payload}",
+                payload.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    (format!("http://{addr}/v1"), seen)
+}
+
+fn connected(base_url: String) -> OpenAiCompatProvider {
+    let provider =
+        OpenAiCompatProvider::new(base_url, "mock".to_string(), None, Duration::from_secs(5));
+    assert!(provider.health_check());
+    provider
+}
+
+/// Provider that answers every request with a fixed usage.
+struct FixedProvider {
+    calls: AtomicUsize,
+}
+
+impl ICompletionProvider for FixedProvider {
+    fn complete(&self, _request: &CompletionRequest) -> CortexResult<Completion> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(Completion {
+            text: "ok".to_string(),
+            prompt_tokens: 40,
+            completion_tokens: 10,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "fixed"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+}
+
+fn budgeted(requests_per_minute: u32, tokens_per_hour: usize) -> BudgetedProvider<FixedProvider> {
+    BudgetedProvider::new(
+        FixedProvider {
+            calls: AtomicUsize::new(0),
+        },
+        RequestBudget {
+            requests_per_minute,
+            tokens_per_hour,
+        },
+    )
+}
+
+fn is_budget_error(err: &CortexError) -> bool {
+    matches!(
+        err,
+        CortexError::CompletionError(CompletionError::BudgetExhausted { .. })
+    )
+}
+
+#[test]
+fn completes_against_openai_compatible_server() {
+    let (base_url, seen) = spawn_mock(
+        r#"{"choices":[{"message":{"role":"assistant","content":"  Always add jitter.  "}}],
+            "usage":{"prompt_tokens":12,"completion_tokens":4}}"#,
+    );
+    let provider = connected(base_url);
+
+    let request = CompletionRequest::new("why jitter?", 32).with_system("be brief");
+    let completion = provider.complete(&request).unwrap();
+    assert_eq!(completion.text, "Always add jitter.");
+    assert_eq!(completion.prompt_tokens, 12);
+    assert_eq!(completion.completion_tokens, 4);
+
+    let bodies = seen.lock().unwrap();
+    let sent: serde_json::Value = serde_json::from_str(bodies.last().unwrap()).unwrap();
+    assert_eq!(sent["model"], "mock");
+    assert_eq!(sent["max_tokens"], 32);
+    assert_eq!(sent["messages"][0]["role"], "system");
+    assert_eq!(sent["messages"][1]["content"], "why jitter?");
+}
+
+#[test]
+fn configured_max_tokens_caps_requests() {
+    let (base_url, seen) = spawn_mock(r#"{"choices":[{"message":{"content":"ok"}}]}"#);
+    let provider = connected(base_url).with_max_tokens(16);
+
+    provider.complete(&CompletionRequest::new("long answer", 64)).unwrap();
+    provider.complete(&CompletionRequest::new("short answer", 8)).unwrap();
+
+    let bodies = seen.lock().unwrap();
+    let sent: Vec<serde_json::Value> = bodies[bodies.len() - 2..]
+        .iter()
+        .map(|b| serde_json::from_str(b).unwrap())
+        .collect();
+    assert_eq!(sent[0]["max_tokens"], 16);
+    assert_eq!(sent[1]["max_tokens"], 8);
+}
+
+#[test]
+fn missing_usage_is_estimated() {
+    let (base_url, _) = spawn_mock(r#"{"choices":[{"message":{"content":"abcdefgh"}}]}"#);
+    let provider = connected(base_url);
+    let completion = provider
+        .complete(&CompletionRequest::new("12345678", 8))
+        .unwrap();
+    assert_eq!(completion.prompt_tokens, 2);
+    assert_eq!(completion.completion_tokens, 2);
+}
+
+#[test]
+fn empty_answer_is_an_error() {
+    let (base_url, _) = spawn_mock(r#"{"choices":[{"message":{"content":"   "}}]}"#);
+    let provider = connected(base_url);
+    let err = provider
+        .complete(&CompletionRequest::new("q", 8))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CortexError::CompletionError(CompletionError::EmptyResponse { .. })
+    ));
+}
+
+#[test]
+fn unchecked_provider_is_unavailable() {
+    let provider = OpenAiCompatProvider::new(
+        "http://127.0.0.1:9/v1".to_string(),
+        "mock".to_string(),
+        None,
+        Duration::from_millis(200),
+    );
+    assert!(!provider.is_available());
+    assert!(provider.complete(&CompletionRequest::new("q", 8)).is_err());
+    assert!(!provider.health_check());
+}
+
+#[test]
+fn requests_per_minute_is_enforced() {
+    let provider = budgeted(2, 0);
+    let request = CompletionRequest::new("q", 8);
+    provider.complete(&request).unwrap();
+    provider.complete(&request).unwrap();
+    let err = provider.complete(&request).unwrap_err();
+    assert!(is_budget_error(&err));
+
+    let usage = provider.usage();
+    assert_eq!(usage.requests_last_minute, 2);
+    assert_eq!(usage.tokens_last_hour, 100);
+    assert_eq!(usage.rejected, 1);
+    assert_eq!(provider.inner().calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn tokens_per_hour_counts_reported_usage_and_estimate() {
+    // Each call reports 50 tokens; the next request estimates 1 + 8 more.
+    let provider = budgeted(0, 55);
+    let request = CompletionRequest::new("q", 8);
+    provider.complete(&request).unwrap();
+    assert!(is_budget_error(&provider.complete(&request).unwrap_err()));
+    assert_eq!(provider.usage().tokens_last_hour, 50);
+
+    // A request whose own estimate exceeds the budget is refused outright.
+    let fresh = budgeted(0, 55);
+    let big = CompletionRequest::new("q", 500);
+    assert!(is_budget_error(&fresh.complete(&big).unwrap_err()));
+    assert_eq!(fresh.inner().calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn create_provider_falls_back_to_none() {
+    assert!(create_provider(&LlmConfig::default()).is_none());
+
+    let no_model = LlmConfig {
+        enabled: true,
+        ..Default::default()
+    };
+    assert!(create_provider(&no_model).is_none());
+
+    let unreachable = LlmConfig {
+        enabled: true,
+        model: "mock".to_string(),
+        base_url: "http://127.0.0.1:9/v1".to_string(),
+        ..Default::default()
+    };
+    assert!(create_provider(&unreachable).is_none());
+}
+
+#[test]
+fn create_provider_connects_to_reachable_server() {
+    let (base_url, _) = spawn_mock(r#"{"choices":[{"message":{"content":"hi"}}]}"#);
+    let config = LlmConfig {
+        enabled: true,
+        model: "mock".to_string(),
+        base_url,
+        ..Default::default()
+    };
+    let provider = create_provider(&config).unwrap();
+    assert!(provider.is_available());
+    assert_eq!(provider.name(), "mock");
+    assert_eq!(
+        provider
+            .complete(&CompletionRequest::new("hello", 8))
+            .unwrap()
+            .text,
+        "hi"
+    );
+}
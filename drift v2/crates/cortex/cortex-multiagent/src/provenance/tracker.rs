@@ -191,6 +191,7 @@ fn action_to_str(action: &ProvenanceAction) -> &'static str {
         ProvenanceAction::CorrectedBy => "corrected_by",
         ProvenanceAction::ReclassifiedFrom => "reclassified_from",
         ProvenanceAction::Retracted => "retracted",
+        ProvenanceAction::ImportedFrom => "imported_from",
     }
 }
 
@@ -207,6 +208,7 @@ fn str_to_action(s: &str) -> ProvenanceAction {
         "corrected_by" => ProvenanceAction::CorrectedBy,
         "reclassified_from" => ProvenanceAction::ReclassifiedFrom,
         "retracted" => ProvenanceAction::Retracted,
+        "imported_from" => ProvenanceAction::ImportedFrom,
         _ => ProvenanceAction::Created, // Fallback.
     }
 }
@@ -220,6 +222,7 @@ fn origin_from_action(action: &ProvenanceAction) -> ProvenanceOrigin {
         ProvenanceAction::MergedWith => ProvenanceOrigin::Derived,
         ProvenanceAction::ConsolidatedFrom => ProvenanceOrigin::Derived,
         ProvenanceAction::Retracted => ProvenanceOrigin::Derived,
+        ProvenanceAction::ImportedFrom => ProvenanceOrigin::Imported,
         _ => ProvenanceOrigin::Human,
     }
 }
@@ -64,8 +64,8 @@ pub fn cortex_health_get_health() -> napi::Result<serde_json::Value> {
                 0.0
             } else {
                 // Non-zero cache entries with a real provider → estimate rate.
-                // L1 hits are fast (most accessed), L2 hits are warm.
-                (stats.l1_count as f64 / stats.total.max(1) as f64).min(1.0)
+                // L1 and precomputed L3 hits are fast, L2 hits are warm.
+                ((stats.l1_count + stats.l3_count) as f64 / stats.total.max(1) as f64).min(1.0)
             }
         } else {
             // No cache entries yet — fresh start.
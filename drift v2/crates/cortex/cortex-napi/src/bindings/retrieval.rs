@@ -43,14 +43,23 @@ pub fn cortex_retrieval_search(
         sent_ids: vec![],
     };
 
+    let engine = RetrievalEngine::new(&rt.storage, &rt.compression, rt.config.retrieval.clone());
+
+    // With an LLM configured, embed a HyDE answer instead of the bare query.
+    let embed_text = match rt.llm.as_deref() {
+        Some(llm) if rt.config.retrieval.query_expansion => {
+            engine.hypothetical_document(&context, Some(llm))
+        }
+        _ => query.clone(),
+    };
+
     // Try to get a query embedding for hybrid search.
     let mut embeddings = rt
         .embeddings
         .lock()
         .map_err(|e| napi::Error::from_reason(format!("Embedding lock poisoned: {e}")))?;
-    let query_embedding = embeddings.embed_query_for_search(&query).ok();
+    let query_embedding = embeddings.embed_query_for_search(&embed_text).ok();
 
-    let engine = RetrievalEngine::new(&rt.storage, &rt.compression, rt.config.retrieval.clone());
     let results = engine
         .retrieve_with_embedding(&context, budget, query_embedding.as_deref())
         .map_err(error_types::to_napi_error)?;
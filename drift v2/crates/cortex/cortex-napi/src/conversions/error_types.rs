@@ -23,6 +23,7 @@ pub mod codes {
     pub const RUNTIME_NOT_INITIALIZED: &str = "RUNTIME_NOT_INITIALIZED";
     pub const TEMPORAL_ERROR: &str = "TEMPORAL_ERROR";
     pub const MULTI_AGENT_ERROR: &str = "MULTI_AGENT_ERROR";
+    pub const COMPLETION_ERROR: &str = "COMPLETION_ERROR";
 }
 
 /// Map a CortexError to a structured napi::Error with an error code.
@@ -84,6 +85,9 @@ pub fn to_napi_error(err: CortexError) -> napi::Error {
         CortexError::MultiAgentError(ref e) => {
             (codes::MULTI_AGENT_ERROR, format!("Multi-agent error: {e}"))
         }
+        CortexError::CompletionError(ref e) => {
+            (codes::COMPLETION_ERROR, format!("Completion error: {e}"))
+        }
     };
 
     napi::Error::new(Status::GenericFailure, format!("[{code}] {message}"))
@@ -9,17 +9,21 @@ use std::sync::{Arc, Mutex, OnceLock};
 use cortex_causal::CausalEngine;
 use cortex_cloud::CloudEngine;
 use cortex_compression::CompressionEngine;
+use cortex_consolidation::llm_polish::CompletionPolisher;
 use cortex_consolidation::ConsolidationEngine;
 use cortex_core::config::CortexConfig;
 use cortex_core::errors::CortexResult;
+use cortex_core::traits::ICompletionProvider;
 use cortex_decay::DecayEngine;
 use cortex_embeddings::EmbeddingEngine;
+use cortex_learning::extraction::CompletionExtractor;
 use cortex_learning::LearningEngine;
 use cortex_multiagent::MultiAgentEngine;
 use cortex_observability::ObservabilityEngine;
 use cortex_prediction::PredictionEngine;
 use cortex_privacy::PrivacyEngine;
 use cortex_session::SessionManager;
+use cortex_storage::encryption;
 use cortex_storage::StorageEngine;
 use cortex_temporal::TemporalEngine;
 use cortex_validation::ValidationEngine;
@@ -49,6 +53,8 @@ pub struct CortexRuntime {
     pub cloud: Option<Mutex<CloudEngine>>,
     pub temporal: TemporalEngine,
     pub multiagent: Mutex<MultiAgentEngine>,
+    /// Optional completion backend (`[llm]` config); None when disabled or unreachable.
+    pub llm: Option<Arc<dyn ICompletionProvider>>,
     pub config: CortexConfig,
 }
 
@@ -73,17 +79,25 @@ impl CortexRuntime {
         };
 
         // Storage — wrapped in Arc for sharing with learning/consolidation engines
-        let storage = Arc::new(match &opts.db_path {
-            Some(path) => StorageEngine::open(path)?,
-            None => StorageEngine::open_in_memory()?,
+        let key_source = encryption::key_source(&config.storage);
+        let storage = Arc::new(match (&opts.db_path, &key_source) {
+            (Some(path), Some(source)) => {
+                StorageEngine::open_encrypted(path, &encryption::load_key(source)?)?
+            }
+            (Some(path), None) => StorageEngine::open(path)?,
+            (None, _) => StorageEngine::open_in_memory()?,
         });
         // Create a trait-object Arc for engines that need IMemoryStorage
         let storage_trait: Arc<dyn cortex_core::traits::IMemoryStorage> = storage.clone();
 
         // Embeddings — D-01: use persistent L2 cache when file-backed.
+        // The L2 cache is a separate plaintext file, so encrypted mode keeps
+        // embeddings in memory only.
         let embeddings = match storage.pool().db_path.as_ref() {
-            Some(db_path) => EmbeddingEngine::new_with_db_path(config.embedding.clone(), db_path),
-            None => EmbeddingEngine::new(config.embedding.clone()),
+            Some(db_path) if key_source.is_none() => {
+                EmbeddingEngine::new_with_db_path(config.embedding.clone(), db_path)
+            }
+            _ => EmbeddingEngine::new(config.embedding.clone()),
         };
 
         // Compression
@@ -99,17 +113,26 @@ impl CortexRuntime {
         // Validation
         let validation = ValidationEngine::default();
 
+        // LLM — optional; every consumer falls back to its rule-based path without it.
+        let llm = cortex_llm::create_provider(&config.llm);
+
         // Learning — wired to shared storage for persistence
         let mut learning = LearningEngine::with_storage(storage_trait.clone());
+        if let Some(provider) = &llm {
+            learning.set_llm_extractor(Box::new(CompletionExtractor::new(provider.clone())));
+        }
         // Pre-populate existing memories for dedup.
         let _ = learning.refresh_existing_memories();
 
         // Consolidation — B-03: shares the main EmbeddingEngine via clone instead of
         // creating a duplicate. The main engine's cache and provider chain are reused.
         let consolidation_embedder = embeddings.clone_provider();
-        let consolidation =
+        let mut consolidation =
             ConsolidationEngine::new(consolidation_embedder)
                 .with_storage(storage_trait.clone());
+        if let Some(provider) = llm.as_ref().filter(|_| config.consolidation.llm_polish) {
+            consolidation.set_polisher(Box::new(CompletionPolisher::new(provider.clone())));
+        }
 
         // Prediction — shares the same Arc<StorageEngine> (B-01: no duplicate pool)
         let prediction = PredictionEngine::new(storage.clone());
@@ -176,6 +199,7 @@ impl CortexRuntime {
             cloud,
             temporal,
             multiagent: Mutex::new(multiagent),
+            llm,
             config,
         })
     }
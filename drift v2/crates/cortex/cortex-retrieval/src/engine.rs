@@ -7,7 +7,7 @@ use cortex_core::config::RetrievalConfig;
 use cortex_core::errors::CortexResult;
 use cortex_core::models::namespace::NamespaceId;
 use cortex_core::models::{CompressedMemory, RetrievalContext};
-use cortex_core::traits::{ICompressor, IMemoryStorage, IRetriever};
+use cortex_core::traits::{ICompletionProvider, ICompressor, IMemoryStorage, IRetriever};
 use tracing::{debug, info};
 
 use crate::budget::BudgetManager;
@@ -53,6 +53,17 @@ impl<'a> RetrievalEngine<'a> {
         self
     }
 
+    /// HyDE document for a context: a hypothetical answer to embed in place
+    /// of the raw focus. Written by `llm` when available, templated otherwise.
+    pub fn hypothetical_document(
+        &self,
+        context: &RetrievalContext,
+        llm: Option<&dyn ICompletionProvider>,
+    ) -> String {
+        let intent = self.intent_engine.classify(context);
+        expansion::hyde::generate_hypothetical_with(&context.focus, intent, llm)
+    }
+
     /// Run the full retrieval pipeline with an optional query embedding.
     pub fn retrieve_with_embedding(
         &self,
@@ -0,0 +1,129 @@
+//! Compare two evaluation reports over the same golden set.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::report::{EvalReport, MetricSummary};
+
+/// Change in one metric between a baseline and a candidate run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    /// `"overall"` or an intent name.
+    pub scope: String,
+    /// e.g. `"recall@5"`, `"mrr"`, `"ndcg@10"`.
+    pub metric: String,
+    pub baseline: f64,
+    pub candidate: f64,
+    pub delta: f64,
+}
+
+/// A query whose reciprocal rank changed between runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryChange {
+    pub query_id: String,
+    pub baseline_rr: f64,
+    pub candidate_rr: f64,
+}
+
+/// Metric and per-query differences between two runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportDiff {
+    pub baseline: String,
+    pub candidate: String,
+    pub metrics: Vec<MetricDelta>,
+    pub improved: Vec<QueryChange>,
+    pub regressed: Vec<QueryChange>,
+}
+
+impl ReportDiff {
+    /// Diff `candidate` against `baseline`. Intents present in only one
+    /// report are compared against an empty summary.
+    pub fn between(baseline: &EvalReport, candidate: &EvalReport) -> Self {
+        let mut metrics = summary_deltas("overall", &baseline.overall, &candidate.overall);
+        let empty = MetricSummary::default();
+        let mut intents: Vec<&String> = baseline
+            .per_intent
+            .keys()
+            .chain(candidate.per_intent.keys())
+            .collect();
+        intents.sort();
+        intents.dedup();
+        for intent in intents {
+            metrics.extend(summary_deltas(
+                intent,
+                baseline.per_intent.get(intent).unwrap_or(&empty),
+                candidate.per_intent.get(intent).unwrap_or(&empty),
+            ));
+        }
+
+        let baseline_rr: HashMap<&str, f64> = baseline
+            .queries
+            .iter()
+            .map(|q| (q.query_id.as_str(), q.reciprocal_rank))
+            .collect();
+        let mut improved = Vec::new();
+        let mut regressed = Vec::new();
+        for q in &candidate.queries {
+            let Some(&before) = baseline_rr.get(q.query_id.as_str()) else {
+                continue;
+            };
+            let change = QueryChange {
+                query_id: q.query_id.clone(),
+                baseline_rr: before,
+                candidate_rr: q.reciprocal_rank,
+            };
+            if q.reciprocal_rank > before {
+                improved.push(change);
+            } else if q.reciprocal_rank < before {
+                regressed.push(change);
+            }
+        }
+
+        Self {
+            baseline: baseline.config_name.clone(),
+            candidate: candidate.config_name.clone(),
+            metrics,
+            improved,
+            regressed,
+        }
+    }
+
+    /// Metrics that dropped by more than `tolerance`.
+    pub fn regressions(&self, tolerance: f64) -> Vec<&MetricDelta> {
+        self.metrics
+            .iter()
+            .filter(|m| m.delta < -tolerance)
+            .collect()
+    }
+}
+
+fn summary_deltas(
+    scope: &str,
+    baseline: &MetricSummary,
+    candidate: &MetricSummary,
+) -> Vec<MetricDelta> {
+    let delta = |metric: String, b: f64, c: f64| MetricDelta {
+        scope: scope.to_string(),
+        metric,
+        baseline: b,
+        candidate: c,
+        delta: c - b,
+    };
+    let before: HashMap<String, f64> = baseline.named_metrics().into_iter().collect();
+    let mut deltas: Vec<MetricDelta> = candidate
+        .named_metrics()
+        .into_iter()
+        .map(|(metric, c)| {
+            let b = before.get(&metric).copied().unwrap_or(0.0);
+            delta(metric, b, c)
+        })
+        .collect();
+    // Metrics only the baseline computed (e.g. a k that was dropped).
+    for (metric, b) in baseline.named_metrics() {
+        if !deltas.iter().any(|d| d.metric == metric) {
+            deltas.push(delta(metric, b, 0.0));
+        }
+    }
+    deltas
+}
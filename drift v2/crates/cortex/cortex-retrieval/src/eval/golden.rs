@@ -0,0 +1,98 @@
+//! Golden query sets: queries paired with the memory IDs they should surface.
+//!
+//! Stored as JSON or TOML (picked by file extension):
+//!
+//! ```json
+//! {
+//!   "name": "core-recall",
+//!   "queries": [
+//!     { "id": "q1", "query": "connection pool sizing", "intent": "recall",
+//!       "expected": ["mem-pool-1", "mem-pool-2"],
+//!       "relevance": { "mem-pool-1": 3.0 } }
+//!   ]
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use cortex_core::errors::{CortexError, CortexResult};
+use cortex_core::intent::Intent;
+use serde::{Deserialize, Serialize};
+
+/// A named set of golden queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenSet {
+    pub name: String,
+    pub queries: Vec<GoldenQuery>,
+}
+
+/// One golden query and its expected results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenQuery {
+    pub id: String,
+    pub query: String,
+    /// Intent to pin. `None` lets the classifier decide, and the report
+    /// groups the query under whatever it classified.
+    #[serde(default)]
+    pub intent: Option<Intent>,
+    #[serde(default)]
+    pub active_files: Vec<String>,
+    /// Memory IDs a correct retrieval should return.
+    pub expected: Vec<String>,
+    /// Optional graded relevance for nDCG. Expected IDs missing here count as 1.0.
+    #[serde(default)]
+    pub relevance: HashMap<String, f64>,
+}
+
+impl GoldenQuery {
+    /// Relevance grade of a memory for this query (0.0 if not expected).
+    pub fn grade(&self, memory_id: &str) -> f64 {
+        if !self.expected.iter().any(|id| id == memory_id) {
+            return 0.0;
+        }
+        self.relevance.get(memory_id).copied().unwrap_or(1.0)
+    }
+}
+
+impl GoldenSet {
+    /// Load a golden set from a `.json` or `.toml` file.
+    pub fn load(path: &Path) -> CortexResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CortexError::ConfigError(format!("read golden set {}: {e}", path.display()))
+        })?;
+        let set = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(|e| {
+                CortexError::ConfigError(format!("parse golden set {}: {e}", path.display()))
+            })?,
+            _ => serde_json::from_str(&content)?,
+        };
+        Self::validated(set)
+    }
+
+    /// Parse a golden set from a JSON string.
+    pub fn from_json(json: &str) -> CortexResult<Self> {
+        Self::validated(serde_json::from_str(json)?)
+    }
+
+    /// Reject sets that would make metrics meaningless: duplicate query IDs
+    /// or queries without expected results.
+    fn validated(set: Self) -> CortexResult<Self> {
+        let mut seen = HashSet::new();
+        for q in &set.queries {
+            if !seen.insert(q.id.as_str()) {
+                return Err(CortexError::ConfigError(format!(
+                    "golden set '{}': duplicate query id '{}'",
+                    set.name, q.id
+                )));
+            }
+            if q.expected.is_empty() {
+                return Err(CortexError::ConfigError(format!(
+                    "golden set '{}': query '{}' has no expected results",
+                    set.name, q.id
+                )));
+            }
+        }
+        Ok(set)
+    }
+}
@@ -0,0 +1,56 @@
+//! Ranking metrics: recall@k, reciprocal rank, nDCG@k.
+//!
+//! All functions take the retrieved IDs in rank order and the golden query.
+
+use super::golden::GoldenQuery;
+
+/// Fraction of expected IDs found in the top `k` results.
+pub fn recall_at_k(retrieved: &[String], query: &GoldenQuery, k: usize) -> f64 {
+    if query.expected.is_empty() {
+        return 0.0;
+    }
+    let hits = query
+        .expected
+        .iter()
+        .filter(|id| retrieved.iter().take(k).any(|r| r == *id))
+        .count();
+    hits as f64 / query.expected.len() as f64
+}
+
+/// 1 / rank of the first relevant result, or 0.0 if none was retrieved.
+pub fn reciprocal_rank(retrieved: &[String], query: &GoldenQuery) -> f64 {
+    retrieved
+        .iter()
+        .position(|id| query.grade(id) > 0.0)
+        .map_or(0.0, |pos| 1.0 / (pos + 1) as f64)
+}
+
+/// Normalized discounted cumulative gain over the top `k` results.
+pub fn ndcg_at_k(retrieved: &[String], query: &GoldenQuery, k: usize) -> f64 {
+    let dcg: f64 = retrieved
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(i, id)| discounted(query.grade(id), i))
+        .sum();
+
+    let mut ideal: Vec<f64> = query.expected.iter().map(|id| query.grade(id)).collect();
+    ideal.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    let idcg: f64 = ideal
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(i, g)| discounted(*g, i))
+        .sum();
+
+    if idcg > 0.0 {
+        dcg / idcg
+    } else {
+        0.0
+    }
+}
+
+/// Exponential gain discounted by log2 of the 1-based rank + 1.
+fn discounted(grade: f64, index: usize) -> f64 {
+    (2f64.powf(grade) - 1.0) / ((index + 2) as f64).log2()
+}
@@ -0,0 +1,150 @@
+//! Retrieval quality evaluation against golden query sets.
+//!
+//! Runs `RetrievalEngine` over a `GoldenSet` under one or more
+//! `EvalConfig`s and reports recall@k, MRR and nDCG@k, overall and per
+//! intent. `ReportDiff` compares two runs so a config change can be gated
+//! on "no metric dropped by more than X" in CI.
+
+pub mod diff;
+pub mod golden;
+pub mod metrics;
+pub mod report;
+
+use std::collections::BTreeMap;
+
+use cortex_core::config::RetrievalConfig;
+use cortex_core::errors::CortexResult;
+use cortex_core::intent::Intent;
+use cortex_core::models::RetrievalContext;
+use cortex_core::traits::{ICompressor, IEmbeddingProvider, IMemoryStorage};
+use tracing::{info, warn};
+
+use crate::engine::RetrievalEngine;
+use crate::intent::IntentEngine;
+
+pub use diff::{MetricDelta, QueryChange, ReportDiff};
+pub use golden::{GoldenQuery, GoldenSet};
+pub use report::{EvalReport, MetricSummary, QueryResult};
+
+/// Cutoffs used when none are configured.
+pub const DEFAULT_KS: &[usize] = &[1, 5, 10];
+
+/// A named retrieval configuration to evaluate.
+#[derive(Debug, Clone)]
+pub struct EvalConfig {
+    pub name: String,
+    pub retrieval: RetrievalConfig,
+    /// Token budget per query. Small budgets truncate results and lower recall.
+    pub budget: usize,
+}
+
+impl EvalConfig {
+    pub fn new(name: impl Into<String>, retrieval: RetrievalConfig) -> Self {
+        let budget = retrieval.default_budget;
+        Self {
+            name: name.into(),
+            retrieval,
+            budget,
+        }
+    }
+}
+
+/// Runs golden sets through the retrieval pipeline.
+pub struct Evaluator<'a> {
+    storage: &'a dyn IMemoryStorage,
+    compressor: &'a dyn ICompressor,
+    embedder: Option<&'a dyn IEmbeddingProvider>,
+    ks: Vec<usize>,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(storage: &'a dyn IMemoryStorage, compressor: &'a dyn ICompressor) -> Self {
+        Self {
+            storage,
+            compressor,
+            embedder: None,
+            ks: DEFAULT_KS.to_vec(),
+        }
+    }
+
+    /// Embed queries so vector search participates. Without an embedder
+    /// the run is FTS5 + entity expansion only.
+    pub fn with_embedder(mut self, embedder: &'a dyn IEmbeddingProvider) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Override the recall/nDCG cutoffs.
+    pub fn with_ks(mut self, ks: &[usize]) -> Self {
+        self.ks = ks.to_vec();
+        self
+    }
+
+    /// Evaluate one configuration.
+    pub fn evaluate(&self, set: &GoldenSet, config: &EvalConfig) -> CortexResult<EvalReport> {
+        let engine = RetrievalEngine::new(self.storage, self.compressor, config.retrieval.clone());
+        let intents = IntentEngine::new();
+
+        let mut results = Vec::with_capacity(set.queries.len());
+        for query in &set.queries {
+            let context = RetrievalContext {
+                focus: query.query.clone(),
+                intent: query.intent,
+                active_files: query.active_files.clone(),
+                budget: config.budget,
+                sent_ids: Vec::new(),
+            };
+            let embedding = self.embed(&query.query);
+            let retrieved: Vec<String> = engine
+                .retrieve_with_embedding(&context, config.budget, embedding.as_deref())?
+                .into_iter()
+                .map(|m| m.memory_id)
+                .collect();
+            results.push(self.score(query, intents.classify(&context), retrieved));
+        }
+
+        let report = EvalReport::new(&set.name, &config.name, results);
+        info!(
+            golden_set = %set.name,
+            config = %config.name,
+            queries = report.overall.queries,
+            mrr = report.overall.mrr,
+            "retrieval evaluation complete"
+        );
+        Ok(report)
+    }
+
+    /// Evaluate several configurations over the same golden set.
+    pub fn evaluate_all(
+        &self,
+        set: &GoldenSet,
+        configs: &[EvalConfig],
+    ) -> CortexResult<Vec<EvalReport>> {
+        configs.iter().map(|c| self.evaluate(set, c)).collect()
+    }
+
+    fn embed(&self, text: &str) -> Option<Vec<f32>> {
+        let embedder = self.embedder?;
+        match embedder.embed(text) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                warn!(error = %e, "query embedding failed, evaluating text-only");
+                None
+            }
+        }
+    }
+
+    fn score(&self, query: &GoldenQuery, intent: Intent, retrieved: Vec<String>) -> QueryResult {
+        let per_k = |f: &dyn Fn(usize) -> f64| -> BTreeMap<usize, f64> {
+            self.ks.iter().map(|&k| (k, f(k))).collect()
+        };
+        QueryResult {
+            query_id: query.id.clone(),
+            intent,
+            recall_at_k: per_k(&|k| metrics::recall_at_k(&retrieved, query, k)),
+            reciprocal_rank: metrics::reciprocal_rank(&retrieved, query),
+            ndcg_at_k: per_k(&|k| metrics::ndcg_at_k(&retrieved, query, k)),
+            retrieved,
+        }
+    }
+}
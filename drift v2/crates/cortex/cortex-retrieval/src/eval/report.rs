@@ -0,0 +1,107 @@
+//! Per-query results and aggregated summaries for one evaluation run.
+
+use std::collections::BTreeMap;
+
+use cortex_core::intent::Intent;
+use serde::{Deserialize, Serialize};
+
+/// Metrics for a single golden query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub query_id: String,
+    /// Intent the query ran under (pinned or classified).
+    pub intent: Intent,
+    /// Retrieved memory IDs in rank order.
+    pub retrieved: Vec<String>,
+    pub recall_at_k: BTreeMap<usize, f64>,
+    pub reciprocal_rank: f64,
+    pub ndcg_at_k: BTreeMap<usize, f64>,
+}
+
+/// Mean metrics over a group of queries.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub queries: usize,
+    pub recall_at_k: BTreeMap<usize, f64>,
+    pub mrr: f64,
+    pub ndcg_at_k: BTreeMap<usize, f64>,
+}
+
+impl MetricSummary {
+    /// Average the given query results.
+    pub fn from_results<'a>(results: impl IntoIterator<Item = &'a QueryResult>) -> Self {
+        let mut summary = Self::default();
+        for r in results {
+            summary.queries += 1;
+            summary.mrr += r.reciprocal_rank;
+            for (k, v) in &r.recall_at_k {
+                *summary.recall_at_k.entry(*k).or_default() += v;
+            }
+            for (k, v) in &r.ndcg_at_k {
+                *summary.ndcg_at_k.entry(*k).or_default() += v;
+            }
+        }
+        if summary.queries > 0 {
+            let n = summary.queries as f64;
+            summary.mrr /= n;
+            summary.recall_at_k.values_mut().for_each(|v| *v /= n);
+            summary.ndcg_at_k.values_mut().for_each(|v| *v /= n);
+        }
+        summary
+    }
+
+    /// Flatten into `(metric name, value)` pairs, e.g. `("recall@5", 0.8)`.
+    pub fn named_metrics(&self) -> Vec<(String, f64)> {
+        let mut out: Vec<(String, f64)> = self
+            .recall_at_k
+            .iter()
+            .map(|(k, v)| (format!("recall@{k}"), *v))
+            .collect();
+        out.push(("mrr".to_string(), self.mrr));
+        out.extend(
+            self.ndcg_at_k
+                .iter()
+                .map(|(k, v)| (format!("ndcg@{k}"), *v)),
+        );
+        out
+    }
+}
+
+/// Full result of running one configuration over one golden set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalReport {
+    pub golden_set: String,
+    pub config_name: String,
+    pub overall: MetricSummary,
+    /// Summary per intent, keyed by the intent's snake_case name.
+    pub per_intent: BTreeMap<String, MetricSummary>,
+    pub queries: Vec<QueryResult>,
+}
+
+impl EvalReport {
+    pub(crate) fn new(golden_set: &str, config_name: &str, queries: Vec<QueryResult>) -> Self {
+        let overall = MetricSummary::from_results(&queries);
+        let mut grouped: BTreeMap<String, Vec<&QueryResult>> = BTreeMap::new();
+        for q in &queries {
+            grouped.entry(intent_name(q.intent)).or_default().push(q);
+        }
+        let per_intent = grouped
+            .into_iter()
+            .map(|(intent, results)| (intent, MetricSummary::from_results(results)))
+            .collect();
+        Self {
+            golden_set: golden_set.to_string(),
+            config_name: config_name.to_string(),
+            overall,
+            per_intent,
+            queries,
+        }
+    }
+}
+
+fn intent_name(intent: Intent) -> String {
+    serde_json::to_value(intent)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("{intent:?}"))
+}
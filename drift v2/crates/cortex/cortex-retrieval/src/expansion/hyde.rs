@@ -1,11 +1,16 @@
 //! Hypothetical Document Embedding (HyDE).
 //!
 //! Generates a hypothetical answer to the query, then embeds that answer
-//! for improved semantic search. Since we don't have an LLM in-process,
-//! this module creates a structured hypothetical document from the query
-//! context that captures the expected shape of a relevant memory.
+//! for improved semantic search. When a completion provider is configured
+//! the hypothetical answer is written by the model; otherwise (or when the
+//! provider fails) this module creates a structured hypothetical document
+//! from the query context that captures the expected shape of a relevant memory.
 
 use cortex_core::intent::Intent;
+use cortex_core::traits::{CompletionRequest, ICompletionProvider};
+
+/// Token cap for LLM-written hypothetical documents.
+const HYDE_MAX_TOKENS: usize = 160;
 
 /// Generate a hypothetical document from a query and detected intent.
 ///
@@ -31,3 +36,46 @@ pub fn generate_hypothetical(query: &str, intent: Intent) -> String {
 
     format!("{intent_prefix} {query}. This is relevant because it directly addresses the query and provides actionable context.")
 }
+
+/// Generate a hypothetical document, preferring the completion provider when
+/// one is available and falling back to the template otherwise.
+pub fn generate_hypothetical_with(
+    query: &str,
+    intent: Intent,
+    llm: Option<&dyn ICompletionProvider>,
+) -> String {
+    llm.and_then(|provider| generate_with_llm(query, intent, provider))
+        .unwrap_or_else(|| generate_hypothetical(query, intent))
+}
+
+/// Ask the completion provider for a short passage answering the query.
+///
+/// Returns `None` if the provider is unavailable, errors, or answers with
+/// nothing usable.
+pub fn generate_with_llm(
+    query: &str,
+    intent: Intent,
+    provider: &dyn ICompletionProvider,
+) -> Option<String> {
+    if !provider.is_available() {
+        return None;
+    }
+    let request = CompletionRequest::new(
+        format!("Task intent: {intent:?}\nQuestion: {query}\n\nWrite the note that answers it."),
+        HYDE_MAX_TOKENS,
+    )
+    .with_system(
+        "You write short engineering memory notes (2-3 sentences) that would \
+         answer a developer's question about their codebase. Answer directly.",
+    );
+    match provider.complete(&request) {
+        Ok(completion) => {
+            let text = completion.text.trim();
+            (!text.is_empty()).then(|| text.to_string())
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "HyDE completion failed, using template");
+            None
+        }
+    }
+}
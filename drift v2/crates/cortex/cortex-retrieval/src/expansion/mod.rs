@@ -4,6 +4,7 @@ pub mod hyde;
 pub mod synonym_expander;
 
 use cortex_core::intent::Intent;
+use cortex_core::traits::ICompletionProvider;
 
 /// Expanded query with both text and hypothetical document.
 #[derive(Debug, Clone)]
@@ -16,8 +17,18 @@ pub struct ExpandedQuery {
 
 /// Expand a query using synonym expansion and HyDE.
 pub fn expand_query(query: &str, intent: Intent) -> ExpandedQuery {
+    expand_query_with(query, intent, None)
+}
+
+/// Expand a query, letting the completion provider (if any) write the
+/// hypothetical document.
+pub fn expand_query_with(
+    query: &str,
+    intent: Intent,
+    llm: Option<&dyn ICompletionProvider>,
+) -> ExpandedQuery {
     let expanded_text = synonym_expander::expand(query);
-    let hypothetical_doc = hyde::generate_hypothetical(query, intent);
+    let hypothetical_doc = hyde::generate_hypothetical_with(query, intent, llm);
 
     ExpandedQuery {
         expanded_text,
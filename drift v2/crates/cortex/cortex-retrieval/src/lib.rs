@@ -30,13 +30,18 @@
 //! │   ├── Provenance ([drift:*] tags)
 //! │   ├── Feedback (confidence adjustment)
 //! │   └── Validation (pre-generation checks)
-//! └── WhySynthesizer
-//!     ├── Synthesizer (8-step pipeline)
-//!     └── Aggregator (warning dedup + severity)
+//! ├── WhySynthesizer
+//! │   ├── Synthesizer (8-step pipeline)
+//! │   └── Aggregator (warning dedup + severity)
+//! └── Evaluator
+//!     ├── GoldenSet (query → expected memory IDs)
+//!     ├── Metrics (recall@k, MRR, nDCG@k)
+//!     └── ReportDiff (per-metric, per-intent regressions)
 //! ```
 
 pub mod budget;
 pub mod engine;
+pub mod eval;
 pub mod expansion;
 pub mod generation;
 pub mod intent;
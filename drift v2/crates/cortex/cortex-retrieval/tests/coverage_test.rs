@@ -90,6 +90,64 @@ fn hyde_generic_intent() {
     assert!(doc.contains("Context:"));
 }
 
+/// Completion provider returning a fixed answer, or failing when `answer` is None.
+struct FixedLlm {
+    answer: Option<&'static str>,
+}
+
+impl cortex_core::traits::ICompletionProvider for FixedLlm {
+    fn complete(
+        &self,
+        _request: &cortex_core::traits::CompletionRequest,
+    ) -> cortex_core::errors::CortexResult<cortex_core::traits::Completion> {
+        let text = self.answer.ok_or_else(|| {
+            cortex_core::errors::CompletionError::ProviderUnavailable {
+                provider: "fixed".to_string(),
+            }
+        })?;
+        Ok(cortex_core::traits::Completion {
+            text: text.to_string(),
+            prompt_tokens: 0,
+            completion_tokens: 0,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "fixed"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn hyde_prefers_llm_document() {
+    let llm = FixedLlm {
+        answer: Some("Null checks were added to the session loader."),
+    };
+    let doc = cortex_retrieval::expansion::hyde::generate_hypothetical_with(
+        "null pointer",
+        Intent::FixBug,
+        Some(&llm),
+    );
+    assert_eq!(doc, "Null checks were added to the session loader.");
+}
+
+#[test]
+fn hyde_falls_back_to_template_on_llm_error() {
+    let llm = FixedLlm { answer: None };
+    let doc = cortex_retrieval::expansion::hyde::generate_hypothetical_with(
+        "null pointer",
+        Intent::FixBug,
+        Some(&llm),
+    );
+    assert_eq!(
+        doc,
+        cortex_retrieval::expansion::hyde::generate_hypothetical("null pointer", Intent::FixBug)
+    );
+}
+
 // ─── Query Expansion (combined) ──────────────────────────────────────────────
 
 #[test]
@@ -0,0 +1,219 @@
+//! Retrieval evaluation harness: metric math, golden set loading, and a
+//! baseline-vs-candidate run over a seeded store.
+
+use std::collections::HashMap;
+
+use cortex_compression::CompressionEngine;
+use cortex_core::config::RetrievalConfig;
+use cortex_core::memory::*;
+use cortex_core::traits::IMemoryStorage;
+use cortex_retrieval::eval::{metrics, EvalConfig, Evaluator, GoldenQuery, GoldenSet, ReportDiff};
+use cortex_storage::StorageEngine;
+
+fn golden_query(expected: &[&str]) -> GoldenQuery {
+    GoldenQuery {
+        id: "q".to_string(),
+        query: "q".to_string(),
+        intent: None,
+        active_files: vec![],
+        expected: expected.iter().map(|s| s.to_string()).collect(),
+        relevance: HashMap::new(),
+    }
+}
+
+fn ids(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+fn seed(storage: &StorageEngine, id: &str, summary: &str, memory_type: MemoryType) {
+    let content = TypedContent::Core(cortex_core::memory::types::CoreContent {
+        project_name: String::new(),
+        description: summary.to_string(),
+        metadata: serde_json::Value::Null,
+    });
+    let now = chrono::Utc::now();
+    storage
+        .create(&BaseMemory {
+            id: id.to_string(),
+            memory_type,
+            content: content.clone(),
+            summary: summary.to_string(),
+            transaction_time: now,
+            valid_time: now,
+            valid_until: None,
+            confidence: Confidence::new(0.8),
+            importance: Importance::Normal,
+            last_accessed: now,
+            access_count: 1,
+            linked_patterns: vec![],
+            linked_constraints: vec![],
+            linked_files: vec![],
+            linked_functions: vec![],
+            tags: vec![],
+            archived: false,
+            superseded_by: None,
+            supersedes: None,
+            namespace: Default::default(),
+            source_agent: Default::default(),
+            content_hash: BaseMemory::compute_content_hash(&content).unwrap(),
+        })
+        .unwrap();
+}
+
+fn seeded_storage() -> StorageEngine {
+    let storage = StorageEngine::open_in_memory().unwrap();
+    seed(
+        &storage,
+        "mem-pool",
+        "connection pool sizing is 4 readers",
+        MemoryType::Semantic,
+    );
+    seed(
+        &storage,
+        "mem-pool-timeout",
+        "pool checkout timeout during sizing spikes",
+        MemoryType::Tribal,
+    );
+    seed(
+        &storage,
+        "mem-retry",
+        "retry storm bug fixed with jittered backoff",
+        MemoryType::Incident,
+    );
+    seed(
+        &storage,
+        "mem-auth",
+        "authentication token rotation every 24h",
+        MemoryType::DecisionContext,
+    );
+    seed(
+        &storage,
+        "mem-noise",
+        "unrelated note about release notes formatting",
+        MemoryType::Semantic,
+    );
+    storage
+}
+
+#[test]
+fn recall_counts_expected_hits_within_cutoff() {
+    let q = golden_query(&["a", "b"]);
+    let retrieved = ids(&["x", "a", "y", "b"]);
+    assert_eq!(metrics::recall_at_k(&retrieved, &q, 1), 0.0);
+    assert_eq!(metrics::recall_at_k(&retrieved, &q, 2), 0.5);
+    assert_eq!(metrics::recall_at_k(&retrieved, &q, 10), 1.0);
+}
+
+#[test]
+fn reciprocal_rank_uses_first_relevant_hit() {
+    let q = golden_query(&["b"]);
+    assert_eq!(metrics::reciprocal_rank(&ids(&["a", "b"]), &q), 0.5);
+    assert_eq!(metrics::reciprocal_rank(&ids(&["a", "c"]), &q), 0.0);
+}
+
+#[test]
+fn ndcg_is_one_for_ideal_order_and_respects_grades() {
+    let mut q = golden_query(&["a", "b"]);
+    q.relevance.insert("a".to_string(), 3.0);
+    assert!((metrics::ndcg_at_k(&ids(&["a", "b"]), &q, 5) - 1.0).abs() < 1e-9);
+    let swapped = metrics::ndcg_at_k(&ids(&["b", "a"]), &q, 5);
+    assert!(swapped < 1.0 && swapped > 0.0);
+    assert_eq!(metrics::ndcg_at_k(&ids(&["x"]), &q, 5), 0.0);
+}
+
+#[test]
+fn golden_set_rejects_duplicates_and_empty_expectations() {
+    let dup = r#"{"name":"s","queries":[
+        {"id":"q","query":"a","expected":["m"]},
+        {"id":"q","query":"b","expected":["m"]}]}"#;
+    assert!(GoldenSet::from_json(dup).is_err());
+    let empty = r#"{"name":"s","queries":[{"id":"q","query":"a","expected":[]}]}"#;
+    assert!(GoldenSet::from_json(empty).is_err());
+}
+
+#[test]
+fn golden_fixture_loads() {
+    let set = GoldenSet::load(&test_fixtures::fixture_path(
+        "eval/retrieval_golden_set.json",
+    ))
+    .unwrap();
+    assert_eq!(set.queries.len(), 3);
+    assert_eq!(set.queries[0].grade("mem-pool"), 2.0);
+    assert_eq!(set.queries[0].grade("mem-pool-timeout"), 1.0);
+    assert_eq!(set.queries[0].grade("mem-noise"), 0.0);
+}
+
+#[test]
+fn evaluation_reports_overall_and_per_intent() {
+    let storage = seeded_storage();
+    let compressor = CompressionEngine::new();
+    let set = GoldenSet::load(&test_fixtures::fixture_path(
+        "eval/retrieval_golden_set.json",
+    ))
+    .unwrap();
+
+    let report = Evaluator::new(&storage, &compressor)
+        .evaluate(
+            &set,
+            &EvalConfig::new("default", RetrievalConfig::default()),
+        )
+        .unwrap();
+
+    assert_eq!(report.overall.queries, 3);
+    assert_eq!(report.queries.len(), 3);
+    assert!(report.per_intent.contains_key("recall"));
+    assert!(report.per_intent.contains_key("fix_bug"));
+    assert!(report.overall.mrr > 0.0, "FTS5 should find keyword matches");
+    for k in [1, 5, 10] {
+        assert!(report.overall.recall_at_k.contains_key(&k));
+        assert!(report.overall.ndcg_at_k.contains_key(&k));
+    }
+    let retry = report
+        .queries
+        .iter()
+        .find(|q| q.query_id == "retry-bug")
+        .unwrap();
+    assert_eq!(
+        retry.retrieved.first().map(String::as_str),
+        Some("mem-retry")
+    );
+}
+
+#[test]
+fn diff_flags_regressions_between_configs() {
+    let storage = seeded_storage();
+    let compressor = CompressionEngine::new();
+    let set = GoldenSet::load(&test_fixtures::fixture_path(
+        "eval/retrieval_golden_set.json",
+    ))
+    .unwrap();
+
+    let baseline = EvalConfig::new("baseline", RetrievalConfig::default());
+    let mut starved = EvalConfig::new("starved", RetrievalConfig::default());
+    starved.budget = 0;
+
+    let reports = Evaluator::new(&storage, &compressor)
+        .with_ks(&[1, 3])
+        .evaluate_all(&set, &[baseline.clone(), starved])
+        .unwrap();
+
+    let same = ReportDiff::between(&reports[0], &reports[0]);
+    assert!(same.regressions(0.0).is_empty());
+    assert!(same.improved.is_empty() && same.regressed.is_empty());
+
+    let diff = ReportDiff::between(&reports[0], &reports[1]);
+    assert_eq!(diff.baseline, "baseline");
+    assert!(diff
+        .regressions(0.01)
+        .iter()
+        .any(|m| m.scope == "overall" && m.metric == "mrr"));
+    assert_eq!(
+        diff.regressed.len(),
+        reports[0]
+            .queries
+            .iter()
+            .filter(|q| q.reciprocal_rank > 0.0)
+            .count()
+    );
+    assert!(diff.metrics.iter().any(|m| m.metric == "recall@3"));
+}
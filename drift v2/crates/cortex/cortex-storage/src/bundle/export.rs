@@ -0,0 +1,183 @@
+//! Export memories and their graph edges into a bundle.
+
+use std::collections::{BTreeSet, HashSet};
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use cortex_core::errors::CortexResult;
+use cortex_core::memory::MemoryType;
+
+use super::format::{
+    BundleHeader, BundleRecord, EmbeddingModelInfo, EmbeddingRecord, BUNDLE_FORMAT_VERSION,
+};
+use crate::queries::{causal_ops, memory_crud, relationship_ops, vector_search};
+use crate::to_storage_err;
+
+/// Which memories go into a bundle. Empty `memory_types` means all types.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportFilter {
+    /// Namespace URI (e.g. `team://platform/`). `None` exports every namespace.
+    pub namespace: Option<String>,
+    pub memory_types: Vec<MemoryType>,
+    /// Inclusive lower bound on `transaction_time`.
+    pub from: Option<DateTime<Utc>>,
+    /// Inclusive upper bound on `transaction_time`.
+    pub to: Option<DateTime<Utc>>,
+    pub include_archived: bool,
+    pub include_embeddings: bool,
+}
+
+/// Record counts written to a bundle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportStats {
+    pub memories: usize,
+    pub relationships: usize,
+    pub causal_edges: usize,
+    pub embeddings: usize,
+}
+
+/// Export every memory matching `filter` into `writer` as a JSONL bundle.
+///
+/// Relationships and causal edges are only exported when both endpoints are
+/// in the bundle, so an import never creates dangling edges.
+pub fn export_bundle<W: Write>(
+    conn: &Connection,
+    filter: &ExportFilter,
+    source_label: Option<&str>,
+    writer: &mut W,
+) -> CortexResult<ExportStats> {
+    let ids = select_ids(conn, filter)?;
+    let id_set: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    let memories = memory_crud::bulk_get(conn, &ids)?;
+
+    let mut relationships = Vec::new();
+    let mut causal_edges = Vec::new();
+    let mut embeddings = Vec::new();
+    let mut models = BTreeSet::new();
+    for id in &ids {
+        relationships.extend(
+            relationship_ops::get_relationships(conn, id, None)?
+                .into_iter()
+                .filter(|e| e.source_id == *id && id_set.contains(e.target_id.as_str())),
+        );
+        causal_edges.extend(
+            causal_ops::get_edges(conn, id)?
+                .into_iter()
+                .filter(|e| e.source_id == *id && id_set.contains(e.target_id.as_str())),
+        );
+        if filter.include_embeddings {
+            if let Some((content_hash, model_name, vector)) =
+                vector_search::get_embedding_for_memory(conn, id)?
+            {
+                models.insert((model_name.clone(), vector.len()));
+                embeddings.push(EmbeddingRecord {
+                    memory_id: id.clone(),
+                    content_hash,
+                    model_name,
+                    vector,
+                });
+            }
+        }
+    }
+
+    let stats = ExportStats {
+        memories: memories.len(),
+        relationships: relationships.len(),
+        causal_edges: causal_edges.len(),
+        embeddings: embeddings.len(),
+    };
+    let header = BundleHeader {
+        format_version: BUNDLE_FORMAT_VERSION,
+        bundle_id: uuid::Uuid::new_v4().to_string(),
+        created_at: Utc::now(),
+        source_label: source_label.map(str::to_string),
+        filter: filter.clone(),
+        embedding_models: models
+            .into_iter()
+            .map(|(model_name, dimensions)| EmbeddingModelInfo {
+                model_name,
+                dimensions,
+            })
+            .collect(),
+        counts: stats,
+    };
+
+    write_record(writer, &BundleRecord::Header(header))?;
+    for memory in memories {
+        write_record(writer, &BundleRecord::Memory(Box::new(memory)))?;
+    }
+    for edge in relationships {
+        write_record(writer, &BundleRecord::Relationship(edge))?;
+    }
+    for edge in causal_edges {
+        write_record(writer, &BundleRecord::CausalEdge(edge))?;
+    }
+    for embedding in embeddings {
+        write_record(writer, &BundleRecord::Embedding(embedding))?;
+    }
+    writer
+        .flush()
+        .map_err(|e| to_storage_err(format!("flush bundle: {e}")))?;
+
+    Ok(stats)
+}
+
+fn write_record<W: Write>(writer: &mut W, record: &BundleRecord) -> CortexResult<()> {
+    serde_json::to_writer(&mut *writer, record)?;
+    writer
+        .write_all(b"\n")
+        .map_err(|e| to_storage_err(format!("write bundle: {e}")))
+}
+
+/// Memory IDs matching the filter, oldest first so imports replay in order.
+fn select_ids(conn: &Connection, filter: &ExportFilter) -> CortexResult<Vec<String>> {
+    let mut clauses: Vec<String> = Vec::new();
+    let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+
+    if !filter.include_archived {
+        clauses.push("archived = 0".to_string());
+    }
+    if let Some(ns) = &filter.namespace {
+        params.push(Box::new(ns.clone()));
+        clauses.push(format!("namespace_id = ?{}", params.len()));
+    }
+    if let Some(from) = filter.from {
+        params.push(Box::new(from.to_rfc3339()));
+        clauses.push(format!("transaction_time >= ?{}", params.len()));
+    }
+    if let Some(to) = filter.to {
+        params.push(Box::new(to.to_rfc3339()));
+        clauses.push(format!("transaction_time <= ?{}", params.len()));
+    }
+    if !filter.memory_types.is_empty() {
+        let mut placeholders = Vec::with_capacity(filter.memory_types.len());
+        for memory_type in &filter.memory_types {
+            let type_str =
+                serde_json::to_string(memory_type).map_err(|e| to_storage_err(e.to_string()))?;
+            params.push(Box::new(type_str.trim_matches('"').to_string()));
+            placeholders.push(format!("?{}", params.len()));
+        }
+        clauses.push(format!("memory_type IN ({})", placeholders.join(", ")));
+    }
+
+    let where_clause = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", clauses.join(" AND "))
+    };
+    let sql = format!("SELECT id FROM memories{where_clause} ORDER BY transaction_time, id");
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| to_storage_err(e.to_string()))?;
+    let params_refs: Vec<&dyn rusqlite::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| row.get::<_, String>(0))
+        .map_err(|e| to_storage_err(e.to_string()))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| to_storage_err(e.to_string()))
+}
@@ -0,0 +1,58 @@
+//! Bundle record types. One JSON object per line, tagged by `kind`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use cortex_core::memory::{BaseMemory, RelationshipEdge};
+use cortex_core::traits::CausalEdge;
+
+use super::export::{ExportFilter, ExportStats};
+
+/// Current bundle format version. Readers reject bundles from the future.
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// A single line of a bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BundleRecord {
+    Header(BundleHeader),
+    Memory(Box<BaseMemory>),
+    Relationship(RelationshipEdge),
+    CausalEdge(CausalEdge),
+    Embedding(EmbeddingRecord),
+}
+
+/// First record of every bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleHeader {
+    pub format_version: u32,
+    /// Unique id for this export, recorded in import provenance.
+    pub bundle_id: String,
+    pub created_at: DateTime<Utc>,
+    /// Free-form origin label (team, repo, machine).
+    #[serde(default)]
+    pub source_label: Option<String>,
+    /// The filter the bundle was exported with.
+    pub filter: ExportFilter,
+    /// Embedding models present in the bundle, so importers can skip
+    /// vectors from a model they don't run.
+    #[serde(default)]
+    pub embedding_models: Vec<EmbeddingModelInfo>,
+    pub counts: ExportStats,
+}
+
+/// Model metadata for the embeddings carried in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingModelInfo {
+    pub model_name: String,
+    pub dimensions: usize,
+}
+
+/// A stored embedding for one memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingRecord {
+    pub memory_id: String,
+    pub content_hash: String,
+    pub model_name: String,
+    pub vector: Vec<f32>,
+}
@@ -0,0 +1,461 @@
+//! Import a bundle with ID remapping, conflict resolution and provenance.
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use cortex_core::errors::CortexResult;
+use cortex_core::memory::{BaseMemory, RelationshipEdge};
+use cortex_core::models::namespace::NamespaceId;
+use cortex_core::traits::CausalEdge;
+
+use super::format::{BundleHeader, BundleRecord, EmbeddingRecord, BUNDLE_FORMAT_VERSION};
+use crate::queries::multiagent_ops::{self, InsertAgentParams, InsertProvenanceHopParams};
+use crate::queries::{causal_ops, memory_crud, relationship_ops, vector_search};
+use crate::to_storage_err;
+use crate::versioning::VersionTracker;
+
+/// What to do when an incoming memory collides with a local one
+/// (same ID under `IdStrategy::Preserve`, or same content hash).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep the local memory; edges pointing at the incoming one are
+    /// redirected to it.
+    #[default]
+    Skip,
+    /// Replace the local memory's fields with the incoming ones.
+    Overwrite,
+    /// Field-level merge with `MemoryCRDT` semantics: last-writer-wins on
+    /// content by `transaction_time`, max on confidence/access, union on
+    /// tags and links.
+    Merge,
+}
+
+/// How incoming memory IDs map onto local IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// Keep bundle IDs. Collisions go through the conflict policy.
+    Preserve,
+    /// Assign fresh UUIDs to every new memory; edges are rewritten.
+    #[default]
+    Remap,
+}
+
+/// Options controlling an import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportOptions {
+    pub conflict_policy: ConflictPolicy,
+    pub id_strategy: IdStrategy,
+    /// Move imported memories into this namespace instead of the bundle's.
+    pub target_namespace: Option<NamespaceId>,
+    pub import_embeddings: bool,
+    /// Only import embeddings produced by this model. `None` accepts any.
+    pub embedding_model: Option<String>,
+    /// Agent recorded in the provenance chain of every imported memory.
+    pub provenance_agent: String,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            conflict_policy: ConflictPolicy::default(),
+            id_strategy: IdStrategy::default(),
+            target_namespace: None,
+            import_embeddings: true,
+            embedding_model: None,
+            provenance_agent: "bundle-import".to_string(),
+        }
+    }
+}
+
+/// Outcome of an import.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub bundle_id: String,
+    pub inserted: usize,
+    pub overwritten: usize,
+    pub merged: usize,
+    pub skipped: usize,
+    pub relationships: usize,
+    pub causal_edges: usize,
+    pub embeddings: usize,
+    /// Embeddings dropped because of a model mismatch or a skipped memory.
+    pub embeddings_skipped: usize,
+    /// Edges dropped because an endpoint wasn't imported.
+    pub dangling_edges: usize,
+    /// Bundle memory ID → local memory ID.
+    pub id_map: HashMap<String, String>,
+}
+
+/// Parsed bundle contents, validated before anything is written.
+struct ParsedBundle {
+    header: BundleHeader,
+    memories: Vec<BaseMemory>,
+    relationships: Vec<RelationshipEdge>,
+    causal_edges: Vec<CausalEdge>,
+    embeddings: Vec<EmbeddingRecord>,
+}
+
+/// How a single memory landed locally.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Inserted,
+    Overwritten,
+    Merged,
+    Skipped,
+}
+
+impl Outcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Inserted => "insert",
+            Self::Overwritten => "overwrite",
+            Self::Merged => "merge",
+            Self::Skipped => "skip",
+        }
+    }
+}
+
+/// Import a JSONL bundle.
+///
+/// The whole stream is parsed and version-checked before the first write,
+/// so a truncated or foreign file leaves the database untouched. The writes
+/// then run in one SAVEPOINT: a record that fails to land rolls back every
+/// memory, edge and embedding imported before it.
+pub fn import_bundle<R: BufRead>(
+    conn: &Connection,
+    reader: R,
+    options: &ImportOptions,
+) -> CortexResult<ImportReport> {
+    let bundle = parse_bundle(reader)?;
+
+    conn.execute_batch("SAVEPOINT import_bundle")
+        .map_err(|e| to_storage_err(format!("import_bundle savepoint: {e}")))?;
+    let report = match write_bundle(conn, &bundle, options) {
+        Ok(report) => {
+            conn.execute_batch("RELEASE import_bundle")
+                .map_err(|e| to_storage_err(format!("import_bundle release: {e}")))?;
+            report
+        }
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK TO import_bundle");
+            let _ = conn.execute_batch("RELEASE import_bundle");
+            return Err(e);
+        }
+    };
+
+    tracing::info!(
+        bundle_id = %report.bundle_id,
+        inserted = report.inserted,
+        overwritten = report.overwritten,
+        merged = report.merged,
+        skipped = report.skipped,
+        "bundle imported"
+    );
+    Ok(report)
+}
+
+/// Inner import logic; runs inside `import_bundle`'s savepoint.
+fn write_bundle(
+    conn: &Connection,
+    bundle: &ParsedBundle,
+    options: &ImportOptions,
+) -> CortexResult<ImportReport> {
+    let mut report = ImportReport {
+        bundle_id: bundle.header.bundle_id.clone(),
+        ..Default::default()
+    };
+
+    ensure_provenance_agent(conn, &options.provenance_agent)?;
+
+    let mut outcomes: HashMap<String, Outcome> = HashMap::new();
+    for incoming in &bundle.memories {
+        let (local_id, outcome) = import_memory(conn, incoming, options)?;
+        match outcome {
+            Outcome::Inserted => report.inserted += 1,
+            Outcome::Overwritten => report.overwritten += 1,
+            Outcome::Merged => report.merged += 1,
+            Outcome::Skipped => report.skipped += 1,
+        }
+        if outcome != Outcome::Skipped {
+            record_provenance(conn, &local_id, incoming, &bundle.header, outcome, options)?;
+        }
+        outcomes.insert(local_id.clone(), outcome);
+        report.id_map.insert(incoming.id.clone(), local_id);
+    }
+
+    for edge in &bundle.relationships {
+        let (Some(source), Some(target)) = (
+            report.id_map.get(&edge.source_id),
+            report.id_map.get(&edge.target_id),
+        ) else {
+            report.dangling_edges += 1;
+            continue;
+        };
+        let mut edge = edge.clone();
+        edge.source_id = source.clone();
+        edge.target_id = target.clone();
+        relationship_ops::add_relationship(conn, &edge)?;
+        report.relationships += 1;
+    }
+
+    for edge in &bundle.causal_edges {
+        let (Some(source), Some(target)) = (
+            report.id_map.get(&edge.source_id),
+            report.id_map.get(&edge.target_id),
+        ) else {
+            report.dangling_edges += 1;
+            continue;
+        };
+        let exists = causal_ops::get_edges(conn, source)?
+            .iter()
+            .any(|e| e.source_id == *source && e.target_id == *target);
+        if exists && options.conflict_policy == ConflictPolicy::Skip {
+            continue;
+        }
+        // Re-adding an existing edge would duplicate its evidence rows.
+        if exists {
+            causal_ops::remove_edge(conn, source, target)?;
+        }
+        let mut edge = edge.clone();
+        edge.source_id = source.clone();
+        edge.target_id = target.clone();
+        causal_ops::add_edge(conn, &edge)?;
+        report.causal_edges += 1;
+    }
+
+    for embedding in &bundle.embeddings {
+        let local_id = report.id_map.get(&embedding.memory_id);
+        let writable = local_id
+            .and_then(|id| outcomes.get(id))
+            .is_some_and(|o| matches!(o, Outcome::Inserted | Outcome::Overwritten));
+        let model_ok = match &options.embedding_model {
+            Some(model) => *model == embedding.model_name,
+            None => true,
+        };
+        match local_id {
+            Some(id) if options.import_embeddings && writable && model_ok => {
+                vector_search::store_embedding(
+                    conn,
+                    id,
+                    &embedding.content_hash,
+                    &embedding.vector,
+                    &embedding.model_name,
+                )?;
+                report.embeddings += 1;
+            }
+            _ => report.embeddings_skipped += 1,
+        }
+    }
+    Ok(report)
+}
+
+fn parse_bundle<R: BufRead>(reader: R) -> CortexResult<ParsedBundle> {
+    let mut header = None;
+    let mut memories = Vec::new();
+    let mut relationships = Vec::new();
+    let mut causal_edges = Vec::new();
+    let mut embeddings = Vec::new();
+
+    for (line_no, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| to_storage_err(format!("read bundle: {e}")))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: BundleRecord = serde_json::from_str(&line)
+            .map_err(|e| to_storage_err(format!("bundle line {}: {e}", line_no + 1)))?;
+        match (record, header.is_some()) {
+            (BundleRecord::Header(h), false) => {
+                if h.format_version > BUNDLE_FORMAT_VERSION {
+                    return Err(to_storage_err(format!(
+                        "bundle format v{} is newer than supported v{BUNDLE_FORMAT_VERSION}",
+                        h.format_version
+                    )));
+                }
+                header = Some(h);
+            }
+            (BundleRecord::Header(_), true) => {
+                return Err(to_storage_err(format!(
+                    "bundle line {}: duplicate header",
+                    line_no + 1
+                )))
+            }
+            (_, false) => {
+                return Err(to_storage_err(
+                    "bundle must start with a header record".to_string(),
+                ))
+            }
+            (BundleRecord::Memory(m), true) => memories.push(*m),
+            (BundleRecord::Relationship(e), true) => relationships.push(e),
+            (BundleRecord::CausalEdge(e), true) => causal_edges.push(e),
+            (BundleRecord::Embedding(e), true) => embeddings.push(e),
+        }
+    }
+
+    let header = header.ok_or_else(|| to_storage_err("bundle is empty".to_string()))?;
+    Ok(ParsedBundle {
+        header,
+        memories,
+        relationships,
+        causal_edges,
+        embeddings,
+    })
+}
+
+/// Write one incoming memory and return its local ID and what happened.
+fn import_memory(
+    conn: &Connection,
+    incoming: &BaseMemory,
+    options: &ImportOptions,
+) -> CortexResult<(String, Outcome)> {
+    let mut incoming = incoming.clone();
+    if let Some(ns) = &options.target_namespace {
+        incoming.namespace = ns.clone();
+    }
+
+    let existing = match options.id_strategy {
+        IdStrategy::Preserve => memory_crud::get_memory(conn, &incoming.id)?,
+        IdStrategy::Remap => None,
+    };
+    let existing = match existing {
+        Some(m) => Some(m),
+        None => find_by_content_hash(conn, &incoming.content_hash)?
+            .map(|id| memory_crud::get_memory(conn, &id))
+            .transpose()?
+            .flatten(),
+    };
+
+    let Some(local) = existing else {
+        if options.id_strategy == IdStrategy::Remap {
+            incoming.id = uuid::Uuid::new_v4().to_string();
+        }
+        memory_crud::insert_memory_inner(conn, &incoming)?;
+        return Ok((incoming.id, Outcome::Inserted));
+    };
+
+    let outcome = match options.conflict_policy {
+        ConflictPolicy::Skip => return Ok((local.id, Outcome::Skipped)),
+        ConflictPolicy::Overwrite => {
+            incoming.id = local.id.clone();
+            Outcome::Overwritten
+        }
+        ConflictPolicy::Merge => {
+            incoming = merge_memories(&local, &incoming)?;
+            Outcome::Merged
+        }
+    };
+    VersionTracker::snapshot(conn, &local, &options.provenance_agent, outcome.as_str())?;
+    memory_crud::update_memory_inner(conn, &incoming)?;
+    Ok((local.id, outcome))
+}
+
+/// Field-level merge of two replicas of the same memory.
+///
+/// Mirrors `cortex_crdt::MemoryCRDT::merge`: LWW registers resolved by
+/// `transaction_time` (ties keep local), max registers for confidence and
+/// recency, OR-set union for tags, links and supersedes.
+fn merge_memories(local: &BaseMemory, incoming: &BaseMemory) -> CortexResult<BaseMemory> {
+    let (winner, loser) = if incoming.transaction_time > local.transaction_time {
+        (incoming, local)
+    } else {
+        (local, incoming)
+    };
+
+    let mut merged = winner.clone();
+    merged.id = local.id.clone();
+    merged.source_agent = local.source_agent.clone();
+    merged.confidence = if incoming.confidence.value() > local.confidence.value() {
+        incoming.confidence
+    } else {
+        local.confidence
+    };
+    merged.last_accessed = local.last_accessed.max(incoming.last_accessed);
+    merged.access_count = local.access_count.max(incoming.access_count);
+    union_into(&mut merged.tags, &loser.tags);
+    union_into(&mut merged.linked_patterns, &loser.linked_patterns);
+    union_into(&mut merged.linked_constraints, &loser.linked_constraints);
+    union_into(&mut merged.linked_files, &loser.linked_files);
+    union_into(&mut merged.linked_functions, &loser.linked_functions);
+    if merged.supersedes.is_none() {
+        merged.supersedes = loser.supersedes.clone();
+    }
+    merged.content_hash = BaseMemory::compute_content_hash(&merged.content)?;
+    Ok(merged)
+}
+
+fn union_into<T: Clone + PartialEq>(target: &mut Vec<T>, other: &[T]) {
+    for item in other {
+        if !target.contains(item) {
+            target.push(item.clone());
+        }
+    }
+}
+
+fn find_by_content_hash(conn: &Connection, content_hash: &str) -> CortexResult<Option<String>> {
+    conn.query_row(
+        "SELECT id FROM memories WHERE content_hash = ?1 AND archived = 0
+         ORDER BY transaction_time LIMIT 1",
+        params![content_hash],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| to_storage_err(e.to_string()))
+}
+
+/// Provenance hops reference `agent_registry`, so the import agent must exist.
+fn ensure_provenance_agent(conn: &Connection, agent_id: &str) -> CortexResult<()> {
+    if multiagent_ops::get_agent(conn, agent_id)?.is_some() {
+        return Ok(());
+    }
+    let now = Utc::now().to_rfc3339();
+    multiagent_ops::insert_agent(
+        conn,
+        &InsertAgentParams {
+            agent_id,
+            name: "Bundle import",
+            namespace_id: &NamespaceId::default_namespace().to_uri(),
+            capabilities_json: "[\"import\"]",
+            parent_agent: None,
+            registered_at: &now,
+            status: "active",
+        },
+    )
+}
+
+fn record_provenance(
+    conn: &Connection,
+    local_id: &str,
+    incoming: &BaseMemory,
+    header: &BundleHeader,
+    outcome: Outcome,
+    options: &ImportOptions,
+) -> CortexResult<()> {
+    let hop_index = multiagent_ops::get_provenance_chain(conn, local_id)?.len() as i32;
+    let details = serde_json::json!({
+        "bundle_id": header.bundle_id,
+        "source_label": header.source_label,
+        "origin_id": incoming.id,
+        "origin_agent": incoming.source_agent.0,
+        "policy": outcome.as_str(),
+    })
+    .to_string();
+    let now = Utc::now().to_rfc3339();
+    multiagent_ops::insert_provenance_hop(
+        conn,
+        &InsertProvenanceHopParams {
+            memory_id: local_id,
+            hop_index,
+            agent_id: &options.provenance_agent,
+            action: "imported_from",
+            timestamp: &now,
+            confidence_delta: 0.0,
+            details: Some(&details),
+        },
+    )
+}
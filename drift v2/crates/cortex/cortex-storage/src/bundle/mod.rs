@@ -0,0 +1,20 @@
+//! Portable memory bundles: versioned JSONL export/import.
+//!
+//! A bundle is a single JSONL stream. The first record is a `header`
+//! (format version, bundle id, export filter, embedding model metadata),
+//! followed by `memory`, `relationship`, `causal_edge` and optional
+//! `embedding` records. Links travel inside each memory.
+//!
+//! Import remaps IDs, resolves conflicts (skip / overwrite / field-level
+//! merge) and records an `imported_from` provenance hop for every memory
+//! it writes, so seeded memories stay distinguishable from local ones.
+
+pub mod export;
+pub mod format;
+pub mod import;
+
+pub use export::{export_bundle, ExportFilter, ExportStats};
+pub use format::{
+    BundleHeader, BundleRecord, EmbeddingModelInfo, EmbeddingRecord, BUNDLE_FORMAT_VERSION,
+};
+pub use import::{import_bundle, ConflictPolicy, IdStrategy, ImportOptions, ImportReport};
@@ -0,0 +1,94 @@
+//! Encryption at rest (page-level, via SQLCipher).
+//!
+//! Every page of the database file — memories, embeddings, FTS5 shadow
+//! tables, WAL frames — is encrypted transparently, so queries, FTS5
+//! indexing, compaction and backups keep working unchanged once the key
+//! has been applied to the connection.
+//!
+//! Requires the `encryption` cargo feature, which links SQLCipher instead
+//! of stock SQLite. Without it, opening an encrypted pool fails loudly
+//! rather than silently writing plaintext.
+//!
+//! Key handling is shared with drift.db and lives in
+//! `drift_core::workspace::encryption`, which documents where keys come
+//! from; this module adapts it to [`CortexResult`] and layers the
+//! `[storage]` config on top via [`key_source`].
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+
+use cortex_core::config::StorageConfig;
+use cortex_core::errors::{CortexResult, StorageError};
+use drift_core::workspace::encryption as shared;
+
+pub use shared::{
+    cipher_available, DatabaseKey, KeySource, CORTEX_KEYFILE_ENV, CORTEX_KEY_ENV, RAW_KEY_LEN,
+};
+
+/// Where cortex.db's key comes from.
+///
+/// With `encryption_enabled`, the configured keyfile wins over the configured
+/// env var, and a missing key is an error when it is loaded. Otherwise the
+/// shared `CORTEX_DB_KEYFILE` / `CORTEX_DB_KEY` variables apply, so Cortex
+/// and drift's workspace backups agree on the key; `None` means plaintext.
+pub fn key_source(config: &StorageConfig) -> Option<KeySource> {
+    if !config.encryption_enabled {
+        return KeySource::from_env(CORTEX_KEYFILE_ENV, CORTEX_KEY_ENV);
+    }
+    Some(match &config.encryption_keyfile {
+        Some(path) => KeySource::KeyFile(PathBuf::from(path)),
+        None => KeySource::Env(config.encryption_key_env.clone()),
+    })
+}
+
+/// Load cortex.db's key per [`key_source`]. `Ok(None)` means plaintext.
+pub fn resolve_key(config: &StorageConfig) -> CortexResult<Option<DatabaseKey>> {
+    key_source(config).as_ref().map(load_key).transpose()
+}
+
+/// Load the key material from `source`.
+pub fn load_key(source: &KeySource) -> CortexResult<DatabaseKey> {
+    source.load().map_err(cortex_err)
+}
+
+/// Apply the key to a freshly opened connection.
+///
+/// Must run before any other statement (including pragmas) touches the
+/// database. A wrong key fails here instead of on the first query.
+pub fn apply_key(conn: &Connection, key: &DatabaseKey) -> CortexResult<()> {
+    shared::apply_key(conn, key).map_err(cortex_err)
+}
+
+/// Whether the file at `path` is an unencrypted SQLite database. Missing
+/// or empty files return `false`.
+pub fn is_plaintext_database(path: &Path) -> CortexResult<bool> {
+    shared::is_plaintext_database(path).map_err(cortex_err)
+}
+
+/// Re-encrypt an encrypted database under a new key.
+pub fn rotate_key(
+    db_path: &Path,
+    old_key: &DatabaseKey,
+    new_key: &DatabaseKey,
+) -> CortexResult<()> {
+    shared::rotate_key(db_path, Some(old_key), new_key).map_err(cortex_err)
+}
+
+/// Encrypt an existing plaintext database in place.
+pub fn encrypt_database(db_path: &Path, key: &DatabaseKey) -> CortexResult<()> {
+    shared::encrypt_database(db_path, key).map_err(cortex_err)
+}
+
+/// Decrypt an encrypted database in place.
+pub fn decrypt_database(db_path: &Path, key: &DatabaseKey) -> CortexResult<()> {
+    shared::decrypt_database(db_path, key).map_err(cortex_err)
+}
+
+fn cortex_err(err: drift_core::errors::StorageError) -> cortex_core::CortexError {
+    let reason = match err {
+        drift_core::errors::StorageError::EncryptionError { reason } => reason,
+        other => other.to_string(),
+    };
+    cortex_core::CortexError::StorageError(StorageError::EncryptionError { reason })
+}
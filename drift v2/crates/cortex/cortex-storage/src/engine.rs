@@ -14,6 +14,8 @@ use cortex_core::models::{AuditActor, AuditOperation};
 use cortex_core::traits::{CausalEdge, CausalEvidence, ICausalStorage, IMemoryStorage};
 
 use crate::audit::AuditLogger;
+use crate::bundle::{self, ExportFilter, ExportStats, ImportOptions, ImportReport};
+use crate::encryption::DatabaseKey;
 use crate::migrations;
 use crate::pool::ConnectionPool;
 use crate::versioning::VersionTracker;
@@ -37,6 +39,16 @@ impl StorageEngine {
         Ok(engine)
     }
 
+    /// Open a storage engine backed by an encrypted file on disk.
+    /// A new file is created encrypted; an existing file must already be
+    /// encrypted under `key` (see `encryption::encrypt_database` to convert).
+    pub fn open_encrypted(path: &Path, key: &DatabaseKey) -> CortexResult<Self> {
+        let pool = ConnectionPool::open_encrypted(path, 4, key)?;
+        let engine = Self { pool, use_read_pool: true };
+        engine.initialize()?;
+        Ok(engine)
+    }
+
     /// Open an in-memory storage engine (for testing).
     /// Routes all reads through the writer since in-memory read pool
     /// connections are isolated databases that can't see writer's changes.
@@ -65,6 +77,27 @@ impl StorageEngine {
         &self.pool
     }
 
+    /// Export memories matching `filter` as a JSONL bundle.
+    pub fn export_bundle<W: std::io::Write>(
+        &self,
+        filter: &ExportFilter,
+        source_label: Option<&str>,
+        mut writer: W,
+    ) -> CortexResult<ExportStats> {
+        self.with_reader(|conn| bundle::export_bundle(conn, filter, source_label, &mut writer))
+    }
+
+    /// Import a JSONL bundle produced by [`StorageEngine::export_bundle`].
+    pub fn import_bundle<R: std::io::BufRead>(
+        &self,
+        reader: R,
+        options: &ImportOptions,
+    ) -> CortexResult<ImportReport> {
+        self.pool
+            .writer
+            .with_conn_sync(|conn| bundle::import_bundle(conn, reader, options))
+    }
+
     /// Execute a read-only query on the best available connection.
     /// File-backed: uses the read pool (no writer contention).
     /// In-memory: uses the writer (read pool is isolated).
@@ -5,7 +5,9 @@
 //! Single write connection + read pool (WAL mode).
 
 pub mod audit;
+pub mod bundle;
 pub mod compaction;
+pub mod encryption;
 pub mod engine;
 pub mod migrations;
 pub mod pool;
@@ -9,6 +9,8 @@ use std::sync::Arc;
 
 use cortex_core::errors::CortexResult;
 
+use crate::encryption::DatabaseKey;
+
 pub use read_pool::ReadPool;
 pub use write_connection::WriteConnection;
 
@@ -35,6 +37,22 @@ impl ConnectionPool {
         })
     }
 
+    /// Open a connection pool for an encrypted database file. Every connection,
+    /// writer and readers alike, is keyed before use.
+    pub fn open_encrypted(
+        path: &Path,
+        read_pool_size: usize,
+        key: &DatabaseKey,
+    ) -> CortexResult<Self> {
+        let writer = Arc::new(WriteConnection::open_encrypted(path, key)?);
+        let readers = Arc::new(ReadPool::open_encrypted(path, read_pool_size, key)?);
+        Ok(Self {
+            writer,
+            readers,
+            db_path: Some(path.to_path_buf()),
+        })
+    }
+
     /// Open an in-memory connection pool (for testing).
     /// Note: In-memory mode uses separate databases for writer and readers,
     /// so readers won't see writer's changes. For integration tests, use a
@@ -8,6 +8,7 @@ use rusqlite::Connection;
 use cortex_core::errors::CortexResult;
 
 use super::pragmas::apply_read_pragmas;
+use crate::encryption::{apply_key, DatabaseKey};
 use crate::to_storage_err;
 
 /// Default number of read connections.
@@ -25,6 +26,19 @@ pub struct ReadPool {
 impl ReadPool {
     /// Open a pool of read connections to the given database path.
     pub fn open(path: &Path, pool_size: usize) -> CortexResult<Self> {
+        Self::open_with_key(path, pool_size, None)
+    }
+
+    /// Open a pool of read connections to an encrypted database.
+    pub fn open_encrypted(path: &Path, pool_size: usize, key: &DatabaseKey) -> CortexResult<Self> {
+        Self::open_with_key(path, pool_size, Some(key))
+    }
+
+    fn open_with_key(
+        path: &Path,
+        pool_size: usize,
+        key: Option<&DatabaseKey>,
+    ) -> CortexResult<Self> {
         let size = pool_size.clamp(1, MAX_POOL_SIZE);
         let mut connections = Vec::with_capacity(size);
         for _ in 0..size {
@@ -34,6 +48,9 @@ impl ReadPool {
                     | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
             )
             .map_err(|e| to_storage_err(e.to_string()))?;
+            if let Some(key) = key {
+                apply_key(&conn, key)?;
+            }
             apply_read_pragmas(&conn)?;
             connections.push(std::sync::Mutex::new(conn));
         }
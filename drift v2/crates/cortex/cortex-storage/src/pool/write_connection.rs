@@ -9,6 +9,7 @@ use tokio::sync::Mutex;
 use cortex_core::errors::CortexResult;
 
 use super::pragmas::apply_pragmas;
+use crate::encryption::{apply_key, DatabaseKey};
 use crate::to_storage_err;
 
 /// A single write connection protected by an async mutex.
@@ -26,6 +27,17 @@ impl WriteConnection {
         })
     }
 
+    /// Open a write connection to an encrypted database. The key is applied
+    /// before pragmas so the header is never read with the wrong cipher state.
+    pub fn open_encrypted(path: &Path, key: &DatabaseKey) -> CortexResult<Self> {
+        let conn = Connection::open(path).map_err(|e| to_storage_err(e.to_string()))?;
+        apply_key(&conn, key)?;
+        apply_pragmas(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
     /// Open an in-memory database (for testing).
     pub fn open_in_memory() -> CortexResult<Self> {
         let conn = Connection::open_in_memory().map_err(|e| to_storage_err(e.to_string()))?;
@@ -28,7 +28,8 @@ pub fn insert_memory(conn: &Connection, memory: &BaseMemory) -> CortexResult<()>
 }
 
 /// Inner insert logic, operating on the provided connection (or transaction via Deref).
-fn insert_memory_inner(conn: &Connection, memory: &BaseMemory) -> CortexResult<()> {
+/// For callers that already hold a transaction, such as bundle import.
+pub(crate) fn insert_memory_inner(conn: &Connection, memory: &BaseMemory) -> CortexResult<()> {
     let content_json =
         serde_json::to_string(&memory.content).map_err(|e| to_storage_err(e.to_string()))?;
     let tags_json =
@@ -170,7 +171,8 @@ pub fn update_memory(conn: &Connection, memory: &BaseMemory) -> CortexResult<()>
 }
 
 /// Inner update logic, operating on the provided connection (or transaction via Deref).
-fn update_memory_inner(conn: &Connection, memory: &BaseMemory) -> CortexResult<()> {
+/// For callers that already hold a transaction, such as bundle import.
+pub(crate) fn update_memory_inner(conn: &Connection, memory: &BaseMemory) -> CortexResult<()> {
     // Fetch old state for diff-based event emission.
     let old = get_memory(conn, &memory.id)?;
 
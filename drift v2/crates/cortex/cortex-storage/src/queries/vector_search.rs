@@ -1,6 +1,6 @@
 //! sqlite-vec similarity search queries.
 
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 
 use cortex_core::errors::CortexResult;
 use cortex_core::memory::BaseMemory;
@@ -143,6 +143,36 @@ fn store_embedding_inner(
     Ok(())
 }
 
+/// A memory's stored embedding: (content_hash, model_name, vector).
+pub type StoredEmbedding = (String, String, Vec<f32>);
+
+/// Load the embedding linked to a memory, if any.
+pub fn get_embedding_for_memory(
+    conn: &Connection,
+    memory_id: &str,
+) -> CortexResult<Option<StoredEmbedding>> {
+    let row = conn
+        .query_row(
+            "SELECT me.content_hash, me.model_name, me.embedding, me.dimensions
+             FROM memory_embedding_link mel
+             JOIN memory_embeddings me ON me.id = mel.embedding_id
+             WHERE mel.memory_id = ?1",
+            params![memory_id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                    row.get::<_, i32>(3)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(|e| to_storage_err(e.to_string()))?;
+
+    Ok(row.map(|(hash, model, blob, dims)| (hash, model, bytes_to_f32_vec(&blob, dims as usize))))
+}
+
 /// Convert f32 slice to bytes (little-endian).
 fn f32_vec_to_bytes(v: &[f32]) -> Vec<u8> {
     v.iter().flat_map(|f| f.to_le_bytes()).collect()
@@ -6,6 +6,7 @@ use rusqlite::Connection;
 
 use cortex_core::errors::CortexResult;
 
+use crate::encryption::{apply_key, DatabaseKey};
 use crate::to_storage_err;
 
 /// Create a backup of the database to the given path.
@@ -37,3 +38,45 @@ pub fn restore_from_backup(conn: &mut Connection, backup_path: &Path) -> CortexR
 
     Ok(())
 }
+
+/// Create a backup of an encrypted database. SQLCipher's backup API only
+/// copies pages between databases keyed identically, so the destination is
+/// keyed with the same key before the copy — the backup stays encrypted.
+pub fn create_encrypted_backup(
+    conn: &Connection,
+    backup_path: &Path,
+    key: &DatabaseKey,
+) -> CortexResult<()> {
+    let mut dst = Connection::open(backup_path)
+        .map_err(|e| to_storage_err(format!("open backup dest: {e}")))?;
+    apply_key(&dst, key)?;
+
+    let backup = rusqlite::backup::Backup::new(conn, &mut dst)
+        .map_err(|e| to_storage_err(format!("init backup: {e}")))?;
+
+    backup
+        .run_to_completion(100, std::time::Duration::from_millis(10), None)
+        .map_err(|e| to_storage_err(format!("run backup: {e}")))?;
+
+    Ok(())
+}
+
+/// Restore an encrypted database from an encrypted backup made with the same key.
+pub fn restore_from_encrypted_backup(
+    conn: &mut Connection,
+    backup_path: &Path,
+    key: &DatabaseKey,
+) -> CortexResult<()> {
+    let src = Connection::open(backup_path)
+        .map_err(|e| to_storage_err(format!("open backup source: {e}")))?;
+    apply_key(&src, key)?;
+
+    let backup = rusqlite::backup::Backup::new(&src, conn)
+        .map_err(|e| to_storage_err(format!("init restore: {e}")))?;
+
+    backup
+        .run_to_completion(100, std::time::Duration::from_millis(10), None)
+        .map_err(|e| to_storage_err(format!("run restore: {e}")))?;
+
+    Ok(())
+}
@@ -0,0 +1,384 @@
+//! Portable memory bundles: export filters, import ID remapping, conflict
+//! policies, embeddings and provenance marking.
+
+use chrono::{Duration, Utc};
+use cortex_core::memory::*;
+use cortex_core::models::namespace::NamespaceId;
+use cortex_core::traits::{CausalEdge, ICausalStorage, IMemoryStorage};
+use cortex_storage::bundle::{
+    ConflictPolicy, ExportFilter, IdStrategy, ImportOptions, BUNDLE_FORMAT_VERSION,
+};
+use cortex_storage::queries::{multiagent_ops, vector_search};
+use cortex_storage::StorageEngine;
+
+fn make_memory(id: &str, observation: &str) -> BaseMemory {
+    let now = Utc::now();
+    let tc = TypedContent::Insight(cortex_core::memory::types::InsightContent {
+        observation: observation.to_string(),
+        evidence: vec![],
+    });
+    BaseMemory {
+        id: id.to_string(),
+        memory_type: MemoryType::Insight,
+        content: tc.clone(),
+        summary: format!("summary {id}"),
+        transaction_time: now,
+        valid_time: now,
+        valid_until: None,
+        confidence: Confidence::new(0.6),
+        importance: Importance::Normal,
+        last_accessed: now,
+        access_count: 1,
+        linked_patterns: vec![],
+        linked_constraints: vec![],
+        linked_files: vec![],
+        linked_functions: vec![],
+        tags: vec!["shared".to_string()],
+        archived: false,
+        superseded_by: None,
+        supersedes: None,
+        namespace: Default::default(),
+        source_agent: Default::default(),
+        content_hash: BaseMemory::compute_content_hash(&tc).unwrap(),
+    }
+}
+
+fn export_all(storage: &StorageEngine, filter: &ExportFilter) -> Vec<u8> {
+    let mut out = Vec::new();
+    storage
+        .export_bundle(filter, Some("team-a"), &mut out)
+        .unwrap();
+    out
+}
+
+/// Source store with two linked memories, a causal edge and an embedding.
+fn seeded_source() -> StorageEngine {
+    let storage = StorageEngine::open_in_memory().unwrap();
+    let mut a = make_memory("a", "retries need jitter");
+    a.linked_files.push(FileLink {
+        file_path: "src/retry.rs".to_string(),
+        line_start: Some(10),
+        line_end: Some(20),
+        content_hash: None,
+    });
+    let b = make_memory("b", "backoff caps at 30s");
+    storage.create(&a).unwrap();
+    storage.create(&b).unwrap();
+    storage
+        .add_relationship(&RelationshipEdge {
+            source_id: "a".to_string(),
+            target_id: "b".to_string(),
+            relationship_type: RelationshipType::Supports,
+            strength: 0.9,
+            evidence: vec![],
+            cross_agent_relation: None,
+        })
+        .unwrap();
+    storage
+        .add_edge(&CausalEdge {
+            source_id: "a".to_string(),
+            target_id: "b".to_string(),
+            relation: "caused".to_string(),
+            strength: 0.7,
+            evidence: vec![],
+            source_agent: None,
+        })
+        .unwrap();
+    storage
+        .pool()
+        .writer
+        .with_conn_sync(|conn| {
+            vector_search::store_embedding(conn, "a", &a.content_hash, &[0.1, 0.2, 0.3], "model-x")
+        })
+        .unwrap();
+    storage
+}
+
+#[test]
+fn round_trip_remaps_ids_and_edges() {
+    let source = seeded_source();
+    let filter = ExportFilter {
+        include_embeddings: true,
+        ..Default::default()
+    };
+    let bundle = export_all(&source, &filter);
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let report = target
+        .import_bundle(bundle.as_slice(), &ImportOptions::default())
+        .unwrap();
+    assert_eq!(report.inserted, 2);
+    assert_eq!(report.relationships, 1);
+    assert_eq!(report.causal_edges, 1);
+    assert_eq!(report.embeddings, 1);
+
+    let new_a = &report.id_map["a"];
+    let new_b = &report.id_map["b"];
+    assert_ne!(new_a, "a");
+
+    let imported = target.get(new_a).unwrap().unwrap();
+    assert_eq!(imported.linked_files.len(), 1);
+    let rels = target.get_relationships(new_a, None).unwrap();
+    assert!(rels.iter().any(|r| r.target_id == *new_b));
+    let edges = target.get_edges(new_a).unwrap();
+    assert!(edges.iter().any(|e| e.target_id == *new_b));
+    let embedding = target
+        .pool()
+        .writer
+        .with_conn_sync(|conn| vector_search::get_embedding_for_memory(conn, new_a))
+        .unwrap()
+        .unwrap();
+    assert_eq!(embedding.1, "model-x");
+}
+
+#[test]
+fn imported_memories_carry_provenance() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let report = target
+        .import_bundle(bundle.as_slice(), &ImportOptions::default())
+        .unwrap();
+    let chain = target
+        .pool()
+        .writer
+        .with_conn_sync(|conn| multiagent_ops::get_provenance_chain(conn, &report.id_map["a"]))
+        .unwrap();
+    assert_eq!(chain.len(), 1);
+    assert_eq!(chain[0].action, "imported_from");
+    let details: serde_json::Value =
+        serde_json::from_str(chain[0].details.as_deref().unwrap()).unwrap();
+    assert_eq!(details["origin_id"], "a");
+    assert_eq!(details["source_label"], "team-a");
+    assert_eq!(details["bundle_id"], report.bundle_id.as_str());
+}
+
+#[test]
+fn export_filters_by_type_namespace_and_date() {
+    let storage = StorageEngine::open_in_memory().unwrap();
+    let old = {
+        let mut m = make_memory("old", "old insight");
+        m.transaction_time = Utc::now() - Duration::days(30);
+        m
+    };
+    let team = {
+        let mut m = make_memory("team", "team insight");
+        m.namespace = NamespaceId::parse("team://platform/").unwrap();
+        m
+    };
+    let mut tribal = make_memory("tribal", "tribal note");
+    tribal.memory_type = MemoryType::Tribal;
+    for m in [&old, &team, &tribal] {
+        storage.create(m).unwrap();
+    }
+
+    let mut out = Vec::new();
+    let stats = storage
+        .export_bundle(
+            &ExportFilter {
+                memory_types: vec![MemoryType::Insight],
+                from: Some(Utc::now() - Duration::days(1)),
+                ..Default::default()
+            },
+            None,
+            &mut out,
+        )
+        .unwrap();
+    assert_eq!(stats.memories, 1);
+    assert!(String::from_utf8(out).unwrap().contains("\"id\":\"team\""));
+
+    let mut out = Vec::new();
+    let stats = storage
+        .export_bundle(
+            &ExportFilter {
+                namespace: Some("team://platform/".to_string()),
+                ..Default::default()
+            },
+            None,
+            &mut out,
+        )
+        .unwrap();
+    assert_eq!(stats.memories, 1);
+}
+
+#[test]
+fn skip_policy_keeps_local_copy() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let mut local = make_memory("local-a", "retries need jitter");
+    local.summary = "local summary".to_string();
+    target.create(&local).unwrap();
+
+    let report = target
+        .import_bundle(bundle.as_slice(), &ImportOptions::default())
+        .unwrap();
+    assert_eq!(report.skipped, 1);
+    assert_eq!(report.inserted, 1);
+    assert_eq!(report.id_map["a"], "local-a");
+    let kept = target.get("local-a").unwrap().unwrap();
+    assert_eq!(kept.summary, "local summary");
+}
+
+#[test]
+fn overwrite_policy_replaces_and_versions() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let mut local = make_memory("a", "retries need jitter");
+    local.summary = "local summary".to_string();
+    target.create(&local).unwrap();
+
+    let options = ImportOptions {
+        conflict_policy: ConflictPolicy::Overwrite,
+        id_strategy: IdStrategy::Preserve,
+        ..Default::default()
+    };
+    let report = target.import_bundle(bundle.as_slice(), &options).unwrap();
+    assert_eq!(report.overwritten, 1);
+    assert_eq!(report.id_map["a"], "a");
+    assert_eq!(target.get("a").unwrap().unwrap().summary, "summary a");
+    let history = target
+        .pool()
+        .writer
+        .with_conn_sync(|conn| cortex_storage::versioning::query::get_history(conn, "a"))
+        .unwrap();
+    assert!(!history.is_empty());
+}
+
+#[test]
+fn merge_policy_unions_tags_and_keeps_max_confidence() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let mut local = make_memory("a", "retries need jitter");
+    local.tags = vec!["local".to_string()];
+    local.confidence = Confidence::new(0.9);
+    local.access_count = 7;
+    local.transaction_time = Utc::now() - Duration::days(1);
+    target.create(&local).unwrap();
+
+    let options = ImportOptions {
+        conflict_policy: ConflictPolicy::Merge,
+        id_strategy: IdStrategy::Preserve,
+        ..Default::default()
+    };
+    let report = target.import_bundle(bundle.as_slice(), &options).unwrap();
+    assert_eq!(report.merged, 1);
+
+    let merged = target.get("a").unwrap().unwrap();
+    assert!(merged.tags.contains(&"local".to_string()));
+    assert!(merged.tags.contains(&"shared".to_string()));
+    assert!((merged.confidence.value() - 0.9).abs() < 1e-9);
+    assert_eq!(merged.access_count, 7);
+    // The incoming replica is newer, so its summary wins.
+    assert_eq!(merged.summary, "summary a");
+    assert_eq!(merged.linked_files.len(), 1);
+}
+
+#[test]
+fn embeddings_from_other_models_are_skipped() {
+    let source = seeded_source();
+    let filter = ExportFilter {
+        include_embeddings: true,
+        ..Default::default()
+    };
+    let bundle = export_all(&source, &filter);
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let options = ImportOptions {
+        embedding_model: Some("model-y".to_string()),
+        ..Default::default()
+    };
+    let report = target.import_bundle(bundle.as_slice(), &options).unwrap();
+    assert_eq!(report.embeddings, 0);
+    assert_eq!(report.embeddings_skipped, 1);
+}
+
+#[test]
+fn target_namespace_overrides_bundle_namespace() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    let options = ImportOptions {
+        target_namespace: Some(NamespaceId::parse("team://seeded/").unwrap()),
+        ..Default::default()
+    };
+    let report = target.import_bundle(bundle.as_slice(), &options).unwrap();
+    let imported = target.get(&report.id_map["b"]).unwrap().unwrap();
+    assert_eq!(imported.namespace.to_uri(), "team://seeded/");
+}
+
+#[test]
+fn newer_format_version_is_rejected_before_writing() {
+    let source = seeded_source();
+    let bundle = String::from_utf8(export_all(&source, &ExportFilter::default())).unwrap();
+    let future = bundle.replacen(
+        &format!("\"format_version\":{BUNDLE_FORMAT_VERSION}"),
+        &format!("\"format_version\":{}", BUNDLE_FORMAT_VERSION + 1),
+        1,
+    );
+
+    let target = StorageEngine::open_in_memory().unwrap();
+    assert!(target
+        .import_bundle(future.as_bytes(), &ImportOptions::default())
+        .is_err());
+    assert!(target
+        .query_by_type(MemoryType::Insight)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn bundle_without_header_is_rejected() {
+    let target = StorageEngine::open_in_memory().unwrap();
+    let memory = serde_json::json!({ "kind": "memory" }).to_string();
+    assert!(target
+        .import_bundle(memory.as_bytes(), &ImportOptions::default())
+        .is_err());
+    assert!(target
+        .import_bundle(&b""[..], &ImportOptions::default())
+        .is_err());
+}
+
+#[test]
+fn failure_midway_rolls_back_the_whole_import() {
+    let source = seeded_source();
+    let bundle = export_all(&source, &ExportFilter::default());
+
+    // The bundle parses cleanly, but its second memory cannot be written.
+    let target = StorageEngine::open_in_memory().unwrap();
+    target
+        .pool()
+        .writer
+        .with_conn_sync(|conn| {
+            conn.execute_batch(
+                "CREATE TEMP TRIGGER reject_b BEFORE INSERT ON memories
+                 WHEN NEW.summary = 'summary b'
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+            Ok(())
+        })
+        .unwrap();
+
+    let err = target
+        .import_bundle(bundle.as_slice(), &ImportOptions::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("rejected"), "{err}");
+    assert!(target
+        .query_by_type(MemoryType::Insight)
+        .unwrap()
+        .is_empty());
+    let agent = target
+        .pool()
+        .writer
+        .with_conn_sync(|conn| multiagent_ops::get_agent(conn, "bundle-import"))
+        .unwrap();
+    assert!(agent.is_none());
+}
@@ -0,0 +1,282 @@
+//! Encryption at rest: key parsing, key sources, and (with the `encryption`
+//! feature) SQLCipher round-trips, FTS5, backups and key rotation.
+
+use cortex_core::config::StorageConfig;
+use cortex_storage::encryption::{self, DatabaseKey, KeySource};
+
+const RAW_HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KEY MATERIAL
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn parse_hex_is_raw_key() {
+    let key = DatabaseKey::parse(RAW_HEX).unwrap();
+    match &key {
+        DatabaseKey::Raw(bytes) => {
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(bytes[31], 0x1f);
+        }
+        other => panic!("expected raw key, got {other:?}"),
+    }
+    let wrapped = DatabaseKey::parse(&format!("x'{RAW_HEX}'")).unwrap();
+    assert_eq!(key, wrapped);
+}
+
+#[test]
+fn parse_other_material_is_passphrase() {
+    let key = DatabaseKey::parse("  correct horse battery staple \n").unwrap();
+    assert_eq!(key, DatabaseKey::Passphrase("correct horse battery staple".into()));
+}
+
+#[test]
+fn parse_empty_is_error() {
+    assert!(DatabaseKey::parse("   ").is_err());
+}
+
+#[test]
+fn from_raw_enforces_length() {
+    assert!(DatabaseKey::from_raw(&[7u8; 32]).is_ok());
+    assert!(DatabaseKey::from_raw(&[7u8; 16]).is_err());
+}
+
+#[test]
+fn debug_never_prints_key_material() {
+    let raw = format!("{:?}", DatabaseKey::parse(RAW_HEX).unwrap());
+    let pass = format!("{:?}", DatabaseKey::parse("hunter2").unwrap());
+    assert!(!raw.contains("0102"));
+    assert!(!pass.contains("hunter2"));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KEY SOURCES
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn key_source_disabled_by_default() {
+    assert!(encryption::key_source(&StorageConfig::default()).is_none());
+}
+
+#[test]
+fn key_source_prefers_keyfile() {
+    let config = StorageConfig {
+        encryption_enabled: true,
+        encryption_keyfile: Some("/etc/cortex/db.key".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        encryption::key_source(&config),
+        Some(KeySource::KeyFile("/etc/cortex/db.key".into()))
+    );
+
+    let config = StorageConfig {
+        encryption_enabled: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        encryption::key_source(&config),
+        Some(KeySource::Env("CORTEX_DB_KEY".into()))
+    );
+}
+
+#[test]
+fn keyfile_first_line_is_loaded() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("db.key");
+    std::fs::write(&path, format!("{RAW_HEX}\n# rotated 2026-10\n")).unwrap();
+    let key = KeySource::KeyFile(path).load().unwrap();
+    assert!(matches!(key, DatabaseKey::Raw(_)));
+}
+
+#[test]
+fn missing_env_var_is_error() {
+    let source = KeySource::Env("CORTEX_TEST_KEY_THAT_IS_NEVER_SET".into());
+    assert!(source.load().is_err());
+}
+
+#[test]
+fn plaintext_detection() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("plain.db");
+    assert!(!encryption::is_plaintext_database(&path).unwrap());
+    drop(cortex_storage::StorageEngine::open(&path).unwrap());
+    assert!(encryption::is_plaintext_database(&path).unwrap());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WITHOUT SQLCIPHER: refuse instead of silently writing plaintext
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn open_encrypted_fails_without_sqlcipher() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("enc.db");
+    let key = DatabaseKey::parse(RAW_HEX).unwrap();
+    let err = cortex_storage::StorageEngine::open_encrypted(&path, &key)
+        .err()
+        .expect("must refuse without SQLCipher");
+    assert!(err.to_string().contains("SQLCipher"));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WITH SQLCIPHER
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "encryption")]
+mod sqlcipher {
+    use super::*;
+
+    use chrono::Utc;
+    use cortex_core::memory::types::*;
+    use cortex_core::memory::*;
+    use cortex_core::traits::IMemoryStorage;
+    use cortex_storage::StorageEngine;
+
+    fn make_memory(id: &str, knowledge: &str) -> BaseMemory {
+        let content = TypedContent::Tribal(TribalContent {
+            knowledge: knowledge.to_string(),
+            severity: "high".to_string(),
+            warnings: vec![],
+            consequences: vec![],
+        });
+        BaseMemory {
+            id: id.to_string(),
+            memory_type: MemoryType::Tribal,
+            content: content.clone(),
+            summary: knowledge.to_string(),
+            transaction_time: Utc::now(),
+            valid_time: Utc::now(),
+            valid_until: None,
+            confidence: Confidence::new(0.8),
+            importance: Importance::Normal,
+            last_accessed: Utc::now(),
+            access_count: 0,
+            linked_patterns: vec![],
+            linked_constraints: vec![],
+            linked_files: vec![],
+            linked_functions: vec![],
+            tags: vec![],
+            archived: false,
+            superseded_by: None,
+            supersedes: None,
+            content_hash: BaseMemory::compute_content_hash(&content).unwrap(),
+            namespace: Default::default(),
+            source_agent: Default::default(),
+        }
+    }
+
+    fn key(material: &str) -> DatabaseKey {
+        DatabaseKey::parse(material).unwrap()
+    }
+
+    #[test]
+    fn round_trip_and_file_is_not_plaintext() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("enc.db");
+        {
+            let engine = StorageEngine::open_encrypted(&path, &key(RAW_HEX)).unwrap();
+            engine
+                .create(&make_memory("m1", "payments ledger uses idempotency keys"))
+                .unwrap();
+            engine.pool().writer.with_conn_sync(|c| {
+                c.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);").unwrap();
+                Ok(())
+            })
+            .unwrap();
+        }
+        assert!(!encryption::is_plaintext_database(&path).unwrap());
+        let bytes = std::fs::read(&path).unwrap();
+        let needle = b"idempotency";
+        assert!(!bytes.windows(needle.len()).any(|w| w == needle));
+
+        let engine = StorageEngine::open_encrypted(&path, &key(RAW_HEX)).unwrap();
+        assert!(engine.get("m1").unwrap().is_some());
+    }
+
+    #[test]
+    fn wrong_key_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("enc.db");
+        drop(StorageEngine::open_encrypted(&path, &key("right")).unwrap());
+        assert!(StorageEngine::open_encrypted(&path, &key("wrong")).is_err());
+        assert!(StorageEngine::open(&path).is_err());
+    }
+
+    #[test]
+    fn fts5_search_works_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("enc.db");
+        let engine = StorageEngine::open_encrypted(&path, &key("fts")).unwrap();
+        engine
+            .create(&make_memory("m1", "retry storms on the billing queue"))
+            .unwrap();
+        let hits = engine.search_fts5("billing", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[test]
+    fn backup_stays_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("enc.db");
+        let backup_path = dir.path().join("enc.backup.db");
+        let k = key("backup");
+        let engine = StorageEngine::open_encrypted(&path, &k).unwrap();
+        engine.create(&make_memory("m1", "backed up")).unwrap();
+        engine
+            .pool()
+            .writer
+            .with_conn_sync(|conn| {
+                cortex_storage::recovery::backup::create_encrypted_backup(conn, &backup_path, &k)
+            })
+            .unwrap();
+        assert!(!encryption::is_plaintext_database(&backup_path).unwrap());
+        let restored = StorageEngine::open_encrypted(&backup_path, &k).unwrap();
+        assert!(restored.get("m1").unwrap().is_some());
+    }
+
+    #[test]
+    fn rotate_encrypt_decrypt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rot.db");
+        {
+            let engine = StorageEngine::open(&path).unwrap();
+            engine.create(&make_memory("m1", "rotated")).unwrap();
+        }
+
+        encryption::encrypt_database(&path, &key("first")).unwrap();
+        assert!(!encryption::is_plaintext_database(&path).unwrap());
+
+        encryption::rotate_key(&path, &key("first"), &key(RAW_HEX)).unwrap();
+        assert!(StorageEngine::open_encrypted(&path, &key("first")).is_err());
+        {
+            let engine = StorageEngine::open_encrypted(&path, &key(RAW_HEX)).unwrap();
+            assert!(engine.get("m1").unwrap().is_some());
+        }
+
+        encryption::decrypt_database(&path, &key(RAW_HEX)).unwrap();
+        assert!(encryption::is_plaintext_database(&path).unwrap());
+        let engine = StorageEngine::open(&path).unwrap();
+        assert!(engine.get("m1").unwrap().is_some());
+    }
+
+    #[test]
+    fn rotation_preserves_user_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ver.db");
+        let open = |k: &DatabaseKey| {
+            let conn = rusqlite::Connection::open(&path).unwrap();
+            encryption::apply_key(&conn, k).unwrap();
+            conn
+        };
+        drop(StorageEngine::open_encrypted(&path, &key("first")).unwrap());
+        open(&key("first")).execute_batch("PRAGMA user_version = 42;").unwrap();
+
+        encryption::rotate_key(&path, &key("first"), &key(RAW_HEX)).unwrap();
+        let version: i64 = open(&key(RAW_HEX))
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, 42);
+    }
+}
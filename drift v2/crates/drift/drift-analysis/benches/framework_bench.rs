@@ -54,6 +54,7 @@ fn bench_framework_matching(c: &mut Criterion) {
                     functions: &[],
                     call_sites: &[],
                     exports: &[],
+                    embedded_sql_cache: Default::default(),
                 };
                 matcher.analyze_file(&ctx);
             }
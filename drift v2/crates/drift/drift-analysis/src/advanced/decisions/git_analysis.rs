@@ -1,11 +1,13 @@
 //! git2 crate integration for commit history analysis.
 //!
-//! High-performance pipeline for extracting decisions from git history.
+//! High-performance pipeline for extracting decisions from git history, and
+//! the per-commit file changes that history analytics is built on.
 
 use std::path::Path;
 
 use super::types::{CommitSummary, Decision};
 use super::categorizer::DecisionCategorizer;
+use crate::advanced::history::types::{ChangeKind, CommitChanges, FileChange, Hunk};
 
 /// Git history analyzer using git2.
 pub struct GitAnalyzer {
@@ -43,41 +45,85 @@ impl GitAnalyzer {
         Ok(decisions)
     }
 
-    /// Walk commits from HEAD, collecting summaries.
-    fn walk_commits(&self, repo: &git2::Repository) -> Result<Vec<CommitSummary>, String> {
-        let mut revwalk = repo.revwalk()
-            .map_err(|e| format!("Failed to create revwalk: {}", e))?;
+    /// Collect the file changes of the last `max_commits` commits, newest
+    /// first, for history analytics. Merge commits are skipped.
+    pub fn commit_changes(&self, repo_path: &Path) -> Result<Vec<CommitChanges>, String> {
+        let repo = git2::Repository::open(repo_path)
+            .map_err(|e| format!("Failed to open repository: {}", e))?;
+        self.collect_changes(&repo)
+    }
 
-        revwalk.push_head()
-            .map_err(|e| format!("Failed to push HEAD: {}", e))?;
+    /// [`commit_changes`](Self::commit_changes) for a project root that may
+    /// sit anywhere inside its repository. Paths are reported as
+    /// `root.join(..)` — the form the scanner and parsers key files by —
+    /// and changes outside `root` are dropped.
+    pub fn project_changes(&self, root: &Path) -> Result<Vec<CommitChanges>, String> {
+        let repo = git2::Repository::discover(root)
+            .map_err(|e| format!("Failed to open repository: {}", e))?;
+        let workdir = repo.workdir().ok_or("Repository has no working directory")?;
+        let (canonical_root, canonical_workdir) = root
+            .canonicalize()
+            .and_then(|r| workdir.canonicalize().map(|w| (r, w)))
+            .map_err(|e| format!("Failed to resolve project root: {}", e))?;
+        let prefix = canonical_root
+            .strip_prefix(&canonical_workdir)
+            .map_err(|_| "Project root is outside the repository".to_string())?;
+        let rebase = |path: &str| {
+            Path::new(path).strip_prefix(prefix).ok().map(|rel| root.join(rel).to_string_lossy().into_owned())
+        };
 
-        revwalk.set_sorting(git2::Sort::TIME)
-            .map_err(|e| format!("Failed to set sorting: {}", e))?;
+        let mut commits = self.collect_changes(&repo)?;
+        for commit in &mut commits {
+            commit.files.retain_mut(|change| match rebase(&change.path) {
+                Some(path) => {
+                    change.path = path;
+                    change.old_path = change.old_path.as_deref().and_then(rebase);
+                    true
+                }
+                None => false,
+            });
+        }
+        commits.retain(|c| !c.files.is_empty());
+        Ok(commits)
+    }
 
-        let mut summaries = Vec::new();
+    fn collect_changes(&self, repo: &git2::Repository) -> Result<Vec<CommitChanges>, String> {
+        let mailmap = repo.mailmap().ok();
 
-        for (i, oid_result) in revwalk.enumerate() {
-            if i >= self.max_commits {
-                break;
+        let mut changes = Vec::new();
+        self.walk(repo, |commit| {
+            if commit.parent_count() > 1 {
+                return;
+            }
+            let author = match &mailmap {
+                Some(mailmap) => commit.author_with_mailmap(mailmap).ok(),
+                None => None,
             }
+            .unwrap_or_else(|| commit.author());
 
-            let oid = match oid_result {
-                Ok(oid) => oid,
-                Err(_) => continue,
-            };
+            changes.push(CommitChanges {
+                sha: commit.id().to_string(),
+                author: author.name().unwrap_or("unknown").to_string(),
+                timestamp: commit.time().seconds(),
+                files: Self::file_changes(repo, commit),
+            });
+        })?;
 
-            let commit = match repo.find_commit(oid) {
-                Ok(c) => c,
-                Err(_) => continue,
-            };
+        Ok(changes)
+    }
 
+    /// Walk commits from HEAD, collecting summaries.
+    fn walk_commits(&self, repo: &git2::Repository) -> Result<Vec<CommitSummary>, String> {
+        let mut summaries = Vec::new();
+
+        self.walk(repo, |commit| {
             let message = commit.message().unwrap_or("").to_string();
             let author = commit.author().name().unwrap_or("unknown").to_string();
             let timestamp = commit.time().seconds();
-            let sha = oid.to_string();
+            let sha = commit.id().to_string();
 
             // Get diff stats
-            let (files_changed, insertions, deletions) = self.diff_stats(repo, &commit);
+            let (files_changed, insertions, deletions) = self.diff_stats(repo, commit);
 
             summaries.push(CommitSummary {
                 sha,
@@ -88,11 +134,107 @@ impl GitAnalyzer {
                 insertions,
                 deletions,
             });
-        }
+        })?;
 
         Ok(summaries)
     }
 
+    /// Visit up to `max_commits` commits from HEAD, newest first.
+    fn walk(
+        &self,
+        repo: &git2::Repository,
+        mut visit: impl FnMut(&git2::Commit),
+    ) -> Result<(), String> {
+        let mut revwalk = repo.revwalk()
+            .map_err(|e| format!("Failed to create revwalk: {}", e))?;
+
+        revwalk.push_head()
+            .map_err(|e| format!("Failed to push HEAD: {}", e))?;
+
+        revwalk.set_sorting(git2::Sort::TIME)
+            .map_err(|e| format!("Failed to set sorting: {}", e))?;
+
+        for (i, oid_result) in revwalk.enumerate() {
+            if i >= self.max_commits {
+                break;
+            }
+
+            let oid = match oid_result {
+                Ok(oid) => oid,
+                Err(_) => continue,
+            };
+
+            if let Ok(commit) = repo.find_commit(oid) {
+                visit(&commit);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Per-file changes of a commit against its first parent, with
+    /// zero-context hunks and renames detected.
+    fn file_changes(repo: &git2::Repository, commit: &git2::Commit) -> Vec<FileChange> {
+        let tree = match commit.tree() {
+            Ok(t) => t,
+            Err(_) => return vec![],
+        };
+        let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+
+        let mut opts = git2::DiffOptions::new();
+        opts.context_lines(0);
+        let mut diff = match repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut opts)) {
+            Ok(d) => d,
+            Err(_) => return vec![],
+        };
+        let _ = diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)));
+
+        let mut files = Vec::new();
+        for (idx, delta) in diff.deltas().enumerate() {
+            let kind = match delta.status() {
+                git2::Delta::Added => ChangeKind::Added,
+                git2::Delta::Deleted => ChangeKind::Deleted,
+                git2::Delta::Renamed => ChangeKind::Renamed,
+                _ => ChangeKind::Modified,
+            };
+            let new_path = delta.new_file().path().map(|p| p.to_string_lossy().to_string());
+            let old_path = delta.old_file().path().map(|p| p.to_string_lossy().to_string());
+            let path = match kind {
+                ChangeKind::Deleted => old_path.clone(),
+                _ => new_path,
+            };
+            let Some(path) = path else { continue };
+
+            let mut change = FileChange {
+                path,
+                old_path: if kind == ChangeKind::Renamed { old_path } else { None },
+                kind,
+                insertions: 0,
+                deletions: 0,
+                hunks: Vec::new(),
+            };
+            // Binary files have no patch; they count as a change without lines.
+            if let Ok(Some(patch)) = git2::Patch::from_diff(&diff, idx) {
+                if let Ok((_, insertions, deletions)) = patch.line_stats() {
+                    change.insertions = insertions as u32;
+                    change.deletions = deletions as u32;
+                }
+                for h in 0..patch.num_hunks() {
+                    if let Ok((hunk, _)) = patch.hunk(h) {
+                        change.hunks.push(Hunk {
+                            old_start: hunk.old_start(),
+                            old_lines: hunk.old_lines(),
+                            new_start: hunk.new_start(),
+                            new_lines: hunk.new_lines(),
+                        });
+                    }
+                }
+            }
+            files.push(change);
+        }
+        files
+    }
+
     /// Get diff stats for a commit.
     fn diff_stats(
         &self,
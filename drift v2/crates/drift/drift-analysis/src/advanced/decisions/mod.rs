@@ -3,12 +3,14 @@
 //! 12 decision categories, ADR detection, temporal correlation.
 
 pub mod types;
+#[cfg(feature = "native")]
 pub mod git_analysis;
 pub mod adr_detection;
 pub mod categorizer;
 pub mod temporal;
 
 pub use types::*;
+#[cfg(feature = "native")]
 pub use git_analysis::GitAnalyzer;
 pub use adr_detection::AdrDetector;
 pub use categorizer::DecisionCategorizer;
@@ -0,0 +1,264 @@
+//! Historical backfill — replay analysis across past commits to seed
+//! health trends.
+//!
+//! Walks the first-parent chain from HEAD, oldest first, reading each tree
+//! straight from the object database (no checkout). Between adjacent
+//! commits only files whose blob changed are re-parsed and re-detected;
+//! the `IncrementalAnalyzer` content-hash cache, fed with blob ids (git's
+//! content hash), decides what can be reused.
+
+use std::path::Path;
+
+use drift_core::config::ScanConfig;
+use drift_core::types::collections::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::types::PatternMatch;
+use crate::engine::visitor::{DetectionEngine, VisitorRegistry};
+use crate::engine::{AnalysisPipeline, IncrementalAnalyzer, ResolutionIndex};
+use crate::enforcement::audit::{DuplicateDetector, HealthScorer, PatternAuditData, PatternStatus};
+use crate::enforcement::gates::{GateInputBuilder, GateOrchestrator};
+use crate::enforcement::rules::types::PatternInfo;
+use crate::frameworks::{CompiledFrameworkPack, FrameworkMatcher};
+use crate::parsers::ParserManager;
+use crate::scanner::language_detect::Language;
+use crate::scanner::walker::PathFilter;
+
+/// Tuning for a backfill run.
+#[derive(Debug, Clone)]
+pub struct BackfillConfig {
+    /// First-parent commits to replay, counting back from HEAD.
+    pub max_commits: usize,
+    /// Scan settings (includes, extra ignores, max file size), applied to
+    /// each tree as the walker applies them to the working directory.
+    pub scan: ScanConfig,
+}
+
+impl Default for BackfillConfig {
+    fn default() -> Self {
+        Self {
+            max_commits: 50,
+            scan: ScanConfig::default(),
+        }
+    }
+}
+
+/// Health measured at one historical commit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackfillPoint {
+    pub sha: String,
+    /// Commit time (seconds since the epoch).
+    pub timestamp: i64,
+    /// Source files in the tree.
+    pub files: usize,
+    /// Files parsed for this commit (cache misses).
+    pub files_analyzed: usize,
+    /// Distinct detected patterns.
+    pub pattern_count: usize,
+    pub match_count: usize,
+    /// Violations across all quality gates.
+    pub violation_count: usize,
+    pub health_score: f64,
+}
+
+/// Replays detection, gates and health scoring over past commits.
+pub struct Backfiller {
+    config: BackfillConfig,
+    parser: ParserManager,
+    pipeline: AnalysisPipeline,
+    frameworks: FrameworkMatcher,
+    incremental: IncrementalAnalyzer,
+    /// Matches per file in the current tree.
+    matches: FxHashMap<String, Vec<PatternMatch>>,
+}
+
+impl Backfiller {
+    pub fn new(packs: Vec<CompiledFrameworkPack>, config: BackfillConfig) -> Self {
+        Self {
+            config,
+            parser: ParserManager::new(),
+            pipeline: AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new())),
+            frameworks: FrameworkMatcher::new(packs),
+            incremental: IncrementalAnalyzer::new(),
+            matches: FxHashMap::default(),
+        }
+    }
+
+    /// Replay up to `max_commits` first-parent commits, oldest first.
+    pub fn run(&mut self, repo_path: &Path) -> Result<Vec<BackfillPoint>, String> {
+        let repo = git2::Repository::open(repo_path)
+            .map_err(|e| format!("Failed to open repository: {}", e))?;
+        let chain = first_parent_chain(&repo, self.config.max_commits)?;
+        let filter = PathFilter::new(repo.workdir().unwrap_or(repo_path), &self.config.scan);
+
+        let mut points = Vec::with_capacity(chain.len());
+        let mut previous: Option<git2::Tree> = None;
+        for commit in chain.iter().rev() {
+            let tree = commit.tree().map_err(|e| format!("Failed to read tree: {}", e))?;
+            let files_analyzed = self.sync_tree(&repo, &filter, previous.as_ref(), &tree)?;
+            points.push(self.snapshot(commit, files_analyzed));
+            previous = Some(tree);
+        }
+        Ok(points)
+    }
+
+    /// Bring the per-file matches in line with `tree`, analyzing new and
+    /// changed blobs. Paths the scanner would skip are left out. Returns
+    /// the number of files analyzed.
+    fn sync_tree(
+        &mut self,
+        repo: &git2::Repository,
+        filter: &PathFilter,
+        previous: Option<&git2::Tree>,
+        tree: &git2::Tree,
+    ) -> Result<usize, String> {
+        let mut changed: Vec<(String, Option<git2::Oid>)> = Vec::new();
+        match previous {
+            None => {
+                tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                    if entry.kind() == Some(git2::ObjectType::Blob) {
+                        if let Some(name) = entry.name() {
+                            changed.push((format!("{dir}{name}"), Some(entry.id())));
+                        }
+                    }
+                    git2::TreeWalkResult::Ok
+                })
+                .map_err(|e| format!("Failed to walk tree: {}", e))?;
+            }
+            Some(previous) => {
+                let diff = repo
+                    .diff_tree_to_tree(Some(previous), Some(tree), None)
+                    .map_err(|e| format!("Failed to diff trees: {}", e))?;
+                for delta in diff.deltas() {
+                    if delta.status() == git2::Delta::Deleted {
+                        if let Some(path) = delta.old_file().path() {
+                            changed.push((path.to_string_lossy().to_string(), None));
+                        }
+                    } else if let Some(path) = delta.new_file().path() {
+                        changed.push((path.to_string_lossy().to_string(), Some(delta.new_file().id())));
+                    }
+                }
+            }
+        }
+
+        changed.retain(|(path, _)| !filter.is_excluded(Path::new(path)));
+
+        let mut analyzed = 0;
+        for (path, blob) in changed {
+            let Some(blob) = blob else {
+                self.forget(&path);
+                continue;
+            };
+            let hash = content_hash(blob);
+            if !self.incremental.needs_analysis(&path, hash) {
+                continue;
+            }
+            match self.analyze_blob(repo, &path, blob) {
+                Some(matches) => {
+                    analyzed += 1;
+                    self.incremental.update_hash(path.clone(), hash);
+                    self.matches.insert(path, matches);
+                }
+                None => self.forget(&path),
+            }
+        }
+        Ok(analyzed)
+    }
+
+    fn forget(&mut self, path: &str) {
+        self.matches.remove(path);
+        self.incremental.remove_files(&[path.into()]);
+    }
+
+    /// Detect patterns in one blob; `None` for unsupported, binary or
+    /// oversized files.
+    fn analyze_blob(&mut self, repo: &git2::Repository, path: &str, oid: git2::Oid) -> Option<Vec<PatternMatch>> {
+        let file_path = Path::new(path);
+        Language::from_extension(file_path.extension().and_then(|e| e.to_str()))?;
+        let blob = repo.find_blob(oid).ok()?;
+        if blob.is_binary() || blob.size() as u64 > self.config.scan.effective_max_file_size() {
+            return None;
+        }
+        let source = blob.content();
+
+        let Ok((parse_result, tree)) = self.parser.parse_returning_tree(source, file_path) else {
+            return Some(Vec::new());
+        };
+        let mut resolution_index = ResolutionIndex::new();
+        let result = self.pipeline.analyze_file_with_frameworks(
+            &parse_result,
+            source,
+            &tree,
+            &mut resolution_index,
+            &mut self.frameworks,
+        );
+        Some(result.matches)
+    }
+
+    /// Score the current tree as of `commit`.
+    fn snapshot(&self, commit: &git2::Commit, files_analyzed: usize) -> BackfillPoint {
+        let match_count = self.matches.values().map(Vec::len).sum();
+        let patterns = PatternInfo::group(self.matches.values().flatten().map(PatternInfo::from));
+
+        let audit: Vec<PatternAuditData> = patterns
+            .iter()
+            .map(|p| PatternAuditData {
+                id: p.pattern_id.clone(),
+                name: p.pattern_id.clone(),
+                category: p.category.clone(),
+                status: PatternStatus::Discovered,
+                confidence: p.confidence,
+                location_count: p.locations.len(),
+                outlier_count: p.outliers.len(),
+                in_call_graph: false,
+                constraint_issues: 0,
+                has_error_issues: false,
+                locations: p.locations.iter().map(|l| format!("{}:{}", l.file, l.line)).collect(),
+            })
+            .collect();
+        let duplicates = DuplicateDetector::new().detect(&audit);
+        let (health_score, _) = HealthScorer::new().compute(&audit, &duplicates);
+
+        let mut files: Vec<String> = self.matches.keys().cloned().collect();
+        files.sort();
+        let file_count = files.len();
+        let pattern_count = patterns.len();
+        let input = GateInputBuilder::new().files(files).patterns(patterns).build();
+        let violation_count = GateOrchestrator::new()
+            .execute(&input)
+            .map(|results| results.iter().map(|r| r.violations.len()).sum())
+            .unwrap_or(0);
+
+        BackfillPoint {
+            sha: commit.id().to_string(),
+            timestamp: commit.time().seconds(),
+            files: file_count,
+            files_analyzed,
+            pattern_count,
+            match_count,
+            violation_count,
+            health_score,
+        }
+    }
+}
+
+/// A blob id folded to the `IncrementalAnalyzer` hash width.
+fn content_hash(blob: git2::Oid) -> u64 {
+    let bytes = blob.as_bytes();
+    u64::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+}
+
+/// HEAD and up to `max - 1` first-parent ancestors, newest first.
+fn first_parent_chain(repo: &git2::Repository, max: usize) -> Result<Vec<git2::Commit<'_>>, String> {
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    let mut chain = Vec::new();
+    let mut next = Some(head);
+    while let Some(commit) = next.filter(|_| chain.len() < max) {
+        next = commit.parent(0).ok();
+        chain.push(commit);
+    }
+    Ok(chain)
+}
@@ -0,0 +1,217 @@
+//! File and function churn, with renames followed back from HEAD.
+//!
+//! Commits are processed newest first. Each HEAD function's line range is
+//! carried backwards through every diff of its file: hunks overlapping the
+//! range count as a change to the function, then the range is mapped onto
+//! the pre-commit line numbers. A range that maps to nothing marks the
+//! commit that introduced the function. Merges are skipped by the walker,
+//! so the mapping is exact along linear history and a close approximation
+//! across branches.
+
+use drift_core::types::collections::FxHashMap;
+
+use super::types::{ChangeKind, CommitChanges, FileChurn, FunctionChurn, FunctionSpan, Hunk};
+
+/// For each commit (newest first), the HEAD path of each changed file, or
+/// `None` for files that no longer exist under any HEAD path's history
+/// (an earlier file that was deleted and later re-created).
+pub fn resolve_head_paths(commits: &[CommitChanges]) -> Vec<Vec<Option<String>>> {
+    let mut aliases: FxHashMap<String, Option<String>> = FxHashMap::default();
+    commits
+        .iter()
+        .map(|commit| {
+            commit
+                .files
+                .iter()
+                .map(|change| {
+                    let head = aliases.get(&change.path).cloned().unwrap_or_else(|| Some(change.path.clone()));
+                    match change.kind {
+                        ChangeKind::Renamed => {
+                            if let Some(old) = &change.old_path {
+                                aliases.insert(old.clone(), head.clone());
+                            }
+                        }
+                        // Older changes at this path belong to a different file.
+                        ChangeKind::Added => {
+                            aliases.insert(change.path.clone(), None);
+                        }
+                        ChangeKind::Modified | ChangeKind::Deleted => {}
+                    }
+                    head
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Lines changed by a commit, for attributing work to its author.
+pub fn changed_lines(insertions: u32, deletions: u32) -> u32 {
+    (insertions + deletions).max(1)
+}
+
+#[derive(Default)]
+struct FileAccumulator {
+    commits: u32,
+    insertions: u32,
+    deletions: u32,
+    authors: FxHashMap<String, u32>,
+    first: Option<i64>,
+    last: Option<i64>,
+}
+
+/// Per-file churn keyed by HEAD path, by descending commit count.
+pub fn file_churn(commits: &[CommitChanges], head_paths: &[Vec<Option<String>>]) -> Vec<FileChurn> {
+    let mut files: FxHashMap<&str, FileAccumulator> = FxHashMap::default();
+    for (commit, heads) in commits.iter().zip(head_paths) {
+        for (change, head) in commit.files.iter().zip(heads) {
+            let Some(head) = head else { continue };
+            let acc = files.entry(head.as_str()).or_default();
+            acc.commits += 1;
+            acc.insertions += change.insertions;
+            acc.deletions += change.deletions;
+            *acc.authors.entry(commit.author.clone()).or_default() += changed_lines(change.insertions, change.deletions);
+            acc.first = Some(acc.first.map_or(commit.timestamp, |t| t.min(commit.timestamp)));
+            acc.last = Some(acc.last.map_or(commit.timestamp, |t| t.max(commit.timestamp)));
+        }
+    }
+
+    let mut churn: Vec<FileChurn> = files
+        .into_iter()
+        .map(|(file, acc)| {
+            let total: u32 = acc.authors.values().sum();
+            let top = acc.authors.iter().max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)));
+            FileChurn {
+                file: file.to_string(),
+                commits: acc.commits,
+                insertions: acc.insertions,
+                deletions: acc.deletions,
+                authors: acc.authors.len() as u32,
+                top_author: top.map(|(author, _)| author.clone()),
+                top_author_share: top.map_or(0.0, |(_, &changes)| changes as f64 / total.max(1) as f64),
+                first_changed_at: acc.first.unwrap_or(0),
+                last_changed_at: acc.last.unwrap_or(0),
+            }
+        })
+        .collect();
+    churn.sort_by(|a, b| b.commits.cmp(&a.commits).then_with(|| a.file.cmp(&b.file)));
+    churn
+}
+
+/// A HEAD function's range (1-based, inclusive) in the line numbering
+/// after the commit being processed.
+struct Tracked {
+    index: usize,
+    start: u32,
+    end: u32,
+}
+
+/// Per-function churn for the functions at HEAD, by descending commit count.
+pub fn function_churn(commits: &[CommitChanges], functions: &[FunctionSpan]) -> Vec<FunctionChurn> {
+    let mut churn: Vec<FunctionChurn> = functions
+        .iter()
+        .map(|f| FunctionChurn {
+            file: f.file.clone(),
+            name: f.name.clone(),
+            line: f.line,
+            end_line: f.end_line,
+            commits: 0,
+            lines_added: 0,
+            lines_deleted: 0,
+            last_changed_at: 0,
+        })
+        .collect();
+
+    // Keyed by the file's path after the commit being processed.
+    let mut tracked: FxHashMap<String, Vec<Tracked>> = FxHashMap::default();
+    for (index, f) in functions.iter().enumerate() {
+        tracked.entry(f.file.clone()).or_default().push(Tracked { index, start: f.line + 1, end: f.end_line + 1 });
+    }
+
+    for commit in commits {
+        for change in &commit.files {
+            let Some(mut spans) = tracked.remove(&change.path) else { continue };
+            let mut hunks = change.hunks.clone();
+            hunks.sort_by_key(|h| h.new_start);
+
+            for span in &spans {
+                let (added, deleted) = hunks.iter().fold((0, 0), |(a, d), h| {
+                    let (ha, hd) = overlap(h, span.start, span.end);
+                    (a + ha, d + hd)
+                });
+                if added + deleted > 0 {
+                    let f = &mut churn[span.index];
+                    f.commits += 1;
+                    f.lines_added += added;
+                    f.lines_deleted += deleted;
+                    f.last_changed_at = f.last_changed_at.max(commit.timestamp);
+                }
+            }
+
+            if change.kind == ChangeKind::Added {
+                continue;
+            }
+            spans.retain_mut(|span| match map_to_old(span.start, span.end, &hunks) {
+                Some((start, end)) => {
+                    span.start = start;
+                    span.end = end;
+                    true
+                }
+                None => false,
+            });
+            if !spans.is_empty() {
+                let before = change.old_path.clone().unwrap_or_else(|| change.path.clone());
+                tracked.entry(before).or_default().extend(spans);
+            }
+        }
+    }
+
+    churn.sort_by(|a, b| {
+        b.commits.cmp(&a.commits).then_with(|| a.file.cmp(&b.file)).then(a.line.cmp(&b.line))
+    });
+    churn
+}
+
+/// Lines a hunk added and deleted within `[start, end]` (new-side lines).
+fn overlap(h: &Hunk, start: u32, end: u32) -> (u32, u32) {
+    if h.new_lines == 0 {
+        // Pure deletion between new lines `new_start` and `new_start + 1`.
+        return if start <= h.new_start && h.new_start < end { (0, h.old_lines) } else { (0, 0) };
+    }
+    let hunk_end = h.new_start + h.new_lines - 1;
+    let lo = start.max(h.new_start);
+    let hi = end.min(hunk_end);
+    if lo > hi {
+        return (0, 0);
+    }
+    let added = hi - lo + 1;
+    (added, h.old_lines * added / h.new_lines)
+}
+
+/// Map a new-side range onto old-side lines; `None` if the commit added
+/// the whole range.
+fn map_to_old(start: u32, end: u32, hunks: &[Hunk]) -> Option<(u32, u32)> {
+    let old_start = map_line(start, hunks, true);
+    let old_end = map_line(end, hunks, false);
+    (old_start <= old_end && old_end > 0).then_some((old_start, old_end))
+}
+
+fn map_line(line: u32, hunks: &[Hunk], is_start: bool) -> u32 {
+    let mut delta: i64 = 0;
+    for h in hunks {
+        if h.new_lines > 0 && line >= h.new_start && line < h.new_start + h.new_lines {
+            // Inside a changed block: clamp to the old block's edge.
+            return match (is_start, h.old_lines) {
+                (true, 0) => h.old_start + 1,
+                (true, _) => h.old_start,
+                (false, 0) => h.old_start,
+                (false, n) => h.old_start + n - 1,
+            };
+        }
+        let after_line = if h.new_lines > 0 { h.new_start > line } else { h.new_start >= line };
+        if after_line {
+            break;
+        }
+        delta += h.old_lines as i64 - h.new_lines as i64;
+    }
+    (line as i64 + delta).max(0) as u32
+}
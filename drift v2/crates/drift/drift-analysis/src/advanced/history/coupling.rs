@@ -0,0 +1,53 @@
+//! Change coupling — files that keep changing in the same commits.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use super::types::{ChangeCoupling, HistoryConfig};
+
+/// Coupled file pairs from per-commit HEAD paths, by descending degree.
+pub fn change_coupling(head_paths: &[Vec<Option<String>>], config: &HistoryConfig) -> Vec<ChangeCoupling> {
+    let mut revisions: FxHashMap<&str, u32> = FxHashMap::default();
+    let mut pairs: FxHashMap<(&str, &str), u32> = FxHashMap::default();
+
+    for heads in head_paths {
+        let files: FxHashSet<&str> = heads.iter().flatten().map(String::as_str).collect();
+        for file in &files {
+            *revisions.entry(file).or_default() += 1;
+        }
+        if files.len() < 2 || files.len() > config.max_files_per_commit {
+            continue;
+        }
+        let mut files: Vec<&str> = files.into_iter().collect();
+        files.sort_unstable();
+        for (i, a) in files.iter().enumerate() {
+            for b in &files[i + 1..] {
+                *pairs.entry((a, b)).or_default() += 1;
+            }
+        }
+    }
+
+    let mut coupling: Vec<ChangeCoupling> = pairs
+        .into_iter()
+        .filter(|&(_, co)| co >= config.min_co_changes)
+        .map(|((a, b), co_changes)| {
+            let revisions_a = revisions[a];
+            let revisions_b = revisions[b];
+            ChangeCoupling {
+                file_a: a.to_string(),
+                file_b: b.to_string(),
+                co_changes,
+                revisions_a,
+                revisions_b,
+                degree: 2.0 * co_changes as f64 / (revisions_a + revisions_b) as f64,
+            }
+        })
+        .filter(|c| c.degree >= config.min_degree)
+        .collect();
+    coupling.sort_by(|x, y| {
+        y.degree
+            .total_cmp(&x.degree)
+            .then(y.co_changes.cmp(&x.co_changes))
+            .then_with(|| (&x.file_a, &x.file_b).cmp(&(&y.file_a, &y.file_b)))
+    });
+    coupling
+}
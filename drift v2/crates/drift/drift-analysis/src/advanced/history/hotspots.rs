@@ -0,0 +1,31 @@
+//! Hotspots — complex files that keep changing.
+
+use crate::structural::complexity::ComplexityIndex;
+
+use super::types::{FileChurn, Hotspot};
+
+/// Rank files by commits × summed cyclomatic complexity. Files without
+/// complexity metrics (non-source files, unparsed languages) are skipped.
+pub fn rank_hotspots(churn: &[FileChurn], complexity: &ComplexityIndex) -> Vec<Hotspot> {
+    let mut hotspots: Vec<Hotspot> = churn
+        .iter()
+        .filter_map(|f| {
+            let total: u32 = complexity.file_functions(&f.file).iter().map(|c| c.cyclomatic).sum();
+            (total > 0).then(|| Hotspot {
+                file: f.file.clone(),
+                commits: f.commits,
+                complexity: total,
+                score: f.commits as f64 * total as f64,
+            })
+        })
+        .collect();
+
+    let max = hotspots.iter().map(|h| h.score).fold(0.0, f64::max);
+    if max > 0.0 {
+        for h in &mut hotspots {
+            h.score /= max;
+        }
+    }
+    hotspots.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.file.cmp(&b.file)));
+    hotspots
+}
@@ -0,0 +1,46 @@
+//! History Analytics — churn, change coupling, hotspots and ownership, plus
+//! historical backfill of health trends.
+//!
+//! Built on the same git2 walker as decision mining
+//! (`GitAnalyzer::commit_changes`); the analyses work on the collected
+//! `CommitChanges`, newest first, so they can be tested without a
+//! repository. `backfill` replays detection over past trees instead.
+
+pub mod types;
+pub mod churn;
+pub mod coupling;
+pub mod hotspots;
+pub mod ownership;
+#[cfg(feature = "native")]
+pub mod backfill;
+
+pub use types::*;
+pub use churn::{file_churn, function_churn, resolve_head_paths};
+pub use coupling::change_coupling;
+pub use hotspots::rank_hotspots;
+pub use ownership::module_ownership;
+#[cfg(feature = "native")]
+pub use backfill::{BackfillConfig, BackfillPoint, Backfiller};
+
+use crate::structural::complexity::ComplexityIndex;
+
+/// Run every history analysis over `commits` (newest first). `functions`
+/// are the functions at HEAD that per-function churn is mapped onto.
+pub fn analyze(
+    commits: &[CommitChanges],
+    functions: &[FunctionSpan],
+    complexity: &ComplexityIndex,
+    config: &HistoryConfig,
+) -> HistoryReport {
+    let head_paths = resolve_head_paths(commits);
+    let file_churn = file_churn(commits, &head_paths);
+    let hotspots = rank_hotspots(&file_churn, complexity);
+    HistoryReport {
+        commits_analyzed: commits.len() as u32,
+        function_churn: function_churn(commits, functions),
+        coupling: change_coupling(&head_paths, config),
+        ownership: module_ownership(commits, &head_paths),
+        file_churn,
+        hotspots,
+    }
+}
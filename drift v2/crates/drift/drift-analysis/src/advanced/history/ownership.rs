@@ -0,0 +1,49 @@
+//! Author ownership and bus factor per module (directory).
+
+use drift_core::types::collections::FxHashMap;
+
+use crate::structural::module_of;
+
+use super::churn::changed_lines;
+use super::types::{AuthorShare, CommitChanges, ModuleOwnership};
+
+/// Ownership of every module touched by `commits`, by module path.
+pub fn module_ownership(commits: &[CommitChanges], head_paths: &[Vec<Option<String>>]) -> Vec<ModuleOwnership> {
+    let mut modules: FxHashMap<&str, FxHashMap<&str, u32>> = FxHashMap::default();
+    for (commit, heads) in commits.iter().zip(head_paths) {
+        for (change, head) in commit.files.iter().zip(heads) {
+            let Some(head) = head else { continue };
+            *modules.entry(module_of(head)).or_default().entry(&commit.author).or_default() +=
+                changed_lines(change.insertions, change.deletions);
+        }
+    }
+
+    let mut ownership: Vec<ModuleOwnership> = modules
+        .into_iter()
+        .map(|(module, authors)| {
+            let total_changes: u32 = authors.values().sum();
+            let mut authors: Vec<AuthorShare> = authors
+                .into_iter()
+                .map(|(author, changes)| AuthorShare {
+                    author: author.to_string(),
+                    changes,
+                    share: changes as f64 / total_changes.max(1) as f64,
+                })
+                .collect();
+            authors.sort_by(|a, b| b.changes.cmp(&a.changes).then_with(|| a.author.cmp(&b.author)));
+
+            let mut covered = 0;
+            let mut bus_factor = 0;
+            for a in &authors {
+                covered += a.changes;
+                bus_factor += 1;
+                if covered * 2 >= total_changes {
+                    break;
+                }
+            }
+            ModuleOwnership { module: module.to_string(), authors, bus_factor, total_changes }
+        })
+        .collect();
+    ownership.sort_by(|a, b| a.module.cmp(&b.module));
+    ownership
+}
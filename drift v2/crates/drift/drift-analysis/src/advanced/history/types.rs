@@ -0,0 +1,216 @@
+//! History analytics types — per-commit file changes and the churn,
+//! coupling, hotspot and ownership results derived from them.
+
+use drift_core::types::collections::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// A diff hunk in git's line numbering (1-based; a side with zero lines
+/// starts at the line *before* the change).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+}
+
+/// How a commit changed a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+/// One file touched by a commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChange {
+    /// Path after the commit (before it, for deletions).
+    pub path: String,
+    /// Path before the commit, for renames.
+    pub old_path: Option<String>,
+    pub kind: ChangeKind,
+    pub insertions: u32,
+    pub deletions: u32,
+    pub hunks: Vec<Hunk>,
+}
+
+/// A non-merge commit and the files it changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitChanges {
+    pub sha: String,
+    pub author: String,
+    pub timestamp: i64,
+    pub files: Vec<FileChange>,
+}
+
+/// A function as it exists at HEAD (0-based lines, as in `FunctionInfo`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSpan {
+    pub file: String,
+    pub name: String,
+    pub line: u32,
+    pub end_line: u32,
+}
+
+/// How often a file changed, and by whom.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileChurn {
+    /// Path at HEAD, with renames followed.
+    pub file: String,
+    pub commits: u32,
+    pub insertions: u32,
+    pub deletions: u32,
+    pub authors: u32,
+    pub top_author: Option<String>,
+    /// Top author's share of changed lines (0.0–1.0).
+    pub top_author_share: f64,
+    pub first_changed_at: i64,
+    pub last_changed_at: i64,
+}
+
+/// How often a function changed, from hunks mapped onto its line range.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionChurn {
+    pub file: String,
+    pub name: String,
+    /// 0-based start line at HEAD.
+    pub line: u32,
+    pub end_line: u32,
+    pub commits: u32,
+    pub lines_added: u32,
+    pub lines_deleted: u32,
+    pub last_changed_at: i64,
+}
+
+/// Two files that tend to change in the same commits.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeCoupling {
+    /// Lexicographically smaller path of the pair.
+    pub file_a: String,
+    pub file_b: String,
+    pub co_changes: u32,
+    pub revisions_a: u32,
+    pub revisions_b: u32,
+    /// Co-changes over the average revision count of the pair (0.0–1.0).
+    pub degree: f64,
+}
+
+/// A file ranked by churn × complexity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hotspot {
+    pub file: String,
+    pub commits: u32,
+    /// Summed cyclomatic complexity of the file's functions.
+    pub complexity: u32,
+    /// commits × complexity, normalized so the top hotspot scores 1.0.
+    pub score: f64,
+}
+
+/// An author's share of a module's changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorShare {
+    pub author: String,
+    /// Changed lines (insertions + deletions) attributed to the author.
+    pub changes: u32,
+    pub share: f64,
+}
+
+/// Who knows a module (directory).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleOwnership {
+    pub module: String,
+    /// Authors by descending share.
+    pub authors: Vec<AuthorShare>,
+    /// Fewest authors that together made at least half of the changes.
+    pub bus_factor: u32,
+    pub total_changes: u32,
+}
+
+impl ModuleOwnership {
+    pub fn primary_owner(&self) -> Option<&AuthorShare> {
+        self.authors.first()
+    }
+}
+
+/// Tuning for history analytics.
+#[derive(Debug, Clone)]
+pub struct HistoryConfig {
+    /// Minimum shared commits before a pair counts as coupled.
+    pub min_co_changes: u32,
+    /// Minimum coupling degree to report.
+    pub min_degree: f64,
+    /// Commits touching more files than this (mass reformatting, vendoring)
+    /// are left out of coupling.
+    pub max_files_per_commit: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            min_co_changes: 3,
+            min_degree: 0.3,
+            max_files_per_commit: 50,
+        }
+    }
+}
+
+/// Everything derived from one history walk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HistoryReport {
+    pub commits_analyzed: u32,
+    /// By descending commit count.
+    pub file_churn: Vec<FileChurn>,
+    /// By descending commit count.
+    pub function_churn: Vec<FunctionChurn>,
+    /// By descending degree.
+    pub coupling: Vec<ChangeCoupling>,
+    /// By descending score.
+    pub hotspots: Vec<Hotspot>,
+    /// By module path.
+    pub ownership: Vec<ModuleOwnership>,
+}
+
+/// Function change frequency looked up by file and start line, for
+/// impact scoring.
+#[derive(Debug, Clone, Default)]
+pub struct ChurnIndex {
+    commits: FxHashMap<(String, u32), u32>,
+    max_commits: u32,
+}
+
+impl ChurnIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_function_churn<'a>(churn: impl IntoIterator<Item = &'a FunctionChurn>) -> Self {
+        let mut index = Self::new();
+        for f in churn {
+            index.insert(&f.file, f.line, f.commits);
+        }
+        index
+    }
+
+    pub fn insert(&mut self, file: &str, line: u32, commits: u32) {
+        self.max_commits = self.max_commits.max(commits);
+        self.commits.insert((file.to_string(), line), commits);
+    }
+
+    /// Commits that touched the function starting at `line` in `file`.
+    pub fn commits(&self, file: &str, line: u32) -> Option<u32> {
+        self.commits.get(&(file.to_string(), line)).copied()
+    }
+
+    /// Change frequency relative to the most-changed function (0.0–1.0).
+    pub fn change_frequency(&self, file: &str, line: u32) -> Option<f32> {
+        let commits = self.commits(file, line)?;
+        Some(commits as f32 / self.max_commits.max(1) as f32)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commits.is_empty()
+    }
+}
@@ -3,7 +3,9 @@
 //! Level 4 leaf systems built on the full Drift analysis stack:
 //! - Simulation Engine: Monte Carlo effort estimation with 13 task categories
 //! - Decision Mining: git2-based institutional decision extraction
+//! - History Analytics: churn, change coupling, hotspots and ownership
 //! - N+1 Query Detection: Advanced ORM-aware loop-query detection
 
 pub mod simulation;
 pub mod decisions;
+pub mod history;
@@ -24,9 +24,9 @@ pub struct ComplexityScorer;
 impl Scorer for ComplexityScorer {
     fn score(&self, task: &SimulationTask, _approach: &SimulationApproach) -> f64 {
         let ctx = &task.context;
-        // Normalize: complexity 0-50 maps to 0.0-1.0
-        let cyclomatic = (ctx.avg_complexity / 50.0).clamp(0.0, 1.0);
-        let cognitive = (ctx.avg_cognitive_complexity / 50.0).clamp(0.0, 1.0);
+        // Normalize per-function averages: cyclomatic saturates at 20, cognitive at 30
+        let cyclomatic = (ctx.avg_complexity / 20.0).clamp(0.0, 1.0);
+        let cognitive = (ctx.avg_cognitive_complexity / 30.0).clamp(0.0, 1.0);
         // Weighted average: cognitive complexity is harder to reason about
         let raw = cyclomatic * 0.4 + cognitive * 0.6;
         raw.clamp(0.0, 1.0)
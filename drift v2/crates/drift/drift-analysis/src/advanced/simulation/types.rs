@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::structural::complexity::ComplexitySummary;
+
 /// 13 task categories for simulation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -77,9 +79,9 @@ impl std::fmt::Display for TaskCategory {
 /// Context for a simulation task — metrics from the analysis stack.
 #[derive(Debug, Clone, Default, serde::Deserialize)]
 pub struct SimulationContext {
-    /// Average cyclomatic complexity of affected files.
+    /// Average per-function cyclomatic complexity of affected files.
     pub avg_complexity: f64,
-    /// Average cognitive complexity of affected files.
+    /// Average per-function cognitive complexity of affected files.
     pub avg_cognitive_complexity: f64,
     /// Blast radius (transitive caller count) of affected functions.
     pub blast_radius: u32,
@@ -97,6 +99,21 @@ pub struct SimulationContext {
     pub coupling_instability: f64,
 }
 
+impl SimulationContext {
+    /// Fill complexity averages the caller left unset from measured metrics.
+    pub fn fill_complexity(&mut self, summary: &ComplexitySummary) {
+        if summary.function_count == 0 {
+            return;
+        }
+        if self.avg_complexity == 0.0 {
+            self.avg_complexity = summary.avg_cyclomatic;
+        }
+        if self.avg_cognitive_complexity == 0.0 {
+            self.avg_cognitive_complexity = summary.avg_cognitive;
+        }
+    }
+}
+
 /// A simulation task to evaluate.
 #[derive(Debug, Clone)]
 pub struct SimulationTask {
@@ -3,11 +3,14 @@
 //! Phase 1 (Learn): Detect frameworks, extract models and fields.
 //! Phase 2 (Detect): Identify sensitive fields and data boundaries.
 
+use std::collections::HashMap;
+
 use drift_core::errors::BoundaryError;
 
 use crate::parsers::types::ParseResult;
 
 use super::extractors::{self, FieldExtractor};
+use super::schema::{self, DatabaseSchema, MigrationFile};
 use super::sensitive::SensitiveFieldDetector;
 use super::types::{
     BoundaryScanResult, FrameworkSignature, OrmFramework,
@@ -35,6 +38,17 @@ impl BoundaryDetector {
     pub fn detect(
         &self,
         parse_results: &[ParseResult],
+    ) -> Result<BoundaryScanResult, BoundaryError> {
+        self.detect_with_sources(parse_results, &HashMap::new())
+    }
+
+    /// Run boundary detection with file contents keyed by `ParseResult::file`,
+    /// so source-level extractors (GORM, Diesel, Drizzle, ...) can read struct
+    /// tags, attributes and builder chains.
+    pub fn detect_with_sources(
+        &self,
+        parse_results: &[ParseResult],
+        sources: &HashMap<String, String>,
     ) -> Result<BoundaryScanResult, BoundaryError> {
         let mut result = BoundaryScanResult::default();
 
@@ -43,9 +57,13 @@ impl BoundaryDetector {
         result.frameworks_detected = detected_frameworks.clone();
 
         for pr in parse_results {
+            let source = sources.get(&pr.file);
             for extractor in &self.extractors {
                 if detected_frameworks.contains(&extractor.framework()) {
-                    let models = extractor.extract_models(pr);
+                    let models = match source {
+                        Some(source) => extractor.extract_models_from_source(pr, source),
+                        None => extractor.extract_models(pr),
+                    };
                     for model in models {
                         result.total_fields += model.fields.len();
                         result.models.push(model);
@@ -64,6 +82,40 @@ impl BoundaryDetector {
         Ok(result)
     }
 
+    /// Run boundary detection and reconcile the models against the schema
+    /// reconstructed from `migrations`. Sensitive columns that no model
+    /// exposes are reported against the migration that created them.
+    pub fn detect_with_migrations(
+        &self,
+        parse_results: &[ParseResult],
+        sources: &HashMap<String, String>,
+        migrations: &[MigrationFile],
+    ) -> Result<BoundaryScanResult, BoundaryError> {
+        let mut result = self.detect_with_sources(parse_results, sources)?;
+        if migrations.is_empty() {
+            return Ok(result);
+        }
+
+        let db = DatabaseSchema::replay(migrations);
+        result.schema_drift = schema::compare_models(&db, &result.models);
+
+        for field in self.sensitive_detector.detect_schema_columns(&db) {
+            let covered = result.sensitive_fields.iter().any(|existing| {
+                existing.field_name.eq_ignore_ascii_case(&field.field_name)
+                    && result.models.iter().any(|m| {
+                        m.name == existing.model_name
+                            && m.table_name.as_deref().unwrap_or(&m.name).eq_ignore_ascii_case(&field.model_name)
+                    })
+            });
+            if !covered {
+                result.sensitive_fields.push(field);
+            }
+        }
+        result.total_sensitive = result.sensitive_fields.len();
+        result.schema = Some(db);
+        Ok(result)
+    }
+
     /// Detect which ORM frameworks are used in the codebase.
     fn detect_frameworks(&self, parse_results: &[ParseResult]) -> Vec<OrmFramework> {
         let mut detected = Vec::new();
@@ -153,5 +205,47 @@ fn build_framework_signatures() -> Vec<FrameworkSignature> {
             decorator_patterns: vec![],
             schema_file_patterns: vec!["*.php".into()],
         },
+        FrameworkSignature {
+            framework: OrmFramework::Gorm,
+            import_patterns: vec!["gorm.io/gorm".into(), "jinzhu/gorm".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.go".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Sqlx,
+            import_patterns: vec!["jmoiron/sqlx".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.go".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Diesel,
+            import_patterns: vec!["diesel".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["schema.rs".into(), "models.rs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::SeaOrm,
+            import_patterns: vec!["sea_orm".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["entities/*.rs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::SqlxRust,
+            import_patterns: vec!["sqlx::".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.rs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Drizzle,
+            import_patterns: vec!["drizzle-orm".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["schema.ts".into(), "*.schema.ts".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Exposed,
+            import_patterns: vec!["org.jetbrains.exposed".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.kt".into()],
+        },
     ]
 }
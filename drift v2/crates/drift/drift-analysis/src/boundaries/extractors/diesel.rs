@@ -0,0 +1,198 @@
+//! Diesel field extractor (Rust).
+//!
+//! Reads `table!` schema macros (the authoritative column list) and model
+//! structs deriving `Queryable`/`Selectable`/`Insertable`/`AsChangeset`,
+//! with `#[diesel(...)]` table, column, primary key and `belongs_to`
+//! attributes.
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs::{self, SourceStruct};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DieselExtractor;
+
+const MODEL_DERIVES: &[&str] = &["Queryable", "Selectable", "Insertable", "AsChangeset", "Identifiable", "QueryableByName"];
+
+impl FieldExtractor for DieselExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Diesel }
+    fn schema_file_patterns(&self) -> &[&str] { &["schema.rs", "models.rs"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Rust || !source.contains("diesel") && !source.contains("table!") {
+            return Vec::new();
+        }
+        let mut models = table_macros(pr, source);
+        for s in structs::rust_structs(source) {
+            if s.derives().iter().any(|d| MODEL_DERIVES.contains(d)) {
+                models.push(struct_model(pr, &s));
+            }
+        }
+        models
+    }
+}
+
+fn struct_model(pr: &ParseResult, s: &SourceStruct) -> ExtractedModel {
+    // Legacy attributes (`#[table_name = "users"]`, `#[belongs_to(User)]`)
+    // are read alongside `#[diesel(...)]`.
+    let mut args = structs::attr_args(&s.attrs, "diesel");
+    args.extend(s.attrs.iter().map(String::as_str).filter(|a| a.starts_with("table_name") || a.starts_with("belongs_to") || a.starts_with("primary_key")));
+
+    let table = structs::attr_value(&args, "table_name")
+        .map(|t| t.rsplit("::").next().unwrap_or(t).to_string())
+        .unwrap_or_else(|| structs::plural_table(&s.name));
+    let primary_key: Vec<&str> = args
+        .iter()
+        .filter_map(|a| a.strip_prefix("primary_key").map(str::trim))
+        .filter_map(|a| a.strip_prefix('(').and_then(|a| a.strip_suffix(')')))
+        .flat_map(structs::split_top)
+        .collect();
+
+    let relationships = args
+        .iter()
+        .filter_map(|a| a.strip_prefix("belongs_to")?.trim().strip_prefix('(')?.strip_suffix(')'))
+        .filter_map(|inner| {
+            let parts = structs::split_top(inner);
+            let target = parts.first()?.rsplit("::").next()?.trim().to_string();
+            let foreign_key = structs::attr_value(&parts, "foreign_key")
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{}_id", structs::snake_case(&target)));
+            Some(Relationship { kind: RelationshipKind::BelongsTo, target_model: target, foreign_key: Some(foreign_key) })
+        })
+        .collect();
+
+    let fields = s
+        .fields
+        .iter()
+        .map(|f| {
+            let field_args = structs::attr_args(&f.attrs, "diesel");
+            let name = structs::attr_value(&field_args, "column_name").unwrap_or(&f.name).to_string();
+            ExtractedField {
+                is_primary_key: if primary_key.is_empty() { name == "id" } else { primary_key.contains(&f.name.as_str()) },
+                name,
+                field_type: Some(f.ty.clone()),
+                is_nullable: structs::option_inner(&f.ty).is_some(),
+                is_unique: false,
+                default_value: None,
+                line: f.line,
+            }
+        })
+        .collect();
+
+    ExtractedModel {
+        name: s.name.clone(),
+        table_name: Some(table),
+        file: pr.file.clone(),
+        line: s.line,
+        framework: OrmFramework::Diesel,
+        fields,
+        relationships,
+        confidence: 0.90,
+    }
+}
+
+/// `diesel::table! { users (id) { id -> Int4, ssn -> Nullable<Text>, } }`
+/// plus `joinable!(posts -> users (user_id));`.
+fn table_macros(pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+    let mut models: Vec<ExtractedModel> = Vec::new();
+    let mut rest = 0;
+    while let Some(pos) = source[rest..].find("table!") {
+        let start = rest + pos;
+        rest = start + 6;
+        if source[..start].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let Some(open) = source[rest..].find('{').map(|p| rest + p) else { break };
+        let Some(close) = matching_brace(source, open) else { break };
+        rest = close;
+        parse_tables(pr, source, open + 1, close, &mut models);
+    }
+
+    let mut rest = source;
+    while let Some(pos) = rest.find("joinable!(") {
+        let body = &rest[pos + 10..];
+        rest = body;
+        let Some(end) = body.find(')') else { break };
+        // `posts -> users (user_id`
+        let Some((child, parent)) = body[..end].split_once("->") else { continue };
+        let Some((parent, fk)) = parent.split_once('(') else { continue };
+        let (child, parent, fk) = (child.trim(), parent.trim(), fk.trim());
+        if let Some(model) = models.iter_mut().find(|m| m.name == child) {
+            model.relationships.push(Relationship {
+                kind: RelationshipKind::BelongsTo,
+                target_model: parent.to_string(),
+                foreign_key: Some(fk.to_string()),
+            });
+        }
+    }
+    models
+}
+
+fn parse_tables(pr: &ParseResult, source: &str, start: usize, end: usize, models: &mut Vec<ExtractedModel>) {
+    let mut pos = start;
+    while pos < end {
+        let body = &source[pos..end];
+        let Some(open) = body.find('{') else { break };
+        let header = body[..open].trim();
+        // Skip `use` lines and `#[sql_name = ..]` attributes in the header.
+        let header = header.lines().map(str::trim).rfind(|l| !l.is_empty() && !l.starts_with("use ") && !l.starts_with('#')).unwrap_or("");
+        let (name, keys) = match header.split_once('(') {
+            Some((name, keys)) => (name.trim(), keys.trim_end_matches(')').split(',').map(str::trim).collect::<Vec<_>>()),
+            None => (header, vec!["id"]),
+        };
+        let name = name.rsplit('.').next().unwrap_or(name).to_string();
+        let Some(close) = matching_brace(source, pos + open) else { break };
+        let fields = source[pos + open + 1..close]
+            .lines()
+            .enumerate()
+            .filter_map(|(offset, line)| {
+                let (column, ty) = line.trim().trim_end_matches(',').split_once("->")?;
+                let (column, ty) = (column.trim().trim_start_matches("r#"), ty.trim());
+                Some(ExtractedField {
+                    name: column.to_string(),
+                    field_type: Some(ty.to_string()),
+                    is_primary_key: keys.contains(&column),
+                    is_nullable: ty.starts_with("Nullable<"),
+                    is_unique: false,
+                    default_value: None,
+                    line: structs::line_of(source, pos + open + 1) + offset as u32,
+                })
+            })
+            .collect();
+        if !name.is_empty() {
+            models.push(ExtractedModel {
+                table_name: Some(name.clone()),
+                name,
+                file: pr.file.clone(),
+                line: structs::line_of(source, pos + open),
+                framework: OrmFramework::Diesel,
+                fields,
+                relationships: Vec::new(),
+                confidence: 0.95,
+            });
+        }
+        pos = close + 1;
+    }
+}
+
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
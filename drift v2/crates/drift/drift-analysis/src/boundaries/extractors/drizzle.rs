@@ -0,0 +1,243 @@
+//! Drizzle ORM field extractor (TypeScript/JavaScript).
+//!
+//! Tables are `pgTable`/`mysqlTable`/`sqliteTable` calls assigned to a
+//! variable; columns come from the builder chains (`varchar('email')
+//! .notNull().unique()`), relationships from `.references()` and
+//! `relations()` declarations.
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DrizzleExtractor;
+
+const TABLE_FUNCTIONS: &[&str] = &["pgTable(", "mysqlTable(", "sqliteTable(", ".table("];
+
+impl FieldExtractor for DrizzleExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Drizzle }
+    fn schema_file_patterns(&self) -> &[&str] { &["schema.ts", "*.schema.ts", "db/schema/*.ts"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if !matches!(pr.language, Language::TypeScript | Language::JavaScript) || !source.contains("drizzle-orm") {
+            return Vec::new();
+        }
+        let mut models = Vec::new();
+        let mut pos = 0;
+        while let Some((call, open)) = next_table_call(source, pos) {
+            pos = open + 1;
+            let Some(variable) = assigned_variable(&source[..call]) else { continue };
+            let Some(close) = matching(source, open) else { break };
+            pos = close;
+            let args = split_top(source, open + 1, close);
+            let Some(&(_, table)) = args.first() else { continue };
+            let Some(table) = string_literal(table) else { continue };
+            let Some(&(columns_at, columns)) = args.get(1) else { continue };
+            if !columns.starts_with('{') {
+                continue;
+            }
+            let (fields, relationships) = columns_of(source, columns_at, columns_at + columns.len() - 1);
+            models.push(ExtractedModel {
+                name: variable.to_string(),
+                table_name: Some(table.to_string()),
+                file: pr.file.clone(),
+                line: structs::line_of(source, call),
+                framework: OrmFramework::Drizzle,
+                fields,
+                relationships,
+                confidence: 0.90,
+            });
+        }
+        attach_relations(source, &mut models);
+        models
+    }
+}
+
+fn next_table_call(source: &str, from: usize) -> Option<(usize, usize)> {
+    TABLE_FUNCTIONS
+        .iter()
+        .filter_map(|f| source[from..].find(f).map(|p| (from + p, from + p + f.len() - 1)))
+        .min()
+}
+
+/// `export const users = ` before the call → `users`.
+fn assigned_variable(before: &str) -> Option<&str> {
+    let line = before.rsplit('\n').next()?;
+    let (lhs, call) = line.split_once('=')?;
+    // `pgSchema('app').table(` keeps the receiver on the same side as the call.
+    if !call.trim().chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '(' || c == ')' || c == '\'' || c == '"') {
+        return None;
+    }
+    let name = lhs.split_whitespace().last()?.trim_end_matches(':');
+    (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')).then_some(name)
+}
+
+fn columns_of(source: &str, open: usize, close: usize) -> (Vec<ExtractedField>, Vec<Relationship>) {
+    let mut fields = Vec::new();
+    let mut relationships = Vec::new();
+    for (at, entry) in split_top(source, open + 1, close) {
+        let Some((key, expr)) = entry.split_once(':') else { continue };
+        let key = key.trim().trim_matches(['\'', '"']);
+        let expr = expr.trim();
+        let Some(paren) = expr.find('(') else { continue };
+        let builder = expr[..paren].rsplit('.').next().unwrap_or("").trim();
+        let args_close = matching(expr, paren).unwrap_or(expr.len() - 1);
+        let column = split_top(expr, paren + 1, args_close)
+            .first()
+            .and_then(|(_, a)| string_literal(a))
+            .unwrap_or(key);
+        let chain = &expr[args_close..];
+        let primary = chain.contains(".primaryKey(");
+        let not_null = chain.contains(".notNull(") || primary || builder.ends_with("serial") || builder == "serial";
+        let default_value = chain.find(".default(").map(|d| {
+            let value = &chain[d + 9..];
+            value[..matching(chain, d + 8).map(|c| c - d - 9).unwrap_or(value.len())].trim().to_string()
+        });
+        let default_value = default_value.or_else(|| {
+            ["defaultNow", "defaultRandom", "$defaultFn", "$default"]
+                .iter()
+                .find(|d| chain.contains(&format!(".{d}(")))
+                .map(|d| format!("{d}()"))
+        });
+        if let Some(r) = chain.find(".references(") {
+            // `.references(() => users.id)`
+            let target = chain[r + 12..]
+                .split("=>")
+                .nth(1)
+                .and_then(|t| t.trim().split('.').next())
+                .map(|t| t.trim().to_string());
+            if let Some(target) = target {
+                relationships.push(Relationship { kind: RelationshipKind::BelongsTo, target_model: target, foreign_key: Some(column.to_string()) });
+            }
+        }
+        fields.push(ExtractedField {
+            name: column.to_string(),
+            field_type: Some(builder.to_string()),
+            is_primary_key: primary,
+            is_nullable: !not_null,
+            is_unique: chain.contains(".unique("),
+            default_value,
+            line: structs::line_of(source, at),
+        });
+    }
+    (fields, relationships)
+}
+
+/// `relations(users, ({ many, one }) => ({ posts: many(posts), ... }))`
+fn attach_relations(source: &str, models: &mut [ExtractedModel]) {
+    let mut pos = 0;
+    while let Some(p) = source[pos..].find("relations(") {
+        let open = pos + p + "relations".len();
+        pos = open + 1;
+        if source[..open - "relations".len()].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let Some(close) = matching(source, open) else { break };
+        let args = split_top(source, open + 1, close);
+        let Some(model) = args.first().and_then(|(_, owner)| models.iter_mut().find(|m| m.name == *owner)) else { continue };
+        let Some(&(body_at, body)) = args.get(1) else { continue };
+        for (kind, marker) in [(RelationshipKind::HasMany, "many("), (RelationshipKind::HasOne, "one(")] {
+            let mut rest = body;
+            while let Some(q) = rest.find(marker) {
+                let call_at = body_at + (body.len() - rest.len()) + q;
+                rest = &rest[q + marker.len()..];
+                if source[..call_at].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let Some(call_close) = matching(source, call_at + marker.len() - 1) else { continue };
+                let call_args = split_top(source, call_at + marker.len(), call_close);
+                let Some(&(_, target)) = call_args.first() else { continue };
+                // `one(users, { fields: [posts.authorId], ... })` owns the key.
+                let fields = call_args.get(1).map(|(_, c)| *c).unwrap_or("");
+                let (kind, foreign_key) = match fields.find("fields:") {
+                    Some(f) => {
+                        let key = fields[f + 7..].trim().trim_start_matches('[').split([',', ']']).next().unwrap_or("");
+                        (RelationshipKind::BelongsTo, key.rsplit('.').next().map(|k| k.trim().to_string()))
+                    }
+                    None => (kind, None),
+                };
+                if !model.relationships.iter().any(|r| r.target_model == target && r.kind == kind) {
+                    model.relationships.push(Relationship { kind, target_model: target.to_string(), foreign_key });
+                }
+            }
+        }
+    }
+}
+
+fn string_literal(text: &str) -> Option<&str> {
+    let text = text.trim();
+    let quote = text.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+    text[1..].strip_suffix(quote).filter(|s| !s.contains(quote))
+}
+
+/// Index of the bracket closing the one at `open`, skipping JS strings.
+fn matching(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in text[open..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Top-level comma-separated parts of `text[start..end]` with offsets.
+fn split_top(text: &str, start: usize, end: usize) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut i = start;
+    let mut part_start = start;
+    while i < end {
+        let c = text[i..].chars().next().unwrap_or(' ');
+        match c {
+            '(' | '[' | '{' | '\'' | '"' | '`' => {
+                let close = if matches!(c, '\'' | '"' | '`') {
+                    text[i + 1..end].find(c).map(|p| i + 1 + p)
+                } else {
+                    matching(text, i)
+                };
+                i = close.unwrap_or(end - 1) + 1;
+                continue;
+            }
+            ',' => {
+                parts.push((part_start, i));
+                part_start = i + 1;
+            }
+            _ => {}
+        }
+        i += c.len_utf8();
+    }
+    parts.push((part_start, end));
+    parts
+        .into_iter()
+        .map(|(s, e)| {
+            let raw = &text[s..e.min(text.len())];
+            (s + raw.len() - raw.trim_start().len(), raw.trim())
+        })
+        .filter(|(_, p)| !p.is_empty())
+        .collect()
+}
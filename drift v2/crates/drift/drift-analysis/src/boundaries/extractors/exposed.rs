@@ -0,0 +1,145 @@
+//! Exposed field extractor (Kotlin).
+//!
+//! Tables are `object Users : Table("users")` (or `IntIdTable`/`LongIdTable`/
+//! `UUIDTable`) declarations; columns are `val x = varchar("x", 255)...`
+//! properties, and `reference()`/`optReference()` columns are foreign keys.
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct ExposedExtractor;
+
+const TABLE_BASES: &[&str] = &["Table", "IntIdTable", "LongIdTable", "UUIDTable", "ULongIdTable", "IdTable", "CompositeIdTable"];
+
+impl FieldExtractor for ExposedExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Exposed }
+    fn schema_file_patterns(&self) -> &[&str] { &["*Table.kt", "*Tables.kt", "Schema.kt"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Kotlin || !source.contains("org.jetbrains.exposed") {
+            return Vec::new();
+        }
+        let lines: Vec<&str> = source.lines().collect();
+        let mut models = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let Some((name, base, table_arg)) = table_header(lines[i]) else {
+                i += 1;
+                continue;
+            };
+            let start = i;
+            let mut depth = lines[i].matches('{').count() as i32 - lines[i].matches('}').count() as i32;
+            let mut fields: Vec<(String, ExtractedField)> = Vec::new();
+            let mut relationships = Vec::new();
+            let mut primary_key: Vec<String> = Vec::new();
+            i += 1;
+            while i < lines.len() && depth > 0 {
+                let line = lines[i].trim();
+                if depth == 1 {
+                    if let Some(keys) = line.strip_prefix("override val primaryKey").and_then(|r| r.split_once("PrimaryKey(")) {
+                        primary_key = keys.1.split([',', ')']).map(|k| k.trim().to_string()).filter(|k| !k.is_empty() && !k.contains('=')).collect();
+                    } else if let Some((property, field, reference)) = column(line, i as u32) {
+                        if let Some(target) = reference {
+                            relationships.push(Relationship { kind: RelationshipKind::BelongsTo, target_model: target, foreign_key: Some(field.name.clone()) });
+                        }
+                        fields.push((property, field));
+                    }
+                }
+                depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+                i += 1;
+            }
+            if base != "Table" && !fields.iter().any(|(_, f)| f.name == "id") {
+                // Id tables declare their `id` column implicitly.
+                fields.insert(0, ("id".to_string(), ExtractedField {
+                    name: "id".to_string(),
+                    field_type: Some(base.trim_end_matches("IdTable").trim_end_matches("Table").to_string()),
+                    is_primary_key: true,
+                    is_nullable: false,
+                    is_unique: false,
+                    default_value: None,
+                    line: start as u32,
+                }));
+            }
+            let fields = fields
+                .into_iter()
+                .map(|(property, mut field)| {
+                    field.is_primary_key |= primary_key.contains(&property);
+                    field
+                })
+                .collect();
+            models.push(ExtractedModel {
+                table_name: Some(table_arg.unwrap_or_else(|| name.strip_suffix("Table").filter(|n| !n.is_empty()).unwrap_or(&name).to_string())),
+                name,
+                file: pr.file.clone(),
+                line: start as u32,
+                framework: OrmFramework::Exposed,
+                fields,
+                relationships,
+                confidence: 0.90,
+            });
+        }
+        models
+    }
+}
+
+/// `object Users : IntIdTable("users") {` → `(Users, IntIdTable, Some(users))`.
+fn table_header(line: &str) -> Option<(String, &str, Option<String>)> {
+    let rest = line.trim().strip_prefix("internal ").unwrap_or(line.trim());
+    let rest = rest.strip_prefix("object ")?;
+    let (name, base) = rest.split_once(':')?;
+    let base = base.trim();
+    let base_name = base.split(['(', '<', ' ', '{']).next()?.trim();
+    if !TABLE_BASES.contains(&base_name) || !line.contains('{') {
+        return None;
+    }
+    let table = base
+        .split_once('(')
+        .and_then(|(_, args)| args.split(')').next())
+        .and_then(|args| structs::split_top(args).into_iter().find(|a| a.starts_with('"') || a.starts_with("name =")))
+        .map(|a| a.trim_start_matches("name =").trim().trim_matches('"').to_string());
+    Some((name.trim().to_string(), base_name, table))
+}
+
+/// `val ssn = varchar("ssn", 11).nullable()` → property, field and the
+/// referenced table for `reference("org_id", Orgs)`.
+fn column(line: &str, line_no: u32) -> Option<(String, ExtractedField, Option<String>)> {
+    let rest = line.strip_prefix("val ")?;
+    let (property, expr) = rest.split_once('=')?;
+    let property = property.trim().split(':').next()?.trim().to_string();
+    let expr = expr.trim();
+    let open = expr.find('(')?;
+    let builder = expr[..open].trim();
+    if !builder.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    let close = expr[open..].find(')').map(|c| open + c)?;
+    let args = structs::split_top(&expr[open + 1..close]);
+    let column = args.first()?.trim_matches('"').to_string();
+    let chain = &expr[close..];
+    let reference = matches!(builder, "reference" | "optReference")
+        .then(|| args.get(1).map(|t| t.trim().to_string()))
+        .flatten();
+    let default_value = [".default(", ".defaultExpression(", ".clientDefault"]
+        .iter()
+        .find_map(|d| chain.find(d).map(|p| chain[p + d.len()..].trim_start_matches(['(', '{', ' ']).split([')', '}']).next().unwrap_or("").trim().to_string()));
+    Some((
+        property,
+        ExtractedField {
+            name: column,
+            field_type: Some(builder.to_string()),
+            is_primary_key: false,
+            is_nullable: chain.contains(".nullable()") || builder == "optReference",
+            is_unique: chain.contains(".uniqueIndex(") || chain.contains(".unique("),
+            default_value,
+            line: line_no,
+        },
+        reference,
+    ))
+}
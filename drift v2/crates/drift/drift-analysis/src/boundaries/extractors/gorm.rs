@@ -0,0 +1,174 @@
+//! GORM field extractor (Go).
+//!
+//! Columns come from struct fields and `gorm:"..."` tags; field names are
+//! reported as database column names (`column:` tag or GORM's snake case).
+
+use std::collections::HashMap;
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs::{self, SourceField, SourceStruct};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct GormExtractor;
+
+impl FieldExtractor for GormExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Gorm }
+    fn schema_file_patterns(&self) -> &[&str] { &["models/*.go", "*_model.go", "model.go"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        // Struct fields and tags are not in the parse result.
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Go || !source.contains("gorm") {
+            return Vec::new();
+        }
+        let declared = structs::go_structs(source);
+        let by_name: HashMap<&str, &SourceStruct> = declared.iter().map(|s| (s.name.as_str(), s)).collect();
+        let table_names = table_name_overrides(source);
+
+        declared
+            .iter()
+            .filter(|s| is_model(s, source, &table_names))
+            .map(|s| {
+                let mut fields = Vec::new();
+                let mut relationships = Vec::new();
+                collect_fields(s, &by_name, &mut fields, &mut relationships, 0);
+                ExtractedModel {
+                    name: s.name.clone(),
+                    table_name: Some(table_names.get(s.name.as_str()).cloned().unwrap_or_else(|| structs::plural_table(&s.name))),
+                    file: pr.file.clone(),
+                    line: s.line,
+                    framework: OrmFramework::Gorm,
+                    fields,
+                    relationships,
+                    confidence: 0.90,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A struct is a model when it embeds `gorm.Model`, carries `gorm` tags,
+/// overrides `TableName()` or is passed to `AutoMigrate`.
+fn is_model(s: &SourceStruct, source: &str, table_names: &HashMap<String, String>) -> bool {
+    s.fields.iter().any(|f| f.ty == "gorm.Model" || tag(f).is_some())
+        || table_names.contains_key(&s.name)
+        || source.contains("AutoMigrate(") && source.contains(&format!("&{}{{}}", s.name))
+}
+
+fn tag(field: &SourceField) -> Option<&str> {
+    field.attrs.first().and_then(|t| structs::go_tag(t, "gorm"))
+}
+
+/// `key` or `key:value` segments of a gorm tag (keys are case-insensitive).
+fn tag_setting<'a>(tag: &'a str, key: &str) -> Option<&'a str> {
+    tag.split(';').find_map(|segment| {
+        let (k, v) = segment.split_once(':').unwrap_or((segment, ""));
+        k.trim().eq_ignore_ascii_case(key).then_some(v.trim())
+    })
+}
+
+fn collect_fields(
+    s: &SourceStruct,
+    by_name: &HashMap<&str, &SourceStruct>,
+    fields: &mut Vec<ExtractedField>,
+    relationships: &mut Vec<Relationship>,
+    depth: u8,
+) {
+    let field_names: Vec<&str> = s.fields.iter().map(|f| f.name.as_str()).collect();
+    for field in &s.fields {
+        let gorm = tag(field).unwrap_or("");
+        if gorm == "-" || tag_setting(gorm, "-").is_some() || !field.name.starts_with(char::is_uppercase) {
+            continue;
+        }
+        let base = field.ty.trim_start_matches("[]").trim_start_matches('*');
+
+        if field.ty == "gorm.Model" {
+            for (name, nullable) in [("id", false), ("created_at", false), ("updated_at", false), ("deleted_at", true)] {
+                fields.push(ExtractedField {
+                    name: name.to_string(),
+                    field_type: Some(if name == "id" { "uint" } else { "time.Time" }.to_string()),
+                    is_primary_key: name == "id",
+                    is_nullable: nullable,
+                    is_unique: false,
+                    default_value: None,
+                    line: field.line,
+                });
+            }
+            continue;
+        }
+        // Embedded struct from the same file (`Base`, or `gorm:"embedded"`).
+        let embedded = field.embedded || tag_setting(gorm, "embedded").is_some();
+        if embedded && depth < 3 {
+            if let Some(inner) = by_name.get(base) {
+                collect_fields(inner, by_name, fields, relationships, depth + 1);
+                continue;
+            }
+        }
+
+        if let Some(join) = tag_setting(gorm, "many2many") {
+            relationships.push(Relationship { kind: RelationshipKind::ManyToMany, target_model: base.to_string(), foreign_key: Some(join.to_string()) });
+            continue;
+        }
+        let is_struct_type = base.starts_with(char::is_uppercase) && !base.contains('.');
+        if is_struct_type {
+            let foreign_key = tag_setting(gorm, "foreignKey").map(structs::snake_case);
+            if field.ty.starts_with("[]") {
+                relationships.push(Relationship { kind: RelationshipKind::HasMany, target_model: base.to_string(), foreign_key });
+                continue;
+            }
+            let own_key = format!("{}ID", field.name);
+            if field_names.contains(&own_key.as_str()) {
+                relationships.push(Relationship {
+                    kind: RelationshipKind::BelongsTo,
+                    target_model: base.to_string(),
+                    foreign_key: Some(foreign_key.unwrap_or_else(|| structs::snake_case(&own_key))),
+                });
+                continue;
+            }
+            if foreign_key.is_some() || by_name.contains_key(base) {
+                relationships.push(Relationship { kind: RelationshipKind::HasOne, target_model: base.to_string(), foreign_key });
+                continue;
+            }
+        }
+
+        let not_null = tag_setting(gorm, "not null").is_some();
+        let nullable = !not_null
+            && (field.ty.starts_with('*') || field.ty.starts_with("sql.Null") || field.ty == "gorm.DeletedAt");
+        fields.push(ExtractedField {
+            name: tag_setting(gorm, "column").map(str::to_string).unwrap_or_else(|| structs::snake_case(&field.name)),
+            field_type: Some(field.ty.clone()),
+            is_primary_key: tag_setting(gorm, "primaryKey").or_else(|| tag_setting(gorm, "primary_key")).is_some()
+                || field.name == "ID",
+            is_nullable: nullable,
+            is_unique: tag_setting(gorm, "unique").is_some() || tag_setting(gorm, "uniqueIndex").is_some(),
+            default_value: tag_setting(gorm, "default").map(str::to_string),
+            line: field.line,
+        });
+    }
+}
+
+/// `func (User) TableName() string { return "app_users" }`
+fn table_name_overrides(source: &str) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+    let mut rest = source;
+    while let Some(pos) = rest.find(") TableName() string") {
+        let head = &rest[..pos];
+        let receiver = head.rfind("func (").map(|start| &head[start + 6..]).unwrap_or("");
+        let receiver = receiver.split_whitespace().last().unwrap_or("").trim_start_matches('*');
+        let body = &rest[pos..];
+        let literal = body
+            .find("return \"")
+            .filter(|r| !body[..*r].contains("\nfunc "))
+            .and_then(|r| body[r + 8..].split('"').next());
+        if let (false, Some(table)) = (receiver.is_empty(), literal) {
+            names.insert(receiver.to_string(), table.to_string());
+        }
+        rest = &rest[pos + 1..];
+    }
+    names
+}
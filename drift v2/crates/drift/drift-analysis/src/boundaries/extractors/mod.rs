@@ -1,4 +1,8 @@
-//! Field extractors for 10 ORM frameworks.
+//! Field extractors for 17 ORM frameworks.
+//!
+//! Go, Rust, Drizzle and Exposed mappings live in struct tags, attributes
+//! and builder chains the parsers do not surface, so those extractors read
+//! the file source via `extract_models_from_source`.
 
 pub mod sequelize;
 pub mod typeorm;
@@ -10,6 +14,13 @@ pub mod mongoose;
 pub mod ef_core;
 pub mod hibernate;
 pub mod eloquent;
+pub mod gorm;
+pub mod sqlx;
+pub mod diesel;
+pub mod sea_orm;
+pub mod drizzle;
+pub mod exposed;
+mod structs;
 
 use crate::parsers::types::ParseResult;
 use super::types::{ExtractedModel, OrmFramework};
@@ -24,6 +35,12 @@ pub trait FieldExtractor: Send + Sync {
 
     /// Extract models from a parse result.
     fn extract_models(&self, parse_result: &ParseResult) -> Vec<ExtractedModel>;
+
+    /// Extract models with the file source available. Defaults to
+    /// `extract_models`; source-level extractors override this.
+    fn extract_models_from_source(&self, parse_result: &ParseResult, _source: &str) -> Vec<ExtractedModel> {
+        self.extract_models(parse_result)
+    }
 }
 
 /// Create all built-in field extractors.
@@ -39,5 +56,12 @@ pub fn create_all_extractors() -> Vec<Box<dyn FieldExtractor>> {
         Box::new(ef_core::EfCoreExtractor),
         Box::new(hibernate::HibernateExtractor),
         Box::new(eloquent::EloquentExtractor),
+        Box::new(gorm::GormExtractor),
+        Box::new(sqlx::SqlxExtractor),
+        Box::new(diesel::DieselExtractor),
+        Box::new(sea_orm::SeaOrmExtractor),
+        Box::new(sqlx::SqlxRustExtractor),
+        Box::new(drizzle::DrizzleExtractor),
+        Box::new(exposed::ExposedExtractor),
     ]
 }
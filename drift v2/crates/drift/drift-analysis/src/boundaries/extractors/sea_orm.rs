@@ -0,0 +1,113 @@
+//! SeaORM field extractor (Rust).
+//!
+//! Entities are `#[derive(DeriveEntityModel)] struct Model` with
+//! `#[sea_orm(...)]` attributes; relationships come from the `Relation`
+//! enum. `Model` is named after its module (`entities/user.rs` → `User`).
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct SeaOrmExtractor;
+
+impl FieldExtractor for SeaOrmExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::SeaOrm }
+    fn schema_file_patterns(&self) -> &[&str] { &["entities/*.rs", "entity/*.rs"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Rust || !source.contains("DeriveEntityModel") {
+            return Vec::new();
+        }
+        let relationships = relations(source);
+        structs::rust_structs(source)
+            .into_iter()
+            .filter(|s| s.derives().contains(&"DeriveEntityModel"))
+            .map(|s| {
+                let entity_args = structs::attr_args(&s.attrs, "sea_orm");
+                let name = if s.name == "Model" { module_model_name(&pr.file) } else { s.name.clone() };
+                let fields = s
+                    .fields
+                    .iter()
+                    .filter_map(|f| {
+                        let args = structs::attr_args(&f.attrs, "sea_orm");
+                        if structs::attr_flag(&args, "ignore") {
+                            return None;
+                        }
+                        Some(ExtractedField {
+                            name: structs::attr_value(&args, "column_name").unwrap_or(&f.name).to_string(),
+                            field_type: Some(f.ty.clone()),
+                            is_primary_key: structs::attr_flag(&args, "primary_key"),
+                            is_nullable: structs::attr_flag(&args, "nullable") || structs::option_inner(&f.ty).is_some(),
+                            is_unique: structs::attr_flag(&args, "unique"),
+                            default_value: structs::attr_value(&args, "default_value").map(str::to_string),
+                            line: f.line,
+                        })
+                    })
+                    .collect();
+                ExtractedModel {
+                    table_name: Some(structs::attr_value(&entity_args, "table_name").map(str::to_string).unwrap_or_else(|| structs::plural_table(&name))),
+                    name,
+                    file: pr.file.clone(),
+                    line: s.line,
+                    framework: OrmFramework::SeaOrm,
+                    fields,
+                    relationships: relationships.clone(),
+                    confidence: 0.95,
+                }
+            })
+            .collect()
+    }
+}
+
+/// `src/entities/order_item.rs` → `OrderItem`.
+fn module_model_name(file: &str) -> String {
+    let stem = file.rsplit(['/', '\\']).next().unwrap_or(file).trim_end_matches(".rs");
+    pascal_case(stem)
+}
+
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let mut chars = p.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Variants of `enum Relation` annotated with `belongs_to`/`has_one`/`has_many`.
+fn relations(source: &str) -> Vec<Relationship> {
+    let Some(start) = source.find("enum Relation") else { return Vec::new() };
+    let body = &source[start..];
+    let end = body.find("\n}").unwrap_or(body.len());
+    let mut out = Vec::new();
+    let mut rest = &body[..end];
+    while let Some(pos) = rest.find("#[sea_orm(") {
+        let inner_start = pos + "#[sea_orm(".len();
+        let Some(close) = rest[inner_start..].find(")]") else { break };
+        let args = structs::split_top(&rest[inner_start..inner_start + close]);
+        rest = &rest[inner_start + close..];
+        let (kind, target) = if let Some(t) = structs::attr_value(&args, "belongs_to") {
+            (RelationshipKind::BelongsTo, t)
+        } else if let Some(t) = structs::attr_value(&args, "has_many") {
+            (RelationshipKind::HasMany, t)
+        } else if let Some(t) = structs::attr_value(&args, "has_one") {
+            (RelationshipKind::HasOne, t)
+        } else {
+            continue;
+        };
+        // `super::user::Entity` → `User`
+        let module = target.trim_end_matches("::Entity").rsplit("::").next().unwrap_or(target);
+        let foreign_key = structs::attr_value(&args, "from")
+            .map(|from| structs::snake_case(from.rsplit("::").next().unwrap_or(from)));
+        out.push(Relationship { kind, target_model: pascal_case(module), foreign_key });
+    }
+    out
+}
@@ -0,0 +1,134 @@
+//! sqlx field extractors — Go (`jmoiron/sqlx`, `db:"..."` tags) and Rust
+//! (`#[derive(FromRow)]`). Neither maps a table, so models carry no
+//! `table_name`; fields are the column names rows are scanned from.
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+use super::structs::{self, SourceStruct};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use crate::boundaries::types::ExtractedField;
+
+pub struct SqlxExtractor;
+
+impl FieldExtractor for SqlxExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Sqlx }
+    fn schema_file_patterns(&self) -> &[&str] { &["*.go"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Go || !source.contains("db:\"") {
+            return Vec::new();
+        }
+        structs::go_structs(source)
+            .into_iter()
+            .filter_map(|s| {
+                let fields: Vec<ExtractedField> = s
+                    .fields
+                    .iter()
+                    .filter_map(|f| {
+                        let column = structs::go_tag(f.attrs.first()?, "db")?.split(',').next()?;
+                        (column != "-" && !column.is_empty()).then(|| ExtractedField {
+                            name: column.to_string(),
+                            field_type: Some(f.ty.clone()),
+                            is_primary_key: column == "id",
+                            is_nullable: f.ty.starts_with('*') || f.ty.starts_with("sql.Null"),
+                            is_unique: false,
+                            default_value: None,
+                            line: f.line,
+                        })
+                    })
+                    .collect();
+                (!fields.is_empty()).then(|| model(pr, &s, fields, OrmFramework::Sqlx))
+            })
+            .collect()
+    }
+}
+
+pub struct SqlxRustExtractor;
+
+impl FieldExtractor for SqlxRustExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::SqlxRust }
+    fn schema_file_patterns(&self) -> &[&str] { &["models.rs", "*/models/*.rs"] }
+
+    fn extract_models(&self, _pr: &ParseResult) -> Vec<ExtractedModel> {
+        Vec::new()
+    }
+
+    fn extract_models_from_source(&self, pr: &ParseResult, source: &str) -> Vec<ExtractedModel> {
+        if pr.language != Language::Rust || !source.contains("FromRow") {
+            return Vec::new();
+        }
+        structs::rust_structs(source)
+            .into_iter()
+            .filter(|s| s.derives().contains(&"FromRow"))
+            .map(|s| {
+                let struct_args = structs::attr_args(&s.attrs, "sqlx");
+                let rename_all = structs::attr_value(&struct_args, "rename_all");
+                let fields = s
+                    .fields
+                    .iter()
+                    .filter_map(|f| {
+                        let args = structs::attr_args(&f.attrs, "sqlx");
+                        if structs::attr_flag(&args, "skip") || structs::attr_flag(&args, "flatten") {
+                            return None;
+                        }
+                        let name = structs::attr_value(&args, "rename")
+                            .map(str::to_string)
+                            .unwrap_or_else(|| rename(&f.name, rename_all));
+                        Some(ExtractedField {
+                            is_primary_key: name == "id",
+                            name,
+                            field_type: Some(f.ty.clone()),
+                            is_nullable: structs::option_inner(&f.ty).is_some(),
+                            is_unique: false,
+                            default_value: structs::attr_flag(&args, "default").then(|| "Default::default()".to_string()),
+                            line: f.line,
+                        })
+                    })
+                    .collect();
+                model(pr, &s, fields, OrmFramework::SqlxRust)
+            })
+            .collect()
+    }
+}
+
+fn model(pr: &ParseResult, s: &SourceStruct, fields: Vec<ExtractedField>, framework: OrmFramework) -> ExtractedModel {
+    ExtractedModel {
+        name: s.name.clone(),
+        table_name: None,
+        file: pr.file.clone(),
+        line: s.line,
+        framework,
+        fields,
+        relationships: Vec::new(),
+        confidence: 0.80,
+    }
+}
+
+/// Apply serde-style `rename_all` to a snake_case field name.
+fn rename(field: &str, rename_all: Option<&str>) -> String {
+    match rename_all {
+        Some("lowercase") => field.replace('_', "").to_lowercase(),
+        Some("UPPERCASE") => field.replace('_', "").to_uppercase(),
+        Some("SCREAMING_SNAKE_CASE") => field.to_uppercase(),
+        Some(case @ ("camelCase" | "PascalCase")) => {
+            let mut out = String::with_capacity(field.len());
+            let mut upper = case == "PascalCase";
+            for c in field.chars() {
+                if c == '_' {
+                    upper = true;
+                } else if upper {
+                    out.extend(c.to_uppercase());
+                    upper = false;
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        _ => field.to_string(),
+    }
+}
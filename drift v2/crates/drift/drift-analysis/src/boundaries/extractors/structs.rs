@@ -0,0 +1,341 @@
+//! Struct scanning for ORMs whose mappings live in Go struct tags and Rust
+//! attributes, which the parsers do not surface on `ClassInfo`.
+
+/// A struct declaration with its fields. Lines are 0-based.
+#[derive(Debug, Clone)]
+pub struct SourceStruct {
+    pub name: String,
+    pub line: u32,
+    /// Rust outer attributes (`derive(Queryable)`, `diesel(table_name = users)`).
+    pub attrs: Vec<String>,
+    pub fields: Vec<SourceField>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceField {
+    pub name: String,
+    pub ty: String,
+    /// Go: the raw struct tag. Rust: each field attribute.
+    pub attrs: Vec<String>,
+    pub line: u32,
+    /// Go: anonymous (embedded) field such as `gorm.Model` or `*Base`.
+    pub embedded: bool,
+}
+
+impl SourceStruct {
+    /// Last path segment of every derived trait.
+    pub fn derives(&self) -> Vec<&str> {
+        attr_args(&self.attrs, "derive")
+            .into_iter()
+            .map(|d| d.rsplit("::").next().unwrap_or(d).trim())
+            .collect()
+    }
+}
+
+/// Go `type X struct { ... }` declarations.
+pub fn go_structs(source: &str) -> Vec<SourceStruct> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut structs = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        let header = trimmed.strip_prefix("type ").and_then(|rest| {
+            let (name, rest) = rest.trim_start().split_once(char::is_whitespace)?;
+            // Generic structs: `type Page[T any] struct {`.
+            let name = name.split('[').next().unwrap_or(name);
+            rest.trim_start().starts_with("struct").then_some(name).filter(|_| trimmed.ends_with('{'))
+        });
+        let Some(name) = header else {
+            i += 1;
+            continue;
+        };
+        let start = i;
+        let mut fields = Vec::new();
+        i += 1;
+        let mut depth = 1;
+        while i < lines.len() && depth > 0 {
+            let line = lines[i].trim();
+            let code = strip_go_comment(line);
+            depth += code.matches('{').count() as i32 - code.matches('}').count() as i32;
+            if depth == 1 && !code.is_empty() {
+                fields.extend(go_field(code, i as u32));
+            }
+            i += 1;
+        }
+        structs.push(SourceStruct { name: name.to_string(), line: start as u32, attrs: Vec::new(), fields });
+    }
+    structs
+}
+
+fn strip_go_comment(line: &str) -> &str {
+    // `//` inside a backtick tag is not a comment.
+    let tag_end = line.rfind('`').unwrap_or(0);
+    match line[tag_end..].find("//") {
+        Some(pos) => line[..tag_end + pos].trim(),
+        None => line.trim(),
+    }
+}
+
+fn go_field(code: &str, line: u32) -> Vec<SourceField> {
+    let (decl, tag) = match code.find('`') {
+        Some(start) => {
+            let end = code[start + 1..].find('`').map(|e| start + 1 + e).unwrap_or(code.len());
+            (code[..start].trim(), code[start + 1..end].to_string())
+        }
+        None => (code, String::new()),
+    };
+    let attrs = if tag.is_empty() { Vec::new() } else { vec![tag] };
+    let mut parts = decl.splitn(2, char::is_whitespace);
+    let first = parts.next().unwrap_or("");
+    let rest = parts.next().map(str::trim).unwrap_or("");
+    if rest.is_empty() {
+        // Embedded: `gorm.Model`, `*Base`.
+        let ty = first.trim_start_matches('*');
+        let name = ty.rsplit('.').next().unwrap_or(ty);
+        return vec![SourceField { name: name.to_string(), ty: first.to_string(), attrs, line, embedded: true }];
+    }
+    // `A, B string`
+    let (names, ty) = if first.ends_with(',') {
+        let split = decl.rfind(',').unwrap_or(0);
+        let after = decl[split + 1..].trim();
+        let (last, ty) = after.split_once(char::is_whitespace).unwrap_or((after, ""));
+        let mut names: Vec<&str> = decl[..split].split(',').map(str::trim).collect();
+        names.push(last);
+        (names, ty.trim())
+    } else {
+        (vec![first], rest)
+    };
+    names
+        .into_iter()
+        .filter(|n| !n.is_empty())
+        .map(|n| SourceField { name: n.to_string(), ty: ty.to_string(), attrs: attrs.clone(), line, embedded: false })
+        .collect()
+}
+
+/// Value of `key:"value"` in a Go struct tag.
+pub fn go_tag<'a>(tag: &'a str, key: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(&format!("{key}:\"")) {
+        let before = &rest[..pos];
+        let after = &rest[pos + key.len() + 2..];
+        if before.is_empty() || before.ends_with(' ') {
+            return after.find('"').map(|end| &after[..end]);
+        }
+        rest = after;
+    }
+    None
+}
+
+/// Rust `struct X { ... }` declarations with their attributes.
+pub fn rust_structs(source: &str) -> Vec<SourceStruct> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut structs = Vec::new();
+    let mut pending: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with("#[") {
+            let (attr, next) = read_attr(&lines, i);
+            pending.push(attr);
+            i = next;
+            continue;
+        }
+        if trimmed.starts_with("//") || trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+        let Some(name) = struct_name(trimmed) else {
+            pending.clear();
+            i += 1;
+            continue;
+        };
+        let attrs = std::mem::take(&mut pending);
+        let start = i;
+        // Find the opening brace (generics and where clauses may wrap).
+        while i < lines.len() && !lines[i].contains('{') {
+            if lines[i].trim_end().ends_with(';') {
+                break;
+            }
+            i += 1;
+        }
+        if i >= lines.len() || !lines[i].contains('{') {
+            i += 1;
+            continue;
+        }
+        let mut fields = Vec::new();
+        let mut field_attrs: Vec<String> = Vec::new();
+        let mut depth = lines[i].matches('{').count() as i32 - lines[i].matches('}').count() as i32;
+        i += 1;
+        while i < lines.len() && depth > 0 {
+            let line = lines[i].trim();
+            if line.starts_with("#[") {
+                let (attr, next) = read_attr(&lines, i);
+                field_attrs.push(attr);
+                i = next;
+                continue;
+            }
+            depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+            if depth >= 1 && !line.starts_with("//") && !line.is_empty() {
+                let decl = strip_visibility(line);
+                if let Some((name, ty)) = decl.split_once(':') {
+                    let name = name.trim().trim_start_matches("r#");
+                    if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                        fields.push(SourceField {
+                            name: name.to_string(),
+                            ty: ty.trim().trim_end_matches(',').trim().to_string(),
+                            attrs: std::mem::take(&mut field_attrs),
+                            line: i as u32,
+                            embedded: false,
+                        });
+                    }
+                }
+                field_attrs.clear();
+            }
+            i += 1;
+        }
+        structs.push(SourceStruct { name: name.to_string(), line: start as u32, attrs, fields });
+    }
+    structs
+}
+
+/// Read a `#[...]` attribute that may span lines; returns its inner text
+/// and the next line index.
+fn read_attr(lines: &[&str], start: usize) -> (String, usize) {
+    let mut text = String::new();
+    let mut depth = 0i32;
+    let mut i = start;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        let mut in_str = false;
+        for c in line.chars() {
+            match c {
+                '"' => in_str = !in_str,
+                '[' | '(' if !in_str => depth += 1,
+                ']' | ')' if !in_str => depth -= 1,
+                _ => {}
+            }
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(line);
+        i += 1;
+        if depth <= 0 {
+            break;
+        }
+    }
+    let inner = text.trim().trim_start_matches("#[").trim_end_matches(']').trim().to_string();
+    (inner, i)
+}
+
+fn struct_name(line: &str) -> Option<&str> {
+    let rest = strip_visibility(line).strip_prefix("struct ")?;
+    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+    (end > 0).then(|| &rest[..end])
+}
+
+fn strip_visibility(line: &str) -> &str {
+    let line = line.trim();
+    if let Some(rest) = line.strip_prefix("pub(") {
+        return rest.split_once(')').map(|(_, r)| r.trim_start()).unwrap_or(line);
+    }
+    line.strip_prefix("pub ").unwrap_or(line).trim_start()
+}
+
+/// Top-level comma-separated arguments of every `name(...)` attribute.
+pub fn attr_args<'a>(attrs: &'a [String], name: &str) -> Vec<&'a str> {
+    attrs
+        .iter()
+        .filter_map(|a| {
+            let rest = a.strip_prefix(name)?.trim_start();
+            let inner = rest.strip_prefix('(')?.strip_suffix(')')?;
+            Some(split_top(inner))
+        })
+        .flatten()
+        .collect()
+}
+
+/// `key = value` (or `key = "value"`) among attribute arguments.
+pub fn attr_value<'a>(args: &[&'a str], key: &str) -> Option<&'a str> {
+    args.iter().find_map(|arg| {
+        let (k, v) = arg.split_once('=')?;
+        (k.trim() == key).then(|| v.trim().trim_matches('"'))
+    })
+}
+
+/// Whether a bare flag (`primary_key`, `unique`) appears among arguments.
+pub fn attr_flag(args: &[&str], flag: &str) -> bool {
+    args.iter().any(|arg| arg.trim() == flag || arg.split_once('=').is_some_and(|(k, _)| k.trim() == flag))
+}
+
+pub fn split_top(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_str = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_str = !in_str,
+            '(' | '[' | '{' | '<' if !in_str => depth += 1,
+            ')' | ']' | '}' | '>' if !in_str => depth -= 1,
+            ',' if !in_str && depth == 0 => {
+                parts.push(text[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// `Option<String>` → `Some("String")`.
+pub fn option_inner(ty: &str) -> Option<&str> {
+    let ty = ty.trim();
+    let inner = ty.strip_prefix("Option<").or_else(|| ty.strip_prefix("std::option::Option<"))?;
+    inner.strip_suffix('>')
+}
+
+/// GORM-style snake case that keeps initialisms together:
+/// `UserID` → `user_id`, `SSN` → `ssn`, `HTTPStatus` → `http_status`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Conventional plural table name: `User` → `users`, `Category` →
+/// `categories`, `Address` → `addresses`.
+pub fn plural_table(name: &str) -> String {
+    let snake = snake_case(name);
+    if let Some(stem) = snake.strip_suffix('y').filter(|s| !s.ends_with(['a', 'e', 'i', 'o', 'u'])) {
+        format!("{stem}ies")
+    } else if snake.ends_with('s') || snake.ends_with('x') || snake.ends_with("ch") || snake.ends_with("sh") {
+        format!("{snake}es")
+    } else {
+        format!("{snake}s")
+    }
+}
+
+/// 0-based line of a byte offset.
+pub fn line_of(source: &str, offset: usize) -> u32 {
+    source[..offset.min(source.len())].matches('\n').count() as u32
+}
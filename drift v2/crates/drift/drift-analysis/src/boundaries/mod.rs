@@ -8,7 +8,9 @@ pub mod types;
 pub mod detector;
 pub mod sensitive;
 pub mod extractors;
+pub mod schema;
 
 pub use types::{BoundaryScanResult, SensitivityType, OrmFramework, ExtractedModel, ExtractedField};
 pub use detector::BoundaryDetector;
 pub use sensitive::SensitiveFieldDetector;
+pub use schema::{DatabaseSchema, MigrationFile, SchemaDrift, SchemaDriftKind};
@@ -0,0 +1,348 @@
+//! Alembic revisions: `op.*` calls inside `upgrade()` (plus SQL passed to
+//! `op.execute`), and the `revision`/`down_revision` identifiers used to
+//! order the chain.
+
+use super::{column, ddl, table_key, Column, ColumnChange, ForeignKey, Index, SchemaOp};
+
+/// `revision = "abc123"` / `revision: str = "abc123"`.
+pub fn revision(source: &str) -> Option<String> {
+    assignment(source, "revision")
+}
+
+/// `down_revision = "abc123"` (`None` for the root revision). Merge
+/// revisions list several parents; the first one is used for ordering.
+pub fn down_revision(source: &str) -> Option<String> {
+    assignment(source, "down_revision")
+}
+
+fn assignment(source: &str, name: &str) -> Option<String> {
+    source.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix(name)?;
+        let rest = rest.trim_start();
+        let rest = match rest.strip_prefix(':') {
+            Some(annotated) => annotated.split_once('=')?.1,
+            None => rest.strip_prefix('=')?,
+        };
+        first_string(rest)
+    })
+}
+
+pub fn parse(source: &str) -> Vec<(u32, SchemaOp)> {
+    let Some(start) = source.find("def upgrade") else { return vec![] };
+    // The body runs until the next top-level statement.
+    let body_start = source[start..].find('\n').map(|p| start + p + 1).unwrap_or(source.len());
+    let body_end = source[body_start..]
+        .match_indices('\n')
+        .map(|(p, _)| body_start + p + 1)
+        .find(|&p| source[p..].starts_with(|c: char| !c.is_whitespace() && c != '#'))
+        .unwrap_or(source.len());
+    let base_line = source[..body_start].matches('\n').count() as u32 + 1;
+    let body = &source[body_start..body_end];
+
+    let mut ops = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = body[from..].find("op.") {
+        let at = from + pos;
+        from = at + 3;
+        if at > 0 && body.as_bytes()[at - 1].is_ascii_alphanumeric() {
+            continue;
+        }
+        let name: String = body[at + 3..].chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+        let open = at + 3 + name.len();
+        if !body[open..].starts_with('(') {
+            continue;
+        }
+        let Some(close) = matching_paren(body, open) else { continue };
+        let line = base_line + body[..at].matches('\n').count() as u32;
+        let args = split_args(&body[open + 1..close]);
+        ops.extend(op_call(&name, &args, line));
+        from = close;
+    }
+    ops
+}
+
+fn op_call(name: &str, args: &[&str], line: u32) -> Vec<(u32, SchemaOp)> {
+    let positional: Vec<&str> = args.iter().copied().filter(|a| keyword(a).is_none()).collect();
+    let kwarg = |key: &str| args.iter().find_map(|a| keyword(a).filter(|(k, _)| *k == key).map(|(_, v)| v));
+    let string_at = |i: usize| positional.get(i).and_then(|a| first_string(a));
+    let mut ops = Vec::new();
+    match name {
+        "create_table" => {
+            let Some(table) = string_at(0) else { return ops };
+            let mut columns: Vec<Column> = Vec::new();
+            let mut indexes = Vec::new();
+            let mut foreign_keys = Vec::new();
+            for item in &positional[1..] {
+                let item = item.trim();
+                if item.starts_with("sa.Column(") || item.starts_with("Column(") {
+                    if let Some((col, fk)) = sa_column(item, line) {
+                        columns.push(col);
+                        foreign_keys.extend(fk);
+                    }
+                } else if let Some(inner) = constructor_args(item, "PrimaryKeyConstraint") {
+                    let pk = string_args(&inner);
+                    for col in columns.iter_mut().filter(|c| pk.contains(&c.name)) {
+                        col.primary_key = true;
+                        col.nullable = false;
+                    }
+                } else if let Some(inner) = constructor_args(item, "UniqueConstraint") {
+                    let cols = string_args(&inner);
+                    if cols.len() == 1 {
+                        for col in columns.iter_mut().filter(|c| c.name == cols[0]) {
+                            col.unique = true;
+                        }
+                    }
+                    indexes.push(Index { name: keyword_string(&inner, "name"), columns: cols, unique: true });
+                } else if let Some(inner) = constructor_args(item, "ForeignKeyConstraint") {
+                    let parts = split_args(&inner);
+                    let local = parts.first().map(|p| string_args(p)).unwrap_or_default();
+                    let remote = parts.get(1).map(|p| string_args(p)).unwrap_or_default();
+                    if let Some(fk) = foreign_key(local, &remote, keyword_string(&inner, "ondelete")) {
+                        foreign_keys.push(fk);
+                    }
+                }
+            }
+            ops.push((line, SchemaOp::CreateTable { name: table, columns, indexes, foreign_keys }));
+        }
+        "drop_table" => {
+            if let Some(name) = string_at(0) {
+                ops.push((line, SchemaOp::DropTable { name }));
+            }
+        }
+        "rename_table" => {
+            if let (Some(from), Some(to)) = (string_at(0), string_at(1)) {
+                ops.push((line, SchemaOp::RenameTable { from, to }));
+            }
+        }
+        "add_column" => {
+            if let (Some(table), Some(item)) = (string_at(0), positional.get(1)) {
+                if let Some((column, fk)) = sa_column(item.trim(), line) {
+                    ops.push((line, SchemaOp::AddColumn { table: table.clone(), column }));
+                    if let Some(foreign_key) = fk {
+                        ops.push((line, SchemaOp::AddForeignKey { table, foreign_key }));
+                    }
+                }
+            }
+        }
+        "drop_column" => {
+            if let (Some(table), Some(column)) = (string_at(0), string_at(1)) {
+                ops.push((line, SchemaOp::DropColumn { table, column }));
+            }
+        }
+        "alter_column" => {
+            let (Some(table), Some(column)) = (string_at(0), string_at(1)) else { return ops };
+            let change = ColumnChange {
+                data_type: kwarg("type_").map(sa_type),
+                nullable: kwarg("nullable").map(|v| v.trim() != "False"),
+                default: kwarg("server_default").map(|v| Some(v.trim().to_string()).filter(|d| d != "None")),
+            };
+            if change != ColumnChange::default() {
+                ops.push((line, SchemaOp::AlterColumn { table: table.clone(), column: column.clone(), change }));
+            }
+            if let Some(to) = kwarg("new_column_name").and_then(first_string) {
+                ops.push((line, SchemaOp::RenameColumn { table, from: column, to }));
+            }
+        }
+        "create_index" => {
+            if let (Some(index_name), Some(table)) = (string_at(0), string_at(1)) {
+                let columns = positional.get(2).map(|c| string_args(c)).unwrap_or_default();
+                let unique = kwarg("unique").is_some_and(|v| v.trim() == "True");
+                ops.push((line, SchemaOp::AddIndex { table, index: Index { name: Some(index_name), columns, unique } }));
+            }
+        }
+        "drop_index" => {
+            if let Some(name) = string_at(0) {
+                ops.push((line, SchemaOp::DropIndex { name }));
+            }
+        }
+        "create_unique_constraint" => {
+            if let (Some(name), Some(table)) = (string_at(0), string_at(1)) {
+                let columns = positional.get(2).map(|c| string_args(c)).unwrap_or_default();
+                ops.push((line, SchemaOp::AddIndex { table, index: Index { name: Some(name), columns, unique: true } }));
+            }
+        }
+        "create_primary_key" => {
+            if let (Some(table), Some(cols)) = (string_at(1), positional.get(2)) {
+                ops.push((line, SchemaOp::SetPrimaryKey { table, columns: string_args(cols) }));
+            }
+        }
+        "create_foreign_key" => {
+            // (name, source_table, referent_table, local_cols, remote_cols)
+            if let (Some(table), Some(referent)) = (string_at(1), string_at(2)) {
+                let local = positional.get(3).map(|c| string_args(c)).unwrap_or_default();
+                let remote = positional.get(4).map(|c| string_args(c)).unwrap_or_default();
+                ops.push((line, SchemaOp::AddForeignKey {
+                    table,
+                    foreign_key: ForeignKey {
+                        columns: local,
+                        ref_table: table_key(&referent),
+                        ref_columns: remote,
+                        on_delete: kwarg("ondelete").and_then(first_string),
+                    },
+                }));
+            }
+        }
+        "execute" => {
+            if let Some(sql) = positional.first().and_then(|a| first_string(a)) {
+                ops.extend(ddl::parse(&sql).into_iter().map(|(offset, op)| (line + offset - 1, op)));
+            }
+        }
+        _ => {}
+    }
+    ops
+}
+
+/// `sa.Column('email', sa.String(255), sa.ForeignKey('orgs.id'), nullable=False)`.
+fn sa_column(item: &str, line: u32) -> Option<(Column, Option<ForeignKey>)> {
+    let inner = constructor_args(item, "Column")?;
+    let args = split_args(&inner);
+    let name = first_string(args.first()?)?;
+    let mut fk = None;
+    let mut data_type = String::new();
+    for arg in &args[1..] {
+        let arg = arg.trim();
+        if keyword(arg).is_some() {
+            continue;
+        }
+        if let Some(target) = constructor_args(arg, "ForeignKey") {
+            let target = first_string(&target).unwrap_or_default();
+            fk = foreign_key(vec![name.clone()], &[target], keyword_string(arg, "ondelete"));
+        } else if data_type.is_empty() {
+            data_type = sa_type(arg);
+        }
+    }
+    let kw = |key: &str| args.iter().find_map(|a| keyword(a).filter(|(k, _)| *k == key).map(|(_, v)| v.trim().to_string()));
+    let mut col = column(&name, &data_type, line);
+    col.primary_key = kw("primary_key").as_deref() == Some("True");
+    // SQLAlchemy columns are nullable unless they are (part of) the primary key.
+    col.nullable = match kw("nullable").as_deref() {
+        Some("False") => false,
+        Some(_) => true,
+        None => !col.primary_key,
+    };
+    col.unique = kw("unique").as_deref() == Some("True");
+    col.default = kw("server_default").filter(|d| d != "None");
+    Some((col, fk))
+}
+
+/// `["org_id"]`, `["orgs.id"]` → FK (`ref_table` from the dotted target).
+fn foreign_key(columns: Vec<String>, targets: &[String], on_delete: Option<String>) -> Option<ForeignKey> {
+    let first = targets.first()?;
+    let (table, _) = first.rsplit_once('.')?;
+    let ref_columns = targets.iter().filter_map(|t| t.rsplit_once('.').map(|(_, c)| c.to_string())).collect();
+    Some(ForeignKey { columns, ref_table: table_key(table), ref_columns, on_delete })
+}
+
+/// `sa.String(length=255)` → `string(length=255)`; `postgresql.JSONB()` → `jsonb`.
+fn sa_type(arg: &str) -> String {
+    let arg = arg.trim();
+    let name = arg.split('(').next().unwrap_or(arg);
+    let name = name.rsplit('.').next().unwrap_or(name).to_lowercase();
+    match arg.find('(').and_then(|open| matching_paren(arg, open).map(|close| &arg[open + 1..close])) {
+        Some(params) if !params.trim().is_empty() => format!("{name}({})", params.trim()),
+        _ => name,
+    }
+}
+
+/// `key=value` outside of nested calls → `(key, value)`.
+fn keyword(arg: &str) -> Option<(&str, &str)> {
+    let arg = arg.trim();
+    let eq = arg.find('=')?;
+    let key = &arg[..eq];
+    (!key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_') && !arg[eq + 1..].starts_with('='))
+        .then(|| (key, &arg[eq + 1..]))
+}
+
+fn keyword_string(args: &str, key: &str) -> Option<String> {
+    split_args(args)
+        .into_iter()
+        .find_map(|a| keyword(a).filter(|(k, _)| *k == key).and_then(|(_, v)| first_string(v)))
+}
+
+/// Inner text of `sa.Name(...)` / `Name(...)`.
+fn constructor_args(item: &str, name: &str) -> Option<String> {
+    let item = item.trim();
+    let head = item.split('(').next()?;
+    if head.rsplit('.').next()? != name {
+        return None;
+    }
+    let open = item.find('(')?;
+    let close = matching_paren(item, open)?;
+    Some(item[open + 1..close].to_string())
+}
+
+/// All string literals in an argument: `['a', 'b']` → `["a", "b"]`.
+fn string_args(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = text;
+    while let Some(s) = first_string(rest) {
+        let skip = rest.find(&s).map(|p| p + s.len() + 1).unwrap_or(rest.len());
+        out.push(s);
+        rest = &rest[skip.min(rest.len())..];
+        if keyword(rest.trim_start_matches([',', ' ', ']'])).is_some() {
+            break;
+        }
+    }
+    out
+}
+
+/// First `'…'`/`"…"` literal (including triple-quoted ones).
+fn first_string(text: &str) -> Option<String> {
+    let start = text.find(['"', '\''])?;
+    let quote = text[start..].chars().next()?;
+    let triple = format!("{quote}{quote}{quote}");
+    if text[start..].starts_with(&triple) {
+        let body = &text[start + 3..];
+        return body.find(&triple).map(|end| body[..end].to_string());
+    }
+    let body = &text[start + 1..];
+    body.find(quote).map(|end| body[..end].to_string())
+}
+
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for (i, c) in text[open..].char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(open + i);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    None
+}
+
+fn split_args(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(text[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.into_iter().filter(|p| !p.is_empty()).collect()
+}
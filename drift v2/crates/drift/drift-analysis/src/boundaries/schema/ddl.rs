@@ -0,0 +1,648 @@
+//! SQL DDL parser: `CREATE TABLE`, `ALTER TABLE`, `CREATE INDEX`, `DROP …`
+//! and `RENAME TABLE` across the PostgreSQL, MySQL, SQLite and SQL Server
+//! dialects. Everything else (DML, functions, grants) is skipped.
+
+use super::{column, table_key, Column, ColumnChange, ForeignKey, Index, SchemaOp};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    /// Keyword, identifier (quotes removed) or number.
+    Word(String),
+    Str(String),
+    Punct(char),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    line: u32,
+}
+
+/// Keywords that end a column's type and start its constraints.
+const COLUMN_CONSTRAINTS: &[&str] = &[
+    "NOT", "NULL", "PRIMARY", "UNIQUE", "DEFAULT", "REFERENCES", "CONSTRAINT", "CHECK",
+    "AUTO_INCREMENT", "AUTOINCREMENT", "IDENTITY", "GENERATED", "COLLATE", "COMMENT", "ON",
+    "AS", "FIRST", "AFTER", "USING",
+];
+
+/// Keywords that start a table-level constraint inside `CREATE TABLE (...)`.
+const TABLE_CONSTRAINTS: &[&str] = &[
+    "CONSTRAINT", "PRIMARY", "UNIQUE", "FOREIGN", "CHECK", "INDEX", "KEY", "FULLTEXT", "SPATIAL",
+    "EXCLUDE",
+];
+
+/// Parse a SQL script into schema operations with their 1-based line.
+pub fn parse(sql: &str) -> Vec<(u32, SchemaOp)> {
+    let tokens = tokenize(sql);
+    let mut ops = Vec::new();
+    for statement in tokens.split(|t| t.tok == Tok::Punct(';')) {
+        if let Some(first) = statement.first() {
+            let mut p = Parser { toks: statement, pos: 0 };
+            for op in p.statement() {
+                ops.push((first.line, op));
+            }
+        }
+    }
+    ops
+}
+
+// ─── Tokenizer ──────────────────────────────────────────────────────────────
+
+fn tokenize(sql: &str) -> Vec<Token> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1u32;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '#' => {
+                // MySQL line comment.
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            // `[name]` is a SQL Server identifier; `text[]` is a PostgreSQL array.
+            '\'' | '"' | '`' | '['
+                if c != '[' || i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == ']') =>
+            {
+                let close = if c == '[' { ']' } else { c };
+                let start_line = line;
+                let mut text = String::new();
+                i += 1;
+                while i < chars.len() {
+                    if chars[i] == close {
+                        // Doubled quote is an escaped quote.
+                        if close != ']' && chars.get(i + 1) == Some(&close) {
+                            text.push(close);
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    text.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+                let tok = if c == '\'' { Tok::Str(text) } else { Tok::Word(text) };
+                tokens.push(Token { tok, line: start_line });
+            }
+            '$' => {
+                // Dollar-quoted body (`$$ ... $$`, `$fn$ ... $fn$`) or `$1`.
+                let tag_end = chars[i + 1..].iter().position(|c| !(c.is_alphanumeric() || *c == '_')).map(|p| i + 1 + p);
+                match tag_end {
+                    Some(end) if chars[end] == '$' && !chars[i + 1..end].iter().any(|c| c.is_ascii_digit()) => {
+                        let tag = &chars[i..=end];
+                        let start_line = line;
+                        let body_start = end + 1;
+                        let mut j = body_start;
+                        while j < chars.len() && !chars[j..].starts_with(tag) {
+                            if chars[j] == '\n' {
+                                line += 1;
+                            }
+                            j += 1;
+                        }
+                        tokens.push(Token { tok: Tok::Str(chars[body_start..j.min(chars.len())].iter().collect()), line: start_line });
+                        i = j + tag.len();
+                    }
+                    _ => {
+                        tokens.push(Token { tok: Tok::Punct('$'), line });
+                        i += 1;
+                    }
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$') {
+                    i += 1;
+                }
+                tokens.push(Token { tok: Tok::Word(chars[start..i].iter().collect()), line });
+            }
+            _ => {
+                tokens.push(Token { tok: Tok::Punct(c), line });
+                i += 1;
+            }
+        }
+    }
+    tokens
+}
+
+// ─── Parser ─────────────────────────────────────────────────────────────────
+
+struct Parser<'a> {
+    toks: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Tok> {
+        self.toks.get(self.pos).map(|t| &t.tok)
+    }
+
+    fn line(&self) -> u32 {
+        self.toks.get(self.pos).or(self.toks.last()).map(|t| t.line).unwrap_or(0)
+    }
+
+    fn is_kw(&self, kw: &str) -> bool {
+        matches!(self.peek(), Some(Tok::Word(w)) if w.eq_ignore_ascii_case(kw))
+    }
+
+    fn eat_kw(&mut self, kw: &str) -> bool {
+        let hit = self.is_kw(kw);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    /// Consume a keyword sequence only if it matches in full.
+    fn eat_kws(&mut self, kws: &[&str]) -> bool {
+        let matched = kws.iter().enumerate().all(|(offset, kw)| {
+            matches!(self.toks.get(self.pos + offset).map(|t| &t.tok), Some(Tok::Word(w)) if w.eq_ignore_ascii_case(kw))
+        });
+        if matched {
+            self.pos += kws.len();
+        }
+        matched
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Tok::Punct(c))
+    }
+
+    fn eat_punct(&mut self, c: char) -> bool {
+        let hit = self.is_punct(c);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.toks.len()
+    }
+
+    /// A possibly schema-qualified name; returns the last segment.
+    fn name(&mut self) -> Option<String> {
+        let mut name = match self.peek()? {
+            Tok::Word(w) => w.clone(),
+            _ => return None,
+        };
+        self.pos += 1;
+        while self.is_punct('.') {
+            self.pos += 1;
+            match self.peek() {
+                Some(Tok::Word(w)) => {
+                    name = w.clone();
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        Some(name)
+    }
+
+    /// The parenthesised group at the cursor, split on top-level commas.
+    fn group(&mut self) -> Vec<&'a [Token]> {
+        if !self.is_punct('(') {
+            return Vec::new();
+        }
+        let start = self.pos + 1;
+        let mut depth = 0;
+        let mut items = Vec::new();
+        let mut item_start = start;
+        while let Some(tok) = self.peek() {
+            match tok {
+                Tok::Punct('(') => depth += 1,
+                Tok::Punct(')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        items.push(&self.toks[item_start..self.pos]);
+                        self.pos += 1;
+                        break;
+                    }
+                }
+                Tok::Punct(',') if depth == 1 => {
+                    items.push(&self.toks[item_start..self.pos]);
+                    item_start = self.pos + 1;
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        items.into_iter().filter(|i| !i.is_empty()).collect()
+    }
+
+    /// `(a, b DESC, lower(c))` → `["a", "b", "lower"]`.
+    fn column_list(&mut self) -> Vec<String> {
+        self.group()
+            .into_iter()
+            .filter_map(|item| match &item[0].tok {
+                Tok::Word(w) => Some(table_key(w)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Render tokens until one of `stops` appears at depth 0.
+    fn text_until(&mut self, stops: &[&str]) -> String {
+        let mut out = String::new();
+        let mut depth = 0i32;
+        while let Some(tok) = self.peek() {
+            match tok {
+                Tok::Word(w) if depth == 0 && stops.iter().any(|s| w.eq_ignore_ascii_case(s)) => break,
+                Tok::Punct(',') | Tok::Punct(')') if depth == 0 => break,
+                Tok::Punct('(') => depth += 1,
+                Tok::Punct(')') => depth -= 1,
+                _ => {}
+            }
+            push_token(&mut out, tok);
+            self.pos += 1;
+        }
+        out
+    }
+
+    fn statement(&mut self) -> Vec<SchemaOp> {
+        if self.eat_kw("CREATE") {
+            self.eat_kws(&["OR", "REPLACE"]);
+            if self.eat_kw("TEMP") || self.eat_kw("TEMPORARY") || self.eat_kws(&["GLOBAL", "TEMPORARY"]) || self.eat_kws(&["LOCAL", "TEMPORARY"]) {
+                return vec![];
+            }
+            self.eat_kw("UNLOGGED");
+            if self.eat_kw("TABLE") {
+                return self.create_table();
+            }
+            let unique = self.eat_kw("UNIQUE");
+            self.eat_kw("CLUSTERED");
+            self.eat_kw("NONCLUSTERED");
+            if self.eat_kw("INDEX") {
+                return self.create_index(unique);
+            }
+            return vec![];
+        }
+        if self.eat_kw("ALTER") && self.eat_kw("TABLE") {
+            return self.alter_table();
+        }
+        if self.eat_kw("DROP") {
+            if self.eat_kw("TABLE") {
+                self.eat_kws(&["IF", "EXISTS"]);
+                let mut ops = Vec::new();
+                while let Some(name) = self.name() {
+                    ops.push(SchemaOp::DropTable { name });
+                    if !self.eat_punct(',') {
+                        break;
+                    }
+                }
+                return ops;
+            }
+            if self.eat_kw("INDEX") {
+                self.eat_kw("CONCURRENTLY");
+                self.eat_kws(&["IF", "EXISTS"]);
+                return self.name().map(|name| SchemaOp::DropIndex { name }).into_iter().collect();
+            }
+            return vec![];
+        }
+        if self.eat_kws(&["RENAME", "TABLE"]) {
+            let mut ops = Vec::new();
+            while let Some(from) = self.name() {
+                if !self.eat_kw("TO") {
+                    break;
+                }
+                let Some(to) = self.name() else { break };
+                ops.push(SchemaOp::RenameTable { from, to });
+                if !self.eat_punct(',') {
+                    break;
+                }
+            }
+            return ops;
+        }
+        vec![]
+    }
+
+    fn create_table(&mut self) -> Vec<SchemaOp> {
+        self.eat_kws(&["IF", "NOT", "EXISTS"]);
+        let Some(name) = self.name() else { return vec![] };
+        if !self.is_punct('(') {
+            // `CREATE TABLE x AS SELECT …` / `LIKE y`: columns unknown.
+            return vec![SchemaOp::CreateTable { name, columns: vec![], indexes: vec![], foreign_keys: vec![] }];
+        }
+        let mut columns: Vec<Column> = Vec::new();
+        let mut indexes = Vec::new();
+        let mut foreign_keys = Vec::new();
+        let mut primary_key: Vec<String> = Vec::new();
+        for item in self.group() {
+            let mut p = Parser { toks: item, pos: 0 };
+            if TABLE_CONSTRAINTS.iter().any(|kw| p.is_kw(kw)) {
+                match p.table_constraint() {
+                    Some(Constraint::PrimaryKey(cols)) => primary_key = cols,
+                    Some(Constraint::Index(index)) => indexes.push(index),
+                    Some(Constraint::ForeignKey(fk)) => foreign_keys.push(fk),
+                    None => {}
+                }
+            } else if let Some((col, fk)) = p.column_def() {
+                if let Some(fk) = fk {
+                    foreign_keys.push(fk);
+                }
+                columns.push(col);
+            }
+        }
+        for col in &mut columns {
+            if primary_key.iter().any(|pk| col.name.eq_ignore_ascii_case(pk)) {
+                col.primary_key = true;
+                col.nullable = false;
+            }
+        }
+        for index in indexes.iter().filter(|i| i.unique && i.columns.len() == 1) {
+            if let Some(col) = columns.iter_mut().find(|c| c.name.eq_ignore_ascii_case(&index.columns[0])) {
+                col.unique = true;
+            }
+        }
+        vec![SchemaOp::CreateTable { name, columns, indexes, foreign_keys }]
+    }
+
+    fn create_index(&mut self, unique: bool) -> Vec<SchemaOp> {
+        self.eat_kw("CONCURRENTLY");
+        self.eat_kws(&["IF", "NOT", "EXISTS"]);
+        let name = if self.is_kw("ON") { None } else { self.name() };
+        if !self.eat_kw("ON") {
+            return vec![];
+        }
+        self.eat_kw("ONLY");
+        let Some(table) = self.name() else { return vec![] };
+        if self.eat_kw("USING") {
+            self.pos += 1;
+        }
+        let columns = self.column_list();
+        vec![SchemaOp::AddIndex { table, index: Index { name, columns, unique } }]
+    }
+
+    fn alter_table(&mut self) -> Vec<SchemaOp> {
+        self.eat_kws(&["IF", "EXISTS"]);
+        self.eat_kw("ONLY");
+        let Some(table) = self.name() else { return vec![] };
+        let mut ops = Vec::new();
+        // Actions are comma-separated at depth 0.
+        let rest = &self.toks[self.pos..];
+        let mut depth = 0;
+        let mut start = 0;
+        let mut actions = Vec::new();
+        for (i, t) in rest.iter().enumerate() {
+            match t.tok {
+                Tok::Punct('(') => depth += 1,
+                Tok::Punct(')') => depth -= 1,
+                Tok::Punct(',') if depth == 0 => {
+                    actions.push(&rest[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        actions.push(&rest[start..]);
+        for action in actions.into_iter().filter(|a| !a.is_empty()) {
+            let mut p = Parser { toks: action, pos: 0 };
+            ops.extend(p.alter_action(&table));
+        }
+        ops
+    }
+
+    fn alter_action(&mut self, table: &str) -> Vec<SchemaOp> {
+        let table = table.to_string();
+        if self.eat_kw("ADD") {
+            if TABLE_CONSTRAINTS.iter().any(|kw| self.is_kw(kw)) {
+                return match self.table_constraint() {
+                    Some(Constraint::PrimaryKey(columns)) => vec![SchemaOp::SetPrimaryKey { table, columns }],
+                    Some(Constraint::Index(index)) => vec![SchemaOp::AddIndex { table, index }],
+                    Some(Constraint::ForeignKey(foreign_key)) => vec![SchemaOp::AddForeignKey { table, foreign_key }],
+                    None => vec![],
+                };
+            }
+            self.eat_kw("COLUMN");
+            self.eat_kws(&["IF", "NOT", "EXISTS"]);
+            let Some((column, fk)) = self.column_def() else { return vec![] };
+            let mut ops = vec![SchemaOp::AddColumn { table: table.clone(), column }];
+            if let Some(foreign_key) = fk {
+                ops.push(SchemaOp::AddForeignKey { table, foreign_key });
+            }
+            return ops;
+        }
+        if self.eat_kw("DROP") {
+            if ["CONSTRAINT", "INDEX", "KEY", "PRIMARY", "FOREIGN", "DEFAULT"].iter().any(|kw| self.is_kw(kw)) {
+                return vec![];
+            }
+            self.eat_kw("COLUMN");
+            self.eat_kws(&["IF", "EXISTS"]);
+            return self.name().map(|column| SchemaOp::DropColumn { table, column }).into_iter().collect();
+        }
+        if self.eat_kw("ALTER") {
+            self.eat_kw("COLUMN");
+            let Some(column) = self.name() else { return vec![] };
+            let mut change = ColumnChange::default();
+            if self.eat_kws(&["SET", "NOT", "NULL"]) {
+                change.nullable = Some(false);
+            } else if self.eat_kws(&["DROP", "NOT", "NULL"]) {
+                change.nullable = Some(true);
+            } else if self.eat_kws(&["SET", "DEFAULT"]) {
+                change.default = Some(Some(self.text_until(&[])));
+            } else if self.eat_kws(&["DROP", "DEFAULT"]) {
+                change.default = Some(None);
+            } else {
+                // PostgreSQL `[SET DATA] TYPE t`, SQL Server `t [NOT] NULL`.
+                self.eat_kws(&["SET", "DATA"]);
+                self.eat_kw("TYPE");
+                let ty = self.text_until(COLUMN_CONSTRAINTS);
+                if !ty.is_empty() {
+                    change.data_type = Some(ty.to_lowercase());
+                }
+                if self.eat_kws(&["NOT", "NULL"]) {
+                    change.nullable = Some(false);
+                } else if self.eat_kw("NULL") {
+                    change.nullable = Some(true);
+                }
+            }
+            return vec![SchemaOp::AlterColumn { table, column, change }];
+        }
+        if self.eat_kw("MODIFY") {
+            self.eat_kw("COLUMN");
+            return self.column_def()
+                .map(|(column, _)| SchemaOp::ReplaceColumn { table, old: column.name.clone(), column })
+                .into_iter()
+                .collect();
+        }
+        if self.eat_kw("CHANGE") {
+            self.eat_kw("COLUMN");
+            let Some(old) = self.name() else { return vec![] };
+            return self.column_def()
+                .map(|(column, _)| SchemaOp::ReplaceColumn { table, old, column })
+                .into_iter()
+                .collect();
+        }
+        if self.eat_kw("RENAME") {
+            if self.eat_kw("TO") || self.eat_kw("AS") {
+                return self.name().map(|to| SchemaOp::RenameTable { from: table, to }).into_iter().collect();
+            }
+            if self.is_kw("CONSTRAINT") || self.is_kw("INDEX") || self.is_kw("KEY") {
+                return vec![];
+            }
+            self.eat_kw("COLUMN");
+            let Some(from) = self.name() else { return vec![] };
+            if !self.eat_kw("TO") {
+                return vec![];
+            }
+            return self.name().map(|to| SchemaOp::RenameColumn { table, from, to }).into_iter().collect();
+        }
+        vec![]
+    }
+
+    /// `name type [constraints…]`.
+    fn column_def(&mut self) -> Option<(Column, Option<ForeignKey>)> {
+        let line = self.line();
+        let name = match self.peek()? {
+            Tok::Word(w) => w.clone(),
+            _ => return None,
+        };
+        self.pos += 1;
+        let data_type = self.text_until(COLUMN_CONSTRAINTS).to_lowercase();
+        let mut col = column(&name, &data_type, line);
+        if data_type.contains("serial") {
+            col.nullable = false;
+        }
+        let mut fk = None;
+        while !self.at_end() {
+            if self.eat_kws(&["NOT", "NULL"]) {
+                col.nullable = false;
+            } else if self.eat_kw("NULL") {
+                col.nullable = true;
+            } else if self.eat_kws(&["PRIMARY", "KEY"]) {
+                col.primary_key = true;
+                col.nullable = false;
+            } else if self.eat_kw("UNIQUE") {
+                self.eat_kw("KEY");
+                col.unique = true;
+            } else if self.eat_kw("DEFAULT") {
+                let default = self.text_until(COLUMN_CONSTRAINTS);
+                col.default = if !default.is_empty() {
+                    Some(default)
+                } else if self.eat_kw("NULL") {
+                    col.nullable = true;
+                    Some("NULL".to_string())
+                } else {
+                    None
+                };
+            } else if self.eat_kw("REFERENCES") {
+                fk = self.references(vec![col.name.clone()]);
+            } else if self.eat_kw("CONSTRAINT") || self.eat_kw("COLLATE") || self.eat_kw("COMMENT") {
+                self.pos += 1;
+            } else if self.eat_kw("CHECK") || self.eat_kw("IDENTITY") {
+                self.group();
+            } else {
+                self.pos += 1;
+            }
+        }
+        Some((col, fk))
+    }
+
+    /// After `REFERENCES`: `table [(cols)] [ON DELETE action]`.
+    fn references(&mut self, columns: Vec<String>) -> Option<ForeignKey> {
+        let ref_table = table_key(&self.name()?);
+        let ref_columns = self.column_list();
+        let mut on_delete = None;
+        while self.eat_kw("ON") {
+            let is_delete = self.eat_kw("DELETE");
+            if !is_delete {
+                self.eat_kw("UPDATE");
+            }
+            let mut action = Vec::new();
+            while let Some(Tok::Word(w)) = self.peek() {
+                if w.eq_ignore_ascii_case("ON") || COLUMN_CONSTRAINTS.iter().any(|k| k != &"NULL" && w.eq_ignore_ascii_case(k)) {
+                    break;
+                }
+                action.push(w.to_uppercase());
+                self.pos += 1;
+            }
+            if is_delete {
+                on_delete = Some(action.join(" "));
+            }
+        }
+        Some(ForeignKey { columns, ref_table, ref_columns, on_delete })
+    }
+
+    fn table_constraint(&mut self) -> Option<Constraint> {
+        if self.eat_kw("CONSTRAINT") {
+            self.pos += 1;
+        }
+        if self.eat_kws(&["PRIMARY", "KEY"]) {
+            self.eat_kw("CLUSTERED");
+            return Some(Constraint::PrimaryKey(self.column_list()));
+        }
+        if self.eat_kws(&["FOREIGN", "KEY"]) {
+            let columns = self.column_list();
+            if !self.eat_kw("REFERENCES") {
+                return None;
+            }
+            return self.references(columns).map(Constraint::ForeignKey);
+        }
+        let unique = self.eat_kw("UNIQUE");
+        let keyword = self.eat_kw("INDEX") || self.eat_kw("KEY");
+        if !(unique || keyword || self.eat_kw("FULLTEXT") || self.eat_kw("SPATIAL")) {
+            return None;
+        }
+        self.eat_kw("INDEX");
+        self.eat_kw("KEY");
+        let name = if self.is_punct('(') { None } else { self.name() };
+        Some(Constraint::Index(Index { name, columns: self.column_list(), unique }))
+    }
+}
+
+enum Constraint {
+    PrimaryKey(Vec<String>),
+    Index(Index),
+    ForeignKey(ForeignKey),
+}
+
+fn push_token(out: &mut String, tok: &Tok) {
+    match tok {
+        Tok::Word(w) => {
+            if !out.is_empty() && !out.ends_with('(') && !out.ends_with('.') {
+                out.push(' ');
+            }
+            out.push_str(w);
+        }
+        Tok::Str(s) => {
+            if !out.is_empty() && !out.ends_with('(') {
+                out.push(' ');
+            }
+            out.push('\'');
+            out.push_str(s);
+            out.push('\'');
+        }
+        Tok::Punct(c) => {
+            if *c == '(' && out.ends_with(' ') {
+                out.pop();
+            }
+            out.push(*c);
+        }
+    }
+}
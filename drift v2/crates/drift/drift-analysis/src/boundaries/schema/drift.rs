@@ -0,0 +1,189 @@
+//! Drift between the migration-derived schema and ORM models.
+
+use serde::{Deserialize, Serialize};
+
+use super::super::types::ExtractedModel;
+use super::{table_key, DatabaseSchema, Table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SchemaDriftKind {
+    /// Model maps to a table no migration creates.
+    MissingTable,
+    /// Model field has no matching column.
+    MissingColumn,
+    /// Field and column disagree on nullability.
+    NullabilityMismatch,
+    /// NOT NULL column without a default that the model never sets.
+    UnmappedRequiredColumn,
+}
+
+impl SchemaDriftKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MissingTable => "missing_table",
+            Self::MissingColumn => "missing_column",
+            Self::NullabilityMismatch => "nullability_mismatch",
+            Self::UnmappedRequiredColumn => "unmapped_required_column",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaDrift {
+    pub kind: SchemaDriftKind,
+    pub model: String,
+    pub table: String,
+    pub column: Option<String>,
+    /// Model location for model-side findings, migration location for
+    /// `UnmappedRequiredColumn`.
+    pub file: String,
+    pub line: u32,
+    pub message: String,
+}
+
+/// Compare models against the schema. Models whose table cannot be resolved
+/// are reported once as `MissingTable`; an empty schema yields no drift.
+pub fn compare_models(schema: &DatabaseSchema, models: &[ExtractedModel]) -> Vec<SchemaDrift> {
+    let mut drift = Vec::new();
+    if schema.tables.is_empty() {
+        return drift;
+    }
+    let model_names: Vec<String> = models.iter().map(|m| normalize(&m.name)).collect();
+
+    for model in models {
+        let Some(table) = resolve_table(schema, model) else {
+            let table = model.table_name.clone().unwrap_or_else(|| model.name.clone());
+            drift.push(SchemaDrift {
+                kind: SchemaDriftKind::MissingTable,
+                model: model.name.clone(),
+                table: table.clone(),
+                column: None,
+                file: model.file.clone(),
+                line: model.line,
+                message: format!("model `{}` maps to table `{}`, which no migration creates", model.name, table),
+            });
+            continue;
+        };
+
+        for field in &model.fields {
+            let column = table
+                .column(&field.name)
+                .or_else(|| table.column(&snake_case(&field.name)));
+            match column {
+                Some(column) => {
+                    // Primary keys are implicitly NOT NULL; ORMs rarely say so.
+                    if column.primary_key || field.is_primary_key || column.nullable == field.is_nullable {
+                        continue;
+                    }
+                    drift.push(SchemaDrift {
+                        kind: SchemaDriftKind::NullabilityMismatch,
+                        model: model.name.clone(),
+                        table: table.name.clone(),
+                        column: Some(column.name.clone()),
+                        file: model.file.clone(),
+                        line: field.line,
+                        message: format!(
+                            "`{}.{}` is {} in the model but {} in the schema",
+                            model.name,
+                            field.name,
+                            if field.is_nullable { "nullable" } else { "required" },
+                            if column.nullable { "NULL" } else { "NOT NULL" },
+                        ),
+                    });
+                }
+                None if !is_relation(table, field.name.as_str(), field.field_type.as_deref(), &model_names) => {
+                    drift.push(SchemaDrift {
+                        kind: SchemaDriftKind::MissingColumn,
+                        model: model.name.clone(),
+                        table: table.name.clone(),
+                        column: Some(field.name.clone()),
+                        file: model.file.clone(),
+                        line: field.line,
+                        message: format!("`{}.{}` has no column in table `{}`", model.name, field.name, table.name),
+                    });
+                }
+                None => {}
+            }
+        }
+
+        for column in &table.columns {
+            if column.nullable || column.primary_key || column.default.is_some() {
+                continue;
+            }
+            let mapped = model.fields.iter().any(|f| {
+                f.name.eq_ignore_ascii_case(&column.name)
+                    || snake_case(&f.name) == column.name
+                    || column.name.strip_suffix("_id").is_some_and(|base| normalize(&f.name) == normalize(base))
+            });
+            if !mapped && !is_managed_column(&column.name) {
+                drift.push(SchemaDrift {
+                    kind: SchemaDriftKind::UnmappedRequiredColumn,
+                    model: model.name.clone(),
+                    table: table.name.clone(),
+                    column: Some(column.name.clone()),
+                    file: column.file.clone(),
+                    line: column.line,
+                    message: format!(
+                        "`{}.{}` is NOT NULL without a default but model `{}` never sets it",
+                        table.name, column.name, model.name
+                    ),
+                });
+            }
+        }
+    }
+    drift
+}
+
+fn resolve_table<'a>(schema: &'a DatabaseSchema, model: &ExtractedModel) -> Option<&'a Table> {
+    if let Some(table) = model.table_name.as_deref().and_then(|t| schema.table(t)) {
+        return Some(table);
+    }
+    let name = normalize(&model.name);
+    let candidates = [name.clone(), format!("{name}s"), format!("{name}es"), name.strip_suffix('y').map(|s| format!("{s}ies")).unwrap_or_default()];
+    schema
+        .tables
+        .iter()
+        .find(|(key, _)| {
+            let key = normalize(key);
+            candidates.iter().any(|c| !c.is_empty() && *c == key)
+        })
+        .map(|(_, table)| table)
+}
+
+/// Association fields (`author: User`, `posts: Post[]`, `author` with an
+/// `author_id` column) live in other tables or in a foreign key column.
+fn is_relation(table: &Table, field: &str, field_type: Option<&str>, model_names: &[String]) -> bool {
+    if table.column(&format!("{}_id", snake_case(field))).is_some() {
+        return true;
+    }
+    let Some(ty) = field_type else { return false };
+    if ty.ends_with("[]") || ty.starts_with("Vec<") || ty.starts_with("List<") || ty.starts_with("list[") {
+        return true;
+    }
+    let base = ty.trim_end_matches('?').trim_end_matches('!');
+    model_names.contains(&normalize(base))
+}
+
+/// Columns maintained by the database or framework rather than model code.
+fn is_managed_column(name: &str) -> bool {
+    matches!(name, "created_at" | "updated_at" | "inserted_at" | "lock_version" | "version")
+}
+
+fn normalize(name: &str) -> String {
+    table_key(name).replace('_', "")
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,170 @@
+//! Migration discovery and ordering.
+//!
+//! Versioned SQL (Flyway `V1_2__x.sql`, golang-migrate `0001_x.up.sql`,
+//! numbered scripts) and Rails timestamps sort by their numeric version;
+//! Flyway repeatables (`R__x.sql`) run after all versioned files; Alembic
+//! revisions follow their `down_revision` chain. Unversioned SQL
+//! (`schema.sql`) is applied first as a baseline.
+
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "native")]
+use std::path::Path;
+
+use super::{alembic, ddl, rails, SchemaOp};
+
+/// A migration source file (path relative to the project root).
+#[derive(Debug, Clone)]
+pub struct MigrationFile {
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MigrationKind {
+    Sql,
+    Rails,
+    Alembic,
+}
+
+impl MigrationFile {
+    pub fn new(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self { path: path.into(), content: content.into() }
+    }
+
+    pub fn kind(&self) -> MigrationKind {
+        if self.path.ends_with(".rb") {
+            MigrationKind::Rails
+        } else if self.path.ends_with(".py") {
+            MigrationKind::Alembic
+        } else {
+            MigrationKind::Sql
+        }
+    }
+
+    pub fn parse(&self) -> Vec<(u32, SchemaOp)> {
+        match self.kind() {
+            MigrationKind::Sql => ddl::parse(&self.content),
+            MigrationKind::Rails => rails::parse(&self.content),
+            MigrationKind::Alembic => alembic::parse(&self.content),
+        }
+    }
+
+    fn file_name(&self) -> &str {
+        self.path.rsplit(['/', '\\']).next().unwrap_or(&self.path)
+    }
+
+    /// Sort key: `(group, version, path)`; group 0 = baseline, 1 = versioned,
+    /// 2 = repeatable.
+    fn version_key(&self) -> (u8, Vec<u64>) {
+        let name = self.file_name();
+        if name.starts_with("R__") {
+            return (2, vec![]);
+        }
+        let versioned = name.strip_prefix(['V', 'v']).filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit())).unwrap_or(name);
+        let digits_end = versioned.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_')).unwrap_or(versioned.len());
+        let version: Vec<u64> = versioned[..digits_end]
+            .split("__")
+            .next()
+            .unwrap_or("")
+            .split(['.', '_'])
+            .filter_map(|part| part.parse().ok())
+            .collect();
+        if version.is_empty() {
+            (0, vec![])
+        } else {
+            (1, version)
+        }
+    }
+}
+
+/// Whether a path looks like a migration this module can replay.
+pub fn is_migration_path(path: &str) -> bool {
+    let path = path.replace('\\', "/");
+    let lower = path.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    if lower.ends_with(".rb") {
+        return lower.contains("db/migrate/");
+    }
+    if lower.ends_with(".py") {
+        return lower.contains("/versions/") || lower.starts_with("versions/");
+    }
+    if !lower.ends_with(".sql") || name.ends_with(".down.sql") || name.starts_with("u") && name[1..].starts_with(|c: char| c.is_ascii_digit()) {
+        // golang-migrate down files and Flyway undo scripts revert, not build.
+        return false;
+    }
+    let dir_hint = ["migration", "migrate", "flyway", "liquibase", "schema", "ddl", "db/"]
+        .iter()
+        .any(|hint| lower.contains(hint));
+    let name_hint = (name.starts_with('v') && name[1..].starts_with(|c: char| c.is_ascii_digit()))
+        || name.starts_with("r__")
+        || name.ends_with(".up.sql")
+        || name.contains("schema");
+    dir_hint || name_hint
+}
+
+/// Apply order for a set of migrations.
+pub fn order(files: &[MigrationFile]) -> Vec<&MigrationFile> {
+    let (alembic_files, mut others): (Vec<&MigrationFile>, Vec<&MigrationFile>) =
+        files.iter().partition(|f| f.kind() == MigrationKind::Alembic);
+    others.sort_by(|a, b| a.version_key().cmp(&b.version_key()).then_with(|| a.path.cmp(&b.path)));
+    others.extend(alembic_chain(alembic_files));
+    others
+}
+
+/// Topological order of Alembic revisions: roots first, then children.
+/// Files without a revision id (or cycles) are appended by path.
+fn alembic_chain(mut files: Vec<&MigrationFile>) -> Vec<&MigrationFile> {
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    let revisions: HashMap<String, usize> = files
+        .iter()
+        .enumerate()
+        .filter_map(|(i, f)| alembic::revision(&f.content).map(|r| (r, i)))
+        .collect();
+    let mut children: HashMap<Option<usize>, Vec<usize>> = HashMap::new();
+    for (i, f) in files.iter().enumerate() {
+        let parent = alembic::down_revision(&f.content).and_then(|d| revisions.get(&d).copied());
+        children.entry(parent).or_default().push(i);
+    }
+    let mut ordered = Vec::with_capacity(files.len());
+    let mut seen = HashSet::new();
+    let mut stack: Vec<usize> = children.get(&None).cloned().unwrap_or_default();
+    stack.reverse();
+    while let Some(i) = stack.pop() {
+        if !seen.insert(i) {
+            continue;
+        }
+        ordered.push(files[i]);
+        if let Some(kids) = children.get(&Some(i)) {
+            stack.extend(kids.iter().rev());
+        }
+    }
+    for (i, f) in files.iter().enumerate() {
+        if !seen.contains(&i) {
+            ordered.push(f);
+        }
+    }
+    ordered
+}
+
+/// Walk a project for migration files (respecting `.gitignore`).
+#[cfg(feature = "native")]
+pub fn discover_migrations(root: &Path) -> Vec<MigrationFile> {
+    let mut files = Vec::new();
+    for entry in ignore::WalkBuilder::new(root).hidden(true).build().flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let Ok(rel) = entry.path().strip_prefix(root) else { continue };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        if rel.contains("node_modules/") || rel.contains("vendor/") || !is_migration_path(&rel) {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(entry.path()) else { continue };
+        // Alembic's `versions/` is a common directory name; require `op.` usage.
+        if rel.ends_with(".py") && !content.contains("def upgrade") {
+            continue;
+        }
+        files.push(MigrationFile::new(rel, content));
+    }
+    files
+}
@@ -0,0 +1,339 @@
+//! Database schema reconstruction from migrations.
+//!
+//! ORM extractors only see the model side of the boundary. The schema itself
+//! usually lives in migrations: raw SQL (Flyway, Liquibase formatted SQL,
+//! golang-migrate, plain `CREATE TABLE` dumps), Rails `db/migrate` and Alembic
+//! revisions. Each migration is parsed into `SchemaOp`s and replayed in order
+//! into a `DatabaseSchema`, which is then compared against `ExtractedModel`s
+//! (`drift`) and scanned for sensitive columns.
+
+pub mod ddl;
+pub mod rails;
+pub mod alembic;
+pub mod migrations;
+pub mod drift;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{ExtractedField, ExtractedModel, OrmFramework};
+
+pub use drift::{compare_models, SchemaDrift, SchemaDriftKind};
+#[cfg(feature = "native")]
+pub use migrations::discover_migrations;
+pub use migrations::{MigrationFile, MigrationKind};
+
+/// Reconstructed database schema, keyed by lowercased unqualified table name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseSchema {
+    pub tables: BTreeMap<String, Table>,
+    /// Standalone indexes (`CREATE INDEX name ON ...`) by name → table key,
+    /// so `DROP INDEX name` can find them.
+    pub index_owners: BTreeMap<String, String>,
+    /// Migration files in the order they were applied.
+    pub applied: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+    pub indexes: Vec<Index>,
+    pub foreign_keys: Vec<ForeignKey>,
+    /// ORM the defining migration belongs to (`Unknown` for raw SQL).
+    pub framework: OrmFramework,
+    pub file: String,
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Column {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub primary_key: bool,
+    pub unique: bool,
+    pub default: Option<String>,
+    pub file: String,
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Index {
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub ref_table: String,
+    pub ref_columns: Vec<String>,
+    pub on_delete: Option<String>,
+}
+
+/// Partial column change (`ALTER COLUMN`, `change_column_null`, …).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnChange {
+    pub data_type: Option<String>,
+    pub nullable: Option<bool>,
+    /// `Some(None)` drops the default.
+    pub default: Option<Option<String>>,
+}
+
+/// A single schema mutation produced by a migration parser.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaOp {
+    CreateTable { name: String, columns: Vec<Column>, indexes: Vec<Index>, foreign_keys: Vec<ForeignKey> },
+    DropTable { name: String },
+    RenameTable { from: String, to: String },
+    AddColumn { table: String, column: Column },
+    DropColumn { table: String, column: String },
+    RenameColumn { table: String, from: String, to: String },
+    AlterColumn { table: String, column: String, change: ColumnChange },
+    /// MySQL `MODIFY`/`CHANGE`: replace a column definition (possibly renaming it).
+    ReplaceColumn { table: String, old: String, column: Column },
+    SetPrimaryKey { table: String, columns: Vec<String> },
+    AddIndex { table: String, index: Index },
+    DropIndex { name: String },
+    AddForeignKey { table: String, foreign_key: ForeignKey },
+}
+
+/// Lookup key for table and column names: unquoted, unqualified, lowercase.
+pub fn table_key(name: &str) -> String {
+    let name = name.rsplit('.').next().unwrap_or(name);
+    name.trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']' || c == '\'')
+        .to_lowercase()
+}
+
+impl Table {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            columns: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            framework: OrmFramework::Unknown,
+            file: String::new(),
+            line: 0,
+        }
+    }
+
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        let key = table_key(name);
+        self.columns.iter().find(|c| c.name.eq_ignore_ascii_case(&key))
+    }
+
+    fn column_mut(&mut self, name: &str) -> Option<&mut Column> {
+        let key = table_key(name);
+        self.columns.iter_mut().find(|c| c.name.eq_ignore_ascii_case(&key))
+    }
+
+    /// The table as an `ExtractedModel`, so ORM-side analyses (sensitivity,
+    /// drift) can treat migrations as one more model source.
+    pub fn to_extracted_model(&self) -> ExtractedModel {
+        ExtractedModel {
+            name: self.name.clone(),
+            table_name: Some(self.name.clone()),
+            file: self.file.clone(),
+            line: self.line,
+            framework: self.framework,
+            fields: self
+                .columns
+                .iter()
+                .map(|c| ExtractedField {
+                    name: c.name.clone(),
+                    field_type: Some(c.data_type.clone()),
+                    is_primary_key: c.primary_key,
+                    is_nullable: c.nullable,
+                    is_unique: c.unique,
+                    default_value: c.default.clone(),
+                    line: c.line,
+                })
+                .collect(),
+            relationships: Vec::new(),
+            confidence: 0.95,
+        }
+    }
+}
+
+impl DatabaseSchema {
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.get(&table_key(name))
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.tables.values().map(|t| t.columns.len()).sum()
+    }
+
+    /// Apply one operation. Operations against unknown tables or columns are
+    /// ignored: partial histories (squashed migrations, tables owned by
+    /// another service) are the norm, not an error.
+    pub fn apply(&mut self, op: SchemaOp, file: &str, line: u32, framework: OrmFramework) {
+        match op {
+            SchemaOp::CreateTable { name, mut columns, indexes, foreign_keys } => {
+                for column in &mut columns {
+                    column.file = file.to_string();
+                }
+                let key = table_key(&name);
+                self.index_owners.retain(|_, owner| *owner != key);
+                self.tables.insert(key, Table {
+                    name: table_key(&name),
+                    columns,
+                    indexes,
+                    foreign_keys,
+                    framework,
+                    file: file.to_string(),
+                    line,
+                });
+            }
+            SchemaOp::DropTable { name } => {
+                let key = table_key(&name);
+                self.tables.remove(&key);
+                self.index_owners.retain(|_, owner| *owner != key);
+            }
+            SchemaOp::RenameTable { from, to } => {
+                if let Some(mut table) = self.tables.remove(&table_key(&from)) {
+                    let (old, new) = (table_key(&from), table_key(&to));
+                    table.name = new.clone();
+                    for owner in self.index_owners.values_mut().filter(|o| **o == old) {
+                        *owner = new.clone();
+                    }
+                    self.tables.insert(new, table);
+                }
+            }
+            SchemaOp::AddColumn { table, mut column } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    column.file = file.to_string();
+                    t.columns.retain(|c| !c.name.eq_ignore_ascii_case(&column.name));
+                    t.columns.push(column);
+                }
+            }
+            SchemaOp::DropColumn { table, column } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    let key = table_key(&column);
+                    t.columns.retain(|c| !c.name.eq_ignore_ascii_case(&key));
+                    t.indexes.retain(|i| !i.columns.iter().any(|c| c.eq_ignore_ascii_case(&key)));
+                    t.foreign_keys.retain(|f| !f.columns.iter().any(|c| c.eq_ignore_ascii_case(&key)));
+                }
+            }
+            SchemaOp::RenameColumn { table, from, to } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    let (old, new) = (table_key(&from), table_key(&to));
+                    if let Some(c) = t.column_mut(&old) {
+                        c.name = new.clone();
+                    }
+                    let rename = |cols: &mut Vec<String>| {
+                        for c in cols.iter_mut().filter(|c| c.eq_ignore_ascii_case(&old)) {
+                            *c = new.clone();
+                        }
+                    };
+                    t.indexes.iter_mut().for_each(|i| rename(&mut i.columns));
+                    t.foreign_keys.iter_mut().for_each(|f| rename(&mut f.columns));
+                }
+            }
+            SchemaOp::AlterColumn { table, column, change } => {
+                if let Some(c) = self.tables.get_mut(&table_key(&table)).and_then(|t| t.column_mut(&column)) {
+                    if let Some(ty) = change.data_type {
+                        c.data_type = ty;
+                    }
+                    if let Some(nullable) = change.nullable {
+                        c.nullable = nullable;
+                    }
+                    if let Some(default) = change.default {
+                        c.default = default;
+                    }
+                }
+            }
+            SchemaOp::ReplaceColumn { table, old, mut column } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    column.file = file.to_string();
+                    match t.columns.iter().position(|c| c.name.eq_ignore_ascii_case(&table_key(&old))) {
+                        Some(pos) => t.columns[pos] = column,
+                        None => t.columns.push(column),
+                    }
+                }
+            }
+            SchemaOp::SetPrimaryKey { table, columns } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    for c in &mut t.columns {
+                        if columns.iter().any(|pk| c.name.eq_ignore_ascii_case(&table_key(pk))) {
+                            c.primary_key = true;
+                            c.nullable = false;
+                        }
+                    }
+                }
+            }
+            SchemaOp::AddIndex { table, index } => {
+                let key = table_key(&table);
+                if let Some(t) = self.tables.get_mut(&key) {
+                    if index.unique && index.columns.len() == 1 {
+                        if let Some(c) = t.column_mut(&index.columns[0]) {
+                            c.unique = true;
+                        }
+                    }
+                    if let Some(name) = &index.name {
+                        self.index_owners.insert(table_key(name), key);
+                    }
+                    t.indexes.push(index);
+                }
+            }
+            SchemaOp::DropIndex { name } => {
+                let name = table_key(&name);
+                if let Some(owner) = self.index_owners.remove(&name) {
+                    if let Some(t) = self.tables.get_mut(&owner) {
+                        let dropped: Vec<Index> = t.indexes
+                            .iter()
+                            .filter(|i| i.name.as_deref().is_some_and(|n| n.eq_ignore_ascii_case(&name)))
+                            .cloned()
+                            .collect();
+                        t.indexes.retain(|i| !dropped.contains(i));
+                        for index in dropped.iter().filter(|i| i.unique && i.columns.len() == 1) {
+                            if let Some(c) = t.column_mut(&index.columns[0]) {
+                                c.unique = false;
+                            }
+                        }
+                    }
+                }
+            }
+            SchemaOp::AddForeignKey { table, foreign_key } => {
+                if let Some(t) = self.tables.get_mut(&table_key(&table)) {
+                    t.foreign_keys.push(foreign_key);
+                }
+            }
+        }
+    }
+
+    /// Replay migrations (ordered with `migrations::order`) into a schema.
+    pub fn replay(files: &[MigrationFile]) -> Self {
+        let mut schema = Self::default();
+        for file in migrations::order(files) {
+            let framework = match file.kind() {
+                MigrationKind::Rails => OrmFramework::ActiveRecord,
+                MigrationKind::Alembic => OrmFramework::SqlAlchemy,
+                MigrationKind::Sql => OrmFramework::Unknown,
+            };
+            for (line, op) in file.parse() {
+                schema.apply(op, &file.path, line, framework);
+            }
+            schema.applied.push(file.path.clone());
+        }
+        schema
+    }
+}
+
+/// New column with the parser's defaults (nullable, no constraints).
+pub(crate) fn column(name: &str, data_type: &str, line: u32) -> Column {
+    Column {
+        name: table_key(name),
+        data_type: data_type.to_string(),
+        nullable: true,
+        primary_key: false,
+        unique: false,
+        default: None,
+        file: String::new(),
+        line,
+    }
+}
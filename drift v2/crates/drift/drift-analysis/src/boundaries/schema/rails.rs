@@ -0,0 +1,381 @@
+//! Rails migration DSL (`db/migrate/*.rb`): `create_table` blocks, column
+//! helpers and the `add_*`/`remove_*`/`rename_*`/`change_*` statements.
+//! Only `change`/`up` are replayed; `down` bodies are skipped.
+
+use super::{column, table_key, Column, ColumnChange, ForeignKey, Index, SchemaOp};
+
+/// `t.<type>` helpers that declare a column.
+const COLUMN_TYPES: &[&str] = &[
+    "string", "text", "integer", "bigint", "float", "decimal", "numeric", "datetime", "timestamp",
+    "time", "date", "binary", "boolean", "json", "jsonb", "uuid", "citext", "inet", "hstore",
+    "smallint", "money", "virtual", "enum",
+];
+
+pub fn parse(source: &str) -> Vec<(u32, SchemaOp)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut ops = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line_no = (i + 1) as u32;
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with("def down") || trimmed.starts_with("def self.down") {
+            i = skip_block(&lines, i);
+            continue;
+        }
+        let Some((call, args)) = split_call(trimmed) else {
+            i += 1;
+            continue;
+        };
+        let args_list = split_args(args);
+        let positional: Vec<String> = args_list.iter().filter(|a| !is_option(a)).map(|a| symbol(a)).collect();
+        let opt = |key: &str| option(&args_list, key);
+        match call {
+            "create_table" => {
+                let Some(name) = positional.first() else {
+                    i += 1;
+                    continue;
+                };
+                let mut columns = Vec::new();
+                let mut indexes = Vec::new();
+                let mut foreign_keys = Vec::new();
+                match opt("id").as_deref() {
+                    Some("false") => {}
+                    id_type => {
+                        let mut id = column("id", id_type.map(symbol).as_deref().unwrap_or("bigint"), line_no);
+                        id.primary_key = true;
+                        id.nullable = false;
+                        columns.push(id);
+                    }
+                }
+                let end = if trimmed.contains(" do") { skip_block(&lines, i) } else { i + 1 };
+                for (offset, body_line) in lines[i + 1..end.saturating_sub(1).max(i + 1)].iter().enumerate() {
+                    let body_line_no = line_no + 1 + offset as u32;
+                    table_body_line(body_line.trim(), body_line_no, &mut columns, &mut indexes, &mut foreign_keys);
+                }
+                if opt("force").is_some() {
+                    ops.push((line_no, SchemaOp::DropTable { name: name.clone() }));
+                }
+                ops.push((line_no, SchemaOp::CreateTable { name: name.clone(), columns, indexes, foreign_keys }));
+                i = end;
+                continue;
+            }
+            "drop_table" => {
+                if let Some(name) = positional.first() {
+                    ops.push((line_no, SchemaOp::DropTable { name: name.clone() }));
+                }
+            }
+            "rename_table" => {
+                if let [from, to, ..] = positional.as_slice() {
+                    ops.push((line_no, SchemaOp::RenameTable { from: from.clone(), to: to.clone() }));
+                }
+            }
+            "add_column" => {
+                if let [table, name, ty, ..] = positional.as_slice() {
+                    ops.push((line_no, SchemaOp::AddColumn { table: table.clone(), column: rails_column(name, ty, &args_list, line_no) }));
+                }
+            }
+            "remove_column" => {
+                if let [table, name, ..] = positional.as_slice() {
+                    ops.push((line_no, SchemaOp::DropColumn { table: table.clone(), column: name.clone() }));
+                }
+            }
+            "remove_columns" => {
+                if let [table, names @ ..] = positional.as_slice() {
+                    for name in names {
+                        ops.push((line_no, SchemaOp::DropColumn { table: table.clone(), column: name.clone() }));
+                    }
+                }
+            }
+            "rename_column" => {
+                if let [table, from, to, ..] = positional.as_slice() {
+                    ops.push((line_no, SchemaOp::RenameColumn { table: table.clone(), from: from.clone(), to: to.clone() }));
+                }
+            }
+            "change_column" => {
+                if let [table, name, ty, ..] = positional.as_slice() {
+                    let change = ColumnChange {
+                        data_type: Some(ty.clone()),
+                        nullable: opt("null").map(|v| v != "false"),
+                        default: opt("default").map(Some),
+                    };
+                    ops.push((line_no, SchemaOp::AlterColumn { table: table.clone(), column: name.clone(), change }));
+                }
+            }
+            "change_column_null" => {
+                if let [table, name, nullable, ..] = positional.as_slice() {
+                    let change = ColumnChange { nullable: Some(nullable != "false"), ..Default::default() };
+                    ops.push((line_no, SchemaOp::AlterColumn { table: table.clone(), column: name.clone(), change }));
+                }
+            }
+            "change_column_default" => {
+                if let [table, name, ..] = positional.as_slice() {
+                    let default = opt("to").or_else(|| positional.get(2).cloned()).filter(|d| d != "nil");
+                    let change = ColumnChange { default: Some(default), ..Default::default() };
+                    ops.push((line_no, SchemaOp::AlterColumn { table: table.clone(), column: name.clone(), change }));
+                }
+            }
+            "add_index" => {
+                if let Some(table) = positional.first() {
+                    let index = Index {
+                        name: opt("name"),
+                        columns: column_args(args_list.get(1).map(String::as_str).unwrap_or("")),
+                        unique: opt("unique").as_deref() == Some("true"),
+                    };
+                    ops.push((line_no, SchemaOp::AddIndex { table: table.clone(), index }));
+                }
+            }
+            "remove_index" => {
+                if let Some(name) = opt("name") {
+                    ops.push((line_no, SchemaOp::DropIndex { name }));
+                }
+            }
+            "add_reference" | "add_belongs_to" => {
+                if let [table, reference, ..] = positional.as_slice() {
+                    let (col, fk) = reference_column(reference, &args_list, line_no);
+                    ops.push((line_no, SchemaOp::AddColumn { table: table.clone(), column: col }));
+                    if let Some(foreign_key) = fk {
+                        ops.push((line_no, SchemaOp::AddForeignKey { table: table.clone(), foreign_key }));
+                    }
+                }
+            }
+            "add_foreign_key" => {
+                if let [table, to, ..] = positional.as_slice() {
+                    let column = opt("column").unwrap_or_else(|| format!("{}_id", singular(to)));
+                    let foreign_key = ForeignKey {
+                        columns: vec![column],
+                        ref_table: table_key(to),
+                        ref_columns: vec![opt("primary_key").unwrap_or_else(|| "id".to_string())],
+                        on_delete: opt("on_delete"),
+                    };
+                    ops.push((line_no, SchemaOp::AddForeignKey { table: table.clone(), foreign_key }));
+                }
+            }
+            "add_timestamps" => {
+                if let Some(table) = positional.first() {
+                    for name in ["created_at", "updated_at"] {
+                        let mut col = column(name, "datetime", line_no);
+                        col.nullable = opt("null").as_deref() != Some("false");
+                        ops.push((line_no, SchemaOp::AddColumn { table: table.clone(), column: col }));
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    ops
+}
+
+/// One line inside `create_table … do |t|`.
+fn table_body_line(
+    line: &str,
+    line_no: u32,
+    columns: &mut Vec<Column>,
+    indexes: &mut Vec<Index>,
+    foreign_keys: &mut Vec<ForeignKey>,
+) {
+    let Some(rest) = line.strip_prefix("t.") else { return };
+    let Some((helper, args)) = split_call(rest) else {
+        if rest == "timestamps" {
+            push_timestamps(columns, line_no, true);
+        }
+        return;
+    };
+    let args_list = split_args(args);
+    let positional: Vec<String> = args_list.iter().filter(|a| !is_option(a)).map(|a| symbol(a)).collect();
+    match helper {
+        // Rails 5+ timestamps are NOT NULL unless `null: true`.
+        "timestamps" => push_timestamps(columns, line_no, option(&args_list, "null").as_deref() != Some("true")),
+        "index" => indexes.push(Index {
+            name: option(&args_list, "name"),
+            columns: column_args(args_list.first().map(String::as_str).unwrap_or("")),
+            unique: option(&args_list, "unique").as_deref() == Some("true"),
+        }),
+        "references" | "belongs_to" => {
+            for reference in &positional {
+                let (col, fk) = reference_column(reference, &args_list, line_no);
+                columns.push(col);
+                foreign_keys.extend(fk);
+            }
+        }
+        "column" => {
+            if let [name, ty, ..] = positional.as_slice() {
+                columns.push(rails_column(name, ty, &args_list, line_no));
+            }
+        }
+        ty if COLUMN_TYPES.contains(&ty) => {
+            // `t.string :first_name, :last_name, null: false`.
+            for name in &positional {
+                columns.push(rails_column(name, ty, &args_list, line_no));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn push_timestamps(columns: &mut Vec<Column>, line_no: u32, not_null: bool) {
+    for name in ["created_at", "updated_at"] {
+        let mut col = column(name, "datetime", line_no);
+        col.nullable = !not_null;
+        columns.push(col);
+    }
+}
+
+fn rails_column(name: &str, ty: &str, args: &[String], line_no: u32) -> Column {
+    let mut col = column(name, ty, line_no);
+    col.nullable = option(args, "null").as_deref() != Some("false");
+    col.default = option(args, "default").filter(|d| d != "nil");
+    col.unique = option(args, "unique").as_deref() == Some("true")
+        || option(args, "index").is_some_and(|i| i.contains("unique: true"));
+    col.primary_key = option(args, "primary_key").as_deref() == Some("true");
+    col
+}
+
+/// `t.references :account, foreign_key: true` → `account_id` (+ FK to `accounts`).
+fn reference_column(reference: &str, args: &[String], line_no: u32) -> (Column, Option<ForeignKey>) {
+    let ty = option(args, "type").unwrap_or_else(|| "bigint".to_string());
+    let mut col = column(&format!("{reference}_id"), &ty, line_no);
+    col.nullable = option(args, "null").as_deref() != Some("false");
+    let fk = option(args, "foreign_key").filter(|v| v != "false").map(|v| {
+        let ref_table = v
+            .split("to_table:")
+            .nth(1)
+            .map(|t| symbol(t.trim_end_matches('}').trim()))
+            .unwrap_or_else(|| plural(reference));
+        ForeignKey { columns: vec![col.name.clone()], ref_table, ref_columns: vec!["id".to_string()], on_delete: None }
+    });
+    (col, fk)
+}
+
+/// `create_table :users, id: :uuid do |t|` → `("create_table", ":users, id: :uuid")`.
+fn split_call(line: &str) -> Option<(&str, &str)> {
+    let name_end = line.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    let name = &line[..name_end];
+    let rest = line[name_end..].trim_start();
+    let args = if let Some(inner) = rest.strip_prefix('(') {
+        inner.rsplit_once(')').map(|(a, _)| a).unwrap_or(inner)
+    } else if name_end > 0 && line[name_end..].starts_with(' ') {
+        rest
+    } else {
+        return None;
+    };
+    let args = args.split(" do").next().unwrap_or(args);
+    let args = args.split(" #").next().unwrap_or(args).trim();
+    Some((name, args))
+}
+
+/// Split on commas outside brackets/quotes.
+fn split_args(args: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut current = String::new();
+    for c in args.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    out.push(current.trim().to_string());
+                    current.clear();
+                    continue;
+                }
+                _ => {}
+            },
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        out.push(current.trim().to_string());
+    }
+    out
+}
+
+/// `null: false` / `:null => false`.
+fn is_option(arg: &str) -> bool {
+    let arg = arg.trim();
+    (!arg.starts_with(':') && arg.split_once(':').is_some_and(|(k, _)| k.chars().all(|c| c.is_alphanumeric() || c == '_')))
+        || arg.contains("=>")
+}
+
+fn option(args: &[String], key: &str) -> Option<String> {
+    args.iter().find_map(|arg| {
+        let arg = arg.trim();
+        let value = arg
+            .strip_prefix(&format!("{key}:"))
+            .or_else(|| arg.strip_prefix(&format!(":{key} =>")))?;
+        let value = value.trim();
+        Some(if value.starts_with('{') { value.to_string() } else { symbol(value) })
+    })
+}
+
+/// `:email` / `"email"` / `'email'` → `email`.
+fn symbol(value: &str) -> String {
+    value
+        .trim()
+        .trim_start_matches(':')
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+/// `:email` or `[:org_id, :email]` → column names.
+fn column_args(arg: &str) -> Vec<String> {
+    arg.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(symbol)
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+/// Index after the `end` that closes the block opened on `start`.
+fn skip_block(lines: &[&str], start: usize) -> usize {
+    let mut depth = 0i32;
+    for (offset, line) in lines[start..].iter().enumerate() {
+        let trimmed = line.trim();
+        let opens = trimmed.starts_with("def ")
+            || trimmed.starts_with("class ")
+            || trimmed.starts_with("module ")
+            || trimmed.starts_with("if ")
+            || trimmed.starts_with("unless ")
+            || trimmed.starts_with("case ")
+            || trimmed.starts_with("begin")
+            || trimmed.ends_with(" do")
+            || trimmed.contains(" do |");
+        if opens {
+            depth += 1;
+        }
+        if trimmed == "end" || trimmed.starts_with("end ") {
+            depth -= 1;
+            if depth <= 0 {
+                return start + offset + 1;
+            }
+        }
+    }
+    lines.len()
+}
+
+fn plural(name: &str) -> String {
+    if name.ends_with('y') && !name.ends_with("ey") {
+        format!("{}ies", &name[..name.len() - 1])
+    } else if name.ends_with('s') || name.ends_with('x') || name.ends_with("ch") || name.ends_with("sh") {
+        format!("{name}es")
+    } else {
+        format!("{name}s")
+    }
+}
+
+fn singular(name: &str) -> String {
+    let name = symbol(name);
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if let Some(stem) = name.strip_suffix("ses").or_else(|| name.strip_suffix("xes")) {
+        format!("{stem}{}", &name[stem.len()..stem.len() + 1])
+    } else {
+        name.strip_suffix('s').unwrap_or(&name).to_string()
+    }
+}
@@ -1,6 +1,7 @@
 //! Sensitive field detection — 100+ patterns, 6 false-positive filters,
 //! confidence scoring with 5 weighted factors.
 
+use super::schema::DatabaseSchema;
 use super::types::{ExtractedModel, SensitiveField, SensitivityType};
 
 /// Detector for sensitive fields within data models.
@@ -74,6 +75,22 @@ impl SensitiveFieldDetector {
         results
     }
 
+    /// Detect sensitive columns in a migration-derived schema. Findings point
+    /// at the migration that added each column.
+    pub fn detect_schema_columns(&self, schema: &DatabaseSchema) -> Vec<SensitiveField> {
+        let mut results = Vec::new();
+        for table in schema.tables.values() {
+            for mut field in self.detect_sensitive_fields(&table.to_extracted_model()) {
+                if let Some(column) = table.column(&field.field_name) {
+                    field.file = column.file.clone();
+                    field.line = column.line;
+                }
+                results.push(field);
+            }
+        }
+        results
+    }
+
     /// Boost confidence based on model context.
     fn apply_context_boost(&self, confidence: f32, model_name: &str, sensitivity: SensitivityType) -> f32 {
         let model_lower = model_name.to_lowercase();
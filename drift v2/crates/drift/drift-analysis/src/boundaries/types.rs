@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::schema::{DatabaseSchema, SchemaDrift};
+
 /// Result of a boundary scan across the codebase.
 #[derive(Debug, Clone, Default)]
 pub struct BoundaryScanResult {
@@ -10,6 +12,10 @@ pub struct BoundaryScanResult {
     pub frameworks_detected: Vec<OrmFramework>,
     pub total_fields: usize,
     pub total_sensitive: usize,
+    /// Schema reconstructed from migrations, when any were supplied.
+    pub schema: Option<DatabaseSchema>,
+    /// Disagreements between `schema` and `models`.
+    pub schema_drift: Vec<SchemaDrift>,
 }
 
 /// An extracted data model from an ORM framework.
@@ -143,6 +149,8 @@ pub enum OrmFramework {
     Diesel,
     SeaOrm,
     SqlxRust,
+    // Kotlin
+    Exposed,
     // Other
     Unknown,
 }
@@ -182,6 +190,7 @@ impl OrmFramework {
             Self::Diesel => "diesel",
             Self::SeaOrm => "sea-orm",
             Self::SqlxRust => "sqlx-rust",
+            Self::Exposed => "exposed",
             Self::Unknown => "unknown",
         }
     }
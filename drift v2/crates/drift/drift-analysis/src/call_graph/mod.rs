@@ -6,6 +6,7 @@ pub mod types;
 pub mod builder;
 pub mod resolution;
 pub mod traversal;
+#[cfg(feature = "native")]
 pub mod cte_fallback;
 pub mod incremental;
 pub mod di_support;
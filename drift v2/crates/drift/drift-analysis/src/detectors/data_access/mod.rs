@@ -87,6 +87,25 @@ impl Detector for DataAccessDetector {
             }
         }
 
+        // Detect SQL built from application values (concatenation, interpolation, formatting)
+        for candidate in &ctx.embedded_sql().injection_candidates {
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: candidate.line,
+                column: candidate.column,
+                pattern_id: "DA-SQLI-001".to_string(),
+                confidence: candidate.confidence,
+                cwe_ids: SmallVec::from_buf([89, 0]),
+                owasp: Some("A03:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::DataAccess,
+                matched_text: format!(
+                    "{} SQL in {}: {}",
+                    candidate.construction.name(), candidate.api, candidate.fragments.join(", ")
+                ),
+            });
+        }
+
         // Detect repository pattern from class names
         for class in ctx.classes {
             let lower = class.name.to_lowercase();
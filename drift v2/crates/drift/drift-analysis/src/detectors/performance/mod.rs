@@ -46,6 +46,25 @@ impl Detector for PerformanceDetector {
             }
         }
 
+        // Confirmed N+1: embedded SQL reads issued once per loop iteration
+        for detection in &ctx.embedded_sql().n_plus_one {
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: detection.line,
+                column: 0,
+                pattern_id: "PERF-N1-002".to_string(),
+                confidence: detection.confidence,
+                cwe_ids: SmallVec::new(),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Performance,
+                matched_text: format!(
+                    "SQL query in loop (line {}): {}",
+                    detection.loop_line + 1, detection.query_method
+                ),
+            });
+        }
+
         // DP-PERF-01: Gate allocation patterns to Rust only
         if ctx.language == Language::Rust {
             let alloc_callees = ["clone", "to_vec", "to_string", "to_owned", "collect"];
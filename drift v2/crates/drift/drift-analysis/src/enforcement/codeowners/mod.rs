@@ -0,0 +1,99 @@
+//! CODEOWNERS — GitHub/GitLab ownership rules, owners on violations and
+//! gate results summarized per owner.
+
+pub mod types;
+pub mod parser;
+
+pub use types::*;
+pub use parser::CodeOwners;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::Severity;
+
+impl CodeOwners {
+    /// Set `owners` on every violation in `results`. Violation files under
+    /// `root` are matched relative to it, as CODEOWNERS patterns are written.
+    pub fn assign(&self, root: &Path, results: &mut [GateResult]) {
+        let mut cache: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for violation in results.iter_mut().flat_map(|r| r.violations.iter_mut()) {
+            violation.owners = cache
+                .entry(violation.file.clone())
+                .or_insert_with(|| {
+                    let file = Path::new(&violation.file);
+                    self.owners_of(&file.strip_prefix(root).unwrap_or(file).to_string_lossy())
+                })
+                .clone();
+        }
+    }
+}
+
+/// Whether a violation with `owners` belongs to `owner`; [`UNOWNED`]
+/// selects violations without owners.
+pub fn is_owned_by(owners: &[String], owner: &str) -> bool {
+    if owner == UNOWNED {
+        owners.is_empty()
+    } else {
+        owners.iter().any(|o| o.eq_ignore_ascii_case(owner))
+    }
+}
+
+/// Group gate results per owner. A violation with several owners counts
+/// for each; violations without owners go to [`UNOWNED`]. Owners are
+/// ordered by violation count, most first.
+pub fn summarize_by_owner(results: &[GateResult]) -> Vec<OwnerSummary> {
+    let mut by_owner: BTreeMap<&str, OwnerSummary> = BTreeMap::new();
+    for result in results {
+        for violation in &result.violations {
+            let owners: Vec<&str> = if violation.owners.is_empty() {
+                vec![UNOWNED]
+            } else {
+                violation.owners.iter().map(String::as_str).collect()
+            };
+            for owner in owners {
+                let summary = by_owner.entry(owner).or_insert_with(|| OwnerSummary {
+                    owner: owner.to_string(),
+                    violations: 0,
+                    errors: 0,
+                    warnings: 0,
+                    new_violations: 0,
+                    gates: Vec::new(),
+                    files: Vec::new(),
+                });
+                let is_error = violation.severity == Severity::Error;
+                let is_warning = violation.severity == Severity::Warning;
+                summary.violations += 1;
+                summary.errors += usize::from(is_error);
+                summary.warnings += usize::from(is_warning);
+                summary.new_violations += usize::from(violation.is_new);
+                if !summary.files.contains(&violation.file) {
+                    summary.files.push(violation.file.clone());
+                }
+                let gate = match summary.gates.iter_mut().position(|g| g.gate_id == result.gate_id) {
+                    Some(i) => &mut summary.gates[i],
+                    None => {
+                        summary.gates.push(OwnerGateSummary {
+                            gate_id: result.gate_id,
+                            gate_passed: result.passed,
+                            violations: 0,
+                            errors: 0,
+                            warnings: 0,
+                        });
+                        summary.gates.last_mut().expect("just pushed")
+                    }
+                };
+                gate.violations += 1;
+                gate.errors += usize::from(is_error);
+                gate.warnings += usize::from(is_warning);
+            }
+        }
+    }
+    let mut summaries: Vec<OwnerSummary> = by_owner.into_values().collect();
+    for summary in &mut summaries {
+        summary.files.sort();
+    }
+    summaries.sort_by(|a, b| b.violations.cmp(&a.violations).then_with(|| a.owner.cmp(&b.owner)));
+    summaries
+}
@@ -0,0 +1,239 @@
+//! CODEOWNERS parsing and path-to-owner resolution.
+//!
+//! Patterns follow gitignore rules as GitHub and GitLab apply them: a
+//! pattern with a `/` before its last character is anchored at the root,
+//! otherwise it matches at any depth; a trailing `/` matches only directory
+//! contents; `*` stays within one path segment while `**` crosses them. A
+//! pattern whose last segment is a wildcard (`docs/*`) matches files in
+//! that directory but not deeper.
+//!
+//! Within a section the last matching rule wins. GitLab owners are the
+//! union over every section with a matching rule.
+
+use std::path::Path;
+
+use super::types::*;
+
+/// Where each platform looks for the file, in lookup order.
+const LOCATIONS: &[(&str, Option<CodeOwnersDialect>)] = &[
+    (".github/CODEOWNERS", Some(CodeOwnersDialect::GitHub)),
+    (".gitlab/CODEOWNERS", Some(CodeOwnersDialect::GitLab)),
+    ("CODEOWNERS", None),
+    ("docs/CODEOWNERS", None),
+];
+
+/// A parsed CODEOWNERS file.
+#[derive(Debug, Clone)]
+pub struct CodeOwners {
+    pub dialect: CodeOwnersDialect,
+    pub sections: Vec<OwnerSection>,
+}
+
+impl CodeOwners {
+    /// Find and parse the project's CODEOWNERS file. Files outside
+    /// `.github/` and `.gitlab/` are read as GitLab when they contain
+    /// section headers.
+    pub fn discover(root: &Path) -> Option<Self> {
+        LOCATIONS.iter().find_map(|(location, dialect)| {
+            let content = std::fs::read_to_string(root.join(location)).ok()?;
+            let dialect = dialect.unwrap_or_else(|| detect_dialect(&content));
+            Some(Self::parse(&content, dialect))
+        })
+    }
+
+    /// Parse CODEOWNERS content. Lines that cannot be parsed are skipped,
+    /// as the platforms do.
+    pub fn parse(content: &str, dialect: CodeOwnersDialect) -> Self {
+        let mut sections = vec![OwnerSection::default()];
+        let mut current = 0;
+        for (i, raw) in content.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if dialect == CodeOwnersDialect::GitLab {
+                if let Some(header) = parse_section_header(line) {
+                    // A repeated header (case-insensitive) continues its section.
+                    let existing = sections.iter().position(|s| {
+                        s.name.as_deref().map(str::to_lowercase) == header.name.as_deref().map(str::to_lowercase)
+                    });
+                    current = match existing {
+                        Some(pos) => {
+                            if sections[pos].default_owners.is_empty() {
+                                sections[pos].default_owners = header.default_owners;
+                            }
+                            pos
+                        }
+                        None => {
+                            sections.push(header);
+                            sections.len() - 1
+                        }
+                    };
+                    continue;
+                }
+            }
+            let mut tokens = split_tokens(line).into_iter();
+            let Some(pattern) = tokens.next() else { continue };
+            let owners: Vec<String> = tokens.filter(|t| is_owner(t)).collect();
+            let globs = compile_pattern(&pattern);
+            if globs.is_empty() {
+                continue;
+            }
+            sections[current].rules.push(OwnerRule {
+                pattern,
+                owners,
+                line: i as u32 + 1,
+                globs,
+            });
+        }
+        sections.retain(|s| s.name.is_some() || !s.rules.is_empty());
+        Self { dialect, sections }
+    }
+
+    /// Owners of `path` (relative to the project root), in section order.
+    pub fn owners_of(&self, path: &str) -> Vec<String> {
+        let mut owners: Vec<String> = Vec::new();
+        let path = normalize_path(path);
+        for section in &self.sections {
+            let Some(rule) = section.rules.iter().rev().find(|r| r.matches(&path)) else { continue };
+            let rule_owners = if rule.owners.is_empty() && self.dialect == CodeOwnersDialect::GitLab {
+                &section.default_owners
+            } else {
+                &rule.owners
+            };
+            for owner in rule_owners {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+        owners
+    }
+
+    /// The rule deciding `path` in each section that has one.
+    pub fn matching_rules(&self, path: &str) -> Vec<&OwnerRule> {
+        let path = normalize_path(path);
+        self.sections
+            .iter()
+            .filter_map(|s| s.rules.iter().rev().find(|r| r.matches(&path)))
+            .collect()
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").trim_start_matches('/').to_string()
+}
+
+/// GitLab if any line is a section header.
+fn detect_dialect(content: &str) -> CodeOwnersDialect {
+    let has_sections = content
+        .lines()
+        .map(str::trim)
+        .any(|l| !l.starts_with('#') && parse_section_header(l).is_some());
+    if has_sections {
+        CodeOwnersDialect::GitLab
+    } else {
+        CodeOwnersDialect::GitHub
+    }
+}
+
+/// `[Name]`, `^[Name]`, `[Name][2]`, each optionally followed by default
+/// owners.
+fn parse_section_header(line: &str) -> Option<OwnerSection> {
+    let (optional, rest) = match line.strip_prefix('^') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let rest = rest.strip_prefix('[')?;
+    let close = rest.find(']')?;
+    let name = rest[..close].trim();
+    if name.is_empty() {
+        return None;
+    }
+    let mut rest = &rest[close + 1..];
+    let mut approvals = None;
+    if let Some(count) = rest.strip_prefix('[') {
+        let close = count.find(']')?;
+        approvals = Some(count[..close].trim().parse().ok()?);
+        rest = &count[close + 1..];
+    }
+    // Anything else after the header must be owners; otherwise this is a
+    // pattern such as `[abc]*.rs`.
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let default_owners: Vec<String> = split_tokens(rest);
+    if !default_owners.iter().all(|t| is_owner(t)) {
+        return None;
+    }
+    Some(OwnerSection {
+        name: Some(name.to_string()),
+        optional,
+        approvals,
+        default_owners,
+        rules: Vec::new(),
+    })
+}
+
+/// Whitespace-separated tokens, honoring `\ ` escapes and dropping a
+/// trailing ` #comment`.
+fn split_tokens(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    if next != ' ' && next != '#' {
+                        current.push('\\');
+                    }
+                    current.push(next);
+                }
+            }
+            '#' if current.is_empty() => break,
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// `@user`, `@org/team` or an email address.
+fn is_owner(token: &str) -> bool {
+    token.contains('@')
+}
+
+/// Globs equivalent to a CODEOWNERS pattern.
+fn compile_pattern(pattern: &str) -> Vec<glob::Pattern> {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+    if trimmed.is_empty() {
+        // "/" alone owns everything.
+        return glob::Pattern::new("**").into_iter().collect();
+    }
+    let base = if anchored {
+        trimmed.to_string()
+    } else {
+        format!("**/{trimmed}")
+    };
+    let last = base.rsplit('/').next().unwrap_or_default();
+    let mut globs = Vec::new();
+    if !dir_only {
+        globs.push(base.clone());
+    }
+    if dir_only || !last.contains('*') {
+        globs.push(format!("{base}/**"));
+    }
+    globs.iter().filter_map(|g| glob::Pattern::new(g).ok()).collect()
+}
@@ -0,0 +1,81 @@
+//! CODEOWNERS types — dialects, rules, sections and per-owner summaries.
+
+use serde::{Deserialize, Serialize};
+
+use crate::enforcement::gates::GateId;
+
+/// Owner key for violations in files no rule assigns an owner to.
+pub const UNOWNED: &str = "unowned";
+
+/// CODEOWNERS flavor. GitHub has one flat rule list; GitLab adds sections
+/// whose owners combine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodeOwnersDialect {
+    GitHub,
+    GitLab,
+}
+
+/// One `pattern owner...` line.
+#[derive(Debug, Clone)]
+pub struct OwnerRule {
+    /// Pattern as written.
+    pub pattern: String,
+    /// Owners as written; empty means the section's default owners (GitLab)
+    /// or explicitly unowned (GitHub).
+    pub owners: Vec<String>,
+    /// 1-based line in the CODEOWNERS file.
+    pub line: u32,
+    pub(crate) globs: Vec<glob::Pattern>,
+}
+
+impl OwnerRule {
+    pub fn matches(&self, path: &str) -> bool {
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        self.globs.iter().any(|g| g.matches_with(path, options))
+    }
+}
+
+/// A GitLab section (`[Name]`, `^[Optional]`, `[Name][2] @default`), or
+/// the unnamed section before the first header.
+#[derive(Debug, Clone, Default)]
+pub struct OwnerSection {
+    /// `None` for the unnamed section.
+    pub name: Option<String>,
+    /// `^[...]`: approval is optional.
+    pub optional: bool,
+    /// `[...][n]`: approvals required.
+    pub approvals: Option<u32>,
+    /// Owners for rules in the section that list none.
+    pub default_owners: Vec<String>,
+    pub rules: Vec<OwnerRule>,
+}
+
+/// Violation counts for one owner in one gate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnerGateSummary {
+    pub gate_id: GateId,
+    /// Whether the gate as a whole passed.
+    pub gate_passed: bool,
+    pub violations: usize,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Gate results from the point of view of one owner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnerSummary {
+    /// `@user`, `@org/team`, an email, or [`UNOWNED`].
+    pub owner: String,
+    pub violations: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    /// New violations (introduced by the current change).
+    pub new_violations: usize,
+    /// Gates with at least one violation for this owner.
+    pub gates: Vec<OwnerGateSummary>,
+    pub files: Vec<String>,
+}
@@ -59,6 +59,7 @@ impl QualityGate for ConstraintVerificationGate {
                         owasp_category: None,
                         suppressed: false,
                         is_new: false,
+                        owners: Vec::new(),
                     });
                 }
             }
@@ -0,0 +1,100 @@
+//! Gate 7: Duplication — Is any module mostly copy-paste?
+
+use super::types::*;
+use crate::structural::module_of;
+use crate::enforcement::rules::{Severity, Violation};
+
+/// Gate 7: Checks that no module's duplicated share exceeds the threshold.
+pub struct DuplicationGate;
+
+impl QualityGate for DuplicationGate {
+    fn id(&self) -> GateId {
+        GateId::Duplication
+    }
+
+    fn name(&self) -> &'static str {
+        "Duplication"
+    }
+
+    fn description(&self) -> &'static str {
+        "Verifies that code clones stay below the per-module duplication threshold"
+    }
+
+    fn evaluate(&self, input: &GateInput) -> GateResult {
+        let duplication = match &input.duplication {
+            Some(d) => d,
+            None => {
+                return GateResult::skipped(
+                    GateId::Duplication,
+                    "No clone detection data available".to_string(),
+                );
+            }
+        };
+
+        let threshold = duplication.threshold;
+        let score = (100.0 - duplication.overall_percentage).clamp(0.0, 100.0);
+        let over: Vec<&ModuleDuplicationInput> = duplication
+            .modules
+            .iter()
+            .filter(|m| m.percentage > threshold)
+            .collect();
+
+        if over.is_empty() {
+            return if duplication.clones.is_empty() {
+                GateResult::pass(GateId::Duplication, 100.0, "No code clones detected".to_string())
+            } else {
+                GateResult::pass(
+                    GateId::Duplication,
+                    score,
+                    format!(
+                        "Duplication {:.1}% overall, every module within {threshold:.1}%",
+                        duplication.overall_percentage
+                    ),
+                )
+            };
+        }
+
+        let violations: Vec<Violation> = duplication
+            .clones
+            .iter()
+            .filter(|c| over.iter().any(|m| m.module == module_of(&c.file)))
+            .map(|c| Violation {
+                id: format!("duplication-{}-{}", c.file, c.line),
+                file: c.file.clone(),
+                line: c.line,
+                column: None,
+                end_line: Some(c.end_line),
+                end_column: None,
+                severity: Severity::Warning,
+                pattern_id: "duplication".to_string(),
+                rule_id: format!("duplication/{}", c.clone_type),
+                message: format!(
+                    "Code clone (class {}, {}) of {}",
+                    c.class_id, c.clone_type, c.counterpart
+                ),
+                quick_fix: None,
+                cwe_id: None,
+                owasp_category: None,
+                suppressed: false,
+                is_new: false,
+                owners: Vec::new(),
+            })
+            .collect();
+
+        let worst = over
+            .iter()
+            .map(|m| format!("{} ({:.1}%)", m.module, m.percentage))
+            .take(3)
+            .collect::<Vec<_>>()
+            .join(", ");
+        GateResult::fail(
+            GateId::Duplication,
+            score,
+            format!(
+                "{} modules above {threshold:.1}% duplication: {worst}",
+                over.len()
+            ),
+            violations,
+        )
+    }
+}
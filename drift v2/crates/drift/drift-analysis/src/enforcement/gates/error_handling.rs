@@ -52,6 +52,7 @@ impl QualityGate for ErrorHandlingGate {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             });
         }
 
@@ -1,4 +1,4 @@
-//! Quality gates — 6 gates with DAG-based orchestration.
+//! Quality gates — 7 gates with DAG-based orchestration.
 
 pub mod types;
 pub mod orchestrator;
@@ -8,6 +8,7 @@ pub mod security_boundaries;
 pub mod test_coverage;
 pub mod error_handling;
 pub mod regression;
+pub mod duplication;
 pub mod progressive;
 
 pub use types::*;
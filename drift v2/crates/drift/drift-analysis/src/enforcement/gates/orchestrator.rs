@@ -6,6 +6,7 @@ use std::time::Duration;
 use super::types::*;
 use super::progressive::{ProgressiveConfig, ProgressiveEnforcement};
 use super::constraint_verification::ConstraintVerificationGate;
+use super::duplication::DuplicationGate;
 use super::error_handling::ErrorHandlingGate;
 use super::pattern_compliance::PatternComplianceGate;
 use super::regression::RegressionGate;
@@ -21,7 +22,7 @@ pub struct GateOrchestrator {
 }
 
 impl GateOrchestrator {
-    /// Create a new orchestrator with all 6 default gates.
+    /// Create a new orchestrator with all 7 default gates.
     pub fn new() -> Self {
         let gates: Vec<Box<dyn QualityGate>> = vec![
             Box::new(PatternComplianceGate),
@@ -30,6 +31,7 @@ impl GateOrchestrator {
             Box::new(TestCoverageGate),
             Box::new(ErrorHandlingGate),
             Box::new(RegressionGate),
+            Box::new(DuplicationGate),
         ];
         Self {
             gates,
@@ -60,6 +60,7 @@ impl QualityGate for PatternComplianceGate {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
                 });
             }
         }
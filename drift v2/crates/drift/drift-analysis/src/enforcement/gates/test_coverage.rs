@@ -51,6 +51,7 @@ impl QualityGate for TestCoverageGate {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             });
         }
 
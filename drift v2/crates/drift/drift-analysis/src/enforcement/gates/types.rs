@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
-/// The 6 quality gate identifiers.
+/// The 7 quality gate identifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum GateId {
@@ -14,6 +14,7 @@ pub enum GateId {
     TestCoverage,
     ErrorHandling,
     Regression,
+    Duplication,
 }
 
 impl GateId {
@@ -25,6 +26,7 @@ impl GateId {
             Self::TestCoverage => "test-coverage",
             Self::ErrorHandling => "error-handling",
             Self::Regression => "regression",
+            Self::Duplication => "duplication",
         }
     }
 
@@ -36,6 +38,7 @@ impl GateId {
             Self::TestCoverage,
             Self::ErrorHandling,
             Self::Regression,
+            Self::Duplication,
         ]
     }
 }
@@ -174,6 +177,7 @@ pub struct GateInput {
     pub predecessor_results: HashMap<GateId, GateResult>,
     /// Baseline violation keys (format: "file:line:rule_id") for is_new detection.
     pub baseline_violations: HashSet<String>,
+    pub duplication: Option<DuplicationInput>,
     /// Optional feedback stats provider for FP-rate-aware gate evaluation.
     pub feedback_stats: Option<std::sync::Arc<dyn super::super::feedback::stats_provider::FeedbackStatsProvider>>,
 }
@@ -192,6 +196,7 @@ impl std::fmt::Debug for GateInput {
             .field("current_health_score", &self.current_health_score)
             .field("predecessor_results", &self.predecessor_results)
             .field("baseline_violations", &self.baseline_violations)
+            .field("duplication", &self.duplication)
             .field("feedback_stats", &self.feedback_stats.as_ref().map(|_| "<FeedbackStatsProvider>"))
             .finish()
     }
@@ -241,6 +246,35 @@ pub struct ErrorGapInput {
     pub message: String,
 }
 
+/// Code duplication data for the duplication gate.
+#[derive(Debug, Clone)]
+pub struct DuplicationInput {
+    /// Maximum duplicated share of a module's lines (0-100).
+    pub threshold: f64,
+    pub overall_percentage: f64,
+    pub modules: Vec<ModuleDuplicationInput>,
+    pub clones: Vec<CloneInput>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleDuplicationInput {
+    pub module: String,
+    pub percentage: f64,
+}
+
+/// One clone instance, with the instance it is compared against.
+#[derive(Debug, Clone)]
+pub struct CloneInput {
+    pub file: String,
+    pub line: u32,
+    pub end_line: u32,
+    pub class_id: u32,
+    /// "type-1", "type-2" or "type-3".
+    pub clone_type: String,
+    /// `file:line` of another instance of the same class.
+    pub counterpart: String,
+}
+
 /// Gate dependency specification.
 #[derive(Debug, Clone)]
 pub struct GateDependency {
@@ -293,6 +327,15 @@ impl GateInputBuilder {
         self
     }
 
+    /// Group pattern matches by pattern id (see `PatternInfo::group`) for
+    /// the PatternCompliance gate.
+    pub fn patterns_from_matches<'a>(
+        self,
+        matches: impl IntoIterator<Item = &'a crate::engine::types::PatternMatch>,
+    ) -> Self {
+        self.patterns(super::super::rules::PatternInfo::group(matches.into_iter().map(Into::into)))
+    }
+
     /// Add architectural constraints for the ConstraintVerification gate.
     pub fn constraints(mut self, constraints: Vec<ConstraintInput>) -> Self {
         self.input.constraints = constraints;
@@ -346,6 +389,57 @@ impl GateInputBuilder {
         self
     }
 
+    /// Map vulnerable dependency matches to SecurityFindingInput for the
+    /// SecurityBoundaries gate.
+    ///
+    /// Severity follows the advisory score (critical ≥ 9, high ≥ 7,
+    /// medium ≥ 4), capped at low for dev-only dependencies and for
+    /// vulnerable code the project never reaches.
+    pub fn security_findings_from_vulnerable_dependencies(
+        mut self,
+        vulns: &[crate::structural::owasp_cwe::vulnerable_components::VulnerableDependency],
+    ) -> Self {
+        use crate::structural::owasp_cwe::vulnerable_components::VULNERABLE_DEPENDENCY_CWE;
+
+        for vuln in vulns {
+            let score = vuln.effective_severity();
+            let severity = if score >= 9.0 {
+                "critical"
+            } else if score >= 7.0 {
+                "high"
+            } else if score >= 4.0 {
+                "medium"
+            } else {
+                "low"
+            };
+            let mut cwe_ids = vec![VULNERABLE_DEPENDENCY_CWE];
+            cwe_ids.extend(vuln.cwe_ids.iter().copied().filter(|&id| id != VULNERABLE_DEPENDENCY_CWE));
+            let owasp_categories = cwe_to_owasp(&cwe_ids);
+
+            let description = format!(
+                "Vulnerable dependency: {}@{} ({}) [{}]{}",
+                vuln.dependency.name,
+                vuln.dependency.version,
+                vuln.advisory_id,
+                vuln.reachability.name(),
+                vuln.fixed_versions
+                    .first()
+                    .map(|fixed| format!(", fixed in {fixed}"))
+                    .unwrap_or_default(),
+            );
+
+            self.input.security_findings.push(SecurityFindingInput {
+                file: vuln.location(),
+                line: 0,
+                description,
+                severity: severity.to_string(),
+                cwe_ids,
+                owasp_categories,
+            });
+        }
+        self
+    }
+
     /// Add security findings directly (for non-taint sources).
     pub fn security_findings(mut self, findings: Vec<SecurityFindingInput>) -> Self {
         self.input.security_findings.extend(findings);
@@ -451,6 +545,41 @@ impl GateInputBuilder {
         self
     }
 
+    /// Map a clone report for the Duplication gate. Every instance of
+    /// every clone class becomes a `CloneInput` pointing at the class's
+    /// first other instance.
+    pub fn duplication_from_clones(
+        mut self,
+        report: &crate::structural::clones::CloneReport,
+        threshold: f64,
+    ) -> Self {
+        let mut clones = Vec::new();
+        for class in &report.classes {
+            for (i, instance) in class.instances.iter().enumerate() {
+                let other = &class.instances[if i == 0 { 1 } else { 0 }];
+                clones.push(CloneInput {
+                    file: instance.file.clone(),
+                    line: instance.line,
+                    end_line: instance.end_line,
+                    class_id: class.id,
+                    clone_type: class.clone_type.name().to_string(),
+                    counterpart: format!("{}:{}", other.file, other.line),
+                });
+            }
+        }
+        self.input.duplication = Some(DuplicationInput {
+            threshold,
+            overall_percentage: report.duplication_percentage(),
+            modules: report
+                .modules
+                .iter()
+                .map(|m| ModuleDuplicationInput { module: m.module.clone(), percentage: m.percentage })
+                .collect(),
+            clones,
+        });
+        self
+    }
+
     /// Build the final `GateInput`.
     pub fn build(self) -> GateInput {
         self.input
@@ -486,7 +615,7 @@ fn cwe_to_owasp(cwe_ids: &[u32]) -> Vec<String> {
             918 => "A10:2021-Server-Side Request Forgery",
             502 => "A08:2021-Software and Data Integrity Failures",
             117 | 113 => "A09:2021-Security Logging and Monitoring Failures",
-            1333 => "A06:2021-Vulnerable and Outdated Components",
+            1333 | 1395 => "A06:2021-Vulnerable and Outdated Components",
             611 => "A05:2021-Security Misconfiguration",
             434 => "A04:2021-Insecure Design",
             _ => continue,
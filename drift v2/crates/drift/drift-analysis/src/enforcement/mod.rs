@@ -2,9 +2,10 @@
 //!
 //! Subsystems:
 //! - `rules` — Pattern matcher → violations → severity assignment
-//! - `gates` — 6 quality gates with DAG-based orchestration
+//! - `gates` — 7 quality gates with DAG-based orchestration
 //! - `reporters` — SARIF 2.1.0, JSON, console output
-//! - `policy` — 4 aggregation modes for gate results
+//! - `policy` — 4 aggregation modes for gate results, scoped by path or package
+//! - `codeowners` — CODEOWNERS parsing, violation owners, per-owner summaries
 //! - `audit` — 5-factor health scoring, degradation detection
 //! - `feedback` — Tricorder-style FP tracking, auto-disable
 
@@ -12,5 +13,6 @@ pub mod rules;
 pub mod gates;
 pub mod reporters;
 pub mod policy;
+pub mod codeowners;
 pub mod audit;
 pub mod feedback;
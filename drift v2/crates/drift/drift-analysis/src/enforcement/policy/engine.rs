@@ -1,6 +1,11 @@
 //! Policy engine — aggregates gate results per mode.
 
-use crate::enforcement::gates::GateResult;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::enforcement::gates::{GateResult, GateStatus};
+use crate::enforcement::rules::Severity;
+use super::scope::{GoverningPolicy, ScopedPolicy};
 use super::types::*;
 
 /// Policy engine: aggregates gate results according to the active policy.
@@ -123,3 +128,125 @@ impl PolicyEngine {
         (avg_score >= self.policy.threshold, avg_score)
     }
 }
+
+/// Scoped policy engine: evaluates gate results once per scope, each
+/// scope seeing only the violations in the files it governs.
+pub struct ScopedPolicyEngine {
+    policy: ScopedPolicy,
+}
+
+impl ScopedPolicyEngine {
+    pub fn new(policy: ScopedPolicy) -> Self {
+        Self { policy }
+    }
+
+    /// Evaluate gate results over `files`. Files that only appear in
+    /// violations are governed too. Files under `root` are resolved
+    /// relative to it, as scope globs and package paths are written.
+    pub fn evaluate(&self, root: &Path, results: &[GateResult], files: &[String]) -> ScopedPolicyReport {
+        let mut scopes: BTreeMap<String, (GoverningPolicy, HashSet<&str>)> = BTreeMap::new();
+        scopes.insert(
+            GoverningPolicy::ROOT.to_string(),
+            (self.policy.resolve(""), HashSet::new()),
+        );
+        let all_files = files
+            .iter()
+            .map(String::as_str)
+            .chain(results.iter().flat_map(|r| r.violations.iter().map(|v| v.file.as_str())));
+        let mut scope_of: BTreeMap<&str, String> = BTreeMap::new();
+        for file in all_files {
+            if scope_of.contains_key(file) {
+                continue;
+            }
+            let relative = Path::new(file).strip_prefix(root).map(Path::to_string_lossy);
+            let governing = self.policy.resolve(relative.as_deref().unwrap_or(file));
+            let label = governing.scope.clone();
+            scopes
+                .entry(label.clone())
+                .or_insert_with(|| (governing, HashSet::new()))
+                .1
+                .insert(file);
+            scope_of.insert(file, label);
+        }
+
+        let mut scope_results = Vec::with_capacity(scopes.len());
+        for (label, (governing, scope_files)) in &scopes {
+            let scoped: Vec<GateResult> = results
+                .iter()
+                .filter(|r| governing.is_enabled(r.gate_id))
+                .map(|r| scoped_result(r, governing, scope_files))
+                .collect();
+            scope_results.push(ScopeResult {
+                scope: label.clone(),
+                file_count: scope_files.len(),
+                result: PolicyEngine::new(governing.policy.clone()).evaluate(&scoped),
+            });
+        }
+        // BTreeMap order puts "glob:" and "package:" before "root"; report
+        // the root first.
+        scope_results.sort_by_key(|s| s.scope != GoverningPolicy::ROOT);
+
+        let mut violations = Vec::new();
+        for result in results {
+            for v in &result.violations {
+                let (governing, _) = &scopes[&scope_of[v.file.as_str()]];
+                if !governing.is_enabled(result.gate_id) {
+                    continue;
+                }
+                violations.push(GovernedViolation {
+                    violation_id: v.id.clone(),
+                    gate_id: result.gate_id,
+                    file: v.file.clone(),
+                    line: v.line,
+                    rule_id: v.rule_id.clone(),
+                    scope: governing.scope.clone(),
+                    policy_name: governing.policy.name.clone(),
+                });
+            }
+        }
+
+        ScopedPolicyReport {
+            overall_passed: scope_results.iter().all(|s| s.result.overall_passed),
+            scopes: scope_results,
+            violations,
+        }
+    }
+}
+
+/// A gate result as seen from one scope: violations outside the scope are
+/// dropped and the gate is judged on the ones left — an error fails it,
+/// anything else warns — with the scope taking its share of the gate's
+/// score deficit by violation count. Gates that report no per-file
+/// violations, or were skipped or errored, keep their result. The scope's
+/// per-gate minimum score then applies.
+fn scoped_result(result: &GateResult, governing: &GoverningPolicy, files: &HashSet<&str>) -> GateResult {
+    let mut scoped = result.clone();
+    scoped.violations.retain(|v| files.contains(v.file.as_str()));
+    let (total, in_scope) = (result.violations.len(), scoped.violations.len());
+    if total > 0 && !matches!(result.status, GateStatus::Skipped | GateStatus::Errored) {
+        scoped.score = 100.0 - (100.0 - result.score).max(0.0) * in_scope as f64 / total as f64;
+        (scoped.status, scoped.passed) = if scoped.violations.iter().any(|v| v.severity == Severity::Error) {
+            (GateStatus::Failed, false)
+        } else if in_scope > 0 {
+            (GateStatus::Warned, true)
+        } else {
+            (GateStatus::Passed, true)
+        };
+        scoped.summary = if in_scope == 0 {
+            format!("{} (no violations in scope)", result.summary)
+        } else {
+            format!("{} ({in_scope} of {total} violations in scope)", result.summary)
+        };
+    }
+    if let Some(&min_score) = governing.gate_thresholds.get(result.gate_id.as_str()) {
+        if scoped.passed && scoped.status != GateStatus::Skipped && scoped.score < min_score {
+            scoped.status = GateStatus::Failed;
+            scoped.passed = false;
+            scoped.summary = format!(
+                "{} (score {:.1} below scope minimum {min_score:.1})",
+                scoped.summary, scoped.score
+            );
+        }
+    }
+    scoped
+}
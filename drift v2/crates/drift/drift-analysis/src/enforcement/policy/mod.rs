@@ -1,7 +1,10 @@
-//! Policy engine — aggregates gate results via 4 modes.
+//! Policy engine — aggregates gate results via 4 modes, with path- and
+//! package-scoped overrides.
 
 pub mod types;
 pub mod engine;
+pub mod scope;
 
 pub use types::*;
-pub use engine::PolicyEngine;
+pub use engine::{PolicyEngine, ScopedPolicyEngine};
+pub use scope::{GoverningPolicy, PolicyOverride, PolicyScope, ScopedPolicy};
@@ -0,0 +1,307 @@
+//! Scoped policies — path- and package-scoped overrides of the root policy.
+//!
+//! Overrides inherit every field they leave unset from the scopes that
+//! enclose them. A file governed by several overrides resolves them from
+//! least to most specific, so `packages/payments/**` refines `packages/**`
+//! which refines the root policy.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use drift_core::config::gate_config::GateConfig;
+use drift_core::workspace::monorepo::PackageInfo;
+use serde::{Deserialize, Serialize};
+
+use super::types::*;
+use crate::enforcement::gates::GateId;
+
+/// Files a policy override applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PolicyScope {
+    /// Files matching a glob relative to the project root. A trailing `/`
+    /// matches everything under the directory.
+    Glob { pattern: String },
+    /// Files inside a monorepo package.
+    Package { name: String, path: String },
+}
+
+impl PolicyScope {
+    pub fn glob(pattern: impl Into<String>) -> Self {
+        Self::Glob { pattern: pattern.into() }
+    }
+
+    /// Scope covering a detected workspace package.
+    pub fn package(info: &PackageInfo) -> Self {
+        Self::Package {
+            name: info.name.clone(),
+            path: normalize_path(&info.path.to_string_lossy()),
+        }
+    }
+
+    /// Label used in reports: `glob:<pattern>` or `package:<name>`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Glob { pattern } => format!("glob:{pattern}"),
+            Self::Package { name, .. } => format!("package:{name}"),
+        }
+    }
+
+    /// Literal path components before the first wildcard — deeper scopes
+    /// are more specific.
+    fn specificity(&self) -> usize {
+        let literal = match self {
+            Self::Glob { pattern } => pattern
+                .split('/')
+                .take_while(|c| !c.contains(['*', '?', '[']))
+                .filter(|c| !c.is_empty())
+                .count(),
+            Self::Package { path, .. } => path.split('/').filter(|c| !c.is_empty()).count(),
+        };
+        // A package is more specific than a glob over the same directory.
+        literal * 2 + usize::from(matches!(self, Self::Package { .. }))
+    }
+}
+
+/// A policy override. Fields left `None` inherit from the enclosing scope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyOverride {
+    pub scope: PolicyScope,
+    /// Start from this preset instead of the inherited policy.
+    pub preset: Option<PolicyPreset>,
+    pub aggregation_mode: Option<AggregationMode>,
+    pub threshold: Option<f64>,
+    pub weights: Option<HashMap<String, f64>>,
+    pub required_gates: Option<Vec<GateId>>,
+    /// Gates evaluated in this scope; others are ignored.
+    pub enabled_gates: Option<Vec<GateId>>,
+    /// Minimum score per gate (gate_id → score); merged with inherited
+    /// thresholds.
+    #[serde(default)]
+    pub gate_thresholds: HashMap<String, f64>,
+}
+
+impl PolicyOverride {
+    pub fn new(scope: PolicyScope) -> Self {
+        Self {
+            scope,
+            preset: None,
+            aggregation_mode: None,
+            threshold: None,
+            weights: None,
+            required_gates: None,
+            enabled_gates: None,
+            gate_thresholds: HashMap::new(),
+        }
+    }
+
+    pub fn preset(mut self, preset: PolicyPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    pub fn required_gates(mut self, gates: Vec<GateId>) -> Self {
+        self.required_gates = Some(gates);
+        self
+    }
+
+    pub fn enabled_gates(mut self, gates: Vec<GateId>) -> Self {
+        self.enabled_gates = Some(gates);
+        self
+    }
+
+    pub fn gate_threshold(mut self, gate: GateId, min_score: f64) -> Self {
+        self.gate_thresholds.insert(gate.as_str().to_string(), min_score);
+        self
+    }
+}
+
+/// The policy in force for a file, after inheritance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoverningPolicy {
+    /// Labels of the applied scopes, outermost first, joined by ` > `; or
+    /// `root`.
+    pub scope: String,
+    pub policy: Policy,
+    pub enabled_gates: Vec<GateId>,
+    /// Minimum score per gate (gate_id → score).
+    pub gate_thresholds: HashMap<String, f64>,
+}
+
+impl GoverningPolicy {
+    pub const ROOT: &'static str = "root";
+
+    fn root(policy: Policy) -> Self {
+        Self {
+            scope: Self::ROOT.to_string(),
+            policy,
+            enabled_gates: GateId::all().to_vec(),
+            gate_thresholds: HashMap::new(),
+        }
+    }
+
+    fn apply(&mut self, o: &PolicyOverride) {
+        // Nested scopes keep their chain so differently inherited policies
+        // never share a label.
+        self.scope = if self.scope == Self::ROOT {
+            o.scope.label()
+        } else {
+            format!("{} > {}", self.scope, o.scope.label())
+        };
+        if let Some(preset) = o.preset {
+            self.policy = match preset {
+                PolicyPreset::Strict => Policy::strict(),
+                PolicyPreset::Standard => Policy::standard(),
+                PolicyPreset::Lenient => Policy::lenient(),
+                PolicyPreset::Custom => Policy {
+                    name: "custom".to_string(),
+                    preset: PolicyPreset::Custom,
+                    ..self.policy.clone()
+                },
+            };
+        }
+        if let Some(mode) = o.aggregation_mode {
+            self.policy.aggregation_mode = mode;
+        }
+        if let Some(threshold) = o.threshold {
+            self.policy.threshold = threshold;
+        }
+        if let Some(ref weights) = o.weights {
+            self.policy.weights = weights.clone();
+        }
+        if let Some(ref required) = o.required_gates {
+            self.policy.required_gates = required.clone();
+        }
+        if let Some(ref enabled) = o.enabled_gates {
+            self.enabled_gates = enabled.clone();
+        }
+        self.gate_thresholds
+            .extend(o.gate_thresholds.iter().map(|(k, v)| (k.clone(), *v)));
+    }
+
+    pub fn is_enabled(&self, gate: GateId) -> bool {
+        self.enabled_gates.contains(&gate)
+    }
+}
+
+/// A root policy plus scoped overrides.
+#[derive(Debug, Clone)]
+pub struct ScopedPolicy {
+    root: Policy,
+    /// Overrides with their compiled globs, least specific first.
+    overrides: Vec<(PolicyOverride, Option<glob::Pattern>)>,
+}
+
+impl ScopedPolicy {
+    pub fn new(root: Policy) -> Self {
+        Self { root, overrides: Vec::new() }
+    }
+
+    /// Add an override. Fails on an invalid glob.
+    pub fn with_override(mut self, o: PolicyOverride) -> Result<Self, String> {
+        let pattern = match &o.scope {
+            PolicyScope::Glob { pattern } => {
+                let pattern = match pattern.strip_suffix('/') {
+                    Some(dir) => format!("{dir}/**"),
+                    None => pattern.clone(),
+                };
+                Some(glob::Pattern::new(&pattern).map_err(|e| format!("invalid policy glob '{pattern}': {e}"))?)
+            }
+            PolicyScope::Package { .. } => None,
+        };
+        self.overrides.push((o, pattern));
+        // Stable: equally specific overrides apply in declaration order.
+        self.overrides.sort_by_key(|(o, _)| o.scope.specificity());
+        Ok(self)
+    }
+
+    /// Build from `quality_gates` config, resolving package overrides
+    /// against the detected workspace packages.
+    pub fn from_config(config: &GateConfig, packages: &[PackageInfo]) -> Result<Self, String> {
+        let root = match config.policy.as_deref() {
+            Some(name) => preset_policy(name)?,
+            None => Policy::default(),
+        };
+        let mut scoped = Self::new(root);
+        for entry in &config.policy_overrides {
+            let scope = match (&entry.path, &entry.package) {
+                (Some(path), None) => PolicyScope::glob(path.clone()),
+                (None, Some(name)) => packages
+                    .iter()
+                    .find(|p| &p.name == name)
+                    .map(PolicyScope::package)
+                    .ok_or_else(|| format!("policy override for unknown package '{name}'"))?,
+                _ => return Err("policy override needs exactly one of `path` or `package`".to_string()),
+            };
+            let mut o = PolicyOverride::new(scope);
+            if let Some(ref name) = entry.policy {
+                o.preset = Some(preset_policy(name)?.preset);
+            }
+            o.threshold = entry.min_score;
+            o.required_gates = entry.required_gates.as_deref().map(parse_gates).transpose()?;
+            o.enabled_gates = entry.enabled_gates.as_deref().map(parse_gates).transpose()?;
+            for (gate, score) in &entry.gate_thresholds {
+                o.gate_thresholds.insert(parse_gate(gate)?.as_str().to_string(), *score);
+            }
+            scoped = scoped.with_override(o)?;
+        }
+        Ok(scoped)
+    }
+
+    pub fn root(&self) -> &Policy {
+        &self.root
+    }
+
+    /// The policy governing `file` (relative to the project root).
+    pub fn resolve(&self, file: &str) -> GoverningPolicy {
+        let file = normalize_path(file);
+        let mut governing = GoverningPolicy::root(self.root.clone());
+        for (o, pattern) in &self.overrides {
+            let matched = match (&o.scope, pattern) {
+                (_, Some(pattern)) => pattern.matches_with(
+                    &file,
+                    glob::MatchOptions { require_literal_separator: true, ..Default::default() },
+                ),
+                (PolicyScope::Package { path, .. }, None) => {
+                    path.is_empty() || Path::new(&file).starts_with(path)
+                }
+                _ => false,
+            };
+            if matched {
+                governing.apply(o);
+            }
+        }
+        governing
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").trim_end_matches('/').to_string()
+}
+
+fn preset_policy(name: &str) -> Result<Policy, String> {
+    match name {
+        "strict" => Ok(Policy::strict()),
+        "standard" => Ok(Policy::standard()),
+        "lenient" => Ok(Policy::lenient()),
+        other => Err(format!("unknown policy preset '{other}'")),
+    }
+}
+
+fn parse_gate(name: &str) -> Result<GateId, String> {
+    GateId::all()
+        .iter()
+        .copied()
+        .find(|g| g.as_str() == name)
+        .ok_or_else(|| format!("unknown gate '{name}'"))
+}
+
+fn parse_gates(names: &[String]) -> Result<Vec<GateId>, String> {
+    names.iter().map(|n| parse_gate(n)).collect()
+}
@@ -119,3 +119,35 @@ pub struct PolicyResult {
     pub required_gates_passed: bool,
     pub details: String,
 }
+
+/// Policy evaluation for one scope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopeResult {
+    /// Scope label (`root`, `glob:<pattern>` or `package:<name>`).
+    pub scope: String,
+    /// Files governed by this scope.
+    pub file_count: usize,
+    pub result: PolicyResult,
+}
+
+/// A violation with the policy that governed it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernedViolation {
+    pub violation_id: String,
+    pub gate_id: GateId,
+    pub file: String,
+    pub line: u32,
+    pub rule_id: String,
+    pub scope: String,
+    pub policy_name: String,
+}
+
+/// Result of evaluating gate results under a scoped policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedPolicyReport {
+    /// Every scope must pass.
+    pub overall_passed: bool,
+    /// Root first, then scopes by label.
+    pub scopes: Vec<ScopeResult>,
+    pub violations: Vec<GovernedViolation>,
+}
@@ -104,6 +104,9 @@ impl Reporter for ConsoleReporter {
                 if let Some(ref fix) = violation.quick_fix {
                     output.push_str(&format!("    💡 Fix: {}\n", fix.description));
                 }
+                if !violation.owners.is_empty() {
+                    output.push_str(&format!("    Owners: {}\n", violation.owners.join(" ")));
+                }
             }
 
             // Show warnings
@@ -2,6 +2,7 @@
 
 use serde_json::json;
 
+use crate::enforcement::codeowners::summarize_by_owner;
 use crate::enforcement::gates::GateResult;
 use super::Reporter;
 
@@ -38,6 +39,7 @@ impl Reporter for JsonReporter {
                         "owasp_category": v.owasp_category,
                         "suppressed": v.suppressed,
                         "is_new": v.is_new,
+                        "owners": v.owners,
                     })).collect::<Vec<_>>(),
                     "warnings": r.warnings,
                     "execution_time_ms": r.execution_time_ms,
@@ -50,12 +52,15 @@ impl Reporter for JsonReporter {
         let total_violations: usize = results.iter().map(|r| r.violations.len()).sum();
         let all_passed = results.iter().all(|r| r.passed);
 
-        let output = json!({
+        let mut output = json!({
             "overall_passed": all_passed,
             "total_violations": total_violations,
             "gate_count": results.len(),
             "gates": gates,
         });
+        if results.iter().flat_map(|r| &r.violations).any(|v| !v.owners.is_empty()) {
+            output["owners"] = json!(summarize_by_owner(results));
+        }
 
         serde_json::to_string_pretty(&output).map_err(|e| e.to_string())
     }
@@ -12,6 +12,7 @@ pub mod junit;
 pub mod html;
 pub mod sonarqube;
 
+use crate::enforcement::codeowners::is_owned_by;
 use crate::enforcement::gates::GateResult;
 
 /// Trait for report generation.
@@ -35,6 +36,19 @@ pub fn create_reporter(format: &str) -> Option<Box<dyn Reporter>> {
     }
 }
 
+/// Keep only the violations owned by `owner` (`"unowned"` for violations
+/// without owners). Gate results are kept so reports still show every gate.
+pub fn filter_by_owner(results: &[GateResult], owner: &str) -> Vec<GateResult> {
+    results
+        .iter()
+        .map(|r| {
+            let mut filtered = r.clone();
+            filtered.violations.retain(|v| is_owned_by(&v.owners, owner));
+            filtered
+        })
+        .collect()
+}
+
 /// List all available reporter format names.
 pub fn available_formats() -> &'static [&'static str] {
     &["sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube"]
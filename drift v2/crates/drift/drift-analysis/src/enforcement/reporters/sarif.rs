@@ -55,7 +55,7 @@ impl SarifReporter {
                     }]
                 });
 
-                // Add properties (is_new, CWE, OWASP)
+                // Add properties (is_new, CWE, OWASP, owners)
                 let mut properties = serde_json::Map::new();
                 properties.insert("isNew".to_string(), json!(violation.is_new));
                 if let Some(cwe_id) = violation.cwe_id {
@@ -64,6 +64,9 @@ impl SarifReporter {
                 if let Some(ref owasp) = violation.owasp_category {
                     properties.insert("owaspCategory".to_string(), json!(owasp));
                 }
+                if !violation.owners.is_empty() {
+                    properties.insert("owners".to_string(), json!(violation.owners));
+                }
                 result["properties"] = Value::Object(properties);
 
                 // Add quick fix if available
@@ -81,6 +81,7 @@ impl RulesEvaluator {
                     owasp_category: pattern.owasp_categories.first().cloned(),
                     suppressed,
                     is_new,
+                    owners: Vec::new(),
                 });
             }
         }
@@ -97,6 +97,9 @@ pub struct Violation {
     pub suppressed: bool,
     /// Whether this violation was introduced by the current change.
     pub is_new: bool,
+    /// Code owners of the file, from CODEOWNERS.
+    #[serde(default)]
+    pub owners: Vec<String>,
 }
 
 /// Input data for the rules evaluator.
@@ -124,6 +127,52 @@ pub struct PatternInfo {
     pub owasp_categories: Vec<String>,
 }
 
+impl PatternInfo {
+    /// Merge per-occurrence infos by pattern id: locations and outliers are
+    /// concatenated, confidence is averaged over locations, and category,
+    /// CWE and OWASP come from the first occurrence. Sorted by pattern id.
+    pub fn group(occurrences: impl IntoIterator<Item = PatternInfo>) -> Vec<PatternInfo> {
+        let mut by_pattern: std::collections::HashMap<String, PatternInfo> = std::collections::HashMap::new();
+        for occurrence in occurrences {
+            match by_pattern.get_mut(&occurrence.pattern_id) {
+                Some(info) => {
+                    let (n, added) = (info.locations.len() as f64, occurrence.locations.len() as f64);
+                    if n + added > 0.0 {
+                        info.confidence = (info.confidence * n + occurrence.confidence * added) / (n + added);
+                    }
+                    info.locations.extend(occurrence.locations);
+                    info.outliers.extend(occurrence.outliers);
+                }
+                None => {
+                    by_pattern.insert(occurrence.pattern_id.clone(), occurrence);
+                }
+            }
+        }
+        let mut infos: Vec<PatternInfo> = by_pattern.into_values().collect();
+        infos.sort_by(|a, b| a.pattern_id.cmp(&b.pattern_id));
+        infos
+    }
+}
+
+/// A single detection as a one-location pattern, for [`PatternInfo::group`].
+impl From<&crate::engine::types::PatternMatch> for PatternInfo {
+    fn from(m: &crate::engine::types::PatternMatch) -> Self {
+        Self {
+            pattern_id: m.pattern_id.clone(),
+            category: format!("{:?}", m.category),
+            confidence: m.confidence as f64,
+            locations: vec![PatternLocation {
+                file: m.file.clone(),
+                line: m.line,
+                column: Some(m.column),
+            }],
+            outliers: Vec::new(),
+            cwe_ids: m.cwe_ids.to_vec(),
+            owasp_categories: m.owasp.iter().cloned().collect(),
+        }
+    }
+}
+
 /// A location where a pattern was detected.
 #[derive(Debug, Clone)]
 pub struct PatternLocation {
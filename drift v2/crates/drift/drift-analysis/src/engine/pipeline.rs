@@ -7,13 +7,14 @@
 
 use std::time::Instant;
 
+use crate::frameworks::FrameworkMatcher;
 use crate::parsers::types::ParseResult;
 
 use super::regex_engine::RegexEngine;
 use super::resolution::ResolutionIndex;
 use super::string_extraction;
 use super::types::AnalysisResult;
-use super::visitor::{DetectionContext, DetectionEngine};
+use super::visitor::{DetectionContext, DetectionEngine, FileDetectorHandler};
 
 /// The 4-phase analysis pipeline.
 pub struct AnalysisPipeline {
@@ -45,6 +46,31 @@ impl AnalysisPipeline {
         source: &[u8],
         tree: &tree_sitter::Tree,
         resolution_index: &mut ResolutionIndex,
+    ) -> AnalysisResult {
+        self.analyze(parse_result, source, tree, resolution_index, None)
+    }
+
+    /// Analyze a single file, then append the framework packs' matches for
+    /// it. The matcher is reset first, so one matcher can be reused across
+    /// files without accumulating their results.
+    pub fn analyze_file_with_frameworks(
+        &mut self,
+        parse_result: &ParseResult,
+        source: &[u8],
+        tree: &tree_sitter::Tree,
+        resolution_index: &mut ResolutionIndex,
+        frameworks: &mut FrameworkMatcher,
+    ) -> AnalysisResult {
+        self.analyze(parse_result, source, tree, resolution_index, Some(frameworks))
+    }
+
+    fn analyze(
+        &mut self,
+        parse_result: &ParseResult,
+        source: &[u8],
+        tree: &tree_sitter::Tree,
+        resolution_index: &mut ResolutionIndex,
+        frameworks: Option<&mut FrameworkMatcher>,
     ) -> AnalysisResult {
         let total_start = Instant::now();
         let mut result = AnalysisResult {
@@ -78,6 +104,13 @@ impl AnalysisPipeline {
         result.matches.extend(regex_matches);
         result.phase_times_us[2] = phase3_start.elapsed().as_micros() as u64;
 
+        if let Some(frameworks) = frameworks {
+            frameworks.reset();
+            frameworks.analyze_file(&ctx);
+            result.matches.extend_from_slice(frameworks.last_file_results());
+        }
+        result.embedded_sql = ctx.into_embedded_sql();
+
         // Phase 4: Resolution index building
         let phase4_start = Instant::now();
         resolution_index.index_parse_result(parse_result);
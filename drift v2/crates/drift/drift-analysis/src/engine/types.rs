@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
+use crate::language_provider::embedded_sql::EmbeddedSqlResult;
 use crate::scanner::language_detect::Language;
 
 /// Result of analyzing a single file through all 4 phases.
@@ -16,6 +17,8 @@ pub struct AnalysisResult {
     pub resolution_entries: usize,
     pub analysis_time_us: u64,
     pub phase_times_us: [u64; 4],
+    /// Queries, injection candidates and N+1 reads found in embedded SQL.
+    pub embedded_sql: EmbeddedSqlResult,
 }
 
 /// A single pattern detection result — the universal output type.
@@ -159,6 +162,7 @@ impl Default for AnalysisResult {
             resolution_entries: 0,
             analysis_time_us: 0,
             phase_times_us: [0; 4],
+            embedded_sql: EmbeddedSqlResult::default(),
         }
     }
 }
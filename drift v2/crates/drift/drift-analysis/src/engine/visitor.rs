@@ -4,9 +4,12 @@
 //! registered handlers per node type. Detectors MUST implement a visitor trait.
 
 
+use std::sync::OnceLock;
+
 use drift_core::types::collections::FxHashMap;
 use tree_sitter::Node;
 
+use crate::language_provider::embedded_sql::{analyze_embedded_sql, EmbeddedSqlResult};
 use crate::parsers::types::{
     CallSite, ClassInfo, ExportInfo, FunctionInfo, ImportInfo, ParseResult,
 };
@@ -26,6 +29,9 @@ pub struct DetectionContext<'a> {
     pub classes: &'a [ClassInfo],
     pub call_sites: &'a [CallSite],
     pub parse_result: &'a ParseResult,
+    /// Embedded SQL analysis of this file, computed on first use by
+    /// [`Self::embedded_sql`] so every detector shares one pass.
+    pub embedded_sql_cache: OnceLock<EmbeddedSqlResult>,
 }
 
 impl<'a> DetectionContext<'a> {
@@ -41,8 +47,23 @@ impl<'a> DetectionContext<'a> {
             classes: &parse_result.classes,
             call_sites: &parse_result.call_sites,
             parse_result,
+            embedded_sql_cache: OnceLock::new(),
         }
     }
+
+    /// SQL embedded in this file's source.
+    pub fn embedded_sql(&self) -> &EmbeddedSqlResult {
+        self.embedded_sql_cache.get_or_init(|| match std::str::from_utf8(self.source) {
+            Ok(source) => analyze_embedded_sql(self.parse_result, source),
+            Err(_) => EmbeddedSqlResult::default(),
+        })
+    }
+
+    /// Take the embedded SQL analysis, running it if no detector has.
+    pub fn into_embedded_sql(self) -> EmbeddedSqlResult {
+        self.embedded_sql();
+        self.embedded_sql_cache.into_inner().unwrap_or_default()
+    }
 }
 
 /// Trait for AST-visitor-based detectors (AD4).
@@ -68,6 +68,11 @@ impl FrameworkPackRegistry {
         Self::with_builtins_and_custom_filtered(custom_dir, None)
     }
 
+    /// Built-in packs plus the project's custom packs from `<root>/.drift/frameworks/`.
+    pub fn for_project(root: &Path) -> Self {
+        Self::with_builtins_and_custom(&root.join(".drift").join("frameworks"))
+    }
+
     /// Create registry with built-in + custom packs, applying optional config filter.
     pub fn with_builtins_and_custom_filtered(custom_dir: &Path, config: Option<&FrameworkConfig>) -> Self {
         let mut registry = Self::with_builtins_filtered(config);
@@ -205,6 +210,9 @@ fn builtin_packs() -> Vec<(&'static str, &'static str)> {
         ("rails", include_str!("packs/rails.toml")),
         ("go-frameworks", include_str!("packs/go_frameworks.toml")),
         ("rust-frameworks", include_str!("packs/rust_frameworks.toml")),
+        ("fastapi", include_str!("packs/fastapi.toml")),
+        ("ktor", include_str!("packs/ktor.toml")),
+        ("node-frameworks", include_str!("packs/node_frameworks.toml")),
         ("typescript-types", include_str!("packs/typescript_types.toml")),
     ]
 }
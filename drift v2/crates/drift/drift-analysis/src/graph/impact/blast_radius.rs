@@ -3,10 +3,21 @@
 use drift_core::types::collections::FxHashSet;
 use petgraph::graph::NodeIndex;
 
+use crate::advanced::history::ChurnIndex;
 use crate::call_graph::types::CallGraph;
+use crate::structural::complexity::ComplexityIndex;
 
 use super::types::{BlastRadius, RiskScore};
 
+/// Computed metrics that refine a blast radius's risk score. Without
+/// complexity metrics the factor is estimated from line span; without churn
+/// the change frequency is zero.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImpactMetrics<'a> {
+    pub complexity: Option<&'a ComplexityIndex>,
+    pub churn: Option<&'a ChurnIndex>,
+}
+
 /// Compute the blast radius for a function.
 ///
 /// Uses inverse BFS to find all transitive callers — every function
@@ -15,7 +26,9 @@ pub fn compute_blast_radius(
     graph: &CallGraph,
     function_id: NodeIndex,
     max_callers_for_normalization: u32,
+    metrics: Option<&ImpactMetrics<'_>>,
 ) -> BlastRadius {
+    let metrics = metrics.copied().unwrap_or_default();
     let (callers, max_depth) = transitive_callers(graph, function_id);
     let caller_count = callers.len() as u32;
 
@@ -26,8 +39,12 @@ pub fn compute_blast_radius(
     let node = &graph.graph[function_id];
     let sensitivity = compute_sensitivity(node);
 
-    // CG-IMPACT-02: Estimate complexity from line span
-    let complexity = compute_complexity_estimate(node);
+    // CG-IMPACT-02: Cyclomatic/cognitive complexity, estimated from line span if unmeasured
+    let complexity = metrics
+        .complexity
+        .and_then(|index| index.get(&node.file, node.line))
+        .map(|m| m.risk_factor())
+        .unwrap_or_else(|| compute_complexity_estimate(node));
 
     // Test coverage: approximate — functions in test files are covered
     let test_coverage = if node.file.to_lowercase().contains("test") { 0.8 } else { 0.2 };
@@ -37,7 +54,10 @@ pub fn compute_blast_radius(
         sensitivity,
         test_coverage,
         complexity,
-        0.0, // Change frequency: requires git history (out of scope)
+        metrics
+            .churn
+            .and_then(|index| index.change_frequency(&node.file, node.line))
+            .unwrap_or(0.0),
     );
 
     BlastRadius {
@@ -50,13 +70,13 @@ pub fn compute_blast_radius(
 }
 
 /// Compute blast radius for all functions in the graph.
-pub fn compute_all_blast_radii(graph: &CallGraph) -> Vec<BlastRadius> {
+pub fn compute_all_blast_radii(graph: &CallGraph, metrics: Option<&ImpactMetrics<'_>>) -> Vec<BlastRadius> {
     let max_callers = graph.function_count().max(1) as u32;
 
     graph
         .graph
         .node_indices()
-        .map(|idx| compute_blast_radius(graph, idx, max_callers))
+        .map(|idx| compute_blast_radius(graph, idx, max_callers, metrics))
         .collect()
 }
 
@@ -94,7 +114,8 @@ fn compute_sensitivity(node: &crate::call_graph::types::FunctionNode) -> f32 {
     score.min(1.0)
 }
 
-/// CG-IMPACT-02: Estimate complexity from function line span.
+/// CG-IMPACT-02: Estimate complexity from function line span when no
+/// metrics were computed for it.
 fn compute_complexity_estimate(node: &crate::call_graph::types::FunctionNode) -> f32 {
     let line_span = node.end_line.saturating_sub(node.line) as f32;
     // Normalize: 0-10 lines → low, 10-50 → medium, 50+ → high
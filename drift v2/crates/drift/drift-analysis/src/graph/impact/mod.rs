@@ -6,6 +6,6 @@ pub mod dead_code;
 pub mod path_finding;
 
 pub use types::*;
-pub use blast_radius::{compute_blast_radius, compute_all_blast_radii};
+pub use blast_radius::{compute_blast_radius, compute_all_blast_radii, ImpactMetrics};
 pub use dead_code::{detect_dead_code, detect_dead_code_with_resolution_rate, detect_unreachable};
 pub use path_finding::{shortest_path, k_shortest_paths};
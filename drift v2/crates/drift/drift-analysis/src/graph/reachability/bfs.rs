@@ -5,16 +5,21 @@
 
 use std::collections::VecDeque;
 
+#[cfg(feature = "native")]
 use drift_core::errors::CallGraphError;
 use drift_core::types::collections::FxHashSet;
 use petgraph::graph::NodeIndex;
 use petgraph::Direction;
+#[cfg(feature = "native")]
 use rusqlite::Connection;
 
+#[cfg(feature = "native")]
 use crate::call_graph::cte_fallback;
 use crate::call_graph::types::CallGraph;
 
-use super::types::{ReachabilityEngine, ReachabilityResult, SensitivityCategory, TraversalDirection};
+use super::types::{ReachabilityEngine, ReachabilityResult, SensitivityCategory};
+#[cfg(feature = "native")]
+use super::types::TraversalDirection;
 
 /// Threshold for auto-selecting between petgraph and SQLite CTE.
 const AUTO_SELECT_THRESHOLD: usize = 10_000;
@@ -63,6 +68,7 @@ pub fn reachability_inverse(
 }
 
 /// Forward reachability via SQLite CTE (for large graphs).
+#[cfg(feature = "native")]
 pub fn reachability_forward_cte(
     conn: &Connection,
     start_function_id: i64,
@@ -72,6 +78,7 @@ pub fn reachability_forward_cte(
 }
 
 /// Inverse reachability via SQLite CTE (for large graphs).
+#[cfg(feature = "native")]
 pub fn reachability_inverse_cte(
     conn: &Connection,
     start_function_id: i64,
@@ -81,6 +88,7 @@ pub fn reachability_inverse_cte(
 }
 
 /// Auto-select engine and run reachability.
+#[cfg(feature = "native")]
 pub fn reachability_auto(
     graph: &CallGraph,
     start: NodeIndex,
@@ -0,0 +1,1149 @@
+//! Source-level scanning: string literals per language, DB API calls, query
+//! reconstruction through concatenation/formatting/local variables, and loop
+//! regions.
+
+use std::cell::OnceCell;
+
+use crate::scanner::language_detect::Language;
+
+use super::sql::hole;
+use super::QueryConstruction;
+
+/// Calls whose argument is a complete SQL statement. The statement may be in
+/// any of the first three arguments (`QueryContext(ctx, sql)`,
+/// `mysqli_query($conn, $sql)`, `db.Get(&u, sql)`).
+const QUERY_APIS: &[&str] = &[
+    // JS/TS drivers and query builders
+    "query", "execute", "exec", "run", "all", "raw", "prepare", "$queryRaw", "$executeRaw",
+    "$queryRawUnsafe", "$executeRawUnsafe", "unsafe",
+    // Python DB-API / SQLAlchemy / Django
+    "executemany", "executescript", "fetch", "fetchrow", "fetchval",
+    // JDBC / JPA / Spring / Android
+    "executeQuery", "executeUpdate", "prepareStatement", "prepareCall", "createQuery", "createNativeQuery",
+    "createSQLQuery", "queryForObject", "queryForList", "queryForMap", "batchUpdate", "rawQuery", "execSQL",
+    // Go database/sql, sqlx, GORM
+    "Query", "QueryRow", "QueryContext", "QueryRowContext", "Queryx", "QueryRowx", "Exec", "ExecContext",
+    "Prepare", "PrepareContext", "Get", "GetContext", "Select", "SelectContext", "Raw",
+    // Rust rusqlite / sqlx / diesel
+    "query_row", "query_map", "query_one", "query_opt", "query_as", "query_scalar", "sql_query",
+    // .NET ADO / Dapper / EF Core
+    "QueryAsync", "QueryFirst", "QueryFirstOrDefault", "QueryFirstOrDefaultAsync", "QuerySingle",
+    "QuerySingleOrDefault", "QueryMultiple", "Execute", "ExecuteAsync", "ExecuteScalar", "ExecuteScalarAsync",
+    "ExecuteReader", "FromSqlRaw", "ExecuteSqlRaw", "ExecuteSqlRawAsync", "SqlQueryRaw", "FromSqlInterpolated",
+    "ExecuteSqlInterpolated", "SqlQuery", "SqlCommand", "NpgsqlCommand", "MySqlCommand", "SqliteCommand",
+    "OracleCommand",
+    // Rails / Laravel / PHP
+    "find_by_sql", "select_all", "select_one", "select_value", "select_values", "select_rows", "exec_query",
+    "mysqli_query", "pg_query", "statement", "select", "insert", "update", "delete", "unprepared",
+];
+
+/// APIs whose arguments are bound by the driver even when written as
+/// interpolated strings (EF Core `FormattableString`, sqlx macros).
+const PARAMETERIZING_APIS: &[&str] = &["FromSqlInterpolated", "ExecuteSqlInterpolated", "SqlQuery"];
+
+/// Calls that take a SQL fragment (a condition, sort key or expression)
+/// rather than a whole statement. These only matter when built dynamically.
+const FRAGMENT_APIS: &[&str] = &[
+    "whereRaw", "orWhereRaw", "havingRaw", "orHavingRaw", "orderByRaw", "selectRaw", "groupByRaw", "joinRaw",
+    "fromRaw", "literal", "where", "andWhere", "orWhere", "having", "orderBy", "order", "group", "joins",
+    "Where", "Or", "Not", "Order", "Having", "Joins", "Group",
+];
+
+/// Tagged template tags whose substitutions are bound parameters.
+const SAFE_TAGS: &[&str] = &["sql", "SQL", "$queryRaw", "$executeRaw"];
+
+/// Wrappers that take SQL text and return a statement object.
+const WRAPPERS: &[&str] = &["text", "sa.text", "sqlalchemy.text", "Arel.sql", "sql.SQL", "String.raw"];
+
+/// Iterator methods whose callback runs once per element.
+const ITERATORS: &[&str] = &[
+    "forEach", "map", "flatMap", "each", "each_with_index", "each_with_object", "find_each", "flat_map",
+    "for_each", "filter_map", "times", "ForEach", "Select", "SelectMany", "forEachIndexed", "mapIndexed",
+    "onEach", "foreach", "array_map",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Piece {
+    Text(String),
+    Expr(String, HoleKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum HoleKind {
+    Interpolation,
+    Concatenation,
+    Format,
+    /// Tagged-template or driver-bound substitution.
+    Bound,
+}
+
+/// A DB API call with its reconstructed SQL argument.
+#[derive(Debug, Clone)]
+pub(super) struct FoundQuery {
+    pub offset: usize,
+    pub api: String,
+    /// Whole statement (`true`) or fragment API (`false`).
+    pub statement: bool,
+    pub sql: String,
+    pub fragments: Vec<String>,
+    pub construction: QueryConstruction,
+}
+
+pub(super) struct Scanner<'a> {
+    text: &'a str,
+    lang: Language,
+    idents: OnceCell<Vec<(usize, &'a str)>>,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(text: &'a str, lang: Language) -> Self {
+        Self { text, lang, idents: OnceCell::new() }
+    }
+
+    // ---- Literals ----
+
+    /// Parse a string literal starting at byte `i`: its pieces and the byte
+    /// offset just past it. Heredocs consume only their marker.
+    fn literal_at(&self, i: usize) -> Option<(Vec<Piece>, usize)> {
+        let text = self.text;
+        let rest = &text[i..];
+        let first = rest.chars().next()?;
+        if i > 0 && first.is_alphabetic() && is_ident_char(text[..i].chars().next_back().unwrap_or(' ')) {
+            return None;
+        }
+        use Interp::*;
+        match self.lang {
+            Language::Python => {
+                let prefix_len = rest.chars().take_while(|c| "rRbBuUfF".contains(*c)).count();
+                if prefix_len > 2 {
+                    return None;
+                }
+                let prefix = &rest[..prefix_len];
+                let raw = prefix.contains(['r', 'R']);
+                let interp = if prefix.contains(['f', 'F']) { Brace } else { Plain };
+                let body = &rest[prefix_len..];
+                let quote = ["\"\"\"", "'''", "\"", "'"].into_iter().find(|q| body.starts_with(q))?;
+                self.scan(i + prefix_len + quote.len(), Some(quote), !raw, false, interp)
+            }
+            Language::TypeScript | Language::JavaScript => match first {
+                '\'' | '"' => self.scan(i + 1, Some(&rest[..1]), true, false, Plain),
+                '`' => self.scan(i + 1, Some("`"), true, false, DollarBrace),
+                _ => None,
+            },
+            Language::Ruby => match first {
+                '\'' => self.scan(i + 1, Some("'"), true, false, Plain),
+                '"' => self.scan(i + 1, Some("\""), true, false, Hash),
+                '<' if rest.starts_with("<<~") || rest.starts_with("<<-") || rest.starts_with("<<") => {
+                    self.heredoc(i, if rest[2..].starts_with(['~', '-']) { 3 } else { 2 }, Hash)
+                }
+                _ => None,
+            },
+            Language::Php => match first {
+                '\'' => self.scan(i + 1, Some("'"), true, false, Plain),
+                '"' => self.scan(i + 1, Some("\""), true, false, Php),
+                '<' if rest.starts_with("<<<") => self.heredoc(i, 3, Php),
+                _ => None,
+            },
+            Language::Kotlin | Language::Scala => {
+                let interp = if self.lang == Language::Kotlin { Dollar } else { Plain };
+                let (skip, interp) = match (self.lang, first) {
+                    (Language::Scala, 's' | 'f') if rest[1..].starts_with('"') => (1, Dollar),
+                    (_, '"') => (0, interp),
+                    (_, '\'') => return self.char_literal(i),
+                    _ => return None,
+                };
+                let quote = if rest[skip..].starts_with("\"\"\"") { "\"\"\"" } else { "\"" };
+                self.scan(i + skip + quote.len(), Some(quote), quote.len() == 1, false, interp)
+            }
+            Language::Swift => match first {
+                '"' => {
+                    let quote = if rest.starts_with("\"\"\"") { "\"\"\"" } else { "\"" };
+                    self.scan(i + quote.len(), Some(quote), true, false, SwiftParen)
+                }
+                _ => None,
+            },
+            Language::CSharp => {
+                let prefix_len = rest.chars().take_while(|c| *c == '$' || *c == '@').count().min(2);
+                let prefix = &rest[..prefix_len];
+                let body = &rest[prefix_len..];
+                if body.starts_with('\'') && prefix.is_empty() {
+                    return self.char_literal(i);
+                }
+                let quote = if body.starts_with("\"\"\"") { "\"\"\"" } else if body.starts_with('"') { "\"" } else { return None };
+                let verbatim = prefix.contains('@') || quote.len() == 3;
+                let interp = if prefix.contains('$') { Brace } else { Plain };
+                self.scan(i + prefix_len + quote.len(), Some(quote), !verbatim, verbatim, interp)
+            }
+            Language::Go => match first {
+                '"' => self.scan(i + 1, Some("\""), true, false, Plain),
+                '`' => self.scan(i + 1, Some("`"), false, false, Plain),
+                '\'' => self.char_literal(i),
+                _ => None,
+            },
+            Language::Rust => {
+                if first == '\'' {
+                    return self.char_literal(i);
+                }
+                let body = rest.strip_prefix('b').unwrap_or(rest);
+                let skipped = rest.len() - body.len();
+                if let Some(raw) = body.strip_prefix('r') {
+                    let hashes = raw.chars().take_while(|c| *c == '#').count();
+                    if !raw[hashes..].starts_with('"') {
+                        return None;
+                    }
+                    let close = format!("\"{}", "#".repeat(hashes));
+                    return self.scan(i + skipped + 2 + hashes, Some(&close), false, false, Plain);
+                }
+                body.starts_with('"').then_some(())?;
+                self.scan(i + skipped + 1, Some("\""), true, false, Plain)
+            }
+            Language::Java | Language::C | Language::Cpp => match first {
+                '"' if rest.starts_with("\"\"\"") => self.scan(i + 3, Some("\"\"\""), true, false, Plain),
+                '"' => self.scan(i + 1, Some("\""), true, false, Plain),
+                '\'' => self.char_literal(i),
+                'R' if self.lang == Language::Cpp && rest.starts_with("R\"") => {
+                    let open = rest.find('(')?;
+                    let close = format!("){}\"", &rest[2..open]);
+                    self.scan(i + open + 1, Some(&close), false, false, Plain)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    fn char_literal(&self, i: usize) -> Option<(Vec<Piece>, usize)> {
+        let rest = &self.text[i + 1..];
+        let close = rest.char_indices().skip(1).take(10).find(|(j, c)| *c == '\'' && !rest[..*j].ends_with('\\'))?.0;
+        if rest[..close].contains('\n') {
+            return None;
+        }
+        Some((vec![Piece::Text(rest[..close].to_string())], i + 1 + close + 1))
+    }
+
+    /// Ruby `<<~SQL` / PHP `<<<SQL` heredocs. Quoted terminators (`<<~'SQL'`,
+    /// `<<<'SQL'`) disable interpolation.
+    fn heredoc(&self, i: usize, marker_len: usize, interp: Interp) -> Option<(Vec<Piece>, usize)> {
+        let text = self.text;
+        let after = &text[i + marker_len..];
+        let quoted = after.starts_with(['\'', '"']);
+        let single = after.starts_with('\'');
+        let name_start = usize::from(quoted);
+        let name_len = after[name_start..].chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '_').count();
+        if name_len == 0 || !after[name_start..].starts_with(|c: char| c.is_ascii_uppercase() || c == '_') {
+            return None;
+        }
+        let name = &after[name_start..name_start + name_len];
+        let marker_end = i + marker_len + name_start + name_len + usize::from(quoted);
+        let body_start = marker_end + text[marker_end..].find('\n')? + 1;
+        let mut end = body_start;
+        let mut body_end = text.len();
+        for line in text[body_start..].split_inclusive('\n') {
+            let trimmed = line.trim().trim_end_matches([';', ',', ')']);
+            if trimmed == name {
+                body_end = end;
+                break;
+            }
+            end += line.len();
+        }
+        let interp = if single { Interp::Plain } else { interp };
+        let pieces = Scanner::new(&text[body_start..body_end], self.lang).scan(0, None, !single, false, interp)?.0;
+        Some((pieces, marker_end))
+    }
+
+    /// Scan literal contents from `start` up to `close` (or the end of the
+    /// text when `close` is `None`).
+    fn scan(&self, start: usize, close: Option<&str>, escapes: bool, doubled: bool, interp: Interp) -> Option<(Vec<Piece>, usize)> {
+        let text = self.text;
+        let mut pieces = Vec::new();
+        let mut buf = String::new();
+        let mut j = start;
+        while j < text.len() {
+            let rest = &text[j..];
+            if let Some(close) = close {
+                if doubled && rest.starts_with(close) && rest[close.len()..].starts_with(close) {
+                    buf.push_str(close);
+                    j += 2 * close.len();
+                    continue;
+                }
+                if rest.starts_with(close) {
+                    flush(&mut buf, &mut pieces);
+                    return Some((pieces, j + close.len()));
+                }
+                if close.len() == 1 && rest.starts_with('\n') && !matches!(close, "`") && !(self.lang == Language::Ruby || self.lang == Language::Php || self.lang == Language::Python && escapes) {
+                    // Unterminated single-line literal.
+                    return None;
+                }
+            }
+            if let Some((expr, len)) = interp.hole(rest) {
+                flush(&mut buf, &mut pieces);
+                pieces.push(Piece::Expr(expr.trim().to_string(), HoleKind::Interpolation));
+                j += len;
+                continue;
+            }
+            if interp == Interp::Brace && (rest.starts_with("{{") || rest.starts_with("}}")) {
+                buf.push_str(&rest[..1]);
+                j += 2;
+                continue;
+            }
+            let c = rest.chars().next()?;
+            if escapes && c == '\\' {
+                let Some(next) = rest[1..].chars().next() else { break };
+                buf.push(match next {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    other => other,
+                });
+                j += 1 + next.len_utf8();
+                continue;
+            }
+            buf.push(c);
+            j += c.len_utf8();
+        }
+        if close.is_none() {
+            flush(&mut buf, &mut pieces);
+            return Some((pieces, text.len()));
+        }
+        None
+    }
+
+    // ---- Code walking ----
+
+    fn comment_end(&self, i: usize) -> Option<usize> {
+        let rest = &self.text[i..];
+        let hash = matches!(self.lang, Language::Python | Language::Ruby | Language::Php);
+        let slash = !matches!(self.lang, Language::Python | Language::Ruby);
+        if (hash && rest.starts_with('#') && !rest.starts_with("#{")) || (slash && rest.starts_with("//")) {
+            return Some(i + rest.find('\n').unwrap_or(rest.len()));
+        }
+        if slash && rest.starts_with("/*") {
+            return Some(i + rest[2..].find("*/").map(|p| p + 4).unwrap_or(rest.len()));
+        }
+        None
+    }
+
+    /// Byte offsets of identifiers outside literals and comments.
+    fn identifiers(&self) -> &[(usize, &'a str)] {
+        self.idents.get_or_init(|| self.scan_identifiers())
+    }
+
+    fn scan_identifiers(&self) -> Vec<(usize, &'a str)> {
+        let text = self.text;
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < text.len() {
+            if let Some(end) = self.comment_end(i) {
+                i = end;
+                continue;
+            }
+            if let Some((_, end)) = self.literal_at(i) {
+                i = end;
+                continue;
+            }
+            let c = text[i..].chars().next().unwrap_or(' ');
+            if is_ident_start(c) && (i == 0 || !is_ident_char(text[..i].chars().next_back().unwrap_or(' '))) {
+                let len = text[i..].find(|ch: char| !is_ident_char(ch)).unwrap_or(text.len() - i);
+                out.push((i, &text[i..i + len]));
+                i += len;
+                continue;
+            }
+            i += c.len_utf8();
+        }
+        out
+    }
+
+    /// Index of the bracket closing the one at `open`.
+    pub fn matching_close(&self, open: usize) -> Option<usize> {
+        let text = self.text;
+        let (o, c) = match text.as_bytes().get(open)? {
+            b'(' => ('(', ')'),
+            b'[' => ('[', ']'),
+            b'{' => ('{', '}'),
+            _ => return None,
+        };
+        let mut depth = 0usize;
+        let mut i = open;
+        while i < text.len() {
+            if let Some(end) = self.comment_end(i) {
+                i = end;
+                continue;
+            }
+            if let Some((_, end)) = self.literal_at(i) {
+                i = end;
+                continue;
+            }
+            let ch = text[i..].chars().next()?;
+            if ch == o {
+                depth += 1;
+            } else if ch == c {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            i += ch.len_utf8();
+        }
+        None
+    }
+
+    /// Split `range` on top-level occurrences of `sep`, skipping literals and
+    /// nested brackets. Returns trimmed `(offset, text)` parts.
+    fn split_top(&self, start: usize, end: usize, is_sep: &dyn Fn(&str, &str) -> Option<usize>) -> Vec<(usize, &'a str)> {
+        let text = self.text;
+        let mut parts = Vec::new();
+        let mut depth = 0i32;
+        let mut part_start = start;
+        let mut i = start;
+        while i < end {
+            if let Some((_, lit_end)) = self.literal_at(i) {
+                i = lit_end.min(end);
+                continue;
+            }
+            let ch = text[i..].chars().next().unwrap_or(' ');
+            match ch {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ if depth == 0 => {
+                    if let Some(len) = is_sep(&text[..i], &text[i..end]) {
+                        parts.push(part_start);
+                        parts.push(i);
+                        i += len;
+                        part_start = i;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            i += ch.len_utf8();
+        }
+        parts.push(part_start);
+        parts.push(end);
+        parts
+            .chunks(2)
+            .map(|p| {
+                let raw = &text[p[0]..p[1]];
+                let lead = raw.len() - raw.trim_start().len();
+                (p[0] + lead, raw.trim())
+            })
+            .filter(|(_, s)| !s.is_empty())
+            .collect()
+    }
+
+    fn args(&self, open: usize) -> Option<(Vec<(usize, &'a str)>, usize)> {
+        let close = self.matching_close(open)?;
+        let args = self.split_top(open + 1, close, &|_, rest| rest.starts_with(',').then_some(1));
+        Some((args, close))
+    }
+
+    /// End of the statement starting at `start`: a top-level `;`, or a
+    /// newline not continued by an operator.
+    fn statement_end(&self, start: usize) -> usize {
+        let text = self.text;
+        let mut depth = 0i32;
+        let mut i = start;
+        while i < text.len() {
+            if let Some((_, end)) = self.literal_at(i) {
+                i = end;
+                continue;
+            }
+            if let Some(end) = self.comment_end(i) {
+                if depth == 0 && text[start..i].trim().is_empty() {
+                    i = end;
+                    continue;
+                }
+                if depth == 0 {
+                    return i;
+                }
+                i = end;
+                continue;
+            }
+            let ch = text[i..].chars().next().unwrap_or(' ');
+            match ch {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => return i,
+                ')' | ']' | '}' => depth -= 1,
+                ';' if depth == 0 => return i,
+                '\n' if depth == 0 => {
+                    let before = text[start..i].trim_end();
+                    let after = text[i..].trim_start();
+                    let continued = before.ends_with(['+', '.', ',', '(', '%', '\\', '=', '|', '&'])
+                        || after.starts_with(['+', '.', '%'])
+                        || after.starts_with("||")
+                        || before.is_empty();
+                    if !continued {
+                        return i;
+                    }
+                }
+                _ => {}
+            }
+            i += ch.len_utf8();
+        }
+        text.len()
+    }
+
+    // ---- Query reconstruction ----
+
+    /// Evaluate an expression into literal text and dynamic pieces.
+    fn eval(&self, offset: usize, expr: &str, depth: u8) -> Vec<Piece> {
+        let expr = expr.trim();
+        let stripped = strip_parens(expr);
+        if stripped.len() != expr.len() {
+            let inner = offset + expr.find(stripped).unwrap_or(0);
+            return self.eval(inner, stripped, depth);
+        }
+        if let Some(pieces) = self.eval_wrapper(offset, expr, depth) {
+            return pieces;
+        }
+        if let Some(pieces) = self.eval_format(offset, expr, depth) {
+            return pieces;
+        }
+        let php = self.lang == Language::Php;
+        let parts = self.split_top(offset, offset + expr.len(), &|before, rest| concat_op(before, rest, php));
+        if parts.len() > 1 {
+            let mut pieces = Vec::new();
+            for (part_offset, part) in parts {
+                let sub = self.eval_operand(part_offset, part, depth);
+                let literal = sub.iter().any(|p| matches!(p, Piece::Text(_)));
+                pieces.extend(sub.into_iter().map(|p| match p {
+                    // Operands that are not literals at all are concatenated values.
+                    Piece::Expr(e, HoleKind::Interpolation) if !literal => Piece::Expr(e, HoleKind::Concatenation),
+                    other => other,
+                }));
+            }
+            return pieces;
+        }
+        self.eval_operand(offset, expr, depth)
+    }
+
+    fn eval_operand(&self, offset: usize, expr: &str, depth: u8) -> Vec<Piece> {
+        // One or more adjacent literals (`"a" "b"` in C/Python).
+        let mut pieces = Vec::new();
+        let mut i = offset;
+        let end = offset + expr.len();
+        while i < end {
+            match self.literal_at(i) {
+                Some((lit, lit_end)) if lit_end <= end => {
+                    pieces.extend(lit);
+                    i = lit_end;
+                    while let Some(ch) = self.text[i..end].chars().next().filter(|c| c.is_whitespace()) {
+                        i += ch.len_utf8();
+                    }
+                }
+                _ => break,
+            }
+        }
+        if i >= end && !pieces.is_empty() {
+            return pieces;
+        }
+        let is_var = !expr.is_empty()
+            && expr.chars().all(|c| is_ident_char(c) || c == '.' || c == '$')
+            && !expr.starts_with(|c: char| c.is_ascii_digit());
+        if is_var && depth < 3 {
+            if let Some(resolved) = self.resolve_variable(offset, expr, depth + 1) {
+                return resolved;
+            }
+        }
+        vec![Piece::Expr(expr.to_string(), HoleKind::Concatenation)]
+    }
+
+    fn eval_wrapper(&self, offset: usize, expr: &str, depth: u8) -> Option<Vec<Piece>> {
+        let name = WRAPPERS.iter().find(|w| expr.starts_with(*w) && expr[w.len()..].starts_with(['(', '`']))?;
+        let open = offset + name.len();
+        if self.text[open..].starts_with('`') {
+            return self.literal_at(open).map(|(pieces, _)| pieces);
+        }
+        let (args, close) = self.args(open)?;
+        if close + 1 != offset + expr.len() {
+            return None;
+        }
+        let (arg_offset, arg) = args.first()?;
+        Some(self.eval(*arg_offset, arg, depth))
+    }
+
+    /// `"..." % args`, `"...".format(args)`, `String.format("...", args)`,
+    /// `fmt.Sprintf`, `format!`, `sprintf`, `string.Format`.
+    fn eval_format(&self, offset: usize, expr: &str, depth: u8) -> Option<Vec<Piece>> {
+        const FORMATTERS: &[&str] = &[
+            "String.format(", "fmt.Sprintf(", "format!(", "sprintf(", "string.Format(", "String.Format(", "str.format(",
+        ];
+        let end = offset + expr.len();
+        if let Some(f) = FORMATTERS.iter().find(|f| expr.starts_with(*f)) {
+            let (args, close) = self.args(offset + f.len() - 1)?;
+            if close + 1 != end {
+                return None;
+            }
+            let (fmt_offset, fmt) = args.first()?;
+            let template = self.eval_operand(*fmt_offset, fmt, depth);
+            let values: Vec<&str> = args[1..].iter().map(|(_, a)| *a).collect();
+            return Some(apply_format(template, &values));
+        }
+        if self.lang == Language::Python {
+            let parts = self.split_top(offset, end, &|_, rest| {
+                (rest.starts_with('%') && !rest.starts_with("%=")).then_some(1)
+            });
+            if let [(l_off, left), (_, right)] = parts.as_slice() {
+                let template = self.eval_operand(*l_off, left, depth);
+                if template.iter().any(|p| matches!(p, Piece::Text(_))) {
+                    let right = strip_parens(right);
+                    let values: Vec<&str> = self.split_top_str(right);
+                    return Some(apply_format(template, &values));
+                }
+            }
+        }
+        // `"...".format(a, b)`
+        let dot = expr.rfind(".format(")?;
+        if !expr.ends_with(')') {
+            return None;
+        }
+        let template = self.eval_operand(offset, &expr[..dot], depth);
+        if !template.iter().all(|p| matches!(p, Piece::Text(_))) {
+            return None;
+        }
+        let (args, _) = self.args(offset + dot + ".format".len())?;
+        let values: Vec<&str> = args.iter().map(|(_, a)| *a).collect();
+        Some(apply_format(template, &values))
+    }
+
+    fn split_top_str<'b>(&self, text: &'b str) -> Vec<&'b str> {
+        let inner = Scanner::new(text, self.lang);
+        inner
+            .split_top(0, text.len(), &|_, rest| rest.starts_with(',').then_some(1))
+            .into_iter()
+            .map(|(o, s)| &text[o..o + s.len()])
+            .collect()
+    }
+
+    /// Resolve a local variable to the value assigned before `before`, plus
+    /// any `+=`/`.=`/`<<` appends between that assignment and the use.
+    fn resolve_variable(&self, before: usize, name: &str, depth: u8) -> Option<Vec<Piece>> {
+        let text = self.text;
+        let mut base: Option<(usize, usize)> = None;
+        let mut appends: Vec<(usize, usize)> = Vec::new();
+        // `this.sql` / `self.query`: match the last segment, then its qualifier.
+        let (qualifier, last) = match name.rsplit_once('.') {
+            Some((q, l)) => (Some(q), l),
+            None => (None, name),
+        };
+        for &(at, ident) in self.identifiers() {
+            if at >= before {
+                break;
+            }
+            if ident != last {
+                continue;
+            }
+            let head = &text[..at];
+            let qualified = match qualifier {
+                Some(q) => head.strip_suffix('.').is_some_and(|h| h.ends_with(q)),
+                None => !head.ends_with('.'),
+            };
+            if !qualified {
+                continue;
+            }
+            let from = at + last.len();
+            let mut after = &text[from..before];
+            after = after.trim_start_matches([' ', '\t']);
+            // Type annotations: `const sql: string =`, `val sql: String =`.
+            if after.starts_with(':') && !after.starts_with(":=") {
+                let line_end = after.find('\n').unwrap_or(after.len());
+                match after[..line_end].find('=') {
+                    Some(eq) => after = &after[eq..],
+                    None => continue,
+                }
+            }
+            let (op_len, append) = if after.starts_with(":=") {
+                (2, false)
+            } else if after.starts_with("+=") || after.starts_with(".=") || after.starts_with("<<") {
+                (2, true)
+            } else if after.starts_with('=') && !after.starts_with("==") && !after.starts_with("=>") {
+                (1, false)
+            } else {
+                continue;
+            };
+            let rhs_start = before - after.len() + op_len;
+            let rhs_end = self.statement_end(rhs_start).min(before);
+            if append {
+                if base.is_some() {
+                    appends.push((rhs_start, rhs_end));
+                }
+            } else {
+                base = Some((rhs_start, rhs_end));
+                appends.clear();
+            }
+        }
+        let (start, end) = base?;
+        let mut pieces = self.eval_range(start, end, depth);
+        for (start, end) in appends {
+            pieces.extend(self.eval_range(start, end, depth).into_iter().map(|p| match p {
+                Piece::Expr(e, HoleKind::Interpolation) => Piece::Expr(e, HoleKind::Interpolation),
+                Piece::Expr(e, _) => Piece::Expr(e, HoleKind::Concatenation),
+                text => text,
+            }));
+        }
+        Some(pieces)
+    }
+
+    fn eval_range(&self, start: usize, end: usize, depth: u8) -> Vec<Piece> {
+        let raw = &self.text[start..end];
+        let lead = raw.len() - raw.trim_start().len();
+        self.eval(start + lead, raw.trim(), depth)
+    }
+
+    // ---- API calls ----
+
+    pub fn find_queries(&self) -> Vec<FoundQuery> {
+        let text = self.text;
+        let idents = self.identifiers();
+        let mut found = Vec::new();
+        for &(at, ident) in idents {
+            let end = at + ident.len();
+            let after = &text[end..];
+            let receiver = self.receiver_before(at);
+
+            // Tagged templates: sql`...`, prisma.$queryRaw`...`.
+            if matches!(self.lang, Language::TypeScript | Language::JavaScript) && after.starts_with('`') {
+                if SAFE_TAGS.contains(&ident) {
+                    if let Some((pieces, _)) = self.literal_at(end) {
+                        let pieces = pieces
+                            .into_iter()
+                            .map(|p| match p {
+                                Piece::Expr(e, _) => Piece::Expr(e, HoleKind::Bound),
+                                text => text,
+                            })
+                            .collect();
+                        self.push_query(&mut found, at, &receiver, ident, true, pieces);
+                    }
+                }
+                continue;
+            }
+
+            // `cmd.CommandText = "..."`
+            if ident == "CommandText" {
+                let trimmed = after.trim_start();
+                if trimmed.starts_with('=') && !trimmed.starts_with("==") {
+                    let rhs = text.len() - trimmed.len() + 1;
+                    let pieces = self.eval_range(rhs, self.statement_end(rhs), 0);
+                    self.push_query(&mut found, at, &receiver, ident, true, pieces);
+                }
+                continue;
+            }
+
+            let statement = QUERY_APIS.contains(&ident);
+            if !statement && !FRAGMENT_APIS.contains(&ident) {
+                continue;
+            }
+            let Some(open) = call_open(text, end) else { continue };
+            let Some((args, _)) = self.args(open) else { continue };
+            let mut pieces = None;
+            for (offset, arg) in args.iter().take(if statement { 3 } else { 1 }) {
+                let candidate = self.eval(*offset, arg, 0);
+                let usable = if statement {
+                    super::sql::looks_like_sql(&render(&candidate).0)
+                } else {
+                    candidate.iter().any(|p| matches!(p, Piece::Text(t) if !t.trim().is_empty()))
+                        && candidate.iter().any(|p| matches!(p, Piece::Expr(..)))
+                };
+                if usable {
+                    pieces = Some(candidate);
+                    break;
+                }
+            }
+            let Some(mut pieces) = pieces else { continue };
+            if PARAMETERIZING_APIS.contains(&ident) {
+                for p in &mut pieces {
+                    if let Piece::Expr(_, kind @ HoleKind::Interpolation) = p {
+                        *kind = HoleKind::Bound;
+                    }
+                }
+            }
+            self.push_query(&mut found, at, &receiver, ident, statement, pieces);
+        }
+        found
+    }
+
+    fn push_query(&self, found: &mut Vec<FoundQuery>, at: usize, receiver: &str, method: &str, statement: bool, pieces: Vec<Piece>) {
+        let (sql, fragments, kinds) = render(&pieces);
+        let construction = if kinds.contains(&HoleKind::Concatenation) {
+            QueryConstruction::Concatenated
+        } else if kinds.contains(&HoleKind::Format) {
+            QueryConstruction::Formatted
+        } else if kinds.contains(&HoleKind::Interpolation) {
+            QueryConstruction::Interpolated
+        } else if kinds.contains(&HoleKind::Bound) {
+            QueryConstruction::Parameterized
+        } else {
+            QueryConstruction::Literal
+        };
+        let start = if receiver.is_empty() { at } else { at - receiver.len() - 1 };
+        found.push(FoundQuery {
+            offset: start.min(at),
+            api: if receiver.is_empty() { method.to_string() } else { format!("{receiver}.{method}") },
+            statement,
+            sql,
+            fragments,
+            construction,
+        });
+    }
+
+    /// `db` in `db.query(`, `this.pool` in `this.pool.query(`, `DB` in
+    /// `DB::select(`, `$pdo` in `$pdo->query(`.
+    fn receiver_before(&self, at: usize) -> String {
+        let before = &self.text[..at];
+        let trimmed = before.trim_end();
+        let sep = ["?.", ".", "->", "::"].into_iter().find(|s| trimmed.ends_with(s));
+        let Some(sep) = sep else { return String::new() };
+        let head = trimmed[..trimmed.len() - sep.len()].trim_end();
+        let start = head
+            .char_indices()
+            .rev()
+            .take_while(|(i, c)| is_ident_char(*c) || *c == '$' || *c == '.' || *c == '?' || head[..=*i].ends_with("->") || head[..=*i].ends_with("::") || (*c == '-' && head[*i..].starts_with("->")) || (*c == ':' && head[*i..].starts_with("::")))
+            .last()
+            .map(|(i, _)| i)
+            .unwrap_or(head.len());
+        head[start..].replace("?.", ".").replace("->", ".").replace("::", ".").trim_matches('.').to_string()
+    }
+
+    // ---- Loops ----
+
+    /// Loop bodies as `(body_start, body_end, loop_offset)`. Headers are
+    /// excluded: `for row in cursor.execute(...)` runs its query once.
+    pub fn loop_regions(&self) -> Vec<(usize, usize, usize)> {
+        let text = self.text;
+        let indent_based = matches!(self.lang, Language::Python | Language::Ruby);
+        let mut regions = Vec::new();
+        for &(at, ident) in self.identifiers() {
+            let end = at + ident.len();
+            let after = &text[end..];
+            let line_start = text[..at].rfind('\n').map(|p| p + 1).unwrap_or(0);
+            let at_line_start = text[line_start..at].trim().is_empty()
+                || text[line_start..at].trim() == "async";
+            let header_end = at + text[at..].find('\n').unwrap_or(text.len() - at);
+            let region = match ident {
+                "for" | "while" | "until" if indent_based => {
+                    if at_line_start {
+                        Some((header_end, self.indented_block_end(line_start)))
+                    } else if self.lang == Language::Python && ident == "for" {
+                        // Comprehension: the enclosing bracket runs per element.
+                        self.enclosing_bracket(at)
+                    } else {
+                        None
+                    }
+                }
+                "for" | "foreach" | "while" | "loop" | "do" if !indent_based => {
+                    let trimmed = after.trim_start();
+                    let open = text.len() - trimmed.len();
+                    if trimmed.starts_with('(') {
+                        self.matching_close(open).map(|close| {
+                            let body = &text[close + 1..];
+                            let body_start = close + 1 + (body.len() - body.trim_start().len());
+                            match self.matching_close(body_start) {
+                                Some(body_end) if text[body_start..].starts_with('{') => (body_start, body_end),
+                                _ => (close, self.statement_end(body_start)),
+                            }
+                        })
+                    } else {
+                        // `for x in xs {`, `for _, x := range xs {`, `loop {`, `do {`.
+                        let brace = after.find('{').map(|p| end + p);
+                        brace
+                            .filter(|b| !text[end..*b].contains(';') && text[end..*b].matches('\n').count() <= 1)
+                            .and_then(|b| self.matching_close(b).map(|close| (b, close)))
+                    }
+                }
+                _ if ITERATORS.contains(&ident) && (text[..at].ends_with('.') || ident == "array_map") => {
+                    let trimmed = after.trim_start();
+                    let open = text.len() - trimmed.len();
+                    if trimmed.starts_with(['(', '{']) {
+                        self.matching_close(open).map(|close| (open, close))
+                    } else if trimmed.starts_with("do") && indent_based {
+                        Some((header_end, self.indented_block_end(line_start)))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            if let Some((start, end)) = region {
+                regions.push((start, end, at));
+            }
+        }
+        regions
+    }
+
+    fn indented_block_end(&self, line_start: usize) -> usize {
+        let text = self.text;
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let header = &text[line_start..];
+        let header_line = &header[..header.find('\n').unwrap_or(header.len())];
+        let base = indent(header_line);
+        let mut pos = line_start + header_line.len();
+        for line in text[pos..].split_inclusive('\n').skip(1) {
+            if !line.trim().is_empty() && indent(line) <= base {
+                break;
+            }
+            pos += line.len();
+        }
+        pos + 1
+    }
+
+    fn enclosing_bracket(&self, at: usize) -> Option<(usize, usize)> {
+        let text = self.text;
+        let mut depth = 0i32;
+        for (i, c) in text[..at].char_indices().rev() {
+            match c {
+                ')' | ']' | '}' => depth += 1,
+                '(' | '[' | '{' if depth == 0 => return self.matching_close(i).map(|close| (i, close)),
+                '(' | '[' | '{' => depth -= 1,
+                '\n' if depth == 0 && self.lang == Language::Python && text[..i].trim_end().ends_with(':') => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interp {
+    Plain,
+    /// `${expr}` (JS templates).
+    DollarBrace,
+    /// `${expr}` and `$name` (Kotlin, Scala `s""`).
+    Dollar,
+    /// `#{expr}` (Ruby).
+    Hash,
+    /// `{expr}` with `{{`/`}}` escapes (Python f-strings, C# `$""`).
+    Brace,
+    /// `\(expr)` (Swift).
+    SwiftParen,
+    /// `$var`, `$var->prop`, `{$expr}`, `${var}` (PHP).
+    Php,
+}
+
+impl Interp {
+    /// Interpolation at the start of `rest`: `(expression, consumed bytes)`.
+    fn hole(self, rest: &str) -> Option<(&str, usize)> {
+        let braced = |open_len: usize| -> Option<(&str, usize)> {
+            let mut depth = 0;
+            for (i, c) in rest.char_indices().skip(open_len - 1) {
+                match c {
+                    '{' | '(' => depth += 1,
+                    '}' | ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((&rest[open_len..i], i + 1));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            None
+        };
+        let ident_len = |from: usize| rest[from..].find(|c: char| !is_ident_char(c)).unwrap_or(rest.len() - from);
+        match self {
+            Interp::Plain => None,
+            Interp::DollarBrace => rest.starts_with("${").then(|| braced(2)).flatten(),
+            Interp::Dollar => {
+                if rest.starts_with("${") {
+                    braced(2)
+                } else if rest.starts_with('$') && rest[1..].starts_with(is_ident_start) {
+                    let len = ident_len(1);
+                    Some((&rest[1..1 + len], 1 + len))
+                } else {
+                    None
+                }
+            }
+            Interp::Hash => rest.starts_with("#{").then(|| braced(2)).flatten(),
+            Interp::Brace => (rest.starts_with('{') && !rest.starts_with("{{")).then(|| braced(1)).flatten(),
+            Interp::SwiftParen => rest.starts_with("\\(").then(|| braced(2)).flatten(),
+            Interp::Php => {
+                if rest.starts_with("{$") {
+                    braced(1)
+                } else if rest.starts_with("${") {
+                    braced(2)
+                } else if rest.starts_with('$') && rest[1..].starts_with(is_ident_start) {
+                    let mut len = 1 + ident_len(1);
+                    if rest[len..].starts_with("->") && rest[len + 2..].starts_with(is_ident_start) {
+                        len += 2 + ident_len(len + 2);
+                    }
+                    Some((&rest[..len], len))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn flush(buf: &mut String, pieces: &mut Vec<Piece>) {
+    if !buf.is_empty() {
+        match pieces.last_mut() {
+            Some(Piece::Text(t)) => t.push_str(buf),
+            _ => pieces.push(Piece::Text(buf.clone())),
+        }
+        buf.clear();
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Offset of the `(` opening a call whose name ends at `end`, allowing
+/// generic arguments (`Query<User>(`) and macro bangs (`query!(`).
+fn call_open(text: &str, end: usize) -> Option<usize> {
+    let mut i = end;
+    let rest = &text[i..];
+    if rest.starts_with('!') {
+        i += 1;
+    } else if rest.starts_with('<') {
+        let close = rest.find('>')?;
+        if rest[..close].contains(['(', ';', '\n']) {
+            return None;
+        }
+        i += close + 1;
+    }
+    let rest = &text[i..];
+    let trimmed = rest.trim_start_matches([' ', '\t']);
+    trimmed.starts_with('(').then(|| i + rest.len() - trimmed.len())
+}
+
+fn concat_op(before: &str, rest: &str, php: bool) -> Option<usize> {
+    if php {
+        let prev = before.chars().next_back().unwrap_or(' ');
+        let next = rest[1..].chars().next().unwrap_or(' ');
+        return (rest.starts_with('.') && !rest.starts_with(".=") && !rest.starts_with("..")
+            && !prev.is_ascii_digit() && !next.is_ascii_digit() && prev != '.')
+            .then_some(1);
+    }
+    (rest.starts_with('+') && !rest.starts_with("++") && !rest.starts_with("+=") && !before.ends_with('+'))
+        .then_some(1)
+}
+
+fn strip_parens(expr: &str) -> &str {
+    let mut e = expr.trim();
+    while e.starts_with('(') && e.ends_with(')') {
+        // Only strip when the outer pair matches (`(a) + (b)` stays).
+        let mut depth = 0i32;
+        let closes_at_end = e.char_indices().all(|(i, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            depth > 0 || i == e.len() - 1
+        });
+        if !closes_at_end {
+            break;
+        }
+        e = e[1..e.len() - 1].trim();
+    }
+    e
+}
+
+/// Replace format specifiers in literal text with the matching arguments.
+fn apply_format(template: Vec<Piece>, values: &[&str]) -> Vec<Piece> {
+    let mut out = Vec::new();
+    let mut next_arg = 0usize;
+    let mut arg = |key: &str| -> String {
+        match key.parse::<usize>() {
+            Ok(n) => values.get(n).copied().unwrap_or(key).to_string(),
+            Err(_) if !key.is_empty() => values
+                .iter()
+                .find_map(|v| {
+                    let (k, val) = v.split_once('=').or_else(|| v.split_once(':'))?;
+                    (k.trim().trim_matches(['"', '\'']) == key).then(|| val.trim())
+                })
+                .unwrap_or(key)
+                .to_string(),
+            Err(_) => {
+                let v = values.get(next_arg).copied().unwrap_or("?");
+                next_arg += 1;
+                v.to_string()
+            }
+        }
+    };
+    for piece in template {
+        let Piece::Text(text) = piece else {
+            out.push(piece);
+            continue;
+        };
+        let mut buf = String::new();
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let rest = &text[i..];
+            let spec = if c == '%' {
+                if rest.starts_with("%%") {
+                    chars.next();
+                    buf.push('%');
+                    continue;
+                }
+                if let Some(named) = rest.strip_prefix("%(").and_then(|r| r.find(')').map(|e| &r[..e])) {
+                    let len = 2 + named.len() + 2;
+                    Some((arg(named), len))
+                } else {
+                    let body: String = rest[1..].chars().take_while(|c| c.is_ascii_digit() || "$-+. #".contains(*c)).collect();
+                    match rest[1 + body.len()..].chars().next() {
+                        Some(conv) if "sdvqxfriuSD".contains(conv) => {
+                            let key = body.strip_suffix('$').unwrap_or("");
+                            let value = if key.is_empty() { arg("") } else { arg(&(key.parse::<usize>().unwrap_or(1) - 1).to_string()) };
+                            Some((value, 1 + body.len() + 1))
+                        }
+                        _ => None,
+                    }
+                }
+            } else if c == '{' && !rest.starts_with("{{") {
+                rest.find('}').filter(|e| rest[1..*e].chars().all(|c| is_ident_char(c) || c == ':')).map(|e| {
+                    let key = rest[1..e].split(':').next().unwrap_or("");
+                    (arg(key), e + 1)
+                })
+            } else {
+                None
+            };
+            match spec {
+                Some((value, len)) => {
+                    flush(&mut buf, &mut out);
+                    out.push(Piece::Expr(value, HoleKind::Format));
+                    for _ in 1..rest[..len].chars().count() {
+                        chars.next();
+                    }
+                }
+                None => buf.push(c),
+            }
+        }
+        flush(&mut buf, &mut out);
+    }
+    out
+}
+
+/// SQL text with hole markers, the dynamic expressions, and their kinds.
+/// Bound substitutions render as `?` and are not dynamic fragments.
+pub(super) fn render(pieces: &[Piece]) -> (String, Vec<String>, Vec<HoleKind>) {
+    let mut sql = String::new();
+    let mut fragments = Vec::new();
+    let mut kinds = Vec::new();
+    for piece in pieces {
+        match piece {
+            Piece::Text(t) => sql.push_str(t),
+            Piece::Expr(_, HoleKind::Bound) => {
+                sql.push('?');
+                kinds.push(HoleKind::Bound);
+            }
+            Piece::Expr(e, kind) => {
+                sql.push_str(&hole(fragments.len()));
+                fragments.push(e.clone());
+                kinds.push(*kind);
+            }
+        }
+    }
+    (sql, fragments, kinds)
+}
+
+/// 0-based line and column of a byte offset.
+pub(super) fn position(text: &str, offset: usize) -> (u32, u32) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() as u32;
+    let column = (before.len() - before.rfind('\n').map(|p| p + 1).unwrap_or(0)) as u32;
+    (line, column)
+}
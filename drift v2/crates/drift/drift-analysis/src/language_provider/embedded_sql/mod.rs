@@ -0,0 +1,337 @@
+//! Embedded SQL analysis — query strings in application code.
+//!
+//! Finds SQL passed to database APIs as literals, template literals,
+//! concatenations, format calls or local variables built from those, parses
+//! it with a dialect-aware reader, and reports:
+//! - tables/columns touched, as data-access patterns;
+//! - dynamically spliced fragments, as SQL injection candidates for taint;
+//! - per-row queries issued inside loops, as N+1 detections.
+
+pub mod sql;
+mod locate;
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph::taint::registry::SinkPattern;
+use crate::graph::taint::{SanitizerType, SinkType, TaintRegistry};
+use crate::parsers::types::ParseResult;
+
+use super::n_plus_one::{NPlusOneDetection, NPlusOneType};
+use super::types::{DataOperation, OrmPattern};
+use locate::{position, Scanner};
+
+pub use sql::{looks_like_sql, ColumnRef, HolePosition, ParsedSql, SqlDialect, TableRef};
+
+/// How the query text was put together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QueryConstruction {
+    /// A constant string.
+    Literal,
+    /// Substitutions bound by the driver (tagged templates, EF Core
+    /// `FromSqlInterpolated`).
+    Parameterized,
+    /// Language string interpolation (`${}`, f-strings, `#{}`).
+    Interpolated,
+    /// `+` / `.` concatenation, including appends to a variable.
+    Concatenated,
+    /// `%`, `.format`, `Sprintf`, `format!`, `String.format`.
+    Formatted,
+}
+
+impl QueryConstruction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Literal => "literal",
+            Self::Parameterized => "parameterized",
+            Self::Interpolated => "interpolated",
+            Self::Concatenated => "concatenated",
+            Self::Formatted => "formatted",
+        }
+    }
+
+    /// Whether application values are spliced into the SQL text.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Interpolated | Self::Concatenated | Self::Formatted)
+    }
+}
+
+/// A SQL statement passed to a database API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddedQuery {
+    pub file: String,
+    /// 0-based line of the call.
+    pub line: u32,
+    pub column: u32,
+    pub function: Option<String>,
+    /// Call as written, receiver included (`db.query`, `cursor.execute`).
+    pub api: String,
+    /// Reconstructed SQL; dynamic fragments appear as `__drift_hole_<n>__`.
+    pub sql: String,
+    pub dialect: SqlDialect,
+    pub construction: QueryConstruction,
+    pub operation: DataOperation,
+    pub tables: Vec<TableRef>,
+    pub columns: Vec<ColumnRef>,
+    pub placeholders: usize,
+    /// Source expressions of the dynamic fragments, indexed like the holes.
+    pub fragments: Vec<String>,
+    /// 0-based line of the enclosing loop, if the call runs per iteration.
+    pub loop_line: Option<u32>,
+}
+
+impl EmbeddedQuery {
+    /// One data-access pattern per referenced table.
+    pub fn access_patterns(&self) -> Vec<OrmPattern> {
+        self.tables
+            .iter()
+            .map(|table| OrmPattern {
+                framework: "sql".to_string(),
+                operation: table.operation,
+                table: Some(table.name.clone()),
+                fields: self
+                    .columns
+                    .iter()
+                    .filter(|c| c.table.as_deref() == Some(table.name.as_str()))
+                    .map(|c| c.name.clone())
+                    .collect(),
+                file: self.file.clone(),
+                line: self.line,
+                confidence: if self.construction.is_dynamic() { 0.8 } else { 0.95 },
+            })
+            .collect()
+    }
+}
+
+/// A dynamically built query (or fragment) whose spliced values may carry
+/// attacker input.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqlInjectionCandidate {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub function: Option<String>,
+    pub api: String,
+    pub construction: QueryConstruction,
+    /// Spliced expressions that are not obviously safe.
+    pub fragments: Vec<String>,
+    /// Most dangerous position any of them lands in.
+    pub position: HolePosition,
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddedSqlResult {
+    pub queries: Vec<EmbeddedQuery>,
+    pub injection_candidates: Vec<SqlInjectionCandidate>,
+    pub n_plus_one: Vec<NPlusOneDetection>,
+}
+
+/// Analyze one source file.
+pub fn analyze_embedded_sql(parse_result: &ParseResult, content: &str) -> EmbeddedSqlResult {
+    let mut result = EmbeddedSqlResult::default();
+    if !has_query_text(content) {
+        return result;
+    }
+    let dialect = SqlDialect::detect(content);
+    let scanner = Scanner::new(content, parse_result.language);
+    let found = scanner.find_queries();
+    if found.is_empty() {
+        return result;
+    }
+    let loops = scanner.loop_regions();
+    let file = parse_result.file.clone();
+
+    for query in found {
+        let (line, column) = position(content, query.offset);
+        let function = function_at(parse_result, line);
+        let parsed = if query.statement {
+            sql::parse(&query.sql, dialect)
+        } else {
+            // Fragments are read as a WHERE clause to place their holes.
+            sql::parse(&format!("SELECT 1 FROM __fragment WHERE {}", query.sql), dialect)
+        };
+
+        if query.construction.is_dynamic() {
+            let holes = parsed.as_ref().map(|p| p.holes.as_slice()).unwrap_or(&[]);
+            if let Some(candidate) = injection_candidate(&query, holes, &file, line, column, function.clone()) {
+                result.injection_candidates.push(candidate);
+            }
+        }
+
+        let Some(parsed) = parsed.filter(|_| query.statement) else { continue };
+        let loop_line = loops
+            .iter()
+            .filter(|(start, end, _)| *start < query.offset && query.offset < *end)
+            .max_by_key(|(start, _, _)| *start)
+            .map(|(_, _, at)| position(content, *at).0);
+
+        let embedded = EmbeddedQuery {
+            file: file.clone(),
+            line,
+            column,
+            function,
+            api: query.api,
+            sql: query.sql,
+            dialect,
+            construction: query.construction,
+            operation: parsed.operation,
+            tables: parsed.tables,
+            columns: parsed.columns,
+            placeholders: parsed.placeholders,
+            fragments: query.fragments,
+            loop_line,
+        };
+        if let Some(detection) = per_row_query(&embedded) {
+            result.n_plus_one.push(detection);
+        }
+        result.queries.push(embedded);
+    }
+    result
+}
+
+/// Register each candidate's API as a SQL sink so calls the default
+/// registry does not know (`repo.runSql`, `DB::select`) are tracked.
+pub fn register_taint_sinks(registry: &mut TaintRegistry, candidates: &[SqlInjectionCandidate]) {
+    for candidate in candidates {
+        let pattern = sink_pattern(&candidate.api);
+        if registry.match_sink(&pattern).is_some() {
+            continue;
+        }
+        registry.add_sink(SinkPattern {
+            pattern,
+            sink_type: SinkType::SqlQuery,
+            required_sanitizers: vec![SanitizerType::SqlParameterize],
+            framework: Some("embedded_sql".to_string()),
+        });
+    }
+}
+
+/// `this.db.query` → `db.query`: the receiver's last segment plus the method,
+/// which suffix-matches however the call site spells its receiver.
+fn sink_pattern(api: &str) -> String {
+    let mut parts = api.rsplit('.');
+    match (parts.next(), parts.next()) {
+        (Some(method), Some(receiver)) if receiver != "this" && receiver != "self" => format!("{receiver}.{method}"),
+        (Some(method), _) => method.to_string(),
+        _ => api.to_string(),
+    }
+}
+
+/// Cheap pre-filter: a DML verb somewhere in the file.
+fn has_query_text(content: &str) -> bool {
+    ["SELECT", "INSERT", "UPDATE", "DELETE", "select ", "insert ", "update ", "delete ", "MERGE", "WITH "]
+        .iter()
+        .any(|verb| content.contains(verb))
+}
+
+fn function_at(parse_result: &ParseResult, line: u32) -> Option<String> {
+    parse_result
+        .functions
+        .iter()
+        .chain(parse_result.classes.iter().flat_map(|c| c.methods.iter()))
+        .filter(|f| f.line <= line && line <= f.end_line)
+        .min_by_key(|f| f.end_line - f.line)
+        .map(|f| f.name.clone())
+}
+
+fn injection_candidate(
+    query: &locate::FoundQuery,
+    holes: &[(usize, HolePosition)],
+    file: &str,
+    line: u32,
+    column: u32,
+    function: Option<String>,
+) -> Option<SqlInjectionCandidate> {
+    let mut fragments = Vec::new();
+    let mut worst: Option<HolePosition> = None;
+    for (index, fragment) in query.fragments.iter().enumerate() {
+        if is_safe_fragment(fragment) {
+            continue;
+        }
+        let position = holes
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, p)| *p)
+            .unwrap_or(HolePosition::Clause);
+        worst = Some(match worst {
+            Some(current) if severity(current) >= severity(position) => current,
+            _ => position,
+        });
+        fragments.push(fragment.clone());
+    }
+    let position = worst?;
+    Some(SqlInjectionCandidate {
+        file: file.to_string(),
+        line,
+        column,
+        function,
+        api: query.api.clone(),
+        construction: query.construction,
+        fragments,
+        position,
+        confidence: match position {
+            HolePosition::Value => 0.85,
+            HolePosition::Clause => 0.8,
+            HolePosition::Identifier => 0.7,
+        },
+    })
+}
+
+/// Values splicing is dangerous anywhere, but a quoted or bare value is
+/// the classic exploitable shape; identifiers are usually allow-listed.
+fn severity(position: HolePosition) -> u8 {
+    match position {
+        HolePosition::Identifier => 0,
+        HolePosition::Clause => 1,
+        HolePosition::Value => 2,
+    }
+}
+
+/// Fragments that cannot carry attacker text: numbers, constants, numeric
+/// conversions and placeholder lists (`ids.map(() => '?').join(',')`).
+fn is_safe_fragment(fragment: &str) -> bool {
+    let f = fragment.trim();
+    if f.is_empty() || f.parse::<f64>().is_ok() {
+        return true;
+    }
+    let constant = f.rsplit(['.', ':']).next().unwrap_or(f);
+    if constant.len() > 1
+        && constant.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && constant.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return true;
+    }
+    if f.contains("'?'") || f.contains("\"?\"") || f.contains("'%s'") || f.contains("\"%s\"") || f.contains("placeholders") {
+        return true;
+    }
+    const NUMERIC: &[&str] = &["int(", "parseInt(", "Number(", "parseFloat(", "Integer.parseInt(", "strconv.Itoa(", "intval(", "(int)", "to_i", ".length", "len("];
+    NUMERIC.iter().any(|n| f.starts_with(n) || f.ends_with(n))
+}
+
+/// A read that runs once per loop iteration and is keyed by row values.
+fn per_row_query(query: &EmbeddedQuery) -> Option<NPlusOneDetection> {
+    let loop_line = query.loop_line?;
+    if !matches!(query.operation, DataOperation::Select | DataOperation::Join | DataOperation::Count | DataOperation::Aggregate) {
+        return None;
+    }
+    if query.placeholders == 0 && query.fragments.is_empty() {
+        return None;
+    }
+    let upper = query.sql.to_uppercase();
+    if upper.contains(" IN (") || upper.contains(" IN(") || upper.contains("ANY(") || upper.contains("ANY (") {
+        return None;
+    }
+    let table = query.tables.first().map(|t| t.name.as_str()).unwrap_or("the table");
+    Some(NPlusOneDetection {
+        file: query.file.clone(),
+        line: query.line,
+        loop_line,
+        query_method: query.api.clone(),
+        framework: "raw_sql".to_string(),
+        confidence: if query.fragments.is_empty() { 0.8 } else { 0.75 },
+        detection_type: NPlusOneType::LoopQuery,
+        suggestion: format!(
+            "Query {table} once for all rows (WHERE ... IN (...) or a JOIN) before the loop instead of once per iteration"
+        ),
+    })
+}
@@ -0,0 +1,772 @@
+//! Dialect-aware SQL lexer and DML reader for embedded queries.
+//!
+//! Not a validating parser: it reads just enough structure (statement verb,
+//! table references and aliases, column references, placeholder count) to
+//! place a query in the data-access graph, and tolerates fragments and
+//! dynamic holes spliced in from application code.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::language_provider::types::DataOperation;
+
+/// Dynamic fragments are spliced into reconstructed SQL as `__drift_hole_<n>__`.
+pub const HOLE_PREFIX: &str = "__drift_hole_";
+
+pub fn hole(index: usize) -> String {
+    format!("{HOLE_PREFIX}{index}__")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SqlDialect {
+    Generic,
+    Postgres,
+    MySql,
+    Sqlite,
+    SqlServer,
+    Oracle,
+}
+
+impl SqlDialect {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Generic => "generic",
+            Self::Postgres => "postgres",
+            Self::MySql => "mysql",
+            Self::Sqlite => "sqlite",
+            Self::SqlServer => "sqlserver",
+            Self::Oracle => "oracle",
+        }
+    }
+
+    /// Infer the dialect from driver imports and connection types in a file.
+    pub fn detect(content: &str) -> Self {
+        const HINTS: &[(SqlDialect, &[&str])] = &[
+            (SqlDialect::Postgres, &[
+                "'pg'", "\"pg\"", "'postgres'", "\"postgres\"", "psycopg", "asyncpg", "jackc/pgx", "lib/pq",
+                "tokio_postgres", "PgPool", "sqlx::postgres", "Npgsql", "org.postgresql", "jdbc:postgresql",
+                "postgres://", "postgresql://", "@vercel/postgres",
+            ]),
+            (SqlDialect::MySql, &[
+                "mysql2", "'mysql'", "\"mysql\"", "pymysql", "MySQLdb", "go-sql-driver/mysql", "MySqlConnector",
+                "MySql.Data", "jdbc:mysql", "mysql://", "MySqlPool", "mysqli", "sqlx::mysql",
+            ]),
+            (SqlDialect::Sqlite, &[
+                "sqlite3", "better-sqlite3", "rusqlite", "go-sqlite3", "Microsoft.Data.Sqlite", "SQLiteConnection",
+                "jdbc:sqlite", "SqlitePool", "sqlx::sqlite",
+            ]),
+            (SqlDialect::SqlServer, &[
+                "SqlClient", "'mssql'", "\"mssql\"", "tedious", "jdbc:sqlserver", "sqlserver://", "go-mssqldb",
+            ]),
+            (SqlDialect::Oracle, &["oracledb", "cx_Oracle", "jdbc:oracle", "OracleConnection", "godror"]),
+        ];
+        HINTS
+            .iter()
+            .find(|(_, needles)| needles.iter().any(|n| content.contains(n)))
+            .map(|(dialect, _)| *dialect)
+            .unwrap_or(Self::Generic)
+    }
+
+    fn backtick_identifiers(self) -> bool {
+        matches!(self, Self::Generic | Self::MySql | Self::Sqlite)
+    }
+
+    fn bracket_identifiers(self) -> bool {
+        matches!(self, Self::Generic | Self::SqlServer | Self::Sqlite)
+    }
+
+    fn double_quoted_strings(self) -> bool {
+        matches!(self, Self::MySql)
+    }
+
+    fn hash_comments(self) -> bool {
+        matches!(self, Self::MySql)
+    }
+
+    fn dollar_quoting(self) -> bool {
+        matches!(self, Self::Generic | Self::Postgres)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableRef {
+    /// Unqualified, unquoted table (or JPQL entity) name as written.
+    pub name: String,
+    pub alias: Option<String>,
+    /// `Select` for tables that are only read; the statement operation for
+    /// the target of `INSERT`/`UPDATE`/`DELETE`/`MERGE`.
+    pub operation: DataOperation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ColumnRef {
+    /// Resolved table name (aliases expanded) when it can be determined.
+    pub table: Option<String>,
+    pub name: String,
+}
+
+/// Where a dynamic fragment lands in the statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HolePosition {
+    /// A literal value (`= ${id}`, `'%${q}%'`, `LIMIT ${n}`).
+    Value,
+    /// A table, column or sort key (`FROM ${table}`, `ORDER BY ${col}`).
+    Identifier,
+    /// A whole clause or expression (`WHERE ${filter}`).
+    Clause,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedSql {
+    pub operation: DataOperation,
+    pub tables: Vec<TableRef>,
+    pub columns: Vec<ColumnRef>,
+    /// Bound parameters (`?`, `$1`, `:name`, `@name`, `%s`).
+    pub placeholders: usize,
+    /// Position of each hole, indexed like the hole markers.
+    pub holes: Vec<(usize, HolePosition)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Word(String),
+    /// Quoted identifier — never a keyword.
+    Ident(String),
+    /// String literal, with any holes spliced inside it.
+    Str(Vec<usize>),
+    Number,
+    Param,
+    Hole(usize),
+    /// `::` cast.
+    Cast,
+    Punct(char),
+}
+
+const DML_VERBS: &[&str] = &["SELECT", "INSERT", "UPDATE", "DELETE"];
+
+const KEYWORDS: &[&str] = &[
+    "ALL", "AND", "ANY", "AS", "ASC", "BEGIN", "BETWEEN", "BY", "CALL", "CASE", "CAST", "COLLATE", "COMMIT",
+    "CONFLICT", "CREATE", "CROSS", "CURRENT_DATE", "CURRENT_TIME", "CURRENT_TIMESTAMP", "CURRENT_USER",
+    "DEFAULT", "DELETE", "DESC", "DISTINCT", "DO", "DROP", "DUPLICATE", "ELSE", "END", "ESCAPE", "EXCEPT",
+    "EXEC", "EXECUTE", "EXISTS", "FALSE", "FETCH", "FILTER", "FIRST", "FOR", "FROM", "FULL", "GROUP", "HAVING",
+    "IF", "IGNORE", "ILIKE", "IN", "INNER", "INSERT", "INTERSECT", "INTERVAL", "INTO", "IS", "JOIN", "KEY",
+    "LAST", "LATERAL", "LEFT", "LIKE", "LIMIT", "LOCKED", "MATCHED", "MERGE", "MINUS", "NATURAL", "NEXT", "NO",
+    "NOT", "NOTHING", "NOWAIT", "NULL", "NULLS", "OF", "OFFSET", "ON", "ONLY", "OR", "ORDER", "OUTER", "OUTPUT",
+    "OVER", "PARTITION", "RECURSIVE", "REPLACE", "RETURNING", "RIGHT", "ROLLBACK", "ROW", "ROWS", "SELECT",
+    "SET", "SHARE", "SKIP", "SOME", "TABLE", "THEN", "TOP", "TRUE", "TRUNCATE", "UNION", "UNKNOWN", "UPDATE",
+    "UPSERT", "USING", "VALUE", "VALUES", "WHEN", "WHERE", "WINDOW", "WITH", "WITHIN",
+];
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(word))
+}
+
+/// Whether a string reads as a SQL statement rather than prose or a fragment.
+pub fn looks_like_sql(text: &str) -> bool {
+    let body = text.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+    let verb: String = body.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+    if verb.is_empty() || !body[verb.len()..].starts_with(char::is_whitespace) {
+        return false;
+    }
+    // Prose capitalizes only the first letter ("Select an option from ...").
+    if verb != verb.to_ascii_uppercase() && verb != verb.to_ascii_lowercase() {
+        return false;
+    }
+    if body.trim_end().ends_with(['.', '!']) {
+        return false;
+    }
+    let upper = body.to_ascii_uppercase();
+    let has = |kw: &str| {
+        upper
+            .match_indices(kw)
+            .any(|(i, _)| word_boundary(&upper, i) && word_boundary(&upper, i + kw.len()))
+    };
+    match verb.to_ascii_uppercase().as_str() {
+        "SELECT" => has("FROM") || !upper[6..].trim().contains(' '),
+        "INSERT" | "MERGE" | "REPLACE" => has("INTO"),
+        "UPDATE" => has("SET"),
+        "DELETE" => has("FROM"),
+        "WITH" => has("AS") && (has("SELECT") || has("INSERT") || has("UPDATE") || has("DELETE")),
+        "TRUNCATE" => true,
+        "CREATE" | "ALTER" | "DROP" => has("TABLE") || has("INDEX") || has("VIEW"),
+        _ => false,
+    }
+}
+
+fn word_boundary(text: &str, i: usize) -> bool {
+    let before = text[..i].chars().next_back();
+    let after = text[i..].chars().next();
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+    !(is_word(before) && is_word(after))
+}
+
+fn lex(sql: &str, dialect: SqlDialect) -> Vec<Tok> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut toks = Vec::new();
+    let mut i = 0;
+    let n = chars.len();
+    let read_until = |from: usize, close: char| -> (String, usize) {
+        let mut out = String::new();
+        let mut j = from;
+        while j < n {
+            if chars[j] == close {
+                if j + 1 < n && chars[j + 1] == close {
+                    out.push(close);
+                    j += 2;
+                    continue;
+                }
+                return (out, j + 1);
+            }
+            if chars[j] == '\\' && close == '\'' && j + 1 < n {
+                out.push(chars[j + 1]);
+                j += 2;
+                continue;
+            }
+            out.push(chars[j]);
+            j += 1;
+        }
+        (out, n)
+    };
+
+    while i < n {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '-' if next == Some('-') => {
+                while i < n && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '#' if dialect.hash_comments() => {
+                while i < n && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i + 1 < n && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            '\'' => {
+                let (text, end) = read_until(i + 1, '\'');
+                toks.push(Tok::Str(holes_in(&text)));
+                i = end;
+            }
+            '"' if dialect.double_quoted_strings() => {
+                let (text, end) = read_until(i + 1, '"');
+                toks.push(Tok::Str(holes_in(&text)));
+                i = end;
+            }
+            '"' => {
+                let (text, end) = read_until(i + 1, '"');
+                toks.push(ident_or_hole(text));
+                i = end;
+            }
+            '`' if dialect.backtick_identifiers() => {
+                let (text, end) = read_until(i + 1, '`');
+                toks.push(ident_or_hole(text));
+                i = end;
+            }
+            '[' if dialect.bracket_identifiers() => {
+                let (text, end) = read_until(i + 1, ']');
+                toks.push(ident_or_hole(text));
+                i = end;
+            }
+            '$' if next.is_some_and(|d| d.is_ascii_digit()) => {
+                i += 1;
+                while i < n && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                toks.push(Tok::Param);
+            }
+            '$' if dialect.dollar_quoting() => {
+                let tag_end = (i + 1..n).find(|&j| chars[j] == '$');
+                let tag: Option<String> = tag_end
+                    .map(|e| chars[i..=e].iter().collect::<String>())
+                    .filter(|t| t[1..t.len() - 1].chars().all(|c| c.is_alphanumeric() || c == '_'));
+                match tag {
+                    Some(tag) => {
+                        let body_start = i + tag.chars().count();
+                        let rest: String = chars[body_start..].iter().collect();
+                        let body_len = rest.find(&tag).unwrap_or(rest.len());
+                        toks.push(Tok::Str(holes_in(&rest[..body_len])));
+                        i = body_start + rest[..body_len].chars().count() + tag.chars().count();
+                    }
+                    None => {
+                        toks.push(Tok::Punct('$'));
+                        i += 1;
+                    }
+                }
+            }
+            '?' => {
+                i += 1;
+                while i < n && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                toks.push(Tok::Param);
+            }
+            ':' if next == Some(':') => {
+                toks.push(Tok::Cast);
+                i += 2;
+            }
+            ':' | '@' if next.is_some_and(|d| d.is_alphabetic() || d == '_') => {
+                i += 1;
+                while i < n && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                toks.push(Tok::Param);
+            }
+            '%' if matches!(next, Some('s' | 'd' | 'b' | 'f'))
+                && !chars.get(i + 2).is_some_and(|c| c.is_alphanumeric()) =>
+            {
+                toks.push(Tok::Param);
+                i += 2;
+            }
+            '%' if next == Some('(') => {
+                let close = (i..n).find(|&j| chars[j] == ')').unwrap_or(n - 1);
+                toks.push(Tok::Param);
+                i = close + 2;
+            }
+            _ if c.is_ascii_digit() => {
+                while i < n && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                toks.push(Tok::Number);
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < n && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                toks.push(ident_or_hole(word).into_word());
+            }
+            _ => {
+                toks.push(Tok::Punct(c));
+                i += 1;
+            }
+        }
+    }
+    toks
+}
+
+impl Tok {
+    fn into_word(self) -> Tok {
+        match self {
+            Tok::Ident(w) => Tok::Word(w),
+            other => other,
+        }
+    }
+}
+
+fn hole_index(word: &str) -> Option<usize> {
+    word.strip_prefix(HOLE_PREFIX)?.strip_suffix("__")?.parse().ok()
+}
+
+fn ident_or_hole(text: String) -> Tok {
+    match hole_index(&text) {
+        Some(index) => Tok::Hole(index),
+        None => Tok::Ident(text),
+    }
+}
+
+fn holes_in(text: &str) -> Vec<usize> {
+    text.match_indices(HOLE_PREFIX)
+        .filter_map(|(i, _)| {
+            let rest = &text[i..];
+            let end = rest[HOLE_PREFIX.len()..].find("__")? + HOLE_PREFIX.len() + 2;
+            hole_index(&rest[..end])
+        })
+        .collect()
+}
+
+/// Parse a statement. Returns `None` when the text does not start with a
+/// recognizable SQL verb.
+pub fn parse(sql: &str, dialect: SqlDialect) -> Option<ParsedSql> {
+    let toks = lex(sql, dialect);
+    let operation = statement_operation(&toks)?;
+    Some(Reader::new(&toks, operation).read())
+}
+
+fn word_at(toks: &[Tok], i: usize) -> Option<&str> {
+    match toks.get(i) {
+        Some(Tok::Word(w)) => Some(w.as_str()),
+        _ => None,
+    }
+}
+
+fn is_word(toks: &[Tok], i: usize, kw: &str) -> bool {
+    word_at(toks, i).is_some_and(|w| w.eq_ignore_ascii_case(kw))
+}
+
+fn statement_operation(toks: &[Tok]) -> Option<DataOperation> {
+    let first = toks.iter().position(|t| *t != Tok::Punct('('))?;
+    let verb = word_at(toks, first)?.to_ascii_uppercase();
+    let contains = |kws: &[&str]| toks.windows(kws.len()).any(|w| w.iter().zip(kws).all(|(t, k)| matches!(t, Tok::Word(x) if x.eq_ignore_ascii_case(k))));
+    let op = match verb.as_str() {
+        "SELECT" => {
+            if is_word(toks, first + 1, "COUNT") && toks.get(first + 2) == Some(&Tok::Punct('(')) {
+                DataOperation::Count
+            } else if ["SUM", "AVG", "MIN", "MAX"].iter().any(|f| is_word(toks, first + 1, f)) {
+                DataOperation::Aggregate
+            } else if contains(&["JOIN"]) {
+                DataOperation::Join
+            } else {
+                DataOperation::Select
+            }
+        }
+        "INSERT" if contains(&["ON", "CONFLICT"]) || contains(&["ON", "DUPLICATE"]) => DataOperation::Upsert,
+        "INSERT" => DataOperation::Insert,
+        "REPLACE" | "MERGE" | "UPSERT" => DataOperation::Upsert,
+        "UPDATE" => DataOperation::Update,
+        "DELETE" | "TRUNCATE" => DataOperation::Delete,
+        "WITH" => {
+            // Main verb: the first DML keyword outside the CTE bodies.
+            let mut depth = 0i32;
+            let main = toks[first..].iter().find_map(|t| match t {
+                Tok::Punct('(') => {
+                    depth += 1;
+                    None
+                }
+                Tok::Punct(')') => {
+                    depth -= 1;
+                    None
+                }
+                Tok::Word(w) if depth == 0 && DML_VERBS.iter().any(|v| w.eq_ignore_ascii_case(v)) => {
+                    Some(w.to_ascii_uppercase())
+                }
+                _ => None,
+            });
+            match main.as_deref() {
+                Some("INSERT") => DataOperation::Insert,
+                Some("UPDATE") => DataOperation::Update,
+                Some("DELETE") => DataOperation::Delete,
+                _ => DataOperation::Select,
+            }
+        }
+        "CREATE" | "ALTER" | "DROP" => DataOperation::Migration,
+        "BEGIN" | "START" | "COMMIT" | "ROLLBACK" => DataOperation::Transaction,
+        "CALL" | "EXEC" | "EXECUTE" => DataOperation::RawQuery,
+        _ => return None,
+    };
+    Some(op)
+}
+
+struct Reader<'a> {
+    toks: &'a [Tok],
+    operation: DataOperation,
+    tables: Vec<TableRef>,
+    aliases: HashMap<String, String>,
+    ctes: HashSet<String>,
+    /// Names introduced with `AS` in select lists.
+    output_names: HashSet<String>,
+    columns: Vec<(Option<String>, String)>,
+    placeholders: usize,
+    holes: Vec<(usize, HolePosition)>,
+}
+
+impl<'a> Reader<'a> {
+    fn new(toks: &'a [Tok], operation: DataOperation) -> Self {
+        Self {
+            toks,
+            operation,
+            tables: Vec::new(),
+            aliases: HashMap::new(),
+            ctes: HashSet::new(),
+            output_names: HashSet::new(),
+            columns: Vec::new(),
+            placeholders: 0,
+            holes: Vec::new(),
+        }
+    }
+
+    fn name_at(&self, i: usize) -> Option<&'a str> {
+        match self.toks.get(i) {
+            Some(Tok::Word(w)) if !is_keyword(w) => Some(w),
+            Some(Tok::Ident(w)) => Some(w),
+            _ => None,
+        }
+    }
+
+    fn read(mut self) -> ParsedSql {
+        let toks = self.toks;
+        let mut i = 0;
+        while i < toks.len() {
+            match &toks[i] {
+                Tok::Param => self.placeholders += 1,
+                Tok::Hole(index) => {
+                    let position = self.hole_position(i);
+                    self.holes.push((*index, position));
+                }
+                Tok::Str(holes) => self.holes.extend(holes.iter().map(|h| (*h, HolePosition::Value))),
+                Tok::Cast => {
+                    // Skip the target type (`::text`, `::varchar(20)`).
+                    i += 2;
+                    continue;
+                }
+                Tok::Word(w) => {
+                    let upper = w.to_ascii_uppercase();
+                    let target = match upper.as_str() {
+                        "INTO" => Some(self.write_operation()),
+                        // Not `ON DUPLICATE KEY UPDATE`, `DO UPDATE SET`, `FOR UPDATE`.
+                        "UPDATE" if !(i > 0 && ["KEY", "DO", "FOR"].iter().any(|k| is_word(toks, i - 1, k))) => {
+                            Some(self.operation)
+                        }
+                        "FROM" if i > 0 && is_word(toks, i - 1, "DELETE") => Some(self.operation),
+                        "TABLE" if i > 0 && is_word(toks, i - 1, "TRUNCATE") => Some(self.operation),
+                        "TRUNCATE" if !is_word(toks, i + 1, "TABLE") => Some(self.operation),
+                        "FROM" | "JOIN" => Some(DataOperation::Select),
+                        "USING" if !matches!(toks.get(i + 1), Some(Tok::Punct('('))) => Some(DataOperation::Select),
+                        _ => None,
+                    };
+                    if let Some(op) = target {
+                        i = self.table_list(i + 1, op, upper == "FROM");
+                        continue;
+                    }
+                    if upper == "AS" {
+                        if let Some(name) = self.name_at(i + 1) {
+                            if matches!(toks.get(i + 2), Some(Tok::Punct('('))) {
+                                // `name AS (` defines a CTE; the name precedes AS.
+                            } else {
+                                self.output_names.insert(name.to_lowercase());
+                            }
+                        }
+                        i += 2;
+                        continue;
+                    }
+                    if matches!(toks.get(i + 1), Some(Tok::Word(a)) if a.eq_ignore_ascii_case("AS"))
+                        && matches!(toks.get(i + 2), Some(Tok::Punct('(')))
+                    {
+                        self.ctes.insert(w.to_lowercase());
+                        i += 3;
+                        continue;
+                    }
+                    if !is_keyword(w) {
+                        i = self.column_chain(i);
+                        continue;
+                    }
+                }
+                Tok::Ident(_) => {
+                    i = self.column_chain(i);
+                    continue;
+                }
+                Tok::Punct('*') if i > 0 && (is_word(toks, i - 1, "SELECT") || is_word(toks, i - 1, "DISTINCT") || toks[i - 1] == Tok::Punct(',')) => {
+                    self.columns.push((None, "*".to_string()));
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        self.finish()
+    }
+
+    fn write_operation(&self) -> DataOperation {
+        match self.operation {
+            DataOperation::Select | DataOperation::Count | DataOperation::Aggregate => DataOperation::Insert,
+            op => op,
+        }
+    }
+
+    /// Read `name [AS] [alias] [, name [alias]]...` after a table keyword.
+    fn table_list(&mut self, mut i: usize, operation: DataOperation, allow_list: bool) -> usize {
+        let toks = self.toks;
+        loop {
+            if let Some(Tok::Hole(index)) = toks.get(i) {
+                self.holes.push((*index, HolePosition::Identifier));
+                return i + 1;
+            }
+            if is_word(toks, i, "ONLY") || is_word(toks, i, "LATERAL") {
+                i += 1;
+            }
+            let Some(mut name) = self.name_at(i) else { return i };
+            i += 1;
+            // Qualified: schema.table, db.schema.table.
+            while toks.get(i) == Some(&Tok::Punct('.')) {
+                match self.name_at(i + 1) {
+                    Some(segment) => {
+                        name = segment;
+                        i += 2;
+                    }
+                    None => {
+                        if let Some(Tok::Hole(index)) = toks.get(i + 1) {
+                            self.holes.push((*index, HolePosition::Identifier));
+                        }
+                        return i + 2;
+                    }
+                }
+            }
+            // A call in FROM is a table function (`generate_series(...)`).
+            if toks.get(i) == Some(&Tok::Punct('(')) && operation == DataOperation::Select {
+                return i;
+            }
+            if is_word(toks, i, "AS") {
+                i += 1;
+            }
+            let alias = self.name_at(i).map(str::to_string);
+            if alias.is_some() {
+                i += 1;
+            }
+            if let Some(alias) = &alias {
+                self.aliases.insert(alias.to_lowercase(), name.to_string());
+            }
+            self.tables.push(TableRef { name: name.to_string(), alias, operation });
+            if operation != DataOperation::Select && toks.get(i) == Some(&Tok::Punct('(')) {
+                // INSERT INTO t (a, b) — column list of the target table.
+                i += 1;
+                while let Some(tok) = toks.get(i) {
+                    match tok {
+                        Tok::Punct(')') => break,
+                        Tok::Word(w) | Tok::Ident(w) => self.columns.push((Some(name.to_string()), w.clone())),
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                return i + 1;
+            }
+            if allow_list && toks.get(i) == Some(&Tok::Punct(',')) {
+                i += 1;
+                continue;
+            }
+            return i;
+        }
+    }
+
+    /// Read `a`, `a.b` or `a.b.c`; calls (`lower(...)`) are not columns.
+    fn column_chain(&mut self, start: usize) -> usize {
+        let toks = self.toks;
+        let mut parts: Vec<&str> = Vec::new();
+        let mut i = start;
+        loop {
+            match toks.get(i) {
+                Some(Tok::Word(w)) | Some(Tok::Ident(w)) => parts.push(w),
+                Some(Tok::Punct('*')) if !parts.is_empty() => parts.push("*"),
+                Some(Tok::Hole(index)) => {
+                    self.holes.push((*index, HolePosition::Identifier));
+                    return i + 1;
+                }
+                _ => break,
+            }
+            i += 1;
+            if toks.get(i) == Some(&Tok::Punct('.')) {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        if toks.get(i) == Some(&Tok::Punct('(')) || parts.is_empty() {
+            return i.max(start + 1);
+        }
+        let name = parts[parts.len() - 1].to_string();
+        let table = (parts.len() >= 2).then(|| parts[parts.len() - 2].to_string());
+        self.columns.push((table, name));
+        i
+    }
+
+    fn hole_position(&self, i: usize) -> HolePosition {
+        let toks = self.toks;
+        if matches!(toks.get(i + 1), Some(Tok::Punct('.'))) {
+            return HolePosition::Identifier;
+        }
+        let Some(prev) = i.checked_sub(1).and_then(|p| toks.get(p)) else {
+            return HolePosition::Clause;
+        };
+        match prev {
+            Tok::Punct('=' | '<' | '>' | '+' | '-' | '/' | '%') => HolePosition::Value,
+            Tok::Punct('.') => HolePosition::Identifier,
+            Tok::Punct('(' | ',') => {
+                // Inside IN (...) or VALUES (...) these are values; in a
+                // select list or ORDER BY they are identifiers.
+                let mut depth = 0i32;
+                for t in toks[..i].iter().rev() {
+                    match t {
+                        Tok::Punct(')') => depth += 1,
+                        Tok::Punct('(') if depth == 0 => {
+                            return HolePosition::Value;
+                        }
+                        Tok::Punct('(') => depth -= 1,
+                        Tok::Word(w) if depth == 0 => {
+                            let w = w.to_ascii_uppercase();
+                            return match w.as_str() {
+                                "SELECT" | "BY" | "DISTINCT" => HolePosition::Identifier,
+                                "VALUES" | "IN" | "SET" => HolePosition::Value,
+                                _ => continue,
+                            };
+                        }
+                        _ => {}
+                    }
+                }
+                HolePosition::Value
+            }
+            Tok::Word(w) => match w.to_ascii_uppercase().as_str() {
+                "LIKE" | "ILIKE" | "LIMIT" | "OFFSET" | "THEN" | "ELSE" | "BETWEEN" | "IS" | "VALUES" | "TOP" => {
+                    HolePosition::Value
+                }
+                "WHERE" | "AND" | "OR" | "ON" | "HAVING" | "NOT" => HolePosition::Clause,
+                _ => HolePosition::Identifier,
+            },
+            _ => HolePosition::Clause,
+        }
+    }
+
+    fn finish(self) -> ParsedSql {
+        let table_names: HashSet<String> = self.tables.iter().map(|t| t.name.to_lowercase()).collect();
+        let real_tables: Vec<&TableRef> =
+            self.tables.iter().filter(|t| !self.ctes.contains(&t.name.to_lowercase())).collect();
+        let target = self.tables.iter().find(|t| t.operation != DataOperation::Select).map(|t| t.name.clone());
+        let sole = match real_tables.as_slice() {
+            [only] => Some(only.name.clone()),
+            _ => None,
+        };
+
+        let mut seen = HashSet::new();
+        let mut columns = Vec::new();
+        for (qualifier, name) in self.columns {
+            let lower = name.to_lowercase();
+            if qualifier.is_none()
+                && (self.aliases.contains_key(&lower)
+                    || self.output_names.contains(&lower)
+                    || self.ctes.contains(&lower)
+                    || table_names.contains(&lower))
+            {
+                continue;
+            }
+            let table = match qualifier {
+                Some(q) => {
+                    let ql = q.to_lowercase();
+                    if matches!(ql.as_str(), "excluded" | "new" | "old" | "inserted" | "deleted") {
+                        target.clone()
+                    } else {
+                        Some(self.aliases.get(&ql).cloned().unwrap_or(q))
+                    }
+                }
+                None => sole.clone(),
+            };
+            let column = ColumnRef { table, name };
+            if seen.insert(column.clone()) {
+                columns.push(column);
+            }
+        }
+
+        let mut tables: Vec<TableRef> = Vec::new();
+        for table in real_tables {
+            match tables.iter_mut().find(|t| t.name.eq_ignore_ascii_case(&table.name)) {
+                // A table both read and written reports the write.
+                Some(existing) if existing.operation == DataOperation::Select => existing.operation = table.operation,
+                Some(_) => {}
+                None => tables.push(table.clone()),
+            }
+        }
+
+        ParsedSql {
+            operation: self.operation,
+            tables,
+            columns,
+            placeholders: self.placeholders,
+            holes: self.holes,
+        }
+    }
+}
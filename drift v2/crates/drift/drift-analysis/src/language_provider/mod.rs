@@ -8,6 +8,7 @@ pub mod normalizers;
 pub mod framework_matchers;
 pub mod n_plus_one;
 pub mod taint_sinks;
+pub mod embedded_sql;
 
 pub use types::{UnifiedCallChain, ChainCall, CallArg, DataOperation, OrmPattern};
 pub use normalizers::{LanguageNormalizer, normalize_chain};
@@ -1,8 +1,17 @@
 //! Parse cache: Moka LRU in-memory + optional SQLite persistence.
 //! Keyed by (content_hash, language) — same content parsed as different
 //! languages produces separate cache entries.
+//!
+//! Without the `native` feature (WASI builds) Moka is replaced by a bounded
+//! map that evicts an arbitrary entry when full.
 
+#[cfg(feature = "native")]
 use moka::sync::Cache;
+#[cfg(not(feature = "native"))]
+use std::sync::Mutex;
+
+#[cfg(not(feature = "native"))]
+use drift_core::types::collections::FxHashMap;
 
 use super::types::ParseResult;
 use crate::scanner::language_detect::Language;
@@ -16,10 +25,19 @@ fn make_key(content_hash: u64, lang: Language) -> CacheKey {
 }
 
 /// In-memory parse cache using Moka (TinyLFU admission).
+#[cfg(feature = "native")]
 pub struct ParseCache {
     inner: Cache<CacheKey, ParseResult>,
 }
 
+/// In-memory parse cache, bounded map.
+#[cfg(not(feature = "native"))]
+pub struct ParseCache {
+    inner: Mutex<FxHashMap<CacheKey, ParseResult>>,
+    capacity: u64,
+}
+
+#[cfg(feature = "native")]
 impl ParseCache {
     /// Create a new parse cache with the given capacity.
     pub fn new(capacity: u64) -> Self {
@@ -49,6 +67,45 @@ impl ParseCache {
     }
 }
 
+#[cfg(not(feature = "native"))]
+impl ParseCache {
+    /// Create a new parse cache with the given capacity.
+    pub fn new(capacity: u64) -> Self {
+        Self {
+            inner: Mutex::new(FxHashMap::default()),
+            capacity,
+        }
+    }
+
+    /// Get a cached parse result by content hash and language.
+    pub fn get(&self, content_hash: u64, lang: Language) -> Option<ParseResult> {
+        self.inner.lock().ok()?.get(&make_key(content_hash, lang)).cloned()
+    }
+
+    /// Insert a parse result into the cache.
+    pub fn insert(&self, content_hash: u64, lang: Language, result: ParseResult) {
+        let Ok(mut inner) = self.inner.lock() else { return };
+        let key = make_key(content_hash, lang);
+        if inner.len() as u64 >= self.capacity && !inner.contains_key(&key) {
+            let Some(evict) = inner.keys().next().copied() else { return };
+            inner.remove(&evict);
+        }
+        inner.insert(key, result);
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn entry_count(&self) -> u64 {
+        self.inner.lock().map_or(0, |inner| inner.len() as u64)
+    }
+
+    /// Invalidate a cache entry.
+    pub fn invalidate(&self, content_hash: u64, lang: Language) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.remove(&make_key(content_hash, lang));
+        }
+    }
+}
+
 impl Default for ParseCache {
     fn default() -> Self {
         // Default: cache up to 10,000 parse results
@@ -82,6 +82,27 @@ impl Language {
             Language::Scala => "Scala",
         }
     }
+
+    /// Inverse of [`Language::name`], for languages read back from storage.
+    pub fn from_name(name: &str) -> Option<Language> {
+        match name {
+            "TypeScript" => Some(Language::TypeScript),
+            "JavaScript" => Some(Language::JavaScript),
+            "Python" => Some(Language::Python),
+            "Java" => Some(Language::Java),
+            "C#" => Some(Language::CSharp),
+            "Go" => Some(Language::Go),
+            "Rust" => Some(Language::Rust),
+            "Ruby" => Some(Language::Ruby),
+            "PHP" => Some(Language::Php),
+            "Kotlin" => Some(Language::Kotlin),
+            "C++" => Some(Language::Cpp),
+            "C" => Some(Language::C),
+            "Swift" => Some(Language::Swift),
+            "Scala" => Some(Language::Scala),
+            _ => None,
+        }
+    }
 }
 
 impl Language {
@@ -3,14 +3,20 @@
 //! The scanner is the entry point to the entire Drift pipeline. It discovers files,
 //! computes content hashes, detects languages, and produces a `ScanDiff` describing
 //! what changed since the last scan.
+//!
+//! Directory walking (`walker`, `Scanner`) needs the `native` feature; hashing,
+//! language detection and diffing do not.
 
 pub mod cancellation;
 pub mod hasher;
 pub mod incremental;
 pub mod language_detect;
+#[cfg(feature = "native")]
 pub mod scanner;
 pub mod types;
+#[cfg(feature = "native")]
 pub mod walker;
 
+#[cfg(feature = "native")]
 pub use scanner::Scanner;
 pub use types::{ScanDiff, ScanEntry, ScanStats};
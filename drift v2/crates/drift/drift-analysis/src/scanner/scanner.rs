@@ -31,6 +31,12 @@ impl Scanner {
         }
     }
 
+    /// Create a scanner cancelled through a caller-owned handle. `scan`
+    /// resets the handle on entry, so check it before calling.
+    pub fn with_cancellation(config: ScanConfig, cancellation: ScanCancellation) -> Self {
+        Self { config, cancellation }
+    }
+
     /// Get a reference to the cancellation handle for external cancellation.
     pub fn cancellation(&self) -> &ScanCancellation {
         &self.cancellation
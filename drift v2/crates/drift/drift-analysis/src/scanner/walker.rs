@@ -8,6 +8,8 @@ use std::sync::Arc;
 
 use crossbeam_channel as channel;
 use drift_core::config::ScanConfig;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::overrides::{Override, OverrideBuilder};
 
 use super::language_detect::Language;
 use super::types::DiscoveredFile;
@@ -63,36 +65,7 @@ pub fn walk_directory(
         builder.threads(threads);
     }
 
-    // Build overrides: include patterns (whitelist) + ignore patterns (blacklist).
-    //
-    // The `ignore` crate's OverrideBuilder uses gitignore syntax:
-    // - Positive patterns act as a whitelist (only matching files are included)
-    // - Negated patterns (prefixed with !) act as a blacklist (matching files are excluded)
-    //
-    // When include patterns are present, we add them as positive patterns first,
-    // then add ignore patterns as negated patterns. The ignore crate evaluates
-    // overrides in order: if any positive pattern matches, the file is included;
-    // if any negated pattern matches, the file is excluded.
-    let mut overrides = ignore::overrides::OverrideBuilder::new(root);
-
-    // If include patterns are specified, add them as positive whitelist patterns.
-    // Files must match at least one include pattern to be scanned.
-    if !config.include.is_empty() {
-        for pattern in &config.include {
-            let _ = overrides.add(pattern);
-        }
-    }
-
-    // Add default ignore patterns (blacklist)
-    for pattern in DEFAULT_IGNORES {
-        let _ = overrides.add(&format!("!{}/**", pattern));
-        let _ = overrides.add(&format!("!{}", pattern));
-    }
-    // Add user-configured extra ignores (blacklist)
-    for pattern in &config.extra_ignore {
-        let _ = overrides.add(&format!("!{}", pattern));
-    }
-    if let Ok(built) = overrides.build() {
+    if let Ok(built) = build_overrides(root, config) {
         builder.overrides(built);
     }
 
@@ -152,3 +125,77 @@ pub fn walk_directory(
     files.sort_by(|a, b| a.path.cmp(&b.path));
     Ok(files)
 }
+
+/// Include/ignore overrides shared by the walker and [`PathFilter`].
+fn build_overrides(root: &Path, config: &ScanConfig) -> Result<Override, ignore::Error> {
+    // Build overrides: include patterns (whitelist) + ignore patterns (blacklist).
+    //
+    // The `ignore` crate's OverrideBuilder uses gitignore syntax:
+    // - Positive patterns act as a whitelist (only matching files are included)
+    // - Negated patterns (prefixed with !) act as a blacklist (matching files are excluded)
+    //
+    // When include patterns are present, we add them as positive patterns first,
+    // then add ignore patterns as negated patterns. The ignore crate evaluates
+    // overrides in order: if any positive pattern matches, the file is included;
+    // if any negated pattern matches, the file is excluded.
+    let mut overrides = OverrideBuilder::new(root);
+
+    // If include patterns are specified, add them as positive whitelist patterns.
+    // Files must match at least one include pattern to be scanned.
+    if !config.include.is_empty() {
+        for pattern in &config.include {
+            let _ = overrides.add(pattern);
+        }
+    }
+
+    // Add default ignore patterns (blacklist)
+    for pattern in DEFAULT_IGNORES {
+        let _ = overrides.add(&format!("!{}/**", pattern));
+        let _ = overrides.add(&format!("!{}", pattern));
+    }
+    // Add user-configured extra ignores (blacklist)
+    for pattern in &config.extra_ignore {
+        let _ = overrides.add(&format!("!{}", pattern));
+    }
+    overrides.build()
+}
+
+/// The walker's include/exclude rules applied to individual paths, for files
+/// that do not come from a directory walk (e.g. blobs in past git trees).
+///
+/// Uses the include patterns, the default ignores, `extra_ignore` and the
+/// `.gitignore`, `.driftignore` and `.git/info/exclude` files at the root.
+/// Nested ignore files are not consulted.
+pub struct PathFilter {
+    overrides: Override,
+    ignores: Gitignore,
+}
+
+impl PathFilter {
+    pub fn new(root: &Path, config: &ScanConfig) -> Self {
+        let mut ignores = GitignoreBuilder::new(root);
+        for file in [".gitignore", ".driftignore", ".git/info/exclude"] {
+            let path = root.join(file);
+            if path.is_file() {
+                let _ = ignores.add(path);
+            }
+        }
+        Self {
+            overrides: build_overrides(root, config).unwrap_or_else(|_| Override::empty()),
+            ignores: ignores.build().unwrap_or_else(|_| Gitignore::empty()),
+        }
+    }
+
+    /// Whether the walker would skip `path` (relative to the root), either
+    /// directly or because one of its directories is excluded.
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        let dir_excluded = path
+            .ancestors()
+            .skip(1)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .any(|dir| self.overrides.matched(dir, true).is_ignore());
+        dir_excluded
+            || self.overrides.matched(path, false).is_ignore()
+            || self.ignores.matched_path_or_any_parents(path, false).is_ignore()
+    }
+}
@@ -0,0 +1,294 @@
+//! CloneDetector — fragment collection, MinHash LSH candidate search,
+//! pair verification and clone-class grouping.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use tree_sitter::{Node, Tree};
+
+use super::tokens::{shingles, tokenize};
+use super::types::*;
+use crate::parsers::types::{FunctionInfo, ParseResult};
+use crate::patterns::aggregation::similarity::{jaccard_similarity, MinHashIndex};
+use crate::structural::complexity::function_node;
+use crate::structural::module_of;
+
+/// Accumulates fragments file by file, then groups them into clone classes.
+pub struct CloneDetector {
+    config: CloneConfig,
+    fragments: Vec<CloneFragment>,
+    /// Line count per analyzed file.
+    file_lines: FxHashMap<String, u32>,
+}
+
+impl CloneDetector {
+    pub fn new(config: CloneConfig) -> Self {
+        Self {
+            config,
+            fragments: Vec::new(),
+            file_lines: FxHashMap::default(),
+        }
+    }
+
+    /// Collect the functions and large blocks of a parsed file.
+    pub fn add_file(&mut self, parse_result: &ParseResult, tree: &Tree, source: &[u8]) {
+        let line_count = source.split(|&b| b == b'\n').count() - usize::from(source.last() == Some(&b'\n'));
+        let line_count = line_count as u32;
+        self.file_lines.insert(parse_result.file.clone(), line_count);
+
+        let root = tree.root_node();
+        let mut seen: FxHashSet<(u32, &str)> = FxHashSet::default();
+        let functions = parse_result
+            .functions
+            .iter()
+            .chain(parse_result.classes.iter().flat_map(|c| c.methods.iter()))
+            .filter(|f| seen.insert((f.line, f.name.as_str())));
+        for function in functions {
+            let Some(node) = function_node(root, function) else { continue };
+            self.add_fragment(parse_result, function, node, FragmentKind::Function, source, self.config.min_tokens);
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                // The body itself is the function; look for blocks within it.
+                let mut inner = child.walk();
+                for grandchild in child.children(&mut inner) {
+                    self.add_blocks(parse_result, function, grandchild, source);
+                }
+            }
+        }
+    }
+
+    /// Add the outermost blocks under `node` that are large enough.
+    fn add_blocks(&mut self, parse_result: &ParseResult, function: &FunctionInfo, node: Node, source: &[u8]) {
+        if is_block(node.kind())
+            && self.add_fragment(parse_result, function, node, FragmentKind::Block, source, self.config.min_block_tokens)
+        {
+            return;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.add_blocks(parse_result, function, child, source);
+        }
+    }
+
+    fn add_fragment(
+        &mut self,
+        parse_result: &ParseResult,
+        function: &FunctionInfo,
+        node: Node,
+        kind: FragmentKind,
+        source: &[u8],
+        min_tokens: usize,
+    ) -> bool {
+        let tokens = tokenize(node, source);
+        if tokens.normalized.len() < min_tokens {
+            return false;
+        }
+        self.fragments.push(CloneFragment {
+            file: parse_result.file.clone(),
+            name: function.name.clone(),
+            kind,
+            language: parse_result.language,
+            line: node.start_position().row as u32,
+            end_line: node.end_position().row as u32,
+            raw_hash: tokens.raw_hash,
+            normalized_hash: tokens.normalized_hash,
+            tokens: tokens.normalized,
+        });
+        true
+    }
+
+    /// Fragments collected so far.
+    pub fn fragments(&self) -> &[CloneFragment] {
+        &self.fragments
+    }
+
+    /// Group the collected fragments into clone classes and measure
+    /// duplication per file and module.
+    pub fn detect(&self) -> CloneReport {
+        let fragments = &self.fragments;
+        let shingle_sets: Vec<FxHashSet<String>> =
+            fragments.iter().map(|f| shingles(&f.tokens, self.config.shingle_size)).collect();
+
+        let mut index = MinHashIndex::new(self.config.num_perm, self.config.num_bands);
+        for (i, set) in shingle_sets.iter().enumerate() {
+            index.insert(&i.to_string(), set);
+        }
+
+        let mut pairs: Vec<(usize, usize, CloneType, f64)> = Vec::new();
+        for (a, b) in index.find_candidates() {
+            let (Ok(a), Ok(b)) = (a.parse::<usize>(), b.parse::<usize>()) else { continue };
+            let (fa, fb) = (&fragments[a], &fragments[b]);
+            if fa.overlaps(fb) || (!self.config.cross_language && fa.language != fb.language) {
+                continue;
+            }
+            let pair = if fa.raw_hash == fb.raw_hash {
+                Some((CloneType::Type1, 1.0))
+            } else if fa.normalized_hash == fb.normalized_hash {
+                Some((CloneType::Type2, 1.0))
+            } else {
+                let similarity = jaccard_similarity(&shingle_sets[a], &shingle_sets[b]);
+                (similarity >= self.config.similarity_threshold).then_some((CloneType::Type3, similarity))
+            };
+            if let Some((clone_type, similarity)) = pair {
+                pairs.push((a.min(b), a.max(b), clone_type, similarity));
+            }
+        }
+        pairs.sort_by_key(|p| (p.0, p.1));
+
+        let classes = self.group(&pairs);
+        let files = self.file_duplication(&classes);
+        let modules = self.module_duplication(&files);
+        let total_lines = self.file_lines.values().sum();
+        let duplicated_lines = files.iter().map(|f| f.duplicated_lines).sum();
+        CloneReport {
+            classes,
+            files,
+            modules,
+            total_lines,
+            duplicated_lines,
+        }
+    }
+
+    /// Union-find over verified pairs; block classes already explained by
+    /// a class of their enclosing functions are dropped.
+    fn group(&self, pairs: &[(usize, usize, CloneType, f64)]) -> Vec<CloneClass> {
+        let fragments = &self.fragments;
+        let mut parent: Vec<usize> = (0..fragments.len()).collect();
+        fn find(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]];
+                x = parent[x];
+            }
+            x
+        }
+        for &(a, b, _, _) in pairs {
+            let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+            if ra != rb {
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+        let root: Vec<usize> = (0..fragments.len()).map(|i| find(&mut parent, i)).collect();
+
+        let mut members: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+        let mut relation: FxHashMap<usize, (CloneType, f64)> = FxHashMap::default();
+        for &(a, b, clone_type, similarity) in pairs {
+            let entry = relation.entry(root[a]).or_insert((clone_type, similarity));
+            entry.0 = entry.0.max(clone_type);
+            entry.1 = entry.1.min(similarity);
+            members.entry(root[a]).or_default().extend([a, b]);
+        }
+        for group in members.values_mut() {
+            group.sort_unstable();
+            group.dedup();
+        }
+
+        let mut functions_by_file: FxHashMap<&str, Vec<usize>> = FxHashMap::default();
+        for (i, f) in fragments.iter().enumerate() {
+            if f.kind == FragmentKind::Function && members.contains_key(&root[i]) {
+                functions_by_file.entry(f.file.as_str()).or_default().push(i);
+            }
+        }
+        // The class of a cloned function enclosing fragment `i`, if any.
+        let enclosing_class = |i: usize| {
+            functions_by_file
+                .get(fragments[i].file.as_str())?
+                .iter()
+                .find(|&&j| j != i && root[j] != root[i] && fragments[j].contains(&fragments[i]))
+                .map(|&j| root[j])
+        };
+
+        let mut roots: Vec<usize> = members.keys().copied().collect();
+        roots.sort_unstable();
+        let mut kept: Vec<(usize, Vec<usize>)> = Vec::new();
+        for class in roots {
+            let group = &members[&class];
+            let covering: Vec<Option<usize>> = group.iter().map(|&i| enclosing_class(i)).collect();
+            let redundant = covering[0].is_some() && covering.iter().all(|c| *c == covering[0]);
+            if !redundant {
+                kept.push((class, group.clone()));
+            }
+        }
+
+        let mut classes: Vec<CloneClass> = kept
+            .into_iter()
+            .map(|(root, group)| {
+                let mut instances: Vec<CloneInstance> = group
+                    .iter()
+                    .map(|&i| {
+                        let f = &fragments[i];
+                        CloneInstance { file: f.file.clone(), name: f.name.clone(), kind: f.kind, line: f.line, end_line: f.end_line }
+                    })
+                    .collect();
+                instances.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+                let (clone_type, similarity) = relation[&root];
+                CloneClass { id: 0, clone_type, similarity, instances }
+            })
+            .collect();
+        classes.sort_by(|a, b| {
+            b.redundant_lines()
+                .cmp(&a.redundant_lines())
+                .then_with(|| (&a.instances[0].file, a.instances[0].line).cmp(&(&b.instances[0].file, b.instances[0].line)))
+        });
+        for (id, class) in classes.iter_mut().enumerate() {
+            class.id = id as u32 + 1;
+        }
+        classes
+    }
+
+    /// Lines covered by any clone instance, per file.
+    fn file_duplication(&self, classes: &[CloneClass]) -> Vec<FileDuplication> {
+        let mut ranges: FxHashMap<&str, Vec<(u32, u32)>> = FxHashMap::default();
+        for instance in classes.iter().flat_map(|c| &c.instances) {
+            ranges.entry(instance.file.as_str()).or_default().push((instance.line, instance.end_line));
+        }
+        let mut files: Vec<FileDuplication> = ranges
+            .into_iter()
+            .map(|(file, mut ranges)| {
+                ranges.sort_unstable();
+                let mut duplicated = 0;
+                let mut covered_to: Option<u32> = None;
+                for (start, end) in ranges {
+                    let start = covered_to.map_or(start, |c| start.max(c + 1));
+                    if start <= end {
+                        duplicated += end - start + 1;
+                    }
+                    covered_to = Some(covered_to.map_or(end, |c| c.max(end)));
+                }
+                let total_lines = self.file_lines.get(file).copied().unwrap_or(duplicated);
+                FileDuplication { file: file.to_string(), total_lines, duplicated_lines: duplicated.min(total_lines) }
+            })
+            .collect();
+        files.sort_by(|a, b| a.file.cmp(&b.file));
+        files
+    }
+
+    /// Duplication per directory, over every analyzed file.
+    fn module_duplication(&self, files: &[FileDuplication]) -> Vec<ModuleDuplication> {
+        let duplicated: FxHashMap<&str, u32> = files.iter().map(|f| (f.file.as_str(), f.duplicated_lines)).collect();
+        let mut modules: FxHashMap<&str, (u32, u32)> = FxHashMap::default();
+        for (file, &lines) in &self.file_lines {
+            let entry = modules.entry(module_of(file)).or_default();
+            entry.0 += lines;
+            entry.1 += duplicated.get(file.as_str()).copied().unwrap_or(0);
+        }
+        let mut modules: Vec<ModuleDuplication> = modules
+            .into_iter()
+            .map(|(module, (total_lines, duplicated_lines))| ModuleDuplication {
+                module: module.to_string(),
+                total_lines,
+                duplicated_lines,
+                percentage: if total_lines == 0 { 0.0 } else { duplicated_lines as f64 / total_lines as f64 * 100.0 },
+            })
+            .collect();
+        modules.sort_by(|a, b| b.percentage.total_cmp(&a.percentage).then_with(|| a.module.cmp(&b.module)));
+        modules
+    }
+}
+
+impl Default for CloneDetector {
+    fn default() -> Self {
+        Self::new(CloneConfig::default())
+    }
+}
+
+fn is_block(kind: &str) -> bool {
+    matches!(kind, "block" | "statement_block" | "compound_statement" | "do_block" | "body_statement")
+}
@@ -0,0 +1,14 @@
+//! Clone Detection — Type-1/2/3 code clones across functions and large
+//! blocks, grouped into clone classes, with duplication per module.
+//!
+//! Fragments are tokenized from the tree-sitter tree with identifiers and
+//! literals abstracted, shingled, and indexed with the same MinHash LSH
+//! used for pattern deduplication; candidate pairs are verified with exact
+//! shingle Jaccard similarity.
+
+pub mod types;
+pub mod tokens;
+pub mod detector;
+
+pub use types::*;
+pub use detector::CloneDetector;
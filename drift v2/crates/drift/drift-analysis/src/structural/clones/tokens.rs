@@ -0,0 +1,91 @@
+//! Token normalization for clone detection.
+//!
+//! Tokens are the tree-sitter leaves of a fragment, comments dropped and
+//! string literals taken whole. The raw sequence identifies Type-1 clones;
+//! the normalized sequence, with identifiers replaced by `$id` and
+//! literals by `$lit`, identifies Type-2 clones and is what Type-3
+//! shingles are built from.
+
+use std::hash::Hasher;
+
+use drift_core::types::collections::FxHashSet;
+use tree_sitter::Node;
+
+const LITERAL_KEYWORDS: &[&str] = &["true", "false", "null", "nil", "none", "undefined", "None", "True", "False"];
+
+/// Raw and normalized token streams of one fragment.
+pub struct TokenStream {
+    pub raw_hash: u64,
+    pub normalized_hash: u64,
+    pub normalized: Vec<String>,
+}
+
+/// Tokenize the source under `node`.
+pub fn tokenize(node: Node, source: &[u8]) -> TokenStream {
+    let mut raw = xxhash_rust::xxh3::Xxh3::new();
+    let mut normalized = Vec::new();
+    collect(node, source, &mut raw, &mut normalized);
+
+    let mut normalized_hash = xxhash_rust::xxh3::Xxh3::new();
+    for token in &normalized {
+        normalized_hash.write(token.as_bytes());
+        normalized_hash.write_u8(0);
+    }
+    TokenStream {
+        raw_hash: raw.finish(),
+        normalized_hash: normalized_hash.finish(),
+        normalized,
+    }
+}
+
+fn collect(node: Node, source: &[u8], raw: &mut xxhash_rust::xxh3::Xxh3, normalized: &mut Vec<String>) {
+    let kind = node.kind();
+    if kind.contains("comment") {
+        return;
+    }
+    let is_literal = node.is_named() && is_literal_kind(kind);
+    if is_literal || node.child_count() == 0 {
+        let text = &source[node.byte_range()];
+        if text.is_empty() {
+            return;
+        }
+        raw.write(text);
+        raw.write_u8(0);
+        normalized.push(if is_literal || LITERAL_KEYWORDS.contains(&kind) {
+            "$lit".to_string()
+        } else if node.is_named() && is_identifier_kind(kind) {
+            "$id".to_string()
+        } else {
+            String::from_utf8_lossy(text).into_owned()
+        });
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(child, source, raw, normalized);
+    }
+}
+
+fn is_literal_kind(kind: &str) -> bool {
+    kind.contains("string")
+        || kind.contains("number")
+        || kind.contains("integer")
+        || kind.contains("float")
+        || kind == "char_literal"
+        || kind == "rune_literal"
+        || kind == "heredoc_body"
+}
+
+fn is_identifier_kind(kind: &str) -> bool {
+    kind.contains("identifier") || kind == "name" || kind == "constant"
+}
+
+/// Overlapping `size`-token shingles of `tokens`; a shorter sequence is
+/// one shingle.
+pub fn shingles(tokens: &[String], size: usize) -> FxHashSet<String> {
+    let size = size.max(1);
+    if tokens.len() <= size {
+        return std::iter::once(tokens.join(" ")).collect();
+    }
+    tokens.windows(size).map(|w| w.join(" ")).collect()
+}
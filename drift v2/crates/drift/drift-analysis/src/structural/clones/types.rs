@@ -0,0 +1,173 @@
+//! Clone detection types — fragments, clone classes and duplication.
+
+use serde::{Deserialize, Serialize};
+
+use crate::scanner::language_detect::Language;
+
+/// Tuning for clone detection.
+#[derive(Debug, Clone)]
+pub struct CloneConfig {
+    /// Functions with fewer normalized tokens are ignored.
+    pub min_tokens: usize,
+    /// Blocks inside functions with at least this many tokens are compared
+    /// on their own.
+    pub min_block_tokens: usize,
+    /// Tokens per shingle.
+    pub shingle_size: usize,
+    /// Minimum shingle Jaccard similarity for a Type-3 clone.
+    pub similarity_threshold: f64,
+    /// Compare fragments across languages (normally pointless: the token
+    /// vocabularies differ).
+    pub cross_language: bool,
+    /// MinHash permutations; must be divisible by `num_bands`.
+    pub num_perm: usize,
+    pub num_bands: usize,
+}
+
+impl Default for CloneConfig {
+    fn default() -> Self {
+        Self {
+            min_tokens: 50,
+            min_block_tokens: 80,
+            shingle_size: 5,
+            similarity_threshold: 0.7,
+            cross_language: false,
+            num_perm: 128,
+            num_bands: 32,
+        }
+    }
+}
+
+/// Clone type, from exact copies to edited copies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum CloneType {
+    /// Identical apart from whitespace and comments.
+    Type1,
+    /// Identical after abstracting identifiers and literals.
+    Type2,
+    /// Similar token sequences with statements added, removed or changed.
+    Type3,
+}
+
+impl CloneType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Type1 => "type-1",
+            Self::Type2 => "type-2",
+            Self::Type3 => "type-3",
+        }
+    }
+}
+
+/// Whether a fragment is a whole function or a block inside one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FragmentKind {
+    Function,
+    Block,
+}
+
+/// A unit of code compared for cloning.
+#[derive(Debug, Clone)]
+pub struct CloneFragment {
+    pub file: String,
+    /// Enclosing function name.
+    pub name: String,
+    pub kind: FragmentKind,
+    pub language: Language,
+    pub line: u32,
+    pub end_line: u32,
+    /// Hash of the token sequence as written.
+    pub raw_hash: u64,
+    /// Hash of the token sequence with identifiers and literals abstracted.
+    pub normalized_hash: u64,
+    /// Normalized tokens.
+    pub tokens: Vec<String>,
+}
+
+impl CloneFragment {
+    pub fn lines(&self) -> u32 {
+        self.end_line.saturating_sub(self.line) + 1
+    }
+
+    /// Whether `other` lies within this fragment.
+    pub fn contains(&self, other: &CloneFragment) -> bool {
+        self.file == other.file && self.line <= other.line && other.end_line <= self.end_line
+    }
+
+    pub(crate) fn overlaps(&self, other: &CloneFragment) -> bool {
+        self.file == other.file && self.line <= other.end_line && other.line <= self.end_line
+    }
+}
+
+/// One member of a clone class.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloneInstance {
+    pub file: String,
+    pub name: String,
+    pub kind: FragmentKind,
+    pub line: u32,
+    pub end_line: u32,
+}
+
+/// Fragments that are clones of one another.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloneClass {
+    pub id: u32,
+    /// The loosest clone relation within the class.
+    pub clone_type: CloneType,
+    /// Lowest pairwise similarity within the class.
+    pub similarity: f64,
+    /// Members ordered by file and line.
+    pub instances: Vec<CloneInstance>,
+}
+
+impl CloneClass {
+    /// Lines that would go if the class were reduced to one instance.
+    pub fn redundant_lines(&self) -> u32 {
+        let total: u32 = self.instances.iter().map(|i| i.end_line - i.line + 1).sum();
+        let largest = self.instances.iter().map(|i| i.end_line - i.line + 1).max().unwrap_or(0);
+        total - largest
+    }
+}
+
+/// Duplicated lines in one file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileDuplication {
+    pub file: String,
+    pub total_lines: u32,
+    pub duplicated_lines: u32,
+}
+
+/// Share of duplicated lines in one module (directory).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleDuplication {
+    pub module: String,
+    pub total_lines: u32,
+    pub duplicated_lines: u32,
+    /// Duplicated lines as a percentage of total lines (0-100).
+    pub percentage: f64,
+}
+
+/// Everything clone detection produces.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CloneReport {
+    /// Largest classes first.
+    pub classes: Vec<CloneClass>,
+    /// Files containing clones, by path.
+    pub files: Vec<FileDuplication>,
+    /// Every analyzed module, most duplicated first.
+    pub modules: Vec<ModuleDuplication>,
+    pub total_lines: u32,
+    pub duplicated_lines: u32,
+}
+
+impl CloneReport {
+    /// Duplicated lines as a percentage of all analyzed lines (0-100).
+    pub fn duplication_percentage(&self) -> f64 {
+        if self.total_lines == 0 {
+            0.0
+        } else {
+            self.duplicated_lines as f64 / self.total_lines as f64 * 100.0
+        }
+    }
+}
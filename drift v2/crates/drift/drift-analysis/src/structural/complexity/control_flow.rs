@@ -0,0 +1,276 @@
+//! Cyclomatic complexity, cognitive complexity and nesting depth from the
+//! tree-sitter tree of one function.
+//!
+//! Node kinds are matched across the supported grammars. Cognitive
+//! complexity follows the Sonar rules: +1 for each break in linear flow
+//! (`if`, `else if`, `else`, loops, `switch`, `catch`, ternaries, labelled
+//! jumps, recursion, each run of like boolean operators), plus the current
+//! nesting level for the structures that nest. Nested lambdas and
+//! functions raise the nesting level without an increment of their own.
+
+use tree_sitter::Node;
+
+const IF_KINDS: &[&str] = &["if_statement", "if_expression", "if", "unless", "if_modifier", "unless_modifier"];
+const ELSE_IF_KINDS: &[&str] = &["elif_clause", "elsif", "else_if_clause"];
+const ELSE_KINDS: &[&str] = &["else_clause", "else"];
+const LOOP_KINDS: &[&str] = &[
+    "for_statement",
+    "for_in_statement",
+    "enhanced_for_statement",
+    "foreach_statement",
+    "for_each_statement",
+    "for_range_loop",
+    "while_statement",
+    "do_statement",
+    "do_while_statement",
+    "for_expression",
+    "while_expression",
+    "loop_expression",
+    "for",
+    "while",
+    "until",
+    "while_modifier",
+    "until_modifier",
+];
+const SWITCH_KINDS: &[&str] = &[
+    "switch_statement",
+    "switch_expression",
+    "expression_switch_statement",
+    "type_switch_statement",
+    "select_statement",
+    "match_expression",
+    "match_statement",
+    "when_expression",
+    "case",
+    "case_match",
+];
+const CASE_KINDS: &[&str] = &[
+    "switch_case",
+    "switch_label",
+    "switch_section",
+    "case_clause",
+    "case_statement",
+    "expression_case",
+    "type_case",
+    "communication_case",
+    "match_arm",
+    "when",
+    "when_entry",
+    "in_clause",
+];
+const CATCH_KINDS: &[&str] = &["catch_clause", "except_clause", "rescue", "rescue_modifier", "catch_block"];
+const TERNARY_KINDS: &[&str] = &["ternary_expression", "conditional_expression", "conditional"];
+const FUNCTION_KINDS: &[&str] = &[
+    "arrow_function",
+    "function_expression",
+    "function",
+    "function_declaration",
+    "function_definition",
+    "function_item",
+    "method_definition",
+    "method_declaration",
+    "lambda",
+    "lambda_expression",
+    "lambda_literal",
+    "closure_expression",
+    "func_literal",
+    "anonymous_function",
+    "anonymous_function_creation_expression",
+    "do_block",
+];
+const JUMP_KINDS: &[&str] = &["break_statement", "continue_statement"];
+const CALL_KINDS: &[&str] = &[
+    "call_expression",
+    "call",
+    "method_invocation",
+    "invocation_expression",
+    "function_call_expression",
+    "member_call_expression",
+    "scoped_call_expression",
+];
+const LOGICAL_OPERATORS: &[&str] = &["&&", "||", "and", "or"];
+
+/// Control-flow metrics of one function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlFlowMetrics {
+    pub cyclomatic: u32,
+    pub cognitive: u32,
+    pub max_nesting: u32,
+}
+
+/// Measure the function rooted at `function`. `name` is used to spot
+/// recursive calls.
+pub fn measure(function: Node, source: &[u8], name: &str) -> ControlFlowMetrics {
+    let mut walker = Walker {
+        source,
+        name: short_name(name),
+        cyclomatic: 1,
+        cognitive: 0,
+        max_nesting: 0,
+    };
+    walker.children(function, 0);
+    ControlFlowMetrics {
+        cyclomatic: walker.cyclomatic,
+        cognitive: walker.cognitive,
+        max_nesting: walker.max_nesting,
+    }
+}
+
+struct Walker<'a> {
+    source: &'a [u8],
+    name: &'a str,
+    cyclomatic: u32,
+    cognitive: u32,
+    max_nesting: u32,
+}
+
+impl Walker<'_> {
+    fn walk(&mut self, node: Node, nesting: u32) {
+        if !node.is_named() {
+            return;
+        }
+        let kind = node.kind();
+        if IF_KINDS.contains(&kind) {
+            self.conditional(node, nesting, false);
+        } else if ELSE_IF_KINDS.contains(&kind) {
+            self.conditional(node, nesting, true);
+        } else if LOOP_KINDS.contains(&kind) || CATCH_KINDS.contains(&kind) {
+            self.cyclomatic += 1;
+            self.nested(node, nesting);
+        } else if SWITCH_KINDS.contains(&kind) {
+            // Cases add paths; the switch itself is one break in flow.
+            self.nested(node, nesting);
+        } else if TERNARY_KINDS.contains(&kind) {
+            self.cyclomatic += 1;
+            self.cognitive += 1 + nesting;
+            self.children(node, nesting + 1);
+        } else if FUNCTION_KINDS.contains(&kind) {
+            self.children(node, nesting + 1);
+        } else {
+            if CASE_KINDS.contains(&kind) && !self.is_default_arm(node) {
+                self.cyclomatic += 1;
+            } else if kind == "goto_statement" || (JUMP_KINDS.contains(&kind) && node.named_child_count() > 0) {
+                self.cognitive += 1;
+            } else if let Some(op) = logical_operator(node) {
+                self.cyclomatic += 1;
+                // `a && b && c` is one sequence; a change of operator starts another.
+                if enclosing_logical_operator(node) != Some(op) {
+                    self.cognitive += 1;
+                }
+            } else if CALL_KINDS.contains(&kind) && self.is_recursive_call(node) {
+                self.cognitive += 1;
+            }
+            self.children(node, nesting);
+        }
+    }
+
+    fn children(&mut self, node: Node, nesting: u32) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(child, nesting);
+        }
+    }
+
+    /// A structure that adds 1 + nesting and nests its body.
+    fn nested(&mut self, node: Node, nesting: u32) {
+        self.cognitive += 1 + nesting;
+        self.max_nesting = self.max_nesting.max(nesting + 1);
+        self.children(node, nesting + 1);
+    }
+
+    /// `if` / `else if`: else-if chains stay at the nesting of the first `if`.
+    fn conditional(&mut self, node: Node, nesting: u32, else_if: bool) {
+        self.cyclomatic += 1;
+        self.cognitive += if else_if { 1 } else { 1 + nesting };
+        self.max_nesting = self.max_nesting.max(nesting + 1);
+
+        let mut cursor = node.walk();
+        if !cursor.goto_first_child() {
+            return;
+        }
+        loop {
+            let child = cursor.node();
+            let kind = child.kind();
+            let is_alternative = cursor.field_name() == Some("alternative")
+                || ELSE_KINDS.contains(&kind)
+                || ELSE_IF_KINDS.contains(&kind);
+            if is_alternative {
+                self.alternative(child, nesting);
+            } else {
+                self.walk(child, nesting + 1);
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    fn alternative(&mut self, node: Node, nesting: u32) {
+        if !node.is_named() {
+            return;
+        }
+        let kind = node.kind();
+        if IF_KINDS.contains(&kind) || ELSE_IF_KINDS.contains(&kind) {
+            return self.conditional(node, nesting, true);
+        }
+        if ELSE_KINDS.contains(&kind) {
+            let mut cursor = node.walk();
+            let named: Vec<Node> = node.named_children(&mut cursor).filter(|c| !is_comment(c)).collect();
+            if let [only] = named.as_slice() {
+                if IF_KINDS.contains(&only.kind()) {
+                    return self.conditional(*only, nesting, true);
+                }
+            }
+        }
+        // Plain `else` (an else clause, or a bare block in Go/Java/C#).
+        self.cognitive += 1;
+        self.children(node, nesting + 1);
+    }
+
+    fn is_default_arm(&self, node: Node) -> bool {
+        let text = node.utf8_text(self.source).unwrap_or("").trim_start();
+        text.starts_with("default")
+            || text.starts_with("else")
+            || text.strip_prefix('_').is_some_and(|rest| rest.trim_start().starts_with("=>"))
+    }
+
+    fn is_recursive_call(&self, node: Node) -> bool {
+        if self.name.is_empty() {
+            return false;
+        }
+        ["function", "method", "name"]
+            .iter()
+            .find_map(|field| node.child_by_field_name(field))
+            .and_then(|callee| callee.utf8_text(self.source).ok())
+            .is_some_and(|callee| short_name(callee) == self.name)
+    }
+}
+
+/// The boolean operator of a binary node, if it is one.
+fn logical_operator(node: Node) -> Option<&'static str> {
+    let mut cursor = node.walk();
+    let op = node
+        .children(&mut cursor)
+        .filter(|c| !c.is_named())
+        .find_map(|c| LOGICAL_OPERATORS.iter().find(|op| **op == c.kind()).copied());
+    op
+}
+
+/// Operator of the boolean expression `node` is an operand of, looking
+/// through parentheses.
+fn enclosing_logical_operator(node: Node) -> Option<&'static str> {
+    let mut parent = node.parent()?;
+    while parent.kind() == "parenthesized_expression" {
+        parent = parent.parent()?;
+    }
+    logical_operator(parent)
+}
+
+fn is_comment(node: &Node) -> bool {
+    node.kind().contains("comment")
+}
+
+/// `self.process` / `Foo::process` / `$this->process` → `process`.
+fn short_name(name: &str) -> &str {
+    name.rsplit(['.', ':', '>']).next().unwrap_or(name).trim()
+}
@@ -0,0 +1,62 @@
+//! Halstead measures and the maintainability index.
+//!
+//! Operands are identifiers and literals (named leaves, with string
+//! literals taken whole); operators are the remaining tokens — keywords,
+//! operators and opening brackets. Closing brackets and separators only
+//! delimit their opener and are not counted.
+
+use drift_core::types::collections::FxHashMap;
+use tree_sitter::Node;
+
+use super::types::HalsteadMetrics;
+
+const IGNORED_TOKENS: &[&str] = &[")", "]", "}", ",", ";"];
+
+/// Halstead measures of the tokens under `function`.
+pub fn measure(function: Node, source: &[u8]) -> HalsteadMetrics {
+    let mut operators: FxHashMap<&str, u32> = FxHashMap::default();
+    let mut operands: FxHashMap<&[u8], u32> = FxHashMap::default();
+    collect(function, source, &mut operators, &mut operands);
+    HalsteadMetrics::from_counts(
+        operators.len() as u32,
+        operands.len() as u32,
+        operators.values().sum(),
+        operands.values().sum(),
+    )
+}
+
+fn collect<'a>(
+    node: Node<'a>,
+    source: &'a [u8],
+    operators: &mut FxHashMap<&'a str, u32>,
+    operands: &mut FxHashMap<&'a [u8], u32>,
+) {
+    let kind = node.kind();
+    if kind.contains("comment") {
+        return;
+    }
+    let is_string = node.is_named() && (kind.contains("string") || kind == "char_literal" || kind == "heredoc_body");
+    if is_string || (node.child_count() == 0 && node.is_named()) {
+        *operands.entry(&source[node.byte_range()]).or_default() += 1;
+        return;
+    }
+    if node.child_count() == 0 {
+        if !IGNORED_TOKENS.contains(&kind) {
+            *operators.entry(kind).or_default() += 1;
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(child, source, operators, operands);
+    }
+}
+
+/// Maintainability index normalized to 0–100:
+/// `max(0, (171 − 5.2·ln V − 0.23·CC − 16.2·ln LOC) × 100 / 171)`.
+pub fn maintainability_index(volume: f64, cyclomatic: u32, loc: u32) -> f64 {
+    let ln_volume = if volume > 0.0 { volume.ln() } else { 0.0 };
+    let ln_loc = (loc.max(1) as f64).ln();
+    let raw = 171.0 - 5.2 * ln_volume - 0.23 * cyclomatic as f64 - 16.2 * ln_loc;
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+}
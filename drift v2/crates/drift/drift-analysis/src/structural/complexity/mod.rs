@@ -0,0 +1,60 @@
+//! Complexity Metrics — per-function cyclomatic, cognitive (Sonar-style),
+//! nesting depth, Halstead measures and maintainability index.
+//!
+//! Computed from the tree-sitter tree of each parsed function, keyed by
+//! file and start line so constraints, impact analysis and simulation can
+//! look them up.
+
+pub mod types;
+pub mod control_flow;
+pub mod halstead;
+
+pub use types::*;
+
+use drift_core::types::collections::FxHashSet;
+use tree_sitter::{Node, Point, Tree};
+
+use crate::parsers::types::{FunctionInfo, ParseResult};
+
+/// Metrics for every function and method in a parsed file.
+pub fn analyze_file(parse_result: &ParseResult, tree: &Tree, source: &[u8]) -> Vec<FunctionComplexity> {
+    let root = tree.root_node();
+    let mut seen: FxHashSet<(u32, &str)> = FxHashSet::default();
+    parse_result
+        .functions
+        .iter()
+        .chain(parse_result.classes.iter().flat_map(|c| c.methods.iter()))
+        .filter(|f| seen.insert((f.line, f.name.as_str())))
+        .filter_map(|f| {
+            let node = function_node(root, f)?;
+            Some(analyze_function(&parse_result.file, f, node, source))
+        })
+        .collect()
+}
+
+/// Metrics for one function, given its tree-sitter node.
+pub fn analyze_function(file: &str, function: &FunctionInfo, node: Node, source: &[u8]) -> FunctionComplexity {
+    let flow = control_flow::measure(node, source, &function.name);
+    let halstead = halstead::measure(node, source);
+    let loc = function.end_line.saturating_sub(function.line) + 1;
+    FunctionComplexity {
+        file: file.to_string(),
+        name: function.name.clone(),
+        qualified_name: function.qualified_name.clone(),
+        line: function.line,
+        end_line: function.end_line,
+        cyclomatic: flow.cyclomatic,
+        cognitive: flow.cognitive,
+        max_nesting: flow.max_nesting,
+        loc,
+        halstead,
+        maintainability_index: halstead::maintainability_index(halstead.volume, flow.cyclomatic, loc),
+    }
+}
+
+/// The smallest node covering the function's range — the function node itself.
+pub(crate) fn function_node<'t>(root: Node<'t>, function: &FunctionInfo) -> Option<Node<'t>> {
+    let start = Point::new(function.range.start.line as usize, function.range.start.column as usize);
+    let end = Point::new(function.range.end.line as usize, function.range.end.column as usize);
+    root.descendant_for_point_range(start, end)
+}
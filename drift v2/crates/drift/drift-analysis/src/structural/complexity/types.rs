@@ -0,0 +1,229 @@
+//! Complexity types — per-function metrics and lookups over them.
+
+use drift_core::types::collections::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// Halstead software science measures over a function's tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct HalsteadMetrics {
+    /// n1: distinct operators.
+    pub distinct_operators: u32,
+    /// n2: distinct operands.
+    pub distinct_operands: u32,
+    /// N1: total operators.
+    pub total_operators: u32,
+    /// N2: total operands.
+    pub total_operands: u32,
+    /// V = N × log2(n).
+    pub volume: f64,
+    /// D = (n1 / 2) × (N2 / n2).
+    pub difficulty: f64,
+    /// E = D × V.
+    pub effort: f64,
+    /// B = V / 3000.
+    pub estimated_bugs: f64,
+}
+
+impl HalsteadMetrics {
+    /// Derive the measures from operator and operand counts.
+    pub fn from_counts(n1: u32, n2: u32, big_n1: u32, big_n2: u32) -> Self {
+        let vocabulary = (n1 + n2) as f64;
+        let length = (big_n1 + big_n2) as f64;
+        let volume = if vocabulary > 1.0 { length * vocabulary.log2() } else { 0.0 };
+        let difficulty = if n2 > 0 { (n1 as f64 / 2.0) * (big_n2 as f64 / n2 as f64) } else { 0.0 };
+        Self {
+            distinct_operators: n1,
+            distinct_operands: n2,
+            total_operators: big_n1,
+            total_operands: big_n2,
+            volume,
+            difficulty,
+            effort: difficulty * volume,
+            estimated_bugs: volume / 3000.0,
+        }
+    }
+
+    /// n = n1 + n2.
+    pub fn vocabulary(&self) -> u32 {
+        self.distinct_operators + self.distinct_operands
+    }
+
+    /// N = N1 + N2.
+    pub fn length(&self) -> u32 {
+        self.total_operators + self.total_operands
+    }
+}
+
+/// Complexity metrics for one function.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionComplexity {
+    pub file: String,
+    pub name: String,
+    pub qualified_name: Option<String>,
+    /// 0-based start line, as in `FunctionInfo`.
+    pub line: u32,
+    pub end_line: u32,
+    /// McCabe cyclomatic complexity: 1 + decision points.
+    pub cyclomatic: u32,
+    /// Sonar-style cognitive complexity with nesting penalties.
+    pub cognitive: u32,
+    /// Deepest nesting of control structures.
+    pub max_nesting: u32,
+    /// Lines spanned by the function.
+    pub loc: u32,
+    pub halstead: HalsteadMetrics,
+    /// Maintainability index normalized to 0–100 (higher is better).
+    pub maintainability_index: f64,
+}
+
+impl FunctionComplexity {
+    /// Risk contribution in [0.0, 1.0]: cyclomatic saturates at 20,
+    /// cognitive at 30, cognitive weighted higher.
+    pub fn risk_factor(&self) -> f32 {
+        let cyclomatic = (self.cyclomatic as f32 / 20.0).min(1.0);
+        let cognitive = (self.cognitive as f32 / 30.0).min(1.0);
+        cyclomatic * 0.4 + cognitive * 0.6
+    }
+}
+
+/// Which metric a complexity limit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplexityMetric {
+    Cyclomatic,
+    Cognitive,
+    Nesting,
+    /// A lower bound — functions must stay at or above it.
+    Maintainability,
+}
+
+impl ComplexityMetric {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cyclomatic => "cyclomatic",
+            Self::Cognitive => "cognitive",
+            Self::Nesting => "nesting",
+            Self::Maintainability => "maintainability",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cyclomatic" | "cc" => Some(Self::Cyclomatic),
+            "cognitive" => Some(Self::Cognitive),
+            "nesting" | "depth" => Some(Self::Nesting),
+            "maintainability" | "mi" => Some(Self::Maintainability),
+            _ => None,
+        }
+    }
+
+    /// The metric's value for `f`.
+    pub fn value(&self, f: &FunctionComplexity) -> f64 {
+        match self {
+            Self::Cyclomatic => f.cyclomatic as f64,
+            Self::Cognitive => f.cognitive as f64,
+            Self::Nesting => f.max_nesting as f64,
+            Self::Maintainability => f.maintainability_index,
+        }
+    }
+
+    /// Whether `value` breaks `limit`.
+    pub fn exceeds(&self, value: f64, limit: f64) -> bool {
+        match self {
+            Self::Maintainability => value < limit,
+            _ => value > limit,
+        }
+    }
+}
+
+/// Averages over a set of functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComplexitySummary {
+    pub function_count: u32,
+    pub avg_cyclomatic: f64,
+    pub avg_cognitive: f64,
+    pub max_cognitive: u32,
+    pub avg_maintainability: f64,
+}
+
+impl ComplexitySummary {
+    pub fn from_functions<'a>(functions: impl IntoIterator<Item = &'a FunctionComplexity>) -> Self {
+        Self::from_measures(functions.into_iter().map(|f| (f.cyclomatic, f.cognitive, f.maintainability_index)))
+    }
+
+    /// Summarize `(cyclomatic, cognitive, maintainability index)` triples,
+    /// e.g. as read back from storage.
+    pub fn from_measures(measures: impl IntoIterator<Item = (u32, u32, f64)>) -> Self {
+        let mut summary = Self::default();
+        let (mut cyclomatic, mut cognitive, mut mi) = (0u64, 0u64, 0.0);
+        for (cc, cog, index) in measures {
+            summary.function_count += 1;
+            cyclomatic += cc as u64;
+            cognitive += cog as u64;
+            mi += index;
+            summary.max_cognitive = summary.max_cognitive.max(cog);
+        }
+        if summary.function_count > 0 {
+            let n = summary.function_count as f64;
+            summary.avg_cyclomatic = cyclomatic as f64 / n;
+            summary.avg_cognitive = cognitive as f64 / n;
+            summary.avg_maintainability = mi / n;
+        }
+        summary
+    }
+}
+
+/// Function metrics looked up by file and start line — the key shared by
+/// `FunctionInfo` and call graph nodes.
+#[derive(Debug, Clone, Default)]
+pub struct ComplexityIndex {
+    by_file: FxHashMap<String, Vec<FunctionComplexity>>,
+}
+
+impl ComplexityIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_functions(functions: impl IntoIterator<Item = FunctionComplexity>) -> Self {
+        let mut index = Self::new();
+        for f in functions {
+            index.insert(f);
+        }
+        index
+    }
+
+    pub fn insert(&mut self, function: FunctionComplexity) {
+        self.by_file.entry(function.file.clone()).or_default().push(function);
+    }
+
+    /// Metrics of the function starting at `line` in `file`.
+    pub fn get(&self, file: &str, line: u32) -> Option<&FunctionComplexity> {
+        self.file_functions(file).iter().find(|f| f.line == line)
+    }
+
+    pub fn file_functions(&self, file: &str) -> &[FunctionComplexity] {
+        self.by_file.get(file).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn has_file(&self, file: &str) -> bool {
+        self.by_file.contains_key(file)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FunctionComplexity> {
+        self.by_file.values().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_file.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_file.is_empty()
+    }
+
+    /// Summary over the functions of `files`.
+    pub fn summarize(&self, files: &[String]) -> ComplexitySummary {
+        ComplexitySummary::from_functions(files.iter().flat_map(|f| self.file_functions(f)))
+    }
+}
@@ -2,8 +2,12 @@
 
 use drift_core::types::collections::FxHashMap;
 
+use crate::structural::complexity::{ComplexityIndex, ComplexityMetric, FunctionComplexity};
+
 use super::types::{Constraint, ConstraintViolation, InvariantType, VerificationResult};
 
+const DEFAULT_COMPLEXITY_LIMIT: f64 = 20.0;
+
 /// Detects invariant violations using AST-based analysis.
 ///
 /// Unlike v1's regex approach, this uses parsed function/class/import data
@@ -15,6 +19,8 @@ pub struct InvariantDetector {
     imports: FxHashMap<String, Vec<String>>,
     /// File sizes (line counts).
     file_sizes: FxHashMap<String, u32>,
+    /// Per-function complexity metrics.
+    complexity: ComplexityIndex,
 }
 
 /// Minimal function info for constraint checking.
@@ -31,6 +37,7 @@ impl InvariantDetector {
             functions: FxHashMap::default(),
             imports: FxHashMap::default(),
             file_sizes: FxHashMap::default(),
+            complexity: ComplexityIndex::new(),
         }
     }
 
@@ -47,6 +54,13 @@ impl InvariantDetector {
         self.file_sizes.insert(file.to_string(), line_count);
     }
 
+    /// Register computed complexity metrics for functions.
+    pub fn add_complexity(&mut self, functions: impl IntoIterator<Item = FunctionComplexity>) {
+        for f in functions {
+            self.complexity.insert(f);
+        }
+    }
+
     /// Verify a constraint against the registered codebase data.
     pub fn verify(&self, constraint: &Constraint) -> VerificationResult {
         if !constraint.enabled {
@@ -278,15 +292,40 @@ impl InvariantDetector {
         violations
     }
 
+    /// Target: `<limit>` (cyclomatic) or `<metric>:<limit>` with metric one of
+    /// `cyclomatic`, `cognitive`, `nesting` or `maintainability` (a minimum).
+    /// Files without computed metrics fall back to function count per file.
     fn check_complexity_limit(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
-        // Simplified: check function count per file as a proxy for complexity
-        let limit: usize = constraint.target.parse().unwrap_or(20);
+        let (metric, limit) = match constraint.target.split_once(':') {
+            Some((metric, limit)) => (
+                ComplexityMetric::parse(metric).unwrap_or(ComplexityMetric::Cyclomatic),
+                limit.trim().parse().unwrap_or(DEFAULT_COMPLEXITY_LIMIT),
+            ),
+            None => (ComplexityMetric::Cyclomatic, constraint.target.trim().parse().unwrap_or(DEFAULT_COMPLEXITY_LIMIT)),
+        };
         let mut violations = Vec::new();
 
         let files = self.scoped_files(constraint.scope.as_deref());
         for file in files {
-            if let Some(fns) = self.functions.get(file) {
-                if fns.len() > limit {
+            if self.complexity.has_file(file) {
+                for f in self.complexity.file_functions(file) {
+                    let value = metric.value(f);
+                    if metric.exceeds(value, limit) {
+                        let bound = if metric == ComplexityMetric::Maintainability { ">=" } else { "<=" };
+                        violations.push(ConstraintViolation {
+                            file: file.to_string(),
+                            line: Some(f.line),
+                            message: format!(
+                                "Function '{}' has {} complexity {} (limit {})",
+                                f.name, metric.name(), format_metric(value), format_metric(limit)
+                            ),
+                            expected: format!("{} {} {}", metric.name(), bound, format_metric(limit)),
+                            actual: format!("{} {}", metric.name(), format_metric(value)),
+                        });
+                    }
+                }
+            } else if let Some(fns) = self.functions.get(file) {
+                if fns.len() as f64 > limit {
                     violations.push(ConstraintViolation {
                         file: file.to_string(),
                         line: None,
@@ -376,6 +415,15 @@ impl InvariantDetector {
     }
 }
 
+/// Whole numbers without a fraction, others to one decimal.
+fn format_metric(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
 impl Default for InvariantDetector {
     fn default() -> Self {
         Self::new()
@@ -46,7 +46,7 @@ pub enum InvariantType {
     LayerBoundary,
     /// Module/file/function must not exceed a size limit.
     SizeLimit,
-    /// Cyclomatic complexity must not exceed a threshold.
+    /// Function complexity (cyclomatic by default) must not exceed a threshold.
     ComplexityLimit,
 }
 
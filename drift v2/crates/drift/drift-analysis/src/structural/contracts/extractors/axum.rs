@@ -0,0 +1,197 @@
+//! Axum (Rust) endpoint extractor: `Router::route` chains, `nest` prefixes and
+//! handler extractors (`Json<T>`, `Query<T>`, `Path<T>`) resolved against
+//! serde structs in the same file.
+
+use super::express::extract_string_arg;
+use super::models::{self, Models};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct AxumExtractor;
+
+const METHOD_ROUTERS: &[&str] = &["get", "post", "put", "delete", "patch", "head", "options", "any"];
+
+impl EndpointExtractor for AxumExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        let mut endpoints = Vec::new();
+        let models = models::rust_structs(content);
+        let nests = collect_nests(content);
+
+        let mut from = 0;
+        while let Some(pos) = content[from..].find(".route(") {
+            let open = from + pos + ".route".len();
+            from = open;
+            let Some(close) = models::matching_close(content, open) else { continue };
+            let args = &content[open..=close];
+            let Some(path) = extract_string_arg(args, 1) else { continue };
+            let prefix = nest_prefix(&nests, open);
+            let path = models::join_path(&prefix, &path);
+            let line = models::line_of(content, open);
+
+            for (method, handler) in method_handlers(args) {
+                let (request_fields, response_fields) = handler_fields(content, &handler, &models);
+                endpoints.push(Endpoint {
+                    method,
+                    path: path.clone(),
+                    request_fields,
+                    response_fields,
+                    file: file_path.to_string(),
+                    line,
+                });
+            }
+            from = close;
+        }
+        endpoints
+    }
+
+    fn framework(&self) -> &str { "axum" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("axum") && content.contains(".route(")
+    }
+}
+
+/// `get(list).post(create)` → `[("GET", "list"), ("POST", "create")]`.
+fn method_handlers(args: &str) -> Vec<(String, String)> {
+    let mut handlers = Vec::new();
+    for method in METHOD_ROUTERS {
+        let mut from = 0;
+        let pattern = format!("{method}(");
+        while let Some(pos) = args[from..].find(&pattern) {
+            let at = from + pos;
+            from = at + pattern.len();
+            let preceded_ok = at == 0 || {
+                let prev = args.as_bytes()[at - 1];
+                !(prev.is_ascii_alphanumeric() || prev == b'_')
+            };
+            if !preceded_ok {
+                continue;
+            }
+            let handler: String = args[from..]
+                .trim_start()
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == ':')
+                .collect();
+            let method = if *method == "any" { "ANY".to_string() } else { method.to_uppercase() };
+            handlers.push((method, handler));
+        }
+    }
+    handlers
+}
+
+/// `.nest("/api", api_routes())` → routes built inside `fn api_routes` get `/api`.
+fn collect_nests(content: &str) -> Vec<(String, usize, usize)> {
+    let mut nests = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find(".nest(") {
+        let open = from + pos + ".nest".len();
+        from = open;
+        let Some(close) = models::matching_close(content, open) else { continue };
+        let args = &content[open..=close];
+        let Some(prefix) = extract_string_arg(args, 1) else { continue };
+        let target: String = args
+            .split_once(',')
+            .map(|(_, t)| t.trim_start())
+            .unwrap_or("")
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == ':')
+            .collect();
+        if let Some(start) = models::find_function(content, &["fn "], &target) {
+            if let Some((body_start, body_end)) = models::block_after(content, start) {
+                nests.push((prefix, body_start, body_end));
+            }
+        }
+    }
+    nests
+}
+
+fn nest_prefix(nests: &[(String, usize, usize)], at: usize) -> String {
+    nests
+        .iter()
+        .filter(|(_, start, end)| *start <= at && at <= *end)
+        .map(|(prefix, _, _)| prefix.as_str())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn handler_fields(content: &str, handler: &str, models: &Models) -> (Vec<FieldSpec>, Vec<FieldSpec>) {
+    let Some(start) = models::find_function(content, &["fn "], handler) else {
+        return (vec![], vec![]);
+    };
+    let Some(open) = content[start..].find('(').map(|p| start + p) else { return (vec![], vec![]) };
+    let Some(close) = models::matching_close(content, open) else { return (vec![], vec![]) };
+
+    let mut request = Vec::new();
+    for param in models::split_top_level(&content[open + 1..close], ',') {
+        let Some((pattern, ty)) = param.split_once(':') else { continue };
+        let ty = ty.trim();
+        if let Some(inner) = generic_arg(ty, "Json").or_else(|| generic_arg(ty, "Query")).or_else(|| generic_arg(ty, "Form")) {
+            request.extend(models::fields_for_type(models, inner));
+        } else if let Some(inner) = generic_arg(ty, "Path") {
+            // `Path(id): Path<u64>` / `Path((a, b)): Path<(u32, String)>`.
+            let names: Vec<&str> = pattern
+                .trim()
+                .trim_start_matches("Path(")
+                .trim_end_matches(')')
+                .trim_matches(|c| c == '(' || c == ')')
+                .split(',')
+                .map(str::trim)
+                .collect();
+            if models.contains_key(inner) {
+                request.extend(models::fields_for_type(models, inner));
+                continue;
+            }
+            let types: Vec<&str> = inner
+                .trim_matches(|c| c == '(' || c == ')')
+                .split(',')
+                .map(str::trim)
+                .collect();
+            for (name, ty) in names.iter().zip(types.iter()) {
+                if !name.is_empty() {
+                    request.push(FieldSpec {
+                        name: name.to_string(),
+                        field_type: ty.to_string(),
+                        required: true,
+                        nullable: false,
+                    });
+                }
+            }
+        }
+    }
+
+    let signature_end = content[close..].find('{').map(|p| close + p).unwrap_or(close);
+    let ret = content[close + 1..signature_end]
+        .trim()
+        .strip_prefix("->")
+        .map(str::trim)
+        .unwrap_or("");
+    let response = response_type(ret)
+        .map(|t| models::fields_for_type(models, t))
+        .unwrap_or_default();
+    (request, response)
+}
+
+/// `Json<User>` / `Result<Json<User>, E>` / `(StatusCode, Json<User>)` → `User`.
+fn response_type(ret: &str) -> Option<&str> {
+    let ret = ret.trim();
+    if let Some(inner) = generic_arg(ret, "Result") {
+        let ok = models::split_top_level(inner, ',').into_iter().next()?;
+        return response_type(ok);
+    }
+    if ret.starts_with('(') {
+        return models::split_top_level(&ret[1..ret.len().saturating_sub(1)], ',')
+            .into_iter()
+            .find_map(response_type);
+    }
+    generic_arg(ret, "Json")
+}
+
+/// Inner type of `Wrapper<Inner>` (also `axum::Json<Inner>`).
+fn generic_arg<'a>(ty: &'a str, wrapper: &str) -> Option<&'a str> {
+    let ty = ty.trim();
+    let name_end = ty.find('<')?;
+    let name = ty[..name_end].rsplit("::").next()?;
+    if name != wrapper || !ty.ends_with('>') {
+        return None;
+    }
+    Some(ty[name_end + 1..ty.len() - 1].trim())
+}
@@ -0,0 +1,24 @@
+//! Chi (Go) endpoint extractor, including `r.Route` sub-router blocks.
+
+use super::go_router::{self, GoRouterStyle, TITLE_METHODS};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct ChiExtractor;
+
+const STYLE: GoRouterStyle = GoRouterStyle {
+    methods: TITLE_METHODS,
+    bind_calls: &[".Decode(", "render.Bind(r,", "render.DecodeJSON(r.Body,"],
+    respond_calls: &[".Encode(", "render.JSON("],
+};
+
+impl EndpointExtractor for ChiExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        go_router::extract(&STYLE, content, file_path)
+    }
+
+    fn framework(&self) -> &str { "chi" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("go-chi/chi")
+    }
+}
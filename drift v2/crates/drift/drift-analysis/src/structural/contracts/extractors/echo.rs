@@ -0,0 +1,27 @@
+//! Echo (Go) endpoint extractor.
+
+use super::go_router::{self, GoRouterStyle};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct EchoExtractor;
+
+const STYLE: GoRouterStyle = GoRouterStyle {
+    methods: &[
+        ("GET", "GET"), ("POST", "POST"), ("PUT", "PUT"), ("DELETE", "DELETE"),
+        ("PATCH", "PATCH"), ("HEAD", "HEAD"), ("OPTIONS", "OPTIONS"), ("Any", "ANY"),
+    ],
+    bind_calls: &[".Bind("],
+    respond_calls: &[".JSON(", ".JSONPretty("],
+};
+
+impl EndpointExtractor for EchoExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        go_router::extract(&STYLE, content, file_path)
+    }
+
+    fn framework(&self) -> &str { "echo" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("labstack/echo")
+    }
+}
@@ -86,11 +86,32 @@ impl EndpointExtractor for ExpressExtractor {
     fn framework(&self) -> &str { "express" }
 
     fn matches(&self, content: &str) -> bool {
+        // FastAPI and Flask decorators (`@app.get(`, `@router.get(`) contain
+        // the same call shape; Python sources are never express.
+        if is_python(content) {
+            return false;
+        }
+        // Koa and Hono share the `app.get(` / `router.get(` shape; leave those
+        // files to their own extractors unless express is imported too.
+        let other_router = content.contains("koa-router") || content.contains("@koa/router")
+            || content.contains("new Hono");
+        if other_router && !content.contains("express") {
+            return false;
+        }
         content.contains("express") || content.contains("app.get(") || content.contains("router.get(")
             || content.contains("server.get(") || content.contains("api.get(")
     }
 }
 
+/// `def`/`async def` or `from x import y` at the start of a line.
+fn is_python(content: &str) -> bool {
+    content.lines().any(|line| {
+        let t = line.trim_start();
+        t.starts_with("def ") || t.starts_with("async def ")
+            || (t.starts_with("from ") && t.contains(" import "))
+    })
+}
+
 /// CE-EXP-02: Accumulate successive lines until we find a string arg or hit a limit.
 fn accumulate_lines(lines: &[&str], start: usize, _offset: usize) -> String {
     let mut combined = lines[start].trim().to_string();
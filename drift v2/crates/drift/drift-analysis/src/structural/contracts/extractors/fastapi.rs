@@ -0,0 +1,226 @@
+//! FastAPI endpoint extractor (routes, path params and Pydantic models).
+
+use std::collections::HashMap;
+
+use super::express::extract_string_arg;
+use super::models::{self, Models};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct FastApiExtractor;
+
+const METHODS: &[&str] = &["get", "post", "put", "delete", "patch", "options", "head"];
+
+/// Parameter types FastAPI injects rather than reads from the request.
+const INJECTED_TYPES: &[&str] = &[
+    "Request", "Response", "BackgroundTasks", "WebSocket", "Session", "AsyncSession",
+    "HTTPConnection", "SecurityScopes",
+];
+
+impl EndpointExtractor for FastApiExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        let mut endpoints = Vec::new();
+        let models = models::python_models(content);
+        let prefixes = collect_router_prefixes(content);
+        let lines: Vec<&str> = content.lines().collect();
+
+        let mut i = 0;
+        while i < lines.len() {
+            let trimmed = lines[i].trim();
+            let Some(decorator) = trimmed.strip_prefix('@') else {
+                i += 1;
+                continue;
+            };
+            let Some((receiver, rest)) = decorator.split_once('.') else {
+                i += 1;
+                continue;
+            };
+            let method_name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+            let is_route = METHODS.contains(&method_name.as_str()) || method_name == "api_route";
+            if !is_route || !rest[method_name.len()..].starts_with('(') {
+                i += 1;
+                continue;
+            }
+
+            // Decorator arguments may span lines.
+            let (args, last) = accumulate_call(&lines, i);
+            let open = args.find('(').unwrap_or(0);
+            let Some(path) = extract_string_arg(&args, open + 1)
+                .or_else(|| models::keyword_string(&args, "path"))
+            else {
+                i += 1;
+                continue;
+            };
+            let path = models::join_path(prefixes.get(receiver).map(String::as_str).unwrap_or(""), &path);
+            let methods = if method_name == "api_route" {
+                list_arg(&args, "methods")
+            } else {
+                vec![method_name.to_uppercase()]
+            };
+
+            let signature = find_signature(&lines, last + 1);
+            let (request_fields, return_type) = match &signature {
+                Some(sig) => (request_fields(sig, &path, &models), return_annotation(sig)),
+                None => (vec![], None),
+            };
+            let response_type = expression_arg(&args, "response_model").or(return_type);
+            let response_fields = response_type
+                .filter(|t| !matches!(t.as_str(), "None" | "Response" | "JSONResponse" | "HTMLResponse" | "RedirectResponse"))
+                .map(|t| models::fields_for_type(&models, &t))
+                .unwrap_or_default();
+
+            for method in methods {
+                endpoints.push(Endpoint {
+                    method,
+                    path: path.clone(),
+                    request_fields: request_fields.clone(),
+                    response_fields: response_fields.clone(),
+                    file: file_path.to_string(),
+                    line: (i + 1) as u32,
+                });
+            }
+            i = last + 1;
+        }
+        endpoints
+    }
+
+    fn framework(&self) -> &str { "fastapi" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("fastapi") || content.contains("FastAPI(") || content.contains("APIRouter(")
+    }
+}
+
+/// `router = APIRouter(prefix="/users")` → `router` ↦ `/users`.
+fn collect_router_prefixes(content: &str) -> HashMap<String, String> {
+    let mut prefixes = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let Some((name, value)) = trimmed.split_once('=') else { continue };
+        if !value.contains("APIRouter(") && !value.contains("FastAPI(") {
+            continue;
+        }
+        if let Some(prefix) = models::keyword_string(value, "prefix").or_else(|| models::keyword_string(value, "root_path")) {
+            prefixes.insert(name.trim().to_string(), prefix);
+        }
+    }
+    prefixes
+}
+
+/// Join lines from `start` until parentheses balance. Returns the joined
+/// text and the index of the last line consumed.
+fn accumulate_call(lines: &[&str], start: usize) -> (String, usize) {
+    let mut text = String::new();
+    let mut depth = 0i32;
+    for (offset, line) in lines[start..].iter().enumerate().take(20) {
+        text.push_str(line.trim());
+        text.push(' ');
+        depth += line.matches('(').count() as i32 - line.matches(')').count() as i32;
+        if depth <= 0 {
+            return (text, start + offset);
+        }
+    }
+    (text, start)
+}
+
+/// The `def` following a decorator stack, joined into one string.
+fn find_signature(lines: &[&str], from: usize) -> Option<String> {
+    let mut i = from;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with('@') {
+            i = accumulate_call(lines, i).1 + 1;
+            continue;
+        }
+        if trimmed.starts_with("def ") || trimmed.starts_with("async def ") {
+            return Some(accumulate_call(lines, i).0);
+        }
+        if !trimmed.is_empty() && !trimmed.starts_with('#') {
+            return None;
+        }
+        i += 1;
+    }
+    None
+}
+
+fn request_fields(signature: &str, path: &str, models: &Models) -> Vec<FieldSpec> {
+    let Some(open) = signature.find('(') else { return vec![] };
+    let Some(close) = models::matching_close(signature, open) else { return vec![] };
+    let mut fields = Vec::new();
+    for param in models::split_top_level(&signature[open + 1..close], ',') {
+        let param = param.trim();
+        let Some((name, rest)) = param.split_once(':') else { continue };
+        let name = name.trim();
+        if name.starts_with('*') || name == "self" || name == "cls" {
+            continue;
+        }
+        let (ty, default) = match rest.split_once('=') {
+            Some((ty, default)) => (ty.trim(), Some(default.trim())),
+            None => (rest.trim(), None),
+        };
+        let injected = default.is_some_and(|d| {
+            ["Depends(", "Security(", "Header(", "Cookie("].iter().any(|p| d.starts_with(p))
+        }) || ty.contains("Depends(")
+            || INJECTED_TYPES.contains(&models::unwrap_type(ty).0);
+        if injected {
+            continue;
+        }
+        if models::is_model(models, ty) && !models::unwrap_type(ty).1 {
+            // Body model: its fields are the request.
+            fields.extend(models::fields_for_type(models, ty));
+            continue;
+        }
+        let in_path = path.contains(&format!("{{{name}}}")) || path.contains(&format!("{{{name}:"));
+        let explicit_required = default.is_some_and(|d| d.contains("(...") || d.starts_with("Path("));
+        fields.push(FieldSpec {
+            name: default
+                .and_then(|d| models::keyword_string(d, "alias"))
+                .unwrap_or_else(|| name.to_string()),
+            field_type: ty.to_string(),
+            required: in_path || default.is_none() || explicit_required,
+            nullable: models::unwrap_type(ty).2,
+        });
+    }
+    fields
+}
+
+fn return_annotation(signature: &str) -> Option<String> {
+    let arrow = signature.rfind("->")?;
+    let ty = signature[arrow + 2..].trim().trim_end_matches(':').trim();
+    (!ty.is_empty()).then(|| ty.to_string())
+}
+
+/// Expression value of a keyword argument: `response_model=list[User]`.
+fn expression_arg(args: &str, key: &str) -> Option<String> {
+    let at = args.find(&format!("{key}="))? + key.len() + 1;
+    let rest = &args[at..];
+    let mut depth = 0i32;
+    let end = rest
+        .char_indices()
+        .find(|(_, c)| {
+            match c {
+                '[' | '(' => depth += 1,
+                ']' | ')' if depth > 0 => depth -= 1,
+                ',' | ')' => return true,
+                _ => {}
+            }
+            false
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(rest.len());
+    let value = rest[..end].trim();
+    (!value.is_empty() && value != "None").then(|| value.to_string())
+}
+
+/// `methods=["GET", "POST"]`.
+fn list_arg(args: &str, key: &str) -> Vec<String> {
+    let Some(at) = args.find(&format!("{key}=")) else { return vec!["GET".to_string()] };
+    let rest = &args[at..];
+    let (Some(open), Some(close)) = (rest.find('['), rest.find(']')) else {
+        return vec!["GET".to_string()];
+    };
+    rest[open + 1..close]
+        .split(',')
+        .map(|m| m.trim().trim_matches(|c| c == '"' || c == '\'').to_uppercase())
+        .filter(|m| !m.is_empty())
+        .collect()
+}
@@ -0,0 +1,24 @@
+//! Fiber (Go) endpoint extractor.
+
+use super::go_router::{self, GoRouterStyle, TITLE_METHODS};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct FiberExtractor;
+
+const STYLE: GoRouterStyle = GoRouterStyle {
+    methods: TITLE_METHODS,
+    bind_calls: &[".BodyParser(", ".QueryParser("],
+    respond_calls: &[".JSON("],
+};
+
+impl EndpointExtractor for FiberExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        go_router::extract(&STYLE, content, file_path)
+    }
+
+    fn framework(&self) -> &str { "fiber" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("gofiber/fiber")
+    }
+}
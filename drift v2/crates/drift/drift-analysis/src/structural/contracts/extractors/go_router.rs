@@ -0,0 +1,226 @@
+//! Shared route scanning for Go routers (Echo, Chi, Fiber): `recv.Method("/p",
+//! handler)` calls, `Group` variables, `Route("/p", func(r ...) { ... })`
+//! blocks, and handler bodies resolved against Go structs for bind/response
+//! types.
+
+use std::collections::HashMap;
+
+use super::express::extract_string_arg;
+use super::models::{self, Models};
+use crate::structural::contracts::types::*;
+
+/// Framework-specific spelling.
+pub(crate) struct GoRouterStyle {
+    /// Method call names, e.g. `GET` (Echo) or `Get` (Chi/Fiber).
+    pub methods: &'static [(&'static str, &'static str)],
+    /// Calls that decode the request into `&var`.
+    pub bind_calls: &'static [&'static str],
+    /// Calls whose last argument is the response body.
+    pub respond_calls: &'static [&'static str],
+}
+
+pub(crate) const TITLE_METHODS: &[(&str, &str)] = &[
+    ("Get", "GET"), ("Post", "POST"), ("Put", "PUT"), ("Delete", "DELETE"),
+    ("Patch", "PATCH"), ("Head", "HEAD"), ("Options", "OPTIONS"), ("All", "ANY"),
+];
+
+pub(crate) fn extract(style: &GoRouterStyle, content: &str, file_path: &str) -> Vec<Endpoint> {
+    let models = models::go_structs(content);
+    let groups = collect_groups(content);
+    let route_blocks = collect_route_blocks(content);
+    let mut endpoints = Vec::new();
+
+    for (call, method) in style.methods {
+        let pattern = format!(".{call}(");
+        let mut from = 0;
+        while let Some(pos) = content[from..].find(&pattern) {
+            let at = from + pos;
+            let open = at + pattern.len() - 1;
+            from = open;
+            let Some(path) = extract_string_arg(content, open + 1) else { continue };
+            // `c.Get("Content-Type")` and friends are not routes.
+            if !(path.starts_with('/') || path.is_empty() || path == "*") {
+                continue;
+            }
+            let Some(close) = models::matching_close(content, open) else { continue };
+            let args = &content[open + 1..close];
+            if models::split_top_level(args, ',').len() < 2 {
+                continue;
+            }
+            let receiver = receiver_before(content, at);
+            let prefix = models::join_path(&block_prefix(&route_blocks, at), &group_prefix(&groups, &receiver));
+            let handler = models::split_top_level(args, ',').last().map(|h| h.trim()).unwrap_or("");
+            let (request_fields, response_fields) = handler_fields(style, content, handler, at, &models);
+            endpoints.push(Endpoint {
+                method: method.to_string(),
+                path: models::join_path(&prefix, &path),
+                request_fields,
+                response_fields,
+                file: file_path.to_string(),
+                line: models::line_of(content, at),
+            });
+        }
+    }
+    endpoints.sort_by_key(|e| e.line);
+    endpoints
+}
+
+/// Identifier immediately before `.Method(`.
+fn receiver_before(content: &str, dot: usize) -> String {
+    content[..dot]
+        .chars()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect()
+}
+
+/// `api := e.Group("/api")`, `v1 := api.Group("/v1")` → full prefixes.
+fn collect_groups(content: &str) -> HashMap<String, String> {
+    let mut raw: HashMap<String, (String, String)> = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let Some(pos) = trimmed.find(".Group(") else { continue };
+        let Some(assign) = trimmed.find(":=").or_else(|| trimmed.find(" = ")) else { continue };
+        if assign > pos {
+            continue;
+        }
+        let Some(prefix) = extract_string_arg(trimmed, pos + ".Group(".len()) else { continue };
+        let var = trimmed[..assign].split_whitespace().last().unwrap_or("").to_string();
+        let parent = receiver_before(trimmed, pos);
+        if !var.is_empty() {
+            raw.insert(var, (parent, prefix));
+        }
+    }
+    raw.keys()
+        .map(|var| (var.clone(), resolve_group(&raw, var, 0)))
+        .collect()
+}
+
+fn resolve_group(raw: &HashMap<String, (String, String)>, var: &str, depth: usize) -> String {
+    match raw.get(var) {
+        Some((parent, prefix)) if depth < 8 => models::join_path(&resolve_group(raw, parent, depth + 1), prefix),
+        _ => String::new(),
+    }
+}
+
+fn group_prefix(groups: &HashMap<String, String>, receiver: &str) -> String {
+    groups.get(receiver).cloned().unwrap_or_default()
+}
+
+/// `r.Route("/users", func(r chi.Router) { ... })` → (prefix, body range).
+fn collect_route_blocks(content: &str) -> Vec<(String, usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find(".Route(") {
+        let open = from + pos + ".Route(".len() - 1;
+        from = open;
+        let Some(prefix) = extract_string_arg(content, open + 1) else { continue };
+        let Some(close) = models::matching_close(content, open) else { continue };
+        // Skip past the path literal, which may itself contain `{id}`.
+        let Some(func) = content[open..close].find("func").map(|p| open + p) else { continue };
+        if let Some((start, end)) = models::block_after(&content[..close], func) {
+            blocks.push((prefix, start, end));
+        }
+    }
+    blocks
+}
+
+fn block_prefix(blocks: &[(String, usize, usize)], at: usize) -> String {
+    let mut enclosing: Vec<&(String, usize, usize)> = blocks
+        .iter()
+        .filter(|(_, start, end)| *start < at && at < *end)
+        .collect();
+    enclosing.sort_by_key(|(_, start, _)| *start);
+    enclosing
+        .iter()
+        .fold(String::new(), |acc, (prefix, _, _)| models::join_path(&acc, prefix))
+}
+
+/// Resolve the handler body (named function or inline closure) and read its
+/// bind and response types.
+fn handler_fields(
+    style: &GoRouterStyle,
+    content: &str,
+    handler: &str,
+    call_at: usize,
+    models: &Models,
+) -> (Vec<FieldSpec>, Vec<FieldSpec>) {
+    let body = if handler.starts_with("func") {
+        models::block_after(content, call_at + content[call_at..].find("func").unwrap_or(0))
+    } else {
+        let name = handler.trim_end_matches("()");
+        models::find_function(content, &["func "], name).and_then(|start| models::block_after(content, start))
+    };
+    let Some((start, end)) = body else { return (vec![], vec![]) };
+    let body = &content[start..end];
+
+    let request = style
+        .bind_calls
+        .iter()
+        .find_map(|call| {
+            let at = body.find(call)? + call.len();
+            let var: String = body[at..]
+                .trim_start()
+                .trim_start_matches('&')
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            var_type(body, &var)
+        })
+        .map(|ty| models::fields_for_type(models, &ty))
+        .unwrap_or_default();
+
+    let response = style
+        .respond_calls
+        .iter()
+        .find_map(|call| {
+            let open = body.find(call)? + call.len() - 1;
+            let close = models::matching_close(body, open)?;
+            let arg = models::split_top_level(&body[open + 1..close], ',').last()?.trim();
+            literal_type(arg).or_else(|| var_type(body, arg))
+        })
+        .filter(|ty| models.contains_key(models::unwrap_type(ty).0) || ty.starts_with("[]"))
+        .map(|ty| models::fields_for_type(models, &ty))
+        .unwrap_or_default();
+
+    (request, response)
+}
+
+/// Type of a local: `var x T`, `x := T{`, `x := &T{`, `x := new(T)`, `x := []T{`.
+fn var_type(body: &str, var: &str) -> Option<String> {
+    if var.is_empty() {
+        return None;
+    }
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("var ") {
+            let mut parts = rest.split_whitespace();
+            if parts.next() == Some(var) {
+                return parts.next().map(|t| t.trim_start_matches('*').to_string());
+            }
+        }
+        if let Some(rest) = trimmed.strip_prefix(var).and_then(|r| r.trim_start().strip_prefix(":=")) {
+            let rest = rest.trim();
+            if let Some(inner) = rest.strip_prefix("new(") {
+                return inner.split(')').next().map(str::to_string);
+            }
+            if let Some(ty) = literal_type(rest) {
+                return Some(ty);
+            }
+        }
+    }
+    None
+}
+
+/// `User{...}` / `&User{...}` / `[]User{...}` → the type.
+fn literal_type(expr: &str) -> Option<String> {
+    let expr = expr.trim().trim_start_matches('&');
+    let brace = expr.find('{')?;
+    let ty = &expr[..brace];
+    let valid = !ty.is_empty()
+        && ty.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '[' || c == ']' || c == '*');
+    (valid && !ty.starts_with("map[") && !ty.ends_with(".Map") && ty != "gin.H").then(|| ty.to_string())
+}
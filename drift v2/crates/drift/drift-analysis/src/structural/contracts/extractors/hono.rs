@@ -0,0 +1,146 @@
+//! Hono endpoint extractor: `new Hono()` apps, `basePath`/`route` prefixes
+//! and `zValidator` request schemas resolved against zod objects.
+
+use std::collections::HashMap;
+
+use super::express::extract_string_arg;
+use super::models::{self, Models};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct HonoExtractor;
+
+const METHODS: &[(&str, &str)] = &[
+    ("get", "GET"), ("post", "POST"), ("put", "PUT"), ("delete", "DELETE"),
+    ("patch", "PATCH"), ("options", "OPTIONS"), ("all", "ANY"),
+];
+
+impl EndpointExtractor for HonoExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        let schemas = models::zod_objects(content);
+        let apps = collect_apps(content);
+        let mut endpoints = Vec::new();
+        for (receiver, prefix) in &apps {
+            for (call, method) in METHODS {
+                let pattern = format!("{receiver}.{call}(");
+                let mut from = 0;
+                while let Some(pos) = content[from..].find(&pattern) {
+                    let at = from + pos;
+                    let open = at + pattern.len() - 1;
+                    from = open + 1;
+                    let standalone = at == 0 || {
+                        let prev = content.as_bytes()[at - 1];
+                        !(prev.is_ascii_alphanumeric() || prev == b'_' || prev == b'$' || prev == b'.')
+                    };
+                    if !standalone {
+                        continue;
+                    }
+                    let Some(path) = extract_string_arg(content, open + 1) else { continue };
+                    if !path.starts_with('/') && path != "*" {
+                        continue;
+                    }
+                    let args = models::matching_close(content, open)
+                        .map(|close| &content[open + 1..close])
+                        .unwrap_or("");
+                    endpoints.push(Endpoint {
+                        method: method.to_string(),
+                        path: models::join_path(prefix, &path),
+                        request_fields: validator_fields(args, &schemas),
+                        response_fields: vec![],
+                        file: file_path.to_string(),
+                        line: models::line_of(content, at),
+                    });
+                }
+            }
+        }
+        endpoints.sort_by_key(|e| e.line);
+        endpoints
+    }
+
+    fn framework(&self) -> &str { "hono" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("hono") && content.contains("new Hono")
+    }
+}
+
+/// Hono app variables and their mount prefix: `new Hono().basePath('/api')`
+/// plus `app.route('/users', users)` for sub-apps in the same file.
+fn collect_apps(content: &str) -> HashMap<String, String> {
+    let mut base: HashMap<String, String> = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let Some(new_at) = trimmed.find("new Hono") else { continue };
+        let Some((decl, _)) = trimmed[..new_at].rsplit_once('=') else { continue };
+        let name = decl.split_whitespace().last().unwrap_or("");
+        if name.is_empty() {
+            continue;
+        }
+        let prefix = trimmed[new_at..]
+            .find(".basePath(")
+            .and_then(|p| extract_string_arg(&trimmed[new_at..], p + ".basePath(".len()))
+            .unwrap_or_default();
+        base.insert(name.to_string(), prefix);
+    }
+
+    // child ↦ (parent, mount path)
+    let mut mounts: HashMap<String, (String, String)> = HashMap::new();
+    for parent in base.keys() {
+        let call = format!("{parent}.route(");
+        let mut from = 0;
+        while let Some(pos) = content[from..].find(&call) {
+            let at = from + pos + call.len();
+            from = at;
+            let Some(path) = extract_string_arg(content, at) else { continue };
+            let child: String = content[at..]
+                .split_once(',')
+                .map(|(_, rest)| rest.trim_start())
+                .unwrap_or("")
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                .collect();
+            if base.contains_key(&child) && child != *parent {
+                mounts.insert(child, (parent.clone(), path));
+            }
+        }
+    }
+
+    base.iter()
+        .map(|(name, own)| (name.clone(), models::join_path(&mount_prefix(&base, &mounts, name, 0), own)))
+        .collect()
+}
+
+fn mount_prefix(
+    base: &HashMap<String, String>,
+    mounts: &HashMap<String, (String, String)>,
+    name: &str,
+    depth: usize,
+) -> String {
+    match mounts.get(name) {
+        Some((parent, path)) if depth < 8 => {
+            let parent_prefix = models::join_path(
+                &mount_prefix(base, mounts, parent, depth + 1),
+                base.get(parent).map(String::as_str).unwrap_or(""),
+            );
+            models::join_path(&parent_prefix, path)
+        }
+        _ => String::new(),
+    }
+}
+
+/// `zValidator('json', createUser)` / `zValidator('query', filters)` → the
+/// schema's fields.
+fn validator_fields(args: &str, schemas: &Models) -> Vec<FieldSpec> {
+    let mut fields = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = args[from..].find("zValidator(") {
+        let open = from + pos + "zValidator".len();
+        from = open + 1;
+        let Some(close) = models::matching_close(args, open) else { continue };
+        let parts = models::split_top_level(&args[open + 1..close], ',');
+        let Some(target) = parts.get(1).map(|p| p.trim()) else { continue };
+        if let Some(schema) = schemas.get(target) {
+            fields.extend(schema.iter().cloned());
+        }
+    }
+    fields
+}
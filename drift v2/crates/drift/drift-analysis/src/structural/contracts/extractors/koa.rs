@@ -0,0 +1,115 @@
+//! Koa endpoint extractor (`@koa/router` / `koa-router`).
+
+use std::collections::HashMap;
+
+use super::express::extract_string_arg;
+use super::models;
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct KoaExtractor;
+
+const METHODS: &[(&str, &str)] = &[
+    ("get", "GET"), ("post", "POST"), ("put", "PUT"), ("delete", "DELETE"),
+    ("del", "DELETE"), ("patch", "PATCH"), ("head", "HEAD"), ("options", "OPTIONS"), ("all", "ANY"),
+];
+
+impl EndpointExtractor for KoaExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        let routers = collect_routers(content);
+        let mut endpoints = Vec::new();
+        for (receiver, prefix) in &routers {
+            for (call, method) in METHODS {
+                let pattern = format!("{receiver}.{call}(");
+                let mut from = 0;
+                while let Some(pos) = content[from..].find(&pattern) {
+                    let at = from + pos;
+                    from = at + pattern.len();
+                    let standalone = at == 0 || {
+                        let prev = content.as_bytes()[at - 1];
+                        !(prev.is_ascii_alphanumeric() || prev == b'_' || prev == b'$' || prev == b'.')
+                    };
+                    if !standalone {
+                        continue;
+                    }
+                    // Named routes: `router.get('user', '/users/:id', handler)`.
+                    let Some(first) = extract_string_arg(content, from) else { continue };
+                    let path = if first.starts_with('/') {
+                        first
+                    } else {
+                        let after_name = content[from..].find(',').map(|p| from + p + 1).unwrap_or(from);
+                        match extract_string_arg(content, after_name) {
+                            Some(path) if path.starts_with('/') => path,
+                            _ => continue,
+                        }
+                    };
+                    endpoints.push(Endpoint {
+                        method: method.to_string(),
+                        path: models::join_path(prefix, &path),
+                        request_fields: vec![],
+                        response_fields: vec![],
+                        file: file_path.to_string(),
+                        line: models::line_of(content, at),
+                    });
+                }
+            }
+        }
+        endpoints.sort_by_key(|e| e.line);
+        endpoints
+    }
+
+    fn extract_with_context(&self, content: &str, file_path: &str, parse_result: Option<&crate::parsers::types::ParseResult>) -> Vec<Endpoint> {
+        let mut endpoints = self.extract(content, file_path);
+        if let Some(pr) = parse_result {
+            for ep in &mut endpoints {
+                if let Some(func) = super::find_function_at_line(pr, ep.line.saturating_sub(1)) {
+                    let req_fields = super::params_to_fields(&func.parameters);
+                    if !req_fields.is_empty() {
+                        ep.request_fields = req_fields;
+                    }
+                    if let Some(ref rt) = func.return_type {
+                        let resp_fields = super::return_type_to_fields(rt);
+                        if !resp_fields.is_empty() {
+                            ep.response_fields = resp_fields;
+                        }
+                    }
+                }
+            }
+        }
+        endpoints
+    }
+
+    fn framework(&self) -> &str { "koa" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("koa-router") || content.contains("@koa/router")
+    }
+}
+
+/// `const router = new Router({ prefix: '/api' })` → `router` ↦ `/api`.
+/// `router.prefix('/v1')` calls are applied as well.
+fn collect_routers(content: &str) -> HashMap<String, String> {
+    let mut routers = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let Some(new_at) = trimmed.find("new Router(").or_else(|| trimmed.find("new KoaRouter(")) else { continue };
+        let Some((decl, _)) = trimmed[..new_at].rsplit_once('=') else { continue };
+        let name = decl.split_whitespace().last().unwrap_or("");
+        if name.is_empty() {
+            continue;
+        }
+        let prefix = trimmed[new_at..]
+            .find("prefix:")
+            .and_then(|p| extract_string_arg(&trimmed[new_at..], p + "prefix:".len()))
+            .unwrap_or_default();
+        routers.insert(name.to_string(), prefix);
+    }
+    for (name, prefix) in routers.iter_mut() {
+        let call = format!("{name}.prefix(");
+        if let Some(pos) = content.find(&call) {
+            if let Some(p) = extract_string_arg(content, pos + call.len()) {
+                *prefix = p;
+            }
+        }
+    }
+    routers
+}
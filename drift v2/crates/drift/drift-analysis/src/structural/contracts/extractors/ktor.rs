@@ -0,0 +1,138 @@
+//! Ktor (Kotlin) endpoint extractor: the routing DSL (`route("/p") { get { } }`)
+//! with `call.receive<T>()` / `call.respond(...)` resolved against data classes.
+
+use super::express::extract_string_arg;
+use super::models::{self, Models};
+use super::EndpointExtractor;
+use crate::structural::contracts::types::*;
+
+pub struct KtorExtractor;
+
+const METHODS: &[&str] = &["get", "post", "put", "delete", "patch", "head", "options"];
+
+impl EndpointExtractor for KtorExtractor {
+    fn extract(&self, content: &str, file_path: &str) -> Vec<Endpoint> {
+        let models = models::kotlin_data_classes(content);
+        let routes: Vec<(String, usize, usize)> = dsl_blocks(content, "route")
+            .into_iter()
+            .filter_map(|(path, _, start, end)| Some((path?, start, end)))
+            .collect();
+
+        let mut endpoints = Vec::new();
+        for method in METHODS {
+            for (path, at, start, end) in dsl_blocks(content, method) {
+                let mut enclosing: Vec<&(String, usize, usize)> = routes
+                    .iter()
+                    .filter(|(_, s, e)| *s < at && at < *e)
+                    .collect();
+                enclosing.sort_by_key(|(_, s, _)| *s);
+                let prefix = enclosing
+                    .iter()
+                    .fold(String::new(), |acc, (p, _, _)| models::join_path(&acc, p));
+                let path = models::join_path(&prefix, path.as_deref().unwrap_or(""));
+                let body = &content[start..end];
+                endpoints.push(Endpoint {
+                    method: method.to_uppercase(),
+                    path: if path.is_empty() { "/".to_string() } else { path },
+                    request_fields: receive_type(body)
+                        .map(|t| models::fields_for_type(&models, &t))
+                        .unwrap_or_default(),
+                    response_fields: respond_type(body, &models)
+                        .map(|t| models::fields_for_type(&models, &t))
+                        .unwrap_or_default(),
+                    file: file_path.to_string(),
+                    line: models::line_of(content, at),
+                });
+            }
+        }
+        endpoints.sort_by_key(|e| e.line);
+        endpoints
+    }
+
+    fn framework(&self) -> &str { "ktor" }
+    fn matches(&self, content: &str) -> bool {
+        content.contains("io.ktor") || content.contains("routing {")
+    }
+}
+
+/// Occurrences of `keyword("path") { ... }` or `keyword { ... }` as a DSL
+/// call (not `x.keyword(`): (path, offset, body start, body end).
+fn dsl_blocks(content: &str, keyword: &str) -> Vec<(Option<String>, usize, usize, usize)> {
+    let mut blocks = Vec::new();
+    let bytes = content.as_bytes();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find(keyword) {
+        let at = from + pos;
+        from = at + keyword.len();
+        let boundary = at == 0 || {
+            let prev = bytes[at - 1];
+            !(prev.is_ascii_alphanumeric() || prev == b'_' || prev == b'.')
+        };
+        if !boundary {
+            continue;
+        }
+        let rest = content[from..].trim_start();
+        let after = content.len() - rest.len();
+        let (path, brace_from) = if rest.starts_with('(') {
+            let Some(close) = models::matching_close(content, after) else { continue };
+            (extract_string_arg(content, after + 1), close + 1)
+        } else if rest.starts_with('{') {
+            (None, after)
+        } else {
+            continue;
+        };
+        if !content[brace_from..].trim_start().starts_with('{') {
+            continue;
+        }
+        if let Some((start, end)) = models::block_after(content, brace_from) {
+            blocks.push((path, at, start, end));
+        }
+    }
+    blocks
+}
+
+/// `call.receive<T>()` / `call.receive(T::class)`.
+fn receive_type(body: &str) -> Option<String> {
+    if let Some(pos) = body.find("call.receive<") {
+        let start = pos + "call.receive<".len();
+        let end = body[start..].find(">(")?;
+        return Some(body[start..start + end].trim().to_string());
+    }
+    let pos = body.find("call.receive(")? + "call.receive(".len();
+    let end = body[pos..].find("::class")?;
+    Some(body[pos..pos + end].trim().to_string())
+}
+
+/// Last argument of `call.respond(...)` resolved to a data class: a
+/// constructor call, or a `val` declared with a type, constructor or receive.
+fn respond_type(body: &str, models: &Models) -> Option<String> {
+    let open = body.find("call.respond(")? + "call.respond".len();
+    let close = models::matching_close(body, open)?;
+    let arg = models::split_top_level(&body[open + 1..close], ',').last()?.trim();
+    let ty = constructor_type(arg).or_else(|| val_type(body, arg))?;
+    models::is_model(models, &ty).then_some(ty)
+}
+
+fn constructor_type(expr: &str) -> Option<String> {
+    let name: String = expr.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    let starts_upper = name.chars().next().is_some_and(char::is_uppercase);
+    (starts_upper && expr[name.len()..].starts_with('(')).then_some(name)
+}
+
+fn val_type(body: &str, var: &str) -> Option<String> {
+    for line in body.lines() {
+        let trimmed = line.trim();
+        let Some(decl) = trimmed.strip_prefix("val ").or_else(|| trimmed.strip_prefix("var ")) else { continue };
+        let Some(rest) = decl.strip_prefix(var) else { continue };
+        let rest = rest.trim_start();
+        if let Some(annotated) = rest.strip_prefix(':') {
+            let ty = annotated.split('=').next().unwrap_or("").trim();
+            return (!ty.is_empty()).then(|| ty.to_string());
+        }
+        if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim();
+            return constructor_type(value).or_else(|| receive_type(value));
+        }
+    }
+    None
+}
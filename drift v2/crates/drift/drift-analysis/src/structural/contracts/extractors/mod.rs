@@ -11,13 +11,30 @@ pub mod rails;
 pub mod laravel;
 pub mod gin;
 pub mod actix;
+pub mod fastapi;
+pub mod axum;
+pub mod echo;
+pub mod chi;
+pub mod fiber;
+pub mod koa;
+pub mod hono;
+pub mod ktor;
 pub mod nextjs;
 pub mod trpc;
 pub mod frontend;
+pub mod models;
+mod go_router;
 
 use super::types::{Endpoint, FieldSpec};
 use crate::parsers::types::{ParseResult, FunctionInfo, ParameterInfo, DecoratorInfo};
 
+/// Frameworks whose endpoints are provided by a backend (as opposed to the
+/// `frontend` consumer extractor). Used to split endpoints for BE↔FE matching.
+pub const BACKEND_FRAMEWORKS: &[&str] = &[
+    "express", "fastify", "nestjs", "spring", "flask", "django", "rails", "laravel", "gin",
+    "actix", "aspnet", "nextjs", "fastapi", "axum", "echo", "chi", "fiber", "koa", "hono", "ktor",
+];
+
 /// Trait for extracting API endpoints from source code.
 pub trait EndpointExtractor: Send + Sync {
     /// Extract endpoints from source code content.
@@ -55,6 +72,14 @@ impl ExtractorRegistry {
                 Box::new(laravel::LaravelExtractor),
                 Box::new(gin::GinExtractor),
                 Box::new(actix::ActixExtractor),
+                Box::new(fastapi::FastApiExtractor),
+                Box::new(axum::AxumExtractor),
+                Box::new(echo::EchoExtractor),
+                Box::new(chi::ChiExtractor),
+                Box::new(fiber::FiberExtractor),
+                Box::new(koa::KoaExtractor),
+                Box::new(hono::HonoExtractor),
+                Box::new(ktor::KtorExtractor),
                 Box::new(nextjs::NextJsExtractor),
                 Box::new(trpc::TrpcExtractor),
                 Box::new(frontend::FrontendExtractor),
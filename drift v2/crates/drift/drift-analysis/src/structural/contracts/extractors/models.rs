@@ -0,0 +1,573 @@
+//! Text-level model parsers shared by the extractors: Pydantic models, serde
+//! structs, Go structs, Kotlin data classes and zod objects, each turned into
+//! `FieldSpec`s keyed by type name. Also small helpers for scanning
+//! brace-delimited handler bodies and signatures.
+
+use std::collections::HashMap;
+
+use crate::structural::contracts::types::FieldSpec;
+
+/// Model name → fields.
+pub type Models = HashMap<String, Vec<FieldSpec>>;
+
+/// Fields for a request/response type: a known model's fields, else a single
+/// `value` field carrying the type (the `return_type_to_fields` convention).
+pub fn fields_for_type(models: &Models, ty: &str) -> Vec<FieldSpec> {
+    let ty = ty.trim();
+    if ty.is_empty() {
+        return vec![];
+    }
+    let (inner, is_list, nullable) = unwrap_type(ty);
+    if !is_list {
+        if let Some(fields) = models.get(inner) {
+            return fields.clone();
+        }
+    }
+    vec![FieldSpec {
+        name: "value".to_string(),
+        field_type: ty.to_string(),
+        required: true,
+        nullable,
+    }]
+}
+
+/// Strip nullability and one collection wrapper: returns the element type,
+/// whether it was a collection and whether it was nullable.
+pub fn unwrap_type(ty: &str) -> (&str, bool, bool) {
+    let mut ty = ty.trim();
+    let mut nullable = false;
+    if let Some(rest) = ty.strip_suffix('?') {
+        ty = rest;
+        nullable = true;
+    }
+    if let Some(rest) = ty.strip_prefix('*') {
+        ty = rest;
+        nullable = true;
+    }
+    for (open, close) in [("Option<", '>'), ("Optional[", ']')] {
+        if let Some(rest) = ty.strip_prefix(open).and_then(|r| r.strip_suffix(close)) {
+            ty = rest.trim();
+            nullable = true;
+        }
+    }
+    if let Some((a, b)) = ty.split_once('|') {
+        let (a, b) = (a.trim(), b.trim());
+        if b == "None" || b == "null" {
+            ty = a;
+            nullable = true;
+        } else if a == "None" || a == "null" {
+            ty = b;
+            nullable = true;
+        }
+    }
+    if let Some(rest) = ty.strip_prefix("[]") {
+        return (rest.trim_start_matches('*'), true, nullable);
+    }
+    if let Some(rest) = ty.strip_suffix("[]") {
+        return (rest, true, nullable);
+    }
+    for (open, close) in [("Vec<", '>'), ("List<", '>'), ("list[", ']'), ("List[", ']'), ("Array<", '>')] {
+        if let Some(rest) = ty.strip_prefix(open).and_then(|r| r.strip_suffix(close)) {
+            return (rest.trim(), true, nullable);
+        }
+    }
+    (ty, false, nullable)
+}
+
+/// Whether a (wrapped) type names a known model.
+pub fn is_model(models: &Models, ty: &str) -> bool {
+    models.contains_key(unwrap_type(ty).0)
+}
+
+// ─── Pydantic ───────────────────────────────────────────────────────────────
+
+/// `class Item(BaseModel):` bodies (and subclasses of other models in the file).
+pub fn python_models(content: &str) -> Models {
+    let mut models = Models::new();
+    let lines: Vec<&str> = content.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(rest) = trimmed.strip_prefix("class ") else { continue };
+        let Some((name, bases)) = rest.split_once('(') else { continue };
+        let bases = bases.split(')').next().unwrap_or("");
+        let parents: Vec<&str> = bases.split(',').map(str::trim).collect();
+        let is_model = parents.iter().any(|b| {
+            matches!(*b, "BaseModel" | "pydantic.BaseModel" | "SQLModel") || models.contains_key(*b)
+        });
+        if !is_model {
+            continue;
+        }
+        let mut fields: Vec<FieldSpec> = parents
+            .iter()
+            .filter_map(|b| models.get(*b))
+            .flatten()
+            .cloned()
+            .collect();
+        let indent = line.len() - trimmed.len();
+        for body in &lines[i + 1..] {
+            let body_trimmed = body.trim_start();
+            if body_trimmed.is_empty() || body_trimmed.starts_with('#') {
+                continue;
+            }
+            if body.len() - body_trimmed.len() <= indent {
+                break;
+            }
+            if let Some(field) = python_field(body_trimmed) {
+                fields.retain(|f| f.name != field.name);
+                fields.push(field);
+            }
+        }
+        models.insert(name.trim().to_string(), fields);
+    }
+    models
+}
+
+fn python_field(line: &str) -> Option<FieldSpec> {
+    if line.starts_with("def ") || line.starts_with("async ") || line.starts_with('@')
+        || line.starts_with("class ") || line.starts_with("model_config")
+    {
+        return None;
+    }
+    let (name, rest) = line.split_once(':')?;
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') || name.starts_with('_') {
+        return None;
+    }
+    let (ty, default) = match rest.split_once('=') {
+        Some((ty, default)) => (ty.trim(), Some(default.trim())),
+        None => (rest.trim(), None),
+    };
+    if ty.starts_with("ClassVar") {
+        return None;
+    }
+    let alias = default.and_then(|d| keyword_string(d, "alias"));
+    let required = match default {
+        None => true,
+        Some(d) => d.starts_with("Field(...") || d.starts_with("Field(default=...)"),
+    };
+    Some(FieldSpec {
+        name: alias.unwrap_or_else(|| name.to_string()),
+        field_type: ty.to_string(),
+        required,
+        nullable: unwrap_type(ty).2,
+    })
+}
+
+/// Value of `key="..."` inside a call's argument text.
+pub fn keyword_string(text: &str, key: &str) -> Option<String> {
+    let mut from = 0;
+    while let Some(pos) = text[from..].find(key) {
+        let at = from + pos;
+        let boundary = at == 0 || !(text.as_bytes()[at - 1].is_ascii_alphanumeric() || text.as_bytes()[at - 1] == b'_');
+        let after = text[at + key.len()..].trim_start();
+        if boundary {
+            if let Some(value) = after.strip_prefix('=').or_else(|| after.strip_prefix(':')) {
+                let value = value.trim_start();
+                let quote = value.chars().next()?;
+                if quote == '"' || quote == '\'' {
+                    let end = value[1..].find(quote)?;
+                    return Some(value[1..1 + end].to_string());
+                }
+            }
+        }
+        from = at + key.len();
+    }
+    None
+}
+
+// ─── serde ──────────────────────────────────────────────────────────────────
+
+/// `#[derive(Serialize/Deserialize)]` structs with named fields.
+pub fn rust_structs(content: &str) -> Models {
+    let mut models = Models::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut attrs: Vec<&str> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with("#[") {
+            attrs.push(trimmed);
+            i += 1;
+            continue;
+        }
+        let decl = trimmed.strip_prefix("pub ").unwrap_or(trimmed);
+        let decl = decl.strip_prefix("pub(crate) ").unwrap_or(decl);
+        let is_serde = attrs.iter().any(|a| a.contains("Serialize") || a.contains("Deserialize"));
+        if let (Some(rest), true) = (decl.strip_prefix("struct "), is_serde) {
+            let name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+            let rename_all = attrs.iter().find_map(|a| keyword_string(a, "rename_all"));
+            match (rest.find('{'), rest.trim_end().strip_suffix('}')) {
+                // One-line `struct Paging { page: u32 }`.
+                (Some(open), Some(body)) if open < body.len() => {
+                    let parts = split_top_level(&body[open + 1..], ',');
+                    models.insert(name, rust_struct_fields(&parts, 0, rename_all.as_deref()).0);
+                }
+                (Some(_), None) => {
+                    let (fields, end) = rust_struct_fields(&lines, i + 1, rename_all.as_deref());
+                    models.insert(name, fields);
+                    i = end;
+                }
+                _ => {}
+            }
+        }
+        if !trimmed.is_empty() && !trimmed.starts_with("//") {
+            attrs.clear();
+        }
+        i += 1;
+    }
+    models
+}
+
+fn rust_struct_fields(lines: &[&str], start: usize, rename_all: Option<&str>) -> (Vec<FieldSpec>, usize) {
+    let mut fields = Vec::new();
+    let mut attrs: Vec<&str> = Vec::new();
+    for (offset, line) in lines[start..].iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('}') {
+            return (fields, start + offset);
+        }
+        if trimmed.starts_with("#[") {
+            attrs.push(trimmed);
+            continue;
+        }
+        let decl = trimmed.strip_prefix("pub ").unwrap_or(trimmed);
+        let decl = decl.strip_prefix("pub(crate) ").unwrap_or(decl);
+        let Some((name, ty)) = decl.split_once(':') else { continue };
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let serde_attrs: Vec<&str> = attrs.drain(..).filter(|a| a.starts_with("#[serde(")).collect();
+        if serde_attrs.iter().any(|a| a.contains("skip)") || a.contains("skip,") || a.contains("skip_deserializing")) {
+            continue;
+        }
+        let ty = ty.trim().trim_end_matches(',').trim();
+        let nullable = ty.starts_with("Option<");
+        let has_default = serde_attrs.iter().any(|a| a.contains("default"));
+        let renamed = serde_attrs.iter().find_map(|a| keyword_string(a, "rename"));
+        fields.push(FieldSpec {
+            name: renamed.unwrap_or_else(|| apply_rename_all(name, rename_all)),
+            field_type: ty.to_string(),
+            required: !nullable && !has_default,
+            nullable,
+        });
+    }
+    (fields, lines.len())
+}
+
+fn apply_rename_all(name: &str, rule: Option<&str>) -> String {
+    let words: Vec<&str> = name.split('_').filter(|w| !w.is_empty()).collect();
+    let capitalize = |w: &str| {
+        let mut chars = w.chars();
+        chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    };
+    match rule {
+        Some("camelCase") => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.to_string() } else { capitalize(w) })
+            .collect(),
+        Some("PascalCase") => words.iter().map(|w| capitalize(w)).collect(),
+        Some("kebab-case") => words.join("-"),
+        Some("SCREAMING_SNAKE_CASE") => name.to_uppercase(),
+        Some("UPPERCASE") => name.to_uppercase(),
+        Some("lowercase") => name.to_lowercase(),
+        _ => name.to_string(),
+    }
+}
+
+// ─── Go ─────────────────────────────────────────────────────────────────────
+
+/// `type X struct { ... }` with `json:"..."` tags.
+pub fn go_structs(content: &str) -> Models {
+    let mut models = Models::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        let Some(rest) = trimmed.strip_prefix("type ") else {
+            i += 1;
+            continue;
+        };
+        let mut parts = rest.split_whitespace();
+        let (Some(name), Some(kind)) = (parts.next(), parts.next()) else {
+            i += 1;
+            continue;
+        };
+        if !kind.starts_with("struct") || !rest.contains('{') {
+            i += 1;
+            continue;
+        }
+        let mut fields = Vec::new();
+        i += 1;
+        while i < lines.len() && !lines[i].trim().starts_with('}') {
+            if let Some(field) = go_field(lines[i].trim()) {
+                fields.push(field);
+            }
+            i += 1;
+        }
+        models.insert(name.to_string(), fields);
+        i += 1;
+    }
+    models
+}
+
+fn go_field(line: &str) -> Option<FieldSpec> {
+    if line.is_empty() || line.starts_with("//") {
+        return None;
+    }
+    let (decl, tag) = match line.split_once('`') {
+        Some((decl, tag)) => (decl.trim(), tag.trim_end_matches('`')),
+        None => (line, ""),
+    };
+    let mut parts = decl.split_whitespace();
+    let (name, ty) = (parts.next()?, parts.next()?);
+    let json = tag_value(tag, "json");
+    let (json_name, omitempty) = match json.as_deref() {
+        Some("-") => return None,
+        Some(json) => {
+            let mut opts = json.split(',');
+            let name = opts.next().unwrap_or("").to_string();
+            (Some(name).filter(|n| !n.is_empty()), opts.any(|o| o == "omitempty"))
+        }
+        None => (None, false),
+    };
+    let exported = name.chars().next().is_some_and(|c| c.is_uppercase());
+    if !exported && json_name.is_none() {
+        return None;
+    }
+    let validate = tag_value(tag, "validate").or_else(|| tag_value(tag, "binding")).unwrap_or_default();
+    let nullable = ty.starts_with('*');
+    let required = validate.split(',').any(|v| v == "required") || (!omitempty && !nullable);
+    Some(FieldSpec {
+        name: json_name.unwrap_or_else(|| name.to_string()),
+        field_type: ty.to_string(),
+        required,
+        nullable,
+    })
+}
+
+fn tag_value(tag: &str, key: &str) -> Option<String> {
+    let start = tag.find(&format!("{key}:\""))? + key.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(tag[start..start + end].to_string())
+}
+
+// ─── Kotlin ─────────────────────────────────────────────────────────────────
+
+/// `data class X(val a: Int, val b: String? = null)`.
+pub fn kotlin_data_classes(content: &str) -> Models {
+    let mut models = Models::new();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find("data class ") {
+        let start = from + pos + "data class ".len();
+        from = start;
+        let name: String = content[start..].chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+        let Some(open) = content[start..].find('(').map(|p| start + p) else { continue };
+        let between = content[start + name.len()..open].trim();
+        if !(between.is_empty() || between.starts_with('<')) {
+            continue;
+        }
+        let Some(close) = matching_close(content, open) else { continue };
+        let fields = split_top_level(&content[open + 1..close], ',')
+            .into_iter()
+            .filter_map(|param| kotlin_param(param.trim()))
+            .collect();
+        models.insert(name, fields);
+        from = close;
+    }
+    models
+}
+
+fn kotlin_param(param: &str) -> Option<FieldSpec> {
+    let serial_name = param.find("@SerialName(\"").and_then(|at| {
+        let at = at + "@SerialName(\"".len();
+        let end = param[at..].find('"')?;
+        Some(param[at..at + end].to_string())
+    });
+    let decl = param.rsplit_once("val ").or_else(|| param.rsplit_once("var "))?.1;
+    let (name, rest) = decl.split_once(':')?;
+    let (ty, default) = match rest.split_once('=') {
+        Some((ty, default)) => (ty.trim(), Some(default.trim())),
+        None => (rest.trim(), None),
+    };
+    Some(FieldSpec {
+        name: serial_name.unwrap_or_else(|| name.trim().to_string()),
+        field_type: ty.to_string(),
+        required: default.is_none(),
+        nullable: ty.ends_with('?'),
+    })
+}
+
+// ─── zod ────────────────────────────────────────────────────────────────────
+
+/// `const createUser = z.object({ name: z.string(), age: z.number().optional() })`.
+pub fn zod_objects(content: &str) -> Models {
+    let mut models = Models::new();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find("z.object(") {
+        let at = from + pos;
+        from = at + 9;
+        let before = content[..at].trim_end();
+        let Some(before) = before.strip_suffix('=') else { continue };
+        let name: String = before
+            .trim_end()
+            .chars()
+            .rev()
+            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        let Some(brace) = content[at..].find('{').map(|p| at + p) else { continue };
+        let Some(close) = matching_close(content, brace) else { continue };
+        let fields = split_top_level(&content[brace + 1..close], ',')
+            .into_iter()
+            .filter_map(|entry| {
+                let (key, schema) = entry.split_once(':')?;
+                let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+                let schema = schema.trim();
+                let base = schema.strip_prefix("z.")?;
+                let ty: String = base.chars().take_while(|c| c.is_alphanumeric()).collect();
+                Some(FieldSpec {
+                    name: key.to_string(),
+                    field_type: ty,
+                    required: !schema.contains(".optional()") && !schema.contains(".default("),
+                    nullable: schema.contains(".nullable()") || schema.contains(".nullish()"),
+                })
+            })
+            .collect();
+        if !name.is_empty() {
+            models.insert(name, fields);
+        }
+        from = close;
+    }
+    models
+}
+
+// ─── Scanning helpers ───────────────────────────────────────────────────────
+
+/// Index of the bracket closing the one at `open` (`(`, `[` or `{`),
+/// skipping string literals. A `'` after `&` or `<` is a Rust lifetime, not
+/// a quote.
+pub fn matching_close(text: &str, open: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let (open_ch, close_ch) = match bytes.get(open)? {
+        b'(' => (b'(', b')'),
+        b'[' => (b'[', b']'),
+        b'{' => (b'{', b'}'),
+        _ => return None,
+    };
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut i = open;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match quote {
+            Some(_) if b == b'\\' => i += 1,
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'\'' && i > 0 && matches!(bytes[i - 1], b'&' | b'<') => {}
+            None if b == b'"' || b == b'\'' || b == b'`' => quote = Some(b),
+            None if b == open_ch => depth += 1,
+            None if b == close_ch => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            None => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split on `sep` outside of brackets and string literals.
+pub fn split_top_level(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        let after = prev;
+        prev = c;
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' if matches!(after, '&' | '<') => {}
+                '"' | '\'' | '`' => quote = Some(c),
+                '(' | '[' | '{' | '<' => depth += 1,
+                // `->` / `=>` are arrows, not closing generics.
+                '>' if matches!(after, '-' | '=') => {}
+                ')' | ']' | '}' | '>' => depth -= 1,
+                _ if c == sep && depth == 0 => {
+                    parts.push(&text[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(&text[start..]);
+    parts.into_iter().filter(|p| !p.trim().is_empty()).collect()
+}
+
+/// Body of the brace block that opens at or after `from`.
+pub fn block_after(text: &str, from: usize) -> Option<(usize, usize)> {
+    let open = from + text[from..].find('{')?;
+    let close = matching_close(text, open)?;
+    Some((open, close))
+}
+
+/// Byte offset of the start of a function named `name` (`fn name(`,
+/// `def name(`, `func name(`, `func (r *T) name(`).
+pub fn find_function(text: &str, keywords: &[&str], name: &str) -> Option<usize> {
+    let name = name.rsplit(['.', ':']).next().unwrap_or(name);
+    if name.is_empty() {
+        return None;
+    }
+    for line_start in line_starts(text) {
+        let line = &text[line_start..];
+        let line = &line[..line.find('\n').unwrap_or(line.len())];
+        let trimmed = line.trim_start();
+        let trimmed = trimmed.strip_prefix("pub ").unwrap_or(trimmed);
+        let trimmed = trimmed.strip_prefix("async ").unwrap_or(trimmed);
+        for kw in keywords {
+            let Some(rest) = trimmed.strip_prefix(kw) else { continue };
+            let rest = rest.trim_start();
+            // Go method receivers: `func (h *Handler) name(`.
+            let rest = if rest.starts_with('(') {
+                rest.find(')').map(|p| rest[p + 1..].trim_start()).unwrap_or(rest)
+            } else {
+                rest
+            };
+            if rest.strip_prefix(name).is_some_and(|r| r.starts_with('(') || r.starts_with('<')) {
+                return Some(line_start);
+            }
+        }
+    }
+    None
+}
+
+fn line_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    std::iter::once(0).chain(text.match_indices('\n').map(|(i, _)| i + 1))
+}
+
+/// 1-based line number of a byte offset.
+pub fn line_of(text: &str, offset: usize) -> u32 {
+    text[..offset.min(text.len())].matches('\n').count() as u32 + 1
+}
+
+/// Join a route prefix and a path with exactly one slash between them.
+pub fn join_path(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() {
+        return path.to_string();
+    }
+    if path.is_empty() || path == "/" {
+        return prefix.to_string();
+    }
+    format!("{}/{}", prefix.trim_end_matches('/'), path.trim_start_matches('/'))
+}
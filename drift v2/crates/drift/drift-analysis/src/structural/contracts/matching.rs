@@ -85,7 +85,7 @@ fn type_compatibility(backend_fields: &[FieldSpec], frontend_fields: &[FieldSpec
 }
 
 /// Check if two type strings are compatible.
-fn types_compatible(a: &str, b: &str) -> bool {
+pub(crate) fn types_compatible(a: &str, b: &str) -> bool {
     let a = a.to_lowercase();
     let b = b.to_lowercase();
     if a == b { return true; }
@@ -242,7 +242,7 @@ fn detect_mismatches(backend: &Endpoint, frontend: &Endpoint) -> Vec<ContractMis
 }
 
 /// Check if a type string represents an array type.
-fn is_array_type(type_str: &str) -> bool {
+pub(crate) fn is_array_type(type_str: &str) -> bool {
     let t = type_str.to_lowercase();
     t.starts_with('[') || t.starts_with("array") || t.ends_with("[]")
         || t.starts_with("list<") || t.starts_with("vec<")
@@ -6,5 +6,9 @@ pub mod extractors;
 pub mod matching;
 pub mod breaking_changes;
 pub mod confidence;
+pub mod reconciliation;
+pub mod openapi_emitter;
+#[cfg(feature = "native")]
+pub mod revision_diff;
 
 pub use types::*;
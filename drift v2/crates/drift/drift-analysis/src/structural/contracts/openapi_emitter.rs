@@ -0,0 +1,493 @@
+//! OpenAPI 3.1 emitter.
+//!
+//! Turns extracted backend contracts into an OpenAPI 3.1 document so services
+//! without a spec can bootstrap one (and later reconcile against it, see
+//! [`super::reconciliation`]). Schemas are inferred from the language-level
+//! type strings the extractors record; anything the emitter had to guess is
+//! annotated with `x-drift-*` extensions.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::reconciliation::is_spec_contract;
+use super::types::*;
+
+/// OpenAPI version written to `openapi`.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Emitter options.
+#[derive(Debug, Clone)]
+pub struct OpenApiEmitConfig {
+    /// `info.title`; per-service documents append the service name.
+    pub title: String,
+    /// `info.version`.
+    pub version: String,
+    /// Server URLs listed under `servers`.
+    pub servers: Vec<String>,
+    /// Contracts below this confidence are left out.
+    pub min_confidence: f64,
+}
+
+impl Default for OpenApiEmitConfig {
+    fn default() -> Self {
+        Self {
+            title: "Generated API".to_string(),
+            version: "0.0.0".to_string(),
+            servers: Vec::new(),
+            min_confidence: 0.0,
+        }
+    }
+}
+
+/// An OpenAPI 3.1 document.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenApiDocument {
+    pub openapi: String,
+    pub info: Info,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<Server>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    pub paths: BTreeMap<String, BTreeMap<String, Operation>>,
+    #[serde(rename = "x-drift-generated")]
+    pub drift_generated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Info {
+    pub title: String,
+    pub version: String,
+    #[serde(rename = "x-drift-service", skip_serializing_if = "Option::is_none")]
+    pub drift_service: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Server {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Tag {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    pub operation_id: String,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<Value>,
+    pub responses: BTreeMap<String, Value>,
+    #[serde(rename = "x-drift-confidence")]
+    pub drift_confidence: f64,
+    #[serde(rename = "x-drift-framework")]
+    pub drift_framework: String,
+    #[serde(rename = "x-drift-source")]
+    pub drift_source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "in")]
+    pub location: String,
+    pub required: bool,
+    pub schema: Value,
+}
+
+impl OpenApiDocument {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("OpenAPI document serializes")
+    }
+
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Number of operations across all paths.
+    pub fn operation_count(&self) -> usize {
+        self.paths.values().map(|ops| ops.len()).sum()
+    }
+}
+
+/// Emit one document covering every service; operations are tagged with
+/// their service name.
+pub fn emit_openapi(contracts: &[Contract], config: &OpenApiEmitConfig) -> OpenApiDocument {
+    let sources = emittable(contracts, config);
+    build_document(&sources, config, config.title.clone(), None)
+}
+
+/// Emit one document per service, keyed by service name.
+pub fn emit_openapi_by_service(
+    contracts: &[Contract],
+    config: &OpenApiEmitConfig,
+) -> BTreeMap<String, OpenApiDocument> {
+    let mut groups: BTreeMap<String, Vec<&Contract>> = BTreeMap::new();
+    for contract in emittable(contracts, config) {
+        groups.entry(service_name(&contract.source_file)).or_default().push(contract);
+    }
+    groups
+        .into_iter()
+        .map(|(service, sources)| {
+            let title = format!("{} — {}", config.title, service);
+            let doc = build_document(&sources, config, title, Some(service.clone()));
+            (service, doc)
+        })
+        .collect()
+}
+
+/// Service a source file belongs to: the directory after a conventional
+/// monorepo root (`services/`, `apps/`, `packages/`, `cmd/`), else the first
+/// directory, else `default`.
+pub fn service_name(source_file: &str) -> String {
+    let dirs: Vec<&str> = source_file
+        .split(['/', '\\'])
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    let dirs = &dirs[..dirs.len().saturating_sub(1)];
+    let roots = ["services", "apps", "packages", "cmd"];
+    if let Some(pos) = dirs.iter().position(|d| roots.contains(d)) {
+        if let Some(service) = dirs.get(pos + 1) {
+            return service.to_string();
+        }
+    }
+    match dirs.first() {
+        Some(first) if !matches!(*first, "src" | "app" | "lib") => first.to_string(),
+        _ => "default".to_string(),
+    }
+}
+
+/// Convert any route parameter syntax to OpenAPI templating, returning the
+/// path and its parameters with any type hint (`<int:id>` → `integer`).
+pub fn openapi_path(path: &str) -> (String, Vec<(String, Option<&'static str>)>) {
+    let mut params = Vec::new();
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|segment| match param_segment(segment) {
+            Some((name, hint)) => {
+                params.push((name.clone(), hint));
+                format!("{{{name}}}")
+            }
+            None => segment.to_string(),
+        })
+        .collect();
+    (format!("/{}", segments.join("/")), params)
+}
+
+fn param_segment(segment: &str) -> Option<(String, Option<&'static str>)> {
+    let inner = if let Some(name) = segment.strip_prefix(':') {
+        name
+    } else if segment.len() > 2 && segment.starts_with('{') && segment.ends_with('}') {
+        &segment[1..segment.len() - 1]
+    } else if segment.len() > 2 && segment.starts_with('[') && segment.ends_with(']') {
+        // Next.js catch-alls: [...slug] / [[...slug]].
+        segment.trim_matches(|c| c == '[' || c == ']').trim_start_matches("...")
+    } else if segment.len() > 2 && segment.starts_with('<') && segment.ends_with('>') {
+        let inner = &segment[1..segment.len() - 1];
+        return Some(match inner.split_once(':') {
+            Some((converter, name)) => (clean_param(name), converter_type(converter)),
+            None => (clean_param(inner), None),
+        });
+    } else {
+        return None;
+    };
+    // `{id:int}` (ASP.NET) / `{id:[0-9]+}` (gorilla) constraints.
+    Some(match inner.split_once(':') {
+        Some((name, constraint)) => (clean_param(name), converter_type(constraint)),
+        None => (clean_param(inner), None),
+    })
+}
+
+fn clean_param(name: &str) -> String {
+    name.trim_end_matches('?').to_string()
+}
+
+fn converter_type(converter: &str) -> Option<&'static str> {
+    match converter {
+        "int" | "long" | "[0-9]+" | "\\d+" => Some("integer"),
+        "float" | "double" | "decimal" => Some("number"),
+        "bool" => Some("boolean"),
+        "uuid" | "guid" | "str" | "string" | "slug" | "path" => Some("string"),
+        _ => None,
+    }
+}
+
+/// Infer a JSON Schema (2020-12, as used by OpenAPI 3.1) from a type string.
+/// Types that cannot be mapped keep the original in `x-drift-type`.
+pub fn infer_schema(field_type: &str, nullable: bool) -> Value {
+    let (base, optional) = strip_nullability(field_type.trim());
+    let mut schema = base_schema(base);
+    if nullable || optional {
+        if let Some(Value::String(ty)) = schema.get("type").cloned() {
+            schema["type"] = json!([ty, "null"]);
+        }
+    }
+    schema
+}
+
+/// The JSON Schema primitive a type string maps to, if it maps to one
+/// (`Optional[int]` → `integer`, `User[]` → `array`).
+pub fn json_type(field_type: &str) -> Option<&'static str> {
+    let (base, _) = strip_nullability(field_type.trim());
+    match base_schema(base).get("type")?.as_str()? {
+        "string" => Some("string"),
+        "integer" => Some("integer"),
+        "number" => Some("number"),
+        "boolean" => Some("boolean"),
+        "array" => Some("array"),
+        "object" => Some("object"),
+        _ => None,
+    }
+}
+
+fn strip_nullability(ty: &str) -> (&str, bool) {
+    if let Some(rest) = ty.strip_suffix('?') {
+        return (rest.trim(), true);
+    }
+    for (wrapper, close) in [("Optional[", ']'), ("Optional<", '>'), ("Option<", '>'), ("Nullable<", '>'), ("Maybe<", '>')] {
+        if let Some(inner) = ty.strip_prefix(wrapper).and_then(|rest| rest.strip_suffix(close)) {
+            return (inner.trim(), true);
+        }
+    }
+    let parts: Vec<&str> = ty.split('|').map(str::trim).collect();
+    if parts.len() > 1 {
+        let kept: Vec<&str> = parts
+            .iter()
+            .copied()
+            .filter(|p| !matches!(*p, "null" | "undefined" | "None" | "nil"))
+            .collect();
+        if kept.len() == 1 {
+            return (kept[0], kept.len() < parts.len());
+        }
+    }
+    (ty, false)
+}
+
+fn base_schema(ty: &str) -> Value {
+    if let Some(item) = array_item(ty) {
+        return json!({ "type": "array", "items": infer_schema(item, false) });
+    }
+    let lower = ty.to_lowercase();
+    match lower.as_str() {
+        "" | "any" | "unknown" | "interface{}" | "dynamic" | "mixed" => json!({}),
+        "string" | "str" | "text" | "char" | "&str" | "varchar" => json!({ "type": "string" }),
+        "int" | "integer" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64"
+        | "isize" | "usize" | "long" | "short" | "int32" | "int64" | "uint" | "uint32"
+        | "uint64" | "bigint" => json!({ "type": "integer" }),
+        "number" | "float" | "double" | "decimal" | "f32" | "f64" | "float32" | "float64" => {
+            json!({ "type": "number" })
+        }
+        "bool" | "boolean" => json!({ "type": "boolean" }),
+        "uuid" | "guid" => json!({ "type": "string", "format": "uuid" }),
+        "date" => json!({ "type": "string", "format": "date" }),
+        "datetime" | "date-time" | "timestamp" | "instant" | "time.time" | "localdatetime"
+        | "offsetdatetime" => json!({ "type": "string", "format": "date-time" }),
+        "object" | "dict" | "map" | "record" | "hashmap" | "json" | "jsonvalue" | "value" => {
+            json!({ "type": "object" })
+        }
+        _ if is_map_type(&lower) => json!({ "type": "object" }),
+        _ => json!({ "type": "object", "x-drift-type": ty }),
+    }
+}
+
+fn array_item(ty: &str) -> Option<&str> {
+    if let Some(item) = ty.strip_suffix("[]") {
+        return Some(item);
+    }
+    if let Some(item) = ty.strip_prefix("[]") {
+        return Some(item);
+    }
+    if ty.eq_ignore_ascii_case("array") || ty.eq_ignore_ascii_case("list") {
+        return Some("any");
+    }
+    for prefix in ["list<", "list[", "vec<", "array<", "set<", "hashset<", "ienumerable<", "sequence[", "iterable<"] {
+        // Compare against `ty` itself: lowercasing can change byte lengths.
+        if !ty.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix)) {
+            continue;
+        }
+        let rest = &ty[prefix.len()..];
+        if let Some(item) = rest.strip_suffix('>').or_else(|| rest.strip_suffix(']')) {
+            return Some(item.trim());
+        }
+    }
+    None
+}
+
+fn is_map_type(lower: &str) -> bool {
+    ["map<", "map[", "dict[", "record<", "hashmap<", "dictionary<"]
+        .iter()
+        .any(|p| lower.starts_with(p))
+}
+
+fn object_schema(fields: &[FieldSpec]) -> Value {
+    let properties: serde_json::Map<String, Value> = fields
+        .iter()
+        .map(|f| (f.name.clone(), infer_schema(&f.field_type, f.nullable)))
+        .collect();
+    let required: Vec<&str> = fields.iter().filter(|f| f.required).map(|f| f.name.as_str()).collect();
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    schema
+}
+
+/// Response schema. A lone `value` field is how extractors record a bare
+/// return type, so it becomes the schema itself.
+fn response_schema(fields: &[FieldSpec]) -> Value {
+    match fields {
+        [only] if only.name == "value" => infer_schema(&only.field_type, only.nullable),
+        _ => object_schema(fields),
+    }
+}
+
+/// Backend REST contracts worth emitting: not consumers, not specs.
+fn emittable<'a>(contracts: &'a [Contract], config: &OpenApiEmitConfig) -> Vec<&'a Contract> {
+    contracts
+        .iter()
+        .filter(|c| c.paradigm == Paradigm::Rest)
+        .filter(|c| c.framework != "frontend" && !is_spec_contract(c))
+        .filter(|c| c.confidence >= config.min_confidence)
+        .collect()
+}
+
+fn build_document(
+    sources: &[&Contract],
+    config: &OpenApiEmitConfig,
+    title: String,
+    service: Option<String>,
+) -> OpenApiDocument {
+    let mut paths: BTreeMap<String, BTreeMap<String, Operation>> = BTreeMap::new();
+    let mut tags = BTreeSet::new();
+    let mut operation_ids = BTreeSet::new();
+
+    for contract in sources {
+        let tag = service_name(&contract.source_file);
+        for ep in &contract.endpoints {
+            let methods: Vec<String> = match ep.method.to_lowercase().as_str() {
+                "any" | "all" => vec!["get".into(), "post".into(), "put".into(), "patch".into(), "delete".into()],
+                "get" | "post" | "put" | "patch" | "delete" | "options" | "head" | "trace" => {
+                    vec![ep.method.to_lowercase()]
+                }
+                _ => continue,
+            };
+            let (path, path_params) = openapi_path(&ep.path);
+            for method in methods {
+                let existing = paths.get(&path).and_then(|ops| ops.get(&method));
+                // Same route seen by several extractors: keep the most confident.
+                if existing.is_some_and(|op| op.drift_confidence >= contract.confidence) {
+                    continue;
+                }
+                let operation_id = match existing {
+                    Some(op) => op.operation_id.clone(),
+                    None => unique_id(&mut operation_ids, &method, &path),
+                };
+                let mut operation = build_operation(contract, ep, &method, &path_params, &tag);
+                operation.operation_id = operation_id;
+                tags.insert(tag.clone());
+                paths.entry(path.clone()).or_default().insert(method, operation);
+            }
+        }
+    }
+
+    OpenApiDocument {
+        openapi: OPENAPI_VERSION.to_string(),
+        info: Info {
+            title,
+            version: config.version.clone(),
+            drift_service: service,
+        },
+        servers: config.servers.iter().map(|url| Server { url: url.clone() }).collect(),
+        tags: tags.into_iter().map(|name| Tag { name }).collect(),
+        paths,
+        drift_generated: true,
+    }
+}
+
+fn build_operation(
+    contract: &Contract,
+    ep: &Endpoint,
+    method: &str,
+    path_params: &[(String, Option<&'static str>)],
+    tag: &str,
+) -> Operation {
+    let mut parameters: Vec<Parameter> = path_params
+        .iter()
+        .map(|(name, hint)| {
+            let declared = ep.request_fields.iter().find(|f| &f.name == name);
+            let schema = match (hint, declared) {
+                (Some(ty), _) => json!({ "type": ty }),
+                (None, Some(f)) => infer_schema(&f.field_type, false),
+                (None, None) => json!({ "type": "string" }),
+            };
+            Parameter { name: name.clone(), location: "path".into(), required: true, schema }
+        })
+        .collect();
+
+    let body_fields: Vec<FieldSpec> = ep
+        .request_fields
+        .iter()
+        .filter(|f| !path_params.iter().any(|(name, _)| name == &f.name))
+        .cloned()
+        .collect();
+    let mut request_body = None;
+    if matches!(method, "get" | "delete" | "head" | "options") {
+        parameters.extend(body_fields.iter().map(|f| Parameter {
+            name: f.name.clone(),
+            location: "query".into(),
+            required: f.required,
+            schema: infer_schema(&f.field_type, f.nullable),
+        }));
+    } else if !body_fields.is_empty() {
+        request_body = Some(json!({
+            "required": body_fields.iter().any(|f| f.required),
+            "content": { "application/json": { "schema": object_schema(&body_fields) } },
+        }));
+    }
+
+    let status = if method == "post" { "201" } else { "200" };
+    let mut response = json!({ "description": "Successful response" });
+    if !ep.response_fields.is_empty() {
+        response["content"] = json!({ "application/json": { "schema": response_schema(&ep.response_fields) } });
+    }
+
+    Operation {
+        operation_id: String::new(),
+        tags: vec![tag.to_string()],
+        parameters,
+        request_body,
+        responses: BTreeMap::from([(status.to_string(), response)]),
+        drift_confidence: contract.confidence,
+        drift_framework: contract.framework.clone(),
+        drift_source: format!("{}:{}", ep.file, ep.line),
+    }
+}
+
+fn unique_id(seen: &mut BTreeSet<String>, method: &str, path: &str) -> String {
+    let mut base = method.to_string();
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        let word: String = segment
+            .trim_matches(|c| c == '{' || c == '}')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        base.push('_');
+        if segment.starts_with('{') {
+            base.push_str("by_");
+        }
+        base.push_str(&word);
+    }
+    let mut id = base.clone();
+    let mut n = 2;
+    while !seen.insert(id.clone()) {
+        id = format!("{base}_{n}");
+        n += 1;
+    }
+    id
+}
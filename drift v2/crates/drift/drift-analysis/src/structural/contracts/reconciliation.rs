@@ -0,0 +1,512 @@
+//! Spec ↔ implementation reconciliation.
+//!
+//! Compares contracts declared in schema files (OpenAPI, GraphQL, Protobuf,
+//! AsyncAPI) against contracts recovered from code by the framework
+//! extractors, and reports drift: undocumented endpoints, documented
+//! endpoints with no implementation, and field type/required/nullable
+//! mismatches. Results convert to enforcement `Violation`s or a
+//! `ConstraintInput` for the constraint verification gate.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::matching::{is_array_type, types_compatible};
+use super::openapi_emitter::json_type;
+use super::types::*;
+use crate::enforcement::gates::{ConstraintInput, ConstraintViolationInput};
+use crate::enforcement::rules::{Severity, Violation};
+
+/// Frameworks reported by the schema parsers (i.e. declared, not implemented).
+pub const SPEC_FRAMEWORKS: &[&str] = &["openapi", "graphql", "grpc", "asyncapi"];
+
+/// Whether a contract came from a schema file rather than code.
+pub fn is_spec_contract(contract: &Contract) -> bool {
+    SPEC_FRAMEWORKS.contains(&contract.framework.as_str())
+}
+
+/// Reconciliation options.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReconciliationConfig {
+    /// Compare request fields when both sides declare some.
+    pub check_request_fields: bool,
+    /// Compare response fields when both sides declare some.
+    pub check_response_fields: bool,
+    /// Report implemented fields that the spec does not declare.
+    pub report_undocumented_fields: bool,
+    /// Path prefixes excluded from reconciliation (health checks, admin, …).
+    pub ignore_path_prefixes: Vec<String>,
+}
+
+impl Default for ReconciliationConfig {
+    fn default() -> Self {
+        Self {
+            check_request_fields: true,
+            check_response_fields: true,
+            report_undocumented_fields: true,
+            ignore_path_prefixes: Vec::new(),
+        }
+    }
+}
+
+/// Kind of spec drift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftKind {
+    /// Implemented endpoint absent from the spec.
+    UndocumentedEndpoint,
+    /// Spec endpoint with no implementation.
+    MissingImplementation,
+    /// Spec field the implementation does not handle.
+    FieldNotImplemented,
+    /// Implemented field absent from the spec.
+    FieldUndocumented,
+    /// Field types disagree.
+    TypeMismatch,
+    /// Field is required on one side and optional on the other.
+    RequiredMismatch,
+    /// Implementation may return null where the spec says it cannot.
+    NullableMismatch,
+}
+
+impl DriftKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UndocumentedEndpoint => "undocumented-endpoint",
+            Self::MissingImplementation => "missing-implementation",
+            Self::FieldNotImplemented => "field-not-implemented",
+            Self::FieldUndocumented => "field-undocumented",
+            Self::TypeMismatch => "type-mismatch",
+            Self::RequiredMismatch => "required-mismatch",
+            Self::NullableMismatch => "nullable-mismatch",
+        }
+    }
+}
+
+/// Which side of the exchange a field belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldLocation {
+    Request,
+    Response,
+}
+
+/// A single spec/implementation disagreement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecDrift {
+    pub kind: DriftKind,
+    pub severity: MismatchSeverity,
+    pub method: String,
+    /// Path as written in the spec (or the code, for undocumented endpoints).
+    pub path: String,
+    pub field: Option<String>,
+    pub location: Option<FieldLocation>,
+    /// Where to report it: the code for implemented endpoints, else the spec.
+    pub file: String,
+    pub line: u32,
+    pub message: String,
+}
+
+/// Result of reconciling a spec against the code.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    pub drifts: Vec<SpecDrift>,
+    pub spec_endpoints: usize,
+    pub implemented_endpoints: usize,
+    /// Spec endpoints with a matching implementation.
+    pub matched_endpoints: usize,
+    /// Paradigms declared in the spec but with no implemented contracts to
+    /// compare against (e.g. GraphQL with no resolver extractor).
+    pub unchecked_paradigms: Vec<Paradigm>,
+}
+
+impl ReconciliationReport {
+    /// Fraction of spec endpoints that are implemented.
+    pub fn coverage(&self) -> f64 {
+        if self.spec_endpoints == 0 {
+            1.0
+        } else {
+            self.matched_endpoints as f64 / self.spec_endpoints as f64
+        }
+    }
+
+    pub fn count(&self, kind: DriftKind) -> usize {
+        self.drifts.iter().filter(|d| d.kind == kind).count()
+    }
+
+    /// Enforcement violations, one per drift (`rule_id = contracts/<kind>`).
+    /// Ids carry the endpoint and field: spec parsers report every endpoint
+    /// at line 0, so file and line alone would collide.
+    pub fn to_violations(&self) -> Vec<Violation> {
+        self.drifts
+            .iter()
+            .map(|d| {
+                let rule_id = format!("contracts/{}", d.kind.name());
+                Violation {
+                    id: violation_id(&rule_id, d),
+                    file: d.file.clone(),
+                    line: d.line,
+                    column: None,
+                    end_line: None,
+                    end_column: None,
+                    severity: violation_severity(d.severity),
+                    pattern_id: "contract-reconciliation".to_string(),
+                    rule_id,
+                    message: d.message.clone(),
+                    quick_fix: None,
+                    cwe_id: None,
+                    owasp_category: None,
+                    suppressed: false,
+                    is_new: false,
+                    owners: Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// A constraint for the constraint verification gate. Drifts below
+    /// `min_severity` are left out (and do not fail the constraint).
+    pub fn to_constraint_input(&self, min_severity: MismatchSeverity) -> ConstraintInput {
+        let violations: Vec<ConstraintViolationInput> = self
+            .drifts
+            .iter()
+            .filter(|d| severity_rank(d.severity) >= severity_rank(min_severity))
+            .map(|d| ConstraintViolationInput {
+                file: d.file.clone(),
+                line: Some(d.line),
+                message: d.message.clone(),
+            })
+            .collect();
+        ConstraintInput {
+            id: "contract-spec-reconciliation".to_string(),
+            description: "Implemented API matches its declared spec".to_string(),
+            passed: violations.is_empty(),
+            violations,
+        }
+    }
+}
+
+fn violation_id(rule_id: &str, drift: &SpecDrift) -> String {
+    let mut id = format!(
+        "{}-{}-{}-{} {}",
+        rule_id, drift.file, drift.line, drift.method, drift.path
+    );
+    if let Some(field) = &drift.field {
+        let side = match drift.location {
+            Some(FieldLocation::Request) => "request.",
+            Some(FieldLocation::Response) => "response.",
+            None => "",
+        };
+        id.push_str(&format!("-{side}{field}"));
+    }
+    id
+}
+
+/// Reconcile declared `spec` contracts against `implemented` contracts.
+///
+/// Endpoints are compared per paradigm, keyed by method and normalized path
+/// (`/users/{id}`, `/users/:id` and `/users/<int:id>` are equal). Field
+/// comparisons only run when both sides declare fields for that side of the
+/// exchange: an extractor that recovered no fields is not evidence of drift.
+pub fn reconcile(
+    spec: &[Contract],
+    implemented: &[Contract],
+    config: &ReconciliationConfig,
+) -> ReconciliationReport {
+    let mut report = ReconciliationReport::default();
+
+    let implemented_paradigms: BTreeSet<&'static str> =
+        implemented.iter().map(|c| c.paradigm.name()).collect();
+    let mut unchecked: BTreeSet<&'static str> = BTreeSet::new();
+
+    let mut spec_eps: Vec<(&Contract, &Endpoint)> = Vec::new();
+    for contract in spec {
+        if !implemented_paradigms.contains(contract.paradigm.name()) {
+            unchecked.insert(contract.paradigm.name());
+            continue;
+        }
+        for ep in &contract.endpoints {
+            if !is_ignored(&ep.path, config) {
+                spec_eps.push((contract, ep));
+            }
+        }
+    }
+    let impl_eps: Vec<(&Contract, &Endpoint)> = implemented
+        .iter()
+        .flat_map(|c| c.endpoints.iter().map(move |ep| (c, ep)))
+        .filter(|(_, ep)| !is_ignored(&ep.path, config))
+        .collect();
+    report.spec_endpoints = spec_eps.len();
+    report.implemented_endpoints = impl_eps.len();
+    report.unchecked_paradigms = Paradigm::all()
+        .iter()
+        .copied()
+        .filter(|p| unchecked.contains(p.name()))
+        .collect();
+
+    let mut impl_by_key: HashMap<(Paradigm, String, String), Vec<&Endpoint>> = HashMap::new();
+    for (contract, ep) in &impl_eps {
+        impl_by_key
+            .entry((contract.paradigm, ep.method.to_uppercase(), normalize_path(&ep.path)))
+            .or_default()
+            .push(ep);
+    }
+
+    let mut used: HashSet<(Paradigm, String, String)> = HashSet::new();
+    for (contract, spec_ep) in &spec_eps {
+        let path = normalize_path(&spec_ep.path);
+        let method = spec_ep.method.to_uppercase();
+        let exact = (contract.paradigm, method.clone(), path.clone());
+        let wildcard = ["ANY", "ALL"].iter().find_map(|m| {
+            let key = (contract.paradigm, m.to_string(), path.clone());
+            impl_by_key.contains_key(&key).then_some(key)
+        });
+        let key = if impl_by_key.contains_key(&exact) {
+            exact
+        } else if let Some(key) = wildcard {
+            key
+        } else {
+            report.drifts.push(SpecDrift {
+                kind: DriftKind::MissingImplementation,
+                severity: MismatchSeverity::High,
+                method: spec_ep.method.clone(),
+                path: spec_ep.path.clone(),
+                field: None,
+                location: None,
+                file: spec_ep.file.clone(),
+                line: spec_ep.line,
+                message: format!(
+                    "{} {} is documented in {} but not implemented",
+                    spec_ep.method, spec_ep.path, contract.source_file
+                ),
+            });
+            continue;
+        };
+
+        report.matched_endpoints += 1;
+        let impl_ep = impl_by_key[&key][0];
+        used.insert(key);
+        if config.check_request_fields {
+            compare_fields(&mut report.drifts, spec_ep, impl_ep, FieldLocation::Request, config);
+        }
+        if config.check_response_fields {
+            compare_fields(&mut report.drifts, spec_ep, impl_ep, FieldLocation::Response, config);
+        }
+    }
+
+    for (contract, ep) in &impl_eps {
+        if spec.iter().all(|s| s.paradigm != contract.paradigm) {
+            // Nothing declared for this paradigm: no spec to be out of date with.
+            continue;
+        }
+        let key = (contract.paradigm, ep.method.to_uppercase(), normalize_path(&ep.path));
+        if used.contains(&key) {
+            continue;
+        }
+        report.drifts.push(SpecDrift {
+            kind: DriftKind::UndocumentedEndpoint,
+            severity: MismatchSeverity::Medium,
+            method: ep.method.clone(),
+            path: ep.path.clone(),
+            field: None,
+            location: None,
+            file: ep.file.clone(),
+            line: ep.line,
+            message: format!(
+                "{} {} ({}) is not documented in the API spec",
+                ep.method, ep.path, contract.framework
+            ),
+        });
+    }
+
+    report
+}
+
+fn compare_fields(
+    drifts: &mut Vec<SpecDrift>,
+    spec_ep: &Endpoint,
+    impl_ep: &Endpoint,
+    location: FieldLocation,
+    config: &ReconciliationConfig,
+) {
+    let (spec_fields, impl_fields) = match location {
+        FieldLocation::Request => (&spec_ep.request_fields, &impl_ep.request_fields),
+        FieldLocation::Response => (&spec_ep.response_fields, &impl_ep.response_fields),
+    };
+    if spec_fields.is_empty() || impl_fields.is_empty() {
+        return;
+    }
+    let side = match location {
+        FieldLocation::Request => "request",
+        FieldLocation::Response => "response",
+    };
+    let mut push = |kind: DriftKind, severity: MismatchSeverity, field: &str, message: String| {
+        drifts.push(SpecDrift {
+            kind,
+            severity,
+            method: spec_ep.method.clone(),
+            path: spec_ep.path.clone(),
+            field: Some(field.to_string()),
+            location: Some(location),
+            file: impl_ep.file.clone(),
+            line: impl_ep.line,
+            message: format!("{} {}: {}", spec_ep.method, spec_ep.path, message),
+        });
+    };
+
+    for spec_field in spec_fields {
+        let Some(impl_field) = impl_fields.iter().find(|f| f.name == spec_field.name) else {
+            // A missing documented response field breaks clients; a missing
+            // optional request field is merely ignored by the server.
+            let severity = match (location, spec_field.required) {
+                (FieldLocation::Response, true) => MismatchSeverity::High,
+                (FieldLocation::Request, true) | (FieldLocation::Response, false) => {
+                    MismatchSeverity::Medium
+                }
+                (FieldLocation::Request, false) => MismatchSeverity::Low,
+            };
+            push(
+                DriftKind::FieldNotImplemented,
+                severity,
+                &spec_field.name,
+                format!("documented {side} field '{}' is not implemented", spec_field.name),
+            );
+            continue;
+        };
+
+        if !field_types_compatible(&spec_field.field_type, &impl_field.field_type) {
+            let severity = if is_array_type(&spec_field.field_type)
+                != is_array_type(&impl_field.field_type)
+            {
+                MismatchSeverity::Critical
+            } else {
+                MismatchSeverity::High
+            };
+            push(
+                DriftKind::TypeMismatch,
+                severity,
+                &spec_field.name,
+                format!(
+                    "{side} field '{}' is '{}' in the spec but '{}' in code",
+                    spec_field.name, spec_field.field_type, impl_field.field_type
+                ),
+            );
+        }
+
+        if spec_field.required != impl_field.required {
+            // Code demanding a request field the spec calls optional rejects
+            // spec-following clients; code omitting a "required" response field
+            // breaks them.
+            let harmful = match location {
+                FieldLocation::Request => impl_field.required,
+                FieldLocation::Response => spec_field.required,
+            };
+            push(
+                DriftKind::RequiredMismatch,
+                if harmful { MismatchSeverity::Medium } else { MismatchSeverity::Low },
+                &spec_field.name,
+                format!(
+                    "{side} field '{}' is {} in the spec but {} in code",
+                    spec_field.name,
+                    required_word(spec_field.required),
+                    required_word(impl_field.required)
+                ),
+            );
+        }
+
+        if location == FieldLocation::Response && impl_field.nullable && !spec_field.nullable {
+            push(
+                DriftKind::NullableMismatch,
+                MismatchSeverity::Medium,
+                &spec_field.name,
+                format!(
+                    "response field '{}' may be null in code but is non-nullable in the spec",
+                    spec_field.name
+                ),
+            );
+        }
+    }
+
+    if config.report_undocumented_fields {
+        for impl_field in impl_fields {
+            if !spec_fields.iter().any(|f| f.name == impl_field.name) {
+                push(
+                    DriftKind::FieldUndocumented,
+                    MismatchSeverity::Low,
+                    &impl_field.name,
+                    format!("{side} field '{}' is not documented", impl_field.name),
+                );
+            }
+        }
+    }
+}
+
+/// Compare a spec type with a language-level type from code, looking through
+/// nullability wrappers (`Optional[int]`, `string | null`) and collections.
+fn field_types_compatible(spec_type: &str, impl_type: &str) -> bool {
+    if types_compatible(spec_type, impl_type) {
+        return true;
+    }
+    match json_type(impl_type) {
+        // Unknown class names map to `object`; only trust primitives.
+        Some("object") => spec_type.eq_ignore_ascii_case("object"),
+        Some(json) => types_compatible(spec_type, json),
+        None => true,
+    }
+}
+
+/// Normalize a route path for comparison: leading slash, no trailing slash,
+/// lowercase, and every parameter syntax (`{id}`, `:id`, `<int:id>`,
+/// `[id]`, `*`) collapsed to `{}`.
+pub fn normalize_path(path: &str) -> String {
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let is_param = (s.starts_with('{') && s.ends_with('}'))
+                || s.starts_with(':')
+                || (s.starts_with('<') && s.ends_with('>'))
+                || (s.starts_with('[') && s.ends_with(']'))
+                || s == "*";
+            if is_param {
+                "{}".to_string()
+            } else {
+                s.to_lowercase()
+            }
+        })
+        .collect();
+    format!("/{}", segments.join("/"))
+}
+
+fn is_ignored(path: &str, config: &ReconciliationConfig) -> bool {
+    config
+        .ignore_path_prefixes
+        .iter()
+        .any(|prefix| path.starts_with(prefix.as_str()))
+}
+
+fn required_word(required: bool) -> &'static str {
+    if required {
+        "required"
+    } else {
+        "optional"
+    }
+}
+
+fn severity_rank(severity: MismatchSeverity) -> u8 {
+    match severity {
+        MismatchSeverity::Critical => 3,
+        MismatchSeverity::High => 2,
+        MismatchSeverity::Medium => 1,
+        MismatchSeverity::Low => 0,
+    }
+}
+
+pub(crate) fn violation_severity(severity: MismatchSeverity) -> Severity {
+    match severity {
+        MismatchSeverity::Critical | MismatchSeverity::High => Severity::Error,
+        MismatchSeverity::Medium => Severity::Warning,
+        MismatchSeverity::Low => Severity::Info,
+    }
+}
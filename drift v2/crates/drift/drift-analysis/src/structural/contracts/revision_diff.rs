@@ -0,0 +1,414 @@
+//! Cross-revision contract diffing.
+//!
+//! Extracts contracts at two git revisions straight from the object database
+//! (no checkout), then classifies each endpoint's changes with
+//! [`classify_breaking_changes`]. The result can fail a PR that breaks a
+//! public API via [`ContractDiff::to_violations`] or
+//! [`ContractDiff::to_constraint_input`].
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::breaking_changes::classify_breaking_changes;
+use super::extractors::ExtractorRegistry;
+use super::reconciliation::{is_spec_contract, normalize_path, violation_severity};
+use super::schema_parsers::asyncapi::AsyncApiParser;
+use super::schema_parsers::graphql::GraphqlParser;
+use super::schema_parsers::openapi::OpenApiParser;
+use super::schema_parsers::protobuf::ProtobufParser;
+use super::schema_parsers::SchemaParser;
+use super::types::*;
+use crate::enforcement::gates::{ConstraintInput, ConstraintViolationInput};
+use crate::enforcement::rules::Violation;
+use crate::parsers::ParserManager;
+use crate::scanner::language_detect::Language;
+
+/// Options for reading contracts out of a revision.
+#[derive(Debug, Clone)]
+pub struct RevisionDiffConfig {
+    /// Blobs larger than this are skipped.
+    pub max_file_bytes: usize,
+    /// Directory names never descended into.
+    pub exclude_dirs: Vec<String>,
+    /// Only files under these path prefixes are read (empty = whole tree).
+    pub include_prefixes: Vec<String>,
+}
+
+impl Default for RevisionDiffConfig {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 1024 * 1024,
+            exclude_dirs: ["node_modules", "vendor", "target", "dist", "build", ".git"]
+                .iter()
+                .map(|d| d.to_string())
+                .collect(),
+            include_prefixes: Vec::new(),
+        }
+    }
+}
+
+/// Contracts found at one revision.
+#[derive(Debug, Clone)]
+pub struct RevisionContracts {
+    /// Resolved commit id.
+    pub commit: String,
+    pub contracts: Vec<Contract>,
+    pub files_scanned: usize,
+}
+
+/// How an endpoint changed between revisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointStatus {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Changes to one endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointDiff {
+    pub paradigm: Paradigm,
+    pub method: String,
+    pub path: String,
+    /// Location at head (at base for removed endpoints).
+    pub file: String,
+    pub line: u32,
+    pub status: EndpointStatus,
+    /// Classified changes; see [`BreakingChangeType::is_breaking`].
+    pub changes: Vec<BreakingChange>,
+    /// Additive, backwards-compatible changes.
+    pub additions: Vec<String>,
+}
+
+impl EndpointDiff {
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|c| c.change_type.is_breaking())
+    }
+}
+
+/// Contract changes between two revisions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractDiff {
+    pub base: String,
+    pub head: String,
+    /// Changed endpoints only, ordered by path then method.
+    pub endpoints: Vec<EndpointDiff>,
+}
+
+impl ContractDiff {
+    pub fn has_breaking(&self) -> bool {
+        self.endpoints.iter().any(|e| e.is_breaking())
+    }
+
+    pub fn breaking(&self) -> impl Iterator<Item = &EndpointDiff> {
+        self.endpoints.iter().filter(|e| e.is_breaking())
+    }
+
+    pub fn non_breaking(&self) -> impl Iterator<Item = &EndpointDiff> {
+        self.endpoints.iter().filter(|e| !e.is_breaking())
+    }
+
+    /// One violation per breaking change, identified by endpoint, change
+    /// kind and field so several changes to one endpoint stay distinct.
+    pub fn to_violations(&self) -> Vec<Violation> {
+        let rule_id = "contracts/breaking-change";
+        self.breaking_changes()
+            .map(|(ep, change)| Violation {
+                id: format!(
+                    "{}-{}-{}-{} {}-{:?}-{}",
+                    rule_id,
+                    ep.file,
+                    ep.line,
+                    ep.method,
+                    ep.path,
+                    change.change_type,
+                    change.field.as_deref().unwrap_or_default()
+                ),
+                file: ep.file.clone(),
+                line: ep.line,
+                column: None,
+                end_line: None,
+                end_column: None,
+                severity: violation_severity(change.severity),
+                pattern_id: "contract-revision-diff".to_string(),
+                rule_id: rule_id.to_string(),
+                message: format!("{} {}: {}", ep.method, ep.path, change.message),
+                quick_fix: None,
+                cwe_id: None,
+                owasp_category: None,
+                suppressed: false,
+                is_new: true,
+                owners: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// A constraint for the constraint verification gate; fails on any
+    /// breaking change.
+    pub fn to_constraint_input(&self) -> ConstraintInput {
+        let violations: Vec<ConstraintViolationInput> = self
+            .breaking_changes()
+            .map(|(ep, change)| ConstraintViolationInput {
+                file: ep.file.clone(),
+                line: Some(ep.line),
+                message: format!("{} {}: {}", ep.method, ep.path, change.message),
+            })
+            .collect();
+        ConstraintInput {
+            id: "contract-no-breaking-changes".to_string(),
+            description: format!("No breaking API changes between {} and {}", self.base, self.head),
+            passed: violations.is_empty(),
+            violations,
+        }
+    }
+
+    fn breaking_changes(&self) -> impl Iterator<Item = (&EndpointDiff, &BreakingChange)> {
+        self.endpoints.iter().flat_map(|ep| {
+            ep.changes
+                .iter()
+                .filter(|c| c.change_type.is_breaking())
+                .map(move |c| (ep, c))
+        })
+    }
+}
+
+/// Diff the contracts of two revisions of the repository at `repo_path`.
+pub fn diff_revisions(
+    repo_path: &Path,
+    base: &str,
+    head: &str,
+    config: &RevisionDiffConfig,
+) -> Result<ContractDiff, String> {
+    let repo = git2::Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repository: {}", e))?;
+    let old = contracts_at_revision(&repo, base, config)?;
+    let new = contracts_at_revision(&repo, head, config)?;
+    Ok(ContractDiff {
+        base: old.commit,
+        head: new.commit,
+        endpoints: diff_contract_sets(&old.contracts, &new.contracts),
+    })
+}
+
+/// Extract contracts from the tree at `rev` (any revspec git accepts).
+pub fn contracts_at_revision(
+    repo: &git2::Repository,
+    rev: &str,
+    config: &RevisionDiffConfig,
+) -> Result<RevisionContracts, String> {
+    let commit = repo
+        .revparse_single(rev)
+        .and_then(|obj| obj.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve '{}': {}", rev, e))?;
+    let tree = commit
+        .tree()
+        .map_err(|e| format!("Failed to read tree of '{}': {}", rev, e))?;
+
+    let mut blobs = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+        let name = entry.name().unwrap_or("");
+        match entry.kind() {
+            Some(git2::ObjectType::Tree) if config.exclude_dirs.iter().any(|d| d == name) => {
+                git2::TreeWalkResult::Skip
+            }
+            Some(git2::ObjectType::Blob) => {
+                let path = format!("{root}{name}");
+                let included = config.include_prefixes.is_empty()
+                    || config.include_prefixes.iter().any(|p| path.starts_with(p.as_str()));
+                if included && is_candidate(&path) {
+                    blobs.push((path, entry.id()));
+                }
+                git2::TreeWalkResult::Ok
+            }
+            _ => git2::TreeWalkResult::Ok,
+        }
+    })
+    .map_err(|e| format!("Failed to walk tree of '{}': {}", rev, e))?;
+
+    // git2 objects are not Send: read blobs here, extract in parallel below.
+    let mut files = Vec::with_capacity(blobs.len());
+    for (path, oid) in blobs {
+        let Ok(blob) = repo.find_blob(oid) else { continue };
+        if blob.is_binary() || blob.size() > config.max_file_bytes {
+            continue;
+        }
+        if let Ok(content) = std::str::from_utf8(blob.content()) {
+            files.push((path, content.to_string()));
+        }
+    }
+
+    let registry = ExtractorRegistry::new();
+    let parser_manager = ParserManager::new();
+    let contracts = files
+        .par_iter()
+        .flat_map_iter(|(path, content)| extract_file(path, content, &registry, &parser_manager))
+        .collect();
+
+    Ok(RevisionContracts {
+        commit: commit.id().to_string(),
+        contracts,
+        files_scanned: files.len(),
+    })
+}
+
+/// Diff two contract sets endpoint by endpoint. Consumer (frontend)
+/// contracts are ignored; spec and code contracts are diffed separately.
+pub fn diff_contract_sets(base: &[Contract], head: &[Contract]) -> Vec<EndpointDiff> {
+    let old = index_endpoints(base);
+    let new = index_endpoints(head);
+    let mut diffs = Vec::new();
+
+    for (key, (paradigm, old_ep)) in &old {
+        if new.contains_key(key) {
+            continue;
+        }
+        let before = single(*paradigm, old_ep.clone());
+        let after = Contract { endpoints: vec![], ..before.clone() };
+        let changes = classify_breaking_changes(&before, &after);
+        diffs.push(endpoint_diff(*paradigm, old_ep, EndpointStatus::Removed, changes, vec![]));
+    }
+
+    for (key, (paradigm, new_ep)) in &new {
+        let Some((_, old_ep)) = old.get(key) else {
+            let additions = vec![format!("{} {} added", new_ep.method, new_ep.path)];
+            diffs.push(endpoint_diff(*paradigm, new_ep, EndpointStatus::Added, vec![], additions));
+            continue;
+        };
+        // Route syntax may differ between revisions (`:id` → `{id}`); the key
+        // already matched them, so compare under one path.
+        let old_aligned = Endpoint { method: new_ep.method.clone(), path: new_ep.path.clone(), ..old_ep.clone() };
+        let changes = classify_breaking_changes(
+            &single(*paradigm, old_aligned),
+            &single(*paradigm, new_ep.clone()),
+        );
+        let additions = additive_changes(old_ep, new_ep);
+        if !changes.is_empty() || !additions.is_empty() {
+            diffs.push(endpoint_diff(*paradigm, new_ep, EndpointStatus::Modified, changes, additions));
+        }
+    }
+
+    diffs.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.method.cmp(&b.method)));
+    diffs
+}
+
+type EndpointKey = (&'static str, bool, String, String);
+
+fn index_endpoints(contracts: &[Contract]) -> BTreeMap<EndpointKey, (Paradigm, Endpoint)> {
+    let mut index = BTreeMap::new();
+    for contract in contracts.iter().filter(|c| c.framework != "frontend") {
+        let spec = is_spec_contract(contract);
+        for ep in &contract.endpoints {
+            let key = (contract.paradigm.name(), spec, ep.method.to_uppercase(), normalize_path(&ep.path));
+            index.entry(key).or_insert_with(|| (contract.paradigm, ep.clone()));
+        }
+    }
+    index
+}
+
+fn single(paradigm: Paradigm, endpoint: Endpoint) -> Contract {
+    Contract {
+        id: String::new(),
+        paradigm,
+        source_file: endpoint.file.clone(),
+        endpoints: vec![endpoint],
+        framework: String::new(),
+        confidence: 1.0,
+    }
+}
+
+fn endpoint_diff(
+    paradigm: Paradigm,
+    ep: &Endpoint,
+    status: EndpointStatus,
+    changes: Vec<BreakingChange>,
+    additions: Vec<String>,
+) -> EndpointDiff {
+    EndpointDiff {
+        paradigm,
+        method: ep.method.clone(),
+        path: ep.path.clone(),
+        file: ep.file.clone(),
+        line: ep.line,
+        status,
+        changes,
+        additions,
+    }
+}
+
+fn additive_changes(old: &Endpoint, new: &Endpoint) -> Vec<String> {
+    let mut additions = Vec::new();
+    for field in &new.response_fields {
+        if !old.response_fields.iter().any(|f| f.name == field.name) {
+            additions.push(format!("Field '{}' added to response", field.name));
+        }
+    }
+    for field in &new.request_fields {
+        match old.request_fields.iter().find(|f| f.name == field.name) {
+            None if !field.required => {
+                additions.push(format!("Optional field '{}' added to request", field.name));
+            }
+            Some(old_field) if old_field.required && !field.required => {
+                additions.push(format!("Request field '{}' is no longer required", field.name));
+            }
+            _ => {}
+        }
+    }
+    additions
+}
+
+/// Whether a path could hold a contract: a parseable source file or a
+/// schema file.
+fn is_candidate(path: &str) -> bool {
+    let ext = Path::new(path).extension().and_then(|e| e.to_str());
+    Language::from_extension(ext).is_some()
+        || matches!(ext, Some("yaml" | "yml" | "json" | "graphql" | "gql" | "proto"))
+}
+
+fn extract_file(
+    path: &str,
+    content: &str,
+    registry: &ExtractorRegistry,
+    parser_manager: &ParserManager,
+) -> Vec<Contract> {
+    let ext = Path::new(path).extension().and_then(|e| e.to_str()).unwrap_or("");
+    let mut contracts = Vec::new();
+
+    // JSON/YAML is mostly config; only parse documents that declare a spec.
+    let declares_spec = !matches!(ext, "yaml" | "yml" | "json")
+        || ["openapi", "swagger", "asyncapi"].iter().any(|k| content.contains(k));
+    if declares_spec {
+        let parsers: [&dyn SchemaParser; 4] =
+            [&OpenApiParser, &AsyncApiParser, &GraphqlParser, &ProtobufParser];
+        for parser in parsers {
+            if parser.extensions().contains(&ext) {
+                contracts.extend(parser.parse(content, path));
+            }
+        }
+    }
+
+    if Language::from_extension(Some(ext)).is_some() {
+        let parse_result = parser_manager.parse(content.as_bytes(), Path::new(path)).ok();
+        for (framework, endpoints) in
+            registry.extract_all_with_context(content, path, parse_result.as_ref())
+        {
+            let paradigm = if framework == "trpc" { Paradigm::Trpc } else { Paradigm::Rest };
+            let has_fields = endpoints
+                .iter()
+                .any(|ep| !ep.request_fields.is_empty() || !ep.response_fields.is_empty());
+            contracts.push(Contract {
+                id: format!("{}:{}", path, framework),
+                paradigm,
+                endpoints,
+                source_file: path.to_string(),
+                framework,
+                confidence: if has_fields { 0.9 } else { 0.6 },
+            });
+        }
+    }
+
+    contracts
+}
@@ -79,18 +79,17 @@ fn extract_request_fields(operation: &serde_json::Value, root: &serde_json::Valu
             let resolved = resolve_ref(param, root);
             let name = resolved.get("name").and_then(|n| n.as_str()).unwrap_or("");
             let required = resolved.get("required").and_then(|r| r.as_bool()).unwrap_or(false);
-            let field_type = resolved
+            let (field_type, nullable) = resolved
                 .get("schema")
-                .and_then(|s| s.get("type"))
-                .and_then(|t| t.as_str())
-                .unwrap_or("string");
+                .and_then(schema_type)
+                .unwrap_or_else(|| ("string".to_string(), false));
 
             if !name.is_empty() {
                 fields.push(FieldSpec {
                     name: name.to_string(),
-                    field_type: field_type.to_string(),
+                    field_type,
                     required,
-                    nullable: false,
+                    nullable,
                 });
             }
         }
@@ -151,6 +150,26 @@ fn resolve_ref<'a>(value: &'a serde_json::Value, root: &'a serde_json::Value) ->
     std::borrow::Cow::Borrowed(value)
 }
 
+/// A schema's `type`, accepting the 3.1 array form (`["string", "null"]`).
+/// Returns the non-null type and whether `null` was listed.
+fn schema_type(schema: &serde_json::Value) -> Option<(String, bool)> {
+    match schema.get("type")? {
+        serde_json::Value::String(t) => Some((t.clone(), false)),
+        serde_json::Value::Array(types) => {
+            let names: Vec<&str> = types.iter().filter_map(|t| t.as_str()).collect();
+            let nullable = names.contains(&"null");
+            let non_null: Vec<&str> = names.into_iter().filter(|t| *t != "null").collect();
+            let field_type = match non_null.as_slice() {
+                [single] => single.to_string(),
+                [] => "null".to_string(),
+                _ => "any".to_string(),
+            };
+            Some((field_type, nullable))
+        }
+        _ => None,
+    }
+}
+
 fn extract_schema_fields(schema: &serde_json::Value, fields: &mut Vec<FieldSpec>, root: &serde_json::Value) {
     // CE-OA-02: Handle allOf/oneOf/anyOf composed schemas.
     for compose_key in &["allOf", "oneOf", "anyOf"] {
@@ -178,15 +197,13 @@ fn extract_schema_fields(schema: &serde_json::Value, fields: &mut Vec<FieldSpec>
         for (name, prop) in properties {
             // CE-OA-01: Resolve $ref on individual property schemas.
             let resolved_prop = resolve_ref(prop, root);
-            let field_type = resolved_prop
-                .get("type")
-                .and_then(|t| t.as_str())
-                .unwrap_or("object")
-                .to_string();
-            let nullable = resolved_prop
-                .get("nullable")
-                .and_then(|n| n.as_bool())
-                .unwrap_or(false);
+            let (field_type, type_nullable) = schema_type(&resolved_prop)
+                .unwrap_or_else(|| ("object".to_string(), false));
+            let nullable = type_nullable
+                || resolved_prop
+                    .get("nullable")
+                    .and_then(|n| n.as_bool())
+                    .unwrap_or(false);
 
             // Avoid duplicate fields from composed schemas.
             if !fields.iter().any(|f| f.name == *name) {
@@ -80,6 +80,25 @@ pub fn decompose_with_priors(
     modules
 }
 
+/// Record each module's duplicated share from per-file clone coverage.
+///
+/// High duplication inside a module suggests extracting a shared helper;
+/// clones spread across modules suggest a missing shared module.
+pub fn apply_duplication(
+    modules: &mut [LogicalModule],
+    files: &[crate::structural::clones::FileDuplication],
+) {
+    let by_file: FxHashMap<&str, &crate::structural::clones::FileDuplication> =
+        files.iter().map(|f| (f.file.as_str(), f)).collect();
+    for module in modules {
+        let (duplicated, total) = module.files.iter()
+            .filter_map(|f| by_file.get(f.as_str()))
+            .fold((0u64, 0u64), |(d, t), f| (d + f.duplicated_lines as u64, t + f.total_lines as u64));
+        let total = total.max(module.estimated_complexity);
+        module.duplication = if total == 0 { 0.0 } else { duplicated as f64 / total as f64 * 100.0 };
+    }
+}
+
 /// Cluster files by directory structure (Signal 1).
 fn cluster_by_directory(input: &DecompositionInput) -> Vec<LogicalModule> {
     let mut dir_groups: FxHashMap<String, Vec<String>> = FxHashMap::default();
@@ -108,6 +127,7 @@ fn cluster_by_directory(input: &DecompositionInput) -> Vec<LogicalModule> {
             coupling: 0.0,
             estimated_complexity: 0,
             applied_priors: Vec::new(),
+            duplication: 0.0,
         }
     }).collect()
 }
@@ -337,6 +357,7 @@ fn apply_split(
             coupling: 0.0,
             estimated_complexity: 0,
             applied_priors: vec![applied_prior.clone()],
+            duplication: 0.0,
         });
     }
 }
@@ -391,6 +412,7 @@ fn apply_merge(
         coupling: 0.0,
         estimated_complexity: 0,
         applied_priors: vec![applied_prior],
+        duplication: 0.0,
     });
 }
 
@@ -7,4 +7,4 @@ pub mod types;
 pub mod decomposer;
 
 pub use types::*;
-pub use decomposer::decompose_with_priors;
+pub use decomposer::{apply_duplication, decompose_with_priors};
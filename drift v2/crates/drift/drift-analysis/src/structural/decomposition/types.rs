@@ -62,6 +62,9 @@ pub struct LogicalModule {
     pub estimated_complexity: u64,
     /// Applied priors (if any).
     pub applied_priors: Vec<AppliedPrior>,
+    /// Share of the module's lines inside code clones (0-100).
+    #[serde(default)]
+    pub duplication: f64,
 }
 
 /// A data dependency of a module.
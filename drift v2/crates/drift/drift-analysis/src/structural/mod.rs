@@ -1,7 +1,8 @@
 //! Structural intelligence systems (Phase 5).
 //!
-//! Nine systems providing architecture health, contract verification,
-//! the capstone DNA metric, and security enrichment.
+//! Eleven systems providing architecture health, contract verification,
+//! function complexity, code clones, the capstone DNA metric, and security
+//! enrichment.
 
 pub mod coupling;
 pub mod constraints;
@@ -12,3 +13,13 @@ pub mod dna;
 pub mod owasp_cwe;
 pub mod crypto;
 pub mod decomposition;
+pub mod complexity;
+pub mod clones;
+
+/// The module a file belongs to: its parent directory, or `.` at the root.
+pub fn module_of(file: &str) -> &str {
+    match file.rfind('/') {
+        Some(i) => &file[..i],
+        None => ".",
+    }
+}
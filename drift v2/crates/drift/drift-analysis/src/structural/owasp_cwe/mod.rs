@@ -5,6 +5,7 @@ pub mod registry;
 pub mod enrichment;
 pub mod wrapper_bridge;
 pub mod posture;
+pub mod vulnerable_components;
 
 pub use types::*;
 pub use registry::CweOwaspRegistry;
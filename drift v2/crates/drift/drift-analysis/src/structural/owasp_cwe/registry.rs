@@ -124,6 +124,7 @@ pub fn lookup_cwe(id: u32) -> CweEntry {
         1321 => CweEntry::new(1321, "Prototype Pollution", "The product receives input from an upstream component that specifies attributes that are to be initialized or updated in an object"),
         1333 => CweEntry::new(1333, "Inefficient Regular Expression", "The product uses a regular expression with an inefficient, possibly exponential worst-case computational complexity"),
         1336 => CweEntry::new(1336, "Template Injection", "The product uses a template engine to insert or process externally-influenced input"),
+        1395 => CweEntry::new(1395, "Dependency on Vulnerable Third-Party Component", "The product has a dependency on a third-party component that contains one or more known vulnerabilities"),
         _ => CweEntry::new(id, &format!("CWE-{}", id), "See MITRE CWE database for details"),
     }
 }
@@ -201,4 +202,7 @@ static DETECTOR_MAPPINGS: &[DetectorMapping] = &[
 
     // Integrity
     DetectorMapping { detector_id: "integrity-check", detector_name: "Missing Integrity Check", cwes: &[345], owasp: &[OwaspCategory::A08IntegrityFailures] },
+
+    // Third-party components
+    DetectorMapping { detector_id: "vulnerable-dependency", detector_name: "Vulnerable Dependency", cwes: &[1395], owasp: &[OwaspCategory::A06VulnerableComponents] },
 ];
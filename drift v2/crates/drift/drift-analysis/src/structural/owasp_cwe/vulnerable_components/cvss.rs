@@ -0,0 +1,74 @@
+//! CVSS v3.x base score from a vector string.
+
+/// Base score (0.0–10.0) of a `CVSS:3.0/...` or `CVSS:3.1/...` vector.
+/// Other versions return `None`.
+pub fn base_score(vector: &str) -> Option<f64> {
+    let mut metrics = vector.split('/');
+    if !matches!(metrics.next()?, "CVSS:3.0" | "CVSS:3.1") {
+        return None;
+    }
+    let mut get = std::collections::HashMap::new();
+    for metric in metrics {
+        let (key, value) = metric.split_once(':')?;
+        get.insert(key, value);
+    }
+    let changed = match *get.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let av = match *get.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match *get.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let pr = match (*get.get("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match *get.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let cia = |key: &str| match *get.get(key)? {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    };
+    let iss = 1.0 - (1.0 - cia("C")?) * (1.0 - cia("I")?) * (1.0 - cia("A")?);
+    let impact = if changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02_f64).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let score = if changed { 1.08 * (impact + exploitability) } else { impact + exploitability };
+    Some(round_up(score.min(10.0)))
+}
+
+/// CVSS v3.1 `Roundup`: smallest one-decimal value >= `value`, robust to
+/// floating-point noise.
+fn round_up(value: f64) -> f64 {
+    let int_input = (value * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        ((int_input / 10_000) + 1) as f64 / 10.0
+    }
+}
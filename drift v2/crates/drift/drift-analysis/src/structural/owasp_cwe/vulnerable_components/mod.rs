@@ -0,0 +1,171 @@
+//! Vulnerable components (OWASP A06) — offline matching of resolved
+//! dependencies against a local OSV database dump, with call-graph
+//! reachability of the vulnerable functions.
+//!
+//! No network access: the OSV dump is mirrored ahead of time (e.g. the
+//! per-ecosystem `all.zip` exports from osv.dev, extracted) and pointed at
+//! by `analysis.osv_database`.
+
+pub mod cvss;
+pub mod osv;
+pub mod reachability;
+pub mod version;
+
+use serde::{Deserialize, Serialize};
+
+use crate::call_graph::types::CallGraph;
+use crate::parsers::types::ParseResult;
+
+use super::registry::lookup_cwe;
+use super::types::{OwaspCategory, SecurityFinding};
+
+pub use osv::OsvDatabase;
+pub use reachability::{CallSiteEvidence, Reachability};
+
+/// CWE-1395: Dependency on Vulnerable Third-Party Component.
+pub const VULNERABLE_DEPENDENCY_CWE: u32 = 1395;
+
+/// A resolved dependency to check, as produced by the package inventory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyInput {
+    /// Workspace package that depends on it.
+    pub package: String,
+    /// Directory of that package, relative to the project root.
+    pub package_path: String,
+    /// Lockfile the version was resolved from, relative to the project root.
+    pub lockfile: Option<String>,
+    /// OSV ecosystem (`npm`, `PyPI`, `crates.io`, `Go`, `Maven`, ...).
+    pub ecosystem: String,
+    pub name: String,
+    pub version: String,
+    pub purl: String,
+    pub direct: bool,
+    pub dev: bool,
+}
+
+/// A dependency version affected by an advisory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VulnerableDependency {
+    pub dependency: DependencyInput,
+    /// OSV id (`GHSA-...`, `RUSTSEC-...`, `GO-...`).
+    pub advisory_id: String,
+    /// CVE and other aliases.
+    pub aliases: Vec<String>,
+    pub summary: String,
+    /// 0–10, from the advisory's CVSS vector or severity label.
+    pub severity: f64,
+    pub cwe_ids: Vec<u32>,
+    /// Versions that fix the advisory, lowest first.
+    pub fixed_versions: Vec<String>,
+    /// Vulnerable functions named by the advisory, if any.
+    pub vulnerable_symbols: Vec<String>,
+    pub reachability: Reachability,
+    pub call_sites: Vec<CallSiteEvidence>,
+}
+
+impl VulnerableDependency {
+    /// File to attach findings to: the lockfile, else the package directory.
+    pub fn location(&self) -> String {
+        let dep = &self.dependency;
+        dep.lockfile.clone().unwrap_or_else(|| dep.package_path.clone())
+    }
+
+    /// Severity adjusted for how the dependency is used: dev-only and
+    /// provably unused vulnerabilities are capped at low.
+    pub fn effective_severity(&self) -> f64 {
+        if self.dependency.dev || self.reachability.is_mitigated() {
+            self.severity.min(3.9)
+        } else {
+            self.severity
+        }
+    }
+}
+
+/// Match dependencies against the database. Reachability starts as
+/// [`Reachability::Unknown`] until [`assess_reachability`] runs.
+pub fn match_dependencies(db: &OsvDatabase, deps: &[DependencyInput]) -> Vec<VulnerableDependency> {
+    let mut matches = Vec::new();
+    for dep in deps {
+        for (record, affected) in db.advisories(&dep.ecosystem, &dep.name) {
+            if !affected.affects(&dep.ecosystem, &dep.version) {
+                continue;
+            }
+            matches.push(VulnerableDependency {
+                dependency: dep.clone(),
+                advisory_id: record.id.clone(),
+                aliases: record.aliases.clone(),
+                summary: record.summary.clone().unwrap_or_default(),
+                severity: record.severity_score(affected),
+                cwe_ids: record.cwe_ids(),
+                fixed_versions: affected.fixed_versions(&dep.ecosystem, &dep.version),
+                vulnerable_symbols: affected.symbols(),
+                reachability: Reachability::Unknown,
+                call_sites: Vec::new(),
+            });
+        }
+    }
+    matches
+}
+
+/// Classify each match by import and call-graph reachability.
+pub fn assess_reachability(vulns: &mut [VulnerableDependency], parse_results: &[ParseResult], call_graph: &CallGraph) {
+    let reachable = reachability::reachable_from_entry_points(call_graph);
+    for vuln in vulns {
+        let dep = &vuln.dependency;
+        let (reachability, call_sites) = reachability::classify(
+            &dep.ecosystem,
+            &dep.name,
+            dep.direct,
+            &vuln.vulnerable_symbols,
+            parse_results,
+            call_graph,
+            &reachable,
+        );
+        vuln.reachability = reachability;
+        vuln.call_sites = call_sites;
+    }
+}
+
+/// Enrich matches into security findings for the OWASP/CWE pipeline.
+pub fn to_security_findings(vulns: &[VulnerableDependency]) -> Vec<SecurityFinding> {
+    vulns
+        .iter()
+        .map(|v| {
+            let dep = &v.dependency;
+            let file = v.location();
+            let mut cwes = vec![lookup_cwe(VULNERABLE_DEPENDENCY_CWE)];
+            cwes.extend(v.cwe_ids.iter().map(|&id| lookup_cwe(id)));
+            let ids = std::iter::once(v.advisory_id.as_str())
+                .chain(v.aliases.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(", ");
+            SecurityFinding {
+                id: format!("vulnerable-dependency:{}:{}@{}:{}", file, dep.name, dep.version, v.advisory_id),
+                detector: "vulnerable-dependency".to_string(),
+                file,
+                line: 0,
+                description: format!(
+                    "{}@{} ({}): {} [{}]",
+                    dep.name,
+                    dep.version,
+                    ids,
+                    if v.summary.is_empty() { "known vulnerability" } else { &v.summary },
+                    v.reachability.name()
+                ),
+                severity: v.effective_severity(),
+                cwes,
+                owasp_categories: vec![OwaspCategory::A06VulnerableComponents],
+                confidence: match v.reachability {
+                    Reachability::Reachable => 0.95,
+                    Reachability::Imported => 0.8,
+                    Reachability::Unknown => 0.6,
+                    Reachability::Unreachable | Reachability::NotImported => 0.4,
+                },
+                remediation: Some(match v.fixed_versions.first() {
+                    Some(fixed) => format!("Upgrade {} to {} or later", dep.name, fixed),
+                    None => format!("No fixed version of {} is available; replace or isolate it", dep.name),
+                }),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,307 @@
+//! Local OSV database — advisories loaded from a mirrored dump of
+//! OSV-format JSON records (one record per file, as in the extracted
+//! `<ecosystem>/all.zip` exports, or arrays of records).
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{cvss, version};
+
+/// One OSV advisory.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OsvRecord {
+    pub id: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>,
+    #[serde(default)]
+    pub withdrawn: Option<String>,
+    #[serde(default)]
+    pub severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    pub affected: Vec<OsvAffected>,
+    #[serde(default)]
+    pub database_specific: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OsvSeverity {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub score: String,
+}
+
+/// A package an advisory affects, with the affected version ranges.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OsvAffected {
+    #[serde(default)]
+    pub package: Option<OsvPackage>,
+    #[serde(default)]
+    pub severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    pub ranges: Vec<OsvRange>,
+    #[serde(default)]
+    pub versions: Vec<String>,
+    #[serde(default)]
+    pub ecosystem_specific: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OsvPackage {
+    pub ecosystem: String,
+    pub name: String,
+    #[serde(default)]
+    pub purl: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OsvRange {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub events: Vec<OsvEvent>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OsvEvent {
+    #[serde(default)]
+    pub introduced: Option<String>,
+    #[serde(default)]
+    pub fixed: Option<String>,
+    #[serde(default)]
+    pub last_affected: Option<String>,
+    #[serde(default)]
+    pub limit: Option<String>,
+}
+
+impl OsvRecord {
+    /// CVE and GHSA identifiers: the record id and its aliases.
+    pub fn identifiers(&self) -> Vec<String> {
+        std::iter::once(&self.id).chain(&self.aliases).cloned().collect()
+    }
+
+    /// Severity on a 0–10 scale: the highest CVSS v3 base score, else the
+    /// GitHub severity label, else 5.0.
+    pub fn severity_score(&self, affected: &OsvAffected) -> f64 {
+        let cvss = self
+            .severity
+            .iter()
+            .chain(&affected.severity)
+            .filter_map(|s| cvss::base_score(&s.score))
+            .fold(None, |max: Option<f64>, s| Some(max.map_or(s, |m| m.max(s))));
+        if let Some(score) = cvss {
+            return score;
+        }
+        let label = self.database_specific.as_ref().and_then(|d| d.get("severity")).and_then(Value::as_str);
+        match label.map(str::to_ascii_uppercase).as_deref() {
+            Some("CRITICAL") => 9.0,
+            Some("HIGH") => 7.5,
+            Some("MODERATE") | Some("MEDIUM") => 5.5,
+            Some("LOW") => 2.5,
+            _ => 5.0,
+        }
+    }
+
+    /// CWE ids from `database_specific.cwe_ids` (`["CWE-79"]`).
+    pub fn cwe_ids(&self) -> Vec<u32> {
+        self.database_specific
+            .as_ref()
+            .and_then(|d| d.get("cwe_ids"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| id.as_str()?.trim_start_matches("CWE-").parse().ok())
+            .collect()
+    }
+}
+
+impl OsvAffected {
+    /// Whether `version` of this package falls in an affected range or
+    /// the explicit affected version list. Git commit ranges are ignored.
+    pub fn affects(&self, ecosystem: &str, version: &str) -> bool {
+        if self.versions.iter().any(|v| v == version) {
+            return true;
+        }
+        self.ranges
+            .iter()
+            .filter(|r| r.kind != "GIT")
+            .any(|range| range_affects(ecosystem, range, version))
+    }
+
+    /// Fixed versions newer than `version`, lowest first.
+    pub fn fixed_versions(&self, ecosystem: &str, version: &str) -> Vec<String> {
+        let mut fixed: Vec<String> = self
+            .ranges
+            .iter()
+            .filter(|r| r.kind != "GIT")
+            .flat_map(|r| r.events.iter().filter_map(|e| e.fixed.clone()))
+            .filter(|f| version::compare(ecosystem, f, version) == Ordering::Greater)
+            .collect();
+        fixed.sort_by(|a, b| version::compare(ecosystem, a, b));
+        fixed.dedup();
+        fixed
+    }
+
+    /// Vulnerable functions named by the advisory: Go's
+    /// `ecosystem_specific.imports[].symbols` (qualified by import path)
+    /// and RustSec's `ecosystem_specific.affects.functions`.
+    pub fn symbols(&self) -> Vec<String> {
+        let Some(specific) = &self.ecosystem_specific else { return Vec::new() };
+        let mut symbols = Vec::new();
+        for import in specific.get("imports").and_then(Value::as_array).into_iter().flatten() {
+            let path = import.get("path").and_then(Value::as_str).unwrap_or_default();
+            for symbol in import.get("symbols").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+                symbols.push(if path.is_empty() { symbol.to_string() } else { format!("{path}.{symbol}") });
+            }
+        }
+        let functions = specific.get("affects").and_then(|a| a.get("functions")).or_else(|| specific.get("affected_functions"));
+        symbols.extend(functions.and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str).map(str::to_string));
+        symbols
+    }
+}
+
+/// Evaluate one range: events in version order, `introduced` opening and
+/// `fixed` / `last_affected` / `limit` closing the affected interval.
+fn range_affects(ecosystem: &str, range: &OsvRange, version: &str) -> bool {
+    let key = |e: &OsvEvent| -> Option<String> {
+        e.introduced.clone().or_else(|| e.fixed.clone()).or_else(|| e.last_affected.clone()).or_else(|| e.limit.clone())
+    };
+    let mut events: Vec<&OsvEvent> = range.events.iter().filter(|e| key(e).is_some()).collect();
+    events.sort_by(|a, b| {
+        let (a, b) = (key(a).unwrap_or_default(), key(b).unwrap_or_default());
+        match (a.as_str(), b.as_str()) {
+            ("0", "0") => Ordering::Equal,
+            ("0", _) => Ordering::Less,
+            (_, "0") => Ordering::Greater,
+            (a, b) => version::compare(ecosystem, a, b),
+        }
+    });
+
+    let mut affected = false;
+    for event in events {
+        if let Some(introduced) = &event.introduced {
+            if introduced == "0" || version::compare(ecosystem, version, introduced) != Ordering::Less {
+                affected = true;
+            }
+        } else if let Some(fixed) = event.fixed.as_ref().or(event.limit.as_ref()) {
+            if version::compare(ecosystem, version, fixed) != Ordering::Less {
+                affected = false;
+            }
+        } else if let Some(last) = &event.last_affected {
+            if version::compare(ecosystem, version, last) == Ordering::Greater {
+                affected = false;
+            }
+        }
+    }
+    affected
+}
+
+/// Package name as OSV keys it: PEP 503 for PyPI, lowercase for
+/// case-insensitive registries.
+pub fn normalize_name(ecosystem: &str, name: &str) -> String {
+    match ecosystem {
+        "PyPI" => {
+            let mut out = String::with_capacity(name.len());
+            for c in name.chars().map(|c| c.to_ascii_lowercase()) {
+                if matches!(c, '-' | '_' | '.') {
+                    if !out.ends_with('-') {
+                        out.push('-');
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        "Packagist" | "NuGet" => name.to_ascii_lowercase(),
+        _ => name.to_string(),
+    }
+}
+
+/// Advisories indexed by (ecosystem, normalized package name).
+#[derive(Debug, Default)]
+pub struct OsvDatabase {
+    records: Vec<OsvRecord>,
+    index: HashMap<(String, String), Vec<(usize, usize)>>,
+}
+
+impl OsvDatabase {
+    pub fn from_records(records: Vec<OsvRecord>) -> Self {
+        let mut db = Self::default();
+        for record in records.into_iter().filter(|r| r.withdrawn.is_none()) {
+            let record_index = db.records.len();
+            for (affected_index, affected) in record.affected.iter().enumerate() {
+                let Some(package) = &affected.package else { continue };
+                // `Debian:11` → `Debian`
+                let ecosystem = package.ecosystem.split(':').next().unwrap_or_default().to_string();
+                let name = normalize_name(&ecosystem, &package.name);
+                db.index.entry((ecosystem, name)).or_default().push((record_index, affected_index));
+            }
+            db.records.push(record);
+        }
+        db
+    }
+
+    /// Load every `*.json` file under `path` (or `path` itself if it is a
+    /// file). Files that aren't OSV records are skipped with a warning.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut files = Vec::new();
+        collect_json_files(path, &mut files).map_err(|e| format!("{}: {e}", path.display()))?;
+        let mut records = Vec::new();
+        for file in files {
+            let parsed = std::fs::read_to_string(&file)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()))
+                .and_then(|value| match value {
+                    Value::Array(items) => items.into_iter().map(serde_json::from_value).collect::<Result<Vec<OsvRecord>, _>>().map_err(|e| e.to_string()),
+                    value => serde_json::from_value(value).map(|r| vec![r]).map_err(|e| e.to_string()),
+                });
+            match parsed {
+                Ok(parsed) => records.extend(parsed),
+                Err(e) => tracing::warn!(file = %file.display(), error = %e, "skipping unreadable OSV record"),
+            }
+        }
+        Ok(Self::from_records(records))
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Advisories affecting any version of `name` in `ecosystem`.
+    pub fn advisories<'a>(&'a self, ecosystem: &str, name: &str) -> impl Iterator<Item = (&'a OsvRecord, &'a OsvAffected)> + 'a {
+        self.index
+            .get(&(ecosystem.to_string(), normalize_name(ecosystem, name)))
+            .into_iter()
+            .flatten()
+            .map(|&(r, a)| (&self.records[r], &self.records[r].affected[a]))
+    }
+}
+
+fn collect_json_files(path: &Path, out: &mut Vec<std::path::PathBuf>) -> std::io::Result<()> {
+    if path.is_file() {
+        out.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries: Vec<_> = std::fs::read_dir(path)?.filter_map(Result::ok).map(|e| e.path()).collect();
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            collect_json_files(&entry, out)?;
+        } else if entry.extension().is_some_and(|ext| ext == "json") {
+            out.push(entry);
+        }
+    }
+    Ok(())
+}
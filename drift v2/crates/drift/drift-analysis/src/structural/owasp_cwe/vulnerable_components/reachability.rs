@@ -0,0 +1,175 @@
+//! Reachability of vulnerable dependencies — is the package imported, and
+//! is a vulnerable function called from code an entry point can reach?
+
+use std::collections::VecDeque;
+
+use drift_core::types::collections::FxHashSet;
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
+use serde::{Deserialize, Serialize};
+
+use crate::call_graph::types::CallGraph;
+use crate::parsers::types::ParseResult;
+
+/// How a vulnerable dependency is used by the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reachability {
+    /// A vulnerable function is called from code reachable from an entry point.
+    Reachable,
+    /// The package is imported, but no vulnerable function is called from
+    /// reachable code.
+    Unreachable,
+    /// The package is imported; the advisory names no vulnerable functions.
+    Imported,
+    /// A direct dependency that no source file imports.
+    NotImported,
+    /// A transitive dependency — its use can't be traced through imports.
+    Unknown,
+}
+
+impl Reachability {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Reachable => "reachable",
+            Self::Unreachable => "unreachable",
+            Self::Imported => "imported",
+            Self::NotImported => "not_imported",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Whether the vulnerable code can't be exercised by the project.
+    pub fn is_mitigated(&self) -> bool {
+        matches!(self, Self::Unreachable | Self::NotImported)
+    }
+}
+
+/// A call to a vulnerable function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallSiteEvidence {
+    pub file: String,
+    pub line: u32,
+    /// Enclosing function, `None` for module-level calls.
+    pub function: Option<String>,
+}
+
+/// Whether the import `source` refers to package `name` of `ecosystem`.
+pub fn import_matches(ecosystem: &str, name: &str, source: &str) -> bool {
+    let prefixed = |root: &str, sep: &str| source == root || source.starts_with(&format!("{root}{sep}"));
+    match ecosystem {
+        "npm" => prefixed(name, "/"),
+        "PyPI" => {
+            let lower = name.to_ascii_lowercase();
+            let module = python_module(&lower).map(str::to_string).unwrap_or_else(|| lower.replace(['-', '.'], "_"));
+            prefixed(&module, ".")
+        }
+        "crates.io" => prefixed(&name.replace('-', "_"), "::"),
+        "Go" => prefixed(name, "/"),
+        // `groupId:artifactId` — code imports the group's packages.
+        "Maven" => {
+            let group = name.split(':').next().unwrap_or(name);
+            prefixed(group, ".")
+        }
+        "RubyGems" => prefixed(name, "/"),
+        // `vendor/package` — PHP namespaces start with the vendor.
+        "Packagist" => {
+            let vendor = name.split('/').next().unwrap_or(name).to_ascii_lowercase();
+            let source = source.trim_start_matches('\\').replace('\\', "/").to_ascii_lowercase();
+            source == vendor || source.starts_with(&format!("{vendor}/"))
+        }
+        "NuGet" => prefixed(name, "."),
+        _ => source == name,
+    }
+}
+
+/// Distributions whose import name differs from the package name.
+fn python_module(distribution: &str) -> Option<&'static str> {
+    Some(match distribution {
+        "pyyaml" => "yaml",
+        "pillow" => "PIL",
+        "beautifulsoup4" => "bs4",
+        "scikit-learn" => "sklearn",
+        "opencv-python" => "cv2",
+        "python-dateutil" => "dateutil",
+        "protobuf" => "google.protobuf",
+        "pyjwt" => "jwt",
+        _ => return None,
+    })
+}
+
+/// Unqualified function name of an advisory symbol
+/// (`golang.org/x/text/language.Parse` → `Parse`, `smallvec::SmallVec::insert_many` → `insert_many`).
+pub fn symbol_name(symbol: &str) -> &str {
+    let symbol = symbol.rsplit("::").next().unwrap_or(symbol);
+    symbol.rsplit(['.', '/']).next().unwrap_or(symbol)
+}
+
+/// Functions reachable from any entry point, entry points included.
+pub fn reachable_from_entry_points(call_graph: &CallGraph) -> FxHashSet<NodeIndex> {
+    let mut visited = FxHashSet::default();
+    let mut queue: VecDeque<NodeIndex> = call_graph
+        .graph
+        .node_indices()
+        .filter(|&idx| call_graph.graph[idx].is_entry_point)
+        .collect();
+    visited.extend(queue.iter().copied());
+    while let Some(node) = queue.pop_front() {
+        for next in call_graph.graph.neighbors_directed(node, Direction::Outgoing) {
+            if visited.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited
+}
+
+/// Classify one dependency. Returns the classification and the calls to
+/// vulnerable functions found in importing files.
+pub fn classify(
+    ecosystem: &str,
+    name: &str,
+    direct: bool,
+    symbols: &[String],
+    parse_results: &[ParseResult],
+    call_graph: &CallGraph,
+    reachable: &FxHashSet<NodeIndex>,
+) -> (Reachability, Vec<CallSiteEvidence>) {
+    let importing: Vec<&ParseResult> = parse_results
+        .iter()
+        .filter(|pr| pr.imports.iter().any(|i| import_matches(ecosystem, name, &i.source)))
+        .collect();
+    if importing.is_empty() {
+        let reachability = if direct { Reachability::NotImported } else { Reachability::Unknown };
+        return (reachability, Vec::new());
+    }
+    if symbols.is_empty() {
+        return (Reachability::Imported, Vec::new());
+    }
+
+    let names: FxHashSet<&str> = symbols.iter().map(|s| symbol_name(s)).collect();
+    let mut evidence = Vec::new();
+    let mut is_reachable = false;
+    for pr in importing {
+        for call in pr.call_sites.iter().filter(|c| names.contains(c.callee_name.as_str())) {
+            let enclosing = call_graph
+                .get_file_nodes(&pr.file)
+                .iter()
+                .copied()
+                .filter(|&idx| {
+                    let node = &call_graph.graph[idx];
+                    node.line <= call.line && call.line <= node.end_line
+                })
+                .min_by_key(|&idx| call_graph.graph[idx].end_line - call_graph.graph[idx].line);
+            // Module-level calls run on import.
+            is_reachable |= enclosing.map_or(true, |idx| reachable.contains(&idx));
+            evidence.push(CallSiteEvidence {
+                file: pr.file.clone(),
+                line: call.line,
+                function: enclosing.map(|idx| call_graph.graph[idx].name.clone()),
+            });
+        }
+    }
+    let reachability = if is_reachable { Reachability::Reachable } else { Reachability::Unreachable };
+    (reachability, evidence)
+}
@@ -0,0 +1,108 @@
+//! Ecosystem-aware version ordering for OSV range evaluation.
+//!
+//! Versions are split into numeric and alphabetic tokens and compared
+//! token by token. Pre-release qualifiers (`alpha`, `b`, `rc`, `SNAPSHOT`,
+//! `dev`) sort before the release and post-release qualifiers (`post`,
+//! `sp`, `patch`) after it, which covers SemVer, PEP 440, Maven and
+//! RubyGems closely enough for advisory ranges.
+
+use std::cmp::Ordering;
+
+/// Ecosystems whose `-` introduces a pre-release rather than a separator.
+const SEMVER_ECOSYSTEMS: &[&str] = &["npm", "crates.io", "Go", "NuGet", "Packagist", "Pub", "Hex"];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Token {
+    /// Pre-release qualifier, ranked.
+    Pre(u8),
+    /// End of the version.
+    Release,
+    /// Post-release or unknown qualifier.
+    Post(String),
+    Num(u64),
+}
+
+/// Compare two versions of a package in `ecosystem`.
+pub fn compare(ecosystem: &str, a: &str, b: &str) -> Ordering {
+    let (a, b) = (tokenize(ecosystem, a), tokenize(ecosystem, b));
+    for i in 0..a.len().max(b.len()) {
+        let (x, y) = (a.get(i), b.get(i));
+        let ordering = match (x, y) {
+            (Some(x), Some(y)) => x.cmp(y),
+            // `1.0` == `1.0.0`; otherwise the shorter version is a release.
+            (Some(x), None) => x.cmp(&pad(x)),
+            (None, Some(y)) => pad(y).cmp(y),
+            (None, None) => Ordering::Equal,
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+fn pad(other: &Token) -> Token {
+    match other {
+        Token::Num(_) => Token::Num(0),
+        _ => Token::Release,
+    }
+}
+
+fn tokenize(ecosystem: &str, version: &str) -> Vec<Token> {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let semver = SEMVER_ECOSYSTEMS.contains(&ecosystem);
+    // Build metadata never affects precedence.
+    let version = if semver { version.split('+').next().unwrap_or(version) } else { version };
+    let (release, pre) = match version.split_once('-') {
+        Some((release, pre)) if semver => (release, Some(pre)),
+        _ => (version, None),
+    };
+
+    let mut tokens = Vec::new();
+    push_tokens(release, &mut tokens);
+    if let Some(pre) = pre {
+        tokens.push(Token::Pre(0));
+        push_tokens(pre, &mut tokens);
+    }
+    tokens
+}
+
+fn push_tokens(part: &str, tokens: &mut Vec<Token>) {
+    let mut current = String::new();
+    let flush = |current: &mut String, tokens: &mut Vec<Token>| {
+        if current.is_empty() {
+            return;
+        }
+        let text = std::mem::take(current).to_ascii_lowercase();
+        match text.parse::<u64>() {
+            Ok(n) => tokens.push(Token::Num(n)),
+            Err(_) => tokens.extend(qualifier(&text)),
+        }
+    };
+    for c in part.chars() {
+        if !c.is_ascii_alphanumeric() {
+            flush(&mut current, tokens);
+            continue;
+        }
+        if current.chars().last().is_some_and(|last| last.is_ascii_digit() != c.is_ascii_digit()) {
+            flush(&mut current, tokens);
+        }
+        current.push(c);
+    }
+    flush(&mut current, tokens);
+}
+
+/// `None` for qualifiers that mean "this is the release" (`final`, `ga`).
+fn qualifier(text: &str) -> Option<Token> {
+    let rank = match text {
+        "final" | "ga" | "release" => return None,
+        "dev" => 1,
+        "alpha" | "a" => 2,
+        "beta" | "b" => 3,
+        "milestone" | "m" => 4,
+        "rc" | "cr" | "c" | "pre" | "preview" => 5,
+        "snapshot" => 6,
+        _ => return Some(Token::Post(text.to_string())),
+    };
+    Some(Token::Pre(rank))
+}
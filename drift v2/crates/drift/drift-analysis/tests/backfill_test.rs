@@ -0,0 +1,123 @@
+//! Historical backfill — replaying detection over past commits.
+
+use std::path::Path;
+
+use drift_analysis::advanced::history::{BackfillConfig, Backfiller};
+use drift_analysis::frameworks::registry::FrameworkPackRegistry;
+use drift_core::config::ScanConfig;
+
+const ROUTES_V1: &str = r#"const express = require("express");
+const app = express();
+
+app.get("/users", async (req, res) => {
+  try {
+    const users = await db.query("SELECT * FROM users");
+    res.json(users);
+  } catch (err) {
+    console.log(err);
+  }
+});
+"#;
+
+const ROUTES_V2: &str = r#"const express = require("express");
+const app = express();
+
+app.get("/users", async (req, res) => {
+  try {
+    const users = await db.query("SELECT * FROM users");
+    res.json(users);
+  } catch (err) {
+    console.log(err);
+  }
+});
+
+app.post("/users", async (req, res) => {
+  const password = "hunter2";
+  eval(req.body.code);
+  res.json({ ok: true });
+});
+"#;
+
+const UTIL: &str = "export function add(a: number, b: number): number {\n  return a + b;\n}\n";
+
+fn git_commit(repo: &git2::Repository, files: &[(&str, &str)], time: i64) {
+    let root = repo.workdir().unwrap();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        let full = root.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        index.add_path(Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::new("alice", "alice@example.com", &git2::Time::new(time, 0)).unwrap();
+    let parents: Vec<git2::Commit> = repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, "change", &tree, &parents).unwrap();
+}
+
+#[test]
+fn test_backfill_replays_commits_incrementally() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    git_commit(&repo, &[("src/routes.js", ROUTES_V1), ("src/util.ts", UTIL), ("README.md", "# app\n")], 1_700_000_000);
+    git_commit(&repo, &[("src/routes.js", ROUTES_V2)], 1_700_001_000);
+    git_commit(&repo, &[("README.md", "# app\n\nNotes.\n")], 1_700_002_000);
+
+    let packs = FrameworkPackRegistry::with_builtins().into_packs();
+    let points = Backfiller::new(packs.clone(), BackfillConfig::default()).run(dir.path()).unwrap();
+    assert_eq!(points.iter().map(|p| p.timestamp).collect::<Vec<_>>(), [1_700_000_000, 1_700_001_000, 1_700_002_000]);
+    assert_eq!(points.iter().map(|p| p.files).collect::<Vec<_>>(), [2, 2, 2], "README.md is not source");
+    assert_eq!(
+        points.iter().map(|p| p.files_analyzed).collect::<Vec<_>>(),
+        [2, 1, 0],
+        "only changed source blobs are re-analyzed"
+    );
+    assert!(points[1].match_count > points[0].match_count, "the new route adds matches");
+    assert_eq!(points[2].match_count, points[1].match_count);
+    assert!(points.iter().all(|p| (0.0..=100.0).contains(&p.health_score)));
+
+    // Replaying just HEAD from scratch agrees with the incremental result.
+    let head_only = BackfillConfig { max_commits: 1, ..Default::default() };
+    let fresh = Backfiller::new(packs, head_only).run(dir.path()).unwrap();
+    assert_eq!(fresh.len(), 1);
+    assert_eq!(fresh[0].files_analyzed, 2);
+    assert_eq!(
+        (fresh[0].sha.as_str(), fresh[0].pattern_count, fresh[0].match_count, fresh[0].health_score),
+        (points[2].sha.as_str(), points[2].pattern_count, points[2].match_count, points[2].health_score)
+    );
+}
+
+#[test]
+fn test_backfill_applies_scan_excludes() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    std::fs::write(dir.path().join(".driftignore"), "generated/\n").unwrap();
+    git_commit(
+        &repo,
+        &[
+            ("src/util.ts", UTIL),
+            ("node_modules/lib/index.js", ROUTES_V1),
+            ("web/dist/bundle.js", ROUTES_V1),
+            ("generated/api.ts", UTIL),
+            ("scripts/seed.js", ROUTES_V1),
+        ],
+        1_700_000_000,
+    );
+
+    let packs = FrameworkPackRegistry::with_builtins().into_packs();
+    let config = BackfillConfig {
+        scan: ScanConfig { extra_ignore: vec!["scripts/**".to_string()], ..Default::default() },
+        ..Default::default()
+    };
+    let points = Backfiller::new(packs.clone(), config).run(dir.path()).unwrap();
+    assert_eq!(points[0].files, 1, "default ignores, .driftignore and extra_ignore are skipped");
+
+    let include = BackfillConfig {
+        scan: ScanConfig { include: vec!["scripts/**".to_string()], ..Default::default() },
+        ..Default::default()
+    };
+    let points = Backfiller::new(packs, include).run(dir.path()).unwrap();
+    assert_eq!(points[0].files, 1, "only included paths are replayed");
+}
@@ -0,0 +1,347 @@
+//! Source-level ORM extractor tests — GORM, sqlx (Go/Rust), Diesel, SeaORM,
+//! Drizzle and Exposed, plus sensitive-field coverage through the detector.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use drift_analysis::boundaries::detector::BoundaryDetector;
+use drift_analysis::boundaries::extractors::{
+    diesel::DieselExtractor, drizzle::DrizzleExtractor, exposed::ExposedExtractor, gorm::GormExtractor,
+    sea_orm::SeaOrmExtractor, sqlx::SqlxExtractor, sqlx::SqlxRustExtractor, FieldExtractor,
+};
+use drift_analysis::boundaries::types::{ExtractedModel, OrmFramework, RelationshipKind, SensitivityType};
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::parsers::types::ParseResult;
+
+fn parse(source: &str, file: &str) -> ParseResult {
+    ParserManager::new().parse(source.as_bytes(), Path::new(file)).unwrap()
+}
+
+fn extract(extractor: &dyn FieldExtractor, source: &str, file: &str) -> Vec<ExtractedModel> {
+    extractor.extract_models_from_source(&parse(source, file), source)
+}
+
+fn columns(model: &ExtractedModel) -> Vec<&str> {
+    model.fields.iter().map(|f| f.name.as_str()).collect()
+}
+
+const GORM_MODELS: &str = r#"package models
+
+import (
+	"database/sql"
+	"time"
+
+	"gorm.io/gorm"
+)
+
+type User struct {
+	gorm.Model
+	Email     string         `gorm:"uniqueIndex;not null"`
+	SSN       *string        `gorm:"column:social_security_number"`
+	Phone     sql.NullString
+	CompanyID uint
+	Company   Company
+	Orders    []Order        `gorm:"foreignKey:BuyerID"`
+	Roles     []Role         `gorm:"many2many:user_roles;"`
+	Profile   Profile
+	secret    string
+	Ignored   string         `gorm:"-"`
+}
+
+type Profile struct {
+	ID     uint   `gorm:"primaryKey"`
+	UserID uint
+	Bio    string `gorm:"default:'hello'"`
+}
+
+type Company struct {
+	ID   uint
+	Name string
+}
+
+func (Company) TableName() string {
+	return "org_companies"
+}
+
+type Request struct {
+	Query string
+}
+"#;
+
+#[test]
+fn gorm_struct_tags_columns_and_relationships() {
+    let models = extract(&GormExtractor, GORM_MODELS, "models/user.go");
+    let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+    // `Request` has no GORM signal and is not a model.
+    assert_eq!(names, ["User", "Profile", "Company"]);
+
+    let user = &models[0];
+    assert_eq!(user.table_name.as_deref(), Some("users"));
+    assert_eq!(
+        columns(user),
+        ["id", "created_at", "updated_at", "deleted_at", "email", "social_security_number", "phone", "company_id"]
+    );
+    let field = |name: &str| user.fields.iter().find(|f| f.name == name).unwrap();
+    assert!(field("id").is_primary_key);
+    assert!(field("email").is_unique && !field("email").is_nullable);
+    assert!(field("social_security_number").is_nullable && field("phone").is_nullable);
+    assert_eq!(field("email").line, 11);
+
+    let relations: Vec<(RelationshipKind, &str, Option<&str>)> = user
+        .relationships
+        .iter()
+        .map(|r| (r.kind, r.target_model.as_str(), r.foreign_key.as_deref()))
+        .collect();
+    assert_eq!(
+        relations,
+        [
+            (RelationshipKind::BelongsTo, "Company", Some("company_id")),
+            (RelationshipKind::HasMany, "Order", Some("buyer_id")),
+            (RelationshipKind::ManyToMany, "Role", Some("user_roles")),
+            (RelationshipKind::HasOne, "Profile", None),
+        ]
+    );
+
+    assert_eq!(models[1].fields[2].default_value.as_deref(), Some("'hello'"));
+    assert_eq!(models[2].table_name.as_deref(), Some("org_companies"));
+}
+
+#[test]
+fn detector_uses_sources_for_gorm_sensitive_fields() {
+    let pr = parse(GORM_MODELS, "models/user.go");
+    let sources = HashMap::from([(pr.file.clone(), GORM_MODELS.to_string())]);
+    let detector = BoundaryDetector::new();
+
+    let result = detector.detect_with_sources(std::slice::from_ref(&pr), &sources).unwrap();
+    assert!(result.frameworks_detected.contains(&OrmFramework::Gorm));
+    assert_eq!(result.models.len(), 3);
+    let sensitive: Vec<(&str, SensitivityType)> =
+        result.sensitive_fields.iter().map(|f| (f.field_name.as_str(), f.sensitivity)).collect();
+    assert!(sensitive.contains(&("email", SensitivityType::Pii)));
+    assert!(sensitive.contains(&("social_security_number", SensitivityType::Pii)));
+
+    // Without sources the Go structs are invisible.
+    assert!(detector.detect(&[pr]).unwrap().models.is_empty());
+}
+
+#[test]
+fn sqlx_go_db_tags_and_rust_from_row() {
+    let go = r#"package store
+
+import "github.com/jmoiron/sqlx"
+
+type Account struct {
+	ID       int64          `db:"id"`
+	Password string         `db:"password_hash" json:"-"`
+	Nickname *string        `db:"nickname"`
+	Cached   string         `db:"-"`
+}
+
+type Options struct {
+	Limit int
+}
+"#;
+    let models = extract(&SqlxExtractor, go, "store/account.go");
+    assert_eq!(models.len(), 1);
+    assert_eq!(columns(&models[0]), ["id", "password_hash", "nickname"]);
+    assert!(models[0].fields[0].is_primary_key && models[0].fields[2].is_nullable);
+    assert_eq!(models[0].table_name, None);
+
+    let rust = r#"use sqlx::FromRow;
+
+#[derive(Debug, sqlx::FromRow)]
+#[sqlx(rename_all = "camelCase")]
+pub struct Customer {
+    pub id: i64,
+    pub credit_card: Option<String>,
+    #[sqlx(rename = "mail")]
+    pub email: String,
+    #[sqlx(skip)]
+    pub cache: Vec<u8>,
+}
+
+pub struct NotARow {
+    pub x: i32,
+}
+"#;
+    let models = extract(&SqlxRustExtractor, rust, "src/models.rs");
+    assert_eq!(models.len(), 1);
+    assert_eq!(columns(&models[0]), ["id", "creditCard", "mail"]);
+    assert!(models[0].fields[1].is_nullable);
+}
+
+#[test]
+fn diesel_schema_macros_and_model_attributes() {
+    let schema = r#"// @generated automatically by Diesel CLI.
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        email -> Varchar,
+        ssn -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        author_id -> Int4,
+        title -> Varchar,
+    }
+}
+
+diesel::joinable!(posts -> users (author_id));
+"#;
+    let models = extract(&DieselExtractor, schema, "src/schema.rs");
+    assert_eq!(models.len(), 2);
+    assert_eq!(columns(&models[0]), ["id", "email", "ssn"]);
+    assert!(models[0].fields[0].is_primary_key && models[0].fields[2].is_nullable);
+    assert_eq!(models[0].fields[1].line, 5);
+    assert_eq!(models[1].relationships[0].target_model, "users");
+    assert_eq!(models[1].relationships[0].foreign_key.as_deref(), Some("author_id"));
+
+    let structs = r#"use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Identifiable, Associations)]
+#[diesel(table_name = crate::schema::posts)]
+#[diesel(belongs_to(User, foreign_key = author_id))]
+pub struct Post {
+    pub id: i32,
+    pub author_id: i32,
+    #[diesel(column_name = title)]
+    pub headline: String,
+    pub body: Option<String>,
+}
+"#;
+    let models = extract(&DieselExtractor, structs, "src/models.rs");
+    assert_eq!(models.len(), 1);
+    let post = &models[0];
+    assert_eq!(post.table_name.as_deref(), Some("posts"));
+    assert_eq!(columns(post), ["id", "author_id", "title", "body"]);
+    assert!(post.fields[0].is_primary_key && post.fields[3].is_nullable);
+    assert_eq!(post.relationships[0].kind, RelationshipKind::BelongsTo);
+    assert_eq!(post.relationships[0].foreign_key.as_deref(), Some("author_id"));
+}
+
+#[test]
+fn sea_orm_entity_model_and_relations() {
+    let source = r#"use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "order_items")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub order_id: i32,
+    #[sea_orm(column_name = "sku_code", unique)]
+    pub sku: String,
+    #[sea_orm(nullable)]
+    pub note: String,
+    #[sea_orm(ignore)]
+    pub computed: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::order::Entity",
+        from = "Column::OrderId",
+        to = "super::order::Column::Id"
+    )]
+    Order,
+    #[sea_orm(has_many = "super::line_note::Entity")]
+    LineNote,
+}
+"#;
+    let models = extract(&SeaOrmExtractor, source, "src/entities/order_item.rs");
+    assert_eq!(models.len(), 1);
+    let model = &models[0];
+    assert_eq!(model.name, "OrderItem");
+    assert_eq!(model.table_name.as_deref(), Some("order_items"));
+    assert_eq!(columns(model), ["id", "order_id", "sku_code", "note"]);
+    assert!(model.fields[0].is_primary_key && model.fields[2].is_unique && model.fields[3].is_nullable);
+    let relations: Vec<(RelationshipKind, &str, Option<&str>)> = model
+        .relationships
+        .iter()
+        .map(|r| (r.kind, r.target_model.as_str(), r.foreign_key.as_deref()))
+        .collect();
+    assert_eq!(
+        relations,
+        [(RelationshipKind::BelongsTo, "Order", Some("order_id")), (RelationshipKind::HasMany, "LineNote", None)]
+    );
+}
+
+#[test]
+fn drizzle_tables_and_relations() {
+    let source = r#"import { pgTable, serial, text, varchar, integer, timestamp } from 'drizzle-orm/pg-core';
+import { relations } from 'drizzle-orm';
+
+export const users = pgTable('users', {
+  id: serial('id').primaryKey(),
+  email: varchar('email', { length: 256 }).notNull().unique(),
+  dateOfBirth: text('date_of_birth'),
+  createdAt: timestamp('created_at').defaultNow(),
+});
+
+export const posts = pgTable('posts', {
+  id: serial('id').primaryKey(),
+  authorId: integer('author_id').references(() => users.id),
+  title: text('title').default('untitled'),
+});
+
+export const usersRelations = relations(users, ({ many }) => ({
+  posts: many(posts),
+}));
+"#;
+    let models = extract(&DrizzleExtractor, source, "src/db/schema.ts");
+    assert_eq!(models.len(), 2);
+    let users = &models[0];
+    assert_eq!((users.name.as_str(), users.table_name.as_deref()), ("users", Some("users")));
+    assert_eq!(columns(users), ["id", "email", "date_of_birth", "created_at"]);
+    assert!(users.fields[0].is_primary_key && !users.fields[0].is_nullable);
+    assert!(users.fields[1].is_unique && !users.fields[1].is_nullable);
+    assert!(users.fields[2].is_nullable);
+    assert_eq!(users.fields[3].default_value.as_deref(), Some("defaultNow()"));
+    assert_eq!(users.fields[1].line, 5);
+    assert_eq!(users.relationships[0].kind, RelationshipKind::HasMany);
+    assert_eq!(users.relationships[0].target_model, "posts");
+
+    let posts = &models[1];
+    assert_eq!(posts.relationships[0].target_model, "users");
+    assert_eq!(posts.relationships[0].foreign_key.as_deref(), Some("author_id"));
+    assert_eq!(posts.fields[2].default_value.as_deref(), Some("'untitled'"));
+}
+
+#[test]
+fn exposed_table_objects() {
+    let source = r#"import org.jetbrains.exposed.dao.id.IntIdTable
+import org.jetbrains.exposed.sql.Table
+
+object Users : IntIdTable("users") {
+    val email = varchar("email", 255).uniqueIndex()
+    val ssn = varchar("ssn", 11).nullable()
+    val org = reference("org_id", Orgs)
+    val active = bool("active").default(true)
+}
+
+object OrgsTable : Table() {
+    val code = varchar("code", 8)
+    val name = varchar("name", 50)
+    override val primaryKey = PrimaryKey(code)
+}
+"#;
+    let models = extract(&ExposedExtractor, source, "src/main/kotlin/Tables.kt");
+    assert_eq!(models.len(), 2);
+    let users = &models[0];
+    assert_eq!(users.table_name.as_deref(), Some("users"));
+    assert_eq!(columns(users), ["id", "email", "ssn", "org_id", "active"]);
+    assert!(users.fields[0].is_primary_key);
+    assert!(users.fields[1].is_unique && users.fields[2].is_nullable);
+    assert_eq!(users.fields[4].default_value.as_deref(), Some("true"));
+    assert_eq!(users.relationships[0].target_model, "Orgs");
+
+    let orgs = &models[1];
+    assert_eq!(orgs.table_name.as_deref(), Some("Orgs"));
+    assert_eq!(columns(orgs), ["code", "name"]);
+    assert!(orgs.fields[0].is_primary_key && !orgs.fields[1].is_primary_key);
+}
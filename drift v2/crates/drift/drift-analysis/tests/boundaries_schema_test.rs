@@ -0,0 +1,292 @@
+//! Migration-derived schema tests — DDL/Rails/Alembic replay, ordering,
+//! model drift and sensitive column detection.
+
+use drift_analysis::boundaries::schema::{
+    compare_models, discover_migrations, DatabaseSchema, MigrationFile, SchemaDriftKind,
+};
+use drift_analysis::boundaries::sensitive::SensitiveFieldDetector;
+use drift_analysis::boundaries::types::{ExtractedField, ExtractedModel, OrmFramework, SensitivityType};
+
+fn field(name: &str, nullable: bool) -> ExtractedField {
+    ExtractedField {
+        name: name.to_string(),
+        field_type: Some("string".to_string()),
+        is_primary_key: name == "id",
+        is_nullable: nullable,
+        is_unique: false,
+        default_value: None,
+        line: 3,
+    }
+}
+
+fn model(name: &str, table: &str, fields: Vec<ExtractedField>) -> ExtractedModel {
+    ExtractedModel {
+        name: name.to_string(),
+        table_name: Some(table.to_string()),
+        file: "src/models.ts".to_string(),
+        line: 1,
+        framework: OrmFramework::TypeOrm,
+        fields,
+        relationships: Vec::new(),
+        confidence: 0.9,
+    }
+}
+
+#[test]
+fn postgres_ddl_replays_create_alter_rename_drop() {
+    let files = vec![
+        MigrationFile::new(
+            "db/migration/V1__init.sql",
+            r#"
+            CREATE TABLE public.users (
+                id BIGSERIAL PRIMARY KEY,
+                "email" VARCHAR(255) NOT NULL UNIQUE,
+                name TEXT,
+                tags text[] DEFAULT '{}'
+            );
+            CREATE TABLE audit (id int);
+            CREATE INDEX idx_users_name ON users (name);
+            "#,
+        ),
+        MigrationFile::new(
+            "db/migration/V2__profile.sql",
+            r#"
+            -- split name into parts
+            ALTER TABLE users RENAME COLUMN name TO full_name;
+            ALTER TABLE users ADD COLUMN ssn CHAR(11);
+            ALTER TABLE users ALTER COLUMN full_name SET NOT NULL;
+            CREATE TABLE orders (
+                id serial,
+                user_id bigint REFERENCES users(id) ON DELETE CASCADE,
+                PRIMARY KEY (id)
+            );
+            DROP TABLE audit;
+            "#,
+        ),
+        MigrationFile::new("db/migration/V10__rename.sql", "ALTER TABLE orders RENAME TO purchases;"),
+    ];
+    let schema = DatabaseSchema::replay(&files);
+
+    assert_eq!(schema.applied.len(), 3);
+    assert!(schema.table("audit").is_none());
+    assert!(schema.table("orders").is_none());
+
+    let users = schema.table("users").unwrap();
+    let email = users.column("email").unwrap();
+    assert!(!email.nullable && email.unique);
+    assert!(users.column("id").unwrap().primary_key);
+    let full_name = users.column("full_name").unwrap();
+    assert!(!full_name.nullable);
+    assert!(users.indexes.iter().any(|i| i.columns == ["full_name"]));
+    assert_eq!(users.column("ssn").unwrap().file, "db/migration/V2__profile.sql");
+
+    let purchases = schema.table("purchases").unwrap();
+    assert!(purchases.column("id").unwrap().primary_key);
+    assert_eq!(purchases.foreign_keys[0].ref_table, "users");
+}
+
+#[test]
+fn mysql_modify_change_and_drop_index() {
+    let files = vec![MigrationFile::new(
+        "migrations/001_init.sql",
+        r#"
+        CREATE TABLE `accounts` (
+          `id` int(11) NOT NULL AUTO_INCREMENT,
+          `phone` varchar(20) DEFAULT NULL,
+          `nick` varchar(20),
+          PRIMARY KEY (`id`),
+          UNIQUE KEY `uk_phone` (`phone`)
+        ) ENGINE=InnoDB;
+        ALTER TABLE accounts MODIFY phone varchar(32) NOT NULL;
+        ALTER TABLE accounts CHANGE nick nickname varchar(40);
+        CREATE UNIQUE INDEX uk_nick ON accounts (nickname);
+        DROP INDEX uk_nick ON accounts;
+        "#,
+    )];
+    let schema = DatabaseSchema::replay(&files);
+    let accounts = schema.table("accounts").unwrap();
+
+    assert!(!accounts.column("phone").unwrap().nullable);
+    assert_eq!(accounts.column("phone").unwrap().data_type.to_lowercase(), "varchar(32)");
+    assert!(accounts.column("nick").is_none());
+    assert!(!accounts.column("nickname").unwrap().unique);
+    assert!(accounts.column("id").unwrap().primary_key);
+}
+
+#[test]
+fn flyway_versions_sort_numerically_with_repeatables_last() {
+    let files = vec![
+        MigrationFile::new("sql/R__views.sql", "CREATE TABLE view_cache (id int);"),
+        MigrationFile::new("sql/V10__drop.sql", "DROP TABLE items;"),
+        MigrationFile::new("sql/V2__items.sql", "CREATE TABLE items (id int);"),
+        MigrationFile::new("sql/V1_1__seed.sql", "ALTER TABLE items ADD COLUMN sku text;"),
+        MigrationFile::new("sql/schema.sql", "CREATE TABLE baseline (id int);"),
+    ];
+    let schema = DatabaseSchema::replay(&files);
+    assert_eq!(
+        schema.applied,
+        ["sql/schema.sql", "sql/V1_1__seed.sql", "sql/V2__items.sql", "sql/V10__drop.sql", "sql/R__views.sql"]
+    );
+    // V1_1 ran before items existed, V10 dropped it.
+    assert!(schema.table("items").is_none());
+    assert!(schema.table("baseline").is_some() && schema.table("view_cache").is_some());
+}
+
+#[test]
+fn rails_migrations_replay_in_timestamp_order() {
+    let files = vec![
+        MigrationFile::new(
+            "db/migrate/20240301000000_add_ssn_to_patients.rb",
+            r#"
+class AddSsnToPatients < ActiveRecord::Migration[7.1]
+  def change
+    add_column :patients, :ssn, :string
+    rename_column :patients, :dob, :date_of_birth
+    change_column_null :patients, :date_of_birth, false
+    add_index :patients, :ssn, unique: true
+  end
+end
+"#,
+        ),
+        MigrationFile::new(
+            "db/migrate/20240101000000_create_patients.rb",
+            r#"
+class CreatePatients < ActiveRecord::Migration[7.1]
+  def change
+    create_table :patients do |t|
+      t.string :name, null: false
+      t.date :dob
+      t.references :clinic, foreign_key: true
+      t.timestamps
+    end
+  end
+end
+"#,
+        ),
+    ];
+    let schema = DatabaseSchema::replay(&files);
+    let patients = schema.table("patients").unwrap();
+
+    assert_eq!(patients.framework, OrmFramework::ActiveRecord);
+    assert!(patients.column("id").unwrap().primary_key);
+    assert!(!patients.column("name").unwrap().nullable);
+    assert!(!patients.column("date_of_birth").unwrap().nullable);
+    assert!(patients.column("dob").is_none());
+    assert!(patients.column("clinic_id").is_some());
+    assert!(!patients.column("created_at").unwrap().nullable);
+    assert!(patients.column("ssn").unwrap().unique);
+}
+
+#[test]
+fn alembic_revisions_follow_down_revision_chain() {
+    let files = vec![
+        MigrationFile::new(
+            "alembic/versions/b2_add_card.py",
+            r#"
+revision = 'b2'
+down_revision = 'a1'
+
+def upgrade():
+    op.add_column('customers', sa.Column('card_number', sa.String(19), nullable=True))
+    op.alter_column('customers', 'email', nullable=False)
+
+def downgrade():
+    op.drop_column('customers', 'card_number')
+"#,
+        ),
+        MigrationFile::new(
+            "alembic/versions/a1_init.py",
+            r#"
+revision = 'a1'
+down_revision = None
+
+def upgrade():
+    op.create_table(
+        'customers',
+        sa.Column('id', sa.Integer(), nullable=False),
+        sa.Column('email', sa.String(255)),
+        sa.PrimaryKeyConstraint('id'),
+    )
+
+def downgrade():
+    op.drop_table('customers')
+"#,
+        ),
+    ];
+    let schema = DatabaseSchema::replay(&files);
+    assert_eq!(schema.applied, ["alembic/versions/a1_init.py", "alembic/versions/b2_add_card.py"]);
+
+    let customers = schema.table("customers").unwrap();
+    assert_eq!(customers.framework, OrmFramework::SqlAlchemy);
+    assert!(customers.column("id").unwrap().primary_key);
+    assert!(!customers.column("email").unwrap().nullable);
+    assert!(customers.column("card_number").is_some());
+}
+
+#[test]
+fn models_are_compared_against_schema() {
+    let files = vec![MigrationFile::new(
+        "migrations/001.sql",
+        "CREATE TABLE users (id serial PRIMARY KEY, email text NOT NULL, nickname text, tenant_id int NOT NULL, \
+         created_at timestamp NOT NULL);",
+    )];
+    let schema = DatabaseSchema::replay(&files);
+    let models = vec![
+        model("User", "users", vec![field("id", false), field("email", true), field("nickname", true), field("avatar", true)]),
+        model("Invoice", "invoices", vec![field("id", false)]),
+    ];
+    let drift = compare_models(&schema, &models);
+    let kinds: Vec<(SchemaDriftKind, Option<&str>)> =
+        drift.iter().map(|d| (d.kind, d.column.as_deref())).collect();
+
+    assert!(kinds.contains(&(SchemaDriftKind::NullabilityMismatch, Some("email"))));
+    assert!(kinds.contains(&(SchemaDriftKind::MissingColumn, Some("avatar"))));
+    assert!(kinds.contains(&(SchemaDriftKind::UnmappedRequiredColumn, Some("tenant_id"))));
+    assert!(kinds.contains(&(SchemaDriftKind::MissingTable, None)));
+    // Timestamps are framework-managed; nickname matches.
+    assert!(!kinds.iter().any(|(_, c)| matches!(c, Some("created_at") | Some("nickname"))));
+    assert_eq!(drift.len(), 4);
+
+    assert!(compare_models(&DatabaseSchema::default(), &models).is_empty());
+}
+
+#[test]
+fn sensitive_columns_point_at_defining_migration() {
+    let files = vec![
+        MigrationFile::new("db/V1__users.sql", "CREATE TABLE users (\n  id int,\n  email text\n);"),
+        MigrationFile::new("db/V2__pii.sql", "\nALTER TABLE users ADD COLUMN ssn text;"),
+    ];
+    let schema = DatabaseSchema::replay(&files);
+    let sensitive = SensitiveFieldDetector::new().detect_schema_columns(&schema);
+
+    let ssn = sensitive.iter().find(|f| f.field_name == "ssn").unwrap();
+    assert_eq!(ssn.sensitivity, SensitivityType::Pii);
+    assert_eq!((ssn.file.as_str(), ssn.line), ("db/V2__pii.sql", 2));
+    let email = sensitive.iter().find(|f| f.field_name == "email").unwrap();
+    assert_eq!((email.file.as_str(), email.line), ("db/V1__users.sql", 3));
+}
+
+#[test]
+fn discovers_migrations_and_skips_down_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let write = |rel: &str, content: &str| {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    };
+    write("db/migrations/0001_init.up.sql", "CREATE TABLE a (id int);");
+    write("db/migrations/0001_init.down.sql", "DROP TABLE a;");
+    write("db/migrate/20240101_create_b.rb", "create_table :bs do |t|\nend");
+    write("alembic/versions/x.py", "revision = 'x'\ndef upgrade():\n    op.drop_table('a')\n");
+    write("app/versions/helpers.py", "def helper(): pass");
+    write("src/queries.sql", "SELECT 1;");
+    write("src/main.rs", "fn main() {}");
+
+    let mut paths: Vec<String> = discover_migrations(root).into_iter().map(|m| m.path).collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        ["alembic/versions/x.py", "db/migrate/20240101_create_b.rb", "db/migrations/0001_init.up.sql"]
+    );
+}
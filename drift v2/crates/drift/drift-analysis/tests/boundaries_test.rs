@@ -110,6 +110,7 @@ end"#),
         OrmFramework::Eloquent, OrmFramework::Doctrine, OrmFramework::Propel,
         OrmFramework::Gorm, OrmFramework::Ent, OrmFramework::Sqlx,
         OrmFramework::Diesel, OrmFramework::SeaOrm, OrmFramework::SqlxRust,
+        OrmFramework::Exposed,
         OrmFramework::Unknown,
     ];
     assert!(
@@ -279,7 +280,7 @@ fn t2_bnd_05_confidence_scoring() {
     eprintln!("User.ssn confidence: {}, Config.ssn confidence: {}", user_conf, config_conf);
 }
 
-// ---- T2-BND-06: All 10 field extractors produce valid output ----
+// ---- T2-BND-06: All 17 field extractors produce valid output ----
 
 #[test]
 fn t2_bnd_06_all_extractors() {
@@ -287,8 +288,8 @@ fn t2_bnd_06_all_extractors() {
 
     assert_eq!(
         extractors.len(),
-        10,
-        "should have 10 field extractors, got {}",
+        17,
+        "should have 17 field extractors, got {}",
         extractors.len()
     );
 
@@ -304,6 +305,13 @@ fn t2_bnd_06_all_extractors() {
         OrmFramework::EfCore,
         OrmFramework::Hibernate,
         OrmFramework::Eloquent,
+        OrmFramework::Gorm,
+        OrmFramework::Sqlx,
+        OrmFramework::Diesel,
+        OrmFramework::SeaOrm,
+        OrmFramework::SqlxRust,
+        OrmFramework::Drizzle,
+        OrmFramework::Exposed,
     ];
 
     let extractor_frameworks: Vec<OrmFramework> = extractors.iter().map(|e| e.framework()).collect();
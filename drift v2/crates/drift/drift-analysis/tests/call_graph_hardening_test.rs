@@ -675,8 +675,8 @@ fn ct_imp_01_sensitivity_scoring() {
         g.add_edge(caller, normal_fn, make_edge());
     }
 
-    let auth_radius = impact::compute_blast_radius(&g, auth_fn, 100);
-    let normal_radius = impact::compute_blast_radius(&g, normal_fn, 100);
+    let auth_radius = impact::compute_blast_radius(&g, auth_fn, 100, None);
+    let normal_radius = impact::compute_blast_radius(&g, normal_fn, 100, None);
 
     // Auth function should have higher risk due to sensitivity
     assert!(auth_radius.risk_score.sensitivity > 0.0, "Auth function should have positive sensitivity");
@@ -704,8 +704,8 @@ fn ct_imp_02_complexity_scoring() {
         n
     });
 
-    let short_radius = impact::compute_blast_radius(&g, short, 100);
-    let long_radius = impact::compute_blast_radius(&g, long, 100);
+    let short_radius = impact::compute_blast_radius(&g, short, 100, None);
+    let long_radius = impact::compute_blast_radius(&g, long, 100, None);
 
     assert!(long_radius.risk_score.complexity > short_radius.risk_score.complexity,
         "Long function complexity ({}) should be > short ({})",
@@ -933,7 +933,7 @@ function c() { return a(); }
 
     // Blast radius should handle cycles
     if let Some(start) = graph.get_node("cycle.ts::a") {
-        let radius = impact::compute_blast_radius(&graph, start, 10);
+        let radius = impact::compute_blast_radius(&graph, start, 10, None);
         assert!(radius.max_depth < 100, "Blast radius should be finite");
     }
 }
@@ -1018,7 +1018,7 @@ fn ct_int_09_strategy_confidences() {
 #[test]
 fn ct_int_10_blast_radius_empty() {
     let g = CallGraph::new();
-    let radii = impact::blast_radius::compute_all_blast_radii(&g);
+    let radii = impact::blast_radius::compute_all_blast_radii(&g, None);
     assert!(radii.is_empty(), "Empty graph should have no blast radii");
 }
 
@@ -0,0 +1,207 @@
+//! Clone detection — Type-1/2/3 clones, block clones, cross-language
+//! filtering, per-module duplication, the duplication gate and
+//! decomposition annotation.
+
+use std::path::Path;
+
+use drift_analysis::enforcement::gates::{GateId, GateInputBuilder, GateStatus, QualityGate};
+use drift_analysis::enforcement::gates::duplication::DuplicationGate;
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::structural::clones::*;
+use drift_analysis::structural::decomposition::{apply_duplication, decompose_with_priors};
+use drift_analysis::structural::decomposition::decomposer::{DecompositionInput, FileEntry};
+
+const TOTAL: &str = r#"export function orderTotal(order: Order): number {
+  let total = 0;
+  for (const item of order.items) {
+    if (item.quantity > 0) {
+      total += item.price * item.quantity;
+    }
+  }
+  if (order.coupon) {
+    total = total * (1 - order.coupon.discount);
+  }
+  return Math.round(total * 100) / 100;
+}
+"#;
+
+/// `TOTAL` with every identifier and literal changed.
+const RENAMED: &str = r#"export function invoiceSum(invoice: Invoice): number {
+  let sum = 1;
+  for (const line of invoice.lines) {
+    if (line.count > 5) {
+      sum += line.cost * line.count;
+    }
+  }
+  if (invoice.voucher) {
+    sum = sum * (2 - invoice.voucher.rate);
+  }
+  return Math.floor(sum * 10) / 10;
+}
+"#;
+
+/// `TOTAL` with a statement added.
+const EDITED: &str = r#"export function cartTotal(cart: Cart): number {
+  let total = 0;
+  for (const item of cart.items) {
+    if (item.quantity > 0) {
+      total += item.price * item.quantity;
+    }
+  }
+  if (cart.coupon) {
+    total = total * (1 - cart.coupon.discount);
+  }
+  audit.record(cart.id, total);
+  return Math.round(total * 100) / 100;
+}
+"#;
+
+const UNRELATED: &str = r#"export function parseHeader(raw: string): Header {
+  const [name, ...rest] = raw.split(":");
+  const value = rest.join(":").trim();
+  if (!name || name.length > 64) {
+    throw new Error(`invalid header name: ${name}`);
+  }
+  return { name: name.toLowerCase(), value, sensitive: SENSITIVE.has(name) };
+}
+"#;
+
+fn detector(files: &[(&str, &str)], config: CloneConfig) -> CloneDetector {
+    let parser = ParserManager::new();
+    let mut detector = CloneDetector::new(config);
+    for (path, source) in files {
+        let (pr, tree) = parser.parse_returning_tree(source.as_bytes(), Path::new(path)).unwrap();
+        detector.add_file(&pr, &tree, source.as_bytes());
+    }
+    detector
+}
+
+fn config() -> CloneConfig {
+    CloneConfig { min_tokens: 30, ..Default::default() }
+}
+
+#[test]
+fn test_clone_types_and_classes() {
+    let report = detector(
+        &[
+            ("src/billing/total.ts", TOTAL),
+            ("src/billing/copy.ts", TOTAL),
+            ("src/invoices/sum.ts", RENAMED),
+            ("src/cart/total.ts", EDITED),
+            ("src/http/header.ts", UNRELATED),
+        ],
+        config(),
+    )
+    .detect();
+
+    // Exact copy, renamed copy and edited copy form one class; the
+    // loosest relation in it is Type-3.
+    assert_eq!(report.classes.len(), 1);
+    let class = &report.classes[0];
+    assert_eq!(class.id, 1);
+    assert_eq!(class.clone_type, CloneType::Type3);
+    assert!(class.similarity >= 0.7 && class.similarity < 1.0, "similarity {}", class.similarity);
+    let files: Vec<&str> = class.instances.iter().map(|i| i.file.as_str()).collect();
+    assert_eq!(files, ["src/billing/copy.ts", "src/billing/total.ts", "src/cart/total.ts", "src/invoices/sum.ts"]);
+    assert!(class.instances.iter().all(|i| i.kind == FragmentKind::Function));
+    // 12 lines each, 13 in the edited copy
+    assert_eq!(class.redundant_lines(), 12 * 3);
+
+    // Without the edited copy the class is Type-2; alone with its copy, Type-1.
+    let type2 = detector(&[("a/total.ts", TOTAL), ("b/sum.ts", RENAMED)], config()).detect();
+    assert_eq!(type2.classes[0].clone_type, CloneType::Type2);
+    let type1 = detector(&[("a/total.ts", TOTAL), ("b/total.ts", TOTAL)], config()).detect();
+    assert_eq!((type1.classes[0].clone_type, type1.classes[0].similarity), (CloneType::Type1, 1.0));
+
+    // Below the token minimum nothing is compared.
+    let tiny = CloneConfig { min_tokens: 500, ..Default::default() };
+    assert!(detector(&[("a/total.ts", TOTAL), ("b/total.ts", TOTAL)], tiny).detect().classes.is_empty());
+
+    // Per-file and per-module duplication.
+    let header = report.modules.iter().find(|m| m.module == "src/http").unwrap();
+    assert_eq!((header.duplicated_lines, header.percentage), (0, 0.0));
+    let billing = report.modules.iter().find(|m| m.module == "src/billing").unwrap();
+    assert_eq!((billing.total_lines, billing.duplicated_lines, billing.percentage), (24, 24, 100.0));
+    assert_eq!(report.files.len(), 4);
+    assert_eq!(report.duplicated_lines, 12 * 3 + 13);
+    assert_eq!(report.total_lines, 12 * 3 + 13 + 8);
+}
+
+#[test]
+fn test_block_clones_and_cross_language_filter() {
+    // The same loop pasted into two otherwise different functions.
+    let block = r#"
+    for (const item of order.items) {
+      if (item.quantity > 0 && item.available) {
+        total += item.price * item.quantity;
+        count += item.quantity;
+        seen.add(item.sku);
+      } else {
+        skipped.push({ sku: item.sku, reason: "unavailable" });
+      }
+    }"#;
+    let a = format!("export function summarize(order: Order) {{\n  const seen = new Set();\n  let total = 0, count = 0;\n  const skipped = [];{block}\n  return {{ total, count, skipped }};\n}}\n");
+    let b = format!("export async function reconcile(order: Order, ledger: Ledger) {{\n  await ledger.lock(order.id);\n  const seen = new Set(ledger.skus);\n  let total = ledger.opening, count = 0;\n  const skipped = ledger.pending;{block}\n  await ledger.commit(order.id, total, count);\n  return skipped.length === 0;\n}}\n");
+    let blocks = CloneConfig { min_tokens: 1000, min_block_tokens: 40, ..Default::default() };
+    let report = detector(&[("src/a.ts", &a), ("src/b.ts", &b)], blocks.clone()).detect();
+    assert_eq!(report.classes.len(), 1);
+    let class = &report.classes[0];
+    assert_eq!(class.clone_type, CloneType::Type1);
+    assert!(class.instances.iter().all(|i| i.kind == FragmentKind::Block && i.end_line - i.line == 8));
+    assert_eq!((class.instances[0].name.as_str(), class.instances[1].name.as_str()), ("summarize", "reconcile"));
+
+    // A JavaScript copy of TypeScript code only pairs when asked to.
+    let js = TOTAL.replace("order: Order): number", "order");
+    let mixed = [("src/total.ts", TOTAL), ("src/total.js", js.as_str())];
+    assert!(detector(&mixed, config()).detect().classes.is_empty());
+    let cross = CloneConfig { cross_language: true, ..config() };
+    assert_eq!(detector(&mixed, cross).detect().classes.len(), 1);
+}
+
+#[test]
+fn test_duplication_gate_and_decomposition() {
+    let report = detector(
+        &[
+            ("src/billing/total.ts", TOTAL),
+            ("src/billing/copy.ts", TOTAL),
+            ("src/billing/header.ts", UNRELATED),
+        ],
+        config(),
+    )
+    .detect();
+    // billing: 24 of 32 lines duplicated
+    assert_eq!(report.modules[0].percentage, 75.0);
+
+    let gate = DuplicationGate;
+    assert_eq!(gate.id(), GateId::Duplication);
+    assert_eq!(gate.evaluate(&GateInputBuilder::new().build()).status, GateStatus::Skipped);
+
+    let strict = gate.evaluate(&GateInputBuilder::new().duplication_from_clones(&report, 50.0).build());
+    assert_eq!(strict.status, GateStatus::Failed);
+    assert_eq!(strict.violations.len(), 2);
+    let v = &strict.violations[0];
+    assert_eq!((v.file.as_str(), v.line, v.end_line, v.rule_id.as_str()), ("src/billing/copy.ts", 0, Some(11), "duplication/type-1"));
+    assert!(v.message.contains("src/billing/total.ts:0"), "{}", v.message);
+    assert!((strict.score - (100.0 - report.duplication_percentage())).abs() < 1e-9);
+
+    let lenient = gate.evaluate(&GateInputBuilder::new().duplication_from_clones(&report, 90.0).build());
+    assert!(lenient.passed && lenient.violations.is_empty());
+
+    let file = |path: &str, source: &str| FileEntry {
+        path: path.to_string(),
+        line_count: source.lines().count() as u64,
+        language: "typescript".to_string(),
+    };
+    let input = DecompositionInput {
+        files: vec![file("src/billing/total.ts", TOTAL), file("src/billing/copy.ts", TOTAL), file("src/http/header.ts", UNRELATED)],
+        call_edges: vec![],
+        data_access: vec![],
+        functions: vec![],
+    };
+    let mut modules = decompose_with_priors(&input, &[]);
+    apply_duplication(&mut modules, &report.files);
+    let billing = modules.iter().find(|m| m.name == "billing").unwrap();
+    let http = modules.iter().find(|m| m.name == "http").unwrap();
+    assert_eq!(billing.duplication, 100.0);
+    assert_eq!(http.duplication, 0.0);
+}
@@ -0,0 +1,222 @@
+//! CODEOWNERS — GitHub and GitLab parsing, path resolution, owners on
+//! violations, per-owner summaries and owner-filtered reports.
+
+use std::path::Path;
+
+use drift_analysis::enforcement::codeowners::*;
+use drift_analysis::enforcement::gates::{GateId, GateResult};
+use drift_analysis::enforcement::reporters::{filter_by_owner, json::JsonReporter, Reporter};
+use drift_analysis::enforcement::rules::{Severity, Violation};
+
+fn violation(id: &str, file: &str, severity: Severity) -> Violation {
+    Violation {
+        id: id.to_string(),
+        file: file.to_string(),
+        line: 1,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity,
+        pattern_id: "p".to_string(),
+        rule_id: "r".to_string(),
+        message: "m".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+    }
+}
+
+#[test]
+fn github_last_matching_rule_wins() {
+    let owners = CodeOwners::parse(
+        "# Default owners\n\
+         *       @org/core\n\
+         *.js    @org/frontend  # inline comment\n\
+         /build/logs/ @doctocat\n\
+         docs/*  docs@example.com\n\
+         apps/   @octocat\n\
+         /scripts/ \n",
+        CodeOwnersDialect::GitHub,
+    );
+
+    assert_eq!(owners.owners_of("src/main.rs"), ["@org/core"]);
+    assert_eq!(owners.owners_of("web/app.js"), ["@org/frontend"]);
+    assert_eq!(owners.owners_of("build/logs/today.log"), ["@doctocat"]);
+    // Unanchored directory patterns match at any depth.
+    assert_eq!(owners.owners_of("web/apps/index.ts"), ["@octocat"]);
+    // `docs/*` covers direct children only.
+    assert_eq!(owners.owners_of("docs/guide.md"), ["docs@example.com"]);
+    assert_eq!(owners.owners_of("docs/api/index.md"), ["@org/core"]);
+    // A rule without owners leaves the path unowned.
+    assert!(owners.owners_of("scripts/release.sh").is_empty());
+
+    let rules = owners.matching_rules("web/app.js");
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].pattern, "*.js");
+    assert_eq!(rules[0].line, 3);
+}
+
+#[test]
+fn anchored_patterns_match_from_root() {
+    let owners = CodeOwners::parse("/src/api/ @api\nlib/util.rs @util\n", CodeOwnersDialect::GitHub);
+
+    assert_eq!(owners.owners_of("src/api/routes/users.ts"), ["@api"]);
+    assert!(owners.owners_of("vendor/src/api/x.ts").is_empty());
+    assert_eq!(owners.owners_of("./lib/util.rs"), ["@util"]);
+    assert!(owners.owners_of("crates/lib/util.rs").is_empty());
+    assert_eq!(owners.owners_of("src\\api\\index.ts"), ["@api"]);
+}
+
+#[test]
+fn gitlab_sections_union_and_defaults() {
+    let content = "\
+*.rs @rustaceans
+
+[Security][2] @org/security
+/src/auth/
+*.pem @secops
+
+^[Docs] @writers
+*.md
+
+[security]
+/src/crypto/
+";
+    let owners = CodeOwners::parse(content, CodeOwnersDialect::GitLab);
+    // Unnamed section, Security (continued by `[security]`) and Docs.
+    assert_eq!(owners.sections.len(), 3);
+    let security = &owners.sections[1];
+    assert_eq!(security.name.as_deref(), Some("Security"));
+    assert_eq!(security.approvals, Some(2));
+    assert_eq!(security.rules.len(), 3);
+    assert!(owners.sections[2].optional);
+
+    assert_eq!(owners.owners_of("src/auth/login.rs"), ["@rustaceans", "@org/security"]);
+    assert_eq!(owners.owners_of("src/crypto/aes.rs"), ["@rustaceans", "@org/security"]);
+    assert_eq!(owners.owners_of("certs/server.pem"), ["@secops"]);
+    assert_eq!(owners.owners_of("README.md"), ["@writers"]);
+    assert!(owners.owners_of("package.json").is_empty());
+}
+
+#[test]
+fn bracket_patterns_are_not_sections() {
+    let owners = CodeOwners::parse("[abc]*.rs @letters\n", CodeOwnersDialect::GitLab);
+    assert_eq!(owners.owners_of("src/apple.rs"), ["@letters"]);
+    assert!(owners.owners_of("src/zebra.rs").is_empty());
+}
+
+#[test]
+fn discover_prefers_github_location() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(CodeOwners::discover(dir.path()).is_none());
+
+    std::fs::write(dir.path().join("CODEOWNERS"), "[Backend] @backend\n*.rs\n").unwrap();
+    let root = CodeOwners::discover(dir.path()).unwrap();
+    assert_eq!(root.dialect, CodeOwnersDialect::GitLab);
+    assert_eq!(root.owners_of("src/lib.rs"), ["@backend"]);
+
+    std::fs::create_dir(dir.path().join(".github")).unwrap();
+    std::fs::write(dir.path().join(".github/CODEOWNERS"), "* @everyone\n").unwrap();
+    let github = CodeOwners::discover(dir.path()).unwrap();
+    assert_eq!(github.dialect, CodeOwnersDialect::GitHub);
+    assert_eq!(github.owners_of("src/lib.rs"), ["@everyone"]);
+}
+
+fn owned_results() -> Vec<GateResult> {
+    let owners = CodeOwners::parse(
+        "* @org/core\n/src/auth/ @org/security @alice\n*.md\n",
+        CodeOwnersDialect::GitHub,
+    );
+    let mut new_violation = violation("v3", "src/main.rs", Severity::Warning);
+    new_violation.is_new = true;
+    let mut results = vec![
+        GateResult::fail(
+            GateId::SecurityBoundaries,
+            40.0,
+            "Security violations".to_string(),
+            vec![
+                violation("v1", "src/auth/login.ts", Severity::Error),
+                violation("v2", "src/auth/token.ts", Severity::Warning),
+            ],
+        ),
+        GateResult::fail(
+            GateId::PatternCompliance,
+            70.0,
+            "Pattern violations".to_string(),
+            vec![new_violation, violation("v4", "README.md", Severity::Info)],
+        ),
+    ];
+    owners.assign(Path::new("/repo"), &mut results);
+    results
+}
+
+#[test]
+fn assign_sets_owners_on_violations() {
+    let results = owned_results();
+    assert_eq!(results[0].violations[0].owners, ["@org/security", "@alice"]);
+    assert_eq!(results[1].violations[0].owners, ["@org/core"]);
+    assert!(results[1].violations[1].owners.is_empty());
+}
+
+#[test]
+fn assign_matches_absolute_files_relative_to_root() {
+    let owners = CodeOwners::parse(
+        "* @org/core\n/src/api/ @api\nsrc/payments/ @payments\ndocs/* @docs\n",
+        CodeOwnersDialect::GitHub,
+    );
+    let mut results = vec![GateResult::fail(
+        GateId::PatternCompliance,
+        50.0,
+        "Pattern violations".to_string(),
+        vec![
+            violation("v1", "/repo/src/api/users.ts", Severity::Error),
+            violation("v2", "/repo/src/payments/charge.ts", Severity::Error),
+            violation("v3", "/repo/docs/guide.md", Severity::Info),
+            violation("v4", "/repo/lib/src/api/inner.ts", Severity::Info),
+        ],
+    )];
+    owners.assign(Path::new("/repo"), &mut results);
+    let assigned: Vec<&[String]> = results[0].violations.iter().map(|v| v.owners.as_slice()).collect();
+    assert_eq!(assigned, [&["@api".to_string()][..], &["@payments".to_string()], &["@docs".to_string()], &["@org/core".to_string()]]);
+}
+
+#[test]
+fn summarize_groups_violations_per_owner() {
+    let summaries = summarize_by_owner(&owned_results());
+    let names: Vec<&str> = summaries.iter().map(|s| s.owner.as_str()).collect();
+    assert_eq!(names, ["@alice", "@org/security", "@org/core", UNOWNED]);
+
+    let security = &summaries[1];
+    assert_eq!(security.violations, 2);
+    assert_eq!(security.errors, 1);
+    assert_eq!(security.warnings, 1);
+    assert_eq!(security.files, ["src/auth/login.ts", "src/auth/token.ts"]);
+    assert_eq!(security.gates.len(), 1);
+    assert_eq!(security.gates[0].gate_id, GateId::SecurityBoundaries);
+    assert!(!security.gates[0].gate_passed);
+
+    let core = &summaries[2];
+    assert_eq!(core.new_violations, 1);
+    assert_eq!(core.gates[0].gate_id, GateId::PatternCompliance);
+}
+
+#[test]
+fn reports_filter_by_owner() {
+    let results = owned_results();
+
+    let alice = filter_by_owner(&results, "@ALICE");
+    assert_eq!(alice.len(), 2, "every gate is kept");
+    assert_eq!(alice[0].violations.len(), 2);
+    assert!(alice[1].violations.is_empty());
+
+    let unowned = filter_by_owner(&results, UNOWNED);
+    assert_eq!(unowned[1].violations.len(), 1);
+    assert_eq!(unowned[1].violations[0].file, "README.md");
+
+    let report = JsonReporter.generate(&results).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(json["owners"].as_array().unwrap().len(), 4);
+}
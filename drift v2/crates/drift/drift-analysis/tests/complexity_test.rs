@@ -0,0 +1,195 @@
+//! Function complexity — cyclomatic, cognitive, nesting, Halstead and
+//! maintainability index, and their use in constraints, impact and simulation.
+
+use std::path::Path;
+
+use drift_analysis::advanced::simulation::types::SimulationContext;
+use drift_analysis::call_graph::builder::CallGraphBuilder;
+use drift_analysis::graph::impact::{compute_all_blast_radii, ImpactMetrics};
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::structural::complexity::{analyze_file, ComplexityIndex, ComplexitySummary, FunctionComplexity};
+use drift_analysis::structural::constraints::detector::{FunctionInfo, InvariantDetector};
+use drift_analysis::structural::constraints::types::{Constraint, ConstraintSource, InvariantType};
+
+fn metrics(source: &str, file: &str) -> Vec<FunctionComplexity> {
+    let (pr, tree) = ParserManager::new().parse_returning_tree(source.as_bytes(), Path::new(file)).unwrap();
+    analyze_file(&pr, &tree, source.as_bytes())
+}
+
+fn function<'a>(metrics: &'a [FunctionComplexity], name: &str) -> &'a FunctionComplexity {
+    metrics.iter().find(|f| f.name == name).unwrap_or_else(|| panic!("no metrics for {name}: {metrics:?}"))
+}
+
+const CLASSIFY_TS: &str = r#"function classify(items: number[], strict: boolean): string {
+  let out = "";
+  for (const item of items) {
+    if (item > 10 && strict) {
+      out += "big";
+    } else if (item > 5) {
+      out += "mid";
+    } else {
+      out += item < 0 ? "neg" : "small";
+    }
+  }
+  return out;
+}
+
+function fact(n: number): number {
+  if (n <= 1) {
+    return 1;
+  }
+  return n * fact(n - 1);
+}
+
+function check(a: boolean, b: boolean, c: boolean, d: boolean): boolean {
+  return a && b && c || d;
+}
+"#;
+
+#[test]
+fn test_typescript_cyclomatic_and_cognitive() {
+    let m = metrics(CLASSIFY_TS, "src/classify.ts");
+
+    let classify = function(&m, "classify");
+    // for, if, &&, else if, ternary
+    assert_eq!(classify.cyclomatic, 6);
+    // for 1 + if (nested) 2 + && 1 + else if 1 + else 1 + ternary (nesting 2) 3
+    assert_eq!(classify.cognitive, 9);
+    assert_eq!(classify.max_nesting, 2);
+    assert_eq!((classify.line, classify.loc), (0, 13));
+
+    let fact = function(&m, "fact");
+    assert_eq!(fact.cyclomatic, 2);
+    assert_eq!(fact.cognitive, 2, "if + recursion");
+
+    let check = function(&m, "check");
+    assert_eq!(check.cyclomatic, 4);
+    assert_eq!(check.cognitive, 2, "one && run, one || run");
+    assert_eq!(check.max_nesting, 0);
+}
+
+#[test]
+fn test_python_and_go_control_flow() {
+    let py = metrics(
+        r#"def process(rows):
+    total = 0
+    for row in rows:
+        if row.valid:
+            try:
+                total += row.value
+            except ValueError:
+                continue
+        elif row.skip:
+            pass
+    return total
+"#,
+        "etl/process.py",
+    );
+    let process = function(&py, "process");
+    assert_eq!(process.cyclomatic, 5);
+    // for 1 + if 2 + except (nesting 2) 3 + elif 1
+    assert_eq!(process.cognitive, 7);
+    assert_eq!(process.max_nesting, 3);
+
+    let go = metrics(
+        r#"package grades
+
+func grade(score int) string {
+	switch {
+	case score >= 90:
+		return "A"
+	case score >= 80:
+		return "B"
+	default:
+		return "F"
+	}
+}
+"#,
+        "grades/grade.go",
+    );
+    let grade = function(&go, "grade");
+    assert_eq!(grade.cyclomatic, 3, "default adds no path");
+    assert_eq!(grade.cognitive, 1, "a switch counts once");
+}
+
+#[test]
+fn test_halstead_and_maintainability() {
+    let m = metrics("function add(a, b) {\n  return a + b;\n}\n", "src/add.js");
+    let add = function(&m, "add");
+    // Operators: function ( { return +   Operands: add a b a b
+    assert_eq!(add.halstead.distinct_operators, 5);
+    assert_eq!(add.halstead.distinct_operands, 3);
+    assert_eq!(add.halstead.total_operands, 5);
+    assert!((add.halstead.volume - 30.0).abs() < 1e-9, "V = 10 × log2(8), got {}", add.halstead.volume);
+    assert!((add.halstead.difficulty - 25.0 / 6.0).abs() < 1e-9);
+    assert!(add.maintainability_index > 70.0);
+
+    let classify = function(&metrics(CLASSIFY_TS, "src/classify.ts"), "classify").clone();
+    assert!(classify.halstead.volume > add.halstead.volume);
+    assert!(classify.maintainability_index < add.maintainability_index);
+}
+
+fn constraint(target: &str) -> Constraint {
+    Constraint {
+        id: "complexity".into(),
+        description: "Keep functions simple".into(),
+        invariant_type: InvariantType::ComplexityLimit,
+        target: target.into(),
+        scope: None,
+        source: ConstraintSource::Manual,
+        enabled: true,
+    }
+}
+
+#[test]
+fn test_complexity_limit_uses_metrics() {
+    let m = metrics(CLASSIFY_TS, "src/classify.ts");
+    let mut detector = InvariantDetector::new();
+    let funcs = m.iter().map(|f| FunctionInfo { name: f.name.clone(), line: f.line, is_exported: false }).collect();
+    detector.add_file("src/classify.ts", funcs, vec![], 24);
+    detector.add_complexity(m);
+
+    let cognitive = detector.verify(&constraint("cognitive:5"));
+    assert_eq!(cognitive.violations.len(), 1);
+    assert_eq!(cognitive.violations[0].line, Some(0));
+    assert!(cognitive.violations[0].message.contains("'classify'"));
+    assert_eq!(cognitive.violations[0].actual, "cognitive 9");
+
+    assert!(detector.verify(&constraint("5")).violations.len() == 1, "plain limit is cyclomatic");
+    assert!(detector.verify(&constraint("nesting:2")).passed);
+    assert!(!detector.verify(&constraint("maintainability:99")).passed, "maintainability is a minimum");
+
+    // Files without metrics keep the function-count proxy.
+    detector.add_file("src/other.rb", vec![FunctionInfo { name: "a".into(), line: 1, is_exported: false }; 3], vec![], 10);
+    let fallback = detector.verify(&constraint("2"));
+    assert!(fallback.violations.iter().any(|v| v.file == "src/other.rb" && v.message == "File has 3 functions (max 2)"));
+}
+
+#[test]
+fn test_blast_radius_and_simulation_use_metrics() {
+    let source = CLASSIFY_TS;
+    let (pr, tree) = ParserManager::new().parse_returning_tree(source.as_bytes(), Path::new("src/classify.ts")).unwrap();
+    let index = ComplexityIndex::from_functions(analyze_file(&pr, &tree, source.as_bytes()));
+    let (graph, _) = CallGraphBuilder::new().build(&[pr]).unwrap();
+
+    let estimated = compute_all_blast_radii(&graph, None);
+    let measured = compute_all_blast_radii(&graph, Some(&ImpactMetrics { complexity: Some(&index), ..Default::default() }));
+    let factor = |radii: &[drift_analysis::graph::impact::BlastRadius], name: &str| {
+        radii.iter().find(|r| graph.graph[r.function_id].name == name).unwrap().risk_score.complexity
+    };
+    let classify = index.get("src/classify.ts", 0).unwrap();
+    assert_eq!(factor(&measured, "classify"), classify.risk_factor());
+    assert_ne!(factor(&measured, "classify"), factor(&estimated, "classify"));
+    assert!(factor(&measured, "classify") > factor(&measured, "check"));
+
+    let summary = index.summarize(&["src/classify.ts".to_string()]);
+    assert_eq!(summary, ComplexitySummary::from_functions(index.iter()));
+    assert_eq!(summary.function_count, 3);
+    assert_eq!(summary.max_cognitive, 9);
+    assert!((summary.avg_cognitive - 13.0 / 3.0).abs() < 1e-9);
+
+    let mut ctx = SimulationContext { avg_complexity: 12.0, ..Default::default() };
+    ctx.fill_complexity(&summary);
+    assert_eq!(ctx.avg_complexity, 12.0, "caller-supplied values win");
+    assert_eq!(ctx.avg_cognitive_complexity, summary.avg_cognitive);
+}
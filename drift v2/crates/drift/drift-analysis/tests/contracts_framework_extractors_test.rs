@@ -0,0 +1,373 @@
+//! Contract extractors for FastAPI, Axum, Echo, Chi, Fiber, Koa, Hono and Ktor.
+
+use drift_analysis::structural::contracts::extractors::*;
+use drift_analysis::structural::contracts::extractors::axum::AxumExtractor;
+use drift_analysis::structural::contracts::extractors::chi::ChiExtractor;
+use drift_analysis::structural::contracts::extractors::echo::EchoExtractor;
+use drift_analysis::structural::contracts::extractors::express::ExpressExtractor;
+use drift_analysis::structural::contracts::extractors::fastapi::FastApiExtractor;
+use drift_analysis::structural::contracts::extractors::fiber::FiberExtractor;
+use drift_analysis::structural::contracts::extractors::hono::HonoExtractor;
+use drift_analysis::structural::contracts::extractors::koa::KoaExtractor;
+use drift_analysis::structural::contracts::extractors::ktor::KtorExtractor;
+use drift_analysis::structural::contracts::types::*;
+
+fn find<'a>(eps: &'a [Endpoint], method: &str, path: &str) -> &'a Endpoint {
+    eps.iter()
+        .find(|e| e.method == method && e.path == path)
+        .unwrap_or_else(|| panic!("no {method} {path} in {:#?}", eps))
+}
+
+fn field<'a>(fields: &'a [FieldSpec], name: &str) -> &'a FieldSpec {
+    fields.iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("no field {name} in {fields:#?}"))
+}
+
+#[test]
+fn test_fastapi_routes_and_pydantic_models() {
+    let content = r#"
+from fastapi import APIRouter, Depends, FastAPI
+from pydantic import BaseModel, Field
+
+router = APIRouter(prefix="/users")
+
+class UserIn(BaseModel):
+    email: str
+    nickname: str | None = None
+    display: str = Field(..., alias="displayName")
+
+class UserOut(BaseModel):
+    id: int
+    email: str
+
+@router.get("/{user_id}", response_model=UserOut)
+async def get_user(user_id: int, verbose: bool = False, db: Session = Depends(get_db)):
+    ...
+
+@router.post(
+    "",
+    status_code=201,
+)
+def create_user(payload: UserIn) -> UserOut:
+    ...
+
+@router.api_route("/ping", methods=["GET", "HEAD"])
+def ping():
+    return None
+"#;
+    let ext = FastApiExtractor;
+    assert!(ext.matches(content));
+    let eps = ext.extract(content, "app/users.py");
+    assert_eq!(eps.len(), 4, "{eps:#?}");
+
+    let get = find(&eps, "GET", "/users/{user_id}");
+    assert!(field(&get.request_fields, "user_id").required);
+    assert!(!field(&get.request_fields, "verbose").required);
+    assert!(get.request_fields.iter().all(|f| f.name != "db"));
+    assert_eq!(get.response_fields.len(), 2);
+    assert_eq!(field(&get.response_fields, "id").field_type, "int");
+
+    let create = find(&eps, "POST", "/users");
+    assert!(field(&create.request_fields, "email").required);
+    assert!(field(&create.request_fields, "nickname").nullable);
+    assert!(field(&create.request_fields, "displayName").required);
+    assert!(create.response_fields.iter().any(|f| f.name == "id"));
+
+    find(&eps, "HEAD", "/users/ping");
+}
+
+#[test]
+fn test_axum_router_nest_and_extractors() {
+    let content = r#"
+use axum::{routing::{get, post}, Json, Router, extract::{Path, Query, State}};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateUser {
+    display_name: String,
+    #[serde(default)]
+    admin: bool,
+}
+
+#[derive(Serialize)]
+struct User {
+    id: u64,
+    display_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Paging { page: u32 }
+
+pub fn app() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .nest("/api", api_routes())
+}
+
+fn api_routes() -> Router<AppState> {
+    Router::new()
+        .route("/users", get(list_users).post(create_user))
+        .route("/users/:id", get(get_user))
+}
+
+async fn health() -> &'static str { "ok" }
+
+async fn list_users(Query(p): Query<Paging>) -> Json<Vec<User>> { todo!() }
+
+async fn create_user(State(s): State<AppState>, Json(body): Json<CreateUser>) -> Result<(StatusCode, Json<User>), AppError> {
+    todo!()
+}
+
+async fn get_user(Path(id): Path<u64>) -> Json<User> { todo!() }
+"#;
+    let ext = AxumExtractor;
+    assert!(ext.matches(content));
+    let eps = ext.extract(content, "src/routes.rs");
+    assert_eq!(eps.len(), 4, "{eps:#?}");
+
+    find(&eps, "GET", "/health");
+    let list = find(&eps, "GET", "/api/users");
+    assert_eq!(list.request_fields[0].name, "page");
+
+    let create = find(&eps, "POST", "/api/users");
+    assert!(field(&create.request_fields, "displayName").required);
+    assert!(!field(&create.request_fields, "admin").required);
+    assert_eq!(field(&create.response_fields, "id").field_type, "u64");
+    assert!(field(&create.response_fields, "display_name").nullable);
+
+    let get = find(&eps, "GET", "/api/users/:id");
+    assert_eq!(field(&get.request_fields, "id").field_type, "u64");
+}
+
+const GO_MODELS: &str = r#"
+type CreateUser struct {
+	Email    string  `json:"email" validate:"required"`
+	Nickname *string `json:"nickname,omitempty"`
+	Secret   string  `json:"-"`
+}
+
+type User struct {
+	ID    int64  `json:"id"`
+	Email string `json:"email"`
+}
+"#;
+
+#[test]
+fn test_echo_groups_bind_and_json() {
+    let content = format!(r#"
+import "github.com/labstack/echo/v4"
+{GO_MODELS}
+func main() {{
+	e := echo.New()
+	api := e.Group("/api")
+	v1 := api.Group("/v1")
+	v1.POST("/users", createUser)
+	v1.GET("/users/:id", func(c echo.Context) error {{
+		return c.JSON(http.StatusOK, User{{ID: 1}})
+	}})
+	e.GET("/health", health)
+}}
+
+func createUser(c echo.Context) error {{
+	var req CreateUser
+	if err := c.Bind(&req); err != nil {{
+		return err
+	}}
+	user := &User{{Email: req.Email}}
+	_ = c.Get("user")
+	return c.JSON(http.StatusCreated, user)
+}}
+"#);
+    let ext = EchoExtractor;
+    assert!(ext.matches(&content));
+    let eps = ext.extract(&content, "cmd/api/main.go");
+    assert_eq!(eps.len(), 3, "{eps:#?}");
+
+    let create = find(&eps, "POST", "/api/v1/users");
+    assert!(field(&create.request_fields, "email").required);
+    assert!(field(&create.request_fields, "nickname").nullable);
+    assert!(create.request_fields.iter().all(|f| f.name != "Secret" && f.name != "-"));
+    assert!(create.response_fields.iter().any(|f| f.name == "id"));
+
+    let get = find(&eps, "GET", "/api/v1/users/:id");
+    assert!(get.response_fields.iter().any(|f| f.name == "email"));
+    find(&eps, "GET", "/health");
+}
+
+#[test]
+fn test_chi_route_blocks_and_json_decoder() {
+    let content = format!(r#"
+import "github.com/go-chi/chi/v5"
+{GO_MODELS}
+func routes() http.Handler {{
+	r := chi.NewRouter()
+	r.Get("/health", health)
+	r.Route("/users", func(r chi.Router) {{
+		r.Post("/", h.create)
+		r.Route("/{{id}}", func(r chi.Router) {{
+			r.Get("/", h.get)
+		}})
+	}})
+	return r
+}}
+
+func (h *Handler) create(w http.ResponseWriter, r *http.Request) {{
+	var body CreateUser
+	json.NewDecoder(r.Body).Decode(&body)
+	out := User{{Email: body.Email}}
+	json.NewEncoder(w).Encode(out)
+}}
+"#);
+    let ext = ChiExtractor;
+    let eps = ext.extract(&content, "internal/http/routes.go");
+    assert_eq!(eps.len(), 3, "{eps:#?}");
+    find(&eps, "GET", "/health");
+    let create = find(&eps, "POST", "/users");
+    assert!(create.request_fields.iter().any(|f| f.name == "email"));
+    assert!(create.response_fields.iter().any(|f| f.name == "id"));
+    find(&eps, "GET", "/users/{id}");
+}
+
+#[test]
+fn test_fiber_groups_and_body_parser() {
+    let content = format!(r#"
+import "github.com/gofiber/fiber/v2"
+{GO_MODELS}
+func setup(app *fiber.App) {{
+	api := app.Group("/api")
+	api.Post("/users", func(c *fiber.Ctx) error {{
+		u := new(CreateUser)
+		if err := c.BodyParser(u); err != nil {{
+			return err
+		}}
+		ct := c.Get("Content-Type")
+		return c.Status(201).JSON(User{{Email: u.Email}})
+	}})
+	api.Delete("/users/:id", remove)
+}}
+"#);
+    let ext = FiberExtractor;
+    let eps = ext.extract(&content, "main.go");
+    assert_eq!(eps.len(), 2, "{eps:#?}");
+    let create = find(&eps, "POST", "/api/users");
+    assert!(create.request_fields.iter().any(|f| f.name == "email"));
+    assert!(create.response_fields.iter().any(|f| f.name == "id"));
+    find(&eps, "DELETE", "/api/users/:id");
+}
+
+#[test]
+fn test_koa_router_prefix_and_named_routes() {
+    let content = r#"
+const Router = require('@koa/router');
+const router = new Router({ prefix: '/api' });
+router.get('/users', listUsers);
+router.get('user', '/users/:id', getUser);
+router.del('/users/:id', removeUser);
+app.use(router.routes());
+"#;
+    let ext = KoaExtractor;
+    assert!(ext.matches(content));
+    let eps = ext.extract(content, "src/routes.js");
+    assert_eq!(eps.len(), 3, "{eps:#?}");
+    find(&eps, "GET", "/api/users");
+    find(&eps, "GET", "/api/users/:id");
+    find(&eps, "DELETE", "/api/users/:id");
+
+    // Express must not double-report koa routes.
+    assert!(!ExpressExtractor.matches(content));
+}
+
+#[test]
+fn test_hono_base_path_sub_apps_and_validators() {
+    let content = r#"
+import { Hono } from 'hono';
+import { zValidator } from '@hono/zod-validator';
+import { z } from 'zod';
+
+const createUser = z.object({
+  email: z.string().email(),
+  nickname: z.string().optional(),
+});
+
+const users = new Hono();
+users.get('/', (c) => c.json([]));
+users.post('/', zValidator('json', createUser), (c) => c.json({ ok: true }, 201));
+
+const app = new Hono().basePath('/api');
+app.route('/users', users);
+app.get('/health', (c) => c.text('ok'));
+"#;
+    let ext = HonoExtractor;
+    assert!(ext.matches(content));
+    let eps = ext.extract(content, "src/index.ts");
+    assert_eq!(eps.len(), 3, "{eps:#?}");
+    find(&eps, "GET", "/api/health");
+    find(&eps, "GET", "/api/users");
+    let create = find(&eps, "POST", "/api/users");
+    assert!(field(&create.request_fields, "email").required);
+    assert!(!field(&create.request_fields, "nickname").required);
+    assert!(!ExpressExtractor.matches(content));
+}
+
+#[test]
+fn test_ktor_routing_dsl() {
+    let content = r#"
+import io.ktor.server.routing.*
+
+@Serializable
+data class CreateUser(val email: String, @SerialName("nick_name") val nickname: String? = null)
+
+@Serializable
+data class User(val id: Long, val email: String)
+
+fun Application.module() {
+    routing {
+        get("/health") { call.respondText("ok") }
+        route("/api") {
+            route("/users") {
+                get {
+                    call.respond(listOf<User>())
+                }
+                post {
+                    val req = call.receive<CreateUser>()
+                    val user = User(1, req.email)
+                    call.respond(HttpStatusCode.Created, user)
+                }
+                get("/{id}") {
+                    val id = call.parameters["id"]
+                    call.respond(User(id!!.toLong(), "x"))
+                }
+            }
+        }
+    }
+}
+"#;
+    let ext = KtorExtractor;
+    assert!(ext.matches(content));
+    let eps = ext.extract(content, "src/main/kotlin/Routes.kt");
+    assert_eq!(eps.len(), 4, "{eps:#?}");
+    find(&eps, "GET", "/health");
+    find(&eps, "GET", "/api/users");
+    let create = find(&eps, "POST", "/api/users");
+    assert!(field(&create.request_fields, "email").required);
+    assert!(field(&create.request_fields, "nick_name").nullable);
+    assert!(create.response_fields.iter().any(|f| f.name == "id"));
+    let get = find(&eps, "GET", "/api/users/{id}");
+    assert_eq!(field(&get.response_fields, "id").field_type, "Long");
+}
+
+#[test]
+fn test_registry_reports_new_frameworks_as_backend() {
+    let registry = ExtractorRegistry::new();
+    let content = "from fastapi import FastAPI\napp = FastAPI()\n\n@app.get(\"/items\")\ndef items():\n    return []\n";
+    let results = registry.extract_all(content, "main.py");
+    let frameworks: Vec<&str> = results.iter().map(|(fw, _)| fw.as_str()).collect();
+    assert!(frameworks.contains(&"fastapi"), "{frameworks:?}");
+    assert!(!frameworks.contains(&"express"), "{frameworks:?}");
+    for fw in ["fastapi", "axum", "echo", "chi", "fiber", "koa", "hono", "ktor"] {
+        assert!(BACKEND_FRAMEWORKS.contains(&fw), "{fw} missing from BACKEND_FRAMEWORKS");
+    }
+    assert!(!BACKEND_FRAMEWORKS.contains(&"frontend"));
+}
@@ -0,0 +1,187 @@
+//! OpenAPI 3.1 emitter tests.
+
+use drift_analysis::structural::contracts::openapi_emitter::*;
+use drift_analysis::structural::contracts::reconciliation::{reconcile, ReconciliationConfig};
+use drift_analysis::structural::contracts::schema_parsers::openapi::OpenApiParser;
+use drift_analysis::structural::contracts::schema_parsers::SchemaParser;
+use drift_analysis::structural::contracts::types::*;
+use serde_json::json;
+
+fn field(name: &str, ty: &str, required: bool, nullable: bool) -> FieldSpec {
+    FieldSpec { name: name.into(), field_type: ty.into(), required, nullable }
+}
+
+fn endpoint(method: &str, path: &str, request: Vec<FieldSpec>, response: Vec<FieldSpec>) -> Endpoint {
+    Endpoint {
+        method: method.into(), path: path.into(),
+        request_fields: request, response_fields: response,
+        file: "services/users/src/routes.ts".into(), line: 12,
+    }
+}
+
+fn users_contract() -> Contract {
+    Contract {
+        id: "express:users".into(),
+        paradigm: Paradigm::Rest,
+        endpoints: vec![
+            endpoint("GET", "/users", vec![field("limit", "number", false, false)], vec![
+                field("value", "User[]", true, false),
+            ]),
+            endpoint("GET", "/users/:id", vec![], vec![
+                field("id", "number", true, false),
+                field("email", "string", true, false),
+                field("nickname", "string | null", false, false),
+            ]),
+            endpoint("POST", "/users", vec![
+                field("email", "string", true, false),
+                field("age", "Optional[int]", false, false),
+            ], vec![]),
+        ],
+        source_file: "services/users/src/routes.ts".into(),
+        framework: "express".into(),
+        confidence: 0.8,
+    }
+}
+
+fn orders_contract() -> Contract {
+    Contract {
+        id: "flask:orders".into(),
+        paradigm: Paradigm::Rest,
+        endpoints: vec![Endpoint {
+            method: "DELETE".into(), path: "/orders/<int:order_id>".into(),
+            request_fields: vec![], response_fields: vec![],
+            file: "services/orders/app.py".into(), line: 4,
+        }],
+        source_file: "services/orders/app.py".into(),
+        framework: "flask".into(),
+        confidence: 0.7,
+    }
+}
+
+#[test]
+fn emits_paths_parameters_and_schemas() {
+    let doc = emit_openapi(&[users_contract()], &OpenApiEmitConfig::default());
+    let value = serde_json::to_value(&doc).unwrap();
+
+    assert_eq!(value["openapi"], "3.1.0");
+    assert_eq!(doc.operation_count(), 3);
+
+    let list = &value["paths"]["/users"]["get"];
+    assert_eq!(list["parameters"][0]["in"], "query");
+    assert_eq!(list["parameters"][0]["required"], false);
+    let list_schema = &list["responses"]["200"]["content"]["application/json"]["schema"];
+    assert_eq!(list_schema["type"], "array");
+    assert_eq!(list_schema["items"]["x-drift-type"], "User");
+
+    let get = &value["paths"]["/users/{id}"]["get"];
+    assert_eq!(get["parameters"][0], json!({ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }));
+    let schema = &get["responses"]["200"]["content"]["application/json"]["schema"];
+    assert_eq!(schema["properties"]["id"]["type"], "number");
+    assert_eq!(schema["properties"]["nickname"]["type"], json!(["string", "null"]));
+    assert_eq!(schema["required"], json!(["id", "email"]));
+
+    let create = &value["paths"]["/users"]["post"];
+    let body = &create["requestBody"]["content"]["application/json"]["schema"];
+    assert_eq!(body["properties"]["age"]["type"], json!(["integer", "null"]));
+    assert_eq!(body["required"], json!(["email"]));
+    assert!(create["responses"]["201"].is_object());
+
+    assert_eq!(get["x-drift-confidence"], 0.8);
+    assert_eq!(get["x-drift-framework"], "express");
+    assert_eq!(get["x-drift-source"], "services/users/src/routes.ts:12");
+    assert_eq!(value["x-drift-generated"], true);
+}
+
+#[test]
+fn groups_by_service() {
+    let contracts = [users_contract(), orders_contract()];
+    let docs = emit_openapi_by_service(&contracts, &OpenApiEmitConfig::default());
+    assert_eq!(docs.keys().collect::<Vec<_>>(), vec!["orders", "users"]);
+
+    let orders = serde_json::to_value(&docs["orders"]).unwrap();
+    assert_eq!(orders["info"]["x-drift-service"], "orders");
+    let delete = &orders["paths"]["/orders/{order_id}"]["delete"];
+    assert_eq!(delete["parameters"][0]["schema"]["type"], "integer");
+    assert_eq!(delete["operationId"], "delete_orders_by_order_id");
+
+    let combined = serde_json::to_value(emit_openapi(&contracts, &OpenApiEmitConfig::default())).unwrap();
+    let tags: Vec<&str> = combined["tags"].as_array().unwrap().iter().map(|t| t["name"].as_str().unwrap()).collect();
+    assert_eq!(tags, vec!["orders", "users"]);
+}
+
+#[test]
+fn skips_consumers_specs_and_low_confidence() {
+    let mut frontend = users_contract();
+    frontend.framework = "frontend".into();
+    let mut spec = orders_contract();
+    spec.framework = "openapi".into();
+    let mut weak = orders_contract();
+    weak.confidence = 0.2;
+    let config = OpenApiEmitConfig { min_confidence: 0.5, ..Default::default() };
+    assert_eq!(emit_openapi(&[frontend, spec, weak], &config).operation_count(), 0);
+}
+
+#[test]
+fn duplicate_routes_keep_most_confident() {
+    let mut better = users_contract();
+    better.framework = "nestjs".into();
+    better.confidence = 0.95;
+    let doc = emit_openapi(&[users_contract(), better], &OpenApiEmitConfig::default());
+    assert_eq!(doc.operation_count(), 3);
+    let get = &doc.paths["/users/{id}"]["get"];
+    assert_eq!(get.drift_framework, "nestjs");
+    assert_eq!(get.operation_id, "get_users_by_id");
+}
+
+#[test]
+fn yaml_round_trips_through_parser_without_drift() {
+    let contracts = [users_contract(), orders_contract()];
+    let doc = emit_openapi(&contracts, &OpenApiEmitConfig::default());
+    let yaml = doc.to_yaml().unwrap();
+    assert!(yaml.contains("openapi: 3.1.0"));
+
+    let parsed = OpenApiParser.parse(&yaml, "generated.yaml");
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].endpoints.len(), 4);
+
+    let json_parsed = OpenApiParser.parse(&doc.to_json(), "generated.json");
+    assert_eq!(json_parsed[0].endpoints.len(), 4);
+
+    let report = reconcile(&parsed, &contracts, &ReconciliationConfig::default());
+    assert_eq!(report.matched_endpoints, 4);
+    assert!(report.drifts.is_empty(), "{:?}", report.drifts);
+}
+
+#[test]
+fn schema_and_path_inference() {
+    assert_eq!(infer_schema("Vec<i64>", false), json!({ "type": "array", "items": { "type": "integer" } }));
+    assert_eq!(infer_schema("Map<String, Object>", false), json!({ "type": "object" }));
+    assert_eq!(infer_schema("uuid", true), json!({ "type": ["string", "null"], "format": "uuid" }));
+    assert_eq!(infer_schema("any", true), json!({}));
+    assert_eq!(infer_schema("bool?", false), json!({ "type": ["boolean", "null"] }));
+
+    let (path, params) = openapi_path("api/[...slug]/{id:int}/items/<uuid:item>");
+    assert_eq!(path, "/api/{slug}/{id}/items/{item}");
+    assert_eq!(params, vec![
+        ("slug".to_string(), None),
+        ("id".to_string(), Some("integer")),
+        ("item".to_string(), Some("string")),
+    ]);
+
+    assert_eq!(service_name("services/billing/src/api.go"), "billing");
+    assert_eq!(service_name("gateway/routes.ts"), "gateway");
+    assert_eq!(service_name("src/index.ts"), "default");
+    assert_eq!(service_name("main.py"), "default");
+}
+
+#[test]
+fn schema_inference_handles_non_ascii_types() {
+    // An unclosed wrapper is not a nullability wrapper.
+    assert_eq!(infer_schema("Option<日", false), json!({ "type": "object", "x-drift-type": "Option<日" }));
+    assert_eq!(infer_schema("Optional[名前]", false), json!({ "type": ["object", "null"], "x-drift-type": "名前" }));
+    assert_eq!(
+        infer_schema("LIST<Ünïcode>", false),
+        json!({ "type": "array", "items": { "type": "object", "x-drift-type": "Ünïcode" } })
+    );
+    assert_eq!(json_type("Sequence[日付]"), Some("array"));
+}
@@ -0,0 +1,220 @@
+//! Spec ↔ implementation reconciliation tests.
+
+use drift_analysis::enforcement::rules::Severity;
+use drift_analysis::structural::contracts::reconciliation::*;
+use drift_analysis::structural::contracts::types::*;
+
+fn field(name: &str, ty: &str, required: bool, nullable: bool) -> FieldSpec {
+    FieldSpec { name: name.into(), field_type: ty.into(), required, nullable }
+}
+
+fn endpoint(method: &str, path: &str, file: &str, line: u32) -> Endpoint {
+    Endpoint {
+        method: method.into(), path: path.into(),
+        request_fields: vec![], response_fields: vec![],
+        file: file.into(), line,
+    }
+}
+
+fn contract(framework: &str, source: &str, paradigm: Paradigm, endpoints: Vec<Endpoint>) -> Contract {
+    Contract {
+        id: format!("{framework}:{source}"),
+        paradigm,
+        endpoints,
+        source_file: source.into(),
+        framework: framework.into(),
+        confidence: 0.9,
+    }
+}
+
+fn spec_users() -> Contract {
+    let mut get_user = endpoint("GET", "/users/{id}", "openapi.yaml", 0);
+    get_user.response_fields = vec![
+        field("id", "integer", true, false),
+        field("email", "string", true, false),
+        field("tags", "array", false, false),
+    ];
+    let mut create_user = endpoint("POST", "/users", "openapi.yaml", 0);
+    create_user.request_fields = vec![
+        field("email", "string", true, false),
+        field("nickname", "string", false, false),
+    ];
+    contract("openapi", "openapi.yaml", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "openapi.yaml", 0),
+        get_user,
+        create_user,
+        endpoint("DELETE", "/users/{id}", "openapi.yaml", 0),
+    ])
+}
+
+#[test]
+fn matches_across_path_param_syntaxes() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users/", "routes.ts", 3),
+        endpoint("GET", "/users/:userId", "routes.ts", 7),
+        endpoint("POST", "users", "routes.ts", 11),
+        endpoint("DELETE", "/Users/<int:id>", "routes.ts", 15),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+    assert!(report.drifts.is_empty(), "{:?}", report.drifts);
+    assert_eq!(report.spec_endpoints, 4);
+    assert_eq!(report.matched_endpoints, 4);
+    assert_eq!(report.coverage(), 1.0);
+}
+
+#[test]
+fn reports_undocumented_and_missing_endpoints() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "routes.ts", 3),
+        endpoint("GET", "/users/:id", "routes.ts", 7),
+        endpoint("POST", "/users", "routes.ts", 11),
+        endpoint("GET", "/admin/stats", "routes.ts", 20),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+
+    assert_eq!(report.count(DriftKind::MissingImplementation), 1);
+    let missing = report.drifts.iter().find(|d| d.kind == DriftKind::MissingImplementation).unwrap();
+    assert_eq!((missing.method.as_str(), missing.path.as_str()), ("DELETE", "/users/{id}"));
+    assert_eq!(missing.file, "openapi.yaml");
+
+    assert_eq!(report.count(DriftKind::UndocumentedEndpoint), 1);
+    let extra = report.drifts.iter().find(|d| d.kind == DriftKind::UndocumentedEndpoint).unwrap();
+    assert_eq!(extra.path, "/admin/stats");
+    assert_eq!((extra.file.as_str(), extra.line), ("routes.ts", 20));
+    assert_eq!(report.coverage(), 0.75);
+}
+
+#[test]
+fn ignored_prefixes_and_wildcard_methods() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("ALL", "/users", "routes.ts", 3),
+        endpoint("GET", "/users/:id", "routes.ts", 7),
+        endpoint("DELETE", "/users/:id", "routes.ts", 9),
+        endpoint("GET", "/health", "routes.ts", 1),
+    ]);
+    let config = ReconciliationConfig {
+        ignore_path_prefixes: vec!["/health".into()],
+        ..Default::default()
+    };
+    let report = reconcile(&[spec_users()], &[implemented], &config);
+    assert!(report.drifts.is_empty(), "{:?}", report.drifts);
+}
+
+#[test]
+fn field_type_required_and_nullable_drift() {
+    let mut get_user = endpoint("GET", "/users/:id", "routes.ts", 7);
+    get_user.response_fields = vec![
+        field("id", "string", true, false),
+        field("email", "string", false, true),
+        field("tags", "string", false, false),
+        field("internal_flag", "boolean", true, false),
+    ];
+    let mut create_user = endpoint("POST", "/users", "routes.ts", 11);
+    create_user.request_fields = vec![field("nickname", "string", true, false)];
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "routes.ts", 3),
+        get_user,
+        create_user,
+        endpoint("DELETE", "/users/:id", "routes.ts", 15),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+
+    let find = |kind: DriftKind, name: &str| {
+        report.drifts.iter()
+            .find(|d| d.kind == kind && d.field.as_deref() == Some(name))
+            .unwrap_or_else(|| panic!("no {kind:?} for {name}: {:?}", report.drifts))
+    };
+    assert_eq!(find(DriftKind::TypeMismatch, "id").severity, MismatchSeverity::High);
+    assert_eq!(find(DriftKind::TypeMismatch, "tags").severity, MismatchSeverity::Critical);
+    assert_eq!(find(DriftKind::RequiredMismatch, "email").severity, MismatchSeverity::Medium);
+    assert_eq!(find(DriftKind::NullableMismatch, "email").location, Some(FieldLocation::Response));
+    assert_eq!(find(DriftKind::FieldUndocumented, "internal_flag").severity, MismatchSeverity::Low);
+
+    // Request side: required email never read, optional nickname now required.
+    let email = find(DriftKind::FieldNotImplemented, "email");
+    assert_eq!(email.location, Some(FieldLocation::Request));
+    assert_eq!(email.severity, MismatchSeverity::Medium);
+    assert_eq!(find(DriftKind::RequiredMismatch, "nickname").severity, MismatchSeverity::Medium);
+
+    // Field drift is reported at the implementation site.
+    assert!(report.drifts.iter().all(|d| d.file == "routes.ts"));
+}
+
+#[test]
+fn fields_are_skipped_when_extractor_found_none() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "routes.ts", 3),
+        endpoint("GET", "/users/:id", "routes.ts", 7),
+        endpoint("POST", "/users", "routes.ts", 11),
+        endpoint("DELETE", "/users/:id", "routes.ts", 15),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+    assert_eq!(report.drifts.len(), 0);
+}
+
+#[test]
+fn paradigms_without_implementations_are_unchecked() {
+    let graphql = contract("graphql", "schema.graphql", Paradigm::GraphQL, vec![
+        endpoint("query", "users", "schema.graphql", 0),
+    ]);
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/orders", "routes.ts", 3),
+    ]);
+    let report = reconcile(&[graphql], &[implemented], &ReconciliationConfig::default());
+    assert_eq!(report.unchecked_paradigms, vec![Paradigm::GraphQL]);
+    // No REST spec at all: nothing to be undocumented against.
+    assert!(report.drifts.is_empty());
+    assert_eq!(report.spec_endpoints, 0);
+}
+
+#[test]
+fn converts_to_gate_inputs() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "routes.ts", 3),
+        endpoint("GET", "/users/:id", "routes.ts", 7),
+        endpoint("POST", "/users", "routes.ts", 11),
+        endpoint("GET", "/debug", "routes.ts", 30),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+
+    let violations = report.to_violations();
+    assert_eq!(violations.len(), 2);
+    let missing = violations.iter().find(|v| v.rule_id == "contracts/missing-implementation").unwrap();
+    assert_eq!(missing.severity, Severity::Error);
+    let undocumented = violations.iter().find(|v| v.rule_id == "contracts/undocumented-endpoint").unwrap();
+    assert_eq!(undocumented.severity, Severity::Warning);
+    assert_eq!(undocumented.id, "contracts/undocumented-endpoint-routes.ts-30-GET /debug");
+
+    let strict = report.to_constraint_input(MismatchSeverity::Low);
+    assert!(!strict.passed);
+    assert_eq!(strict.violations.len(), 2);
+    let lenient = report.to_constraint_input(MismatchSeverity::High);
+    assert_eq!(lenient.violations.len(), 1);
+    assert!(!lenient.passed);
+    assert!(report.to_constraint_input(MismatchSeverity::Critical).passed);
+}
+
+#[test]
+fn violations_in_one_spec_file_have_distinct_ids() {
+    let implemented = contract("express", "routes.ts", Paradigm::Rest, vec![
+        endpoint("GET", "/users", "routes.ts", 3),
+    ]);
+    let report = reconcile(&[spec_users()], &[implemented], &ReconciliationConfig::default());
+
+    let violations = report.to_violations();
+    let missing: Vec<_> = violations
+        .iter()
+        .filter(|v| v.rule_id == "contracts/missing-implementation")
+        .collect();
+    assert_eq!(missing.len(), 3);
+    assert!(missing.iter().all(|v| v.file == "openapi.yaml" && v.line == 0));
+    let ids: std::collections::HashSet<_> = violations.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(ids.len(), violations.len(), "{ids:?}");
+}
+
+#[test]
+fn spec_framework_detection() {
+    assert!(is_spec_contract(&spec_users()));
+    assert!(!is_spec_contract(&contract("express", "r.ts", Paradigm::Rest, vec![])));
+    assert_eq!(normalize_path("api/v1/items/[slug]/"), "/api/v1/items/{}");
+}
@@ -0,0 +1,214 @@
+//! Cross-revision contract diff tests.
+
+use std::path::Path;
+
+use drift_analysis::enforcement::rules::Severity;
+use drift_analysis::structural::contracts::revision_diff::*;
+use drift_analysis::structural::contracts::types::*;
+
+const SPEC_V1: &str = r#"
+openapi: 3.1.0
+info: { title: Users, version: "1" }
+paths:
+  /users/{id}:
+    get:
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                type: object
+                required: [id, email]
+                properties:
+                  id: { type: integer }
+                  email: { type: string }
+                  legacy_name: { type: string }
+  /users:
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [email]
+              properties:
+                email: { type: string }
+                nickname: { type: string }
+      responses:
+        "201": { description: created }
+"#;
+
+const SPEC_V2: &str = r#"
+openapi: 3.1.0
+info: { title: Users, version: "2" }
+paths:
+  /users/{id}:
+    get:
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                type: object
+                required: [id, email]
+                properties:
+                  id: { type: string }
+                  email: { type: string }
+                  avatar: { type: string }
+  /users:
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [email, password]
+              properties:
+                email: { type: string }
+                nickname: { type: string }
+                password: { type: string }
+      responses:
+        "201": { description: created }
+"#;
+
+const ROUTES_V1: &str = "const express = require('express');\nconst app = express();\napp.get('/health', handler);\napp.get('/orders/:id', handler);\n";
+const ROUTES_V2: &str = "const express = require('express');\nconst app = express();\napp.get('/health', handler);\napp.get('/orders/:orderId', handler);\napp.post('/orders', handler);\n";
+
+fn commit(repo: &git2::Repository, files: &[(&str, Option<&str>)], message: &str) -> git2::Oid {
+    let root = repo.workdir().unwrap();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        match content {
+            Some(content) => {
+                let full = root.join(path);
+                std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+                std::fs::write(&full, content).unwrap();
+                index.add_path(Path::new(path)).unwrap();
+            }
+            None => {
+                std::fs::remove_file(root.join(path)).unwrap();
+                index.remove_path(Path::new(path)).unwrap();
+            }
+        }
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parents: Vec<git2::Commit> = repo.head().ok()
+        .and_then(|h| h.peel_to_commit().ok())
+        .into_iter()
+        .collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+fn two_revisions() -> (tempfile::TempDir, git2::Oid, git2::Oid) {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    let base = commit(&repo, &[
+        ("api/openapi.yaml", Some(SPEC_V1)),
+        ("services/orders/routes.js", Some(ROUTES_V1)),
+        ("node_modules/pkg/routes.js", Some("app.get('/vendored', h);")),
+    ], "v1");
+    let head = commit(&repo, &[
+        ("api/openapi.yaml", Some(SPEC_V2)),
+        ("services/orders/routes.js", Some(ROUTES_V2)),
+    ], "v2");
+    (dir, base, head)
+}
+
+fn find<'a>(diff: &'a ContractDiff, method: &str, path: &str) -> &'a EndpointDiff {
+    diff.endpoints.iter()
+        .find(|e| e.method.eq_ignore_ascii_case(method) && e.path == path)
+        .unwrap_or_else(|| panic!("no diff for {method} {path}: {:#?}", diff.endpoints))
+}
+
+#[test]
+fn diffs_revisions_from_object_database() {
+    let (dir, base, head) = two_revisions();
+    // Prove nothing is read from the working tree.
+    std::fs::remove_dir_all(dir.path().join("api")).unwrap();
+
+    let diff = diff_revisions(dir.path(), &base.to_string(), "HEAD", &RevisionDiffConfig::default()).unwrap();
+    assert_eq!(diff.base, base.to_string());
+    assert_eq!(diff.head, head.to_string());
+    assert!(diff.has_breaking());
+
+    let get_user = find(&diff, "GET", "/users/{id}");
+    assert_eq!(get_user.status, EndpointStatus::Modified);
+    assert!(get_user.is_breaking());
+    let kinds: Vec<BreakingChangeType> = get_user.changes.iter().map(|c| c.change_type).collect();
+    assert!(kinds.contains(&BreakingChangeType::TypeChanged), "{kinds:?}");
+    assert!(kinds.contains(&BreakingChangeType::FieldRemoved), "{kinds:?}");
+    assert!(get_user.additions.iter().any(|a| a.contains("avatar")));
+
+    let violations = diff.to_violations();
+    let ids: std::collections::HashSet<&str> = violations.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(ids.len(), violations.len(), "{ids:?}");
+    let get_user_ids = violations.iter().filter(|v| v.message.starts_with("GET /users/{id}")).count();
+    assert!(get_user_ids >= 2);
+
+    let create = find(&diff, "POST", "/users");
+    assert!(create.changes.iter().any(|c| c.change_type == BreakingChangeType::RequiredAdded));
+
+    // `:id` → `:orderId` is the same route; the new POST is additive only.
+    assert!(diff.endpoints.iter().all(|e| e.path != "/orders/:orderId"));
+    let new_order = find(&diff, "POST", "/orders");
+    assert_eq!(new_order.status, EndpointStatus::Added);
+    assert!(!new_order.is_breaking());
+    assert_eq!(diff.non_breaking().count(), 1);
+    assert!(diff.endpoints.iter().all(|e| e.path != "/vendored"));
+}
+
+#[test]
+fn reverting_to_same_tree_has_no_changes() {
+    let (dir, base, _) = two_revisions();
+    let diff = diff_revisions(dir.path(), &base.to_string(), &base.to_string(), &RevisionDiffConfig::default()).unwrap();
+    assert!(diff.endpoints.is_empty());
+    assert!(diff.to_constraint_input().passed);
+}
+
+#[test]
+fn removed_endpoints_fail_the_gate() {
+    let (dir, _, _) = two_revisions();
+    let repo = git2::Repository::open(dir.path()).unwrap();
+    commit(&repo, &[("services/orders/routes.js", None)], "drop orders");
+
+    let diff = diff_revisions(dir.path(), "HEAD~1", "HEAD", &RevisionDiffConfig::default()).unwrap();
+    let removed: Vec<&EndpointDiff> = diff.breaking().collect();
+    assert_eq!(removed.len(), 3);
+    assert!(removed.iter().all(|e| e.status == EndpointStatus::Removed));
+    assert!(removed.iter().all(|e| e.changes[0].change_type == BreakingChangeType::EndpointRemoved));
+
+    let violations = diff.to_violations();
+    assert_eq!(violations.len(), 3);
+    assert!(violations.iter().all(|v| v.severity == Severity::Error && v.file == "services/orders/routes.js"));
+    let constraint = diff.to_constraint_input();
+    assert!(!constraint.passed);
+    assert_eq!(constraint.violations.len(), 3);
+}
+
+#[test]
+fn include_prefixes_and_bad_revisions() {
+    let (dir, base, head) = two_revisions();
+    let config = RevisionDiffConfig { include_prefixes: vec!["services/".into()], ..Default::default() };
+    let diff = diff_revisions(dir.path(), &base.to_string(), &head.to_string(), &config).unwrap();
+    assert!(!diff.has_breaking());
+    assert_eq!(diff.endpoints.len(), 1);
+
+    assert!(diff_revisions(dir.path(), "no-such-ref", "HEAD", &config).is_err());
+}
+
+#[test]
+fn contract_sets_ignore_frontend_consumers() {
+    let ep = Endpoint {
+        method: "GET".into(), path: "/api/me".into(),
+        request_fields: vec![], response_fields: vec![],
+        file: "app.tsx".into(), line: 3,
+    };
+    let frontend = Contract {
+        id: "app.tsx:frontend".into(), paradigm: Paradigm::Rest, endpoints: vec![ep],
+        source_file: "app.tsx".into(), framework: "frontend".into(), confidence: 0.6,
+    };
+    assert!(diff_contract_sets(&[frontend], &[]).is_empty());
+}
@@ -213,6 +213,7 @@ fn make_ctx<'a>(pr: &'a ParseResult) -> DetectionContext<'a> {
         classes: &pr.classes,
         call_sites: &pr.call_sites,
         parse_result: pr,
+        embedded_sql_cache: Default::default(),
     }
 }
 
@@ -491,7 +492,7 @@ fn make_sequelize_pr() -> ParseResult {
 #[test]
 fn deep_all_boundary_extractors() {
     let extractors = extractors::create_all_extractors();
-    assert_eq!(extractors.len(), 10);
+    assert_eq!(extractors.len(), 17);
     for ext in &extractors {
         let fw = ext.framework();
         assert!(!fw.name().is_empty());
@@ -926,6 +927,7 @@ fn deep_orm_framework_all_variants() {
         OrmFramework::Eloquent, OrmFramework::Doctrine, OrmFramework::Propel,
         OrmFramework::Gorm, OrmFramework::Ent, OrmFramework::Sqlx,
         OrmFramework::Diesel, OrmFramework::SeaOrm, OrmFramework::SqlxRust,
+        OrmFramework::Exposed,
         OrmFramework::Unknown,
     ];
     for fw in &frameworks {
@@ -472,13 +472,13 @@ fn critical_orchestrator_skipped_gates_are_passing() {
     }
 }
 
-/// All 6 gates must appear in results even when some are skipped.
+/// All 7 gates must appear in results even when some are skipped.
 #[test]
 fn critical_orchestrator_all_gates_present() {
     let orchestrator = GateOrchestrator::new();
     let input = GateInput::default();
     let results = orchestrator.execute(&input).unwrap();
-    assert_eq!(results.len(), 6, "Must have exactly 6 gate results");
+    assert_eq!(results.len(), 7, "Must have exactly 7 gate results");
 
     let ids: Vec<GateId> = results.iter().map(|r| r.gate_id).collect();
     for expected in GateId::all() {
@@ -953,6 +953,7 @@ fn critical_sarif_line_zero_clamped() {
             rule_id: "test/rule".to_string(), message: "test".to_string(),
             quick_fix: None, cwe_id: None, owasp_category: None,
             suppressed: false, is_new: false,
+            owners: Vec::new(),
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
@@ -984,6 +985,7 @@ fn critical_sarif_both_cwe_and_owasp() {
             cwe_id: Some(89),
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false, is_new: false,
+            owners: Vec::new(),
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
@@ -1018,6 +1020,7 @@ fn critical_sarif_rule_deduplication() {
         message: format!("Violation {i}"),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).collect();
 
     let results = vec![GateResult {
@@ -1123,6 +1126,7 @@ fn critical_is_new_flag_preserved() {
         rule_id: "test/new".to_string(), message: "New violation".to_string(),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: true,
+        owners: Vec::new(),
     };
 
     // Through JSON serialization
@@ -1211,6 +1215,7 @@ fn critical_storage_null_optional_fields() {
         owasp_category: None, // NULL
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
 
     insert_violation(&conn, &v).unwrap();
@@ -1247,6 +1252,7 @@ fn critical_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
 
     let v2 = ViolationRow {
@@ -1266,6 +1272,7 @@ fn critical_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
 
     insert_violation(&conn, &v1).unwrap();
@@ -776,6 +776,7 @@ fn e2e_full_pipeline_all_languages() {
             &call_graph,
             NodeIndex::new(0),
             call_graph.function_count().max(1) as u32,
+            None,
         );
         eprintln!(
             "[Impact] Blast radius: {} callers, risk {:.2} in {:?}",
@@ -861,7 +862,7 @@ fn e2e_full_pipeline_all_languages() {
     for result in &gate_results {
         eprintln!("  {} — {:?} (score: {:.2})", result.gate_id, result.status, result.score);
     }
-    assert_eq!(gate_results.len(), 6, "Should evaluate all 6 gates");
+    assert_eq!(gate_results.len(), 7, "Should evaluate all 7 gates");
 
     // Policy Engine
     let policy_start = Instant::now();
@@ -1322,7 +1323,7 @@ fn e2e_error_recovery_malformed_inputs() {
     let orchestrator = GateOrchestrator::new();
     let empty_gate_input = GateInput::default();
     let gate_results = orchestrator.execute(&empty_gate_input).unwrap();
-    assert_eq!(gate_results.len(), 6, "Should evaluate all 6 gates even with empty input");
+    assert_eq!(gate_results.len(), 7, "Should evaluate all 7 gates even with empty input");
 
     // All reporters with empty gate results should produce valid output
     for &format in reporters::available_formats() {
@@ -1424,6 +1425,7 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
                 },
                 drift_analysis::enforcement::rules::Violation {
                     id: "sec-002".to_string(),
@@ -1441,6 +1443,7 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A02:2025".to_string()),
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
                 },
             ],
         ),
@@ -1648,6 +1651,7 @@ fn build_gate_input(files: &[(&str, &str)]) -> GateInput {
         current_health_score: Some(78.0),
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
+        duplication: None,
         feedback_stats: None,
     }
 }
@@ -2695,6 +2699,7 @@ fn e2e_policy_engine_presets_and_modes() {
         GateResult::pass(GateId::TestCoverage, 1.0, "OK".to_string()),
         GateResult::pass(GateId::ConstraintVerification, 1.0, "OK".to_string()),
         GateResult::pass(GateId::Regression, 1.0, "OK".to_string()),
+        GateResult::pass(GateId::Duplication, 1.0, "OK".to_string()),
     ];
 
     let strict_all_pass = PolicyEngine::new(Policy::strict()).evaluate(&all_pass);
@@ -3763,13 +3768,14 @@ fn e2e_gate_predecessor_dag() {
         current_health_score: Some(50.0), // Significant drop
         predecessor_results,
         baseline_violations: std::collections::HashSet::new(),
+        duplication: None,
         feedback_stats: None,
     };
 
     let orchestrator = GateOrchestrator::new();
     let results = orchestrator.execute(&gate_input).unwrap();
 
-    assert_eq!(results.len(), 6, "Should evaluate all 6 gates");
+    assert_eq!(results.len(), 7, "Should evaluate all 7 gates");
 
     // Check regression gate — it should detect the health score drop
     let regression_gate = results.iter()
@@ -3800,6 +3806,7 @@ fn e2e_gate_predecessor_dag() {
         current_health_score: Some(85.0),
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
+        duplication: None,
         feedback_stats: None,
     };
 
@@ -4105,6 +4112,7 @@ fn e2e_reporter_idempotency() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
                 },
             ],
         ),
@@ -6809,7 +6817,7 @@ fn e2e_rules_evaluator_severity_dedup_suppression() {
 fn e2e_gate_orchestrator_dag() {
     use drift_analysis::enforcement::gates::{GateOrchestrator, GateInput, GateId, GateStatus};
 
-    // Test default orchestrator (6 gates with dependencies)
+    // Test default orchestrator (7 gates with dependencies)
     let orchestrator = GateOrchestrator::new();
 
     // Validate dependencies (no circular deps)
@@ -6827,7 +6835,7 @@ fn e2e_gate_orchestrator_dag() {
         );
     }
 
-    assert_eq!(results.len(), 6, "Should have 6 gate results");
+    assert_eq!(results.len(), 7, "Should have 7 gate results");
 
     // All gates should produce a result (passed, failed, warned, or skipped)
     for r in &results {
@@ -6955,7 +6963,7 @@ fn e2e_policy_engine_all_modes() {
     assert!(!required_result.overall_passed, "Should fail when required gate fails, even in AnyMustPass mode");
     assert!(!required_result.required_gates_passed);
 
-    // Test with all passing gates — strict policy requires all 6 gates
+    // Test with all passing gates — strict policy requires all 7 gates
     let all_pass = vec![
         GateResult::pass(GateId::PatternCompliance, 90.0, "OK".to_string()),
         GateResult::pass(GateId::ConstraintVerification, 85.0, "OK".to_string()),
@@ -6963,13 +6971,14 @@ fn e2e_policy_engine_all_modes() {
         GateResult::pass(GateId::TestCoverage, 80.0, "OK".to_string()),
         GateResult::pass(GateId::ErrorHandling, 88.0, "OK".to_string()),
         GateResult::pass(GateId::Regression, 95.0, "OK".to_string()),
+        GateResult::pass(GateId::Duplication, 92.0, "OK".to_string()),
     ];
     let strict2 = Policy::strict();
     let strict_engine2 = PolicyEngine::new(strict2);
     let all_pass_result = strict_engine2.evaluate(&all_pass);
     assert!(all_pass_result.overall_passed, "All passing gates should pass strict policy");
 
-    // Test with empty results — strict policy requires all 6 gates, so empty = fail
+    // Test with empty results — strict policy requires all 7 gates, so empty = fail
     let empty_result = strict_engine2.evaluate(&[]);
     assert!(!empty_result.overall_passed, "Empty results should fail strict policy (missing required gates)");
 
@@ -7004,6 +7013,7 @@ fn e2e_reporter_all_formats() {
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         },
         Violation {
             id: "naming-001".to_string(),
@@ -7021,6 +7031,7 @@ fn e2e_reporter_all_formats() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     ];
 
@@ -8737,7 +8748,7 @@ fn e2e_blast_radius_risk_scoring() {
     });
 
     // Blast radius for shared_util (high fan-in)
-    let blast = compute_blast_radius(&graph, shared_util, 20);
+    let blast = compute_blast_radius(&graph, shared_util, 20, None);
     eprintln!(
         "[BlastRadius] sharedUtil: {} callers, max_depth={}, risk={:.3}",
         blast.caller_count, blast.max_depth, blast.risk_score.overall
@@ -8746,7 +8757,7 @@ fn e2e_blast_radius_risk_scoring() {
     assert!(blast.risk_score.blast_radius > 0.4, "High fan-in should have significant blast radius factor");
 
     // Blast radius for a leaf caller (low fan-in)
-    let leaf_blast = compute_blast_radius(&graph, callers[9], 20);
+    let leaf_blast = compute_blast_radius(&graph, callers[9], 20, None);
     eprintln!(
         "[BlastRadius] caller9: {} callers, risk={:.3}",
         leaf_blast.caller_count, leaf_blast.risk_score.overall
@@ -8755,7 +8766,7 @@ fn e2e_blast_radius_risk_scoring() {
     assert!(leaf_blast.risk_score.blast_radius < 0.01, "Leaf should have near-zero blast radius");
 
     // All blast radii
-    let all_radii = compute_all_blast_radii(&graph);
+    let all_radii = compute_all_blast_radii(&graph, None);
     assert_eq!(all_radii.len(), 11, "Should have blast radius for all 11 functions");
 
     // RiskScore computation
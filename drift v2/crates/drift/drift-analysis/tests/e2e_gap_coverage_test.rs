@@ -258,7 +258,7 @@ fn isolation_zero_violations_gates_policy_reporters() {
     let orchestrator = GateOrchestrator::new();
     let input = GateInput::default();
     let results = orchestrator.execute(&input).unwrap();
-    assert_eq!(results.len(), 6, "Should still produce 6 gate results with zero input");
+    assert_eq!(results.len(), 7, "Should still produce 7 gate results with zero input");
 
     // All gates should pass with no input (no violations = good)
     for r in &results {
@@ -540,8 +540,8 @@ fn edge_case_gate_input_with_feedback_stats() {
     // Gates should evaluate without panic
     let orchestrator = GateOrchestrator::new();
     let results = orchestrator.execute(&input).unwrap();
-    assert_eq!(results.len(), 6);
-    eprintln!("[EdgeCase] GateInput with FeedbackStatsProvider: 6 gates evaluated");
+    assert_eq!(results.len(), 7);
+    eprintln!("[EdgeCase] GateInput with FeedbackStatsProvider: 7 gates evaluated");
 }
 
 /// Aggregation pipeline with a single pattern (edge case: can't compute variance).
@@ -636,6 +636,7 @@ fn storage_roundtrip_violation_field_fidelity() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     };
 
     enforcement::insert_violation(&conn, &original).unwrap();
@@ -685,6 +686,7 @@ fn storage_roundtrip_suppressed_violation() {
         owasp_category: None,
         suppressed: true,
         is_new: false,
+        owners: Vec::new(),
     };
 
     enforcement::insert_violation(&conn, &v).unwrap();
@@ -895,6 +897,7 @@ fn storage_roundtrip_multiple_violations_ordering() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         };
         enforcement::insert_violation(&conn, &v).unwrap();
     }
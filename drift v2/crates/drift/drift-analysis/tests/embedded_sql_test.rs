@@ -0,0 +1,298 @@
+//! Embedded SQL tests — dialect-aware parsing, query reconstruction across
+//! languages, injection candidates, taint sink registration and N+1.
+
+use std::path::Path;
+
+use drift_analysis::graph::taint::{SinkType, TaintRegistry};
+use drift_analysis::language_provider::embedded_sql::sql::parse;
+use drift_analysis::language_provider::embedded_sql::{
+    analyze_embedded_sql, looks_like_sql, register_taint_sinks, EmbeddedSqlResult, HolePosition, QueryConstruction,
+    SqlDialect,
+};
+use drift_analysis::language_provider::DataOperation;
+use drift_analysis::parsers::manager::ParserManager;
+
+fn analyze(source: &str, file: &str) -> EmbeddedSqlResult {
+    let pr = ParserManager::new().parse(source.as_bytes(), Path::new(file)).unwrap();
+    analyze_embedded_sql(&pr, source)
+}
+
+fn tables(sql: &str, dialect: SqlDialect) -> Vec<(String, DataOperation)> {
+    let parsed = parse(sql, dialect).unwrap();
+    parsed.tables.into_iter().map(|t| (t.name, t.operation)).collect()
+}
+
+#[test]
+fn parses_tables_columns_and_aliases() {
+    let parsed = parse(
+        "SELECT u.id, u.email, o.total FROM public.users u JOIN \"orders\" AS o ON o.user_id = u.id WHERE u.id = $1",
+        SqlDialect::Postgres,
+    )
+    .unwrap();
+    assert_eq!(parsed.operation, DataOperation::Join);
+    let names: Vec<&str> = parsed.tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["users", "orders"]);
+    assert_eq!(parsed.placeholders, 1);
+    assert!(parsed.columns.iter().any(|c| c.table.as_deref() == Some("users") && c.name == "email"));
+    assert!(parsed.columns.iter().any(|c| c.table.as_deref() == Some("orders") && c.name == "user_id"));
+
+    assert_eq!(
+        tables("INSERT INTO `logs` (`msg`) VALUES (?) ON DUPLICATE KEY UPDATE msg = VALUES(msg)", SqlDialect::MySql),
+        [("logs".to_string(), DataOperation::Upsert)]
+    );
+    assert_eq!(
+        tables(
+            "WITH recent AS (SELECT * FROM events WHERE ts > now() - interval '1 day') \
+             DELETE FROM sessions WHERE id IN (SELECT session_id FROM recent)",
+            SqlDialect::Postgres
+        ),
+        [("events".to_string(), DataOperation::Select), ("sessions".to_string(), DataOperation::Delete)]
+    );
+    assert_eq!(
+        tables("UPDATE accounts SET balance = balance - :amt WHERE id = :id", SqlDialect::Generic),
+        [("accounts".to_string(), DataOperation::Update)]
+    );
+    assert_eq!(parse("SELECT count(*) FROM users", SqlDialect::Sqlite).unwrap().operation, DataOperation::Count);
+    assert!(looks_like_sql("select id from users where id = ?"));
+    assert!(!looks_like_sql("Select a user from the list"));
+}
+
+#[test]
+fn detects_dialect_from_driver_imports() {
+    assert_eq!(SqlDialect::detect("import psycopg2\n"), SqlDialect::Postgres);
+    assert_eq!(SqlDialect::detect("const mysql = require('mysql2');"), SqlDialect::MySql);
+    assert_eq!(SqlDialect::detect("using Microsoft.Data.SqlClient;"), SqlDialect::SqlServer);
+    assert_eq!(SqlDialect::detect("fn main() {}"), SqlDialect::Generic);
+}
+
+#[test]
+fn js_template_literal_is_injection_candidate_but_tagged_template_is_not() {
+    let result = analyze(
+        r#"
+import { sql } from '@vercel/postgres';
+
+async function findUser(db, req) {
+  const rows = await db.query(`SELECT id, email FROM users WHERE name = '${req.query.name}'`);
+  return rows;
+}
+
+async function findUserSafe(req) {
+  return sql`SELECT id FROM users WHERE name = ${req.query.name}`;
+}
+
+async function byIds(db, ids) {
+  return db.query(`SELECT * FROM users WHERE id IN (${ids.map(() => '?').join(',')})`, ids);
+}
+"#,
+        "src/users.js",
+    );
+    assert_eq!(result.queries.len(), 3);
+    assert!(result.queries.iter().all(|q| q.dialect == SqlDialect::Postgres));
+
+    let unsafe_query = &result.queries[0];
+    assert_eq!(unsafe_query.api, "db.query");
+    assert_eq!(unsafe_query.construction, QueryConstruction::Interpolated);
+    assert_eq!(unsafe_query.line, 4);
+    assert_eq!(unsafe_query.function.as_deref(), Some("findUser"));
+    assert_eq!(unsafe_query.tables[0].name, "users");
+
+    assert_eq!(result.queries[1].construction, QueryConstruction::Parameterized);
+
+    assert_eq!(result.injection_candidates.len(), 1);
+    let candidate = &result.injection_candidates[0];
+    assert_eq!(candidate.fragments, ["req.query.name"]);
+    assert_eq!(candidate.position, HolePosition::Value);
+    assert_eq!((candidate.api.as_str(), candidate.line), ("db.query", 4));
+}
+
+#[test]
+fn python_percent_format_and_fstring_queries() {
+    let result = analyze(
+        r#"
+import psycopg2
+
+def search(cursor, term, order):
+    cursor.execute("SELECT id FROM products WHERE name LIKE '%%%s%%'" % term)
+    cursor.execute(f"SELECT id, price FROM products ORDER BY {order}")
+    cursor.execute("SELECT id FROM products WHERE sku = %s", (term,))
+"#,
+        "app/search.py",
+    );
+    assert_eq!(result.queries.len(), 3);
+    let constructions: Vec<QueryConstruction> = result.queries.iter().map(|q| q.construction).collect();
+    assert_eq!(
+        constructions,
+        [QueryConstruction::Formatted, QueryConstruction::Interpolated, QueryConstruction::Literal]
+    );
+    assert_eq!(result.queries[2].placeholders, 1);
+
+    let positions: Vec<HolePosition> = result.injection_candidates.iter().map(|c| c.position).collect();
+    assert_eq!(positions, [HolePosition::Value, HolePosition::Identifier]);
+    assert_eq!(result.injection_candidates[1].fragments, ["order"]);
+}
+
+#[test]
+fn adjacent_literals_separated_by_multibyte_whitespace() {
+    let result = analyze(
+        "import sqlite3\n\ndef find(cur):\n    cur.execute(\"SELECT * FROM users \"\u{3000}\"WHERE id = 1\")\n",
+        "app/find.py",
+    );
+    assert_eq!(result.queries.len(), 1);
+    assert_eq!(result.queries[0].sql, "SELECT * FROM users WHERE id = 1");
+    assert_eq!(result.queries[0].construction, QueryConstruction::Literal);
+}
+
+#[test]
+fn concatenation_through_local_variables() {
+    let go = analyze(
+        r#"
+package store
+
+import "database/sql"
+
+func Orders(db *sql.DB, status string) {
+	query := "SELECT id, total FROM orders WHERE status = '" + status + "'"
+	query += " ORDER BY created_at DESC"
+	rows, _ := db.Query(query)
+	_ = rows
+}
+"#,
+        "store/orders.go",
+    );
+    assert_eq!(go.queries.len(), 1);
+    let q = &go.queries[0];
+    assert_eq!(q.construction, QueryConstruction::Concatenated);
+    assert_eq!(q.fragments, ["status"]);
+    assert!(q.sql.contains("ORDER BY created_at"));
+    assert!(q.columns.iter().any(|c| c.name == "created_at"));
+    assert_eq!(go.injection_candidates[0].function.as_deref(), Some("Orders"));
+
+    let java = analyze(
+        r#"
+class ReportDao {
+    private static final String TABLE = "reports";
+
+    List<Report> load(JdbcTemplate jdbc, String owner) {
+        String sql = String.format("SELECT * FROM %s WHERE owner = '%s'", TABLE, owner);
+        return jdbc.queryForList(sql);
+    }
+}
+"#,
+        "src/ReportDao.java",
+    );
+    assert_eq!(java.queries.len(), 1);
+    assert_eq!(java.queries[0].construction, QueryConstruction::Formatted);
+    // The constant table name is safe; the owner value is not.
+    assert_eq!(java.injection_candidates[0].fragments, ["owner"]);
+    assert_eq!(java.injection_candidates[0].position, HolePosition::Value);
+}
+
+#[test]
+fn per_row_queries_in_loops_are_n_plus_one() {
+    let result = analyze(
+        r#"
+async function load(db, users) {
+  for (const user of users) {
+    user.orders = await db.query('SELECT * FROM orders WHERE user_id = $1', [user.id]);
+  }
+  users.forEach(async (u) => {
+    await db.query('UPDATE users SET seen = now() WHERE id = $1', [u.id]);
+  });
+  const all = await db.query('SELECT * FROM orders WHERE user_id = ANY($1)', [users.map((u) => u.id)]);
+  return all;
+}
+"#,
+        "src/load.js",
+    );
+    assert_eq!(result.queries.len(), 3);
+    assert_eq!(result.queries[0].loop_line, Some(2));
+    assert_eq!(result.queries[1].loop_line, Some(5));
+    assert_eq!(result.queries[2].loop_line, None);
+
+    // Writes and batched reads are not N+1.
+    assert_eq!(result.n_plus_one.len(), 1);
+    let detection = &result.n_plus_one[0];
+    assert_eq!((detection.line, detection.loop_line), (3, 2));
+    assert_eq!(detection.framework, "raw_sql");
+    assert!(result.injection_candidates.is_empty());
+
+    let py = analyze(
+        "def totals(cursor, ids):\n    for row in cursor.execute(\"SELECT id FROM carts\"):\n        cursor.execute(\"SELECT sum(price) FROM items WHERE cart_id = ?\", (row[0],))\n",
+        "app/carts.py",
+    );
+    assert_eq!(py.queries[0].loop_line, None);
+    assert_eq!(py.queries[1].loop_line, Some(1));
+    assert_eq!(py.n_plus_one.len(), 1);
+}
+
+#[test]
+fn fragments_and_heredocs() {
+    let ruby = analyze(
+        r#"
+class Report
+  def run(params)
+    User.where("name = '#{params[:name]}'")
+    ActiveRecord::Base.connection.exec_query(<<~SQL)
+      SELECT id FROM invoices WHERE paid = true
+    SQL
+  end
+end
+"#,
+        "app/models/report.rb",
+    );
+    assert_eq!(ruby.queries.len(), 1);
+    assert_eq!(ruby.queries[0].tables[0].name, "invoices");
+    assert_eq!(ruby.injection_candidates.len(), 1);
+    assert_eq!(ruby.injection_candidates[0].api, "User.where");
+
+    let php = analyze(
+        "<?php\n$rows = mysqli_query($conn, \"SELECT * FROM posts WHERE slug = '$slug'\");\n",
+        "web/post.php",
+    );
+    assert_eq!(php.queries[0].construction, QueryConstruction::Interpolated);
+    assert_eq!(php.injection_candidates[0].fragments, ["$slug"]);
+}
+
+#[test]
+fn candidates_register_taint_sinks() {
+    let result = analyze(
+        "function run(repo, q) {\n  return repo.runSql(`DELETE FROM carts WHERE id = ${q.id}`);\n}\nfunction go(db, q) {\n  return db.query(\"SELECT * FROM t WHERE a = \" + q.a);\n}\n",
+        "src/run.js",
+    );
+    // `runSql` is not a known API; only the `db.query` call is found.
+    assert_eq!(result.injection_candidates.len(), 1);
+    let mut registry = TaintRegistry::with_defaults();
+    let before = registry.sinks.len();
+    register_taint_sinks(&mut registry, &result.injection_candidates);
+    // `db.query` is already a default sink.
+    assert_eq!(registry.sinks.len(), before);
+
+    let ruby = analyze(
+        "def find(name)\n  DB.select_all(\"SELECT * FROM users WHERE name = '\" + name + \"'\")\nend\n",
+        "lib/find.rb",
+    );
+    register_taint_sinks(&mut registry, &ruby.injection_candidates);
+    assert_eq!(registry.sinks.len(), before + 1);
+    assert_eq!(registry.match_sink("DB.select_all").unwrap().sink_type, SinkType::SqlQuery);
+}
+
+#[test]
+fn pipeline_result_carries_embedded_sql_into_the_taint_registry() {
+    use drift_analysis::engine::{AnalysisPipeline, DetectionEngine, ResolutionIndex, VisitorRegistry};
+    use drift_analysis::graph::taint::analyze_intraprocedural;
+
+    let source = "def find(params)\n  DB.select_all(\"SELECT * FROM users WHERE name = '\" + params[:name] + \"'\")\nend\n";
+    let (pr, tree) = ParserManager::new()
+        .parse_returning_tree(source.as_bytes(), Path::new("lib/find.rb"))
+        .unwrap();
+    let mut pipeline = AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new()));
+    let result = pipeline.analyze_file(&pr, source.as_bytes(), &tree, &mut ResolutionIndex::new());
+    assert_eq!(result.embedded_sql.injection_candidates.len(), 1);
+    assert_eq!(result.embedded_sql.queries.len(), 1);
+
+    let mut registry = TaintRegistry::with_defaults();
+    assert!(analyze_intraprocedural(&pr, &registry).is_empty(), "DB.select_all is not a default sink");
+    register_taint_sinks(&mut registry, &result.embedded_sql.injection_candidates);
+    let flows = analyze_intraprocedural(&pr, &registry);
+    assert!(flows.iter().any(|f| f.sink.sink_type == SinkType::SqlQuery && f.sink.line == 1));
+}
@@ -330,7 +330,8 @@ fn eft_gate_12_regression_fails_on_new_errors() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: true, // This is a NEW error
+                is_new: true, // This is a NEW error,
+                owners: Vec::new(),
             }],
             warnings: vec![],
             execution_time_ms: 0,
@@ -381,7 +382,8 @@ fn eft_gate_13_regression_passes_existing_only() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: false, // NOT new
+                is_new: false, // NOT new,
+                owners: Vec::new(),
             }],
             warnings: vec![],
             execution_time_ms: 0,
@@ -402,7 +404,7 @@ fn eft_gate_13_regression_passes_existing_only() {
     assert!(result.passed, "Regression gate should pass when only existing violations");
 }
 
-/// EFT-GATE-14: Orchestrator executes 6 gates in dependency order.
+/// EFT-GATE-14: Orchestrator executes 7 gates in dependency order.
 #[test]
 fn eft_gate_14_orchestrator_dependency_order() {
     let input = GateInputBuilder::new()
@@ -446,12 +448,13 @@ fn eft_gate_14_orchestrator_dependency_order() {
         })
         .previous_health_score(80.0)
         .current_health_score(82.0)
+        .duplication_from_clones(&drift_analysis::structural::clones::CloneReport::default(), 10.0)
         .build();
 
     let orchestrator = GateOrchestrator::new();
     let results = orchestrator.execute(&input).unwrap();
 
-    assert_eq!(results.len(), 6, "Should have 6 gate results");
+    assert_eq!(results.len(), 7, "Should have 7 gate results");
 
     // Verify all gates ran (none skipped since pattern compliance passes)
     for result in &results {
@@ -619,7 +622,7 @@ fn eft_gate_18_full_pipeline_real_scores() {
     let orchestrator = GateOrchestrator::new();
     let results = orchestrator.execute(&input).unwrap();
 
-    assert_eq!(results.len(), 6);
+    assert_eq!(results.len(), 7);
 
     // PatternCompliance should fail (high confidence outlier = Error)
     let pc = results.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
@@ -1028,6 +1031,7 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: Some("A03:2021-Injection".to_string()),
                 suppressed: false,
                 is_new: true,
+                owners: Vec::new(),
             },
             Violation {
                 id: "v2".to_string(),
@@ -1045,6 +1049,7 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             },
             Violation {
                 id: "v3".to_string(),
@@ -1062,6 +1067,7 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: true, // suppressed — should be excluded from most outputs
                 is_new: false,
+                owners: Vec::new(),
             },
         ],
         warnings: vec!["Health score dropped 5 points".to_string()],
@@ -1844,6 +1850,7 @@ fn eft_napi_01_violation_row_new_fields_round_trip() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -1882,6 +1889,7 @@ fn eft_napi_02_violation_row_null_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -1982,6 +1990,7 @@ fn eft_napi_05_query_by_file_returns_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     }).unwrap();
 
     insert_violation(&conn, &ViolationRow {
@@ -2001,6 +2010,7 @@ fn eft_napi_05_query_by_file_returns_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let rows = query_violations_by_file(&conn, "src/target.ts").unwrap();
@@ -2145,6 +2155,7 @@ fn eft_napi_10_reporter_generates_from_gate_results() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     }];
 
     let gate_results = vec![GateResult {
@@ -2225,6 +2236,7 @@ fn eft_napi_12_upsert_preserves_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     }).unwrap();
 
     // Upsert with different values for new fields
@@ -2245,6 +2257,7 @@ fn eft_napi_12_upsert_preserves_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let rows = query_all_violations(&conn).unwrap();
@@ -2314,6 +2327,7 @@ fn eft_napi_14_batch_mixed_new_fields() {
             owasp_category: None,
             suppressed: false,
             is_new: i % 5 == 0,
+            owners: Vec::new(),
         }).unwrap();
     }
 
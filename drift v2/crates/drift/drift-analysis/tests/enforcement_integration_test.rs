@@ -135,7 +135,7 @@ fn test_full_enforcement_round_trip() {
     let violations = evaluator.evaluate(&input);
     assert!(!violations.is_empty(), "Step 1: Should detect violations");
 
-    // Step 2: Quality gates — evaluate all 6 gates
+    // Step 2: Quality gates — evaluate all 7 gates
     let gate_input = GateInput {
         patterns: make_realistic_patterns(),
         constraints: vec![ConstraintInput {
@@ -165,7 +165,7 @@ fn test_full_enforcement_round_trip() {
 
     let orchestrator = GateOrchestrator::new();
     let gate_results = orchestrator.execute(&gate_input).unwrap();
-    assert_eq!(gate_results.len(), 6, "Step 2: Should have 6 gate results");
+    assert_eq!(gate_results.len(), 7, "Step 2: Should have 7 gate results");
 
     // Step 3: Policy engine — aggregate gate results
     let policy = Policy::standard();
@@ -204,7 +204,7 @@ fn test_full_enforcement_round_trip() {
 
     // Full round-trip complete
     assert!(violations.len() >= 2, "Round-trip produced violations");
-    assert!(gate_results.len() == 6, "Round-trip evaluated all gates");
+    assert!(gate_results.len() == 7, "Round-trip evaluated all gates");
     assert!(health_score > 0.0, "Round-trip computed health score");
     assert!(!sarif_output.is_empty(), "Round-trip generated SARIF");
 }
@@ -236,6 +236,7 @@ fn test_enforcement_data_persistence() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -338,6 +339,7 @@ fn test_napi_type_contracts() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     };
     let json = serde_json::to_string(&v).unwrap();
     assert!(json.contains("\"severity\":\"error\""));
@@ -397,6 +399,7 @@ fn test_materialized_views_refresh() {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     )
     .unwrap();
@@ -420,6 +423,7 @@ fn test_materialized_views_refresh() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     )
     .unwrap();
@@ -463,6 +467,7 @@ fn test_sarif_upload_format() {
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         }],
         warnings: vec![],
         execution_time_ms: 10,
@@ -544,7 +549,7 @@ fn test_gate_evaluation_performance() {
     let results = orchestrator.execute(&input).unwrap();
     let elapsed = start.elapsed();
 
-    assert_eq!(results.len(), 6);
+    assert_eq!(results.len(), 7);
     assert!(
         elapsed.as_millis() < 1000,
         "Gate evaluation took {}ms for 10K violations, should be <1000ms",
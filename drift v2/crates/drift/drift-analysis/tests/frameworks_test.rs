@@ -3227,6 +3227,7 @@ content_patterns = ["(?i)useEffect", "(?i)useState"]
         functions: &[],
         call_sites: &[],
         exports: &[],
+        embedded_sql_cache: Default::default(),
     };
     matcher.analyze_file(&ctx);
     let results = matcher.results();
@@ -3341,6 +3342,7 @@ fn fwt_perf_15_benchmark_23_packs_1000_files() {
             functions: &[],
             call_sites: &[],
             exports: &[],
+            embedded_sql_cache: Default::default(),
         };
         matcher.analyze_file(&ctx);
     }
@@ -3403,6 +3405,7 @@ deviation_threshold = 0.10
             file: &file, language: Language::TypeScript, source: src,
             parse_result: &pr, imports: &[], classes: &[], functions: &[],
             call_sites: &[], exports: &[],
+            embedded_sql_cache: Default::default(),
         };
         learner.learn(&ctx);
     }
@@ -3412,6 +3415,7 @@ deviation_threshold = 0.10
         file: "src/b_0.ts", language: Language::TypeScript, source: src_b,
         parse_result: &pr_b, imports: &[], classes: &[], functions: &[],
         call_sites: &[], exports: &[],
+        embedded_sql_cache: Default::default(),
     };
     learner.learn(&ctx_b);
 
@@ -3494,6 +3498,7 @@ deviation_threshold = 0.05
             file: &file, language: Language::TypeScript, source: src,
             parse_result: &pr, imports: &[], classes: &[], functions: &[],
             call_sites: &[], exports: &[],
+            embedded_sql_cache: Default::default(),
         };
         learner.learn(&ctx);
     }
@@ -3503,6 +3508,7 @@ deviation_threshold = 0.05
         file: "src/rare.ts", language: Language::TypeScript, source: src_b,
         parse_result: &pr_b, imports: &[], classes: &[], functions: &[],
         call_sites: &[], exports: &[],
+        embedded_sql_cache: Default::default(),
     };
     learner.learn(&ctx_b);
 
@@ -3548,6 +3554,7 @@ deviation_threshold = 0.15
         file: "src/only.ts", language: Language::TypeScript, source: src,
         parse_result: &pr, imports: &[], classes: &[], functions: &[],
         call_sites: &[], exports: &[],
+        embedded_sql_cache: Default::default(),
     };
     learner.learn(&ctx);
     learner.detect(&ctx);
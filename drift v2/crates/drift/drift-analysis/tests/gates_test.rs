@@ -46,6 +46,7 @@ fn make_gate_input() -> GateInput {
         current_health_score: Some(82.0),
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
+        duplication: None,
         feedback_stats: None,
     }
 }
@@ -57,7 +58,7 @@ fn test_all_six_gates_evaluate() {
     let input = make_gate_input();
     let results = orchestrator.execute(&input).unwrap();
 
-    assert_eq!(results.len(), 6, "Should have 6 gate results");
+    assert_eq!(results.len(), 7, "Should have 7 gate results");
 
     // Verify each gate produced a result
     let gate_ids: Vec<GateId> = results.iter().map(|r| r.gate_id).collect();
@@ -116,7 +116,7 @@ fn test_all_systems_performance_10k() {
     let _gaps = error_handling::analyze_gaps(&[], &[], &[pr]);
 
     // 4. Impact
-    let _radius = impact::compute_blast_radius(&g, root, node_count as u32);
+    let _radius = impact::compute_blast_radius(&g, root, node_count as u32, None);
     let _dead = impact::detect_dead_code(&g);
 
     // 5. Test topology
@@ -307,7 +307,7 @@ fn test_error_handling_impact_integration() {
     }
 
     // High blast radius
-    let radius = impact::compute_blast_radius(&g, core_fn, 100);
+    let radius = impact::compute_blast_radius(&g, core_fn, 100, None);
     assert_eq!(radius.caller_count, 50);
     assert!(radius.risk_score.blast_radius > 0.4);
 
@@ -0,0 +1,195 @@
+//! Git history analytics — churn with renames, hunk-to-function mapping,
+//! change coupling, hotspots, ownership, and churn-aware impact scoring.
+
+use std::path::Path;
+
+use drift_analysis::advanced::decisions::GitAnalyzer;
+use drift_analysis::advanced::history::*;
+use drift_analysis::call_graph::builder::CallGraphBuilder;
+use drift_analysis::graph::impact::{compute_all_blast_radii, ImpactMetrics};
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::structural::complexity::{analyze_file, ComplexityIndex};
+use drift_analysis::structural::module_of;
+
+fn hunk(old_start: u32, old_lines: u32, new_start: u32, new_lines: u32) -> Hunk {
+    Hunk { old_start, old_lines, new_start, new_lines }
+}
+
+fn change(path: &str, kind: ChangeKind, insertions: u32, deletions: u32, hunks: Vec<Hunk>) -> FileChange {
+    FileChange { path: path.into(), old_path: None, kind, insertions, deletions, hunks }
+}
+
+fn commit(author: &str, timestamp: i64, files: Vec<FileChange>) -> CommitChanges {
+    CommitChanges { sha: format!("{timestamp:x}"), author: author.into(), timestamp, files }
+}
+
+/// Newest first: bob edits `run`, alice renames main.ts → app.ts (adding a
+/// header line), alice creates both files.
+fn linear_history() -> Vec<CommitChanges> {
+    let mut rename = change("src/app.ts", ChangeKind::Renamed, 1, 0, vec![hunk(0, 0, 1, 1)]);
+    rename.old_path = Some("src/main.ts".into());
+    vec![
+        commit("bob", 300, vec![
+            change("src/app.ts", ChangeKind::Modified, 2, 1, vec![hunk(5, 1, 5, 2)]),
+            change("src/util.ts", ChangeKind::Modified, 1, 1, vec![hunk(2, 1, 2, 1)]),
+        ]),
+        commit("alice", 200, vec![rename]),
+        commit("alice", 100, vec![
+            change("src/main.ts", ChangeKind::Added, 10, 0, vec![hunk(0, 0, 1, 10)]),
+            change("src/util.ts", ChangeKind::Added, 4, 0, vec![hunk(0, 0, 1, 4)]),
+        ]),
+    ]
+}
+
+#[test]
+fn test_churn_follows_renames_and_maps_hunks_to_functions() {
+    let commits = linear_history();
+    let functions = [
+        FunctionSpan { file: "src/app.ts".into(), name: "run".into(), line: 3, end_line: 8 },
+        FunctionSpan { file: "src/app.ts".into(), name: "header".into(), line: 0, end_line: 0 },
+    ];
+    let report = analyze(&commits, &functions, &ComplexityIndex::new(), &HistoryConfig::default());
+    assert_eq!(report.commits_analyzed, 3);
+
+    let app = report.file_churn.iter().find(|f| f.file == "src/app.ts").unwrap();
+    assert_eq!(app.commits, 3, "history before the rename counts toward the HEAD path");
+    assert_eq!((app.insertions, app.deletions), (13, 1));
+    assert_eq!((app.first_changed_at, app.last_changed_at), (100, 300));
+    assert_eq!(app.top_author.as_deref(), Some("alice"));
+    assert!(report.file_churn.iter().all(|f| f.file != "src/main.ts"));
+
+    // bob's hunk lands inside `run`; the rename's new header line shifts it
+    // back down to where alice originally wrote it.
+    let run = report.function_churn.iter().find(|f| f.name == "run").unwrap();
+    assert_eq!((run.commits, run.lines_added, run.lines_deleted, run.last_changed_at), (2, 7, 1, 300));
+    let header = report.function_churn.iter().find(|f| f.name == "header").unwrap();
+    assert_eq!((header.commits, header.lines_added), (1, 1), "introduced by the rename commit");
+
+    let churn = ChurnIndex::from_function_churn(&report.function_churn);
+    assert_eq!(churn.change_frequency("src/app.ts", 3), Some(1.0));
+    assert_eq!(churn.change_frequency("src/app.ts", 0), Some(0.5));
+    assert_eq!(churn.change_frequency("src/other.ts", 0), None);
+}
+
+#[test]
+fn test_coupling_and_ownership() {
+    let commits = linear_history();
+    let config = HistoryConfig { min_co_changes: 2, ..Default::default() };
+    let report = analyze(&commits, &[], &ComplexityIndex::new(), &config);
+
+    assert_eq!(report.coupling.len(), 1);
+    let pair = &report.coupling[0];
+    assert_eq!((pair.file_a.as_str(), pair.file_b.as_str()), ("src/app.ts", "src/util.ts"));
+    assert_eq!((pair.co_changes, pair.revisions_a, pair.revisions_b), (2, 3, 2));
+    assert!((pair.degree - 0.8).abs() < 1e-9);
+    assert!(analyze(&commits, &[], &ComplexityIndex::new(), &HistoryConfig::default()).coupling.is_empty());
+
+    let too_wide = HistoryConfig { min_co_changes: 1, max_files_per_commit: 1, ..Default::default() };
+    assert!(change_coupling(&resolve_head_paths(&commits), &too_wide).is_empty());
+
+    assert_eq!(report.ownership.len(), 1);
+    let src = &report.ownership[0];
+    assert_eq!(src.module, "src");
+    // alice: 10 + 4 + 1 changed lines, bob: 3 + 2
+    assert_eq!(src.total_changes, 20);
+    assert_eq!(src.primary_owner().map(|a| (a.author.as_str(), a.changes)), Some(("alice", 15)));
+    assert_eq!(src.bus_factor, 1);
+    assert_eq!(module_of("README.md"), ".");
+    assert!(report.hotspots.is_empty(), "no complexity, no hotspots");
+}
+
+const APP_V1: &str = r#"export function route(req: Request): string {
+  if (req.admin) {
+    return "admin";
+  }
+  return "user";
+}
+
+export function health(): string {
+  return "ok";
+}
+"#;
+
+const APP_V2: &str = r#"export function route(req: Request): string {
+  if (req.admin) {
+    return "admin";
+  }
+  return "guest";
+}
+
+export function health(): string {
+  return "ok";
+}
+"#;
+
+const UTIL: &str = "export const VERSION = 1;\nexport function noop() {}\n";
+
+fn git_commit(repo: &git2::Repository, files: &[(&str, Option<&str>)], author: &str, time: i64) {
+    let root = repo.workdir().unwrap();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        match content {
+            Some(content) => {
+                let full = root.join(path);
+                std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+                std::fs::write(&full, content).unwrap();
+                index.add_path(Path::new(path)).unwrap();
+            }
+            None => {
+                std::fs::remove_file(root.join(path)).unwrap();
+                index.remove_path(Path::new(path)).unwrap();
+            }
+        }
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::new(author, &format!("{author}@example.com"), &git2::Time::new(time, 0)).unwrap();
+    let parents: Vec<git2::Commit> = repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, "change", &tree, &parents).unwrap();
+}
+
+#[test]
+fn test_git_walk_hotspots_and_impact() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    git_commit(&repo, &[("src/app.ts", Some(APP_V1)), ("src/util.ts", Some(UTIL))], "alice", 1_700_000_000);
+    git_commit(&repo, &[("src/app.ts", Some(APP_V2))], "bob", 1_700_001_000);
+    git_commit(&repo, &[("src/util.ts", None), ("src/lib/util.ts", Some(UTIL))], "alice", 1_700_002_000);
+
+    let commits = GitAnalyzer::new().commit_changes(dir.path()).unwrap();
+    assert_eq!(commits.iter().map(|c| c.author.as_str()).collect::<Vec<_>>(), ["alice", "bob", "alice"]);
+    let moved = &commits[0].files[0];
+    assert_eq!((moved.kind, moved.path.as_str(), moved.old_path.as_deref()), (ChangeKind::Renamed, "src/lib/util.ts", Some("src/util.ts")));
+    assert_eq!(commits[1].files[0].hunks, vec![hunk(5, 1, 5, 1)], "zero-context hunks");
+    assert_eq!(GitAnalyzer::new().with_max_commits(1).commit_changes(dir.path()).unwrap().len(), 1);
+
+    let (pr, tree) = ParserManager::new().parse_returning_tree(APP_V2.as_bytes(), Path::new("src/app.ts")).unwrap();
+    let complexity = ComplexityIndex::from_functions(analyze_file(&pr, &tree, APP_V2.as_bytes()));
+    let spans: Vec<FunctionSpan> = complexity
+        .iter()
+        .map(|f| FunctionSpan { file: f.file.clone(), name: f.name.clone(), line: f.line, end_line: f.end_line })
+        .collect();
+    let report = analyze(&commits, &spans, &complexity, &HistoryConfig::default());
+
+    let util = report.file_churn.iter().find(|f| f.file == "src/lib/util.ts").unwrap();
+    assert_eq!(util.commits, 2);
+    let route = report.function_churn.iter().find(|f| f.name == "route").unwrap();
+    let health = report.function_churn.iter().find(|f| f.name == "health").unwrap();
+    assert_eq!((route.commits, health.commits), (2, 1));
+
+    assert_eq!(report.hotspots.len(), 1);
+    assert_eq!(report.hotspots[0].file, "src/app.ts");
+    assert_eq!((report.hotspots[0].commits, report.hotspots[0].complexity, report.hotspots[0].score), (2, 3, 1.0));
+
+    let churn = ChurnIndex::from_function_churn(&report.function_churn);
+    let (graph, _) = CallGraphBuilder::new().build(&[pr]).unwrap();
+    let radii = compute_all_blast_radii(
+        &graph,
+        Some(&ImpactMetrics { complexity: Some(&complexity), churn: Some(&churn) }),
+    );
+    let frequency = |name: &str| {
+        radii.iter().find(|r| graph.graph[r.function_id].name == name).unwrap().risk_score.change_frequency
+    };
+    assert_eq!((frequency("route"), frequency("health")), (1.0, 0.5));
+}
@@ -43,7 +43,7 @@ fn test_blast_radius_transitive_closure() {
     g.add_edge(b, d, make_edge());
     g.add_edge(c, d, make_edge());
 
-    let radius = compute_blast_radius(&g, d, 100);
+    let radius = compute_blast_radius(&g, d, 100, None);
 
     // D's transitive callers: B, C, A
     assert_eq!(radius.caller_count, 3);
@@ -135,7 +135,7 @@ fn test_blast_radius_circular_dependency() {
     g.add_edge(b, c, make_edge());
     g.add_edge(c, a, make_edge());
 
-    let radius = compute_blast_radius(&g, a, 100);
+    let radius = compute_blast_radius(&g, a, 100, None);
 
     // All 3 nodes are in the cycle, so A's callers include B and C
     assert_eq!(radius.caller_count, 2);
@@ -172,8 +172,8 @@ fn test_impact_scoring_high_vs_low() {
         g_low.add_edge(caller, target_l, make_edge());
     }
 
-    let radius_high = compute_blast_radius(&g_high, target_h, 200);
-    let radius_low = compute_blast_radius(&g_low, target_l, 200);
+    let radius_high = compute_blast_radius(&g_high, target_h, 200, None);
+    let radius_low = compute_blast_radius(&g_low, target_l, 200, None);
 
     assert!(radius_high.risk_score.overall > radius_low.risk_score.overall,
         "High-impact ({}) should score higher than low-impact ({})",
@@ -252,7 +252,7 @@ fn test_compute_all_blast_radii() {
     g.add_edge(a, b, make_edge());
     g.add_edge(b, c, make_edge());
 
-    let radii = blast_radius::compute_all_blast_radii(&g);
+    let radii = blast_radius::compute_all_blast_radii(&g, None);
     assert_eq!(radii.len(), 3);
 
     // C should have the highest blast radius (A and B call it transitively)
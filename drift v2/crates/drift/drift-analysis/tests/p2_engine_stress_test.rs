@@ -638,10 +638,11 @@ fn stress_orm_framework_names() {
         OrmFramework::Eloquent, OrmFramework::Doctrine, OrmFramework::Propel,
         OrmFramework::Gorm, OrmFramework::Ent, OrmFramework::Sqlx,
         OrmFramework::Diesel, OrmFramework::SeaOrm, OrmFramework::SqlxRust,
+        OrmFramework::Exposed,
         OrmFramework::Unknown,
     ];
 
-    // All 33 frameworks should have unique non-empty names
+    // All 34 frameworks should have unique non-empty names
     let mut names = std::collections::HashSet::new();
     for fw in &frameworks {
         let name = fw.name();
@@ -649,7 +650,7 @@ fn stress_orm_framework_names() {
         assert!(names.insert(name), "duplicate framework name: {name}");
         assert!(!format!("{fw}").is_empty());
     }
-    assert_eq!(frameworks.len(), 33);
+    assert_eq!(frameworks.len(), 34);
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -34,7 +34,7 @@ fn edge_conf(conf: f32) -> CallEdge {
 fn stress_blast_radius_isolated_node() {
     let mut g = CallGraph::new();
     let a = g.add_function(node("a.ts", "alone", false));
-    let br = impact::blast_radius::compute_blast_radius(&g, a, 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, a, 100, None);
     assert_eq!(br.caller_count, 0);
     assert_eq!(br.max_depth, 0);
     assert!(br.transitive_callers.is_empty());
@@ -49,7 +49,7 @@ fn stress_blast_radius_star_topology() {
         let c = g.add_function(node(&format!("c{i}.ts"), &format!("caller_{i}"), false));
         g.add_edge(c, target, edge());
     }
-    let br = impact::blast_radius::compute_blast_radius(&g, target, 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, target, 100, None);
     assert_eq!(br.caller_count, 50);
     assert_eq!(br.max_depth, 1);
     assert!((br.risk_score.blast_radius - 0.5).abs() < 0.01);
@@ -62,7 +62,7 @@ fn stress_blast_radius_chain() {
         g.add_function(node(&format!("{i}.ts"), &format!("f{i}"), false))
     }).collect();
     for i in 0..4 { g.add_edge(nodes[i], nodes[i+1], edge()); }
-    let br = impact::blast_radius::compute_blast_radius(&g, nodes[4], 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, nodes[4], 100, None);
     assert_eq!(br.caller_count, 4);
     assert_eq!(br.max_depth, 4);
 }
@@ -76,7 +76,7 @@ fn stress_blast_radius_cycle() {
     g.add_edge(a, b, edge());
     g.add_edge(b, c, edge());
     g.add_edge(c, a, edge());
-    let br = impact::blast_radius::compute_blast_radius(&g, a, 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, a, 100, None);
     assert_eq!(br.caller_count, 2);
 }
 
@@ -90,7 +90,7 @@ fn stress_blast_radius_fan_in() {
         let c = g.add_function(node(&format!("c{i}.ts"), &format!("caller_{i}"), false));
         g.add_edge(c, mid, edge());
     }
-    let br = impact::blast_radius::compute_blast_radius(&g, target, 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, target, 100, None);
     assert_eq!(br.caller_count, 21);
     assert_eq!(br.max_depth, 2);
 }
@@ -158,7 +158,7 @@ fn stress_blast_radius_struct_fields() {
     let target = g.add_function(node("target.ts", "target", false));
     let caller = g.add_function(node("caller.ts", "caller", false));
     g.add_edge(caller, target, edge());
-    let br = impact::blast_radius::compute_blast_radius(&g, target, 100);
+    let br = impact::blast_radius::compute_blast_radius(&g, target, 100, None);
     assert_eq!(br.function_id, target);
     assert_eq!(br.caller_count, 1);
     assert_eq!(br.transitive_callers.len(), 1);
@@ -55,7 +55,7 @@ fn stress_perf_10k_blast_radius_under_500ms() {
     }).collect();
     for i in 0..9_999 { g.add_edge(nodes[i], nodes[i+1], edge()); }
     let start = Instant::now();
-    let br = impact::blast_radius::compute_blast_radius(&g, nodes[9_999], 10_000);
+    let br = impact::blast_radius::compute_blast_radius(&g, nodes[9_999], 10_000, None);
     let elapsed = start.elapsed();
     assert_eq!(br.caller_count, 9_999);
     assert!(elapsed.as_millis() < 500, "10K blast radius took {}ms", elapsed.as_millis());
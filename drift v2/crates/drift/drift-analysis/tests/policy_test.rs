@@ -1,9 +1,11 @@
-//! Phase 6 tests: Policy Engine — Aggregation Modes
-//! T6-POL-01 through T6-POL-06
+//! Phase 6 tests: Policy Engine — Aggregation Modes and Scoped Policies
+//! T6-POL-01 through T6-POL-10
+
+use std::path::Path;
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::policy::*;
-use drift_analysis::enforcement::rules::Severity;
+use drift_analysis::enforcement::rules::{Severity, Violation};
 
 fn make_gate_results(pass_count: usize, fail_count: usize) -> Vec<GateResult> {
     let gate_ids = GateId::all();
@@ -187,3 +189,237 @@ fn test_progressive_ramp_up() {
     });
     assert_eq!(pe3.effective_severity(Severity::Error, false), Severity::Error);
 }
+
+fn violation(gate: &str, file: &str, line: u32) -> Violation {
+    Violation {
+        id: format!("{gate}-{file}-{line}"),
+        file: file.to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity: Severity::Error,
+        pattern_id: gate.to_string(),
+        rule_id: format!("{gate}/rule"),
+        message: "violation".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+    }
+}
+
+fn payments_package() -> drift_core::workspace::monorepo::PackageInfo {
+    drift_core::workspace::monorepo::PackageInfo {
+        name: "payments".to_string(),
+        path: "packages/payments".into(),
+        language: Some("typescript".to_string()),
+        framework: None,
+        dependencies: Vec::new(),
+    }
+}
+
+/// T6-POL-07: Overrides resolve from least to most specific and inherit
+/// unset fields.
+#[test]
+fn test_scoped_policy_inheritance() {
+    let scoped = ScopedPolicy::new(Policy::standard())
+        .with_override(PolicyOverride::new(PolicyScope::glob("packages/**")).threshold(60.0))
+        .unwrap()
+        .with_override(
+            PolicyOverride::new(PolicyScope::package(&payments_package()))
+                .preset(PolicyPreset::Strict)
+                .gate_threshold(GateId::TestCoverage, 90.0),
+        )
+        .unwrap()
+        .with_override(PolicyOverride::new(PolicyScope::glob("tools/")).preset(PolicyPreset::Lenient))
+        .unwrap();
+
+    let root = scoped.resolve("src/main.ts");
+    assert_eq!(root.scope, "root");
+    assert_eq!(root.policy.name, "standard");
+
+    let web = scoped.resolve("packages/web/src/app.ts");
+    assert_eq!(web.scope, "glob:packages/**");
+    assert_eq!(web.policy.aggregation_mode, AggregationMode::Threshold);
+    assert_eq!(web.policy.threshold, 60.0, "threshold overridden, mode inherited");
+
+    let payments = scoped.resolve("packages/payments/src/charge.ts");
+    assert_eq!(payments.scope, "glob:packages/** > package:payments");
+    assert_eq!(payments.policy.aggregation_mode, AggregationMode::AllMustPass);
+    assert_eq!(payments.gate_thresholds.get("test-coverage"), Some(&90.0));
+
+    let tools = scoped.resolve("tools/gen/build.ts");
+    assert_eq!(tools.policy.preset, PolicyPreset::Lenient);
+    assert!(!scoped.resolve("src/tools/x.ts").scope.starts_with("glob:tools"));
+}
+
+/// T6-POL-08: A failure in one scope does not fail another, and each
+/// violation reports the policy that governed it.
+#[test]
+fn test_scoped_policy_evaluation() {
+    let scoped = ScopedPolicy::new(Policy::standard())
+        .with_override(PolicyOverride::new(PolicyScope::package(&payments_package())).preset(PolicyPreset::Strict))
+        .unwrap()
+        .with_override(PolicyOverride::new(PolicyScope::glob("tools/**")).preset(PolicyPreset::Lenient))
+        .unwrap();
+
+    let mut results: Vec<GateResult> = GateId::all()
+        .iter()
+        .map(|&g| GateResult::pass(g, 95.0, "OK".to_string()))
+        .collect();
+    results[0] = GateResult::fail(
+        GateId::PatternCompliance,
+        60.0,
+        "Failed".to_string(),
+        vec![violation("pattern-compliance", "tools/gen.ts", 3)],
+    );
+    let files = vec![
+        "src/app.ts".to_string(),
+        "packages/payments/charge.ts".to_string(),
+        "tools/gen.ts".to_string(),
+    ];
+
+    let report = ScopedPolicyEngine::new(scoped.clone()).evaluate(Path::new("/repo"), &results, &files);
+    assert_eq!(report.scopes[0].scope, "root");
+    assert_eq!(report.scopes.len(), 3);
+    assert!(report.overall_passed, "strict payments scope is unaffected by a tools/ violation");
+    assert_eq!(report.violations.len(), 1);
+    assert_eq!(report.violations[0].scope, "glob:tools/**");
+    assert_eq!(report.violations[0].policy_name, "lenient");
+
+    // The same violation inside payments fails the strict scope only.
+    results[0].violations = vec![violation("pattern-compliance", "packages/payments/charge.ts", 9)];
+    let report = ScopedPolicyEngine::new(scoped).evaluate(Path::new("/repo"), &results, &files);
+    assert!(!report.overall_passed);
+    let failing: Vec<&str> = report
+        .scopes
+        .iter()
+        .filter(|s| !s.result.overall_passed)
+        .map(|s| s.scope.as_str())
+        .collect();
+    assert_eq!(failing, vec!["package:payments"]);
+}
+
+/// T6-POL-09: Per-scope gate thresholds and enabled gates.
+#[test]
+fn test_scoped_gate_thresholds_and_enabled_gates() {
+    let scoped = ScopedPolicy::new(Policy::lenient())
+        .with_override(PolicyOverride::new(PolicyScope::glob("core/**")).gate_threshold(GateId::TestCoverage, 90.0))
+        .unwrap()
+        .with_override(
+            PolicyOverride::new(PolicyScope::glob("generated/**"))
+                .preset(PolicyPreset::Strict)
+                .enabled_gates(vec![GateId::SecurityBoundaries])
+                .required_gates(vec![GateId::SecurityBoundaries]),
+        )
+        .unwrap();
+    let results = vec![
+        GateResult::pass(GateId::SecurityBoundaries, 100.0, "OK".to_string()),
+        GateResult::fail(
+            GateId::TestCoverage,
+            80.0,
+            "Failed".to_string(),
+            vec![violation("test-coverage", "generated/api.ts", 1)],
+        ),
+    ];
+    let files = vec!["core/a.ts".to_string(), "generated/api.ts".to_string()];
+    let report = ScopedPolicyEngine::new(scoped.clone()).evaluate(Path::new("/repo"), &results, &files);
+
+    let scope = |name: &str| report.scopes.iter().find(|s| s.scope == name).unwrap();
+    assert!(scope("glob:generated/**").result.overall_passed, "test coverage is not enabled for generated code");
+    assert_eq!(scope("glob:generated/**").result.gate_count, 1);
+    assert!(
+        scope("glob:core/**").result.overall_passed,
+        "a violation in generated/ does not count against core's coverage minimum"
+    );
+    assert!(report.violations.is_empty(), "violations of disabled gates are not governed");
+
+    // With a warning of its own, core takes half the gate's deficit
+    // (score 80) and is held to its minimum of 90.
+    let mut results = results;
+    results[1].score = 60.0;
+    results[1].violations.push(Violation { severity: Severity::Warning, ..violation("test-coverage", "core/a.ts", 3) });
+    let report = ScopedPolicyEngine::new(scoped).evaluate(Path::new("/repo"), &results, &files);
+    let core = &report.scopes.iter().find(|s| s.scope == "glob:core/**").unwrap().result;
+    assert_eq!(core.gates_failed, 1, "score 80 is below the core minimum of 90");
+    assert_eq!(report.violations.len(), 1);
+    assert_eq!(report.violations[0].scope, "glob:core/**");
+}
+
+/// A scope is judged on its own violations: a warning in a lenient scope
+/// does not inherit the failure caused by errors elsewhere. Absolute file
+/// paths resolve relative to the project root.
+#[test]
+fn test_scoped_status_from_own_violations_with_absolute_paths() {
+    let scoped = ScopedPolicy::new(Policy::lenient())
+        .with_override(PolicyOverride::new(PolicyScope::glob("packages/payments/**")).preset(PolicyPreset::Strict))
+        .unwrap()
+        .with_override(PolicyOverride::new(PolicyScope::glob("tools/")).preset(PolicyPreset::Lenient))
+        .unwrap();
+    let results = vec![GateResult::fail(
+        GateId::PatternCompliance,
+        70.0,
+        "Failed".to_string(),
+        vec![
+            violation("pattern-compliance", "/repo/packages/payments/charge.ts", 1),
+            violation("pattern-compliance", "/repo/packages/payments/refund.ts", 2),
+            Violation { severity: Severity::Warning, ..violation("pattern-compliance", "/repo/tools/gen.ts", 3) },
+        ],
+    )];
+    let files = vec![
+        "/repo/src/app.ts".to_string(),
+        "/repo/packages/payments/charge.ts".to_string(),
+        "/repo/packages/payments/refund.ts".to_string(),
+        "/repo/tools/gen.ts".to_string(),
+    ];
+    let report = ScopedPolicyEngine::new(scoped).evaluate(Path::new("/repo"), &results, &files);
+
+    let scope = |name: &str| &report.scopes.iter().find(|s| s.scope == name).unwrap().result;
+    assert_eq!(report.scopes.len(), 3, "absolute paths reach their glob scopes");
+    let payments = scope("glob:packages/payments/**");
+    assert!(!payments.overall_passed);
+    assert_eq!(payments.overall_score, 80.0, "two thirds of the 30-point deficit");
+    let tools = scope("glob:tools/");
+    assert!(tools.overall_passed, "one warning does not fail the lenient scope");
+    assert_eq!((tools.gates_failed, tools.overall_score), (0, 90.0));
+    assert!(scope("root").overall_passed);
+    assert_eq!(
+        report.violations.iter().map(|v| v.scope.as_str()).collect::<Vec<_>>(),
+        ["glob:packages/payments/**", "glob:packages/payments/**", "glob:tools/"]
+    );
+}
+
+/// T6-POL-10: Scoped policies load from `quality_gates` config.
+#[test]
+fn test_scoped_policy_from_config() {
+    let config = drift_core::config::DriftConfig::from_toml(
+        r#"
+[quality_gates]
+policy = "standard"
+
+[[quality_gates.policy_overrides]]
+package = "payments"
+policy = "strict"
+gate_thresholds = { "security-boundaries" = 95.0 }
+
+[[quality_gates.policy_overrides]]
+path = "tools/**"
+policy = "lenient"
+enabled_gates = ["pattern-compliance"]
+"#,
+    )
+    .unwrap();
+    let scoped = ScopedPolicy::from_config(&config.quality_gates, &[payments_package()]).unwrap();
+    let payments = scoped.resolve("packages/payments/index.ts");
+    assert_eq!(payments.policy.name, "strict");
+    assert_eq!(payments.gate_thresholds.get("security-boundaries"), Some(&95.0));
+    assert_eq!(scoped.resolve("tools/x.ts").enabled_gates, vec![GateId::PatternCompliance]);
+
+    assert!(ScopedPolicy::from_config(&config.quality_gates, &[]).is_err(), "unknown package");
+    let mut bad = config.quality_gates.clone();
+    bad.policy_overrides[1].enabled_gates = Some(vec!["no-such-gate".to_string()]);
+    assert!(ScopedPolicy::from_config(&bad, &[payments_package()]).is_err());
+}
@@ -31,6 +31,7 @@ fn make_cwe_owasp_violation() -> Violation {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     }
 }
 
@@ -56,6 +57,7 @@ fn make_warning_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     }
 }
 
@@ -77,6 +79,7 @@ fn make_info_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     }
 }
 
@@ -98,6 +101,7 @@ fn make_hint_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     }
 }
 
@@ -337,6 +341,7 @@ fn t10_04_console_report_readability() {
             owasp_category: None,
             suppressed: false,
             is_new: i % 2 == 0,
+            owners: Vec::new(),
         })
         .collect();
 
@@ -361,6 +366,7 @@ fn t10_04_console_report_readability() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     });
 
     let results = vec![GateResult::fail(
@@ -550,6 +556,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         Violation {
             id: "v2".to_string(),
@@ -567,6 +574,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         Violation {
             id: "v3".to_string(),
@@ -584,6 +592,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         // One with CWE for Security category
         Violation {
@@ -602,6 +611,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     ];
 
@@ -600,7 +600,7 @@ fn t18_08_impact_blast_radius_scoring() {
         );
     }
 
-    let blast = compute_blast_radius(&graph, target, 11);
+    let blast = compute_blast_radius(&graph, target, 11, None);
 
     // blast_radius must be > 0
     assert!(
@@ -171,6 +171,7 @@ fn build_gate_input() -> GateInput {
         current_health_score: Some(0.82),
         predecessor_results: HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
+        duplication: None,
         feedback_stats: None,
     }
 }
@@ -200,6 +201,7 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         },
         Violation {
             id: "singleton-outlier-src/module_3.ts-13".to_string(),
@@ -217,6 +219,7 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     ];
 
@@ -340,7 +340,7 @@ fn t6_08_gate_execution_timing() {
         .build();
     let results = orch.execute(&input).unwrap();
 
-    assert_eq!(results.len(), 6, "All 6 default gates must run");
+    assert_eq!(results.len(), 7, "All 7 default gates must run");
     // The orchestrator records execution_time_ms via as_millis(), so sub-ms
     // gates will read 0. We verify the field is populated (>= 0) and that at
     // least the orchestrator attempted to set it for non-skipped gates.
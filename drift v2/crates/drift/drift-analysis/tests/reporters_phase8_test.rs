@@ -31,6 +31,7 @@ fn test_violations() -> Vec<Violation> {
             owasp_category: Some("A09:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         },
         Violation {
             id: "security-boundary-src/db.ts-10".to_string(),
@@ -48,6 +49,7 @@ fn test_violations() -> Vec<Violation> {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         Violation {
             id: "info-hint-src/utils.ts-5".to_string(),
@@ -69,6 +71,7 @@ fn test_violations() -> Vec<Violation> {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     ]
 }
@@ -269,6 +272,7 @@ fn t8_rpt_06_unicode_handling() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     }];
 
     let results = vec![GateResult::fail(
@@ -337,6 +341,7 @@ fn t8_rpt_07_large_violation_set() {
             },
             suppressed: false,
             is_new: i % 2 == 0,
+            owners: Vec::new(),
         })
         .collect();
 
@@ -439,6 +444,7 @@ fn test_suppressed_violations_excluded() {
             owasp_category: None,
             suppressed: true,
             is_new: false,
+            owners: Vec::new(),
         },
     ];
 
@@ -488,6 +494,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         Violation {
             id: "bug".to_string(),
@@ -505,6 +512,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
         Violation {
             id: "smell".to_string(),
@@ -522,6 +530,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         },
     ];
 
@@ -37,6 +37,7 @@ fn make_test_results() -> Vec<GateResult> {
                     owasp_category: Some("A03:2021-Injection".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
                 },
                 Violation {
                     id: "v2".to_string(),
@@ -54,6 +55,7 @@ fn make_test_results() -> Vec<GateResult> {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
                 },
             ],
             warnings: vec![],
@@ -168,6 +170,7 @@ fn test_sarif_10k_violations_performance() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         })
         .collect();
 
@@ -685,6 +685,7 @@ fn stress_serde_round_trip_all_types() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     };
     let json = serde_json::to_string(&v).unwrap();
     let v2: Violation = serde_json::from_str(&json).unwrap();
@@ -823,6 +824,7 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             },
             Violation {
                 id: "v-suppressed".to_string(),
@@ -840,6 +842,7 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: true,
                 is_new: false,
+                owners: Vec::new(),
             },
         ],
         warnings: vec![],
@@ -1046,6 +1049,7 @@ fn stress_storage_full_round_trip() {
                 },
                 suppressed: i % 20 == 0, // 5% suppressed
                 is_new: false,
+                owners: Vec::new(),
             },
         )
         .unwrap();
@@ -1178,6 +1182,7 @@ fn stress_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
     };
 
     // Insert twice with same ID
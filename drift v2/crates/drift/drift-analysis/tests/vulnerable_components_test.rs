@@ -0,0 +1,176 @@
+//! Vulnerable dependency matching — version ordering, CVSS scoring, OSV
+//! range evaluation, call-graph reachability and gate severity.
+
+use std::cmp::Ordering;
+use std::path::Path;
+
+use drift_analysis::call_graph::builder::CallGraphBuilder;
+use drift_analysis::enforcement::gates::GateInputBuilder;
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::parsers::types::ParseResult;
+use drift_analysis::structural::owasp_cwe::vulnerable_components::{
+    assess_reachability, cvss, match_dependencies, osv, to_security_findings, version, DependencyInput,
+    OsvDatabase, Reachability,
+};
+use drift_analysis::structural::owasp_cwe::OwaspCategory;
+
+fn dep(ecosystem: &str, name: &str, version: &str, direct: bool) -> DependencyInput {
+    DependencyInput {
+        package: "app".to_string(),
+        package_path: ".".to_string(),
+        lockfile: Some("package-lock.json".to_string()),
+        ecosystem: ecosystem.to_string(),
+        name: name.to_string(),
+        version: version.to_string(),
+        purl: format!("pkg:npm/{name}@{version}"),
+        direct,
+        dev: false,
+    }
+}
+
+fn lodash_db() -> OsvDatabase {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("npm")).unwrap();
+    std::fs::write(
+        dir.path().join("npm/GHSA-35jh-r3h4-6jhm.json"),
+        r#"{
+            "id": "GHSA-35jh-r3h4-6jhm",
+            "aliases": ["CVE-2021-23337"],
+            "summary": "Command Injection in lodash",
+            "severity": [{"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:H/UI:N/S:U/C:H/I:H/A:H"}],
+            "affected": [{
+                "package": {"ecosystem": "npm", "name": "lodash"},
+                "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "4.17.21"}]}],
+                "ecosystem_specific": {"affected_functions": ["lodash.template"]}
+            }],
+            "database_specific": {"severity": "HIGH", "cwe_ids": ["CWE-94"]}
+        }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("npm/withdrawn.json"),
+        r#"[{"id": "GHSA-xxxx", "withdrawn": "2024-01-01T00:00:00Z", "affected": [{"package": {"ecosystem": "npm", "name": "lodash"}, "versions": ["4.17.20"]}]}]"#,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("npm/garbage.json"), "not json").unwrap();
+    OsvDatabase::load(dir.path()).unwrap()
+}
+
+fn parse(source: &str, file: &str) -> ParseResult {
+    ParserManager::new().parse(source.as_bytes(), Path::new(file)).unwrap()
+}
+
+#[test]
+fn test_version_ordering() {
+    assert_eq!(version::compare("npm", "4.17.20", "4.17.21"), Ordering::Less);
+    assert_eq!(version::compare("npm", "1.0.0-rc.1", "1.0.0"), Ordering::Less);
+    assert_eq!(version::compare("npm", "1.0.0-alpha", "1.0.0-beta"), Ordering::Less);
+    assert_eq!(version::compare("npm", "v1.2.3+build.5", "1.2.3"), Ordering::Equal);
+    assert_eq!(version::compare("PyPI", "2.0", "2.0.0"), Ordering::Equal);
+    assert_eq!(version::compare("PyPI", "2.0rc1", "2.0"), Ordering::Less);
+    assert_eq!(version::compare("PyPI", "2.0.post1", "2.0"), Ordering::Greater);
+    assert_eq!(version::compare("Maven", "2.14.0-SNAPSHOT", "2.14.0"), Ordering::Less);
+    assert_eq!(version::compare("Maven", "2.9.10.8", "2.10.0"), Ordering::Less);
+}
+
+#[test]
+fn test_cvss_base_score() {
+    assert_eq!(cvss::base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), Some(9.8));
+    assert_eq!(cvss::base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H"), Some(10.0));
+    assert_eq!(cvss::base_score("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"), Some(6.1));
+    assert_eq!(cvss::base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N"), Some(0.0));
+    assert_eq!(cvss::base_score("AV:N/AC:L/Au:N/C:P/I:P/A:P"), None);
+}
+
+#[test]
+fn test_normalize_name() {
+    assert_eq!(osv::normalize_name("PyPI", "Django_REST.framework"), "django-rest-framework");
+    assert_eq!(osv::normalize_name("Packagist", "Symfony/HTTP-Kernel"), "symfony/http-kernel");
+    assert_eq!(osv::normalize_name("npm", "@Scope/Pkg"), "@Scope/Pkg");
+}
+
+#[test]
+fn test_osv_range_matching() {
+    let db = lodash_db();
+    assert_eq!(db.len(), 1, "withdrawn and unreadable records are skipped");
+
+    let vulns = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", true), dep("npm", "lodash", "4.17.21", true)]);
+    assert_eq!(vulns.len(), 1);
+    let v = &vulns[0];
+    assert_eq!(v.dependency.version, "4.17.20");
+    assert_eq!(v.advisory_id, "GHSA-35jh-r3h4-6jhm");
+    assert_eq!(v.aliases, vec!["CVE-2021-23337"]);
+    assert_eq!(v.fixed_versions, vec!["4.17.21"]);
+    assert_eq!(v.cwe_ids, vec![94]);
+    assert_eq!(v.vulnerable_symbols, vec!["lodash.template"]);
+    assert_eq!(v.severity, 7.2, "CVSS vector wins over the severity label");
+}
+
+#[test]
+fn test_last_affected_and_explicit_versions() {
+    let record: osv::OsvRecord = serde_json::from_str(
+        r#"{"id": "PYSEC-1", "affected": [{
+            "package": {"ecosystem": "PyPI", "name": "PyYAML"},
+            "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "5.1"}, {"last_affected": "5.3.1"}]}],
+            "versions": ["4.2b1"]
+        }]}"#,
+    )
+    .unwrap();
+    let db = OsvDatabase::from_records(vec![record]);
+    let affected = |v: &str| !match_dependencies(&db, &[dep("PyPI", "pyyaml", v, true)]).is_empty();
+    assert!(affected("5.1"));
+    assert!(affected("5.3.1"));
+    assert!(!affected("5.4"));
+    assert!(!affected("5.0"));
+    assert!(affected("4.2b1"));
+}
+
+#[test]
+fn test_reachability_classification() {
+    let db = lodash_db();
+    let results = vec![
+        parse("import _ from 'lodash';\nexport function render(input: string) {\n  return _.template(input);\n}\n", "src/render.ts"),
+        parse("import { template } from 'lodash';\nfunction legacy(input: string) {\n  return template(input);\n}\n", "src/legacy.ts"),
+    ];
+    let (graph, _) = CallGraphBuilder::new().build(&results).unwrap();
+
+    let mut reachable = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", true)]);
+    assess_reachability(&mut reachable, &results, &graph);
+    assert_eq!(reachable[0].reachability, Reachability::Reachable);
+    assert_eq!(reachable[0].call_sites.len(), 2);
+    assert!(reachable[0].call_sites.iter().any(|c| c.file == "src/render.ts" && c.function.as_deref() == Some("render")));
+
+    let mut unreachable = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", true)]);
+    assess_reachability(&mut unreachable, &results[1..], &graph);
+    assert_eq!(unreachable[0].reachability, Reachability::Unreachable);
+
+    let mut not_imported = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", true)]);
+    assess_reachability(&mut not_imported, &[], &graph);
+    assert_eq!(not_imported[0].reachability, Reachability::NotImported);
+
+    let mut transitive = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", false)]);
+    assess_reachability(&mut transitive, &[], &graph);
+    assert_eq!(transitive[0].reachability, Reachability::Unknown);
+}
+
+#[test]
+fn test_security_findings_and_gate_input() {
+    let db = lodash_db();
+    let mut vulns = match_dependencies(&db, &[dep("npm", "lodash", "4.17.20", true)]);
+
+    let findings = to_security_findings(&vulns);
+    assert_eq!(findings[0].detector, "vulnerable-dependency");
+    assert_eq!(findings[0].file, "package-lock.json");
+    assert_eq!(findings[0].owasp_categories, vec![OwaspCategory::A06VulnerableComponents]);
+    assert_eq!(findings[0].cwes.iter().map(|c| c.id).collect::<Vec<_>>(), vec![1395, 94]);
+    assert_eq!(findings[0].remediation.as_deref(), Some("Upgrade lodash to 4.17.21 or later"));
+
+    let input = GateInputBuilder::new().security_findings_from_vulnerable_dependencies(&vulns).build();
+    assert_eq!(input.security_findings[0].severity, "high");
+    assert_eq!(input.security_findings[0].cwe_ids, vec![1395, 94]);
+    assert!(input.security_findings[0].owasp_categories.iter().any(|c| c.starts_with("A06")));
+
+    vulns[0].reachability = Reachability::Unreachable;
+    let input = GateInputBuilder::new().security_findings_from_vulnerable_dependencies(&vulns).build();
+    assert_eq!(input.security_findings[0].severity, "low");
+}
@@ -8,6 +8,7 @@ use drift_core::traits::WeightProvider;
 use super::deduplication::ContextSession;
 use super::intent::{ContextIntent, IntentWeights};
 use super::ordering::ContentOrderer;
+use super::repo_map::{self, RepoGraph, RepoMapFocus};
 use crate::tokenization::budget::{ContextDepthBudget, TokenBudget};
 use crate::tokenization::counter::TokenCounter;
 
@@ -105,6 +106,11 @@ impl ContextEngine {
         self
     }
 
+    /// The session, with whatever this engine recorded in it.
+    pub fn into_session(self) -> Option<ContextSession> {
+        self.session
+    }
+
     /// Generate context for the given intent and depth.
     pub fn generate(
         &mut self,
@@ -183,6 +189,52 @@ impl ContextEngine {
         })
     }
 
+    /// Generate a repository map: signatures of the highest-ranked symbols,
+    /// grouped by file, as many as fit the depth's token budget. The ranking
+    /// favours what `intent` cares about. Files in `focus` are recorded in
+    /// the session, and files mentioned earlier in it are added to `focus`.
+    pub fn generate_repo_map(
+        &mut self,
+        intent: ContextIntent,
+        depth: ContextDepth,
+        graph: &RepoGraph,
+        focus: &RepoMapFocus,
+    ) -> Result<ContextOutput, ContextError> {
+        let mut focus = focus.clone();
+        if let Some(ref mut session) = self.session {
+            for file in &focus.files {
+                session.mention_file(file.clone());
+            }
+            for file in session.mentioned_files() {
+                if !focus.files.contains(file) {
+                    focus.files.push(file.clone());
+                }
+            }
+        }
+
+        let ranked = repo_map::rank(graph, &focus, Some(intent));
+        let budget = TokenBudget::for_depth(depth.to_budget()).available();
+        let mut sections = repo_map::fit_to_budget(&ranked, budget, &self.token_counter);
+        if sections.is_empty() {
+            sections.push((
+                "overview".to_string(),
+                "No symbols available for a repository map.".to_string(),
+            ));
+        }
+
+        let combined = repo_map::combine(&sections);
+        let token_count = self.token_counter.count(&combined)
+            .unwrap_or_else(|_| TokenCounter::count_approximate(&combined));
+
+        Ok(ContextOutput {
+            sections,
+            token_count,
+            intent,
+            depth,
+            content_hash: ContextSession::hash_content(&combined),
+        })
+    }
+
     /// Truncate content to approximately fit within a token budget.
     fn truncate_to_tokens(&self, content: &str, max_tokens: usize) -> String {
         if max_tokens == 0 {
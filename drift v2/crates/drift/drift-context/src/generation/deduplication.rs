@@ -13,6 +13,8 @@ pub struct ContextSession {
     pub total_tokens_sent: usize,
     /// Number of requests in this session.
     pub request_count: u32,
+    /// Files the agent has been shown or is editing, oldest first.
+    mentioned_files: Vec<String>,
 }
 
 impl ContextSession {
@@ -22,6 +24,7 @@ impl ContextSession {
             sent_hashes: HashSet::new(),
             total_tokens_sent: 0,
             request_count: 0,
+            mentioned_files: Vec::new(),
         }
     }
 
@@ -63,9 +66,24 @@ impl ContextSession {
         self.sent_hashes.len()
     }
 
+    /// Record a file the agent has been shown or is editing; repo maps for
+    /// this session are biased toward it.
+    pub fn mention_file(&mut self, path: impl Into<String>) {
+        let path = path.into();
+        if !self.mentioned_files.contains(&path) {
+            self.mentioned_files.push(path);
+        }
+    }
+
+    /// Files recorded with [`mention_file`](Self::mention_file).
+    pub fn mentioned_files(&self) -> &[String] {
+        &self.mentioned_files
+    }
+
     /// Reset the session.
     pub fn reset(&mut self) {
         self.sent_hashes.clear();
+        self.mentioned_files.clear();
         self.total_tokens_sent = 0;
         self.request_count = 0;
     }
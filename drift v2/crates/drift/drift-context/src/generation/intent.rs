@@ -24,6 +24,23 @@ impl ContextIntent {
             Self::GenerateSpec => "generate_spec",
         }
     }
+
+    /// Path and symbol-name fragments a repository map favours for this
+    /// intent: failure handling and tests for bugs, entry points for
+    /// orientation, trust boundaries for audits, the public surface for
+    /// features and specs.
+    pub fn repo_map_terms(&self) -> &'static [&'static str] {
+        match self {
+            Self::FixBug => &["error", "exception", "catch", "retry", "fail", "panic", "validat", "test", "spec"],
+            Self::AddFeature => &["route", "controller", "handler", "service", "api", "command", "plugin", "register"],
+            Self::UnderstandCode => &["main", "index", "app", "server", "bootstrap", "init", "config"],
+            Self::SecurityAudit => &[
+                "auth", "login", "password", "token", "secret", "crypt", "session", "permission", "sanitiz", "sql",
+                "query", "upload", "csrf", "jwt",
+            ],
+            Self::GenerateSpec => &["route", "controller", "handler", "api", "model", "schema", "types", "dto", "interface"],
+        }
+    }
 }
 
 impl std::fmt::Display for ContextIntent {
@@ -57,6 +74,7 @@ impl IntentWeights {
         w.insert("test_topology".to_string(), 1.8);
         w.insert("call_graph".to_string(), 1.6);
         w.insert("taint_analysis".to_string(), 1.5);
+        w.insert("change_history".to_string(), 1.4);
         w.insert("data_flow".to_string(), 1.4);
         w.insert("constraints".to_string(), 1.2);
         w.insert("conventions".to_string(), 0.8);
@@ -74,6 +92,7 @@ impl IntentWeights {
         w.insert("call_graph".to_string(), 1.3);
         w.insert("test_topology".to_string(), 1.2);
         w.insert("constraints".to_string(), 1.0);
+        w.insert("change_history".to_string(), 0.9);
         w.insert("overview".to_string(), 0.8);
         w
     }
@@ -86,6 +105,7 @@ impl IntentWeights {
         w.insert("public_api".to_string(), 1.5);
         w.insert("conventions".to_string(), 1.3);
         w.insert("dependencies".to_string(), 1.2);
+        w.insert("change_history".to_string(), 1.1);
         w.insert("coupling".to_string(), 1.0);
         w.insert("dna".to_string(), 0.8);
         w
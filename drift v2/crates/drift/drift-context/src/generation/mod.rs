@@ -1,11 +1,13 @@
-//! Context generation — builder, intent-weighted selection, deduplication, ordering.
+//! Context generation — builder, intent-weighted selection, deduplication, ordering, repo map.
 
 pub mod builder;
 pub mod intent;
 pub mod deduplication;
 pub mod ordering;
+pub mod repo_map;
 
 pub use builder::ContextEngine;
 pub use intent::{ContextIntent, IntentWeights};
 pub use deduplication::ContextSession;
 pub use ordering::ContentOrderer;
+pub use repo_map::{RankedFile, RepoGraph, RepoMapFocus, RepoSymbol};
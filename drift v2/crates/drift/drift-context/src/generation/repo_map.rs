@@ -0,0 +1,295 @@
+//! Repository map — PageRank-ranked files and symbols rendered as
+//! signatures-only outlines that fit a token budget.
+//!
+//! The ranking runs over one graph with a node per file and per symbol:
+//! calls link symbols, imports link files, and each file is linked with the
+//! symbols it defines so rank flows between the two layers. Files and
+//! identifiers the agent is working on get extra teleport mass, biasing the
+//! map toward their neighbourhood; files and symbols matching the intent's
+//! terms get a smaller share of it.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::intent::ContextIntent;
+use crate::tokenization::counter::TokenCounter;
+
+/// PageRank damping factor.
+const DAMPING: f64 = 0.85;
+/// Convergence threshold (L1 distance between iterations).
+const TOLERANCE: f64 = 1e-6;
+const MAX_ITERATIONS: usize = 100;
+/// Teleport weight of focused nodes relative to the rest.
+const FOCUS_BOOST: f64 = 20.0;
+/// Teleport weight of nodes matching the intent's terms. Below
+/// `FOCUS_BOOST` so what the agent named outranks what the intent suggests.
+const INTENT_BOOST: f64 = 5.0;
+
+/// A symbol defined in a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoSymbol {
+    /// Name as the call graph knows it (`Class.method` for methods).
+    pub name: String,
+    /// One-line signature, e.g. `async processPayment(order: Order): Promise<Receipt>`.
+    pub signature: String,
+    pub line: u32,
+}
+
+/// Files, their symbols, and the call and import edges between them.
+#[derive(Debug, Clone, Default)]
+pub struct RepoGraph {
+    files: BTreeMap<String, Vec<RepoSymbol>>,
+    calls: Vec<((String, String), (String, String))>,
+    imports: Vec<(String, String)>,
+}
+
+impl RepoGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(&mut self, file: impl Into<String>) {
+        self.files.entry(file.into()).or_default();
+    }
+
+    pub fn add_symbol(&mut self, file: impl Into<String>, symbol: RepoSymbol) {
+        self.files.entry(file.into()).or_default().push(symbol);
+    }
+
+    /// A call from `caller` in `caller_file` to `callee` in `callee_file`.
+    pub fn add_call(&mut self, caller_file: &str, caller: &str, callee_file: &str, callee: &str) {
+        self.calls.push((
+            (caller_file.to_string(), caller.to_string()),
+            (callee_file.to_string(), callee.to_string()),
+        ));
+    }
+
+    /// `from` imports `to`. Imports of files outside the graph are ignored.
+    pub fn add_import(&mut self, from: &str, to: &str) {
+        self.imports.push((from.to_string(), to.to_string()));
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn symbol_count(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+}
+
+/// What the agent is currently looking at.
+#[derive(Debug, Clone, Default)]
+pub struct RepoMapFocus {
+    /// Files mentioned in the request or already open in the session.
+    pub files: Vec<String>,
+    /// Identifiers mentioned in the request.
+    pub identifiers: Vec<String>,
+}
+
+impl RepoMapFocus {
+    /// Extract file paths and identifiers mentioned in free text.
+    pub fn from_text(text: &str) -> Self {
+        let mut focus = Self::default();
+        for token in text.split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '(' | ')' | '`' | '"' | '\'')) {
+            let token = token.trim_end_matches(['.', ':', '?', '!']);
+            if token.contains('/') || token.rsplit_once('.').is_some_and(|(stem, ext)| !stem.is_empty() && (1..=4).contains(&ext.len())) {
+                focus.files.push(token.to_string());
+            } else if token.len() >= 3 && token.chars().all(|c| c.is_alphanumeric() || c == '_') && !token.chars().all(|c| c.is_ascii_digit()) {
+                focus.identifiers.push(token.to_string());
+            }
+        }
+        focus
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.identifiers.is_empty()
+    }
+
+    fn matches_file(&self, path: &str) -> bool {
+        self.files.iter().any(|f| path == f || path.ends_with(&format!("/{f}")))
+    }
+
+    fn matches_symbol(&self, name: &str) -> bool {
+        let short = name.rsplit(['.', ':']).next().unwrap_or(name);
+        self.identifiers.iter().any(|id| id == name || id == short)
+    }
+}
+
+/// A ranked symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedSymbol {
+    pub symbol: RepoSymbol,
+    pub score: f64,
+}
+
+/// A ranked file with its symbols, highest-ranked first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedFile {
+    pub path: String,
+    /// File rank plus the rank of its symbols.
+    pub score: f64,
+    pub symbols: Vec<RankedSymbol>,
+}
+
+/// Rank files and symbols by personalized PageRank, biased toward `focus`
+/// and, when given, toward the files and symbols `intent` cares about.
+pub fn rank(graph: &RepoGraph, focus: &RepoMapFocus, intent: Option<ContextIntent>) -> Vec<RankedFile> {
+    // Node ids: files first, then symbols in file order.
+    let files: Vec<&String> = graph.files.keys().collect();
+    let file_ids: HashMap<&str, usize> = files.iter().enumerate().map(|(i, f)| (f.as_str(), i)).collect();
+    let mut symbol_ids: HashMap<(&str, &str), usize> = HashMap::new();
+    let mut owner: Vec<usize> = Vec::new();
+    let mut symbols: Vec<&RepoSymbol> = Vec::new();
+    for (file_id, file) in files.iter().enumerate() {
+        for symbol in &graph.files[*file] {
+            symbol_ids.entry((file.as_str(), symbol.name.as_str())).or_insert(files.len() + symbols.len());
+            owner.push(file_id);
+            symbols.push(symbol);
+        }
+    }
+    let n = files.len() + symbols.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut out: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+    let mut link = |from: usize, to: usize, weight: f64| {
+        if from != to {
+            *out[from].entry(to).or_default() += weight;
+        }
+    };
+    for ((caller_file, caller), (callee_file, callee)) in &graph.calls {
+        let from = symbol_ids.get(&(caller_file.as_str(), caller.as_str())).or(file_ids.get(caller_file.as_str()));
+        let to = symbol_ids.get(&(callee_file.as_str(), callee.as_str())).or(file_ids.get(callee_file.as_str()));
+        if let (Some(&from), Some(&to)) = (from, to) {
+            link(from, to, 1.0);
+        }
+    }
+    for (from, to) in &graph.imports {
+        if let (Some(&from), Some(&to)) = (file_ids.get(from.as_str()), file_ids.get(to.as_str())) {
+            link(from, to, 1.0);
+        }
+    }
+    for (offset, &file_id) in owner.iter().enumerate() {
+        let symbol_id = files.len() + offset;
+        let siblings = graph.files[files[file_id]].len() as f64;
+        link(symbol_id, file_id, 1.0);
+        link(file_id, symbol_id, 1.0 / siblings);
+    }
+
+    let terms = intent.map(|i| i.repo_map_terms()).unwrap_or_default();
+    let matches_intent = |text: &str| {
+        let text = text.to_lowercase();
+        terms.iter().any(|term| text.contains(term))
+    };
+    let mut personalization = vec![1.0; n];
+    for (i, file) in files.iter().enumerate() {
+        if focus.matches_file(file) {
+            personalization[i] += FOCUS_BOOST;
+        }
+        if matches_intent(file) {
+            personalization[i] += INTENT_BOOST;
+        }
+    }
+    for (offset, symbol) in symbols.iter().enumerate() {
+        if focus.matches_file(files[owner[offset]]) || focus.matches_symbol(&symbol.name) {
+            personalization[files.len() + offset] += FOCUS_BOOST;
+        }
+        if matches_intent(&symbol.name) {
+            personalization[files.len() + offset] += INTENT_BOOST;
+        }
+    }
+    let total: f64 = personalization.iter().sum();
+    personalization.iter_mut().for_each(|p| *p /= total);
+
+    let totals: Vec<f64> = out.iter().map(|edges| edges.values().sum()).collect();
+    let mut ranks = personalization.clone();
+    for _ in 0..MAX_ITERATIONS {
+        let dangling: f64 = (0..n).filter(|&u| totals[u] == 0.0).map(|u| ranks[u]).sum();
+        let mut next: Vec<f64> = personalization.iter().map(|p| (1.0 - DAMPING + DAMPING * dangling) * p).collect();
+        for (u, edges) in out.iter().enumerate() {
+            for (&v, &w) in edges {
+                next[v] += DAMPING * ranks[u] * w / totals[u];
+            }
+        }
+        let delta: f64 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+        ranks = next;
+        if delta < TOLERANCE {
+            break;
+        }
+    }
+
+    let mut ranked: Vec<RankedFile> = files
+        .iter()
+        .enumerate()
+        .map(|(i, path)| RankedFile { path: (*path).clone(), score: ranks[i], symbols: Vec::new() })
+        .collect();
+    for (offset, symbol) in symbols.into_iter().enumerate() {
+        let score = ranks[files.len() + offset];
+        let file = &mut ranked[owner[offset]];
+        file.score += score;
+        file.symbols.push(RankedSymbol { symbol: symbol.clone(), score });
+    }
+    for file in &mut ranked {
+        file.symbols.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.symbol.line.cmp(&b.symbol.line)));
+    }
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.path.cmp(&b.path)));
+    ranked
+}
+
+/// Render the `count` highest-ranked entries (symbols, or bare files for
+/// files without symbols) as one section per file, best file first.
+fn render(ranked: &[RankedFile], count: usize) -> Vec<(String, String)> {
+    let mut entries: Vec<(f64, usize, Option<usize>)> = Vec::new();
+    for (f, file) in ranked.iter().enumerate() {
+        if file.symbols.is_empty() {
+            entries.push((file.score, f, None));
+        }
+        entries.extend(file.symbols.iter().enumerate().map(|(s, sym)| (sym.score, f, Some(s))));
+    }
+    entries.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut selected: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for &(_, f, s) in entries.iter().take(count) {
+        let symbols = selected.entry(f).or_default();
+        symbols.extend(s);
+    }
+    selected
+        .into_iter()
+        .map(|(f, symbol_indices)| {
+            let file = &ranked[f];
+            let mut symbols: Vec<&RepoSymbol> = symbol_indices.iter().map(|&s| &file.symbols[s].symbol).collect();
+            symbols.sort_by_key(|s| s.line);
+            let outline = symbols.iter().map(|s| format!("{}: {}", s.line, s.signature)).collect::<Vec<_>>().join("\n");
+            (file.path.clone(), outline)
+        })
+        .collect()
+}
+
+pub(crate) fn combine(sections: &[(String, String)]) -> String {
+    sections
+        .iter()
+        .map(|(name, content)| format!("## {}\n\n{}", name, content))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn count_tokens(counter: &TokenCounter, text: &str) -> usize {
+    counter.count(text).unwrap_or_else(|_| TokenCounter::count_approximate(text))
+}
+
+/// Sections for the largest prefix of the ranking whose rendering fits in
+/// `budget` tokens.
+pub(crate) fn fit_to_budget(ranked: &[RankedFile], budget: usize, counter: &TokenCounter) -> Vec<(String, String)> {
+    let entry_count: usize = ranked.iter().map(|f| f.symbols.len().max(1)).sum();
+    let (mut low, mut high) = (0, entry_count);
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if count_tokens(counter, &combine(&render(ranked, mid))) <= budget {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    render(ranked, low)
+}
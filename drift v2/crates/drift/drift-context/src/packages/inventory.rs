@@ -0,0 +1,301 @@
+//! Dependency inventory — one resolved dependency graph per workspace
+//! package.
+//!
+//! Workspace members come from `drift_core::workspace::monorepo`; each
+//! member's manifests are matched with the nearest lockfile (walking up to
+//! the workspace root), and the lockfile's edges are followed from the
+//! member's direct dependencies to produce the transitive closure.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use drift_core::workspace::monorepo::{detect_workspace, WorkspaceLayout};
+use serde::{Deserialize, Serialize};
+
+use super::lockfile::{self, cargo, DirectDependency, LockedImport, LockedPackage, Lockfile};
+use super::PackageManager;
+
+/// Manifest file → lockfiles that may pin it, and the manager used when
+/// no lockfile is found.
+const ECOSYSTEMS: &[(&str, &[&str], PackageManager)] = &[
+    ("package.json", &["package-lock.json", "npm-shrinkwrap.json", "yarn.lock", "pnpm-lock.yaml"], PackageManager::Npm),
+    ("Cargo.toml", &["Cargo.lock"], PackageManager::Cargo),
+    ("pyproject.toml", &["poetry.lock"], PackageManager::Poetry),
+    ("requirements.txt", &[], PackageManager::Pip),
+    ("go.mod", &["go.sum"], PackageManager::Go),
+    ("Gemfile", &["Gemfile.lock"], PackageManager::Bundler),
+    ("composer.json", &["composer.lock"], PackageManager::Composer),
+    ("pom.xml", &[], PackageManager::Maven),
+    ("build.gradle", &["gradle.lockfile"], PackageManager::Gradle),
+    ("build.gradle.kts", &["gradle.lockfile"], PackageManager::Gradle),
+];
+
+/// A dependency in a workspace package's resolved graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedDependency {
+    pub name: String,
+    /// Pinned version; `None` when neither a lockfile nor an exact
+    /// manifest requirement pins it.
+    pub version: Option<String>,
+    pub manager: PackageManager,
+    pub purl: String,
+    /// Declared by the package's own manifest.
+    pub direct: bool,
+    /// Only reachable from dev/test dependencies.
+    pub dev: bool,
+    pub checksum: Option<String>,
+    /// purls of this dependency's own dependencies.
+    pub depends_on: Vec<String>,
+}
+
+/// The resolved dependencies of one workspace package for one ecosystem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    pub package: String,
+    /// Package directory relative to the workspace root (`.` for the root).
+    pub path: String,
+    pub manager: PackageManager,
+    /// Lockfile the graph was resolved from, relative to the workspace root.
+    pub lockfile: Option<String>,
+    pub dependencies: Vec<ResolvedDependency>,
+}
+
+impl DependencyGraph {
+    pub fn direct(&self) -> impl Iterator<Item = &ResolvedDependency> {
+        self.dependencies.iter().filter(|d| d.direct)
+    }
+}
+
+/// Build the dependency inventory for every package in the workspace at `root`.
+pub fn build_inventory(root: &Path) -> Result<Vec<DependencyGraph>, String> {
+    let layout = detect_workspace(root).map_err(|e| e.to_string())?;
+    let mut members: Vec<(Option<String>, PathBuf)> = vec![(None, PathBuf::from("."))];
+    if let WorkspaceLayout::Monorepo { packages, .. } = layout {
+        members.extend(packages.into_iter().map(|p| (Some(p.name), p.path)));
+    }
+
+    // Workspace siblings are first-party, not dependencies.
+    let mut first_party: HashSet<String> = HashSet::new();
+    for (_, path) in &members {
+        for (manifest, _, _) in ECOSYSTEMS {
+            if let Ok(content) = std::fs::read_to_string(root.join(path).join(manifest)) {
+                first_party.extend(manifest_name(manifest, &content));
+            }
+        }
+    }
+
+    let mut lockfiles: HashMap<PathBuf, Option<Lockfile>> = HashMap::new();
+    let mut graphs = Vec::new();
+    for (member_name, rel) in members {
+        let dir = root.join(&rel);
+        for (manifest, lock_names, default_manager) in ECOSYSTEMS {
+            let Ok(content) = std::fs::read_to_string(dir.join(manifest)) else { continue };
+            let declared = match lockfile::parse_manifest(manifest, &content) {
+                Ok(declared) => declared,
+                Err(e) => {
+                    tracing::warn!(path = %dir.join(manifest).display(), error = %e, "skipping unreadable manifest");
+                    continue;
+                }
+            };
+            let declared: Vec<DirectDependency> = declared.into_iter().filter(|d| !first_party.contains(&d.name)).collect();
+
+            let found = find_lockfile(root, &dir, lock_names);
+            let manager = found
+                .as_ref()
+                .and_then(|(_, name)| lockfile::LOCKFILES.iter().find(|(l, _)| l == name).map(|(_, m)| *m))
+                .unwrap_or(*default_manager);
+            let lock = found.as_ref().and_then(|(path, name)| {
+                lockfiles
+                    .entry(path.clone())
+                    .or_insert_with(|| {
+                        let content = std::fs::read_to_string(path).ok()?;
+                        lockfile::parse_lockfile(name, &content)
+                            .map_err(|e| tracing::warn!(path = %path.display(), error = %e, "skipping unreadable lockfile"))
+                            .ok()
+                    })
+                    .as_ref()
+            });
+            let importer = lock.and_then(|lock| {
+                let key = if manager == PackageManager::Cargo {
+                    cargo::cargo_package_name(&content)?
+                } else {
+                    let lock_dir = found.as_ref()?.0.parent()?;
+                    relative(lock_dir, &dir)
+                };
+                lock.importers.get(&key).map(Vec::as_slice)
+            });
+
+            let package = manifest_name(manifest, &content)
+                .or_else(|| member_name.clone())
+                .unwrap_or_else(|| root.file_name().and_then(|n| n.to_str()).unwrap_or("root").to_string());
+            graphs.push(DependencyGraph {
+                package,
+                path: relative(root, &dir),
+                manager,
+                lockfile: found.as_ref().map(|(path, _)| relative(root, path)),
+                dependencies: resolve_graph(manager, &declared, lock, importer),
+            });
+        }
+    }
+    Ok(graphs)
+}
+
+/// Resolve a package's dependency graph. Roots are the importer's locked
+/// direct dependencies when the lockfile records them, otherwise the
+/// manifest's declarations resolved against the lockfile; anything the
+/// lockfile doesn't pin is kept at its manifest requirement.
+pub fn resolve_graph(
+    manager: PackageManager,
+    declared: &[DirectDependency],
+    lock: Option<&Lockfile>,
+    importer: Option<&[LockedImport]>,
+) -> Vec<ResolvedDependency> {
+    let declared_dev: HashMap<&str, bool> = declared.iter().fold(HashMap::new(), |mut map, d| {
+        *map.entry(d.name.as_str()).or_insert(true) &= d.dev;
+        map
+    });
+    let mut out: Vec<ResolvedDependency> = Vec::new();
+    // (locked id, direct, dev)
+    let mut roots: Vec<(String, bool, bool)> = Vec::new();
+    match (lock, importer) {
+        (Some(lock), Some(imports)) => {
+            for import in imports {
+                let Some(package) = lock.get(&import.id) else { continue };
+                let dev = import.dev || declared_dev.get(package.name.as_str()).copied().unwrap_or(false);
+                roots.push((import.id.clone(), true, dev));
+            }
+        }
+        _ => {
+            for dep in declared {
+                match lock.and_then(|lock| lock.resolve(dep)) {
+                    Some(package) => roots.push((package.id.clone(), !dep.indirect, dep.dev)),
+                    None => {
+                        let version = dep.requirement.as_deref().map(lockfile::exact_version).filter(|v| is_exact(v)).map(str::to_string);
+                        out.push(ResolvedDependency {
+                            purl: purl(manager, &dep.name, version.as_deref()),
+                            name: dep.name.clone(),
+                            version,
+                            manager,
+                            direct: !dep.indirect,
+                            dev: dep.dev,
+                            checksum: None,
+                            depends_on: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    let Some(lock) = lock else { return out };
+    let by_id: HashMap<&str, &LockedPackage> = lock.packages.iter().map(|p| (p.id.as_str(), p)).collect();
+    if lock.flat {
+        for package in &lock.packages {
+            if !roots.iter().any(|(id, _, _)| *id == package.id) {
+                roots.push((package.id.clone(), false, package.dev));
+            }
+        }
+    }
+
+    // Production roots first so anything they reach is marked non-dev.
+    roots.sort_by_key(|(_, _, dev)| *dev);
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut queue: VecDeque<(String, bool)> = VecDeque::new();
+    for (id, direct, dev) in roots {
+        if let Some(&i) = index.get(&id) {
+            out[i].direct |= direct;
+            continue;
+        }
+        queue.push_back((id.clone(), dev));
+        while let Some((id, dev)) = queue.pop_front() {
+            if index.contains_key(&id) {
+                continue;
+            }
+            let Some(package) = by_id.get(id.as_str()) else { continue };
+            index.insert(id.clone(), out.len());
+            out.push(ResolvedDependency {
+                name: package.name.clone(),
+                version: Some(package.version.clone()).filter(|v| !v.is_empty()),
+                manager,
+                purl: purl(manager, &package.name, Some(&package.version)),
+                direct: false,
+                dev,
+                checksum: package.checksum.clone(),
+                depends_on: package.dependencies.iter().filter_map(|d| by_id.get(d.as_str())).map(|d| purl(manager, &d.name, Some(&d.version))).collect(),
+            });
+            queue.extend(package.dependencies.iter().map(|d| (d.clone(), dev)));
+        }
+        if let Some(&i) = index.get(&id) {
+            out[i].direct |= direct;
+        }
+    }
+    out.sort_by(|a, b| a.purl.cmp(&b.purl));
+    out.dedup_by(|a, b| a.purl == b.purl);
+    out
+}
+
+/// Package URL for a dependency (`pkg:npm/%40scope/name@1.0.0`).
+pub fn purl(manager: PackageManager, name: &str, version: Option<&str>) -> String {
+    let kind = manager.purl_type();
+    let path = match kind {
+        "npm" => name.replacen('@', "%40", 1),
+        // `group:artifact` → `group/artifact`
+        "maven" => name.replacen(':', "/", 1),
+        _ => name.to_string(),
+    };
+    match version.filter(|v| !v.is_empty()) {
+        Some(version) => format!("pkg:{kind}/{path}@{version}"),
+        None => format!("pkg:{kind}/{path}"),
+    }
+}
+
+/// A requirement that pins one version (`1.2.3`, `v1.2.3`), not a range.
+fn is_exact(requirement: &str) -> bool {
+    requirement.trim_start_matches('v').starts_with(|c: char| c.is_ascii_digit())
+        && !requirement.contains(['^', '~', '<', '>', '*', ' ', ',', '|'])
+        && !requirement.ends_with(".x")
+}
+
+/// Nearest directory from `dir` up to `root` holding one of `names`.
+fn find_lockfile(root: &Path, dir: &Path, names: &[&str]) -> Option<(PathBuf, &'static str)> {
+    let mut current = Some(dir);
+    while let Some(d) = current {
+        for (name, _) in lockfile::LOCKFILES {
+            if names.contains(name) && d.join(name).is_file() {
+                return Some((d.join(name), name));
+            }
+        }
+        if d == root {
+            break;
+        }
+        current = d.parent().filter(|p| p.starts_with(root));
+    }
+    None
+}
+
+fn relative(base: &Path, path: &Path) -> String {
+    let rel = path.strip_prefix(base).unwrap_or(path);
+    let parts: Vec<String> = rel
+        .components()
+        .filter(|c| !matches!(c, std::path::Component::CurDir))
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if parts.is_empty() { ".".to_string() } else { parts.join("/") }
+}
+
+/// The package name a manifest declares for itself.
+fn manifest_name(manifest: &str, content: &str) -> Option<String> {
+    match manifest {
+        "package.json" | "composer.json" => {
+            let json: serde_json::Value = serde_json::from_str(content).ok()?;
+            json.get("name")?.as_str().map(str::to_string)
+        }
+        "Cargo.toml" => cargo::cargo_package_name(content),
+        "pyproject.toml" => {
+            let toml: toml::Value = toml::from_str(content).ok()?;
+            let name = toml.get("project").and_then(|p| p.get("name")).or_else(|| toml.get("tool")?.get("poetry")?.get("name"))?;
+            name.as_str().map(lockfile::python::normalize)
+        }
+        "go.mod" => content.lines().find_map(|l| l.trim().strip_prefix("module ")).map(|m| m.trim().to_string()),
+        _ => None,
+    }
+}
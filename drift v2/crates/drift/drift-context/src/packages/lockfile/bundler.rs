@@ -0,0 +1,99 @@
+//! Bundler: `Gemfile.lock` and `Gemfile`.
+
+use super::{DirectDependency, LockedImport, LockedPackage, Lockfile};
+
+pub fn parse_gemfile_lock(content: &str) -> Lockfile {
+    let mut lock = Lockfile::default();
+    let mut section = "";
+    let mut remote: Option<String> = None;
+    let mut direct = Vec::new();
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim();
+        if indent == 0 {
+            section = trimmed;
+            remote = None;
+            continue;
+        }
+        match section {
+            "GEM" | "GIT" => {
+                if let Some(url) = trimmed.strip_prefix("remote:") {
+                    remote = Some(url.trim().to_string());
+                } else if indent == 4 {
+                    // `nokogiri (1.13.10-x86_64-linux)` — platform variants share an entry.
+                    let (name, version) = spec(trimmed);
+                    let version = version.split('-').next().unwrap_or_default();
+                    if lock.get(name).is_none() {
+                        let mut package = LockedPackage::new(name, name, version);
+                        package.source = remote.clone();
+                        lock.packages.push(package);
+                    }
+                } else if indent == 6 {
+                    let (dep, _) = spec(trimmed);
+                    if let Some(package) = lock.packages.last_mut() {
+                        if !package.dependencies.iter().any(|d| d == dep) {
+                            package.dependencies.push(dep.to_string());
+                        }
+                    }
+                }
+            }
+            "DEPENDENCIES" if indent == 2 => {
+                direct.push(spec(trimmed).0.trim_end_matches('!').to_string());
+            }
+            _ => {}
+        }
+    }
+    let known: Vec<String> = lock.packages.iter().map(|p| p.id.clone()).collect();
+    for package in &mut lock.packages {
+        package.dependencies.retain(|d| known.contains(d));
+    }
+    let imports = direct.into_iter().filter(|d| known.contains(d)).map(|id| LockedImport { id, dev: false }).collect();
+    lock.importers.insert(".".to_string(), imports);
+    lock
+}
+
+/// `rails (~> 7.0.4)` → (`rails`, `~> 7.0.4`).
+fn spec(line: &str) -> (&str, &str) {
+    match line.split_once(" (") {
+        Some((name, rest)) => (name, rest.trim_end_matches(')')),
+        None => (line, ""),
+    }
+}
+
+/// `gem "rails", "~> 7.0"`; gems inside `group :development, :test do`
+/// blocks (or with a `group:` option) that exclude `:production` and the
+/// default group are dev dependencies.
+pub fn parse_gemfile(content: &str) -> Vec<DirectDependency> {
+    let mut deps = Vec::new();
+    let mut groups: Vec<bool> = Vec::new();
+    for line in content.lines() {
+        let line = line.split(" #").next().unwrap_or(line).trim();
+        // `group`, `platforms` and `source` blocks all close with `end`.
+        if line.ends_with(" do") {
+            let inherited = groups.last().copied().unwrap_or(false);
+            groups.push(line.strip_prefix("group ").map_or(inherited, is_dev_group));
+            continue;
+        }
+        if line == "end" {
+            groups.pop();
+            continue;
+        }
+        let Some(rest) = line.strip_prefix("gem ") else { continue };
+        let args: Vec<&str> = rest.split(',').map(str::trim).collect();
+        let Some(name) = args.first().map(|a| a.trim_matches(|c| c == '"' || c == '\'')) else { continue };
+        if args.iter().any(|a| a.starts_with("path:") || a.starts_with(":path")) {
+            continue;
+        }
+        let requirement = args.get(1).filter(|a| a.starts_with(['"', '\''])).map(|a| a.trim_matches(|c| c == '"' || c == '\'').to_string());
+        let dev = groups.last().copied().unwrap_or(false) || args.iter().any(|a| a.starts_with("group:") && is_dev_group(a));
+        deps.push(DirectDependency::new(name, requirement, dev));
+    }
+    deps
+}
+
+fn is_dev_group(groups: &str) -> bool {
+    !groups.contains(":production") && !groups.contains(":default") && (groups.contains(":development") || groups.contains(":test"))
+}
@@ -0,0 +1,105 @@
+//! Cargo: `Cargo.lock` and `Cargo.toml`.
+//!
+//! Packages without a `source` are workspace members or path crates; they
+//! become importers (keyed by crate name) rather than locked packages, and
+//! the dependencies of path crates are folded into whoever depends on them.
+
+use std::collections::HashSet;
+
+use toml::Value;
+
+use super::{DirectDependency, LockedImport, LockedPackage, Lockfile};
+
+const DEPENDENCY_TABLES: &[(&str, bool)] = &[("dependencies", false), ("build-dependencies", false), ("dev-dependencies", true)];
+
+pub fn parse_cargo_lock(content: &str) -> Result<Lockfile, String> {
+    let toml: Value = toml::from_str(content).map_err(|e| format!("Cargo.lock: {e}"))?;
+    let entries: Vec<&Value> = toml.get("package").and_then(Value::as_array).map(|a| a.iter().collect()).unwrap_or_default();
+    let field = |entry: &Value, key: &str| entry.get(key).and_then(Value::as_str).map(str::to_string);
+
+    let ids: Vec<(String, String, bool)> = entries
+        .iter()
+        .map(|e| (field(e, "name").unwrap_or_default(), field(e, "version").unwrap_or_default(), e.get("source").is_some()))
+        .collect();
+    // `"serde"`, `"syn 2.0.1"` or `"syn 2.0.1 (registry+...)"` → index into `entries`.
+    let lookup = |reference: &str| {
+        let mut parts = reference.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let version = parts.next();
+        ids.iter().position(|(n, v, _)| n == name && version.map_or(true, |version| version == v))
+    };
+    let dependencies = |entry: &Value| -> Vec<usize> {
+        entry
+            .get("dependencies")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|d| d.as_str().and_then(lookup))
+            .collect()
+    };
+
+    let mut lock = Lockfile::default();
+    for (index, entry) in entries.iter().enumerate() {
+        let (name, version, sourced) = &ids[index];
+        if *sourced {
+            let mut package = LockedPackage::new(format!("{name} {version}"), name, version);
+            package.checksum = field(entry, "checksum");
+            package.source = field(entry, "source");
+            package.dependencies = dependencies(entry)
+                .into_iter()
+                .filter(|&d| ids[d].2)
+                .map(|d| format!("{} {}", ids[d].0, ids[d].1))
+                .collect();
+            lock.packages.push(package);
+            continue;
+        }
+        // Workspace member or path crate: its registry dependencies plus
+        // those reached through other path crates.
+        let mut imports = Vec::new();
+        let mut seen = HashSet::from([index]);
+        let mut stack = dependencies(entry);
+        while let Some(d) = stack.pop() {
+            if !seen.insert(d) {
+                continue;
+            }
+            if ids[d].2 {
+                imports.push(LockedImport { id: format!("{} {}", ids[d].0, ids[d].1), dev: false });
+            } else {
+                stack.extend(dependencies(entries[d]));
+            }
+        }
+        imports.sort_by(|a, b| a.id.cmp(&b.id));
+        lock.importers.insert(name.clone(), imports);
+    }
+    Ok(lock)
+}
+
+pub fn parse_cargo_toml(content: &str) -> Result<Vec<DirectDependency>, String> {
+    let toml: Value = toml::from_str(content).map_err(|e| format!("Cargo.toml: {e}"))?;
+    let mut deps = Vec::new();
+    let mut tables = vec![&toml];
+    // `[target.'cfg(unix)'.dependencies]`
+    tables.extend(toml.get("target").and_then(Value::as_table).into_iter().flat_map(|t| t.values()));
+    for table in tables {
+        for (section, dev) in DEPENDENCY_TABLES {
+            for (key, spec) in table.get(*section).and_then(Value::as_table).into_iter().flatten() {
+                if spec.get("path").is_some() {
+                    continue;
+                }
+                let name = spec.get("package").and_then(Value::as_str).unwrap_or(key);
+                let requirement = match spec {
+                    Value::String(req) => Some(req.clone()),
+                    _ => spec.get("version").and_then(Value::as_str).map(str::to_string),
+                };
+                deps.push(DirectDependency::new(name, requirement, *dev));
+            }
+        }
+    }
+    Ok(deps)
+}
+
+/// `[package] name` of a crate manifest — the importer key in `Cargo.lock`.
+pub fn cargo_package_name(content: &str) -> Option<String> {
+    let toml: Value = toml::from_str(content).ok()?;
+    toml.get("package")?.get("name")?.as_str().map(str::to_string)
+}
@@ -0,0 +1,49 @@
+//! Composer: `composer.lock` and `composer.json`. Platform requirements
+//! (`php`, `ext-*`, `lib-*`) have no vendor prefix and are skipped.
+
+use serde_json::Value;
+
+use super::{DirectDependency, LockedPackage, Lockfile};
+
+fn is_platform(name: &str) -> bool {
+    !name.contains('/')
+}
+
+pub fn parse_composer_lock(content: &str) -> Result<Lockfile, String> {
+    let json: Value = serde_json::from_str(content).map_err(|e| format!("composer.lock: {e}"))?;
+    let mut lock = Lockfile::default();
+    for (section, dev) in [("packages", false), ("packages-dev", true)] {
+        for entry in json.get(section).and_then(Value::as_array).into_iter().flatten() {
+            let Some(name) = entry.get("name").and_then(Value::as_str) else { continue };
+            let name = name.to_ascii_lowercase();
+            let mut package = LockedPackage::new(name.clone(), name, entry.get("version").and_then(Value::as_str).unwrap_or_default());
+            package.dev = dev;
+            let dist = entry.get("dist");
+            package.checksum = dist.and_then(|d| d.get("shasum")).and_then(Value::as_str).filter(|s| !s.is_empty()).map(str::to_string);
+            package.source = dist.or(entry.get("source")).and_then(|d| d.get("url")).and_then(Value::as_str).map(str::to_string);
+            package.dependencies = entry
+                .get("require")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flat_map(|r| r.keys())
+                .filter(|d| !is_platform(d))
+                .map(|d| d.to_ascii_lowercase())
+                .collect();
+            lock.packages.push(package);
+        }
+    }
+    Ok(lock)
+}
+
+pub fn parse_composer_json(content: &str) -> Result<Vec<DirectDependency>, String> {
+    let json: Value = serde_json::from_str(content).map_err(|e| format!("composer.json: {e}"))?;
+    let mut deps = Vec::new();
+    for (section, dev) in [("require", false), ("require-dev", true)] {
+        for (name, req) in json.get(section).and_then(Value::as_object).into_iter().flatten() {
+            if !is_platform(name) {
+                deps.push(DirectDependency::new(name.to_ascii_lowercase(), req.as_str().map(str::to_string), dev));
+            }
+        }
+    }
+    Ok(deps)
+}
@@ -0,0 +1,59 @@
+//! Go modules: `go.sum` and `go.mod`.
+//!
+//! `go.sum` pins checksums but records no edges; `go.mod` lists every
+//! required module (Go 1.17+ module graph pruning), with transitive ones
+//! marked `// indirect`.
+
+use super::{DirectDependency, LockedPackage, Lockfile};
+
+pub fn parse_go_sum(content: &str) -> Lockfile {
+    let mut lock = Lockfile::default();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(module), Some(version), Some(hash)) = (parts.next(), parts.next(), parts.next()) else { continue };
+        // `/go.mod` lines only hash the module file of versions considered
+        // during resolution, not the selected module contents.
+        if version.ends_with("/go.mod") {
+            continue;
+        }
+        let id = format!("{module}@{version}");
+        if lock.get(&id).is_none() {
+            let mut package = LockedPackage::new(id, module, version);
+            package.checksum = Some(hash.to_string());
+            lock.packages.push(package);
+        }
+    }
+    lock
+}
+
+pub fn parse_go_mod(content: &str) -> Vec<DirectDependency> {
+    let mut deps = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let (code, comment) = line.split_once("//").unwrap_or((line, ""));
+        let code = code.trim();
+        let spec = if in_block {
+            if code == ")" {
+                in_block = false;
+                continue;
+            }
+            code
+        } else if let Some(rest) = code.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+        let mut parts = spec.split_whitespace();
+        if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+            let mut dep = DirectDependency::new(module, Some(version.to_string()), false);
+            dep.indirect = comment.trim() == "indirect";
+            deps.push(dep);
+        }
+    }
+    deps
+}
@@ -0,0 +1,119 @@
+//! Maven and Gradle: `pom.xml`, `build.gradle(.kts)` and `gradle.lockfile`.
+//! Packages are named `group:artifact`.
+
+use std::collections::HashMap;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::{DirectDependency, LockedPackage, Lockfile};
+
+/// Gradle configurations that declare dependencies.
+const GRADLE_CONFIGURATIONS: &[&str] = &[
+    "implementation", "api", "compileOnly", "runtimeOnly", "compile", "runtime",
+    "testImplementation", "testCompileOnly", "testRuntimeOnly", "androidTestImplementation",
+    "kapt", "ksp", "annotationProcessor",
+];
+
+/// `group:artifact:version=compileClasspath,runtimeClasspath`
+pub fn parse_gradle_lockfile(content: &str) -> Lockfile {
+    let mut lock = Lockfile { flat: true, ..Default::default() };
+    for line in content.lines().map(str::trim).filter(|l| !l.starts_with('#')) {
+        let Some((coordinates, configurations)) = line.split_once('=') else { continue };
+        let mut parts = coordinates.splitn(3, ':');
+        let (Some(group), Some(artifact), Some(version)) = (parts.next(), parts.next(), parts.next()) else { continue };
+        let name = format!("{group}:{artifact}");
+        let mut package = LockedPackage::new(name.clone(), name, version);
+        package.dev = configurations.split(',').all(|c| c.trim().starts_with("test") || c.trim().starts_with("androidTest"));
+        lock.packages.push(package);
+    }
+    lock
+}
+
+/// Project-level `<dependencies>` (not `<dependencyManagement>` or plugin
+/// dependencies), with `${property}` versions substituted.
+pub fn parse_pom(content: &str) -> Result<Vec<DirectDependency>, String> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+    let mut path: Vec<String> = Vec::new();
+    let mut properties: HashMap<String, String> = HashMap::new();
+    let mut current: HashMap<String, String> = HashMap::new();
+    let mut raw = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => path.push(String::from_utf8_lossy(e.local_name().as_ref()).into_owned()),
+            Ok(Event::End(_)) => {
+                if path_is(&path, &["project", "dependencies", "dependency"]) {
+                    raw.push(std::mem::take(&mut current));
+                }
+                path.pop();
+            }
+            Ok(Event::Text(text)) => {
+                let text = text.unescape().map_err(|e| format!("pom.xml: {e}"))?.into_owned();
+                let Some(leaf) = path.last() else { continue };
+                if path.len() == 3 && path_is(&path[..2], &["project", "properties"]) {
+                    properties.insert(leaf.clone(), text);
+                } else if path.len() == 2 && path[0] == "project" && leaf == "version" {
+                    properties.insert("project.version".to_string(), text);
+                } else if path.len() == 4 && path_is(&path[..3], &["project", "dependencies", "dependency"]) {
+                    current.insert(leaf.clone(), text);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("pom.xml: {e}")),
+            _ => {}
+        }
+    }
+    let substitute = |value: &str| match value.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+        Some(key) => properties.get(key).cloned(),
+        None => Some(value.to_string()),
+    };
+    Ok(raw
+        .into_iter()
+        .filter_map(|dep| {
+            let name = format!("{}:{}", dep.get("groupId")?, dep.get("artifactId")?);
+            let version = dep.get("version").and_then(|v| substitute(v));
+            let dev = dep.get("scope").map(String::as_str) == Some("test");
+            Some(DirectDependency::new(name, version, dev))
+        })
+        .collect())
+}
+
+fn path_is(path: &[String], expected: &[&str]) -> bool {
+    path.len() == expected.len() && path.iter().zip(expected).all(|(a, b)| a == b)
+}
+
+/// `implementation 'g:a:v'`, `testImplementation("g:a:v")` and
+/// `implementation group: 'g', name: 'a', version: 'v'`. Version catalog
+/// references (`libs.foo`) carry no coordinates and are skipped.
+pub fn parse_gradle_build(content: &str) -> Vec<DirectDependency> {
+    let mut deps = Vec::new();
+    for line in content.lines().map(str::trim) {
+        let Some(configuration) = GRADLE_CONFIGURATIONS
+            .iter()
+            .find(|c| line.strip_prefix(**c).is_some_and(|rest| rest.starts_with([' ', '('])))
+        else {
+            continue;
+        };
+        let dev = configuration.starts_with("test") || configuration.starts_with("androidTest");
+        let args = &line[configuration.len()..];
+        let quoted: Vec<&str> = args.split(['\'', '"']).skip(1).step_by(2).collect();
+        let coordinates = if args.contains("group:") || args.contains("group =") {
+            let field = |key: &str| {
+                let at = args.find(key)?;
+                args[at + key.len()..].split(['\'', '"']).nth(1)
+            };
+            field("group").zip(field("name")).map(|(g, a)| (format!("{g}:{a}"), field("version").map(str::to_string)))
+        } else {
+            quoted.first().and_then(|c| {
+                let mut parts = c.split(':');
+                let (group, artifact) = (parts.next()?, parts.next()?);
+                Some((format!("{group}:{artifact}"), parts.next().map(str::to_string)))
+            })
+        };
+        if let Some((name, version)) = coordinates {
+            deps.push(DirectDependency::new(name, version, dev));
+        }
+    }
+    deps
+}
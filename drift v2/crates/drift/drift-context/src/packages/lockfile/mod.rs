@@ -0,0 +1,162 @@
+//! Lockfile and manifest parsing.
+//!
+//! Each parser turns a lockfile into a flat list of [`LockedPackage`]s whose
+//! `dependencies` point at other packages by id, plus (where the format
+//! records them) the direct dependencies of each workspace importer.
+//! Manifests yield the declared [`DirectDependency`] list used when the
+//! lockfile doesn't say which packages a workspace member depends on.
+
+pub mod bundler;
+pub mod cargo;
+pub mod composer;
+pub mod go;
+pub mod jvm;
+pub mod npm;
+pub mod pnpm;
+pub mod python;
+pub mod yarn;
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::PackageManager;
+
+/// A dependency declared by a manifest (`package.json`, `Cargo.toml`, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectDependency {
+    pub name: String,
+    /// Version requirement as written (`^1.2.0`, `1.2.0`, `>=2`).
+    pub requirement: Option<String>,
+    pub dev: bool,
+    /// Declared but only needed transitively (`// indirect` in go.mod).
+    pub indirect: bool,
+}
+
+impl DirectDependency {
+    pub fn new(name: impl Into<String>, requirement: Option<String>, dev: bool) -> Self {
+        Self { name: name.into(), requirement, dev, indirect: false }
+    }
+}
+
+/// A package pinned by a lockfile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockedPackage {
+    /// Unique key within the lockfile; `dependencies` refer to these.
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    /// Marked dev-only by the lockfile itself.
+    pub dev: bool,
+    /// Integrity hash as recorded (`sha512-...`, hex SHA-256, `h1:...`).
+    pub checksum: Option<String>,
+    /// Registry, git or path source when recorded.
+    pub source: Option<String>,
+    pub dependencies: Vec<String>,
+}
+
+impl LockedPackage {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            version: version.into(),
+            dev: false,
+            checksum: None,
+            source: None,
+            dependencies: Vec::new(),
+        }
+    }
+}
+
+/// A direct dependency of a workspace importer, already resolved to a
+/// locked package id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockedImport {
+    pub id: String,
+    pub dev: bool,
+}
+
+/// A parsed lockfile.
+#[derive(Debug, Clone, Default)]
+pub struct Lockfile {
+    pub packages: Vec<LockedPackage>,
+    /// Direct dependencies per importer. Keys are importer paths relative
+    /// to the lockfile (`.` for the root) or, for `Cargo.lock`, package names.
+    pub importers: BTreeMap<String, Vec<LockedImport>>,
+    /// Alternate keys (`lodash@^4.17.0` descriptors) mapping to package ids.
+    pub aliases: HashMap<String, String>,
+    /// The lockfile records no edges; every package belongs to the
+    /// resolved set (`gradle.lockfile`).
+    pub flat: bool,
+}
+
+impl Lockfile {
+    pub fn get(&self, id: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|p| p.id == id)
+    }
+
+    /// Resolve a manifest dependency to a locked package: an exact
+    /// `name@requirement` alias first, then a package of that name whose
+    /// version satisfies an exact requirement, then the first by name.
+    pub fn resolve(&self, dep: &DirectDependency) -> Option<&LockedPackage> {
+        if let Some(req) = &dep.requirement {
+            if let Some(id) = self.aliases.get(&format!("{}@{}", dep.name, req)) {
+                return self.get(id);
+            }
+        }
+        let mut by_name = self.packages.iter().filter(|p| p.name == dep.name);
+        let first = by_name.next()?;
+        let exact = dep.requirement.as_deref().map(exact_version);
+        Some(std::iter::once(first).chain(by_name).find(|p| Some(p.version.as_str()) == exact).unwrap_or(first))
+    }
+}
+
+/// `=1.2.3`, `==1.2.3` → `1.2.3`; anything else unchanged.
+pub fn exact_version(requirement: &str) -> &str {
+    requirement.trim().trim_start_matches("==").trim_start_matches('=').trim()
+}
+
+/// The lockfile names this module parses, with their package manager.
+pub const LOCKFILES: &[(&str, PackageManager)] = &[
+    ("package-lock.json", PackageManager::Npm),
+    ("npm-shrinkwrap.json", PackageManager::Npm),
+    ("yarn.lock", PackageManager::Yarn),
+    ("pnpm-lock.yaml", PackageManager::Pnpm),
+    ("Cargo.lock", PackageManager::Cargo),
+    ("poetry.lock", PackageManager::Poetry),
+    ("go.sum", PackageManager::Go),
+    ("Gemfile.lock", PackageManager::Bundler),
+    ("composer.lock", PackageManager::Composer),
+    ("gradle.lockfile", PackageManager::Gradle),
+];
+
+/// Parse a lockfile by file name.
+pub fn parse_lockfile(file_name: &str, content: &str) -> Result<Lockfile, String> {
+    match file_name {
+        "package-lock.json" | "npm-shrinkwrap.json" => npm::parse_package_lock(content),
+        "yarn.lock" => Ok(yarn::parse_yarn_lock(content)),
+        "pnpm-lock.yaml" => pnpm::parse_pnpm_lock(content),
+        "Cargo.lock" => cargo::parse_cargo_lock(content),
+        "poetry.lock" => python::parse_poetry_lock(content),
+        "go.sum" => Ok(go::parse_go_sum(content)),
+        "Gemfile.lock" => Ok(bundler::parse_gemfile_lock(content)),
+        "composer.lock" => composer::parse_composer_lock(content),
+        "gradle.lockfile" => Ok(jvm::parse_gradle_lockfile(content)),
+        other => Err(format!("unsupported lockfile: {other}")),
+    }
+}
+
+/// Parse the dependencies declared by a manifest, by file name.
+pub fn parse_manifest(file_name: &str, content: &str) -> Result<Vec<DirectDependency>, String> {
+    match file_name {
+        "package.json" => npm::parse_package_json(content),
+        "Cargo.toml" => cargo::parse_cargo_toml(content),
+        "pyproject.toml" => python::parse_pyproject(content),
+        "requirements.txt" => Ok(python::parse_requirements(content)),
+        "go.mod" => Ok(go::parse_go_mod(content)),
+        "Gemfile" => Ok(bundler::parse_gemfile(content)),
+        "composer.json" => composer::parse_composer_json(content),
+        "pom.xml" => jvm::parse_pom(content),
+        "build.gradle" | "build.gradle.kts" => Ok(jvm::parse_gradle_build(content)),
+        other => Err(format!("unsupported manifest: {other}")),
+    }
+}
@@ -0,0 +1,113 @@
+//! npm: `package-lock.json` / `npm-shrinkwrap.json` (lockfile v1–v3) and
+//! `package.json`.
+
+use serde_json::{Map, Value};
+
+use super::{DirectDependency, LockedImport, LockedPackage, Lockfile};
+
+const PROD_SECTIONS: &[&str] = &["dependencies", "optionalDependencies"];
+
+pub fn parse_package_json(content: &str) -> Result<Vec<DirectDependency>, String> {
+    let json: Value = serde_json::from_str(content).map_err(|e| format!("package.json: {e}"))?;
+    let mut deps = Vec::new();
+    for (section, dev) in [("dependencies", false), ("optionalDependencies", false), ("devDependencies", true)] {
+        for (name, req) in json.get(section).and_then(Value::as_object).into_iter().flatten() {
+            let req = req.as_str().unwrap_or_default();
+            // Workspace siblings and local paths are not third-party packages.
+            if ["workspace:", "file:", "link:"].iter().any(|p| req.starts_with(p)) {
+                continue;
+            }
+            deps.push(DirectDependency::new(name.clone(), Some(req.to_string()), dev));
+        }
+    }
+    Ok(deps)
+}
+
+pub fn parse_package_lock(content: &str) -> Result<Lockfile, String> {
+    let json: Value = serde_json::from_str(content).map_err(|e| format!("package-lock.json: {e}"))?;
+    let entries = match json.get("packages").and_then(Value::as_object) {
+        Some(packages) => packages.clone(),
+        None => {
+            // v1: nested `dependencies` trees keyed by name.
+            let mut flat = Map::new();
+            if let Some(deps) = json.get("dependencies").and_then(Value::as_object) {
+                flatten_v1(deps, "", &mut flat);
+            }
+            flat
+        }
+    };
+
+    let mut lock = Lockfile::default();
+    for (key, entry) in &entries {
+        if !key.contains("node_modules/") || entry.get("link").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_else(|| key.rsplit("node_modules/").next().unwrap_or(key));
+        let mut package = LockedPackage::new(key.clone(), name, entry.get("version").and_then(Value::as_str).unwrap_or_default());
+        package.dev = entry.get("dev").and_then(Value::as_bool).unwrap_or(false);
+        package.checksum = entry.get("integrity").and_then(Value::as_str).map(str::to_string);
+        package.source = entry.get("resolved").and_then(Value::as_str).map(str::to_string);
+        // v2+ lists `dependencies`; v1 lists `requires`.
+        let mut declared = Vec::new();
+        for section in PROD_SECTIONS.iter().chain(&["requires"]) {
+            declared.extend(entry.get(*section).and_then(Value::as_object).into_iter().flat_map(|m| m.keys()));
+        }
+        package.dependencies = declared.into_iter().filter_map(|dep| resolve(&entries, key, dep)).collect();
+        lock.packages.push(package);
+    }
+
+    // Importers: the root (`""`) and workspace members (non-`node_modules` keys).
+    for (key, entry) in &entries {
+        if key.contains("node_modules/") {
+            continue;
+        }
+        let mut imports = Vec::new();
+        for (section, dev) in [("dependencies", false), ("optionalDependencies", false), ("devDependencies", true)] {
+            for dep in entry.get(section).and_then(Value::as_object).into_iter().flat_map(|m| m.keys()) {
+                if let Some(id) = resolve(&entries, key, dep) {
+                    imports.push(LockedImport { id, dev });
+                }
+            }
+        }
+        let importer = if key.is_empty() { ".".to_string() } else { key.clone() };
+        lock.importers.insert(importer, imports);
+    }
+    Ok(lock)
+}
+
+/// Node resolution: `<from>/node_modules/<dep>`, then each enclosing
+/// `node_modules` directory up to the root. Links (workspace members) are
+/// not packages.
+fn resolve(entries: &Map<String, Value>, from: &str, dep: &str) -> Option<String> {
+    let mut base = from.to_string();
+    loop {
+        let candidate = if base.is_empty() { format!("node_modules/{dep}") } else { format!("{base}/node_modules/{dep}") };
+        if let Some(entry) = entries.get(&candidate) {
+            return (entry.get("link").and_then(Value::as_bool) != Some(true)).then_some(candidate);
+        }
+        if base.is_empty() {
+            return None;
+        }
+        // `a/node_modules/b` → `a`; a workspace member directory falls back to the root.
+        base = base.rfind("/node_modules/").map(|pos| base[..pos].to_string()).unwrap_or_default();
+    }
+}
+
+fn flatten_v1(deps: &Map<String, Value>, prefix: &str, out: &mut Map<String, Value>) {
+    for (name, entry) in deps {
+        let key = if prefix.is_empty() { format!("node_modules/{name}") } else { format!("{prefix}/node_modules/{name}") };
+        if let Some(nested) = entry.get("dependencies").and_then(Value::as_object) {
+            flatten_v1(nested, &key, out);
+        }
+        let mut entry = entry.clone();
+        if let Some(obj) = entry.as_object_mut() {
+            obj.remove("dependencies");
+            obj.insert("name".to_string(), Value::String(name.clone()));
+        }
+        out.insert(key, entry);
+    }
+}
+
@@ -0,0 +1,120 @@
+//! pnpm: `pnpm-lock.yaml`, lockfile formats 5.x, 6.x and 9.x.
+//!
+//! Package keys are `/name/1.0.0` (5.x), `/name@1.0.0` (6.x) or
+//! `name@1.0.0` (9.x, with edges under `snapshots`); peer suffixes
+//! (`_react@18.2.0`, `(react@18.2.0)`) are dropped so one id covers every
+//! peer variant.
+
+use serde_yaml::{Mapping, Value};
+
+use super::{LockedImport, LockedPackage, Lockfile};
+
+pub fn parse_pnpm_lock(content: &str) -> Result<Lockfile, String> {
+    let yaml: Value = serde_yaml::from_str(content).map_err(|e| format!("pnpm-lock.yaml: {e}"))?;
+    let version = match yaml.get("lockfileVersion") {
+        Some(Value::Number(n)) => n.as_f64().unwrap_or(0.0),
+        Some(Value::String(s)) => s.parse().unwrap_or(0.0),
+        _ => 0.0,
+    };
+    let legacy = version < 6.0;
+
+    let mut lock = Lockfile::default();
+    for section in ["packages", "snapshots"] {
+        for (key, entry) in mapping(&yaml, section) {
+            let Some(key) = key.as_str() else { continue };
+            let Some((name, version)) = split_key(key, legacy) else { continue };
+            let id = format!("{name}@{version}");
+            let index = match lock.packages.iter().position(|p| p.id == id) {
+                Some(index) => index,
+                None => {
+                    lock.packages.push(LockedPackage::new(id.clone(), name, version));
+                    lock.packages.len() - 1
+                }
+            };
+            let package = &mut lock.packages[index];
+            package.dev |= entry.get("dev").and_then(Value::as_bool).unwrap_or(false);
+            if let Some(resolution) = entry.get("resolution") {
+                package.checksum = package.checksum.take().or_else(|| str_field(resolution, "integrity"));
+                package.source = package.source.take().or_else(|| str_field(resolution, "tarball").or_else(|| str_field(resolution, "repo")));
+            }
+            for deps in ["dependencies", "optionalDependencies"] {
+                for (dep, reference) in mapping(entry, deps) {
+                    if let Some(dep_id) = dep.as_str().and_then(|d| reference_id(d, reference, legacy)) {
+                        if !package.dependencies.contains(&dep_id) {
+                            package.dependencies.push(dep_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // 5.x single-project lockfiles keep the root importer at the top level.
+    let importers: Vec<(String, &Value)> = match yaml.get("importers").and_then(Value::as_mapping) {
+        Some(importers) => importers.iter().filter_map(|(k, v)| Some((k.as_str()?.to_string(), v))).collect(),
+        None => vec![(".".to_string(), &yaml)],
+    };
+    for (path, importer) in importers {
+        let mut imports = Vec::new();
+        for (section, dev) in [("dependencies", false), ("optionalDependencies", false), ("devDependencies", true)] {
+            for (dep, reference) in mapping(importer, section) {
+                // 6.x+: `{ specifier, version }`; 5.x: the version string.
+                let reference = reference.get("version").unwrap_or(reference);
+                if let Some(id) = dep.as_str().and_then(|d| reference_id(d, reference, legacy)) {
+                    imports.push(LockedImport { id, dev });
+                }
+            }
+        }
+        lock.importers.insert(path, imports);
+    }
+    Ok(lock)
+}
+
+fn mapping<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = (&'a Value, &'a Value)> {
+    value.get(key).and_then(Value::as_mapping).map(Mapping::iter).into_iter().flatten()
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+/// Package key → (name, version) without peer suffixes.
+fn split_key(key: &str, legacy: bool) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/');
+    let key = key.split('(').next()?;
+    let (name, version) = if legacy {
+        // `@scope/name/1.0.0_peer@1` — the version is the segment after the name.
+        let segments = if key.starts_with('@') { 2 } else { 1 };
+        let mut parts = key.splitn(segments + 1, '/');
+        let name: Vec<&str> = parts.by_ref().take(segments).collect();
+        (name.join("/"), parts.next()?.to_string())
+    } else {
+        let at = key[1.min(key.len())..].find('@')? + 1;
+        (key[..at].to_string(), key[at + 1..].to_string())
+    };
+    Some((name, strip_peers(&version).to_string()))
+}
+
+/// Dependency reference → package id. `link:` references are workspace
+/// siblings; `/other/1.0.0` and `other@1.0.0` are npm aliases.
+fn reference_id(dep: &str, reference: &Value, legacy: bool) -> Option<String> {
+    let reference = match reference {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    if reference.starts_with("link:") || reference.starts_with("file:") {
+        return None;
+    }
+    if reference.starts_with('/') {
+        return split_key(&reference, legacy).map(|(name, version)| format!("{name}@{version}"));
+    }
+    if !reference.starts_with(|c: char| c.is_ascii_digit()) && reference.contains('@') {
+        return split_key(&reference, false).map(|(name, version)| format!("{name}@{version}"));
+    }
+    Some(format!("{dep}@{}", strip_peers(&reference)))
+}
+
+fn strip_peers(version: &str) -> &str {
+    version.split(['(', '_']).next().unwrap_or(version)
+}
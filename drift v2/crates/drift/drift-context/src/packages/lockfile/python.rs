@@ -0,0 +1,125 @@
+//! Python: `poetry.lock`, `pyproject.toml` (Poetry and PEP 621) and
+//! `requirements.txt`. Names are PEP 503-normalized so lockfile entries
+//! and manifest declarations match.
+
+use toml::Value;
+
+use super::{DirectDependency, LockedPackage, Lockfile};
+
+pub fn parse_poetry_lock(content: &str) -> Result<Lockfile, String> {
+    let toml: Value = toml::from_str(content).map_err(|e| format!("poetry.lock: {e}"))?;
+    let mut lock = Lockfile::default();
+    for entry in toml.get("package").and_then(Value::as_array).into_iter().flatten() {
+        let name = normalize(entry.get("name").and_then(Value::as_str).unwrap_or_default());
+        let version = entry.get("version").and_then(Value::as_str).unwrap_or_default();
+        let mut package = LockedPackage::new(name.clone(), name.clone(), version);
+        // Poetry < 1.5 writes `category`; later versions write `groups`.
+        package.dev = match (entry.get("category").and_then(Value::as_str), entry.get("groups").and_then(Value::as_array)) {
+            (Some(category), _) => category == "dev",
+            (None, Some(groups)) => !groups.iter().any(|g| g.as_str() == Some("main")),
+            (None, None) => false,
+        };
+        package.checksum = entry
+            .get("files")
+            .and_then(Value::as_array)
+            .and_then(|files| files.first())
+            .and_then(|file| file.get("hash"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        package.source = entry.get("source").and_then(|s| s.get("url")).and_then(Value::as_str).map(str::to_string);
+        package.dependencies = entry
+            .get("dependencies")
+            .and_then(Value::as_table)
+            .into_iter()
+            .flat_map(|deps| deps.keys())
+            .map(|d| normalize(d))
+            .collect();
+        lock.packages.push(package);
+    }
+    // Edges to packages the lockfile doesn't pin (platform-only extras) are dropped.
+    let pinned: Vec<String> = lock.packages.iter().map(|p| p.id.clone()).collect();
+    for package in &mut lock.packages {
+        package.dependencies.retain(|d| pinned.contains(d));
+    }
+    Ok(lock)
+}
+
+pub fn parse_pyproject(content: &str) -> Result<Vec<DirectDependency>, String> {
+    let toml: Value = toml::from_str(content).map_err(|e| format!("pyproject.toml: {e}"))?;
+    let mut deps = Vec::new();
+
+    if let Some(poetry) = toml.get("tool").and_then(|t| t.get("poetry")) {
+        let mut tables = vec![(poetry.get("dependencies"), false), (poetry.get("dev-dependencies"), true)];
+        for (group, table) in poetry.get("group").and_then(Value::as_table).into_iter().flatten() {
+            tables.push((table.get("dependencies"), group != "main"));
+        }
+        for (table, dev) in tables {
+            for (name, spec) in table.and_then(Value::as_table).into_iter().flatten() {
+                if name == "python" || spec.get("path").is_some() {
+                    continue;
+                }
+                let requirement = match spec {
+                    Value::String(req) => Some(req.clone()),
+                    _ => spec.get("version").and_then(Value::as_str).map(str::to_string),
+                };
+                deps.push(DirectDependency::new(normalize(name), requirement, dev));
+            }
+        }
+    }
+
+    // PEP 621 `[project]` and PEP 735 `[dependency-groups]`.
+    let project = toml.get("project");
+    let mut lists: Vec<(&Value, bool)> = project.and_then(|p| p.get("dependencies")).map(|d| (d, false)).into_iter().collect();
+    lists.extend(project.and_then(|p| p.get("optional-dependencies")).and_then(Value::as_table).into_iter().flat_map(|t| t.values()).map(|d| (d, false)));
+    lists.extend(toml.get("dependency-groups").and_then(Value::as_table).into_iter().flat_map(|t| t.values()).map(|d| (d, true)));
+    for (list, dev) in lists {
+        for spec in list.as_array().into_iter().flatten().filter_map(Value::as_str) {
+            if let Some(mut dep) = requirement_line(spec) {
+                dep.dev = dev;
+                deps.push(dep);
+            }
+        }
+    }
+    Ok(deps)
+}
+
+pub fn parse_requirements(content: &str) -> Vec<DirectDependency> {
+    content
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or(line).trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('-'))
+        .filter_map(requirement_line)
+        .collect()
+}
+
+/// PEP 508 requirement: `requests[socks]>=2.0; python_version > "3.8"`.
+fn requirement_line(spec: &str) -> Option<DirectDependency> {
+    let spec = spec.split(';').next()?.trim();
+    let end = spec.find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))).unwrap_or(spec.len());
+    let name = &spec[..end];
+    if name.is_empty() || spec.contains("://") {
+        return None;
+    }
+    let rest = spec[end..].trim();
+    let rest = match rest.strip_prefix('[') {
+        Some(extras) => extras.split_once(']').map(|(_, r)| r.trim()).unwrap_or(""),
+        None => rest,
+    };
+    let requirement = rest.trim_start_matches('(').trim_end_matches(')').trim();
+    Some(DirectDependency::new(normalize(name), (!requirement.is_empty()).then(|| requirement.to_string()), false))
+}
+
+/// PEP 503: lowercase, runs of `-`, `_` and `.` collapse to `-`.
+pub fn normalize(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !out.ends_with('-') {
+                out.push('-');
+            }
+        } else {
+            out.push(c.to_ascii_lowercase());
+        }
+    }
+    out
+}
@@ -0,0 +1,105 @@
+//! Yarn: `yarn.lock`, both the classic v1 format and Berry (v2+) YAML.
+//!
+//! Entries are keyed by one or more descriptors (`lodash@^4.17.0`); every
+//! descriptor becomes an alias so manifest ranges and dependency ranges
+//! resolve to the single pinned version.
+
+use super::{LockedPackage, Lockfile};
+
+struct Entry {
+    descriptors: Vec<String>,
+    package: LockedPackage,
+    /// `(name, range)` pairs, resolved once all descriptors are known.
+    ranges: Vec<(String, String)>,
+}
+
+pub fn parse_yarn_lock(content: &str) -> Lockfile {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut section = "";
+    for line in content.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim();
+        if indent == 0 {
+            section = "";
+            let header = trimmed.trim_end_matches(':');
+            if header == "__metadata" {
+                entries.push(Entry { descriptors: Vec::new(), package: LockedPackage::new("", "", ""), ranges: Vec::new() });
+                continue;
+            }
+            let descriptors: Vec<String> = header.split(", ").map(|d| unquote(d).to_string()).collect();
+            let name = descriptors.first().map(|d| split_descriptor(d).0).unwrap_or_default().to_string();
+            entries.push(Entry { descriptors, package: LockedPackage::new("", name, ""), ranges: Vec::new() });
+            continue;
+        }
+        let Some(entry) = entries.last_mut() else { continue };
+        if indent <= 2 {
+            let (key, value) = field(trimmed);
+            section = "";
+            match key {
+                "version" => entry.package.version = value.to_string(),
+                "resolved" | "resolution" => entry.package.source = Some(value.to_string()),
+                "integrity" | "checksum" => entry.package.checksum = Some(value.to_string()),
+                "dependencies" | "optionalDependencies" if value.is_empty() => section = key,
+                _ => {}
+            }
+        } else if !section.is_empty() {
+            let (name, range) = field(trimmed);
+            entry.ranges.push((name.to_string(), range.to_string()));
+        }
+    }
+
+    let mut lock = Lockfile::default();
+    let local = |d: &str| ["workspace:", "patch:", "link:", "portal:"].iter().any(|p| split_descriptor(d).1.starts_with(p));
+    entries.retain(|e| !e.package.name.is_empty() && !e.descriptors.iter().any(|d| local(d)));
+    for entry in &mut entries {
+        entry.package.id = format!("{}@{}", entry.package.name, entry.package.version);
+        for descriptor in &entry.descriptors {
+            lock.aliases.insert(descriptor.clone(), entry.package.id.clone());
+            // Berry descriptors carry the protocol: `lodash@npm:^4.17.0`.
+            let (name, range) = split_descriptor(descriptor);
+            if let Some(range) = range.strip_prefix("npm:") {
+                lock.aliases.insert(format!("{name}@{range}"), entry.package.id.clone());
+            }
+        }
+    }
+    for mut entry in entries {
+        entry.package.dependencies = entry
+            .ranges
+            .iter()
+            .filter_map(|(name, range)| {
+                lock.aliases
+                    .get(&format!("{name}@{range}"))
+                    .or_else(|| lock.aliases.get(&format!("{name}@npm:{range}")))
+                    .cloned()
+            })
+            .collect();
+        if !lock.packages.iter().any(|p| p.id == entry.package.id) {
+            lock.packages.push(entry.package);
+        }
+    }
+    lock
+}
+
+/// `version "1.0.0"` (classic) or `version: 1.0.0` (Berry).
+fn field(line: &str) -> (&str, &str) {
+    let (key, value) = match line.split_once(": ") {
+        Some((k, v)) if !k.contains(' ') || k.starts_with('"') => (k, v),
+        _ => line.trim_end_matches(':').split_once(' ').unwrap_or((line.trim_end_matches(':'), "")),
+    };
+    (unquote(key.trim()), unquote(value.trim()))
+}
+
+fn unquote(s: &str) -> &str {
+    s.trim().trim_matches('"')
+}
+
+/// `@babel/core@^7.0.0` → (`@babel/core`, `^7.0.0`).
+fn split_descriptor(descriptor: &str) -> (&str, &str) {
+    match descriptor[1.min(descriptor.len())..].find('@') {
+        Some(pos) => (&descriptor[..pos + 1], &descriptor[pos + 2..]),
+        None => (descriptor, ""),
+    }
+}
@@ -52,6 +52,11 @@ impl PackageManager {
         }
     }
 
+    /// Inverse of [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|pm| pm.name() == name)
+    }
+
     /// Manifest file name for this package manager.
     pub fn manifest_file(&self) -> &'static str {
         match self {
@@ -89,6 +94,37 @@ impl PackageManager {
         }
     }
 
+    /// Package URL (purl) type for packages this manager installs.
+    pub fn purl_type(&self) -> &'static str {
+        match self {
+            Self::Npm | Self::Yarn | Self::Pnpm | Self::Bun => "npm",
+            Self::Pip | Self::Poetry | Self::Pipenv => "pypi",
+            Self::Cargo => "cargo",
+            Self::Go => "golang",
+            Self::Maven | Self::Gradle => "maven",
+            Self::NuGet => "nuget",
+            Self::Composer => "composer",
+            Self::Bundler => "gem",
+            Self::CocoaPods => "cocoapods",
+        }
+    }
+
+    /// OSV ecosystem name for packages this manager installs, if OSV
+    /// tracks that ecosystem.
+    pub fn osv_ecosystem(&self) -> Option<&'static str> {
+        match self {
+            Self::Npm | Self::Yarn | Self::Pnpm | Self::Bun => Some("npm"),
+            Self::Pip | Self::Poetry | Self::Pipenv => Some("PyPI"),
+            Self::Cargo => Some("crates.io"),
+            Self::Go => Some("Go"),
+            Self::Maven | Self::Gradle => Some("Maven"),
+            Self::NuGet => Some("NuGet"),
+            Self::Composer => Some("Packagist"),
+            Self::Bundler => Some("RubyGems"),
+            Self::CocoaPods => None,
+        }
+    }
+
     /// Detect package manager from a file name.
     pub fn detect_from_file(filename: &str) -> Option<Self> {
         match filename {
@@ -1,5 +1,11 @@
-//! Package manager support — 15 package managers.
+//! Package manager support — 15 package managers, lockfile parsing,
+//! per-package dependency inventory and SBOM export.
 
+pub mod inventory;
+pub mod lockfile;
 pub mod manager;
+pub mod sbom;
 
+pub use inventory::{build_inventory, DependencyGraph, ResolvedDependency};
 pub use manager::PackageManager;
+pub use sbom::SbomFormat;
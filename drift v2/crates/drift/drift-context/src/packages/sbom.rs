@@ -0,0 +1,284 @@
+//! SBOM export — CycloneDX 1.5 and SPDX 2.3 JSON.
+//!
+//! Workspace packages become application components and every resolved
+//! dependency a library component keyed by its purl; the dependency
+//! relationships mirror each [`DependencyGraph`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+
+use base64::Engine as _;
+use serde_json::{json, Value};
+
+use super::inventory::{DependencyGraph, ResolvedDependency};
+
+/// Supported SBOM formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+impl SbomFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "cyclonedx" | "cdx" => Some(Self::CycloneDx),
+            "spdx" => Some(Self::Spdx),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CycloneDx => "cyclonedx",
+            Self::Spdx => "spdx",
+        }
+    }
+}
+
+/// Export `graphs` as an SBOM document named `name`. `created` is an
+/// RFC 3339 timestamp.
+pub fn export(format: SbomFormat, name: &str, graphs: &[DependencyGraph], created: &str) -> Value {
+    match format {
+        SbomFormat::CycloneDx => cyclonedx(name, graphs, created),
+        SbomFormat::Spdx => spdx(name, graphs, created),
+    }
+}
+
+/// A dependency merged across every graph it appears in: dev only if it
+/// is dev everywhere.
+struct Component<'a> {
+    dependency: &'a ResolvedDependency,
+    dev: bool,
+}
+
+fn components(graphs: &[DependencyGraph]) -> BTreeMap<&str, Component<'_>> {
+    let mut merged: BTreeMap<&str, Component<'_>> = BTreeMap::new();
+    for dependency in graphs.iter().flat_map(|g| &g.dependencies) {
+        merged
+            .entry(dependency.purl.as_str())
+            .and_modify(|c| c.dev &= dependency.dev)
+            .or_insert(Component { dependency, dev: dependency.dev });
+    }
+    merged
+}
+
+fn workspace_ref(graph: &DependencyGraph) -> String {
+    format!("workspace:{}:{}", graph.manager.name(), graph.path)
+}
+
+pub fn cyclonedx(name: &str, graphs: &[DependencyGraph], created: &str) -> Value {
+    let root_ref = format!("workspace:{name}");
+    let mut components_json: Vec<Value> = graphs
+        .iter()
+        .map(|g| json!({ "type": "application", "bom-ref": workspace_ref(g), "name": g.package }))
+        .collect();
+    for (purl, component) in components(graphs) {
+        let dep = component.dependency;
+        let mut entry = json!({
+            "type": "library",
+            "bom-ref": purl,
+            "name": dep.name,
+            "purl": purl,
+            "scope": if component.dev { "optional" } else { "required" },
+        });
+        if dep.manager.purl_type() == "maven" {
+            if let Some((group, artifact)) = dep.name.split_once(':') {
+                entry["group"] = json!(group);
+                entry["name"] = json!(artifact);
+            }
+        }
+        if let Some(version) = &dep.version {
+            entry["version"] = json!(version);
+        }
+        if let Some((alg, hex)) = dep.checksum.as_deref().and_then(checksum) {
+            entry["hashes"] = json!([{ "alg": alg.cyclonedx(), "content": hex }]);
+        }
+        components_json.push(entry);
+    }
+
+    let mut dependencies = vec![json!({ "ref": root_ref, "dependsOn": graphs.iter().map(workspace_ref).collect::<Vec<_>>() })];
+    for graph in graphs {
+        let direct: BTreeSet<&str> = graph.direct().map(|d| d.purl.as_str()).collect();
+        dependencies.push(json!({ "ref": workspace_ref(graph), "dependsOn": direct }));
+    }
+    let mut edges: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for dep in graphs.iter().flat_map(|g| &g.dependencies) {
+        edges.entry(dep.purl.as_str()).or_default().extend(dep.depends_on.iter().map(String::as_str));
+    }
+    dependencies.extend(edges.into_iter().map(|(purl, on)| json!({ "ref": purl, "dependsOn": on })));
+
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "timestamp": created,
+            "tools": { "components": [{ "type": "application", "name": "drift" }] },
+            "component": { "type": "application", "bom-ref": root_ref, "name": name },
+        },
+        "components": components_json,
+        "dependencies": dependencies,
+    })
+}
+
+pub fn spdx(name: &str, graphs: &[DependencyGraph], created: &str) -> Value {
+    let mut packages: Vec<Value> = graphs
+        .iter()
+        .map(|g| {
+            json!({
+                "SPDXID": spdx_id(&workspace_ref(g)),
+                "name": g.package,
+                "downloadLocation": "NOASSERTION",
+                "filesAnalyzed": false,
+                "primaryPackagePurpose": "APPLICATION",
+            })
+        })
+        .collect();
+    for (purl, component) in components(graphs) {
+        let dep = component.dependency;
+        let mut entry = json!({
+            "SPDXID": spdx_id(purl),
+            "name": dep.name,
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+            "primaryPackagePurpose": "LIBRARY",
+            "externalRefs": [{ "referenceCategory": "PACKAGE-MANAGER", "referenceType": "purl", "referenceLocator": purl }],
+        });
+        if let Some(version) = &dep.version {
+            entry["versionInfo"] = json!(version);
+        }
+        if let Some((alg, hex)) = dep.checksum.as_deref().and_then(checksum) {
+            entry["checksums"] = json!([{ "algorithm": alg.spdx(), "checksumValue": hex }]);
+        }
+        packages.push(entry);
+    }
+
+    let mut relationships: Vec<Value> = Vec::new();
+    let mut relate = |from: &str, kind: &str, to: &str| {
+        relationships.push(json!({ "spdxElementId": from, "relationshipType": kind, "relatedSpdxElement": to }));
+    };
+    for graph in graphs {
+        let package_id = spdx_id(&workspace_ref(graph));
+        relate("SPDXRef-DOCUMENT", "DESCRIBES", &package_id);
+        for dep in graph.direct() {
+            if dep.dev {
+                relate(&spdx_id(&dep.purl), "DEV_DEPENDENCY_OF", &package_id);
+            } else {
+                relate(&package_id, "DEPENDS_ON", &spdx_id(&dep.purl));
+            }
+        }
+    }
+    let mut edges: BTreeSet<(&str, &str)> = BTreeSet::new();
+    for dep in graphs.iter().flat_map(|g| &g.dependencies) {
+        edges.extend(dep.depends_on.iter().map(|on| (dep.purl.as_str(), on.as_str())));
+    }
+    for (from, to) in edges {
+        relate(&spdx_id(from), "DEPENDS_ON", &spdx_id(to));
+    }
+
+    // Namespaces must be unique per document; derive one from the content.
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    created.hash(&mut hasher);
+    for graph in graphs {
+        graph.path.hash(&mut hasher);
+        graph.dependencies.iter().for_each(|d| d.purl.hash(&mut hasher));
+    }
+
+    json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": name,
+        "documentNamespace": format!("https://spdx.org/spdxdocs/{}-{:016x}", spdx_id(name).trim_start_matches("SPDXRef-"), hasher.finish()),
+        "creationInfo": { "created": created, "creators": ["Tool: drift"] },
+        "packages": packages,
+        "relationships": relationships,
+    })
+}
+
+/// SPDX identifiers allow letters, digits, `.` and `-` only.
+fn spdx_id(reference: &str) -> String {
+    let sanitized: String = reference.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '-' }).collect();
+    format!("SPDXRef-{sanitized}")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlg {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl HashAlg {
+    fn cyclonedx(self) -> &'static str {
+        match self {
+            Self::Sha1 => "SHA-1",
+            Self::Sha256 => "SHA-256",
+            Self::Sha512 => "SHA-512",
+        }
+    }
+
+    fn spdx(self) -> &'static str {
+        match self {
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+            Self::Sha512 => "SHA512",
+        }
+    }
+
+    fn from_hex_len(len: usize) -> Option<Self> {
+        match len {
+            40 => Some(Self::Sha1),
+            64 => Some(Self::Sha256),
+            128 => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// Lockfile checksum → (algorithm, lowercase hex). Handles SRI strings
+/// (`sha512-<base64>`), `sha256:<hex>` and bare hex digests; Go's `h1:`
+/// dirhashes have no SBOM equivalent.
+fn checksum(raw: &str) -> Option<(HashAlg, String)> {
+    let raw = raw.split_whitespace().next()?;
+    let is_hex = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit());
+    if let Some((alg, digest)) = raw.split_once('-') {
+        let alg = match alg {
+            "sha1" => HashAlg::Sha1,
+            "sha256" => HashAlg::Sha256,
+            "sha512" => HashAlg::Sha512,
+            _ => return None,
+        };
+        let bytes = base64::engine::general_purpose::STANDARD.decode(digest).ok()?;
+        return Some((alg, bytes.iter().map(|b| format!("{b:02x}")).collect()));
+    }
+    // `sha256:<hex>` (Poetry) or Yarn Berry's `10c0/<hex>` cache-keyed SHA-512.
+    let hex = raw.rsplit([':', '/']).next()?;
+    if raw.contains(':') && !raw.starts_with("sha") {
+        return None;
+    }
+    let alg = HashAlg::from_hex_len(hex.len()).filter(|_| is_hex(hex))?;
+    Some((alg, hex.to_ascii_lowercase()))
+}
+
+/// Current time as an RFC 3339 UTC timestamp (`2024-05-01T12:00:00Z`).
+pub fn timestamp_now() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    // Civil-from-days (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z", rem / 3600, rem % 3600 / 60, rem % 60)
+}
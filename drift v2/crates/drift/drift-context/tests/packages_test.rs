@@ -0,0 +1,276 @@
+//! Lockfile parsing, dependency inventory and SBOM export tests.
+
+use std::fs;
+
+use drift_context::packages::inventory::{build_inventory, purl};
+use drift_context::packages::lockfile::{self, DirectDependency};
+use drift_context::packages::sbom::{self, SbomFormat};
+use drift_context::packages::PackageManager;
+
+fn direct(deps: &[DirectDependency], name: &str) -> DirectDependency {
+    deps.iter().find(|d| d.name == name).unwrap_or_else(|| panic!("{name} not declared")).clone()
+}
+
+#[test]
+fn package_lock_v3_resolves_nested_node_modules() {
+    let lock = lockfile::parse_lockfile(
+        "package-lock.json",
+        r#"{
+          "lockfileVersion": 3,
+          "packages": {
+            "": { "dependencies": { "a": "^1.0.0" }, "devDependencies": { "b": "^2.0.0" } },
+            "node_modules/a": { "version": "1.0.0", "integrity": "sha512-AAAA", "dependencies": { "c": "^1.0.0" } },
+            "node_modules/a/node_modules/c": { "version": "1.5.0" },
+            "node_modules/b": { "version": "2.0.0", "dev": true, "dependencies": { "c": "^2.0.0" } },
+            "node_modules/c": { "version": "2.1.0", "dev": true }
+          }
+        }"#,
+    )
+    .unwrap();
+    let a = lock.get("node_modules/a").unwrap();
+    assert_eq!(a.dependencies, vec!["node_modules/a/node_modules/c".to_string()]);
+    assert_eq!(a.checksum.as_deref(), Some("sha512-AAAA"));
+    assert_eq!(lock.get("node_modules/b").unwrap().dependencies, vec!["node_modules/c".to_string()]);
+    let root = &lock.importers["."];
+    assert!(root.iter().any(|i| i.id == "node_modules/b" && i.dev));
+}
+
+#[test]
+fn yarn_classic_and_berry_aliases() {
+    let classic = lockfile::parse_lockfile(
+        "yarn.lock",
+        "# yarn lockfile v1\n\n\"lodash@^4.17.0\", lodash@^4.17.21:\n  version \"4.17.21\"\n  integrity sha512-xyz\n\nexpress@^4.18.0:\n  version \"4.18.2\"\n  dependencies:\n    lodash \"^4.17.0\"\n",
+    )
+    .unwrap();
+    let express = classic.resolve(&DirectDependency::new("express", Some("^4.18.0".into()), false)).unwrap();
+    assert_eq!(express.version, "4.18.2");
+    assert_eq!(express.dependencies, vec!["lodash@4.17.21".to_string()]);
+
+    let berry = lockfile::parse_lockfile(
+        "yarn.lock",
+        "__metadata:\n  version: 8\n\n\"ms@npm:^2.1.3\":\n  version: 2.1.3\n  resolution: \"ms@npm:2.1.3\"\n  checksum: 10c0/aa\n\n\"app@workspace:.\":\n  version: 0.0.0-use.local\n  resolution: \"app@workspace:.\"\n",
+    )
+    .unwrap();
+    assert_eq!(berry.packages.len(), 1);
+    assert_eq!(berry.resolve(&DirectDependency::new("ms", Some("^2.1.3".into()), false)).unwrap().version, "2.1.3");
+}
+
+#[test]
+fn pnpm_v6_and_v9_importers() {
+    let v6 = lockfile::parse_lockfile(
+        "pnpm-lock.yaml",
+        "lockfileVersion: '6.0'\nimporters:\n  .:\n    dependencies:\n      react:\n        specifier: ^18.2.0\n        version: 18.2.0\n  packages/ui:\n    devDependencies:\n      loose-envify:\n        specifier: ^1.4.0\n        version: 1.4.0\npackages:\n  /react@18.2.0:\n    resolution: {integrity: sha512-react}\n    dependencies:\n      loose-envify: 1.4.0\n  /loose-envify@1.4.0:\n    resolution: {integrity: sha512-le}\n    dev: true\n",
+    )
+    .unwrap();
+    assert_eq!(v6.get("react@18.2.0").unwrap().dependencies, vec!["loose-envify@1.4.0".to_string()]);
+    assert_eq!(v6.importers["packages/ui"][0].id, "loose-envify@1.4.0");
+    assert!(v6.importers["packages/ui"][0].dev);
+
+    let v9 = lockfile::parse_lockfile(
+        "pnpm-lock.yaml",
+        "lockfileVersion: '9.0'\nimporters:\n  .:\n    dependencies:\n      react-dom:\n        specifier: ^18.2.0\n        version: 18.2.0(react@18.2.0)\npackages:\n  react-dom@18.2.0:\n    resolution: {integrity: sha512-rd}\n    peerDependencies:\n      react: ^18.2.0\n  react@18.2.0:\n    resolution: {integrity: sha512-r}\nsnapshots:\n  react-dom@18.2.0(react@18.2.0):\n    dependencies:\n      react: 18.2.0\n  react@18.2.0: {}\n",
+    )
+    .unwrap();
+    assert_eq!(v9.importers["."][0].id, "react-dom@18.2.0");
+    assert_eq!(v9.get("react-dom@18.2.0").unwrap().dependencies, vec!["react@18.2.0".to_string()]);
+}
+
+#[test]
+fn cargo_poetry_go_bundler_composer_gradle() {
+    let cargo = lockfile::parse_lockfile(
+        "Cargo.lock",
+        "version = 3\n\n[[package]]\nname = \"app\"\nversion = \"0.1.0\"\ndependencies = [\"serde\"]\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.200\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\nchecksum = \"abc123\"\n",
+    )
+    .unwrap();
+    assert_eq!(cargo.packages.len(), 1);
+    assert_eq!(cargo.importers["app"][0].id, "serde 1.0.200");
+
+    let poetry = lockfile::parse_lockfile(
+        "poetry.lock",
+        "[[package]]\nname = \"Requests\"\nversion = \"2.31.0\"\noptional = false\npython-versions = \">=3.7\"\nfiles = [{file = \"requests.whl\", hash = \"sha256:58cd\"}]\n\n[package.dependencies]\nurllib3 = \">=1.21.1\"\n\n[[package]]\nname = \"urllib3\"\nversion = \"2.2.1\"\n",
+    )
+    .unwrap();
+    let requests = lockfile::python::normalize("Requests");
+    let requests = poetry.packages.iter().find(|p| p.name == requests).unwrap();
+    assert_eq!(requests.checksum.as_deref(), Some("sha256:58cd"));
+    assert_eq!(requests.dependencies.len(), 1);
+
+    let go_sum = lockfile::parse_lockfile(
+        "go.sum",
+        "github.com/pkg/errors v0.9.1 h1:FEBL=\ngithub.com/pkg/errors v0.9.1/go.mod h1:bwaw=\n",
+    )
+    .unwrap();
+    assert_eq!(go_sum.packages.len(), 1);
+    let go_mod = lockfile::parse_manifest("go.mod", "module example.com/app\n\nrequire (\n\tgithub.com/pkg/errors v0.9.1\n\tgolang.org/x/sys v0.1.0 // indirect\n)\n").unwrap();
+    assert!(!direct(&go_mod, "github.com/pkg/errors").indirect);
+    assert!(direct(&go_mod, "golang.org/x/sys").indirect);
+
+    let gems = lockfile::parse_lockfile(
+        "Gemfile.lock",
+        "GEM\n  remote: https://rubygems.org/\n  specs:\n    nokogiri (1.16.0-x86_64-linux)\n      racc (~> 1.4)\n    racc (1.7.3)\n\nPLATFORMS\n  x86_64-linux\n\nDEPENDENCIES\n  nokogiri\n",
+    )
+    .unwrap();
+    let nokogiri = gems.packages.iter().find(|p| p.name == "nokogiri").unwrap();
+    assert_eq!(nokogiri.version, "1.16.0");
+    assert_eq!(nokogiri.dependencies.len(), 1);
+    assert_eq!(gems.importers["."].len(), 1);
+
+    let composer = lockfile::parse_lockfile(
+        "composer.lock",
+        r#"{ "packages": [ { "name": "monolog/monolog", "version": "3.5.0", "require": { "php": ">=8.1", "psr/log": "^3.0" } }, { "name": "psr/log", "version": "3.0.0" } ], "packages-dev": [ { "name": "phpunit/phpunit", "version": "10.5.0" } ] }"#,
+    )
+    .unwrap();
+    assert_eq!(composer.packages.iter().find(|p| p.name == "monolog/monolog").unwrap().dependencies.len(), 1);
+    assert!(composer.packages.iter().find(|p| p.name == "phpunit/phpunit").unwrap().dev);
+
+    let gradle = lockfile::parse_lockfile(
+        "gradle.lockfile",
+        "# comment\ncom.google.guava:guava:33.0.0-jre=compileClasspath,runtimeClasspath\njunit:junit:4.13.2=testCompileClasspath,testRuntimeClasspath\nempty=annotationProcessor\n",
+    )
+    .unwrap();
+    assert!(gradle.flat);
+    assert_eq!(gradle.packages.len(), 2);
+    assert!(gradle.packages.iter().find(|p| p.name == "junit:junit").unwrap().dev);
+}
+
+#[test]
+fn manifests_declare_direct_dependencies() {
+    let pom = lockfile::parse_manifest(
+        "pom.xml",
+        "<project><properties><jackson.version>2.16.1</jackson.version></properties><dependencies><dependency><groupId>com.fasterxml.jackson.core</groupId><artifactId>jackson-databind</artifactId><version>${jackson.version}</version></dependency><dependency><groupId>junit</groupId><artifactId>junit</artifactId><version>4.13.2</version><scope>test</scope></dependency></dependencies></project>",
+    )
+    .unwrap();
+    assert_eq!(direct(&pom, "com.fasterxml.jackson.core:jackson-databind").requirement.as_deref(), Some("2.16.1"));
+    assert!(direct(&pom, "junit:junit").dev);
+
+    let gradle = lockfile::parse_manifest(
+        "build.gradle.kts",
+        "dependencies {\n    implementation(\"org.slf4j:slf4j-api:2.0.9\")\n    testImplementation(\"org.junit.jupiter:junit-jupiter:5.10.0\")\n    implementation(libs.guava)\n}\n",
+    )
+    .unwrap();
+    assert_eq!(gradle.len(), 2);
+    assert!(direct(&gradle, "org.junit.jupiter:junit-jupiter").dev);
+
+    let pyproject = lockfile::parse_manifest(
+        "pyproject.toml",
+        "[project]\nname = \"svc\"\ndependencies = [\"Flask>=3.0\", \"pydantic==2.6.0\"]\n\n[project.optional-dependencies]\ntest = [\"pytest\"]\n",
+    )
+    .unwrap();
+    assert_eq!(direct(&pyproject, "flask").requirement.as_deref(), Some(">=3.0"));
+
+    let gemfile = lockfile::parse_manifest("Gemfile", "source 'https://rubygems.org'\ngem 'rails', '~> 7.1'\ngroup :development, :test do\n  gem 'rspec-rails'\nend\n").unwrap();
+    assert!(!direct(&gemfile, "rails").dev);
+    assert!(direct(&gemfile, "rspec-rails").dev);
+
+    let cargo = lockfile::parse_manifest(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\nlocal = { path = \"../local\" }\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+    )
+    .unwrap();
+    assert_eq!(cargo.len(), 2);
+    assert!(direct(&cargo, "tempfile").dev);
+}
+
+#[test]
+fn purls_follow_the_spec() {
+    assert_eq!(purl(PackageManager::Yarn, "@babel/core", Some("7.24.0")), "pkg:npm/%40babel/core@7.24.0");
+    assert_eq!(purl(PackageManager::Gradle, "junit:junit", Some("4.13.2")), "pkg:maven/junit/junit@4.13.2");
+    assert_eq!(purl(PackageManager::Go, "github.com/pkg/errors", Some("v0.9.1")), "pkg:golang/github.com/pkg/errors@v0.9.1");
+    assert_eq!(purl(PackageManager::Pip, "flask", None), "pkg:pypi/flask");
+}
+
+fn npm_monorepo() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("package.json"), r#"{ "name": "mono", "private": true, "workspaces": ["packages/*"], "devDependencies": { "typescript": "^5.3.0" } }"#).unwrap();
+    fs::create_dir_all(root.join("packages/api")).unwrap();
+    fs::create_dir_all(root.join("packages/web")).unwrap();
+    fs::write(root.join("packages/api/package.json"), r#"{ "name": "@mono/api", "dependencies": { "express": "^4.18.0", "@mono/web": "workspace:*" } }"#).unwrap();
+    fs::write(root.join("packages/web/package.json"), r#"{ "name": "@mono/web", "dependencies": { "react": "^18.2.0" } }"#).unwrap();
+    fs::write(
+        root.join("package-lock.json"),
+        r#"{
+          "lockfileVersion": 3,
+          "packages": {
+            "": { "workspaces": ["packages/*"], "devDependencies": { "typescript": "^5.3.0" } },
+            "packages/api": { "name": "@mono/api", "dependencies": { "express": "^4.18.0" } },
+            "packages/web": { "name": "@mono/web", "dependencies": { "react": "^18.2.0" } },
+            "node_modules/@mono/api": { "resolved": "packages/api", "link": true },
+            "node_modules/@mono/web": { "resolved": "packages/web", "link": true },
+            "node_modules/express": { "version": "4.18.2", "integrity": "sha512-ZXhwcmVzcw==", "dependencies": { "debug": "2.6.9" } },
+            "node_modules/debug": { "version": "2.6.9" },
+            "node_modules/react": { "version": "18.2.0", "dependencies": { "loose-envify": "^1.1.0" } },
+            "node_modules/loose-envify": { "version": "1.4.0" },
+            "node_modules/typescript": { "version": "5.3.3", "dev": true }
+          }
+        }"#,
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn inventory_resolves_each_workspace_package() {
+    let dir = npm_monorepo();
+    let graphs = build_inventory(dir.path()).unwrap();
+    assert_eq!(graphs.len(), 3);
+
+    let api = graphs.iter().find(|g| g.path == "packages/api").unwrap();
+    assert_eq!(api.package, "@mono/api");
+    assert_eq!(api.lockfile.as_deref(), Some("package-lock.json"));
+    let names: Vec<&str> = api.dependencies.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(names, vec!["debug", "express"]);
+    let express = api.dependencies.iter().find(|d| d.name == "express").unwrap();
+    assert!(express.direct);
+    assert_eq!(express.depends_on, vec!["pkg:npm/debug@2.6.9".to_string()]);
+    assert!(!api.dependencies.iter().find(|d| d.name == "debug").unwrap().direct);
+
+    let root = graphs.iter().find(|g| g.path == ".").unwrap();
+    assert_eq!(root.dependencies.len(), 1);
+    assert!(root.dependencies[0].dev);
+}
+
+#[test]
+fn inventory_without_lockfile_keeps_exact_pins() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("requirements.txt"), "flask==3.0.2\nrequests>=2.0\n# comment\n").unwrap();
+    let graphs = build_inventory(dir.path()).unwrap();
+    assert_eq!(graphs.len(), 1);
+    let graph = &graphs[0];
+    assert_eq!(graph.manager, PackageManager::Pip);
+    assert!(graph.lockfile.is_none());
+    let flask = graph.dependencies.iter().find(|d| d.name == "flask").unwrap();
+    assert_eq!(flask.version.as_deref(), Some("3.0.2"));
+    assert!(graph.dependencies.iter().find(|d| d.name == "requests").unwrap().version.is_none());
+}
+
+#[test]
+fn sbom_exports_cyclonedx_and_spdx() {
+    let dir = npm_monorepo();
+    let graphs = build_inventory(dir.path()).unwrap();
+    let created = "2024-05-01T12:00:00Z";
+
+    let cdx = sbom::export(SbomFormat::parse("CycloneDX").unwrap(), "mono", &graphs, created);
+    assert_eq!(cdx["bomFormat"], "CycloneDX");
+    let components = cdx["components"].as_array().unwrap();
+    let express = components.iter().find(|c| c["name"] == "express").unwrap();
+    assert_eq!(express["purl"], "pkg:npm/express@4.18.2");
+    assert_eq!(express["scope"], "required");
+    assert_eq!(express["hashes"][0]["alg"], "SHA-512");
+    assert_eq!(express["hashes"][0]["content"], "65787072657373");
+    assert_eq!(components.iter().find(|c| c["name"] == "typescript").unwrap()["scope"], "optional");
+    // Three workspace applications plus five libraries.
+    assert_eq!(components.len(), 8);
+    let edges = cdx["dependencies"].as_array().unwrap();
+    assert!(edges.iter().any(|e| e["ref"] == "pkg:npm/express@4.18.2" && e["dependsOn"][0] == "pkg:npm/debug@2.6.9"));
+
+    let spdx = sbom::export(SbomFormat::Spdx, "mono", &graphs, created);
+    assert_eq!(spdx["spdxVersion"], "SPDX-2.3");
+    assert_eq!(spdx["packages"].as_array().unwrap().len(), 8);
+    let relationships = spdx["relationships"].as_array().unwrap();
+    assert_eq!(relationships.iter().filter(|r| r["relationshipType"] == "DESCRIBES").count(), 3);
+    assert!(relationships.iter().any(|r| r["relationshipType"] == "DEV_DEPENDENCY_OF"));
+    assert!(spdx["packages"].as_array().unwrap().iter().all(|p| {
+        p["SPDXID"].as_str().unwrap().chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+    }));
+}
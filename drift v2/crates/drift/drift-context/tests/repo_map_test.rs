@@ -0,0 +1,185 @@
+//! Repository map tests — PageRank ranking, focus and intent bias, token budgets, formats.
+
+use drift_context::formats::markdown::MarkdownFormatter;
+use drift_context::formats::xml::XmlFormatter;
+use drift_context::generation::builder::{ContextDepth, ContextEngine};
+use drift_context::generation::deduplication::ContextSession;
+use drift_context::generation::intent::ContextIntent;
+use drift_context::generation::repo_map::{rank, RepoGraph, RepoMapFocus, RepoSymbol};
+
+fn symbol(name: &str, line: u32) -> RepoSymbol {
+    RepoSymbol { name: name.to_string(), signature: format!("{name}(input: string): Result"), line }
+}
+
+/// Five feature files that all call into `src/util.ts`, plus a leaf file.
+fn hub_graph() -> RepoGraph {
+    let mut graph = RepoGraph::new();
+    graph.add_symbol("src/util.ts", symbol("formatDate", 3));
+    graph.add_symbol("src/util.ts", symbol("unusedHelper", 20));
+    for feature in ["orders", "billing", "users", "reports", "search"] {
+        let file = format!("src/{feature}.ts");
+        graph.add_symbol(&file, symbol(&format!("{feature}Handler"), 1));
+        graph.add_call(&file, &format!("{feature}Handler"), "src/util.ts", "formatDate");
+        graph.add_import(&file, "src/util.ts");
+    }
+    graph.add_file("README.md");
+    graph
+}
+
+fn large_graph(files: usize, symbols_per_file: usize) -> RepoGraph {
+    let mut graph = RepoGraph::new();
+    for f in 0..files {
+        let file = format!("src/module_{f}/service.ts");
+        for s in 0..symbols_per_file {
+            graph.add_symbol(&file, RepoSymbol {
+                name: format!("operation{s}"),
+                signature: format!("async operation{s}(request: Request{f}, options: Options{s}): Promise<Response{f}>"),
+                line: (s * 10 + 1) as u32,
+            });
+        }
+        if f > 0 {
+            graph.add_call(&file, "operation0", "src/module_0/service.ts", "operation0");
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_central_file_ranks_first() {
+    let ranked = rank(&hub_graph(), &RepoMapFocus::default(), None);
+    assert_eq!(ranked[0].path, "src/util.ts");
+    assert_eq!(ranked[0].symbols[0].symbol.name, "formatDate");
+    assert!(ranked[0].symbols[0].score > ranked[0].symbols[1].score);
+    let total: f64 = ranked.iter().map(|f| f.score).sum();
+    assert!((total - 1.0).abs() < 1e-3, "ranks form a distribution, got {total}");
+}
+
+#[test]
+fn test_focus_biases_ranking() {
+    let graph = hub_graph();
+    let neutral = rank(&graph, &RepoMapFocus::default(), None);
+    let position = |ranked: &[drift_context::generation::repo_map::RankedFile], path: &str| {
+        ranked.iter().position(|f| f.path == path).unwrap()
+    };
+
+    // Feature files tie without focus and fall back to path order.
+    assert_eq!(position(&neutral, "src/users.ts"), 5);
+
+    let focused = rank(&graph, &RepoMapFocus { files: vec!["users.ts".to_string()], identifiers: vec![] }, None);
+    // Right behind the hub, which the focused file feeds.
+    assert_eq!(position(&focused, "src/users.ts"), 1);
+
+    let by_identifier = rank(&graph, &RepoMapFocus { files: vec![], identifiers: vec!["usersHandler".to_string()] }, None);
+    assert!(position(&by_identifier, "src/users.ts") < position(&neutral, "src/users.ts"));
+}
+
+#[test]
+fn test_focus_from_text() {
+    let focus = RepoMapFocus::from_text("Fix the crash in src/billing.ts when `formatDate` gets an empty string, see util.ts.");
+    assert_eq!(focus.files, vec!["src/billing.ts", "util.ts"]);
+    assert!(focus.identifiers.contains(&"formatDate".to_string()));
+    assert!(!focus.identifiers.contains(&"in".to_string()));
+}
+
+#[test]
+fn test_repo_map_fits_budget() {
+    let graph = large_graph(200, 8);
+    let mut engine = ContextEngine::new();
+
+    let overview = engine
+        .generate_repo_map(ContextIntent::UnderstandCode, ContextDepth::Overview, &graph, &RepoMapFocus::default())
+        .unwrap();
+    let deep = engine
+        .generate_repo_map(ContextIntent::UnderstandCode, ContextDepth::Deep, &graph, &RepoMapFocus::default())
+        .unwrap();
+
+    assert!(overview.token_count <= 2048, "overview map has {} tokens", overview.token_count);
+    assert!(deep.token_count <= 12288, "deep map has {} tokens", deep.token_count);
+    assert!(deep.token_count > overview.token_count);
+
+    let lines = |sections: &[(String, String)]| sections.iter().map(|(_, c)| c.lines().count()).sum::<usize>();
+    assert!(lines(&overview.sections) < graph.symbol_count());
+    assert!(lines(&deep.sections) > lines(&overview.sections));
+    assert_eq!(overview.sections[0].0, "src/module_0/service.ts", "hub file comes first");
+    assert!(overview.sections[0].1.starts_with("1: async operation0("), "outline lists signatures by line");
+}
+
+#[test]
+fn test_session_mentions_bias_map() {
+    let graph = large_graph(50, 4);
+    let mut session = ContextSession::new("repo-map");
+    session.mention_file("src/module_42/service.ts");
+    assert_eq!(session.mentioned_files(), ["src/module_42/service.ts"]);
+
+    let mut engine = ContextEngine::new().with_session(session);
+    let output = engine
+        .generate_repo_map(ContextIntent::FixBug, ContextDepth::Overview, &graph, &RepoMapFocus::default())
+        .unwrap();
+    let names: Vec<&str> = output.sections.iter().map(|(n, _)| n.as_str()).collect();
+    assert!(names[..2].contains(&"src/module_42/service.ts"), "mentioned file near the top: {names:?}");
+}
+
+#[test]
+fn test_intent_changes_ranking() {
+    // Four unconnected files that only differ in what they are about.
+    let mut graph = RepoGraph::new();
+    graph.add_symbol("src/auth/session.ts", symbol("verifyToken", 1));
+    graph.add_symbol("src/jobs/retry.ts", symbol("retryOnError", 1));
+    graph.add_symbol("src/routes/orders.ts", symbol("ordersRoute", 1));
+    graph.add_symbol("src/main.ts", symbol("bootstrap", 1));
+    let top = |intent| rank(&graph, &RepoMapFocus::default(), Some(intent))[0].path.clone();
+
+    assert_eq!(rank(&graph, &RepoMapFocus::default(), None)[0].path, "src/auth/session.ts", "path order without an intent");
+    assert_eq!(top(ContextIntent::SecurityAudit), "src/auth/session.ts");
+    assert_eq!(top(ContextIntent::FixBug), "src/jobs/retry.ts");
+    assert_eq!(top(ContextIntent::AddFeature), "src/routes/orders.ts");
+    assert_eq!(top(ContextIntent::UnderstandCode), "src/main.ts");
+
+    let mut engine = ContextEngine::new();
+    let first_section = |engine: &mut ContextEngine, intent| {
+        engine.generate_repo_map(intent, ContextDepth::Overview, &graph, &RepoMapFocus::default()).unwrap().sections[0].0.clone()
+    };
+    assert_eq!(first_section(&mut engine, ContextIntent::FixBug), "src/jobs/retry.ts");
+    assert_eq!(first_section(&mut engine, ContextIntent::UnderstandCode), "src/main.ts");
+
+    // What the agent named still outranks what the intent suggests.
+    let focus = RepoMapFocus { files: vec!["main.ts".to_string()], identifiers: vec![] };
+    assert_eq!(rank(&graph, &focus, Some(ContextIntent::SecurityAudit))[0].path, "src/main.ts");
+}
+
+#[test]
+fn test_focus_files_are_remembered_by_the_session() {
+    let graph = large_graph(50, 4);
+    let focus = RepoMapFocus { files: vec!["src/module_42/service.ts".to_string()], identifiers: vec![] };
+    let mut engine = ContextEngine::new().with_session(ContextSession::new("repo-map"));
+    engine.generate_repo_map(ContextIntent::FixBug, ContextDepth::Overview, &graph, &focus).unwrap();
+    let session = engine.into_session().unwrap();
+    assert_eq!(session.mentioned_files(), ["src/module_42/service.ts"]);
+
+    // A follow-up without focus keeps the bias.
+    let mut engine = ContextEngine::new().with_session(session);
+    let output = engine
+        .generate_repo_map(ContextIntent::FixBug, ContextDepth::Overview, &graph, &RepoMapFocus::default())
+        .unwrap();
+    let names: Vec<&str> = output.sections.iter().map(|(n, _)| n.as_str()).collect();
+    assert!(names[..2].contains(&"src/module_42/service.ts"), "remembered file near the top: {names:?}");
+}
+
+#[test]
+fn test_repo_map_formats() {
+    let mut engine = ContextEngine::new();
+    let output = engine
+        .generate_repo_map(ContextIntent::AddFeature, ContextDepth::Standard, &hub_graph(), &RepoMapFocus::default())
+        .unwrap();
+
+    let md = MarkdownFormatter::new().format(&output);
+    assert!(md.contains("## src/util.ts"));
+    assert!(md.contains("3: formatDate(input: string): Result"));
+    let xml = XmlFormatter::new().format(&output);
+    assert!(xml.contains("<section name=\"src/util.ts\">"));
+
+    let empty = engine
+        .generate_repo_map(ContextIntent::AddFeature, ContextDepth::Standard, &RepoGraph::new(), &RepoMapFocus::default())
+        .unwrap();
+    assert_eq!(empty.sections[0].0, "overview");
+}
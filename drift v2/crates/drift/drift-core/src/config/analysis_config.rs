@@ -25,6 +25,12 @@ pub struct AnalysisConfig {
     pub gast_languages: Vec<String>,
     /// Enable incremental analysis. Default: true.
     pub incremental: Option<bool>,
+    /// Local OSV database dump for vulnerable-dependency matching.
+    /// Default: `.drift/osv` under the project root.
+    pub osv_database: Option<String>,
+    /// Commits walked for churn, coupling and ownership analysis.
+    /// Default: 1000. 0 disables history analytics.
+    pub history_max_commits: Option<u32>,
 }
 
 impl AnalysisConfig {
@@ -42,4 +48,18 @@ impl AnalysisConfig {
     pub fn effective_min_files(&self) -> u32 {
         self.min_files.unwrap_or(2)
     }
+
+    /// Returns the effective history depth, defaulting to 1000 commits.
+    pub fn effective_history_max_commits(&self) -> u32 {
+        self.history_max_commits.unwrap_or(1000)
+    }
+
+    /// Returns the OSV database location, resolved against `root` when
+    /// relative and defaulting to `<root>/.drift/osv`.
+    pub fn effective_osv_database(&self, root: &std::path::Path) -> std::path::PathBuf {
+        match &self.osv_database {
+            Some(path) => root.join(path),
+            None => root.join(".drift").join("osv"),
+        }
+    }
 }
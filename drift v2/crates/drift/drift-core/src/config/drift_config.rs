@@ -119,6 +119,22 @@ impl DriftConfig {
                 });
             }
         }
+        if let Some(max) = config.quality_gates.max_duplication {
+            if !(0.0..=100.0).contains(&max) {
+                return Err(ConfigError::ValidationFailed {
+                    field: "quality_gates.max_duplication".to_string(),
+                    message: "must be between 0 and 100".to_string(),
+                });
+            }
+        }
+        for (i, o) in config.quality_gates.policy_overrides.iter().enumerate() {
+            if o.path.is_some() == o.package.is_some() {
+                return Err(ConfigError::ValidationFailed {
+                    field: format!("quality_gates.policy_overrides[{i}]"),
+                    message: "must set exactly one of path or package".to_string(),
+                });
+            }
+        }
         if let Some(ref max_file_size) = config.scan.max_file_size {
             if *max_file_size == 0 {
                 return Err(ConfigError::ValidationFailed {
@@ -218,6 +234,12 @@ impl DriftConfig {
         if other.analysis.incremental.is_some() {
             base.analysis.incremental = other.analysis.incremental;
         }
+        if other.analysis.osv_database.is_some() {
+            base.analysis.osv_database = other.analysis.osv_database.clone();
+        }
+        if other.analysis.history_max_commits.is_some() {
+            base.analysis.history_max_commits = other.analysis.history_max_commits;
+        }
 
         // Quality gates
         if other.quality_gates.fail_on.is_some() {
@@ -241,6 +263,16 @@ impl DriftConfig {
         if other.quality_gates.ramp_up_period.is_some() {
             base.quality_gates.ramp_up_period = other.quality_gates.ramp_up_period;
         }
+        if other.quality_gates.max_duplication.is_some() {
+            base.quality_gates.max_duplication = other.quality_gates.max_duplication;
+        }
+        if other.quality_gates.policy.is_some() {
+            base.quality_gates.policy = other.quality_gates.policy.clone();
+        }
+        if !other.quality_gates.policy_overrides.is_empty() {
+            base.quality_gates.policy_overrides =
+                other.quality_gates.policy_overrides.clone();
+        }
 
         // MCP
         if other.mcp.cache_ttl_seconds.is_some() {
@@ -1,5 +1,7 @@
 //! Quality gate configuration.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Configuration for the quality gates subsystem.
@@ -20,6 +22,32 @@ pub struct GateConfig {
     pub progressive_enforcement: Option<bool>,
     /// Ramp-up period in days for progressive enforcement.
     pub ramp_up_period: Option<u32>,
+    /// Maximum duplicated share of any module, in percent. Default: 10.
+    pub max_duplication: Option<f64>,
+    /// Root policy preset: "strict" | "standard" | "lenient". Default: "standard".
+    pub policy: Option<String>,
+    /// Policy overrides for paths and packages, inheriting from the root.
+    #[serde(default)]
+    pub policy_overrides: Vec<PolicyOverrideConfig>,
+}
+
+/// A `[[quality_gates.policy_overrides]]` entry. Exactly one of `path`
+/// and `package` selects the scope; unset fields inherit.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct PolicyOverrideConfig {
+    /// Glob relative to the project root, e.g. "tools/**".
+    pub path: Option<String>,
+    /// Monorepo package name.
+    pub package: Option<String>,
+    /// Policy preset for the scope.
+    pub policy: Option<String>,
+    /// Score threshold for the scope (0-100).
+    pub min_score: Option<f64>,
+    pub required_gates: Option<Vec<String>>,
+    pub enabled_gates: Option<Vec<String>>,
+    /// Minimum score per gate id.
+    pub gate_thresholds: HashMap<String, f64>,
 }
 
 impl GateConfig {
@@ -32,4 +60,9 @@ impl GateConfig {
     pub fn effective_min_score(&self) -> u32 {
         self.min_score.unwrap_or(70)
     }
+
+    /// Returns the effective duplication threshold, defaulting to 10%.
+    pub fn effective_max_duplication(&self) -> f64 {
+        self.max_duplication.unwrap_or(10.0)
+    }
 }
@@ -25,6 +25,9 @@ pub enum StorageError {
 
     #[error("Operation not supported: {operation} — {reason}")]
     NotSupported { operation: String, reason: String },
+
+    #[error("Encryption error: {reason}")]
+    EncryptionError { reason: String },
 }
 
 impl DriftErrorCode for StorageError {
@@ -26,6 +26,8 @@ pub struct ViolationRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// CODEOWNERS owners of the file.
+    pub owners: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -122,6 +124,7 @@ pub trait IDriftEnforcement: Send + Sync {
     fn insert_violation(&self, v: &ViolationRow) -> Result<(), StorageError>;
     fn query_violations_by_file(&self, file: &str) -> Result<Vec<ViolationRow>, StorageError>;
     fn query_all_violations(&self) -> Result<Vec<ViolationRow>, StorageError>;
+    fn query_violations_by_owner(&self, owner: &str) -> Result<Vec<ViolationRow>, StorageError>;
 
     // ── gate_results ──
 
@@ -166,6 +169,7 @@ impl<T: IDriftEnforcement + ?Sized> IDriftEnforcement for Arc<T> {
     fn insert_violation(&self, v: &ViolationRow) -> Result<(), StorageError> { (**self).insert_violation(v) }
     fn query_violations_by_file(&self, f: &str) -> Result<Vec<ViolationRow>, StorageError> { (**self).query_violations_by_file(f) }
     fn query_all_violations(&self) -> Result<Vec<ViolationRow>, StorageError> { (**self).query_all_violations() }
+    fn query_violations_by_owner(&self, o: &str) -> Result<Vec<ViolationRow>, StorageError> { (**self).query_violations_by_owner(o) }
     fn insert_gate_result(&self, g: &GateResultRow) -> Result<(), StorageError> { (**self).insert_gate_result(g) }
     fn query_gate_results(&self) -> Result<Vec<GateResultRow>, StorageError> { (**self).query_gate_results() }
     fn insert_audit_snapshot(&self, s: &AuditSnapshotRow) -> Result<(), StorageError> { (**self).insert_audit_snapshot(s) }
@@ -8,6 +8,7 @@ use rusqlite::backup::Backup;
 use rusqlite::{Connection, OpenFlags};
 use tracing::{info, warn};
 
+use super::encryption::{open_keyed, DatabaseKey};
 use super::errors::{WorkspaceError, WorkspaceResult};
 
 /// Backup reasons — all 6 v1 reasons preserved + ci_export added.
@@ -123,11 +124,19 @@ fn reason_to_tier(reason: BackupReason) -> BackupTier {
 }
 
 /// Manages hot backups using the SQLite Backup API.
+///
+/// An encrypted drift.db is opened with the key given to [`Self::with_key`],
+/// or else the one from `DRIFT_DB_KEY` / `DRIFT_DB_KEYFILE`; its backups stay
+/// encrypted under the same key. cortex.db likewise uses the key given to
+/// [`Self::with_cortex_key`], or else the one from `CORTEX_DB_KEY` /
+/// `CORTEX_DB_KEYFILE` (see [`super::encryption`] for the key sources).
 pub struct BackupManager {
     drift_db_path: PathBuf,
     cortex_db_path: PathBuf,
     backup_dir: PathBuf,
     config: BackupConfig,
+    key: Option<DatabaseKey>,
+    cortex_key: Option<DatabaseKey>,
 }
 
 impl BackupManager {
@@ -140,9 +149,23 @@ impl BackupManager {
                 .unwrap_or(drift_path)
                 .join(".drift-backups"),
             config,
+            key: None,
+            cortex_key: None,
         }
     }
 
+    /// Use this key for drift.db instead of resolving it from the environment.
+    pub fn with_key(mut self, key: DatabaseKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Use this key for cortex.db instead of resolving it from the environment.
+    pub fn with_cortex_key(mut self, key: DatabaseKey) -> Self {
+        self.cortex_key = Some(key);
+        self
+    }
+
     /// Create a hot backup using SQLite Backup API.
     /// Safe for WAL-mode databases. Non-blocking for readers.
     pub fn create_backup(
@@ -157,22 +180,29 @@ impl BackupManager {
 
         // Backup drift.db via SQLite Backup API
         let drift_backup_path = backup_path.join("drift.db");
-        self.backup_database(&self.drift_db_path, &drift_backup_path)?;
+        let drift_key = self.drift_key()?;
+        self.backup_database(&self.drift_db_path, &drift_backup_path, drift_key.as_ref())?;
         let drift_db_size = std::fs::metadata(&drift_backup_path)?.len();
 
         // Backup cortex.db if it exists (per D6: independent databases)
         let cortex_db_size = if self.cortex_db_path.exists() {
             let cortex_backup_path = backup_path.join("cortex.db");
-            self.backup_database(&self.cortex_db_path, &cortex_backup_path)?;
+            let cortex_key = self.cortex_key()?;
+            self.backup_database(
+                &self.cortex_db_path,
+                &cortex_backup_path,
+                cortex_key.as_ref(),
+            )?;
             Some(std::fs::metadata(&cortex_backup_path)?.len())
         } else {
             None
         };
 
         // Get schema version from drift.db
-        let conn = Connection::open_with_flags(
+        let conn = open_keyed(
             &self.drift_db_path,
             OpenFlags::SQLITE_OPEN_READ_ONLY,
+            drift_key.as_ref(),
         )?;
         let schema_version: u32 = conn
             .pragma_query_value(None, "user_version", |row| row.get(0))
@@ -192,10 +222,10 @@ impl BackupManager {
         };
 
         // Register in drift.db backup_registry table
-        self.register_backup(&manifest)?;
+        self.register_backup(&manifest, drift_key.as_ref())?;
 
         // Enforce tiered retention policy
-        if let Err(e) = self.enforce_retention() {
+        if let Err(e) = self.enforce_retention(drift_key.as_ref()) {
             warn!(error = %e, "Failed to enforce backup retention policy");
         }
 
@@ -210,10 +240,16 @@ impl BackupManager {
     }
 
     /// Core backup operation using SQLite Backup API.
-    /// 1000 pages per step, 10ms sleep between steps.
-    fn backup_database(&self, source: &Path, dest: &Path) -> WorkspaceResult<()> {
-        let src_conn = Connection::open_with_flags(source, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
-        let mut dst_conn = Connection::open(dest)?;
+    /// 1000 pages per step, 10ms sleep between steps. Both sides get the same
+    /// key: SQLCipher only copies pages between identically keyed databases.
+    fn backup_database(
+        &self,
+        source: &Path,
+        dest: &Path,
+        key: Option<&DatabaseKey>,
+    ) -> WorkspaceResult<()> {
+        let src_conn = open_keyed(source, OpenFlags::SQLITE_OPEN_READ_ONLY, key)?;
+        let mut dst_conn = open_keyed(dest, OpenFlags::default(), key)?;
 
         {
             let backup = Backup::new(&src_conn, &mut dst_conn)?;
@@ -244,9 +280,10 @@ impl BackupManager {
             return Err(WorkspaceError::BackupNotFound(backup_id.to_string()));
         }
 
+        let drift_key = self.drift_key()?;
         let backup_drift = backup_path.join("drift.db");
         let backup_conn =
-            Connection::open_with_flags(&backup_drift, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+            open_keyed(&backup_drift, OpenFlags::SQLITE_OPEN_READ_ONLY, drift_key.as_ref())?;
         let result: String = backup_conn
             .pragma_query_value(None, "integrity_check", |row| row.get(0))
             .unwrap_or_else(|_| "error".to_string());
@@ -262,12 +299,13 @@ impl BackupManager {
         let _ = self.create_backup(BackupReason::PreDestructiveOperation, drift_version);
 
         // Restore drift.db
-        self.backup_database(&backup_drift, &self.drift_db_path)?;
+        self.backup_database(&backup_drift, &self.drift_db_path, drift_key.as_ref())?;
 
         // Restore cortex.db if present
         let backup_cortex = backup_path.join("cortex.db");
         if backup_cortex.exists() {
-            self.backup_database(&backup_cortex, &self.cortex_db_path)?;
+            let cortex_key = self.cortex_key()?;
+            self.backup_database(&backup_cortex, &self.cortex_db_path, cortex_key.as_ref())?;
         }
 
         info!(backup_id = backup_id, "Restored from backup");
@@ -325,8 +363,8 @@ impl BackupManager {
     }
 
     /// Enforce tiered retention policy.
-    fn enforce_retention(&self) -> WorkspaceResult<()> {
-        let conn = Connection::open(&self.drift_db_path)?;
+    fn enforce_retention(&self, key: Option<&DatabaseKey>) -> WorkspaceResult<()> {
+        let conn = open_keyed(&self.drift_db_path, OpenFlags::default(), key)?;
 
         for (tier, max) in [
             ("operational", self.config.max_operational),
@@ -356,8 +394,12 @@ impl BackupManager {
         Ok(())
     }
 
-    fn register_backup(&self, manifest: &BackupManifest) -> WorkspaceResult<()> {
-        let conn = Connection::open(&self.drift_db_path)?;
+    fn register_backup(
+        &self,
+        manifest: &BackupManifest,
+        key: Option<&DatabaseKey>,
+    ) -> WorkspaceResult<()> {
+        let conn = open_keyed(&self.drift_db_path, OpenFlags::default(), key)?;
         conn.execute(
             "INSERT INTO backup_registry
              (id, reason, created_at, drift_db_size, cortex_db_size,
@@ -379,6 +421,20 @@ impl BackupManager {
         Ok(())
     }
 
+    fn drift_key(&self) -> WorkspaceResult<Option<DatabaseKey>> {
+        match &self.key {
+            Some(key) => Ok(Some(key.clone())),
+            None => Ok(DatabaseKey::from_env()?),
+        }
+    }
+
+    fn cortex_key(&self) -> WorkspaceResult<Option<DatabaseKey>> {
+        match &self.cortex_key {
+            Some(key) => Ok(Some(key.clone())),
+            None => Ok(DatabaseKey::cortex_from_env()?),
+        }
+    }
+
     /// Get the backup directory path.
     pub fn backup_dir(&self) -> &Path {
         &self.backup_dir
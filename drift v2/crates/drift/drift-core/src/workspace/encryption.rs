@@ -0,0 +1,338 @@
+//! Page-level encryption at rest via SQLCipher, for drift.db and cortex.db.
+//!
+//! Enabled by the `encryption` cargo feature. Because encryption happens
+//! below the pager, migrations, FTS, VACUUM and backups need no changes once
+//! every connection has been keyed — which is why every open of an encrypted
+//! database goes through [`open_keyed`] or [`apply_key`]. This is the one
+//! implementation: drift-storage re-exports it and cortex-storage adapts it
+//! to its own error type.
+//!
+//! # Key sources
+//!
+//! Each database reads its key from a keyfile variable or a key variable.
+//! The keyfile wins; with neither set the database is plaintext.
+//!
+//! | database    | keyfile             | key             |
+//! |-------------|---------------------|-----------------|
+//! | `drift.db`  | `DRIFT_DB_KEYFILE`  | `DRIFT_DB_KEY`  |
+//! | `cortex.db` | `CORTEX_DB_KEYFILE` | `CORTEX_DB_KEY` |
+//!
+//! Key material — the variable's value, or a keyfile's first line — is a
+//! raw 256-bit key when it is 64 hex chars (optionally `x'…'`-wrapped), and
+//! a passphrase otherwise. Cortex's `[storage]` config can instead name a
+//! keyfile or another variable; with `encryption_enabled` set a missing key
+//! is an error rather than a plaintext database.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::errors::StorageError;
+
+/// Env var holding drift.db's key material.
+pub const KEY_ENV: &str = "DRIFT_DB_KEY";
+/// Env var holding a path to drift.db's keyfile. Wins over `DRIFT_DB_KEY`.
+pub const KEYFILE_ENV: &str = "DRIFT_DB_KEYFILE";
+/// Env var holding cortex.db's key material.
+pub const CORTEX_KEY_ENV: &str = "CORTEX_DB_KEY";
+/// Env var holding a path to cortex.db's keyfile. Wins over `CORTEX_DB_KEY`.
+pub const CORTEX_KEYFILE_ENV: &str = "CORTEX_DB_KEYFILE";
+
+/// Length of a raw SQLCipher key in bytes (256-bit).
+pub const RAW_KEY_LEN: usize = 32;
+
+/// SQLite file header for unencrypted databases.
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Key material for an encrypted database.
+///
+/// A raw key is 32 bytes and bypasses SQLCipher's PBKDF2 derivation; a
+/// passphrase is run through PBKDF2 on every open. Redacted in `Debug`,
+/// scrubbed on drop.
+#[derive(Clone, PartialEq, Eq)]
+pub enum DatabaseKey {
+    Raw(Vec<u8>),
+    Passphrase(String),
+}
+
+impl DatabaseKey {
+    /// Parse key material: 64 hex chars (optionally `x'…'`-wrapped) is a raw
+    /// key, anything else a passphrase.
+    pub fn parse(material: &str) -> Result<Self, StorageError> {
+        let trimmed = material.trim();
+        if trimmed.is_empty() {
+            return Err(encryption_err("database key is empty"));
+        }
+        let hex = trimmed
+            .strip_prefix("x'")
+            .and_then(|s| s.strip_suffix('\''))
+            .unwrap_or(trimmed);
+        if hex.len() == RAW_KEY_LEN * 2 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+                .collect();
+            return Ok(Self::Raw(bytes));
+        }
+        Ok(Self::Passphrase(trimmed.to_string()))
+    }
+
+    /// Build a raw key from bytes. Must be exactly 32 bytes.
+    pub fn from_raw(bytes: &[u8]) -> Result<Self, StorageError> {
+        if bytes.len() != RAW_KEY_LEN {
+            return Err(encryption_err(&format!(
+                "raw key must be {RAW_KEY_LEN} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        Ok(Self::Raw(bytes.to_vec()))
+    }
+
+    /// drift.db's key from `DRIFT_DB_KEYFILE` / `DRIFT_DB_KEY`.
+    /// Returns `Ok(None)` when neither is set (encryption disabled).
+    pub fn from_env() -> Result<Option<Self>, StorageError> {
+        KeySource::from_env(KEYFILE_ENV, KEY_ENV)
+            .map(|s| s.load())
+            .transpose()
+    }
+
+    /// cortex.db's key from `CORTEX_DB_KEYFILE` / `CORTEX_DB_KEY`.
+    /// Returns `Ok(None)` when neither is set (encryption disabled).
+    pub fn cortex_from_env() -> Result<Option<Self>, StorageError> {
+        KeySource::from_env(CORTEX_KEYFILE_ENV, CORTEX_KEY_ENV)
+            .map(|s| s.load())
+            .transpose()
+    }
+
+    fn sql_literal(&self) -> String {
+        match self {
+            Self::Raw(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+                format!("\"x'{hex}'\"")
+            }
+            Self::Passphrase(p) => format!("'{}'", p.replace('\'', "''")),
+        }
+    }
+}
+
+impl fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DatabaseKey(<redacted>)")
+    }
+}
+
+impl Drop for DatabaseKey {
+    fn drop(&mut self) {
+        let bytes = match self {
+            Self::Raw(bytes) => std::mem::take(bytes),
+            Self::Passphrase(p) => std::mem::take(p).into_bytes(),
+        };
+        let mut bytes = bytes;
+        bytes.iter_mut().for_each(|b| *b = 0);
+        std::hint::black_box(&bytes);
+    }
+}
+
+/// Where a database key is loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySource {
+    /// Key material in an environment variable.
+    Env(String),
+    /// Key material on the first line of a file.
+    KeyFile(PathBuf),
+}
+
+impl KeySource {
+    /// The keyfile named by `keyfile_var` if set, else `key_var` if set,
+    /// else `None`.
+    pub fn from_env(keyfile_var: &str, key_var: &str) -> Option<Self> {
+        if let Some(path) = std::env::var_os(keyfile_var) {
+            return Some(Self::KeyFile(PathBuf::from(path)));
+        }
+        std::env::var_os(key_var).map(|_| Self::Env(key_var.to_string()))
+    }
+
+    /// Load the key material.
+    pub fn load(&self) -> Result<DatabaseKey, StorageError> {
+        match self {
+            Self::Env(var) => {
+                let value = std::env::var(var).map_err(|_| {
+                    encryption_err(&format!("environment variable {var} is not set"))
+                })?;
+                DatabaseKey::parse(&value)
+            }
+            Self::KeyFile(path) => {
+                let contents = std::fs::read_to_string(path).map_err(|e| {
+                    encryption_err(&format!("read keyfile {}: {e}", path.display()))
+                })?;
+                DatabaseKey::parse(contents.lines().next().unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// Whether the linked SQLite is SQLCipher. Stock SQLite ignores `PRAGMA key`.
+pub fn cipher_available(conn: &Connection) -> bool {
+    conn.query_row("PRAGMA cipher_version", [], |row| row.get::<_, String>(0))
+        .map(|v| !v.is_empty())
+        .unwrap_or(false)
+}
+
+/// Key a freshly opened connection. Must run before pragmas or migrations.
+pub fn apply_key(conn: &Connection, key: &DatabaseKey) -> Result<(), StorageError> {
+    if !cipher_available(conn) {
+        return Err(encryption_err(
+            "SQLCipher is not linked; build with the `encryption` feature",
+        ));
+    }
+    conn.execute_batch(&format!("PRAGMA key = {};", key.sql_literal()))
+        .map_err(|e| encryption_err(&format!("apply key: {e}")))?;
+    conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+        row.get::<_, i64>(0)
+    })
+    .map_err(|_| encryption_err("wrong key or database is not encrypted"))?;
+    Ok(())
+}
+
+/// Open a connection and key it when `key` is set. A new file opened with a
+/// key is created encrypted.
+pub fn open_keyed(
+    path: &Path,
+    flags: OpenFlags,
+    key: Option<&DatabaseKey>,
+) -> Result<Connection, StorageError> {
+    let conn = Connection::open_with_flags(path, flags).map_err(|e| StorageError::SqliteError {
+        message: e.to_string(),
+    })?;
+    if let Some(key) = key {
+        apply_key(&conn, key)?;
+    }
+    Ok(conn)
+}
+
+/// Whether the file at `path` is an unencrypted SQLite database. Encrypted
+/// databases have a random salt where the plaintext header would be.
+/// Missing or empty files return `false`.
+pub fn is_plaintext_database(path: &Path) -> Result<bool, StorageError> {
+    use std::io::Read;
+
+    let mut file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(encryption_err(&format!("open {}: {e}", path.display()))),
+    };
+    let mut header = [0u8; 16];
+    match file.read_exact(&mut header) {
+        Ok(()) => Ok(&header == SQLITE_HEADER),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(encryption_err(&format!("read {}: {e}", path.display()))),
+    }
+}
+
+/// Re-encrypt a closed database under a new key (or encrypt a plaintext one
+/// when `old_key` is `None`).
+pub fn rotate_key(
+    db_path: &Path,
+    old_key: Option<&DatabaseKey>,
+    new_key: &DatabaseKey,
+) -> Result<(), StorageError> {
+    reencrypt(db_path, old_key, Some(new_key))
+}
+
+/// Encrypt a closed plaintext database in place.
+pub fn encrypt_database(db_path: &Path, key: &DatabaseKey) -> Result<(), StorageError> {
+    reencrypt(db_path, None, Some(key))
+}
+
+/// Decrypt a closed database in place, e.g. before handing it to tooling
+/// that only speaks stock SQLite.
+pub fn decrypt_database(db_path: &Path, key: &DatabaseKey) -> Result<(), StorageError> {
+    reencrypt(db_path, Some(key), None)
+}
+
+/// Copy every table, index, trigger and FTS shadow table from `from`'s
+/// encryption to `to`'s (`None` is plaintext) with `sqlcipher_export` into a
+/// sibling file, then swap it over the original. The database must not be
+/// open elsewhere.
+fn reencrypt(
+    db_path: &Path,
+    from: Option<&DatabaseKey>,
+    to: Option<&DatabaseKey>,
+) -> Result<(), StorageError> {
+    let tmp_path = sibling_path(db_path, "rekey");
+    remove_if_exists(&tmp_path)?;
+
+    {
+        let conn = Connection::open(db_path).map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+        match from {
+            Some(key) => apply_key(&conn, key)?,
+            None if !cipher_available(&conn) => {
+                return Err(encryption_err(
+                    "SQLCipher is not linked; build with the `encryption` feature",
+                ))
+            }
+            None => {}
+        }
+        // Fold the WAL into the main file so the export sees every commit.
+        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+            .map_err(|e| encryption_err(&format!("checkpoint: {e}")))?;
+        let target_key = to
+            .map(DatabaseKey::sql_literal)
+            .unwrap_or_else(|| "''".to_string());
+        let tmp_literal = tmp_path.to_string_lossy().replace('\'', "''");
+        conn.execute_batch(&format!(
+            "ATTACH DATABASE '{tmp_literal}' AS rekeyed KEY {target_key};"
+        ))
+        .map_err(|e| encryption_err(&format!("attach target: {e}")))?;
+        let exported = conn
+            .query_row("SELECT sqlcipher_export('rekeyed')", [], |_| Ok(()))
+            .and_then(|_| copy_user_version(&conn))
+            .map_err(|e| encryption_err(&format!("export: {e}")));
+        let _ = conn.execute_batch("DETACH DATABASE rekeyed;");
+        if let Err(e) = exported {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+    }
+
+    // The WAL and shared-memory files belong to the old key; a leftover one
+    // would be replayed against the re-encrypted file.
+    remove_if_exists(&sibling_path(db_path, "wal"))?;
+    remove_if_exists(&sibling_path(db_path, "shm"))?;
+    std::fs::rename(&tmp_path, db_path)
+        .map_err(|e| encryption_err(&format!("swap re-encrypted file: {e}")))?;
+
+    tracing::info!(path = %db_path.display(), "database re-encrypted");
+    Ok(())
+}
+
+/// `sqlcipher_export` copies schema and data but not the header's
+/// `user_version`, which the migration runners depend on.
+fn copy_user_version(conn: &Connection) -> rusqlite::Result<()> {
+    let version: i64 = conn.query_row("PRAGMA main.user_version", [], |row| row.get(0))?;
+    conn.execute_batch(&format!("PRAGMA rekeyed.user_version = {version};"))
+}
+
+/// `drift.db` → `drift.db-<suffix>`, matching SQLite's own `-wal`/`-shm` naming.
+fn sibling_path(db_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = db_path.as_os_str().to_owned();
+    name.push(format!("-{suffix}"));
+    PathBuf::from(name)
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), StorageError> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(encryption_err(&format!("remove {}: {e}", path.display()))),
+    }
+}
+
+fn encryption_err(reason: &str) -> StorageError {
+    StorageError::EncryptionError {
+        reason: reason.to_string(),
+    }
+}
@@ -57,9 +57,15 @@ pub enum WorkspaceError {
     ConfigError(String),
 
     // Storage
+    #[cfg(feature = "workspace")]
     #[error("Storage error: {0}")]
     Storage(#[from] rusqlite::Error),
 
+    /// Keyed open of drift.db failed (wrong key, SQLCipher not linked).
+    #[cfg(feature = "workspace")]
+    #[error("{0}")]
+    Database(#[from] crate::errors::StorageError),
+
     // IO
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -86,7 +92,10 @@ impl WorkspaceError {
             Self::ExportCorrupted(_) => "EXPORT_CORRUPTED",
             Self::ImportCorrupted(_) => "IMPORT_CORRUPTED",
             Self::ConfigError(_) => "CONFIG_ERROR",
+            #[cfg(feature = "workspace")]
             Self::Storage(_) => "STORAGE_ERROR",
+            #[cfg(feature = "workspace")]
+            Self::Database(_) => "STORAGE_ERROR",
             Self::Io(_) => "IO_ERROR",
             Self::TomlParse(_) => "CONFIG_PARSE_ERROR",
         }
@@ -8,6 +8,7 @@
 //! - **init** — Workspace initialization (`.drift/`, `drift.db`, `drift.toml`)
 //! - **migration** — Schema migration via `PRAGMA user_version`
 //! - **backup** — Hot backup via SQLite Backup API with tiered retention
+//! - **encryption** — SQLCipher keys, keyed opens and key rotation for `drift.db`
 //! - **lock** — Workspace locking via `fd-lock` for concurrent access safety
 //! - **project** — Multi-project switching with health indicators
 //! - **monorepo** — Monorepo workspace detection and per-package partitioning
@@ -19,38 +20,66 @@
 //! - **destructive** — Destructive operation safety (auto-backup + confirmation)
 //! - **ci** — CI environment detection
 //! - **export** — Workspace export/import for portability
+//!
+//! Everything backed by SQLite or `fd-lock` is behind the default `workspace`
+//! feature; `ci`, `detect` and `monorepo` detection are always available.
 
+#[cfg(feature = "workspace")]
 pub mod backup;
 pub mod ci;
+#[cfg(feature = "workspace")]
 pub mod context;
+#[cfg(feature = "workspace")]
 pub mod destructive;
 pub mod detect;
 pub mod errors;
+#[cfg(feature = "workspace")]
+pub mod encryption;
+#[cfg(feature = "workspace")]
 pub mod export;
+#[cfg(feature = "workspace")]
 pub mod gc;
+#[cfg(feature = "workspace")]
 pub mod init;
+#[cfg(feature = "workspace")]
 pub mod integrity;
+#[cfg(feature = "workspace")]
 pub mod lock;
+#[cfg(feature = "workspace")]
 pub mod migration;
 pub mod monorepo;
+#[cfg(feature = "workspace")]
 pub mod project;
+#[cfg(feature = "workspace")]
 pub mod sqlite_storage;
+#[cfg(feature = "workspace")]
 pub mod status;
 
 // Re-export the most commonly used types.
+pub use ci::{detect_ci_environment, is_ci, CIEnvironment};
+pub use errors::{WorkspaceError, WorkspaceResult};
+pub use monorepo::{detect_workspace, WorkspaceLayout};
+
+#[cfg(feature = "workspace")]
 pub use sqlite_storage::SqliteWorkspaceStorage;
+#[cfg(feature = "workspace")]
 pub use backup::{BackupConfig, BackupManager, BackupManifest, BackupReason, BackupTier};
-pub use ci::{detect_ci_environment, is_ci, CIEnvironment};
+#[cfg(feature = "workspace")]
 pub use context::{get_agent_context, get_workspace_context, refresh_workspace_context};
-pub use errors::{WorkspaceError, WorkspaceResult};
+#[cfg(feature = "workspace")]
 pub use gc::{garbage_collect, GCOptions, GCReport};
+#[cfg(feature = "workspace")]
 pub use init::{is_initialized, open_workspace, workspace_init, InitOptions, WorkspaceInfo};
+#[cfg(feature = "workspace")]
 pub use integrity::{auto_recover, verify_workspace, IntegrityReport};
+#[cfg(feature = "workspace")]
 pub use lock::WorkspaceLock;
+#[cfg(feature = "workspace")]
 pub use migration::{get_schema_version, initialize_workspace_db};
-pub use monorepo::{detect_workspace, WorkspaceLayout};
+#[cfg(feature = "workspace")]
 pub use project::{
     format_project_header, format_project_indicator, get_active_project, list_projects,
     resolve_project, switch_project, HealthStatus, ProjectInfo,
 };
+#[cfg(feature = "workspace")]
 pub use status::{workspace_status, DiskUsage, WorkspaceStatus};
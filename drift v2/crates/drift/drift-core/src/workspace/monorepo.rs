@@ -3,6 +3,7 @@
 
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "workspace")]
 use rusqlite::Connection;
 
 use super::errors::WorkspaceResult;
@@ -63,6 +64,7 @@ pub fn detect_workspace(root: &Path) -> WorkspaceResult<WorkspaceLayout> {
 }
 
 /// Register detected packages in drift.db.
+#[cfg(feature = "workspace")]
 pub fn register_packages(conn: &Connection, packages: &[PackageInfo]) -> WorkspaceResult<()> {
     conn.execute("DELETE FROM workspace_packages", [])?;
 
@@ -86,6 +88,7 @@ pub fn register_packages(conn: &Connection, packages: &[PackageInfo]) -> Workspa
 }
 
 /// Get registered packages from drift.db.
+#[cfg(feature = "workspace")]
 pub fn list_packages(conn: &Connection) -> WorkspaceResult<Vec<PackageInfo>> {
     let mut stmt = conn.prepare_cached(
         "SELECT name, path, language, framework, dependencies FROM workspace_packages",
@@ -362,6 +365,7 @@ fn package_from_dir(dir: &Path, root: &Path) -> PackageInfo {
     }
 }
 
+#[cfg(feature = "workspace")]
 fn generate_package_id(name: &str) -> String {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
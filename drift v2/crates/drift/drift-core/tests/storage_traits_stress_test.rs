@@ -646,6 +646,7 @@ fn row_types_with_unicode() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
     };
     assert_eq!(violation.id, "违规_1");
     assert_eq!(violation.rule_id, "rule_🔥");
@@ -939,6 +940,7 @@ fn all_enforcement_row_types_constructible() {
         quick_fix_description: Some("Use X instead".into()),
         cwe_id: Some(79), owasp_category: Some("A03".into()),
         suppressed: false, is_new: true,
+        owners: Vec::new(),
     };
     let _g = GateResultRow {
         gate_id: "g1".into(), status: "passed".into(), passed: true, score: 0.95,
@@ -439,6 +439,98 @@ fn t10_ws_04e_backup_restore() {
     assert_eq!(val, "before_backup");
 }
 
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn t10_ws_04f_keyed_backup_refuses_without_sqlcipher() {
+    let tmp = tempfile::tempdir().unwrap();
+    workspace::workspace_init(workspace::InitOptions {
+        root: Some(tmp.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let key = workspace::encryption::DatabaseKey::parse("backup-key").unwrap();
+    let mgr = workspace::BackupManager::new(&tmp.path().join(".drift"), Default::default())
+        .with_key(key);
+    let err = mgr
+        .create_backup(workspace::BackupReason::UserRequested, "0.1.0")
+        .unwrap_err();
+    assert!(err.to_string().contains("SQLCipher"), "{err}");
+}
+
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn t10_ws_04g_cortex_key_applies_to_cortex_backup() {
+    let tmp = tempfile::tempdir().unwrap();
+    workspace::workspace_init(workspace::InitOptions {
+        root: Some(tmp.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    let drift_path = tmp.path().join(".drift");
+    rusqlite::Connection::open(drift_path.join("cortex.db"))
+        .unwrap()
+        .execute_batch("CREATE TABLE memories (id TEXT PRIMARY KEY);")
+        .unwrap();
+
+    // drift.db is plaintext, so only the cortex.db copy needs SQLCipher.
+    let key = workspace::encryption::DatabaseKey::parse("cortex-key").unwrap();
+    let mgr = workspace::BackupManager::new(&drift_path, Default::default()).with_cortex_key(key);
+    let err = mgr
+        .create_backup(workspace::BackupReason::UserRequested, "0.1.0")
+        .unwrap_err();
+    assert!(err.to_string().contains("SQLCipher"), "{err}");
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn t10_ws_04f_encrypted_backup_round_trip() {
+    use workspace::encryption::{open_keyed, rotate_key, DatabaseKey};
+
+    let tmp = tempfile::tempdir().unwrap();
+    workspace::workspace_init(workspace::InitOptions {
+        root: Some(tmp.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    let drift_path = tmp.path().join(".drift");
+    let db_path = drift_path.join("drift.db");
+    let key = DatabaseKey::parse("backup-key").unwrap();
+    rotate_key(&db_path, None, &key).unwrap();
+
+    let set_value = |value: &str| {
+        let conn = open_keyed(&db_path, Default::default(), Some(&key)).unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO workspace_config (key, value) VALUES ('test_key', ?1)",
+            [value],
+        )
+        .unwrap();
+    };
+    set_value("before_backup");
+
+    let mgr = workspace::BackupManager::new(&drift_path, workspace::BackupConfig::default())
+        .with_key(key.clone());
+    let manifest = mgr
+        .create_backup(workspace::BackupReason::UserRequested, "0.1.0")
+        .unwrap();
+    // The backup is as encrypted as the source: no plaintext SQLite header.
+    let header = fs::read(manifest.backup_path.join("drift.db")).unwrap();
+    assert!(!header.starts_with(b"SQLite format 3"));
+
+    set_value("after_backup");
+    mgr.restore(&manifest.id, "0.1.0").unwrap();
+
+    let conn = open_keyed(&db_path, Default::default(), Some(&key)).unwrap();
+    let val: String = conn
+        .query_row(
+            "SELECT value FROM workspace_config WHERE key = 'test_key'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(val, "before_backup");
+}
+
 // ============================================================
 // T10-WS-05: Workspace lock
 // ============================================================
@@ -0,0 +1,203 @@
+//! Cortex bridge state and the bridge tool calls.
+//!
+//! Opened the way the N-API runtime opens it: bridge.db next to drift.db,
+//! a causal engine, and a read-only drift.db connection for grounding
+//! evidence, keyed like the session's when drift.db is encrypted. Failing
+//! to open bridge.db is not fatal; the bridge tools then fail with
+//! `[BRIDGE_ERROR]` while `drift_health` reports what is missing. Failing to
+//! open drift.db fails grounding checks rather than grounding on no evidence.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use cortex_causal::CausalEngine;
+use cortex_drift_bridge::errors::BridgeError;
+use cortex_drift_bridge::grounding::evidence::context_from_tags;
+use cortex_drift_bridge::grounding::loop_runner::MemoryForGrounding;
+use cortex_drift_bridge::storage::engine::BridgeStorageEngine;
+use cortex_drift_bridge::tools;
+use cortex_drift_bridge::traits::{BridgeMemoryRow, IBridgeStorage};
+use cortex_drift_bridge::BridgeConfig;
+use cortex_core::MemoryType;
+use drift_rpc::protocol::RpcError;
+use drift_storage::connection::encryption::{open_keyed, DatabaseKey};
+use rusqlite::{Connection, OpenFlags};
+use serde_json::Value;
+
+use crate::types::{GroundingCheckParams, MemoryIdParams, MemoryLearnParams, WhyParams};
+
+/// Error code of bridge failures, as the N-API bindings report them.
+pub const BRIDGE_ERROR: &str = "BRIDGE_ERROR";
+
+/// Confidence assumed for a memory grounded by id and type alone.
+const DEFAULT_CONFIDENCE: f64 = 0.5;
+
+pub struct Bridge {
+    pub store: Option<BridgeStorageEngine>,
+    pub config: BridgeConfig,
+    pub causal: Option<CausalEngine>,
+    drift_db: Option<Mutex<Connection>>,
+    /// Why drift.db could not be opened for grounding evidence.
+    drift_db_error: Option<String>,
+}
+
+impl Bridge {
+    pub fn open(bridge_db: &Path, drift_db: &Path) -> Self {
+        let store = match BridgeStorageEngine::open(bridge_db) {
+            Ok(store) => Some(store),
+            Err(e) => {
+                tracing::warn!(error = %e, path = %bridge_db.display(), "drift-mcp: bridge unavailable");
+                None
+            }
+        };
+        let (drift_db, drift_db_error) = match store.as_ref().map(|_| open_drift_db(drift_db)) {
+            Some(Ok(conn)) => (Some(Mutex::new(conn)), None),
+            Some(Err(e)) => {
+                tracing::warn!(error = %e, "drift-mcp: no read-only drift.db for grounding evidence");
+                (None, Some(e.to_string()))
+            }
+            None => (None, None),
+        };
+        Self {
+            causal: store.as_ref().map(|_| CausalEngine::new()),
+            store,
+            config: BridgeConfig::default(),
+            drift_db,
+            drift_db_error,
+        }
+    }
+
+    pub fn why(&self, params: WhyParams) -> Result<Value, RpcError> {
+        self.store()?
+            .with_reader(|conn| {
+                tools::handle_drift_why(&params.entity_type, &params.entity_id, Some(conn), self.causal.as_ref())
+            })
+            .map_err(bridge_error)
+    }
+
+    pub fn memory_learn(&self, params: MemoryLearnParams) -> Result<Value, RpcError> {
+        self.store()?
+            .with_writer(|conn| {
+                tools::handle_drift_memory_learn(
+                    &params.entity_type,
+                    &params.entity_id,
+                    &params.correction,
+                    &params.category,
+                    Some(conn),
+                )
+            })
+            .map_err(bridge_error)
+    }
+
+    /// Ground a stored memory with the evidence its tags point at, or an
+    /// unstored one from its id and `memory_type`.
+    pub fn grounding_check(&self, params: GroundingCheckParams) -> Result<Value, RpcError> {
+        let store = self.store()?;
+        let memory = match store.get_memory(&params.memory_id).map_err(bridge_error)? {
+            Some(row) => stored_memory(&row),
+            None => {
+                let memory_type = params.memory_type.as_deref().ok_or_else(|| {
+                    RpcError::invalid_params(format!(
+                        "memory '{}' is not in bridge.db; pass memory_type to ground it anyway",
+                        params.memory_id
+                    ))
+                })?;
+                let memory_type = parse_memory_type(memory_type)
+                    .ok_or_else(|| RpcError::invalid_params(format!("unknown memory type '{memory_type}'")))?;
+                unstored_memory(params.memory_id, memory_type)
+            }
+        };
+        let drift_db = match (&self.drift_db, &self.drift_db_error) {
+            (Some(conn), _) => Some(
+                conn.lock()
+                    .map_err(|_| RpcError::drift(BRIDGE_ERROR, "drift.db connection is poisoned"))?,
+            ),
+            (None, Some(e)) => {
+                return Err(RpcError::drift(BRIDGE_ERROR, format!("drift.db not available for grounding evidence: {e}")))
+            }
+            (None, None) => None,
+        };
+        tools::handle_drift_grounding_check(&memory, &self.config.grounding, drift_db.as_deref(), Some(store))
+            .map_err(bridge_error)
+    }
+
+    pub fn counterfactual(&self, params: MemoryIdParams) -> Result<Value, RpcError> {
+        tools::handle_drift_counterfactual(&params.memory_id, self.causal.as_ref()).map_err(bridge_error)
+    }
+
+    pub fn intervention(&self, params: MemoryIdParams) -> Result<Value, RpcError> {
+        tools::handle_drift_intervention(&params.memory_id, self.causal.as_ref()).map_err(bridge_error)
+    }
+
+    pub fn health(&self) -> Result<Value, RpcError> {
+        tools::handle_drift_health(
+            self.store.as_ref().map(|s| s as &dyn IBridgeStorage),
+            self.drift_db.as_ref(),
+            self.causal.as_ref(),
+        )
+        .map_err(bridge_error)
+    }
+
+    fn store(&self) -> Result<&BridgeStorageEngine, RpcError> {
+        self.store
+            .as_ref()
+            .ok_or_else(|| RpcError::drift(BRIDGE_ERROR, "bridge.db not available"))
+    }
+}
+
+/// Read-only drift.db, keyed from `DRIFT_DB_KEYFILE` / `DRIFT_DB_KEY` like
+/// the session's connection.
+fn open_drift_db(path: &Path) -> Result<Connection, drift_core::errors::StorageError> {
+    let key = DatabaseKey::from_env()?;
+    open_keyed(path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX, key.as_ref())
+}
+
+fn bridge_error(e: BridgeError) -> RpcError {
+    RpcError::drift(BRIDGE_ERROR, e)
+}
+
+/// Accepts `snake_case`, `PascalCase` and lowercase spellings.
+fn parse_memory_type(name: &str) -> Option<MemoryType> {
+    let wanted = name.to_lowercase().replace('_', "");
+    MemoryType::ALL
+        .into_iter()
+        .find(|t| format!("{t:?}").to_lowercase() == wanted)
+}
+
+fn stored_memory(row: &BridgeMemoryRow) -> MemoryForGrounding {
+    let tags: Vec<String> = serde_json::from_str(&row.tags).unwrap_or_default();
+    let linked_patterns: Vec<String> = serde_json::from_str::<Vec<Value>>(&row.linked_patterns)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|v| v.get("pattern_id").and_then(Value::as_str).map(str::to_string))
+        .collect();
+    let context = context_from_tags(&tags, &linked_patterns, row.confidence);
+    let has_context = context.pattern_id.is_some()
+        || context.constraint_id.is_some()
+        || context.module_path.is_some()
+        || context.file_path.is_some();
+    MemoryForGrounding {
+        current_confidence: row.confidence,
+        evidence_context: has_context.then_some(context),
+        ..unstored_memory(row.id.clone(), parse_memory_type(&row.memory_type).unwrap_or(MemoryType::Core))
+    }
+}
+
+fn unstored_memory(memory_id: String, memory_type: MemoryType) -> MemoryForGrounding {
+    MemoryForGrounding {
+        memory_id,
+        memory_type,
+        current_confidence: DEFAULT_CONFIDENCE,
+        pattern_confidence: None,
+        occurrence_rate: None,
+        false_positive_rate: None,
+        constraint_verified: None,
+        coupling_metric: None,
+        dna_health: None,
+        test_coverage: None,
+        error_handling_gaps: None,
+        decision_evidence: None,
+        boundary_data: None,
+        evidence_context: None,
+    }
+}
@@ -0,0 +1,47 @@
+//! # drift-mcp
+//!
+//! A Model Context Protocol server for Drift that runs without a Node
+//! runtime. It speaks MCP over stdio (newline-delimited JSON-RPC 2.0) and
+//! exposes:
+//!
+//! - **tools** — scan, analyze, stored-result queries, quality gates and
+//!   context generation (the `drift-rpc` handlers), plus the Cortex bridge
+//!   tools from [`cortex_drift_bridge::tools`];
+//! - **resources** — the project's files, violations, gate results and scan
+//!   history, and per-file functions and detections;
+//! - **prompts** — violation review, task context and pattern explanation.
+//!
+//! The server is bound to one project root when it starts; drift.db and
+//! bridge.db live under `<root>/.drift/` unless given explicitly. The bridge
+//! is optional: when bridge.db cannot be opened its tools report the bridge
+//! as unavailable and everything else keeps working.
+//!
+//! ```text
+//! → {"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18",...}}
+//! ← {"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{...},...}}
+//! → {"jsonrpc":"2.0","method":"notifications/initialized"}
+//! → {"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"drift_scan","arguments":{}}}
+//! ← {"jsonrpc":"2.0","id":2,"result":{"content":[{"type":"text","text":"{\"added\":42,...}"}],...}}
+//! ```
+
+pub mod bridge;
+pub mod prompts;
+pub mod resources;
+pub mod server;
+pub mod tools;
+pub mod types;
+
+pub use server::{Server, ServerOptions};
+
+/// MCP protocol revisions this server implements, newest first. A client
+/// asking for any other revision is answered with the newest one.
+pub const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Name reported in `serverInfo`, shared with the TypeScript server.
+pub const SERVER_NAME: &str = "drift-analysis";
+
+/// Serve MCP on stdin/stdout until the input closes.
+pub fn serve_stdio(options: &ServerOptions) -> std::io::Result<()> {
+    let server = Server::open(options).map_err(|e| std::io::Error::other(e.message))?;
+    server.serve(std::io::stdin().lock(), std::io::stdout())
+}
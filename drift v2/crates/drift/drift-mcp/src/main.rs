@@ -0,0 +1,42 @@
+//! `drift-mcp` — serve MCP on stdio for the project at `--root` (default:
+//! the current directory).
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use drift_mcp::ServerOptions;
+
+const USAGE: &str = "usage: drift-mcp [--root PATH] [--db PATH] [--bridge-db PATH] [--version]";
+
+fn main() -> ExitCode {
+    let mut options = ServerOptions { root: PathBuf::from("."), ..Default::default() };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let target = match arg.as_str() {
+            "--version" => {
+                println!("drift-mcp {}", env!("CARGO_PKG_VERSION"));
+                return ExitCode::SUCCESS;
+            }
+            "--root" => &mut options.root,
+            "--db" => options.db_path.insert(PathBuf::new()),
+            "--bridge-db" => options.bridge_db_path.insert(PathBuf::new()),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        };
+        let Some(value) = args.next() else {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        };
+        *target = PathBuf::from(value);
+    }
+
+    match drift_mcp::serve_stdio(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("drift-mcp: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
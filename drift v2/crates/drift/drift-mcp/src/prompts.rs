@@ -0,0 +1,131 @@
+//! Prompt templates that hand the model a task together with the stored
+//! results it needs, so a client does not have to chain tool calls first.
+
+use std::collections::BTreeMap;
+
+use drift_rpc::methods;
+use drift_rpc::protocol::RpcError;
+use drift_rpc::types::{ContextParams, DetectionsParams, ViolationsParams};
+use serde_json::Value;
+
+use crate::server::Server;
+use crate::types::{Content, GetPromptParams, GetPromptResult, ListPromptsResult, Prompt, PromptArgument, PromptMessage};
+
+/// Detections quoted by `explain_pattern`.
+const PATTERN_EXAMPLES: usize = 10;
+
+/// `(name, description, required)` of a prompt argument.
+type Argument = (&'static str, &'static str, bool);
+
+/// Prompts: `(name, description, arguments)`.
+const PROMPTS: &[(&str, &str, &[Argument])] = &[
+    (
+        "review_violations",
+        "Review the stored violations and propose fixes, most severe first.",
+        &[("owner", "Only violations owned by this CODEOWNERS owner, or \"unowned\".", false)],
+    ),
+    (
+        "task_context",
+        "Start a task with project context generated for its intent.",
+        &[
+            ("intent", "fix_bug, add_feature, understand_code, security_audit or generate_spec.", true),
+            ("depth", "overview, standard or deep. Defaults to standard.", false),
+        ],
+    ),
+    (
+        "explain_pattern",
+        "Explain a detected pattern from its occurrences and the memories behind it.",
+        &[("pattern_id", "Pattern id as drift_detections reports it.", true)],
+    ),
+];
+
+pub fn list() -> ListPromptsResult {
+    ListPromptsResult {
+        prompts: PROMPTS
+            .iter()
+            .map(|(name, description, arguments)| Prompt {
+                name: name.to_string(),
+                description: description.to_string(),
+                arguments: arguments
+                    .iter()
+                    .map(|(name, description, required)| PromptArgument {
+                        name: name.to_string(),
+                        description: description.to_string(),
+                        required: *required,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+pub fn get(server: &Server, params: GetPromptParams) -> Result<GetPromptResult, RpcError> {
+    let (_, description, _) = PROMPTS
+        .iter()
+        .find(|(name, _, _)| *name == params.name)
+        .ok_or_else(|| RpcError::invalid_params(format!("unknown prompt '{}'", params.name)))?;
+    let ctx = server.request();
+    let args = &params.arguments;
+
+    let text = match params.name.as_str() {
+        "review_violations" => {
+            let owner = args.get("owner").cloned();
+            let violations = methods::query_violations(&ctx, ViolationsParams { owner, ..Default::default() })?;
+            format!(
+                "Review these Drift violations. Group them by file, explain each problem briefly and propose \
+                 a concrete fix, most severe first. Skip suppressed ones.\n\n{}",
+                to_json(&violations)
+            )
+        }
+        "task_context" => {
+            let intent = required(args, "intent")?;
+            let context = methods::generate_context(
+                &ctx,
+                serde_json::from_value::<ContextParams>(serde_json::json!({
+                    "intent": intent,
+                    "depth": args.get("depth").map_or("standard", String::as_str),
+                }))
+                .map_err(RpcError::invalid_params)?,
+            )?;
+            let mut text = format!(
+                "Context for a {} task in this project. Use it to plan the change; call the drift tools \
+                 for anything it leaves out.\n",
+                intent.replace('_', " ")
+            );
+            for section in context.sections {
+                text.push_str(&format!("\n## {}\n{}\n", section.name, section.content));
+            }
+            text
+        }
+        "explain_pattern" => {
+            let pattern_id = required(args, "pattern_id")?;
+            let occurrences: Vec<_> = methods::query_detections(&ctx, DetectionsParams::default())?
+                .into_iter()
+                .filter(|d| d.pattern_id == pattern_id)
+                .take(PATTERN_EXAMPLES)
+                .collect();
+            format!(
+                "Explain the Drift pattern '{pattern_id}': what it detects, why it matters here and whether \
+                 these occurrences follow or break the project's conventions. Call drift_why with \
+                 entity_type \"pattern\" and this id for the memories behind it.\n\n{}",
+                to_json(&occurrences)
+            )
+        }
+        _ => unreachable!("every prompt in PROMPTS is handled"),
+    };
+
+    Ok(GetPromptResult {
+        description: description.to_string(),
+        messages: vec![PromptMessage { role: "user".to_string(), content: Content::Text { text } }],
+    })
+}
+
+fn required<'a>(args: &'a BTreeMap<String, String>, name: &str) -> Result<&'a str, RpcError> {
+    args.get(name)
+        .map(String::as_str)
+        .ok_or_else(|| RpcError::invalid_params(format!("missing required argument '{name}'")))
+}
+
+fn to_json(value: &impl serde::Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| Value::Null.to_string())
+}
@@ -0,0 +1,94 @@
+//! Read-only views of drift.db as `drift://` resources. Every resource is
+//! JSON, read through the same handlers as the matching tools.
+
+use drift_rpc::methods;
+use drift_rpc::protocol::RpcError;
+use drift_rpc::types::{DetectionsParams, FunctionsParams, ScanHistoryParams};
+use serde::Serialize;
+
+use crate::server::Server;
+use crate::types::{
+    ListResourceTemplatesResult, ListResourcesResult, ReadResourceResult, Resource, ResourceContents,
+    ResourceTemplate,
+};
+
+const MIME_TYPE: &str = "application/json";
+
+/// MCP's error code for a URI that names no resource.
+pub const RESOURCE_NOT_FOUND: i64 = -32002;
+
+const FUNCTIONS_PREFIX: &str = "drift://functions/";
+const DETECTIONS_PREFIX: &str = "drift://detections/";
+
+/// Fixed resources: `(uri, name, description)`.
+const RESOURCES: &[(&str, &str, &str)] = &[
+    ("drift://project/files", "files", "Files recorded by the last scan."),
+    ("drift://project/violations", "violations", "Violations from the last quality gate run."),
+    ("drift://project/gates", "gates", "Quality gate results from the last run."),
+    ("drift://project/scan-history", "scan-history", "The 20 most recent scans."),
+];
+
+pub fn list() -> ListResourcesResult {
+    ListResourcesResult {
+        resources: RESOURCES
+            .iter()
+            .map(|(uri, name, description)| Resource {
+                uri: uri.to_string(),
+                name: name.to_string(),
+                description: description.to_string(),
+                mime_type: MIME_TYPE.to_string(),
+            })
+            .collect(),
+    }
+}
+
+/// Per-file resources. `{+path}` is relative to the project root or
+/// absolute, as `drift://project/files` lists it.
+pub fn templates() -> ListResourceTemplatesResult {
+    ListResourceTemplatesResult {
+        resource_templates: vec![
+            ResourceTemplate {
+                uri_template: format!("{FUNCTIONS_PREFIX}{{+path}}"),
+                name: "functions".to_string(),
+                description: "Functions of one analyzed file.".to_string(),
+                mime_type: MIME_TYPE.to_string(),
+            },
+            ResourceTemplate {
+                uri_template: format!("{DETECTIONS_PREFIX}{{+path}}"),
+                name: "detections".to_string(),
+                description: "Detected patterns in one analyzed file.".to_string(),
+                mime_type: MIME_TYPE.to_string(),
+            },
+        ],
+    }
+}
+
+pub fn read(server: &Server, uri: &str) -> Result<ReadResourceResult, RpcError> {
+    let ctx = server.request();
+    let text = match uri {
+        "drift://project/files" => to_text(methods::query_files(&ctx, Default::default())?),
+        "drift://project/violations" => to_text(methods::query_violations(&ctx, Default::default())?),
+        "drift://project/gates" => to_text(methods::query_gates(&ctx, Default::default())?),
+        "drift://project/scan-history" => {
+            to_text(methods::query_scan_history(&ctx, ScanHistoryParams::default())?)
+        }
+        _ => {
+            if let Some(path) = uri.strip_prefix(FUNCTIONS_PREFIX) {
+                let file = server.resolve(path);
+                to_text(methods::query_functions(&ctx, FunctionsParams { file })?)
+            } else if let Some(path) = uri.strip_prefix(DETECTIONS_PREFIX) {
+                let file = Some(server.resolve(path));
+                to_text(methods::query_detections(&ctx, DetectionsParams { file, ..Default::default() })?)
+            } else {
+                return Err(RpcError::new(RESOURCE_NOT_FOUND, format!("unknown resource '{uri}'")));
+            }
+        }
+    }?;
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents { uri: uri.to_string(), mime_type: MIME_TYPE.to_string(), text }],
+    })
+}
+
+fn to_text(value: impl Serialize) -> Result<String, RpcError> {
+    serde_json::to_string(&value).map_err(|e| RpcError::internal(format!("cannot serialize resource: {e}")))
+}
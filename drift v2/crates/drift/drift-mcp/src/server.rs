@@ -0,0 +1,164 @@
+//! MCP connection loop over newline-delimited JSON-RPC.
+//!
+//! Requests are handled in order on the reading thread: MCP clients wait
+//! for each tool call, and a scan or analysis holds drift.db's writer
+//! anyway. Notifications, including `notifications/cancelled`, are
+//! accepted and ignored; by the time one is read its request has finished.
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use drift_analysis::scanner::cancellation::ScanCancellation;
+use drift_rpc::methods::RequestContext;
+use drift_rpc::protocol::{Message, Response, RpcError, JSONRPC_VERSION};
+use drift_rpc::session::Session;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::bridge::Bridge;
+use crate::types::{Implementation, InitializeParams, InitializeResult, ReadResourceParams};
+use crate::{prompts, resources, tools, PROTOCOL_VERSIONS, SERVER_NAME};
+
+const INSTRUCTIONS: &str = "Drift analyzes this project's code for patterns, conventions and violations. \
+    Run drift_scan, then drift_analyze, before querying; drift_check evaluates the quality gates. \
+    drift_context builds task context, and the drift_why / drift_memory_learn tools connect findings \
+    to Cortex memories.";
+
+/// Where the server finds its project and databases.
+#[derive(Debug, Clone, Default)]
+pub struct ServerOptions {
+    pub root: PathBuf,
+    /// drift.db location. Defaults to `<root>/.drift/drift.db`.
+    pub db_path: Option<PathBuf>,
+    /// bridge.db location. Defaults to `<root>/.drift/bridge.db`.
+    pub bridge_db_path: Option<PathBuf>,
+}
+
+/// One MCP client bound to one project.
+pub struct Server {
+    pub(crate) session: Arc<Session>,
+    pub(crate) bridge: Bridge,
+    initialized: bool,
+}
+
+impl Server {
+    /// Open drift.db as `drift-rpc`'s `initialize` does, then the bridge.
+    pub fn open(options: &ServerOptions) -> Result<Self, RpcError> {
+        let session = Session::open(&drift_rpc::types::InitializeParams {
+            root: options.root.to_string_lossy().to_string(),
+            db_path: options.db_path.as_ref().map(|p| p.to_string_lossy().to_string()),
+            ..Default::default()
+        })?;
+        let bridge_db = options
+            .bridge_db_path
+            .clone()
+            .unwrap_or_else(|| session.root.join(".drift").join("bridge.db"));
+        let bridge = Bridge::open(&bridge_db, &session.db_path);
+        Ok(Self { session: Arc::new(session), bridge, initialized: false })
+    }
+
+    /// Serve requests from `input` until it closes, then flush storage.
+    pub fn serve(mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_line(&line) {
+                let mut bytes = serde_json::to_vec(&response)?;
+                bytes.push(b'\n');
+                output.write_all(&bytes)?;
+                output.flush()?;
+            }
+        }
+        if let Err(e) = self.session.storage.flush_batch_sync() {
+            tracing::warn!(error = %e, "drift-mcp: flush on exit failed");
+        }
+        Ok(())
+    }
+
+    fn handle_line(&mut self, line: &str) -> Option<Response> {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(e) => return Some(Response::new(Value::Null, Err(RpcError::parse_error(e)))),
+        };
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        let message = match serde_json::from_value::<Message>(value) {
+            Ok(message) if message.jsonrpc == JSONRPC_VERSION => message,
+            Ok(_) => return Some(Response::new(id, Err(RpcError::invalid_request("jsonrpc must be \"2.0\"")))),
+            Err(e) => return Some(Response::new(id, Err(RpcError::invalid_request(e.to_string())))),
+        };
+        let id = message.id?;
+        Some(Response::new(id, self.handle_request(&message.method, message.params)))
+    }
+
+    fn handle_request(&mut self, method: &str, params: Option<Value>) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => return to_value(self.initialize(parse(params)?)),
+            "ping" => return Ok(json!({})),
+            _ => {}
+        }
+        if !self.initialized {
+            return Err(RpcError::not_initialized());
+        }
+        match method {
+            "tools/list" => to_value(tools::list()),
+            "tools/call" => to_value(tools::call(self, parse(params)?)?),
+            "resources/list" => to_value(resources::list()),
+            "resources/templates/list" => to_value(resources::templates()),
+            "resources/read" => {
+                let params: ReadResourceParams = parse(params)?;
+                to_value(resources::read(self, &params.uri)?)
+            }
+            "prompts/list" => to_value(prompts::list()),
+            "prompts/get" => to_value(prompts::get(self, parse(params)?)?),
+            name => Err(RpcError::method_not_found(name)),
+        }
+    }
+
+    /// Agree on the client's protocol version when it is one we implement,
+    /// else offer our newest.
+    fn initialize(&mut self, params: InitializeParams) -> InitializeResult {
+        let protocol_version = PROTOCOL_VERSIONS
+            .iter()
+            .find(|v| **v == params.protocol_version)
+            .unwrap_or(&PROTOCOL_VERSIONS[0]);
+        self.initialized = true;
+        InitializeResult {
+            protocol_version: protocol_version.to_string(),
+            capabilities: json!({
+                "tools": { "listChanged": false },
+                "resources": { "subscribe": false, "listChanged": false },
+                "prompts": { "listChanged": false },
+            }),
+            server_info: Implementation {
+                name: SERVER_NAME.to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            instructions: INSTRUCTIONS.to_string(),
+        }
+    }
+
+    /// A context for calling a `drift-rpc` handler. Calls run to completion,
+    /// so the cancellation handle is never tripped.
+    pub(crate) fn request(&self) -> RequestContext {
+        RequestContext::new(self.session.clone(), ScanCancellation::new())
+    }
+
+    /// A path from a tool or resource URI, as drift.db stores it.
+    pub(crate) fn resolve(&self, path: &str) -> String {
+        self.session.root.join(path).to_string_lossy().to_string()
+    }
+}
+
+/// Absent params are an empty object, so all-optional params may be omitted.
+pub(crate) fn parse<P: DeserializeOwned>(params: Option<Value>) -> Result<P, RpcError> {
+    serde_json::from_value(params.unwrap_or_else(|| Value::Object(Default::default())))
+        .map_err(RpcError::invalid_params)
+}
+
+fn to_value(result: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(result).map_err(|e| RpcError::internal(format!("cannot serialize result: {e}")))
+}
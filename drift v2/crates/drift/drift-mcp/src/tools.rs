@@ -0,0 +1,142 @@
+//! Tool table: the `drift-rpc` scan, analysis and query handlers and the
+//! Cortex bridge tools, each with a JSON Schema for its arguments.
+
+use drift_rpc::methods;
+use drift_rpc::protocol::{codes, RpcError};
+use drift_rpc::types::{
+    AnalyzeParams, CheckParams, ContextParams, DetectionsParams, EmptyParams, FilesParams, FunctionsParams,
+    ScanHistoryParams, ScanParams, ViolationsParams,
+};
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::Schema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::server::{parse, Server};
+use crate::types::{
+    CallToolParams, CallToolResult, Content, GroundingCheckParams, ListToolsResult, MemoryIdParams,
+    MemoryLearnParams, ToolAnnotations, ToolDescriptor, WhyParams,
+};
+
+type Handler = fn(&Server, Option<Value>) -> Result<Value, RpcError>;
+type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+pub struct Tool {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The tool does not write to drift.db or bridge.db.
+    pub read_only: bool,
+    /// Calling it again with the same arguments changes nothing further.
+    pub idempotent: bool,
+    handler: Handler,
+    input: SchemaFn,
+}
+
+macro_rules! tool {
+    ($name:literal, $description:literal, read_only: $read_only:literal, idempotent: $idempotent:literal,
+     $params:ty, $handler:expr) => {{
+        fn handle(server: &Server, arguments: Option<Value>) -> Result<Value, RpcError> {
+            dispatch::<$params, _>(server, arguments, $handler)
+        }
+        Tool {
+            name: $name,
+            description: $description,
+            read_only: $read_only,
+            idempotent: $idempotent,
+            handler: handle,
+            input: |gen| gen.subschema_for::<$params>(),
+        }
+    }};
+}
+
+pub static TOOLS: &[Tool] = &[
+    tool!("drift_scan", "Scan the project for added, modified and removed files and record them in drift.db. Run before drift_analyze.",
+        read_only: false, idempotent: true, ScanParams, |s, p| methods::scan(&s.request(), p)),
+    tool!("drift_analyze", "Parse scanned files and detect patterns, replacing their stored detections and functions.",
+        read_only: false, idempotent: true, AnalyzeParams, |s, p| methods::analyze(&s.request(), p)),
+    tool!("drift_files", "Files recorded by the last scan, optionally of one language (e.g. \"TypeScript\").",
+        read_only: true, idempotent: true, FilesParams, |s, p| methods::query_files(&s.request(), p)),
+    tool!("drift_detections", "Detected patterns by file or category, highest confidence first.",
+        read_only: true, idempotent: true, DetectionsParams, |s, p| methods::query_detections(&s.request(), p)),
+    tool!("drift_functions", "Functions of one analyzed file with their signatures and line ranges.",
+        read_only: true, idempotent: true, FunctionsParams, |s, p| methods::query_functions(&s.request(), p)),
+    tool!("drift_violations", "Stored violations from the last drift_check, by file or CODEOWNERS owner.",
+        read_only: true, idempotent: true, ViolationsParams, |s, p| methods::query_violations(&s.request(), p)),
+    tool!("drift_gates", "Quality gate results from the last drift_check.",
+        read_only: true, idempotent: true, EmptyParams, |s, p| methods::query_gates(&s.request(), p)),
+    tool!("drift_scan_history", "Recent scans, newest first.",
+        read_only: true, idempotent: true, ScanHistoryParams, |s, p| methods::query_scan_history(&s.request(), p)),
+    tool!("drift_check", "Run the quality gates over the stored detections, store the violations and optionally render a report.",
+        read_only: false, idempotent: false, CheckParams, |s, p| methods::check(&s.request(), p)),
+    tool!("drift_context", "Generate context for a task (fix_bug, add_feature, understand_code, security_audit, generate_spec) within a token budget.",
+        read_only: true, idempotent: true, ContextParams, |s, p| methods::generate_context(&s.request(), p)),
+    tool!("drift_why", "Explain why a pattern, violation or constraint exists from related memories, grounding history and causal narrative.",
+        read_only: true, idempotent: true, WhyParams, |s, p| s.bridge.why(p)),
+    tool!("drift_memory_learn", "Record a correction to a Drift finding as a feedback memory.",
+        read_only: false, idempotent: false, MemoryLearnParams, |s, p| s.bridge.memory_learn(p)),
+    tool!("drift_grounding_check", "Ground a memory against drift.db evidence and return its score, verdict, evidence and history.",
+        read_only: false, idempotent: false, GroundingCheckParams, |s, p| s.bridge.grounding_check(p)),
+    tool!("drift_counterfactual", "What if this memory did not exist: the downstream memories that would be affected.",
+        read_only: true, idempotent: true, MemoryIdParams, |s, p| s.bridge.counterfactual(p)),
+    tool!("drift_intervention", "If this memory changed, which downstream memories would be affected.",
+        read_only: true, idempotent: true, MemoryIdParams, |s, p| s.bridge.intervention(p)),
+    tool!("drift_health", "Health of bridge storage, the drift.db connection and the causal engine.",
+        read_only: true, idempotent: true, EmptyParams, |s, _: EmptyParams| s.bridge.health()),
+];
+
+pub fn find(name: &str) -> Option<&'static Tool> {
+    TOOLS.iter().find(|t| t.name == name)
+}
+
+/// `tools/list`. Input schemas are inlined: MCP clients expect each one to
+/// be a self-contained object schema.
+pub fn list() -> ListToolsResult {
+    let mut gen = SchemaSettings::draft07()
+        .with(|s| {
+            s.inline_subschemas = true;
+            s.meta_schema = None;
+        })
+        .into_generator();
+    let tools = TOOLS
+        .iter()
+        .map(|tool| ToolDescriptor {
+            name: tool.name.to_string(),
+            description: tool.description.to_string(),
+            input_schema: serde_json::to_value((tool.input)(&mut gen)).unwrap_or(Value::Null),
+            annotations: ToolAnnotations {
+                read_only_hint: tool.read_only,
+                idempotent_hint: tool.idempotent,
+                open_world_hint: false,
+            },
+        })
+        .collect();
+    ListToolsResult { tools }
+}
+
+/// `tools/call`. Unknown tools and malformed arguments are protocol
+/// errors; a tool that fails returns its message with `isError` so the
+/// model can see it.
+pub fn call(server: &Server, params: CallToolParams) -> Result<CallToolResult, RpcError> {
+    let tool = find(&params.name).ok_or_else(|| RpcError::invalid_params(format!("unknown tool '{}'", params.name)))?;
+    Ok(match (tool.handler)(server, params.arguments) {
+        Ok(value) => CallToolResult {
+            content: vec![Content::Text { text: value.to_string() }],
+            is_error: false,
+        },
+        Err(error) if error.code == codes::INVALID_PARAMS => return Err(error),
+        Err(error) => CallToolResult {
+            content: vec![Content::Text { text: error.message }],
+            is_error: true,
+        },
+    })
+}
+
+fn dispatch<P: DeserializeOwned, R: Serialize>(
+    server: &Server,
+    arguments: Option<Value>,
+    handler: fn(&Server, P) -> Result<R, RpcError>,
+) -> Result<Value, RpcError> {
+    let result = handler(server, parse(arguments)?)?;
+    serde_json::to_value(result).map_err(|e| RpcError::internal(format!("cannot serialize result: {e}")))
+}
@@ -0,0 +1,261 @@
+//! MCP server — lifecycle, tools, resources, prompts and the bridge tools
+//! over a socket pair.
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::thread::JoinHandle;
+
+use drift_mcp::{Server, ServerOptions};
+use drift_rpc::protocol::codes;
+use serde_json::{json, Value};
+
+const APP: &str = r#"import express from "express";
+
+export function getUser(req, res) {
+  const id = req.query.id;
+  return db.query("SELECT * FROM users WHERE id = " + id);
+}
+
+export async function listUsers() {
+  return db.query("SELECT * FROM users");
+}
+"#;
+
+struct Client {
+    writer: UnixStream,
+    reader: BufReader<UnixStream>,
+    server: Option<JoinHandle<()>>,
+    next_id: u64,
+}
+
+impl Client {
+    /// Serve `root` with drift.db and bridge.db in `db_dir`, outside the
+    /// scanned tree.
+    fn start(root: &Path, db_dir: &Path, bridge_db: Option<&Path>) -> Self {
+        let options = ServerOptions {
+            root: root.to_path_buf(),
+            db_path: Some(db_dir.join("drift.db")),
+            bridge_db_path: Some(bridge_db.map_or_else(|| db_dir.join("bridge.db"), Path::to_path_buf)),
+        };
+        let server = Server::open(&options).unwrap();
+        let (client, stream) = UnixStream::pair().unwrap();
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        let handle = std::thread::spawn(move || server.serve(reader, stream).unwrap());
+        Self {
+            reader: BufReader::new(client.try_clone().unwrap()),
+            writer: client,
+            server: Some(handle),
+            next_id: 1,
+        }
+    }
+
+    fn send(&mut self, message: Value) {
+        let mut line = serde_json::to_vec(&message).unwrap();
+        line.push(b'\n');
+        self.writer.write_all(&line).unwrap();
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        let mut line = String::new();
+        assert!(self.reader.read_line(&mut line).unwrap() > 0, "server closed the connection");
+        let response: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(response["id"], id, "unexpected response {response}");
+        response
+    }
+
+    fn initialize(&mut self) -> Value {
+        let response = self.request(
+            "initialize",
+            json!({ "protocolVersion": "2025-06-18", "capabilities": {}, "clientInfo": { "name": "test", "version": "0" } }),
+        );
+        self.send(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }));
+        response
+    }
+
+    /// Call a tool and decode the JSON in its text content.
+    fn tool(&mut self, name: &str, arguments: Value) -> Value {
+        let response = self.request("tools/call", json!({ "name": name, "arguments": arguments }));
+        let result = &response["result"];
+        assert_eq!(result["isError"], false, "{name} failed: {response}");
+        serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap()
+    }
+
+    fn read(&mut self, uri: &str) -> Value {
+        let response = self.request("resources/read", json!({ "uri": uri }));
+        let contents = &response["result"]["contents"][0];
+        assert_eq!(contents["uri"], uri, "{response}");
+        serde_json::from_str(contents["text"].as_str().unwrap()).unwrap()
+    }
+
+    fn close(mut self) {
+        drop(self.writer.shutdown(std::net::Shutdown::Write));
+        self.server.take().unwrap().join().unwrap();
+    }
+}
+
+fn project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/users.js"), APP).unwrap();
+    std::fs::write(dir.path().join("CODEOWNERS"), "*.js @web-team\n").unwrap();
+    dir
+}
+
+#[test]
+fn initialize_negotiates_version_and_lists_capabilities() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let mut client = Client::start(dir.path(), db_dir.path(), None);
+    assert_eq!(client.request("tools/list", json!({}))["error"]["code"], codes::NOT_INITIALIZED);
+    assert_eq!(client.request("ping", json!(null))["result"], json!({}));
+
+    let older = client.request("initialize", json!({ "protocolVersion": "2024-11-05", "capabilities": {} }));
+    assert_eq!(older["result"]["protocolVersion"], "2024-11-05");
+    let unknown = client.request("initialize", json!({ "protocolVersion": "1999-01-01", "capabilities": {} }));
+    assert_eq!(unknown["result"]["protocolVersion"], drift_mcp::PROTOCOL_VERSIONS[0]);
+    let init = client.initialize();
+    assert_eq!(init["result"]["serverInfo"]["name"], drift_mcp::SERVER_NAME);
+    assert!(init["result"]["capabilities"]["tools"].is_object());
+    assert!(init["result"]["capabilities"]["prompts"].is_object());
+
+    let tools = client.request("tools/list", json!(null));
+    let tools = tools["result"]["tools"].as_array().unwrap();
+    for name in ["drift_scan", "drift_context", "drift_why", "drift_memory_learn", "drift_grounding_check",
+        "drift_counterfactual", "drift_intervention", "drift_health"]
+    {
+        let tool = tools.iter().find(|t| t["name"] == name).unwrap_or_else(|| panic!("{name} is listed"));
+        assert_eq!(tool["inputSchema"]["type"], "object", "{tool}");
+        assert!(!tool["inputSchema"].to_string().contains("$ref"), "{name} schema is self-contained");
+    }
+    let why = tools.iter().find(|t| t["name"] == "drift_why").unwrap();
+    assert_eq!(why["inputSchema"]["required"], json!(["entity_id", "entity_type"]));
+    assert_eq!(why["annotations"]["readOnlyHint"], true);
+    let context = tools.iter().find(|t| t["name"] == "drift_context").unwrap();
+    assert!(context["inputSchema"]["properties"]["intent"]["enum"].as_array().unwrap().contains(&json!("fix_bug")));
+
+    let resources = client.request("resources/list", json!({}));
+    assert!(resources["result"]["resources"].as_array().unwrap().iter().any(|r| r["uri"] == "drift://project/files"));
+    let templates = client.request("resources/templates/list", json!({}));
+    assert_eq!(templates["result"]["resourceTemplates"][0]["uriTemplate"], "drift://functions/{+path}");
+    let prompts = client.request("prompts/list", json!({}));
+    assert_eq!(prompts["result"]["prompts"].as_array().unwrap().len(), 3);
+
+    assert_eq!(client.request("no/such/method", json!({}))["error"]["code"], codes::METHOD_NOT_FOUND);
+    client.close();
+}
+
+#[test]
+fn drift_tools_resources_and_prompts() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let mut client = Client::start(dir.path(), db_dir.path(), None);
+    client.initialize();
+
+    let scan = client.tool("drift_scan", json!({}));
+    assert_eq!(scan["added"], 2, "{scan}");
+    let analyze = client.tool("drift_analyze", json!({}));
+    assert_eq!(analyze["functions"], 2, "{analyze}");
+
+    let users = dir.path().join("src/users.js").to_string_lossy().to_string();
+    let files = client.tool("drift_files", json!({ "language": "JavaScript" }));
+    assert_eq!(files[0]["path"], users.as_str());
+    let functions = client.read("drift://functions/src/users.js");
+    let names: Vec<&str> = functions.as_array().unwrap().iter().map(|f| f["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["getUser", "listUsers"]);
+    assert_eq!(client.read(&format!("drift://functions/{users}")), functions);
+    let detections = client.read("drift://detections/src/users.js");
+    assert_eq!(detections.as_array().unwrap().len(), analyze["detections"].as_u64().unwrap() as usize);
+
+    let check = client.tool("drift_check", json!({}));
+    assert!(!check["gates"].as_array().unwrap().is_empty(), "{check}");
+    let gates = client.read("drift://project/gates");
+    assert_eq!(gates.as_array().unwrap().len(), check["gates"].as_array().unwrap().len());
+    assert_eq!(client.read("drift://project/scan-history")[0]["status"], "completed");
+
+    let context = client.tool("drift_context", json!({ "intent": "understand_code", "depth": "overview" }));
+    assert!(context["sections"].as_array().unwrap().iter().any(|s| s["name"] == "overview"));
+
+    let prompt = client.request("prompts/get", json!({ "name": "task_context", "arguments": { "intent": "fix_bug" } }));
+    let text = prompt["result"]["messages"][0]["content"]["text"].as_str().unwrap();
+    assert!(text.starts_with("Context for a fix bug task") && text.contains("JavaScript"), "{prompt}");
+    let review = client.request("prompts/get", json!({ "name": "review_violations", "arguments": { "owner": "unowned" } }));
+    assert!(review["result"]["messages"][0]["content"]["text"].as_str().unwrap().ends_with("[]"));
+    let missing = client.request("prompts/get", json!({ "name": "explain_pattern" }));
+    assert_eq!(missing["error"]["code"], codes::INVALID_PARAMS);
+
+    // Protocol errors for bad calls; engine errors come back as tool results.
+    let unknown = client.request("tools/call", json!({ "name": "drift_nothing" }));
+    assert_eq!(unknown["error"]["code"], codes::INVALID_PARAMS);
+    let bad_args = client.request("tools/call", json!({ "name": "drift_context", "arguments": { "intent": "refactor" } }));
+    assert_eq!(bad_args["error"]["code"], codes::INVALID_PARAMS);
+    let bad_report = client.request("tools/call", json!({ "name": "drift_check", "arguments": { "report": "pdf" } }));
+    assert_eq!(bad_report["result"]["isError"], true);
+    assert!(bad_report["result"]["content"][0]["text"].as_str().unwrap().starts_with("[CONFIG_ERROR]"));
+    let no_resource = client.request("resources/read", json!({ "uri": "drift://nowhere" }));
+    assert_eq!(no_resource["error"]["code"], drift_mcp::resources::RESOURCE_NOT_FOUND);
+    client.close();
+}
+
+#[test]
+fn bridge_tools_learn_explain_and_ground_memories() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let mut client = Client::start(dir.path(), db_dir.path(), None);
+    client.initialize();
+
+    let health = client.tool("drift_health", json!({}));
+    assert_eq!(health["subsystem_checks"][0]["healthy"], true, "{health}");
+
+    let learned = client.tool(
+        "drift_memory_learn",
+        json!({ "entity_type": "pattern", "entity_id": "sql-concat", "correction": "Queries here go through the ORM." }),
+    );
+    assert_eq!(learned["category"], "correction");
+    let memory_id = learned["memory_id"].as_str().unwrap().to_string();
+
+    let why = client.tool("drift_why", json!({ "entity_type": "pattern", "entity_id": "sql-concat" }));
+    assert_eq!(why["cortex_memories"][0]["id"], memory_id.as_str(), "{why}");
+
+    let grounded = client.tool("drift_grounding_check", json!({ "memory_id": memory_id }));
+    assert!(grounded.is_object(), "{grounded}");
+    let unstored = client.tool("drift_grounding_check", json!({ "memory_id": "m-1", "memory_type": "PatternRationale" }));
+    assert!(unstored.is_object(), "{unstored}");
+    let untyped = client.request("tools/call", json!({ "name": "drift_grounding_check", "arguments": { "memory_id": "m-1" } }));
+    assert_eq!(untyped["error"]["code"], codes::INVALID_PARAMS);
+
+    let counterfactual = client.tool("drift_counterfactual", json!({ "memory_id": memory_id }));
+    assert_eq!(counterfactual["memory_id"], memory_id.as_str());
+    let intervention = client.tool("drift_intervention", json!({ "memory_id": memory_id }));
+    assert!(intervention.is_object());
+    client.close();
+}
+
+#[test]
+fn bridge_tools_report_an_unavailable_bridge() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    // A regular file where bridge.db's directory should be.
+    let blocker = db_dir.path().join("not-a-dir");
+    std::fs::write(&blocker, "").unwrap();
+    let mut client = Client::start(dir.path(), db_dir.path(), Some(&blocker.join("bridge.db")));
+    client.initialize();
+
+    let why = client.request("tools/call", json!({ "name": "drift_why", "arguments": { "entity_type": "pattern", "entity_id": "x" } }));
+    assert_eq!(why["result"]["isError"], true);
+    assert!(why["result"]["content"][0]["text"].as_str().unwrap().starts_with("[BRIDGE_ERROR]"));
+    let health = client.tool("drift_health", json!({}));
+    assert_eq!(health["status"], "unavailable", "{health}");
+    // Drift tools do not depend on the bridge.
+    assert_eq!(client.tool("drift_scan", json!({}))["added"], 2);
+    client.close();
+}
+
+#[test]
+fn grounding_check_reports_an_unopenable_drift_db() {
+    let db_dir = tempfile::tempdir().unwrap();
+    let bridge = drift_mcp::bridge::Bridge::open(&db_dir.path().join("bridge.db"), &db_dir.path().join("missing.db"));
+    let params = drift_mcp::types::GroundingCheckParams { memory_id: "m-1".into(), memory_type: Some("PatternRationale".into()) };
+    let err = bridge.grounding_check(params).unwrap_err();
+    assert!(err.message.starts_with("[BRIDGE_ERROR] drift.db not available"), "{}", err.message);
+}
@@ -1,6 +1,7 @@
 //! NAPI bindings for Phase 7 advanced systems.
 //!
-//! Exposes: drift_simulate(), drift_decisions(), drift_context(), drift_generate_spec()
+//! Exposes: drift_simulate(), drift_decisions(), drift_context(), drift_generate_spec(),
+//! drift_sbom(), drift_repo_map()
 
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
@@ -27,9 +28,20 @@ pub async fn drift_simulate(
 
     let input: SimulationInput = serde_json::from_str(&context_json)
         .unwrap_or_default();
-    let context = input.context;
+    let mut context = input.context;
     let affected_files = input.affected_files;
 
+    // Complexity averages the caller didn't supply come from the last analysis run
+    if let Ok(rt) = crate::runtime::get() {
+        let rows = rt.storage.with_reader(|conn| {
+            drift_storage::queries::complexity::query_by_files(conn, &affected_files)
+        }).unwrap_or_default();
+        let summary = drift_analysis::structural::complexity::ComplexitySummary::from_measures(
+            rows.iter().map(|r| (r.cyclomatic as u32, r.cognitive as u32, r.maintainability_index)),
+        );
+        context.fill_complexity(&summary);
+    }
+
     let category = match task_category.as_str() {
         "add_feature" => TaskCategory::AddFeature,
         "fix_bug" => TaskCategory::FixBug,
@@ -82,27 +94,20 @@ pub async fn drift_context(
     data_json: String,
 ) -> Result<String> {
     use drift_context::generation::builder::*;
-    use drift_context::generation::intent::ContextIntent;
-
-    let intent = match intent.as_str() {
-        "fix_bug" => ContextIntent::FixBug,
-        "add_feature" => ContextIntent::AddFeature,
-        "understand_code" | "understand" => ContextIntent::UnderstandCode,
-        "security_audit" => ContextIntent::SecurityAudit,
-        "generate_spec" => ContextIntent::GenerateSpec,
-        _ => return Err(Error::from_reason(format!("Unknown intent: {}", intent))),
-    };
 
-    let depth = match depth.as_str() {
-        "overview" => ContextDepth::Overview,
-        "standard" => ContextDepth::Standard,
-        "deep" => ContextDepth::Deep,
-        _ => return Err(Error::from_reason(format!("Unknown depth: {}", depth))),
-    };
+    let intent = parse_intent(&intent)?;
+    let depth = parse_depth(&depth)?;
 
-    let sections: std::collections::HashMap<String, String> = serde_json::from_str(&data_json)
+    let mut sections: std::collections::HashMap<String, String> = serde_json::from_str(&data_json)
         .unwrap_or_default();
 
+    // Git history from the last analysis run, unless the caller supplied it
+    if !sections.contains_key("change_history") {
+        if let Some(history) = crate::runtime::get().ok().and_then(|rt| change_history_section(&rt)) {
+            sections.insert("change_history".to_string(), history);
+        }
+    }
+
     let mut data = AnalysisData::new();
     for (k, v) in sections {
         data.add_section(k, v);
@@ -125,6 +130,215 @@ pub async fn drift_context(
         .map_err(|e| Error::from_reason(format!("Serialization error: {}", e)))
 }
 
+/// Summarize stored hotspots, change coupling and low-bus-factor modules
+/// as a context section. `None` when no history has been analyzed.
+fn change_history_section(rt: &std::sync::Arc<crate::runtime::DriftRuntime>) -> Option<String> {
+    use drift_storage::queries::history as hq;
+
+    let (hotspots, coupling, ownership) = rt.storage.with_reader(|conn| {
+        Ok((
+            hq::query_hotspots(conn, 10)?,
+            hq::query_top_coupling(conn, 10)?,
+            hq::query_all_ownership(conn)?,
+        ))
+    }).ok()?;
+    let mut out = String::new();
+    if !hotspots.is_empty() {
+        out.push_str("Hotspots (frequently changed, complex files):\n");
+        for h in &hotspots {
+            out.push_str(&format!(
+                "- {} — {} commits, complexity {}, score {:.2}\n",
+                h.file, h.commits, h.complexity, h.hotspot_score,
+            ));
+        }
+    }
+    if !coupling.is_empty() {
+        out.push_str("Change coupling (files that change together):\n");
+        for c in &coupling {
+            out.push_str(&format!(
+                "- {} ↔ {} — {} shared commits, degree {:.2}\n",
+                c.file_a, c.file_b, c.co_changes, c.degree,
+            ));
+        }
+    }
+    let at_risk: Vec<_> = ownership.iter().filter(|m| m.bus_factor <= 1).take(10).collect();
+    if !at_risk.is_empty() {
+        out.push_str("Ownership (modules with bus factor 1):\n");
+        for m in at_risk {
+            out.push_str(&format!(
+                "- {} — {} ({:.0}% of {} changed lines)\n",
+                m.module,
+                m.primary_owner.as_deref().unwrap_or("unknown"),
+                m.primary_share * 100.0,
+                m.total_changes,
+            ));
+        }
+    }
+    (!out.is_empty()).then_some(out)
+}
+
+fn parse_intent(intent: &str) -> Result<drift_context::generation::intent::ContextIntent> {
+    use drift_context::generation::intent::ContextIntent;
+    Ok(match intent {
+        "fix_bug" => ContextIntent::FixBug,
+        "add_feature" => ContextIntent::AddFeature,
+        "understand_code" | "understand" => ContextIntent::UnderstandCode,
+        "security_audit" => ContextIntent::SecurityAudit,
+        "generate_spec" => ContextIntent::GenerateSpec,
+        _ => return Err(Error::from_reason(format!("Unknown intent: {}", intent))),
+    })
+}
+
+fn parse_depth(depth: &str) -> Result<drift_context::generation::builder::ContextDepth> {
+    use drift_context::generation::builder::ContextDepth;
+    Ok(match depth {
+        "overview" => ContextDepth::Overview,
+        "standard" => ContextDepth::Standard,
+        "deep" => ContextDepth::Deep,
+        _ => return Err(Error::from_reason(format!("Unknown depth: {}", depth))),
+    })
+}
+
+/// Generate a ranked repository map: PageRank over the call graph and
+/// import graph, rendered as signatures-only outlines within the depth's
+/// token budget.
+///
+/// @param focus - Free text (task description, mentioned files and
+///   identifiers) the ranking is biased toward. Files named here keep
+///   biasing later maps for the life of the runtime.
+/// @param format - "markdown", "xml" or "yaml".
+#[napi]
+pub async fn drift_repo_map(
+    intent: String,
+    depth: String,
+    format: String,
+    focus: Option<String>,
+) -> Result<String> {
+    use drift_analysis::call_graph::CallGraphBuilder;
+    use drift_analysis::parsers::types::FunctionInfo;
+    use drift_analysis::structural::coupling::import_graph::ImportGraphBuilder;
+    use drift_context::formats::{MarkdownFormatter, XmlFormatter, YamlFormatter};
+    use drift_context::generation::builder::ContextEngine;
+    use drift_context::generation::repo_map::{RepoGraph, RepoMapFocus, RepoSymbol};
+
+    let intent = parse_intent(&intent)?;
+    let depth = parse_depth(&depth)?;
+
+    let rt = crate::runtime::get()?;
+    let files = rt.storage
+        .with_reader(drift_storage::queries::files::load_all_file_metadata)
+        .map_err(|e| Error::from_reason(format!("[{}] {e}", crate::conversions::error_codes::STORAGE_ERROR)))?;
+
+    let parser_manager = drift_analysis::parsers::ParserManager::new();
+    let parse_results: Vec<_> = files
+        .iter()
+        .filter(|meta| meta.language.is_some())
+        .filter_map(|meta| {
+            let path = std::path::Path::new(&meta.path);
+            let source = match rt.project_root.as_deref() {
+                Some(root) => std::fs::read(root.join(path)),
+                None => std::fs::read(path),
+            }
+            .ok()?;
+            parser_manager.parse(&source, path).ok()
+        })
+        .collect();
+
+    // Parsers keep annotation punctuation (`: string`, `extends Base`).
+    let clean = |text: &str, keyword: &str| {
+        text.trim().trim_start_matches(keyword).trim_start_matches(':').trim().to_string()
+    };
+    let signature = |name: &str, f: &FunctionInfo| {
+        let params = f.parameters.iter().map(|p| {
+            let rest = if p.is_rest { "..." } else { "" };
+            match &p.type_annotation {
+                Some(ty) => format!("{rest}{}: {}", p.name, clean(ty, "")),
+                None => format!("{rest}{}", p.name),
+            }
+        }).collect::<Vec<_>>().join(", ");
+        let asyncness = if f.is_async { "async " } else { "" };
+        match &f.return_type {
+            Some(ret) => format!("{asyncness}{name}({params}): {}", clean(ret, "")),
+            None => format!("{asyncness}{name}({params})"),
+        }
+    };
+
+    // Lines are rendered 1-based; parsers report tree-sitter rows.
+    let mut graph = RepoGraph::new();
+    for pr in &parse_results {
+        graph.add_file(pr.file.clone());
+        let methods: std::collections::HashSet<(&str, u32)> = pr.classes.iter()
+            .flat_map(|c| c.methods.iter().map(|m| (m.name.as_str(), m.line)))
+            .collect();
+        for f in pr.functions.iter().filter(|f| !methods.contains(&(f.name.as_str(), f.line))) {
+            graph.add_symbol(pr.file.clone(), RepoSymbol {
+                name: f.name.clone(),
+                signature: signature(&f.name, f),
+                line: f.line + 1,
+            });
+        }
+        for class in &pr.classes {
+            let mut header = format!("{} {}", format!("{:?}", class.class_kind).to_lowercase(), class.name);
+            if let Some(base) = &class.extends {
+                // TS heritage clauses arrive whole: `extends Base implements Show`.
+                let base = base.split(" implements ").next().unwrap_or(base);
+                header.push_str(&format!(" extends {}", clean(base, "extends")));
+            }
+            if !class.implements.is_empty() {
+                let interfaces = class.implements.iter().map(|i| clean(i, "implements")).collect::<Vec<_>>();
+                header.push_str(&format!(" implements {}", interfaces.join(", ")));
+            }
+            graph.add_symbol(pr.file.clone(), RepoSymbol {
+                name: class.name.clone(),
+                signature: header,
+                line: class.range.start.line + 1,
+            });
+            for m in &class.methods {
+                let name = format!("{}.{}", class.name, m.name);
+                graph.add_symbol(pr.file.clone(), RepoSymbol {
+                    signature: signature(&name, m),
+                    name,
+                    line: m.line + 1,
+                });
+            }
+        }
+    }
+
+    if let Ok((call_graph, _)) = CallGraphBuilder::new().build(&parse_results) {
+        for edge in call_graph.graph.edge_indices() {
+            if let Some((caller, callee)) = call_graph.graph.edge_endpoints(edge) {
+                let (caller, callee) = (&call_graph.graph[caller], &call_graph.graph[callee]);
+                graph.add_call(&caller.file, &caller.name, &callee.file, &callee.name);
+            }
+        }
+    }
+    // Module depth beyond any path length keeps the import graph file-level.
+    let import_graph = ImportGraphBuilder::from_parse_results(&parse_results, usize::MAX);
+    for (from, targets) in &import_graph.edges {
+        for to in targets {
+            graph.add_import(from, to);
+        }
+    }
+
+    let focus = focus.as_deref().map(RepoMapFocus::from_text).unwrap_or_default();
+    let mut session = rt.context_session.lock()
+        .map_err(|_| Error::from_reason("Context session lock poisoned"))?;
+    let mut engine = ContextEngine::new().with_session(session.clone());
+    let output = engine.generate_repo_map(intent, depth, &graph, &focus)
+        .map_err(|e| Error::from_reason(format!("Context generation error: {}", e)))?;
+    if let Some(updated) = engine.into_session() {
+        *session = updated;
+    }
+    drop(session);
+
+    match format.as_str() {
+        "markdown" | "md" => Ok(MarkdownFormatter::new().format(&output)),
+        "xml" => Ok(XmlFormatter::new().format(&output)),
+        "yaml" => Ok(YamlFormatter::new().format(&output)),
+        _ => Err(Error::from_reason(format!("Unknown format: {}", format))),
+    }
+}
+
 /// Generate a specification document for a module.
 #[napi]
 pub async fn drift_generate_spec(
@@ -171,3 +385,56 @@ struct MigrationPathInput {
     source_framework: Option<String>,
     target_framework: Option<String>,
 }
+
+/// Export the dependency inventory persisted by `drift_analyze` as an SBOM.
+///
+/// @param format - "cyclonedx" or "spdx".
+#[napi]
+pub fn drift_sbom(format: String) -> Result<String> {
+    use drift_context::packages::{sbom, DependencyGraph, PackageManager, ResolvedDependency, SbomFormat};
+
+    let sbom_format = SbomFormat::parse(&format)
+        .ok_or_else(|| Error::from_reason(format!("Unknown SBOM format: {}", format)))?;
+
+    let rt = crate::runtime::get()?;
+    let rows = rt.storage
+        .with_reader(drift_storage::queries::dependencies::query_all)
+        .map_err(|e| Error::from_reason(format!("[{}] {e}", crate::conversions::error_codes::STORAGE_ERROR)))?;
+
+    // Rows are ordered by package path then manager, so each graph is contiguous.
+    let mut graphs: Vec<DependencyGraph> = Vec::new();
+    for row in rows {
+        let Some(manager) = PackageManager::from_name(&row.manager) else { continue };
+        let same_graph = graphs.last().is_some_and(|g| g.path == row.package_path && g.manager == manager);
+        if !same_graph {
+            graphs.push(DependencyGraph {
+                package: row.package,
+                path: row.package_path,
+                manager,
+                lockfile: row.lockfile,
+                dependencies: Vec::new(),
+            });
+        }
+        if let Some(graph) = graphs.last_mut() {
+            graph.dependencies.push(ResolvedDependency {
+                name: row.name,
+                version: row.version,
+                manager,
+                purl: row.purl,
+                direct: row.direct,
+                dev: row.dev,
+                checksum: row.checksum,
+                depends_on: row.depends_on,
+            });
+        }
+    }
+
+    let name = rt.project_root.as_deref()
+        .and_then(|root| root.file_name())
+        .and_then(|n| n.to_str())
+        .unwrap_or("project");
+    let document = sbom::export(sbom_format, name, &graphs, &sbom::timestamp_now());
+
+    serde_json::to_string(&document)
+        .map_err(|e| Error::from_reason(format!("Serialization error: {}", e)))
+}
@@ -1,4 +1,5 @@
-//! Phase 2 NAPI bindings — drift_analyze(), drift_call_graph(), drift_boundaries().
+//! Phase 2 NAPI bindings — drift_analyze(), drift_call_graph(), drift_boundaries(),
+//! drift_backfill().
 
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
@@ -63,6 +64,7 @@ pub struct JsBoundaryResult {
     pub models: Vec<JsModelResult>,
     pub sensitive_fields: Vec<JsSensitiveField>,
     pub frameworks_detected: Vec<String>,
+    pub schema_drift: Vec<JsSchemaDrift>,
 }
 
 /// A model result returned to TypeScript.
@@ -88,6 +90,21 @@ pub struct JsSensitiveField {
     pub confidence: f64,
 }
 
+/// A mismatch between an ORM model and the migration schema.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsSchemaDrift {
+    /// `missing_table`, `missing_column`, `nullability_mismatch` or
+    /// `unmapped_required_column`.
+    pub kind: String,
+    pub model_name: String,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub file: String,
+    pub line: u32,
+    pub message: String,
+}
+
 fn storage_err(e: impl std::fmt::Display) -> napi::Error {
     napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR))
 }
@@ -95,10 +112,10 @@ fn storage_err(e: impl std::fmt::Display) -> napi::Error {
 /// Run the analysis pipeline on the project.
 ///
 /// Orchestrates in phases:
-///   Phase 1: read tracked files → parse → detect → persist detections + functions
-///   Phase 2: cross-file analysis (boundaries, call graph)
+///   Phase 1: read tracked files → parse → detect → persist detections, functions + complexity
+///   Phase 2: cross-file analysis (boundaries, call graph, dependency inventory, git history)
 ///   Phase 3: pattern intelligence + structural (coupling, wrappers, crypto, DNA, etc.)
-///   Phase 4: graph intelligence (taint, errors, impact, test topology, reachability)
+///   Phase 4: graph intelligence (taint, errors, impact, test topology, reachability, vulnerable dependencies)
 ///   Phase 5: enforcement (quality gates, violations, degradation alerts)
 ///
 /// @param max_phase - Stop after this phase (1-5). Default: 5 (all phases).
@@ -127,15 +144,9 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
     // Step 2a: Load framework packs (built-in + custom from .drift/frameworks/)
     let fw_load_timer = std::time::Instant::now();
-    let framework_registry = {
-        let custom_dir = rt.project_root.as_ref()
-            .map(|p| p.join(".drift").join("frameworks"));
-        match custom_dir {
-            Some(ref dir) if dir.is_dir() => {
-                drift_analysis::frameworks::registry::FrameworkPackRegistry::with_builtins_and_custom(dir)
-            }
-            _ => drift_analysis::frameworks::registry::FrameworkPackRegistry::with_builtins(),
-        }
+    let framework_registry = match rt.project_root.as_deref() {
+        Some(root) => drift_analysis::frameworks::registry::FrameworkPackRegistry::for_project(root),
+        None => drift_analysis::frameworks::registry::FrameworkPackRegistry::with_builtins(),
     };
     let framework_packs = framework_registry.into_packs();
     let framework_packs_for_learner = framework_packs.clone();
@@ -152,7 +163,11 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     let mut all_matches: Vec<drift_analysis::engine::types::PatternMatch> = Vec::new();
     let mut detection_rows: Vec<drift_storage::batch::commands::DetectionRow> = Vec::new();
     let mut function_rows: Vec<drift_storage::batch::commands::FunctionRow> = Vec::new();
+    let mut function_complexity: Vec<drift_analysis::structural::complexity::FunctionComplexity> = Vec::new();
+    let mut clone_detector = drift_analysis::structural::clones::CloneDetector::default();
     let mut all_parse_results: Vec<drift_analysis::parsers::ParseResult> = Vec::new();
+    // Embedded SQL per file, from the pipeline; feeds data access (5i) and taint sinks (6a).
+    let mut all_embedded_sql: Vec<drift_analysis::language_provider::embedded_sql::EmbeddedSqlResult> = Vec::new();
     // File content cache — read once in Phase 1, reused in Phase 3+ sub-steps.
     // Eliminates ~15,000 redundant disk reads (9 sub-steps × 1700 files).
     let mut file_contents: std::collections::HashMap<String, String> = std::collections::HashMap::new();
@@ -200,13 +215,19 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
         // Run the 4-phase analysis pipeline
         let mut resolution_index = drift_analysis::engine::ResolutionIndex::new();
-        let result = analysis_pipeline.analyze_file(
+        let mut result = analysis_pipeline.analyze_file(
             &parse_result,
             &source,
             &tree,
             &mut resolution_index,
         );
 
+        // Per-function complexity metrics from the same tree
+        function_complexity.extend(drift_analysis::structural::complexity::analyze_file(
+            &parse_result, &tree, &source,
+        ));
+        clone_detector.add_file(&parse_result, &tree, &source);
+
         // Run framework pattern matcher + learner on this file's ParseResult
         {
             use drift_analysis::engine::visitor::{FileDetectorHandler, LearningDetectorHandler};
@@ -225,6 +246,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
         // Collect parse results for cross-file analyses (boundaries, call graph)
         all_parse_results.push(parse_result.clone());
+        all_embedded_sql.push(std::mem::take(&mut result.embedded_sql));
 
         // Collect matches for pattern intelligence
         all_matches.extend(result.matches.iter().cloned());
@@ -394,6 +416,42 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     // Flush phase 1 results before continuing
     rt.storage.flush_batch_sync().map_err(storage_err)?;
 
+    // Step 3a: Function complexity → function_complexity table
+    let complexity_rows: Vec<drift_storage::queries::complexity::FunctionComplexityRow> = function_complexity
+        .iter()
+        .map(|f| drift_storage::queries::complexity::FunctionComplexityRow {
+            id: 0,
+            file: f.file.clone(),
+            name: f.name.clone(),
+            qualified_name: f.qualified_name.clone(),
+            line: f.line as i64,
+            end_line: f.end_line as i64,
+            cyclomatic: f.cyclomatic as i64,
+            cognitive: f.cognitive as i64,
+            max_nesting: f.max_nesting as i64,
+            loc: f.loc as i64,
+            halstead_volume: f.halstead.volume,
+            halstead_difficulty: f.halstead.difficulty,
+            halstead_effort: f.halstead.effort,
+            halstead_bugs: f.halstead.estimated_bugs,
+            maintainability_index: f.maintainability_index,
+            created_at: 0,
+        })
+        .collect();
+    rt.storage
+        .with_writer(|conn| drift_storage::queries::complexity::replace_all(conn, &complexity_rows))
+        .map_err(storage_err)?;
+    let complexity_index = drift_analysis::structural::complexity::ComplexityIndex::from_functions(function_complexity);
+
+    // Code clones from the same trees — feed decomposition (5k) and the duplication gate (7)
+    let clone_report = clone_detector.detect();
+    drop(clone_detector);
+    drift_log!(
+        "[drift-analyze] clones: {} classes, {:.1}% duplicated",
+        clone_report.classes.len(),
+        clone_report.duplication_percentage(),
+    );
+
     // ── Phase 1 complete: parse + detect ──
     if max_phase < 2 {
         return Ok(all_results);
@@ -403,7 +461,10 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     if !all_parse_results.is_empty() {
         // Boundary detection → persist boundary rows
         let boundary_detector = drift_analysis::boundaries::BoundaryDetector::new();
-        if let Ok(boundary_result) = boundary_detector.detect(&all_parse_results) {
+        let migrations = project_root
+            .map(drift_analysis::boundaries::schema::discover_migrations)
+            .unwrap_or_default();
+        if let Ok(boundary_result) = boundary_detector.detect_with_migrations(&all_parse_results, &file_contents, &migrations) {
             let mut boundary_rows: Vec<drift_storage::batch::commands::BoundaryRow> = Vec::new();
 
             for model in &boundary_result.models {
@@ -419,6 +480,21 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 });
             }
 
+            // Tables reconstructed from migrations, one row per table.
+            if let Some(schema) = &boundary_result.schema {
+                for table in schema.tables.values() {
+                    boundary_rows.push(drift_storage::batch::commands::BoundaryRow {
+                        file: table.file.clone(),
+                        framework: "migration".to_string(),
+                        model_name: table.name.clone(),
+                        table_name: Some(table.name.clone()),
+                        field_name: None,
+                        sensitivity: None,
+                        confidence: 0.95,
+                    });
+                }
+            }
+
             for sf in &boundary_result.sensitive_fields {
                 boundary_rows.push(drift_storage::batch::commands::BoundaryRow {
                     file: sf.file.clone(),
@@ -437,6 +513,24 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 ).map_err(storage_err)?;
             }
 
+            // Schema drift is replaced wholesale so a fixed model clears its finding.
+            let drift_rows: Vec<drift_storage::queries::boundaries::SchemaDriftRow> = boundary_result
+                .schema_drift
+                .iter()
+                .map(|d| drift_storage::queries::boundaries::SchemaDriftRow {
+                    kind: d.kind.name().to_string(),
+                    model_name: d.model.clone(),
+                    table_name: d.table.clone(),
+                    column_name: d.column.clone(),
+                    file: d.file.clone(),
+                    line: d.line as i64,
+                    message: d.message.clone(),
+                })
+                .collect();
+            rt.storage
+                .with_writer(|conn| drift_storage::queries::boundaries::replace_schema_drift(conn, &drift_rows))
+                .map_err(storage_err)?;
+
             // BW-EVT-04: Fire on_boundary_discovered for each detected boundary model
             {
                 use drift_core::events::types::BoundaryDiscoveredEvent;
@@ -482,6 +576,84 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         }
     }
 
+    // Step 3c: Dependency inventory — lockfiles + manifests per workspace package
+    let mut dependency_inputs: Vec<drift_analysis::structural::owasp_cwe::vulnerable_components::DependencyInput> = Vec::new();
+    if let Some(root) = project_root {
+        match drift_context::packages::build_inventory(root) {
+            Ok(graphs) => {
+                dependency_inputs = graphs
+                    .iter()
+                    .filter_map(|graph| Some((graph, graph.manager.osv_ecosystem()?)))
+                    .flat_map(|(graph, ecosystem)| {
+                        graph.dependencies.iter().filter_map(move |dep| {
+                            Some(drift_analysis::structural::owasp_cwe::vulnerable_components::DependencyInput {
+                                package: graph.package.clone(),
+                                package_path: graph.path.clone(),
+                                lockfile: graph.lockfile.clone(),
+                                ecosystem: ecosystem.to_string(),
+                                name: dep.name.clone(),
+                                version: dep.version.clone()?,
+                                purl: dep.purl.clone(),
+                                direct: dep.direct,
+                                dev: dep.dev,
+                            })
+                        })
+                    })
+                    .collect();
+                let rows: Vec<drift_storage::queries::dependencies::DependencyRow> = graphs
+                    .iter()
+                    .flat_map(|graph| {
+                        graph.dependencies.iter().map(move |dep| drift_storage::queries::dependencies::DependencyRow {
+                            id: 0,
+                            package: graph.package.clone(),
+                            package_path: graph.path.clone(),
+                            manager: graph.manager.name().to_string(),
+                            lockfile: graph.lockfile.clone(),
+                            name: dep.name.clone(),
+                            version: dep.version.clone(),
+                            purl: dep.purl.clone(),
+                            direct: dep.direct,
+                            dev: dep.dev,
+                            checksum: dep.checksum.clone(),
+                            depends_on: dep.depends_on.clone(),
+                            created_at: 0,
+                        })
+                    })
+                    .collect();
+                rt.storage
+                    .with_writer(|conn| drift_storage::queries::dependencies::replace_all(conn, &rows))
+                    .map_err(storage_err)?;
+            }
+            Err(e) => eprintln!("[drift-analyze] warning: dependency inventory failed: {e}"),
+        }
+    }
+
+    // Step 3d: Git history — churn, coupling, hotspots, ownership → history tables
+    let mut churn_index = drift_analysis::advanced::history::ChurnIndex::new();
+    let history_max_commits = rt.config.analysis.effective_history_max_commits();
+    if let Some(root) = project_root.filter(|_| history_max_commits > 0) {
+        use drift_analysis::advanced::history;
+        let analyzer = drift_analysis::advanced::decisions::GitAnalyzer::new()
+            .with_max_commits(history_max_commits as usize);
+        match analyzer.project_changes(root) {
+            Ok(commits) => {
+                let spans: Vec<history::FunctionSpan> = complexity_index
+                    .iter()
+                    .map(|f| history::FunctionSpan {
+                        file: f.file.clone(),
+                        name: f.name.clone(),
+                        line: f.line,
+                        end_line: f.end_line,
+                    })
+                    .collect();
+                let report = history::analyze(&commits, &spans, &complexity_index, &history::HistoryConfig::default());
+                churn_index = history::ChurnIndex::from_function_churn(&report.function_churn);
+                persist_history(&rt, &report, root, &files)?;
+            }
+            Err(e) => eprintln!("[drift-analyze] warning: history analysis skipped: {e}"),
+        }
+    }
+
     // ── Phase 2 complete: cross-file analysis ──
     if max_phase < 3 {
         rt.storage.flush_batch_sync().map_err(storage_err)?;
@@ -879,6 +1051,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                     .unwrap_or(0);
                 inv_detector.add_file(&pr.file, funcs, imports, line_count);
             }
+            inv_detector.add_complexity(complexity_index.iter().cloned());
 
             // Build store + verifier, run, persist results
             let mut store = drift_analysis::structural::constraints::store::ConstraintStore::new();
@@ -946,7 +1119,8 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         // 5i: Data access tracking → data_access table (from DataAccess-category detections)
         let mut da_rows: Vec<drift_storage::batch::commands::DataAccessInsertRow> = Vec::new();
         for m in &all_matches {
-            if format!("{:?}", m.category) == "DataAccess" {
+            // Embedded SQL findings are recorded per table below.
+            if format!("{:?}", m.category) == "DataAccess" && !m.pattern_id.starts_with("DA-SQLI") {
                 // Extract operation and table from matched_text (e.g. "ORM call: findAll", "raw query: db.query")
                 let operation = if m.pattern_id.starts_with("DA-RAW") {
                     "raw_query"
@@ -966,6 +1140,19 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 });
             }
         }
+        // Tables and operations referenced by SQL strings embedded in application code
+        let embedded_rows = all_embedded_sql.iter()
+            .flat_map(|sql| sql.queries.iter())
+            .flat_map(|q| q.access_patterns())
+            .map(|p| drift_storage::batch::commands::DataAccessInsertRow {
+                function_id: p.line as i64,
+                table_name: p.table.unwrap_or_default(),
+                operation: p.operation.name().to_string(),
+                framework: Some(p.framework),
+                line: p.line as i64,
+                confidence: p.confidence as f64,
+            });
+        da_rows.extend(embedded_rows);
         if !da_rows.is_empty() {
             rt.storage.send_batch(
                 drift_storage::batch::commands::BatchCommand::InsertDataAccess(da_rows),
@@ -1060,9 +1247,17 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 functions: decomp_functions,
             };
 
-            let modules = drift_analysis::structural::decomposition::decomposer::decompose_with_priors(
+            let mut modules = drift_analysis::structural::decomposition::decomposer::decompose_with_priors(
                 &decomp_input, &[],
             );
+            drift_analysis::structural::decomposition::apply_duplication(&mut modules, &clone_report.files);
+            if let Some(worst) = modules.iter().max_by(|a, b| a.duplication.total_cmp(&b.duplication)) {
+                drift_log!(
+                    "[drift-analyze] 5k most duplicated module: {} ({:.1}%)",
+                    worst.name,
+                    worst.duplication,
+                );
+            }
 
             // Persist applied priors as decomposition decisions
             let mut decision_rows: Vec<drift_storage::batch::commands::DecompositionDecisionInsertRow> = Vec::new();
@@ -1163,7 +1358,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             }
 
             // Run BE↔FE matching
-            let backend_frameworks = ["express", "fastify", "nestjs", "spring", "flask", "django", "rails", "laravel", "gin", "actix", "aspnet", "nextjs"];
+            let backend_frameworks = drift_analysis::structural::contracts::extractors::BACKEND_FRAMEWORKS;
             let frontend_frameworks = ["frontend"];
             let backend_eps: Vec<drift_analysis::structural::contracts::types::Endpoint> = all_contract_endpoints.iter()
                 .filter(|(fw, _)| backend_frameworks.contains(&fw.as_str()))
@@ -1203,6 +1398,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     }
 
     // Step 6: Graph intelligence — taint, error handling, impact, test topology
+    let mut vulnerable_dependencies = Vec::new();
     if !all_parse_results.is_empty() {
         // Re-build call graph (or reuse from Step 3b if we stored it)
         let cg_builder = drift_analysis::call_graph::CallGraphBuilder::new();
@@ -1210,7 +1406,13 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
         if let Ok((ref call_graph, ref _cg_stats)) = call_graph_result {
             // 6a: Taint analysis → taint_flows table
-            let taint_registry = drift_analysis::graph::taint::TaintRegistry::with_defaults();
+            // Database APIs that embedded SQL showed taking spliced queries are sinks too.
+            let mut taint_registry = drift_analysis::graph::taint::TaintRegistry::with_defaults();
+            for sql in &all_embedded_sql {
+                drift_analysis::language_provider::embedded_sql::register_taint_sinks(
+                    &mut taint_registry, &sql.injection_candidates,
+                );
+            }
 
             // Phase 1: intraprocedural (per-file)
             let mut all_taint_flows = Vec::new();
@@ -1278,7 +1480,13 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             }
 
             // 6c: Impact analysis → impact_scores table
-            let blast_radii = drift_analysis::graph::impact::blast_radius::compute_all_blast_radii(call_graph);
+            let blast_radii = drift_analysis::graph::impact::blast_radius::compute_all_blast_radii(
+                call_graph,
+                Some(&drift_analysis::graph::impact::ImpactMetrics {
+                    complexity: Some(&complexity_index),
+                    churn: Some(&churn_index),
+                }),
+            );
             let dead_code = drift_analysis::graph::impact::dead_code::detect_dead_code(call_graph);
 
             let mut impact_rows: Vec<drift_storage::batch::commands::ImpactScoreInsertRow> = Vec::new();
@@ -1400,6 +1608,42 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                     drift_storage::batch::commands::BatchCommand::InsertReachabilityCache(reach_rows),
                 ).map_err(storage_err)?;
             }
+
+            // 6f: Vulnerable dependencies — local OSV dump + call-graph reachability → owasp_findings table
+            if let Some(root) = project_root.filter(|_| !dependency_inputs.is_empty()) {
+                use drift_analysis::structural::owasp_cwe::vulnerable_components as vc;
+                let osv_path = rt.config.analysis.effective_osv_database(root);
+                if osv_path.exists() {
+                    match vc::OsvDatabase::load(&osv_path) {
+                        Ok(db) => {
+                            vulnerable_dependencies = vc::match_dependencies(&db, &dependency_inputs);
+                            vc::assess_reachability(&mut vulnerable_dependencies, &all_parse_results, call_graph);
+                        }
+                        Err(e) => eprintln!("[drift-analyze] warning: cannot load OSV database: {e}"),
+                    }
+                }
+                let vuln_rows: Vec<drift_storage::batch::commands::OwaspFindingInsertRow> =
+                    vc::to_security_findings(&vulnerable_dependencies)
+                        .into_iter()
+                        .map(|f| drift_storage::batch::commands::OwaspFindingInsertRow {
+                            cwes: serde_json::to_string(&f.cwes.iter().map(|c| c.id.to_string()).collect::<Vec<_>>()).unwrap_or_default(),
+                            owasp_categories: f.owasp_categories.iter().map(|c| c.code()).collect::<Vec<_>>().join(","),
+                            id: f.id,
+                            detector: f.detector,
+                            file: f.file,
+                            line: f.line as i64,
+                            description: f.description,
+                            severity: f.severity,
+                            confidence: f.confidence,
+                            remediation: f.remediation,
+                        })
+                        .collect();
+                if !vuln_rows.is_empty() {
+                    rt.storage.send_batch(
+                        drift_storage::batch::commands::BatchCommand::InsertOwaspFindings(vuln_rows),
+                    ).map_err(storage_err)?;
+                }
+            }
         }
     }
 
@@ -1412,38 +1656,84 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     // Step 7: Enforcement — run quality gates, persist violations + gate results
     if !all_parse_results.is_empty() {
         use drift_analysis::enforcement::gates::{GateOrchestrator, GateInputBuilder};
-        use drift_analysis::enforcement::rules::types::PatternInfo as RulesPatternInfo;
 
         // Build GateInput from upstream analysis results
         let file_list: Vec<String> = all_parse_results.iter().map(|pr| pr.file.clone()).collect();
 
-        // Convert detection matches into PatternInfo for enforcement gates
-        let mut pattern_map: std::collections::HashMap<String, RulesPatternInfo> = std::collections::HashMap::new();
-        for m in &all_matches {
-            let entry = pattern_map.entry(m.pattern_id.clone()).or_insert_with(|| RulesPatternInfo {
-                pattern_id: m.pattern_id.clone(),
-                category: format!("{:?}", m.category),
-                confidence: m.confidence as f64,
-                locations: Vec::new(),
-                outliers: Vec::new(),
-                cwe_ids: m.cwe_ids.to_vec(),
-                owasp_categories: m.owasp.as_ref().map(|o| vec![o.clone()]).unwrap_or_default(),
-            });
-            entry.locations.push(drift_analysis::enforcement::rules::types::PatternLocation {
-                file: m.file.clone(),
-                line: m.line,
-                column: Some(m.column),
-            });
-        }
-        let patterns: Vec<RulesPatternInfo> = pattern_map.into_values().collect();
-
         let gate_input = GateInputBuilder::new()
             .files(file_list)
-            .patterns(patterns)
+            .patterns_from_matches(&all_matches)
+            .security_findings_from_vulnerable_dependencies(&vulnerable_dependencies)
+            .duplication_from_clones(&clone_report, rt.config.quality_gates.effective_max_duplication())
             .build();
 
         let orchestrator = GateOrchestrator::new();
-        if let Ok(gate_results) = orchestrator.execute(&gate_input) {
+        if let Ok(mut gate_results) = orchestrator.execute(&gate_input) {
+            // Route violations to their CODEOWNERS owners
+            if let Some(root) = project_root {
+                if let Some(codeowners) = drift_analysis::enforcement::codeowners::CodeOwners::discover(root) {
+                    codeowners.assign(root, &mut gate_results);
+                }
+            }
+
+            // Scoped policies — evaluate each path/package scope on its own files
+            let mut policy_scope_rows: Vec<drift_storage::queries::enforcement::PolicyScopeRow> = Vec::new();
+            let mut governed_rows: Vec<drift_storage::queries::enforcement::GovernedViolationRow> = Vec::new();
+            if !rt.config.quality_gates.policy_overrides.is_empty() {
+                use drift_analysis::enforcement::policy::{ScopedPolicy, ScopedPolicyEngine};
+                use drift_core::workspace::monorepo::{detect_workspace, WorkspaceLayout};
+                let packages = match project_root.map(detect_workspace) {
+                    Some(Ok(WorkspaceLayout::Monorepo { packages, .. })) => packages,
+                    _ => Vec::new(),
+                };
+                match ScopedPolicy::from_config(&rt.config.quality_gates, &packages) {
+                    Ok(scoped) => {
+                        let report = ScopedPolicyEngine::new(scoped).evaluate(
+                            project_root.unwrap_or(std::path::Path::new("")),
+                            &gate_results,
+                            &gate_input.files,
+                        );
+                        for scope in &report.scopes {
+                            drift_log!(
+                                "[drift-analyze] policy {} ({}, {} files): {}",
+                                scope.scope,
+                                scope.result.policy_name,
+                                scope.file_count,
+                                scope.result.details,
+                            );
+                            policy_scope_rows.push(drift_storage::queries::enforcement::PolicyScopeRow {
+                                scope: scope.scope.clone(),
+                                policy_name: scope.result.policy_name.clone(),
+                                aggregation_mode: format!("{:?}", scope.result.aggregation_mode),
+                                overall_passed: scope.result.overall_passed,
+                                overall_score: scope.result.overall_score,
+                                file_count: scope.file_count as i64,
+                                gates_passed: scope.result.gates_passed as i64,
+                                gates_failed: scope.result.gates_failed as i64,
+                                details: scope.result.details.clone(),
+                            });
+                        }
+                        governed_rows = report
+                            .violations
+                            .into_iter()
+                            .map(|v| drift_storage::queries::enforcement::GovernedViolationRow {
+                                violation_id: v.violation_id,
+                                gate_id: v.gate_id.to_string(),
+                                scope: v.scope,
+                                policy_name: v.policy_name,
+                            })
+                            .collect();
+                    }
+                    Err(e) => drift_log!("[drift-analyze] warning: policy overrides ignored: {}", e),
+                }
+            }
+            // Replaced even when empty, so removing the overrides clears a stale report
+            if let Err(e) = rt.storage.with_writer(|conn| {
+                drift_storage::queries::enforcement::replace_scoped_policy(conn, &policy_scope_rows, &governed_rows)
+            }) {
+                drift_log!("[drift-analyze] warning: scoped policy not stored: {}", e);
+            }
+
             // Collect all violations from all gates
             let mut violation_rows: Vec<drift_storage::batch::commands::ViolationInsertRow> = Vec::new();
             let mut gate_result_rows: Vec<drift_storage::batch::commands::GateResultInsertRow> = Vec::new();
@@ -1482,6 +1772,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                         owasp_category: v.owasp_category.clone(),
                         suppressed: v.suppressed,
                         is_new: v.is_new,
+                        owners: v.owners.clone(),
                     });
                 }
             }
@@ -1695,6 +1986,108 @@ fn run_bridge_grounding_loop(
     Ok(())
 }
 
+/// Persist a history report, restricted to files tracked in file_metadata
+/// (history also covers deleted, ignored and non-source files).
+fn persist_history(
+    rt: &std::sync::Arc<crate::runtime::DriftRuntime>,
+    report: &drift_analysis::advanced::history::HistoryReport,
+    root: &std::path::Path,
+    files: &[drift_storage::queries::files::FileMetadataRecord],
+) -> napi::Result<()> {
+    use drift_analysis::structural::module_of;
+    use drift_storage::queries::history as hq;
+
+    // Same key space as the report: `root.join(..)`, as the files were parsed.
+    let tracked: std::collections::HashSet<String> =
+        files.iter().map(|f| root.join(&f.path).to_string_lossy().into_owned()).collect();
+    let tracked_modules: std::collections::HashSet<&str> = tracked.iter().map(|f| module_of(f)).collect();
+    let hotspots: std::collections::HashMap<&str, &drift_analysis::advanced::history::Hotspot> =
+        report.hotspots.iter().map(|h| (h.file.as_str(), h)).collect();
+
+    let file_rows: Vec<hq::FileChurnRow> = report
+        .file_churn
+        .iter()
+        .filter(|f| tracked.contains(f.file.as_str()))
+        .map(|f| {
+            let hotspot = hotspots.get(f.file.as_str());
+            hq::FileChurnRow {
+                file: f.file.clone(),
+                commits: f.commits as i64,
+                insertions: f.insertions as i64,
+                deletions: f.deletions as i64,
+                authors: f.authors as i64,
+                top_author: f.top_author.clone(),
+                top_author_share: f.top_author_share,
+                first_changed_at: f.first_changed_at,
+                last_changed_at: f.last_changed_at,
+                complexity: hotspot.map_or(0, |h| h.complexity as i64),
+                hotspot_score: hotspot.map_or(0.0, |h| h.score),
+                updated_at: 0,
+            }
+        })
+        .collect();
+    let function_rows: Vec<hq::FunctionChurnRow> = report
+        .function_churn
+        .iter()
+        .filter(|f| f.commits > 0)
+        .map(|f| hq::FunctionChurnRow {
+            id: 0,
+            file: f.file.clone(),
+            name: f.name.clone(),
+            line: f.line as i64,
+            end_line: f.end_line as i64,
+            commits: f.commits as i64,
+            lines_added: f.lines_added as i64,
+            lines_deleted: f.lines_deleted as i64,
+            last_changed_at: f.last_changed_at,
+        })
+        .collect();
+    let coupling_rows: Vec<hq::ChangeCouplingRow> = report
+        .coupling
+        .iter()
+        .filter(|c| tracked.contains(c.file_a.as_str()) && tracked.contains(c.file_b.as_str()))
+        .map(|c| hq::ChangeCouplingRow {
+            file_a: c.file_a.clone(),
+            file_b: c.file_b.clone(),
+            co_changes: c.co_changes as i64,
+            revisions_a: c.revisions_a as i64,
+            revisions_b: c.revisions_b as i64,
+            degree: c.degree,
+        })
+        .collect();
+    let ownership_rows: Vec<hq::ModuleOwnershipRow> = report
+        .ownership
+        .iter()
+        .filter(|m| tracked_modules.contains(m.module.as_str()))
+        .map(|m| hq::ModuleOwnershipRow {
+            module: m.module.clone(),
+            primary_owner: m.primary_owner().map(|a| a.author.clone()),
+            primary_share: m.primary_owner().map_or(0.0, |a| a.share),
+            bus_factor: m.bus_factor as i64,
+            contributors: m.authors.len() as i64,
+            total_changes: m.total_changes as i64,
+            authors: serde_json::to_string(&m.authors).unwrap_or_else(|_| "[]".to_string()),
+        })
+        .collect();
+
+    rt.storage
+        .with_writer(|conn| {
+            hq::replace_file_churn(conn, &file_rows)?;
+            hq::replace_function_churn(conn, &function_rows)?;
+            hq::replace_coupling(conn, &coupling_rows)?;
+            hq::replace_ownership(conn, &ownership_rows)
+        })
+        .map_err(storage_err)?;
+    drift_log!(
+        "[drift-analyze] 3d (history): {} commits, {} files, {} coupled pairs, {} modules",
+        report.commits_analyzed,
+        file_rows.len(),
+        coupling_rows.len(),
+        ownership_rows.len(),
+    );
+    Ok(())
+}
+
 /// Build or query the call graph.
 #[napi]
 pub async fn drift_call_graph() -> napi::Result<JsCallGraphResult> {
@@ -1819,9 +2212,118 @@ pub async fn drift_boundaries() -> napi::Result<JsBoundaryResult> {
         }
     }).collect();
 
+    let schema_drift = rt.storage.with_reader(|conn| {
+        drift_storage::queries::boundaries::get_schema_drift(conn)
+    }).map_err(storage_err)?
+        .into_iter()
+        .map(|d| JsSchemaDrift {
+            kind: d.kind,
+            model_name: d.model_name,
+            table_name: d.table_name,
+            column_name: d.column_name,
+            file: d.file,
+            line: d.line as u32,
+            message: d.message,
+        })
+        .collect();
+
     Ok(JsBoundaryResult {
         models,
         sensitive_fields,
         frameworks_detected: all_boundaries,
+        schema_drift,
+    })
+}
+
+/// Health measured at one historical commit.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsBackfillPoint {
+    pub sha: String,
+    pub timestamp: f64,
+    pub files: u32,
+    pub files_analyzed: u32,
+    pub pattern_count: u32,
+    pub violation_count: u32,
+    pub health_score: f64,
+}
+
+/// Result of replaying analysis over past commits.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsBackfillResult {
+    pub commits_analyzed: u32,
+    pub files_analyzed: u32,
+    pub points_written: u32,
+    pub points: Vec<JsBackfillPoint>,
+}
+
+/// Replay detection, gates and health scoring over past commits and record
+/// the results in health_trends at each commit's timestamp, so trend
+/// reports have history on a freshly initialized project.
+///
+/// Only files whose blob changed between commits are re-analyzed. Points
+/// already recorded for a commit time are kept, so re-running is safe.
+///
+/// @param max_commits - First-parent commits to replay from HEAD. Default: 50.
+#[napi(js_name = "driftBackfill")]
+pub async fn drift_backfill(max_commits: Option<u32>) -> napi::Result<JsBackfillResult> {
+    let rt = runtime::get()?;
+    let root = rt.project_root.as_deref().ok_or_else(|| {
+        napi::Error::from_reason(format!(
+            "[{}] Backfill requires a project root",
+            error_codes::INVALID_ARGUMENT
+        ))
+    })?;
+
+    let registry = drift_analysis::frameworks::registry::FrameworkPackRegistry::for_project(root);
+    let config = drift_analysis::advanced::history::BackfillConfig {
+        max_commits: max_commits.unwrap_or(50) as usize,
+        scan: rt.config.scan.clone(),
+    };
+    let points = drift_analysis::advanced::history::Backfiller::new(registry.into_packs(), config)
+        .run(root)
+        .map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::ANALYSIS_ERROR)))?;
+
+    let rows: Vec<drift_storage::queries::enforcement::HealthTrendRow> = points
+        .iter()
+        .flat_map(|p| {
+            [
+                ("health_score", p.health_score),
+                ("pattern_count", p.pattern_count as f64),
+                ("violation_count", p.violation_count as f64),
+            ]
+            .map(|(metric, value)| drift_storage::queries::enforcement::HealthTrendRow {
+                metric_name: metric.to_string(),
+                metric_value: value,
+                recorded_at: p.timestamp.max(0) as u64,
+            })
+        })
+        .collect();
+    let points_written = rt.storage.with_writer(|conn| {
+        drift_storage::queries::enforcement::insert_health_trends_at(conn, &rows)
+    }).map_err(storage_err)?;
+    drift_log!(
+        "[drift-backfill] {} commits replayed, {} trend points written",
+        points.len(),
+        points_written,
+    );
+
+    Ok(JsBackfillResult {
+        commits_analyzed: points.len() as u32,
+        files_analyzed: points.iter().map(|p| p.files_analyzed as u32).sum(),
+        points_written: points_written as u32,
+        points: points
+            .into_iter()
+            .map(|p| JsBackfillPoint {
+                sha: p.sha,
+                timestamp: p.timestamp as f64,
+                files: p.files as u32,
+                files_analyzed: p.files_analyzed as u32,
+                pattern_count: p.pattern_count as u32,
+                violation_count: p.violation_count as u32,
+                health_score: p.health_score,
+            })
+            .collect(),
     })
 }
@@ -32,6 +32,7 @@ pub struct JsViolation {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    pub owners: Vec<String>,
 }
 
 // ─── Gate Result Types ───────────────────────────────────────────────
@@ -58,6 +59,58 @@ pub struct JsCheckResult {
     pub total_violations: u32,
     pub gates: Vec<JsGateResult>,
     pub sarif: Option<String>,
+    /// Scoped policy evaluation, when `quality_gates.policy_overrides` is set.
+    pub policy: Option<JsScopedPolicyReport>,
+    /// Violations per CODEOWNERS owner, most first (`"unowned"` for the rest).
+    pub owners: Vec<JsOwnerSummary>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsOwnerSummary {
+    pub owner: String,
+    pub violations: u32,
+    pub errors: u32,
+    pub warnings: u32,
+    pub new_violations: u32,
+    /// Gates with at least one of the owner's violations.
+    pub gates: Vec<String>,
+    pub files: Vec<String>,
+}
+
+// ─── Scoped Policy Types ─────────────────────────────────────────────
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsPolicyScope {
+    pub scope: String,
+    pub policy_name: String,
+    pub aggregation_mode: String,
+    pub overall_passed: bool,
+    pub overall_score: f64,
+    pub file_count: u32,
+    pub gates_passed: u32,
+    pub gates_failed: u32,
+    pub details: String,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsGovernedViolation {
+    pub violation_id: String,
+    pub gate_id: String,
+    pub scope: String,
+    pub policy_name: String,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsScopedPolicyReport {
+    /// Every scope passed.
+    pub overall_passed: bool,
+    /// Root first, then scopes by label.
+    pub scopes: Vec<JsPolicyScope>,
+    pub violations: Vec<JsGovernedViolation>,
 }
 
 // ─── Audit Types ─────────────────────────────────────────────────────
@@ -116,23 +169,76 @@ pub fn drift_check(_root: String) -> napi::Result<JsCheckResult> {
     let active_gates: Vec<&JsGateResult> = js_gates.iter()
         .filter(|g| g.status != "skipped" && g.status != "no_data")
         .collect();
-    let overall_passed = active_gates.is_empty() || active_gates.iter().all(|g| g.passed);
+    let mut overall_passed = active_gates.is_empty() || active_gates.iter().all(|g| g.passed);
+
+    // With policy overrides, each scope is judged on its own files instead
+    let policy = query_scoped_policy(&rt)?;
+    if let Some(policy) = &policy {
+        overall_passed = policy.overall_passed;
+    }
 
     // PH2-04: Generate SARIF inline
+    let gate_results = storage_to_gate_results(&violations, &gates);
     let sarif = drift_analysis::enforcement::reporters::create_reporter("sarif")
-        .and_then(|reporter| {
-            let gate_results = storage_to_gate_results(&violations, &gates);
-            reporter.generate(&gate_results).ok()
-        });
+        .and_then(|reporter| reporter.generate(&gate_results).ok());
+
+    let owners = drift_analysis::enforcement::codeowners::summarize_by_owner(&gate_results)
+        .into_iter()
+        .map(|o| JsOwnerSummary {
+            owner: o.owner,
+            violations: o.violations as u32,
+            errors: o.errors as u32,
+            warnings: o.warnings as u32,
+            new_violations: o.new_violations as u32,
+            gates: o.gates.iter().map(|g| g.gate_id.to_string()).collect(),
+            files: o.files,
+        })
+        .collect();
 
     Ok(JsCheckResult {
         overall_passed,
         total_violations: violations.len() as u32,
         gates: js_gates,
         sarif,
+        policy,
+        owners,
     })
 }
 
+/// The stored scoped policy evaluation; `None` when no overrides were applied.
+fn query_scoped_policy(rt: &runtime::DriftRuntime) -> napi::Result<Option<JsScopedPolicyReport>> {
+    let (scopes, violations) = rt.storage.with_reader(|conn| {
+        Ok((
+            drift_storage::queries::enforcement::query_policy_scopes(conn)?,
+            drift_storage::queries::enforcement::query_governed_violations(conn)?,
+        ))
+    }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
+    if scopes.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(JsScopedPolicyReport {
+        overall_passed: scopes.iter().all(|s| s.overall_passed),
+        scopes: scopes.into_iter().map(|s| JsPolicyScope {
+            scope: s.scope,
+            policy_name: s.policy_name,
+            aggregation_mode: s.aggregation_mode,
+            overall_passed: s.overall_passed,
+            overall_score: s.overall_score,
+            file_count: s.file_count as u32,
+            gates_passed: s.gates_passed as u32,
+            gates_failed: s.gates_failed as u32,
+            details: s.details,
+        }).collect(),
+        violations: violations.into_iter().map(|v| JsGovernedViolation {
+            violation_id: v.violation_id,
+            gate_id: v.gate_id,
+            scope: v.scope,
+            policy_name: v.policy_name,
+        }).collect(),
+    }))
+}
+
 /// Run audit analysis on the project.
 ///
 /// Wires the full upstream pipeline:
@@ -329,13 +435,15 @@ pub fn drift_audit(_root: String) -> napi::Result<JsAuditResult> {
     })
 }
 
-/// Query violations for the project.
+/// Query violations for the project, optionally only those of one
+/// CODEOWNERS owner (`"unowned"` for violations without owners).
 #[napi]
-pub fn drift_violations(_root: String) -> napi::Result<Vec<JsViolation>> {
+pub fn drift_violations(_root: String, owner: Option<String>) -> napi::Result<Vec<JsViolation>> {
     let rt = runtime::get()?;
 
-    let rows = rt.storage.with_reader(|conn| {
-        drift_storage::queries::enforcement::query_all_violations(conn)
+    let rows = rt.storage.with_reader(|conn| match owner.as_deref() {
+        Some(owner) => drift_storage::queries::enforcement::query_violations_by_owner(conn, owner),
+        None => drift_storage::queries::enforcement::query_all_violations(conn),
     }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
 
     Ok(rows.into_iter().map(|v| JsViolation {
@@ -355,14 +463,16 @@ pub fn drift_violations(_root: String) -> napi::Result<Vec<JsViolation>> {
         owasp_category: v.owasp_category,
         suppressed: v.suppressed,
         is_new: v.is_new,
+        owners: v.owners,
     }).collect())
 }
 
 /// Generate a report in the specified format from stored violations and gate results.
 ///
 /// Supported formats: "sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab"
+/// With `owner`, only that CODEOWNERS owner's violations are reported.
 #[napi]
-pub fn drift_report(format: String) -> napi::Result<String> {
+pub fn drift_report(format: String, owner: Option<String>) -> napi::Result<String> {
     let rt = runtime::get()?;
 
     let violations = rt.storage.with_reader(|conn| {
@@ -374,7 +484,10 @@ pub fn drift_report(format: String) -> napi::Result<String> {
     }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
 
     // Convert storage rows to enforcement gate results
-    let gate_results = storage_to_gate_results(&violations, &gates);
+    let mut gate_results = storage_to_gate_results(&violations, &gates);
+    if let Some(ref owner) = owner {
+        gate_results = drift_analysis::enforcement::reporters::filter_by_owner(&gate_results, owner);
+    }
 
     // Create reporter and generate output
     let reporter = drift_analysis::enforcement::reporters::create_reporter(&format)
@@ -435,6 +548,7 @@ fn storage_to_gate_results(
                     replacement: None,
                 })
             }),
+            owners: v.owners.clone(),
         }
     }).collect();
 
@@ -467,6 +581,7 @@ fn storage_to_gate_results(
             "test-coverage" => GateId::TestCoverage,
             "error-handling" => GateId::ErrorHandling,
             "regression" => GateId::Regression,
+            "duplication" => GateId::Duplication,
             _ => GateId::PatternCompliance,
         };
         let status = match g.status.as_str() {
@@ -230,7 +230,7 @@ fn load_cached_metadata(
         } else {
             0
         };
-        let language = record.language.as_deref().and_then(language_from_name);
+        let language = record.language.as_deref().and_then(Language::from_name);
         cached.insert(
             path.clone(),
             CachedFileMetadata {
@@ -246,27 +246,6 @@ fn load_cached_metadata(
     Ok(cached)
 }
 
-/// Convert a language display name back to a Language enum.
-fn language_from_name(name: &str) -> Option<Language> {
-    match name {
-        "TypeScript" => Some(Language::TypeScript),
-        "JavaScript" => Some(Language::JavaScript),
-        "Python" => Some(Language::Python),
-        "Java" => Some(Language::Java),
-        "C#" => Some(Language::CSharp),
-        "Go" => Some(Language::Go),
-        "Rust" => Some(Language::Rust),
-        "Ruby" => Some(Language::Ruby),
-        "PHP" => Some(Language::Php),
-        "Kotlin" => Some(Language::Kotlin),
-        "C++" => Some(Language::Cpp),
-        "C" => Some(Language::C),
-        "Swift" => Some(Language::Swift),
-        "Scala" => Some(Language::Scala),
-        _ => None,
-    }
-}
-
 // ---- Storage persistence ----
 
 /// Persist scan results to drift.db via the batch writer.
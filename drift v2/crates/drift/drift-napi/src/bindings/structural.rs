@@ -249,7 +249,7 @@ pub fn drift_contract_tracking(root: String) -> napi::Result<JsContractResult> {
     }
 
     // CE-E-01: Run BE↔FE matching to detect mismatches.
-    let backend_frameworks = ["express", "fastify", "nestjs", "spring", "flask", "django", "rails", "laravel", "gin", "actix", "aspnet", "nextjs"];
+    let backend_frameworks = drift_analysis::structural::contracts::extractors::BACKEND_FRAMEWORKS;
     let frontend_frameworks = ["frontend"];
     let backend_eps: Vec<Endpoint> = all_endpoints.iter()
         .filter(|(fw, _)| backend_frameworks.contains(&fw.as_str()))
@@ -325,8 +325,7 @@ fn count_paradigms(frameworks: &std::collections::HashSet<String>) -> u32 {
     let mut paradigms = std::collections::HashSet::new();
     for fw in frameworks {
         match fw.as_str() {
-            "express" | "fastify" | "nestjs" | "spring" | "flask" | "django"
-            | "rails" | "laravel" | "gin" | "actix" | "aspnet" | "nextjs" => {
+            f if drift_analysis::structural::contracts::extractors::BACKEND_FRAMEWORKS.contains(&f) => {
                 paradigms.insert("rest");
             }
             "trpc" => { paradigms.insert("rpc"); }
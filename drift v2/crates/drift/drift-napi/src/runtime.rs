@@ -10,7 +10,9 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use drift_core::config::DriftConfig;
+use drift_context::generation::deduplication::ContextSession;
 use drift_core::events::dispatcher::EventDispatcher;
+use drift_storage::connection::encryption::DatabaseKey;
 use drift_storage::DriftStorageEngine;
 
 use cortex_drift_bridge::BridgeConfig;
@@ -69,6 +71,9 @@ pub struct DriftRuntime {
     pub drift_db_for_bridge: Option<Mutex<rusqlite::Connection>>,
     // ─── Bridge event pipeline (Phase B) ─────────────────────────────────
     pub bridge_deduplicator: Mutex<EventDeduplicator>,
+    /// Files the agent has named across repo map requests, so follow-up
+    /// maps stay biased toward them.
+    pub context_session: Mutex<ContextSession>,
 }
 
 /// Options for initializing the runtime.
@@ -151,13 +156,19 @@ impl DriftRuntime {
             }
         };
 
-        // Open the unified storage engine (DatabaseManager + BatchWriter)
-        let storage = DriftStorageEngine::open(&db_path).map_err(|e| {
-            napi::Error::from_reason(format!(
-                "[{}] {e}",
-                error_codes::STORAGE_ERROR
-            ))
-        })?;
+        // Open the unified storage engine (DatabaseManager + BatchWriter).
+        // DRIFT_DB_KEY / DRIFT_DB_KEYFILE switch it to encrypted-at-rest mode.
+        let storage = DatabaseKey::from_env()
+            .and_then(|key| match key {
+                Some(key) => DriftStorageEngine::open_encrypted(&db_path, key),
+                None => DriftStorageEngine::open(&db_path),
+            })
+            .map_err(|e| {
+                napi::Error::from_reason(format!(
+                    "[{}] {e}",
+                    error_codes::STORAGE_ERROR
+                ))
+            })?;
 
         let mut dispatcher = EventDispatcher::new();
 
@@ -178,12 +189,18 @@ impl DriftRuntime {
         // Optionally open cortex.db for dual-write (P0-3).
         // When cortex_db_path is provided and the file exists, bridge memories
         // are also written to cortex.db so they are visible to Cortex retrieval.
+        // An encrypted cortex.db is keyed from CORTEX_DB_KEYFILE / CORTEX_DB_KEY.
         let cortex_writer: Option<Arc<dyn CortexMemoryWriter>> = opts
             .cortex_db_path
             .as_ref()
             .filter(|p| p.exists())
             .and_then(|cortex_path| {
-                match cortex_storage::StorageEngine::open(cortex_path) {
+                let opened = cortex_storage::encryption::resolve_key(&Default::default())
+                    .and_then(|key| match key {
+                        Some(key) => cortex_storage::StorageEngine::open_encrypted(cortex_path, &key),
+                        None => cortex_storage::StorageEngine::open(cortex_path),
+                    });
+                match opened {
                     Ok(engine) => {
                         tracing::info!(
                             path = %cortex_path.display(),
@@ -263,6 +280,7 @@ impl DriftRuntime {
             bridge_initialized,
             drift_db_for_bridge,
             bridge_deduplicator: Mutex::new(EventDeduplicator::new()),
+            context_session: Mutex::new(ContextSession::new("napi")),
         })
     }
 
@@ -0,0 +1,113 @@
+//! Git history joined to analysis data the way `drift_analyze` (Step 3d)
+//! does it: files are scanned and parsed under an absolute project root that
+//! sits in a subdirectory of its repository, so git's repo-relative paths
+//! must be rebased before churn, hotspots and function churn line up.
+
+use std::path::Path;
+
+use drift_analysis::advanced::decisions::GitAnalyzer;
+use drift_analysis::advanced::history::{self, ChurnIndex, FunctionSpan, HistoryConfig};
+use drift_analysis::parsers::ParserManager;
+use drift_analysis::scanner::Scanner;
+use drift_analysis::structural::complexity::{analyze_file, ComplexityIndex};
+use drift_core::config::ScanConfig;
+use drift_core::events::handler::DriftEventHandler;
+use drift_core::types::collections::FxHashMap;
+
+struct NoOpHandler;
+impl DriftEventHandler for NoOpHandler {}
+
+const APP_V1: &str = r#"export function route(req: any) {
+  if (req.admin) {
+    return "admin";
+  }
+  return "user";
+}
+"#;
+
+const APP_V2: &str = r#"export function route(req: any) {
+  if (req.admin) {
+    return "admin";
+  }
+  if (req.guest) {
+    return "guest";
+  }
+  return "user";
+}
+"#;
+
+fn git_commit(repo: &git2::Repository, files: &[(&str, &str)], author: &str, time: i64) {
+    let workdir = repo.workdir().unwrap();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        let full = workdir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        index.add_path(Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::new(author, &format!("{author}@example.com"), &git2::Time::new(time, 0)).unwrap();
+    let parents: Vec<git2::Commit> = repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, "change", &tree, &parents).unwrap();
+}
+
+#[test]
+fn history_joins_analysis_under_absolute_subdirectory_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    git_commit(
+        &repo,
+        &[("packages/app/src/app.ts", APP_V1), ("packages/other/src/app.ts", APP_V1)],
+        "alice",
+        1_700_000_000,
+    );
+    git_commit(
+        &repo,
+        &[("packages/app/src/app.ts", APP_V2), ("packages/other/src/app.ts", APP_V2)],
+        "bob",
+        1_700_001_000,
+    );
+
+    let root = dir.path().join("packages").join("app");
+    assert!(root.is_absolute());
+
+    // Scan + parse as drift_analyze does: file_metadata paths joined to the root.
+    let diff = Scanner::new(ScanConfig::default())
+        .scan(&root, &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+    let parser = ParserManager::new();
+    let mut functions = Vec::new();
+    for entry in diff.entries.values() {
+        let file_path = root.join(&entry.path);
+        let source = std::fs::read(&file_path).unwrap();
+        let (pr, tree) = parser.parse_returning_tree(&source, &file_path).unwrap();
+        functions.extend(analyze_file(&pr, &tree, &source));
+    }
+    let complexity = ComplexityIndex::from_functions(functions);
+    let app = root.join("src").join("app.ts").to_string_lossy().into_owned();
+    assert!(complexity.has_file(&app));
+
+    let commits = GitAnalyzer::new().project_changes(&root).unwrap();
+    assert_eq!(commits.len(), 2);
+    assert!(
+        commits.iter().flat_map(|c| &c.files).all(|f| f.path == app),
+        "changes outside the project root are dropped and the rest rebased"
+    );
+
+    let spans: Vec<FunctionSpan> = complexity
+        .iter()
+        .map(|f| FunctionSpan { file: f.file.clone(), name: f.name.clone(), line: f.line, end_line: f.end_line })
+        .collect();
+    let report = history::analyze(&commits, &spans, &complexity, &HistoryConfig::default());
+
+    assert_eq!(report.file_churn.len(), 1);
+    assert_eq!((report.file_churn[0].file.as_str(), report.file_churn[0].commits), (app.as_str(), 2));
+    assert_eq!(report.hotspots.len(), 1);
+    assert_eq!(report.hotspots[0].file, app);
+    let route = report.function_churn.iter().find(|f| f.name == "route").unwrap();
+    assert_eq!(route.commits, 2);
+    let churn = ChurnIndex::from_function_churn(&report.function_churn);
+    assert!(churn.change_frequency(&app, route.line).is_some_and(|f| f > 0.0));
+}
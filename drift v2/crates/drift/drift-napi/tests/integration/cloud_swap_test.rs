@@ -261,6 +261,9 @@ impl IDriftEnforcement for MockDriftStorage {
     fn query_all_violations(&self) -> Result<Vec<ViolationRow>, StorageError> {
         Ok(self.violations.lock().unwrap().clone())
     }
+    fn query_violations_by_owner(&self, owner: &str) -> Result<Vec<ViolationRow>, StorageError> {
+        Ok(self.violations.lock().unwrap().iter().filter(|v| v.owners.iter().any(|o| o == owner)).cloned().collect())
+    }
     fn insert_gate_result(&self, _g: &GateResultRow) -> Result<(), StorageError> { Ok(()) }
     fn query_gate_results(&self) -> Result<Vec<GateResultRow>, StorageError> { Ok(vec![]) }
     fn insert_audit_snapshot(&self, _s: &AuditSnapshotRow) -> Result<(), StorageError> { Ok(()) }
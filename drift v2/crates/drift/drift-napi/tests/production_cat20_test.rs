@@ -78,6 +78,7 @@ fn t20_01_mcp_entry_point_contracts() {
         rule_id: "r-test".into(), message: "test violation".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // 1. drift_scan underlying: file metadata query
@@ -398,6 +399,7 @@ fn t20_06_analyze_populates_db() {
         quick_fix_description: Some("Add explicit type".into()),
         cwe_id: Some(79), owasp_category: Some("A03".into()),
         suppressed: false, is_new: true,
+        owners: Vec::new(),
     }).unwrap();
 
     // After analysis: drift status must show non-zero
@@ -0,0 +1,52 @@
+"""Drift analysis engine.
+
+Scan and parse source trees, query call graphs, trace taint flows and run
+quality gates::
+
+    import drift
+
+    project = drift.Project("path/to/repo")
+    graph = project.call_graph()
+    for flow in project.taint_flows():
+        if flow.is_vulnerability:
+            print(flow)
+    failed = [g for g in project.run_gates() if not g.passed]
+"""
+
+from ._native import (
+    CallGraph,
+    CallSite,
+    Class,
+    DriftError,
+    Function,
+    GateResult,
+    Import,
+    ParseResult,
+    PatternMatch,
+    Project,
+    ScanSummary,
+    TaintFlow,
+    Violation,
+    parse,
+    parse_files,
+    scan,
+)
+
+__all__ = [
+    "CallGraph",
+    "CallSite",
+    "Class",
+    "DriftError",
+    "Function",
+    "GateResult",
+    "Import",
+    "ParseResult",
+    "PatternMatch",
+    "Project",
+    "ScanSummary",
+    "TaintFlow",
+    "Violation",
+    "parse",
+    "parse_files",
+    "scan",
+]
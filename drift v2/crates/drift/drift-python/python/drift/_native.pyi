@@ -0,0 +1,155 @@
+"""Type stubs for the native extension (`src/*.rs`)."""
+
+from os import PathLike
+from typing import Optional, Sequence, Union
+
+_Path = Union[str, PathLike[str]]
+
+class DriftError(Exception):
+    """Raised by the engine. Messages start with `[ERROR_CODE]`."""
+
+class ScanSummary:
+    root: str
+    files: list[str]
+    total_size_bytes: int
+    languages: dict[str, int]
+    errors: list[str]
+    duration_ms: int
+    def __len__(self) -> int: ...
+
+class Function:
+    name: str
+    qualified_name: Optional[str]
+    file: str
+    line: int
+    end_line: int
+    parameters: list[str]
+    return_type: Optional[str]
+    is_exported: bool
+    is_async: bool
+
+class Class:
+    name: str
+    kind: str
+    extends: Optional[str]
+    implements: list[str]
+    is_exported: bool
+    methods: list[Function]
+    line: int
+
+class Import:
+    source: str
+    names: list[str]
+    is_type_only: bool
+    line: int
+
+class CallSite:
+    callee_name: str
+    receiver: Optional[str]
+    line: int
+    column: int
+    argument_count: int
+    is_await: bool
+
+class ParseResult:
+    file: str
+    language: str
+    content_hash: int
+    functions: list[Function]
+    classes: list[Class]
+    imports: list[Import]
+    call_sites: list[CallSite]
+    namespace: Optional[str]
+    has_errors: bool
+    error_count: int
+    parse_time_us: int
+
+class PatternMatch:
+    file: str
+    line: int
+    column: int
+    pattern_id: str
+    category: str
+    confidence: float
+    detection_method: str
+    cwe_ids: list[int]
+    owasp: Optional[str]
+    matched_text: str
+
+class TaintFlow:
+    source_file: str
+    source_line: int
+    source_type: str
+    source_expression: str
+    sink_file: str
+    sink_line: int
+    sink_type: str
+    sink_expression: str
+    path: list[str]
+    sanitizers: list[str]
+    is_sanitized: bool
+    cwe_id: Optional[int]
+    confidence: float
+    @property
+    def is_vulnerability(self) -> bool: ...
+
+class Violation:
+    id: str
+    file: str
+    line: int
+    column: Optional[int]
+    severity: str
+    pattern_id: str
+    rule_id: str
+    message: str
+    cwe_id: Optional[int]
+    owasp_category: Optional[str]
+    suppressed: bool
+    is_new: bool
+    owners: list[str]
+
+class GateResult:
+    gate_id: str
+    status: str
+    passed: bool
+    score: float
+    summary: str
+    violations: list[Violation]
+    warnings: list[str]
+    execution_time_ms: int
+    details: str
+    error: Optional[str]
+
+class CallGraph:
+    @property
+    def function_count(self) -> int: ...
+    @property
+    def edge_count(self) -> int: ...
+    def functions(self, file: Optional[str] = None) -> list[str]: ...
+    def callers(self, key: str) -> list[str]: ...
+    def callees(self, key: str) -> list[str]: ...
+    def reachable(self, key: str, max_depth: Optional[int] = None, inverse: bool = False) -> list[str]: ...
+    def entry_points(self) -> list[str]: ...
+    def __contains__(self, key: str) -> bool: ...
+    def __len__(self) -> int: ...
+
+class Project:
+    def __init__(self, root: _Path) -> None: ...
+    @property
+    def root(self) -> str: ...
+    @property
+    def scan(self) -> ScanSummary: ...
+    @property
+    def files(self) -> list[str]: ...
+    @property
+    def parse_results(self) -> list[ParseResult]: ...
+    @property
+    def matches(self) -> list[PatternMatch]: ...
+    def parse_result(self, file: str) -> Optional[ParseResult]: ...
+    def call_graph(self) -> CallGraph: ...
+    def taint_flows(self, max_depth: Optional[int] = None) -> list[TaintFlow]: ...
+    def run_gates(self) -> list[GateResult]: ...
+
+def scan(root: _Path) -> ScanSummary: ...
+def parse(path: _Path, source: Optional[str] = None) -> ParseResult: ...
+def parse_files(paths: Sequence[_Path]) -> list[ParseResult]: ...
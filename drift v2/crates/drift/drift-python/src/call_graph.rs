@@ -0,0 +1,115 @@
+//! `CallGraph` — read-only queries over a built call graph.
+//!
+//! Functions are addressed by their graph key, `file::name`.
+
+use std::sync::Arc;
+
+use drift_analysis::call_graph::{bfs_forward, bfs_inverse, detect_entry_points, CallGraph as RustCallGraph};
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+
+/// Function call graph of a project.
+#[pyclass(module = "drift", frozen)]
+pub struct CallGraph {
+    graph: Arc<RustCallGraph>,
+}
+
+impl CallGraph {
+    pub fn new(graph: Arc<RustCallGraph>) -> Self {
+        Self { graph }
+    }
+
+    fn node(&self, key: &str) -> PyResult<NodeIndex> {
+        self.graph
+            .get_node(key)
+            .ok_or_else(|| PyKeyError::new_err(format!("unknown function '{key}'")))
+    }
+
+    fn keys(&self, nodes: impl IntoIterator<Item = NodeIndex>) -> Vec<String> {
+        let mut keys: Vec<String> = nodes
+            .into_iter()
+            .filter_map(|idx| self.graph.graph.node_weight(idx))
+            .map(|n| format!("{}::{}", n.file, n.name))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    fn neighbors(&self, key: &str, direction: Direction) -> PyResult<Vec<String>> {
+        let idx = self.node(key)?;
+        Ok(self.keys(self.graph.graph.neighbors_directed(idx, direction)))
+    }
+}
+
+#[pymethods]
+impl CallGraph {
+    #[getter]
+    fn function_count(&self) -> usize {
+        self.graph.function_count()
+    }
+
+    #[getter]
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Keys of all functions, or of those in `file`.
+    #[pyo3(signature = (file=None))]
+    fn functions(&self, file: Option<&str>) -> Vec<String> {
+        match file {
+            Some(file) => self.keys(self.graph.get_file_nodes(file).iter().copied()),
+            None => self.keys(self.graph.graph.node_indices()),
+        }
+    }
+
+    /// Functions calling `key` directly.
+    fn callers(&self, key: &str) -> PyResult<Vec<String>> {
+        self.neighbors(key, Direction::Incoming)
+    }
+
+    /// Functions `key` calls directly.
+    fn callees(&self, key: &str) -> PyResult<Vec<String>> {
+        self.neighbors(key, Direction::Outgoing)
+    }
+
+    /// Functions reachable from `key` (or that reach it, with
+    /// `inverse=True`), up to `max_depth` calls away.
+    #[pyo3(signature = (key, max_depth=None, inverse=false))]
+    fn reachable(&self, py: Python<'_>, key: &str, max_depth: Option<usize>, inverse: bool) -> PyResult<Vec<String>> {
+        let idx = self.node(key)?;
+        let graph = &self.graph;
+        let nodes = py.allow_threads(|| {
+            if inverse {
+                bfs_inverse(graph, idx, max_depth)
+            } else {
+                bfs_forward(graph, idx, max_depth)
+            }
+        });
+        Ok(self.keys(nodes))
+    }
+
+    /// Functions that look like entry points: exported, in main/index
+    /// files, route handlers, tests and CLI commands.
+    fn entry_points(&self) -> Vec<String> {
+        self.keys(detect_entry_points(&self.graph))
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        self.graph.get_node(key).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        self.graph.function_count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CallGraph(functions={}, edges={})",
+            self.graph.function_count(),
+            self.graph.edge_count(),
+        )
+    }
+}
@@ -0,0 +1,29 @@
+//! Drift errors → Python exceptions.
+//!
+//! Every error is raised as `drift.DriftError` with the same
+//! `[ERROR_CODE] message` format the N-API bindings use, so callers can
+//! match on the code prefix.
+
+use std::fmt::Display;
+
+use drift_core::errors::DriftErrorCode;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+
+create_exception!(
+    drift,
+    DriftError,
+    PyException,
+    "Error raised by the Drift analysis engine. Messages start with `[ERROR_CODE]`."
+);
+
+/// Convert a typed Drift error.
+pub fn to_py_err<E: DriftErrorCode + Display>(err: E) -> PyErr {
+    DriftError::new_err(err.napi_string())
+}
+
+/// Raise `DriftError` with an explicit code.
+pub fn drift_error(code: &str, message: impl Display) -> PyErr {
+    DriftError::new_err(format!("[{code}] {message}"))
+}
@@ -0,0 +1,76 @@
+//! Module-level functions: `scan()`, `parse()` and `parse_files()`.
+
+use std::path::{Path, PathBuf};
+
+use drift_analysis::parsers::ParserManager;
+use drift_analysis::scanner::types::ScanDiff;
+use drift_analysis::scanner::Scanner;
+use drift_core::config::DriftConfig;
+use drift_core::errors::{error_code, ParseError};
+use drift_core::events::handler::DriftEventHandler;
+use drift_core::types::collections::FxHashMap;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::errors::{drift_error, to_py_err};
+use crate::types::{ParseResult, ScanSummary};
+
+/// No-op event handler — Python callers get the summary, not progress.
+struct NoOpHandler;
+impl DriftEventHandler for NoOpHandler {}
+
+/// Full scan of `root` with its `drift.toml` scan settings. Call with the
+/// GIL released.
+pub(crate) fn scan_root(root: &Path) -> PyResult<ScanDiff> {
+    let config = DriftConfig::load(root, None).unwrap_or_default();
+    Scanner::new(config.scan)
+        .scan(root, &FxHashMap::default(), &NoOpHandler)
+        .map_err(to_py_err)
+}
+
+fn read_source(path: &Path) -> PyResult<Vec<u8>> {
+    std::fs::read(path).map_err(|e| drift_error(error_code::PARSE_ERROR, format!("cannot read {}: {e}", path.display())))
+}
+
+/// Discover and hash the source files under `root`, honoring `.gitignore`,
+/// `.driftignore` and `drift.toml`.
+#[pyfunction]
+pub fn scan(py: Python<'_>, root: PathBuf) -> PyResult<ScanSummary> {
+    let diff = py.allow_threads(|| scan_root(&root))?;
+    Ok(ScanSummary::from_diff(&root, &diff))
+}
+
+/// Parse one file. `source` overrides the file's contents on disk; the
+/// language is always taken from the extension.
+#[pyfunction]
+#[pyo3(signature = (path, source=None))]
+pub fn parse(py: Python<'_>, path: PathBuf, source: Option<String>) -> PyResult<ParseResult> {
+    let source = match source {
+        Some(source) => source.into_bytes(),
+        None => read_source(&path)?,
+    };
+    py.allow_threads(|| ParserManager::new().parse_returning_tree(&source, &path))
+        .map(|(pr, _)| ParseResult::from(&pr))
+        .map_err(to_py_err)
+}
+
+/// Parse files in parallel. Files in unsupported languages are skipped;
+/// unreadable or unparseable files raise.
+#[pyfunction]
+pub fn parse_files(py: Python<'_>, paths: Vec<PathBuf>) -> PyResult<Vec<ParseResult>> {
+    let results = py.allow_threads(|| {
+        let parser = ParserManager::new();
+        paths
+            .par_iter()
+            .map(|path| {
+                let source = read_source(path)?;
+                match parser.parse_returning_tree(&source, path) {
+                    Ok((pr, _)) => Ok(Some(pr)),
+                    Err(ParseError::UnsupportedLanguage { .. }) => Ok(None),
+                    Err(e) => Err(to_py_err(e)),
+                }
+            })
+            .collect::<PyResult<Vec<_>>>()
+    })?;
+    Ok(results.iter().flatten().map(ParseResult::from).collect())
+}
@@ -0,0 +1,52 @@
+//! # drift-python
+//!
+//! PyO3 bindings for the Drift analysis engine, published as the `drift`
+//! Python package.
+//!
+//! Architecture:
+//! - `errors` — `DriftError` exception, Rust error → Python conversion
+//! - `types` — typed Python classes (`ParseResult`, `PatternMatch`, `TaintFlow`, `GateResult`, ...)
+//! - `call_graph` — `CallGraph` queries (callers, callees, reachability)
+//! - `project` — `Project`: scan, parse and detect a directory; taint analysis and quality gates
+//! - `functions` — module-level `scan()`, `parse()` and `parse_files()`
+//!
+//! Rayon-parallel work runs with the GIL released. Tree-sitter grammars and
+//! framework packs are compiled into the extension module, so wheels need
+//! nothing at runtime.
+
+pub mod errors;
+pub mod types;
+pub mod call_graph;
+pub mod project;
+pub mod functions;
+
+use pyo3::prelude::*;
+
+/// Add the `drift` classes, functions and exception to `m`.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("DriftError", m.py().get_type::<errors::DriftError>())?;
+
+    m.add_class::<types::ScanSummary>()?;
+    m.add_class::<types::Function>()?;
+    m.add_class::<types::Class>()?;
+    m.add_class::<types::Import>()?;
+    m.add_class::<types::CallSite>()?;
+    m.add_class::<types::ParseResult>()?;
+    m.add_class::<types::PatternMatch>()?;
+    m.add_class::<types::TaintFlow>()?;
+    m.add_class::<types::Violation>()?;
+    m.add_class::<types::GateResult>()?;
+    m.add_class::<call_graph::CallGraph>()?;
+    m.add_class::<project::Project>()?;
+
+    m.add_function(wrap_pyfunction!(functions::scan, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::parse, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::parse_files, m)?)?;
+    Ok(())
+}
+
+/// Native module, re-exported by `python/drift/__init__.py`.
+#[pymodule]
+fn _native(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    register(m)
+}
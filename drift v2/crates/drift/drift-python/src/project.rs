@@ -0,0 +1,198 @@
+//! `Project` — a scanned, parsed and detected directory.
+//!
+//! Construction scans the root, then parses each file and runs the
+//! detection pipeline and framework packs across rayon's pool. The call
+//! graph is built on first use and shared by queries, taint analysis and
+//! gates.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use drift_analysis::call_graph::{CallGraph as RustCallGraph, CallGraphBuilder};
+use drift_analysis::engine::types as engine;
+use drift_analysis::engine::{AnalysisPipeline, DetectionEngine, ResolutionIndex, VisitorRegistry};
+use drift_analysis::enforcement::codeowners::CodeOwners;
+use drift_analysis::enforcement::gates::{self, GateInputBuilder, GateOrchestrator};
+use drift_analysis::frameworks::{FrameworkMatcher, FrameworkPackRegistry};
+use drift_analysis::graph::taint::{self, analyze_interprocedural, analyze_intraprocedural, TaintRegistry};
+use drift_analysis::language_provider::embedded_sql::{register_taint_sinks, SqlInjectionCandidate};
+use drift_analysis::parsers::types as parsers;
+use drift_analysis::parsers::ParserManager;
+use drift_core::errors::GateError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::call_graph::CallGraph;
+use crate::errors::to_py_err;
+use crate::functions::scan_root;
+use crate::types::{GateResult, ParseResult, PatternMatch, ScanSummary, TaintFlow};
+
+/// An analyzed source tree.
+#[pyclass(module = "drift", frozen)]
+pub struct Project {
+    root: PathBuf,
+    scan: ScanSummary,
+    parse_results: Vec<parsers::ParseResult>,
+    matches: Vec<engine::PatternMatch>,
+    /// Default sinks plus the database APIs embedded SQL was spliced into.
+    taint_registry: TaintRegistry,
+    call_graph: OnceLock<Arc<RustCallGraph>>,
+}
+
+impl Project {
+    /// Scan `root`, then parse and detect every supported file.
+    pub fn analyze(root: PathBuf) -> PyResult<Self> {
+        let diff = scan_root(&root)?;
+        let scan = ScanSummary::from_diff(&root, &diff);
+        let packs = FrameworkPackRegistry::for_project(&root).into_packs();
+        let parser = ParserManager::new();
+
+        let mut analyzed: Vec<(parsers::ParseResult, Vec<engine::PatternMatch>, Vec<SqlInjectionCandidate>)> = scan
+            .files
+            .par_iter()
+            .map_init(
+                || {
+                    let pipeline = AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new()));
+                    (pipeline, FrameworkMatcher::new(packs.clone()))
+                },
+                |(pipeline, matcher), file| {
+                    let source = std::fs::read(root.join(file)).ok()?;
+                    let (parse_result, tree) = parser.parse_returning_tree(&source, Path::new(file)).ok()?;
+                    let mut resolution_index = ResolutionIndex::new();
+                    let result =
+                        pipeline.analyze_file_with_frameworks(&parse_result, &source, &tree, &mut resolution_index, matcher);
+                    Some((parse_result, result.matches, result.embedded_sql.injection_candidates))
+                },
+            )
+            .flatten()
+            .collect();
+        analyzed.sort_by(|a, b| a.0.file.cmp(&b.0.file));
+
+        let mut parse_results = Vec::with_capacity(analyzed.len());
+        let mut matches = Vec::new();
+        let mut taint_registry = TaintRegistry::with_defaults();
+        for (parse_result, file_matches, sql_candidates) in analyzed {
+            parse_results.push(parse_result);
+            matches.extend(file_matches);
+            register_taint_sinks(&mut taint_registry, &sql_candidates);
+        }
+
+        Ok(Self {
+            root,
+            scan,
+            parse_results,
+            matches,
+            taint_registry,
+            call_graph: OnceLock::new(),
+        })
+    }
+
+    /// The call graph, built on first use. Call with the GIL released.
+    fn graph(&self) -> PyResult<Arc<RustCallGraph>> {
+        if let Some(graph) = self.call_graph.get() {
+            return Ok(graph.clone());
+        }
+        let (graph, _stats) = CallGraphBuilder::new().build(&self.parse_results).map_err(to_py_err)?;
+        Ok(self.call_graph.get_or_init(|| Arc::new(graph)).clone())
+    }
+
+    /// Intraprocedural flows per file, then interprocedural flows over the
+    /// call graph. Call with the GIL released.
+    fn flows(&self, max_depth: Option<usize>) -> PyResult<Vec<taint::TaintFlow>> {
+        let graph = self.graph()?;
+        let registry = &self.taint_registry;
+        let mut flows: Vec<taint::TaintFlow> = self
+            .parse_results
+            .par_iter()
+            .flat_map_iter(|pr| analyze_intraprocedural(pr, registry))
+            .collect();
+        flows.extend(analyze_interprocedural(&graph, &self.parse_results, registry, max_depth).map_err(to_py_err)?);
+        Ok(flows)
+    }
+
+    fn gate_results(&self) -> PyResult<Vec<gates::GateResult>> {
+        let flows = self.flows(None)?;
+        let input = GateInputBuilder::new()
+            .files(self.scan.files.clone())
+            .patterns_from_matches(&self.matches)
+            .security_findings_from_taint_flows(&flows)
+            .build();
+        let mut results = GateOrchestrator::new()
+            .execute(&input)
+            .map_err(|e| to_py_err(GateError::EvaluationFailed(e)))?;
+        if let Some(codeowners) = CodeOwners::discover(&self.root) {
+            codeowners.assign(&self.root, &mut results);
+        }
+        Ok(results)
+    }
+}
+
+#[pymethods]
+impl Project {
+    /// Analyze the source tree at `root`.
+    #[new]
+    fn new(py: Python<'_>, root: PathBuf) -> PyResult<Self> {
+        py.allow_threads(|| Self::analyze(root))
+    }
+
+    #[getter]
+    fn root(&self) -> String {
+        self.root.to_string_lossy().to_string()
+    }
+
+    #[getter]
+    fn scan(&self) -> ScanSummary {
+        self.scan.clone()
+    }
+
+    /// Paths of all scanned files, relative to the root.
+    #[getter]
+    fn files(&self) -> Vec<String> {
+        self.scan.files.clone()
+    }
+
+    /// One result per parsed file, sorted by path.
+    #[getter]
+    fn parse_results(&self) -> Vec<ParseResult> {
+        self.parse_results.iter().map(ParseResult::from).collect()
+    }
+
+    /// Pattern matches from the detectors and framework packs.
+    #[getter]
+    fn matches(&self) -> Vec<PatternMatch> {
+        self.matches.iter().map(PatternMatch::from).collect()
+    }
+
+    /// The parse result for `file` (relative to the root), if it was parsed.
+    fn parse_result(&self, file: &str) -> Option<ParseResult> {
+        self.parse_results.iter().find(|pr| pr.file == file).map(ParseResult::from)
+    }
+
+    fn call_graph(&self, py: Python<'_>) -> PyResult<CallGraph> {
+        py.allow_threads(|| self.graph()).map(CallGraph::new)
+    }
+
+    /// Taint flows from sources to sinks, following calls up to
+    /// `max_depth` deep.
+    #[pyo3(signature = (max_depth=None))]
+    fn taint_flows(&self, py: Python<'_>, max_depth: Option<usize>) -> PyResult<Vec<TaintFlow>> {
+        let flows = py.allow_threads(|| self.flows(max_depth))?;
+        Ok(flows.iter().map(TaintFlow::from).collect())
+    }
+
+    /// Run the quality gates over the detected patterns and taint flows.
+    /// Violations carry their CODEOWNERS owners.
+    fn run_gates(&self, py: Python<'_>) -> PyResult<Vec<GateResult>> {
+        let results = py.allow_threads(|| self.gate_results())?;
+        Ok(results.iter().map(GateResult::from).collect())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Project(root={:?}, files={}, matches={})",
+            self.root.to_string_lossy(),
+            self.parse_results.len(),
+            self.matches.len(),
+        )
+    }
+}
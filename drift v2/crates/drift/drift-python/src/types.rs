@@ -0,0 +1,455 @@
+//! Typed Python classes for analysis results.
+//!
+//! Classes are frozen snapshots of the Rust results: attributes are
+//! read-only and enums cross as their lowercase/kebab-case names.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use drift_analysis::engine::types as engine;
+use drift_analysis::enforcement::{gates, rules};
+use drift_analysis::graph::taint::types as taint;
+use drift_analysis::parsers::types as parsers;
+use drift_analysis::scanner::types::ScanDiff;
+use pyo3::prelude::*;
+
+// ---- Scan ----
+
+/// Files found by `drift.scan()`.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct ScanSummary {
+    pub root: String,
+    /// Paths relative to `root`, sorted.
+    pub files: Vec<String>,
+    pub total_size_bytes: u64,
+    /// Language name → file count.
+    pub languages: HashMap<String, usize>,
+    pub errors: Vec<String>,
+    pub duration_ms: u64,
+}
+
+impl ScanSummary {
+    pub fn from_diff(root: &Path, diff: &ScanDiff) -> Self {
+        let mut files: Vec<String> = diff
+            .entries
+            .keys()
+            .map(|p| relative_path(root, p))
+            .collect();
+        files.sort();
+        Self {
+            root: root.to_string_lossy().to_string(),
+            files,
+            total_size_bytes: diff.stats.total_size_bytes,
+            languages: diff
+                .stats
+                .languages_found
+                .iter()
+                .map(|(lang, count)| (lang.name().to_string(), *count))
+                .collect(),
+            errors: diff.errors.clone(),
+            duration_ms: diff.stats.discovery_ms + diff.stats.hashing_ms + diff.stats.diff_ms,
+        }
+    }
+}
+
+#[pymethods]
+impl ScanSummary {
+    fn __len__(&self) -> usize {
+        self.files.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ScanSummary(root={:?}, files={})", self.root, self.files.len())
+    }
+}
+
+/// `path` relative to `root`, with `/` separators.
+pub fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+// ---- Parsing ----
+
+/// A function or method.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub qualified_name: Option<String>,
+    pub file: String,
+    pub line: u32,
+    pub end_line: u32,
+    pub parameters: Vec<String>,
+    pub return_type: Option<String>,
+    pub is_exported: bool,
+    pub is_async: bool,
+}
+
+impl From<&parsers::FunctionInfo> for Function {
+    fn from(f: &parsers::FunctionInfo) -> Self {
+        Self {
+            name: f.name.clone(),
+            qualified_name: f.qualified_name.clone(),
+            file: f.file.clone(),
+            line: f.line,
+            end_line: f.end_line,
+            parameters: f.parameters.iter().map(|p| p.name.clone()).collect(),
+            return_type: f.return_type.clone(),
+            is_exported: f.is_exported,
+            is_async: f.is_async,
+        }
+    }
+}
+
+#[pymethods]
+impl Function {
+    fn __repr__(&self) -> String {
+        format!("Function({:?}, file={:?}, line={})", self.name, self.file, self.line)
+    }
+}
+
+/// A class, interface, struct or similar type declaration.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct Class {
+    pub name: String,
+    pub kind: String,
+    pub extends: Option<String>,
+    pub implements: Vec<String>,
+    pub is_exported: bool,
+    pub methods: Vec<Function>,
+    pub line: u32,
+}
+
+impl From<&parsers::ClassInfo> for Class {
+    fn from(c: &parsers::ClassInfo) -> Self {
+        Self {
+            name: c.name.clone(),
+            kind: format!("{:?}", c.class_kind).to_lowercase(),
+            extends: c.extends.clone(),
+            implements: c.implements.to_vec(),
+            is_exported: c.is_exported,
+            methods: c.methods.iter().map(Function::from).collect(),
+            line: c.range.start.line,
+        }
+    }
+}
+
+#[pymethods]
+impl Class {
+    fn __repr__(&self) -> String {
+        format!("Class({:?}, kind={:?}, methods={})", self.name, self.kind, self.methods.len())
+    }
+}
+
+/// An import statement.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub source: String,
+    /// Imported names (aliases are not applied).
+    pub names: Vec<String>,
+    pub is_type_only: bool,
+    pub line: u32,
+}
+
+impl From<&parsers::ImportInfo> for Import {
+    fn from(i: &parsers::ImportInfo) -> Self {
+        Self {
+            source: i.source.clone(),
+            names: i.specifiers.iter().map(|s| s.name.clone()).collect(),
+            is_type_only: i.is_type_only,
+            line: i.line,
+        }
+    }
+}
+
+#[pymethods]
+impl Import {
+    fn __repr__(&self) -> String {
+        format!("Import({:?}, names={:?})", self.source, self.names)
+    }
+}
+
+/// A call expression.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    pub callee_name: String,
+    pub receiver: Option<String>,
+    pub line: u32,
+    pub column: u32,
+    pub argument_count: u8,
+    pub is_await: bool,
+}
+
+impl From<&parsers::CallSite> for CallSite {
+    fn from(c: &parsers::CallSite) -> Self {
+        Self {
+            callee_name: c.callee_name.clone(),
+            receiver: c.receiver.clone(),
+            line: c.line,
+            column: c.column,
+            argument_count: c.argument_count,
+            is_await: c.is_await,
+        }
+    }
+}
+
+#[pymethods]
+impl CallSite {
+    fn __repr__(&self) -> String {
+        match &self.receiver {
+            Some(receiver) => format!("CallSite({receiver}.{}, line={})", self.callee_name, self.line),
+            None => format!("CallSite({}, line={})", self.callee_name, self.line),
+        }
+    }
+}
+
+/// Structure extracted from one source file.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+    pub file: String,
+    pub language: String,
+    pub content_hash: u64,
+    pub functions: Vec<Function>,
+    pub classes: Vec<Class>,
+    pub imports: Vec<Import>,
+    pub call_sites: Vec<CallSite>,
+    pub namespace: Option<String>,
+    pub has_errors: bool,
+    pub error_count: u32,
+    pub parse_time_us: u64,
+}
+
+impl From<&parsers::ParseResult> for ParseResult {
+    fn from(pr: &parsers::ParseResult) -> Self {
+        Self {
+            file: pr.file.clone(),
+            language: pr.language.name().to_string(),
+            content_hash: pr.content_hash,
+            functions: pr.functions.iter().map(Function::from).collect(),
+            classes: pr.classes.iter().map(Class::from).collect(),
+            imports: pr.imports.iter().map(Import::from).collect(),
+            call_sites: pr.call_sites.iter().map(CallSite::from).collect(),
+            namespace: pr.namespace.clone(),
+            has_errors: pr.has_errors,
+            error_count: pr.error_count,
+            parse_time_us: pr.parse_time_us,
+        }
+    }
+}
+
+#[pymethods]
+impl ParseResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "ParseResult({:?}, language={:?}, functions={}, classes={})",
+            self.file,
+            self.language,
+            self.functions.len(),
+            self.classes.len(),
+        )
+    }
+}
+
+// ---- Detection ----
+
+/// A detected pattern occurrence.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PatternMatch {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub pattern_id: String,
+    pub category: String,
+    pub confidence: f32,
+    pub detection_method: String,
+    pub cwe_ids: Vec<u32>,
+    pub owasp: Option<String>,
+    pub matched_text: String,
+}
+
+impl From<&engine::PatternMatch> for PatternMatch {
+    fn from(m: &engine::PatternMatch) -> Self {
+        Self {
+            file: m.file.clone(),
+            line: m.line,
+            column: m.column,
+            pattern_id: m.pattern_id.clone(),
+            category: m.category.name().to_string(),
+            confidence: m.confidence,
+            detection_method: format!("{:?}", m.detection_method),
+            cwe_ids: m.cwe_ids.to_vec(),
+            owasp: m.owasp.clone(),
+            matched_text: m.matched_text.clone(),
+        }
+    }
+}
+
+#[pymethods]
+impl PatternMatch {
+    fn __repr__(&self) -> String {
+        format!("PatternMatch({:?}, file={:?}, line={})", self.pattern_id, self.file, self.line)
+    }
+}
+
+// ---- Taint ----
+
+/// A flow of untrusted data from a source to a sink.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct TaintFlow {
+    pub source_file: String,
+    pub source_line: u32,
+    pub source_type: String,
+    pub source_expression: String,
+    pub sink_file: String,
+    pub sink_line: u32,
+    pub sink_type: String,
+    pub sink_expression: String,
+    /// Functions the data passes through between source and sink.
+    pub path: Vec<String>,
+    pub sanitizers: Vec<String>,
+    pub is_sanitized: bool,
+    pub cwe_id: Option<u32>,
+    pub confidence: f32,
+}
+
+impl From<&taint::TaintFlow> for TaintFlow {
+    fn from(f: &taint::TaintFlow) -> Self {
+        Self {
+            source_file: f.source.file.clone(),
+            source_line: f.source.line,
+            source_type: f.source.source_type.name().to_string(),
+            source_expression: f.source.expression.clone(),
+            sink_file: f.sink.file.clone(),
+            sink_line: f.sink.line,
+            sink_type: f.sink.sink_type.name().to_string(),
+            sink_expression: f.sink.expression.clone(),
+            path: f.path.iter().map(|h| h.function.clone()).collect(),
+            sanitizers: f.sanitizers_applied.iter().map(|s| s.expression.clone()).collect(),
+            is_sanitized: f.is_sanitized,
+            cwe_id: f.cwe_id,
+            confidence: f.confidence,
+        }
+    }
+}
+
+#[pymethods]
+impl TaintFlow {
+    /// Unsanitized flows are vulnerabilities.
+    #[getter]
+    fn is_vulnerability(&self) -> bool {
+        !self.is_sanitized
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TaintFlow({} {}:{} -> {} {}:{})",
+            self.source_type, self.source_file, self.source_line, self.sink_type, self.sink_file, self.sink_line,
+        )
+    }
+}
+
+// ---- Enforcement ----
+
+/// A rule violation reported by a quality gate.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub id: String,
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+    pub severity: String,
+    pub pattern_id: String,
+    pub rule_id: String,
+    pub message: String,
+    pub cwe_id: Option<u32>,
+    pub owasp_category: Option<String>,
+    pub suppressed: bool,
+    pub is_new: bool,
+    pub owners: Vec<String>,
+}
+
+impl From<&rules::Violation> for Violation {
+    fn from(v: &rules::Violation) -> Self {
+        Self {
+            id: v.id.clone(),
+            file: v.file.clone(),
+            line: v.line,
+            column: v.column,
+            severity: v.severity.to_string(),
+            pattern_id: v.pattern_id.clone(),
+            rule_id: v.rule_id.clone(),
+            message: v.message.clone(),
+            cwe_id: v.cwe_id,
+            owasp_category: v.owasp_category.clone(),
+            suppressed: v.suppressed,
+            is_new: v.is_new,
+            owners: v.owners.clone(),
+        }
+    }
+}
+
+#[pymethods]
+impl Violation {
+    fn __repr__(&self) -> String {
+        format!("Violation({:?}, {} {}:{})", self.rule_id, self.severity, self.file, self.line)
+    }
+}
+
+/// Outcome of one quality gate.
+#[pyclass(module = "drift", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct GateResult {
+    pub gate_id: String,
+    /// `passed`, `failed`, `warned`, `skipped` or `errored`.
+    pub status: String,
+    pub passed: bool,
+    pub score: f64,
+    pub summary: String,
+    pub violations: Vec<Violation>,
+    pub warnings: Vec<String>,
+    pub execution_time_ms: u64,
+    /// Gate-specific details as JSON.
+    pub details: String,
+    pub error: Option<String>,
+}
+
+impl From<&gates::GateResult> for GateResult {
+    fn from(r: &gates::GateResult) -> Self {
+        Self {
+            gate_id: r.gate_id.to_string(),
+            status: format!("{:?}", r.status).to_lowercase(),
+            passed: r.passed,
+            score: r.score,
+            summary: r.summary.clone(),
+            violations: r.violations.iter().map(Violation::from).collect(),
+            warnings: r.warnings.clone(),
+            execution_time_ms: r.execution_time_ms,
+            details: r.details.to_string(),
+            error: r.error.clone(),
+        }
+    }
+}
+
+#[pymethods]
+impl GateResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "GateResult({:?}, status={:?}, score={:.1}, violations={})",
+            self.gate_id,
+            self.status,
+            self.score,
+            self.violations.len(),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+//! Python bindings — module registration, parsing, project analysis,
+//! call-graph queries, taint flows and gates, exercised from Python.
+
+use std::ffi::CString;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+const APP: &str = r#"import { query } from "./db";
+
+export function handler(req: Request) {
+  const id = req.query.id;
+  return load(id);
+}
+
+function load(id: string) {
+  return query("SELECT * FROM users WHERE id = " + id);
+}
+
+export class UserService extends BaseService {
+  find(id: string) {
+    return load(id);
+  }
+}
+"#;
+
+const DB: &str = r#"export function query(sql: string) {
+  return sql;
+}
+"#;
+
+/// Run `code` with `drift` and `root` (a project with `src/app.ts` and
+/// `src/db.ts`) in scope.
+fn run_python(code: &str) {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/app.ts"), APP).unwrap();
+    std::fs::write(dir.path().join("src/db.ts"), DB).unwrap();
+    std::fs::write(dir.path().join("CODEOWNERS"), "* @org/platform\n").unwrap();
+
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, "drift").unwrap();
+        drift_python::register(&module).unwrap();
+        let globals = PyDict::new(py);
+        globals.set_item("drift", module).unwrap();
+        globals.set_item("root", dir.path().to_string_lossy().to_string()).unwrap();
+        let code = CString::new(code).unwrap();
+        if let Err(e) = py.run(&code, Some(&globals), None) {
+            e.print(py);
+            panic!("python code raised: {e}");
+        }
+    });
+}
+
+#[test]
+fn module_exports_classes_and_functions() {
+    run_python(
+        r#"
+for name in ["ParseResult", "PatternMatch", "TaintFlow", "GateResult", "CallGraph", "Project",
+             "ScanSummary", "Violation", "DriftError", "scan", "parse", "parse_files"]:
+    assert hasattr(drift, name), name
+assert issubclass(drift.DriftError, Exception)
+"#,
+    );
+}
+
+#[test]
+fn scan_lists_relative_files() {
+    run_python(
+        r#"
+summary = drift.scan(root)
+assert summary.files == ["CODEOWNERS", "src/app.ts", "src/db.ts"], summary.files
+assert summary.languages == {"TypeScript": 2}, summary.languages
+assert len(summary) == 3
+"#,
+    );
+}
+
+#[test]
+fn parse_returns_typed_results() {
+    run_python(
+        r#"
+import os
+pr = drift.parse(os.path.join(root, "src/app.ts"))
+assert isinstance(pr, drift.ParseResult)
+assert pr.language == "TypeScript"
+names = [f.name for f in pr.functions]
+assert "handler" in names and "load" in names, names
+handler = next(f for f in pr.functions if f.name == "handler")
+assert handler.is_exported and handler.parameters == ["req"], (handler.is_exported, handler.parameters)
+assert pr.classes[0].name == "UserService", pr.classes
+assert "BaseService" in pr.classes[0].extends, pr.classes[0].extends
+assert [m.name for m in pr.classes[0].methods] == ["find"], pr.classes[0].methods
+assert pr.imports[0].source == "./db", pr.imports
+assert any(c.callee_name == "load" for c in pr.call_sites), pr.call_sites
+
+inline = drift.parse("inline.py", "def f(x):\n    return x\n")
+assert inline.language == "Python" and inline.functions[0].name == "f"
+
+try:
+    drift.parse("notes.unknownext", "text")
+    raise AssertionError("expected DriftError")
+except drift.DriftError as e:
+    assert str(e).startswith("[UNSUPPORTED_LANGUAGE]"), str(e)
+
+results = drift.parse_files([os.path.join(root, "src/app.ts"), os.path.join(root, "src/db.ts"),
+                             os.path.join(root, "CODEOWNERS")])
+assert len(results) == 2
+"#,
+    );
+}
+
+#[test]
+fn project_call_graph_queries() {
+    run_python(
+        r#"
+project = drift.Project(root)
+assert project.files == ["CODEOWNERS", "src/app.ts", "src/db.ts"]
+assert [pr.file for pr in project.parse_results] == ["src/app.ts", "src/db.ts"]
+assert project.parse_result("src/db.ts").functions[0].name == "query"
+assert project.parse_result("missing.ts") is None
+assert all(isinstance(m, drift.PatternMatch) for m in project.matches)
+
+graph = project.call_graph()
+assert "src/app.ts::handler" in graph, graph.functions()
+assert graph.callees("src/app.ts::handler") == ["src/app.ts::load"], graph.callees("src/app.ts::handler")
+assert "src/app.ts::handler" in graph.callers("src/app.ts::load"), graph.callers("src/app.ts::load")
+assert "src/app.ts::load" in graph.reachable("src/app.ts::handler")
+assert graph.reachable("src/app.ts::load", inverse=True, max_depth=1) == graph.callers("src/app.ts::load")
+assert "src/db.ts::query" in graph.functions("src/db.ts")
+assert len(graph) == graph.function_count
+try:
+    graph.callers("nope::nope")
+    raise AssertionError("expected KeyError")
+except KeyError:
+    pass
+"#,
+    );
+}
+
+#[test]
+fn project_taint_and_gates() {
+    run_python(
+        r#"
+project = drift.Project(root)
+flows = project.taint_flows()
+assert all(isinstance(f, drift.TaintFlow) for f in flows)
+assert all(f.is_vulnerability == (not f.is_sanitized) for f in flows)
+
+gates = project.run_gates()
+assert gates and all(isinstance(g, drift.GateResult) for g in gates)
+ids = {g.gate_id for g in gates}
+assert {"pattern-compliance", "security-boundaries"} <= ids, ids
+for gate in gates:
+    assert gate.status in {"passed", "failed", "warned", "skipped", "errored"}
+    for v in gate.violations:
+        assert v.owners == ["@org/platform"], v.owners
+"#,
+    );
+}
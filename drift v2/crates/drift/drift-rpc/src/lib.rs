@@ -0,0 +1,34 @@
+//! # drift-rpc
+//!
+//! A versioned JSON-RPC 2.0 API over the Drift engine for editors, CI
+//! systems and tools not written in Node or Python. Requests and responses
+//! are newline-delimited JSON on stdio or a Unix socket.
+//!
+//! A connection starts with `initialize`, which binds it to a project root
+//! and opens its drift.db; `rpc.discover` describes every method with
+//! JSON Schemas for its params and result (also printed by
+//! `drift-rpc --schema`). Long-running methods stream `$/progress`
+//! notifications and stop early on `$/cancelRequest`.
+//!
+//! ```text
+//! → {"jsonrpc":"2.0","id":1,"method":"initialize","params":{"root":"."}}
+//! → {"jsonrpc":"2.0","id":2,"method":"scan"}
+//! ← {"jsonrpc":"2.0","method":"$/progress","params":{"requestId":2,"phase":"scanning",...}}
+//! ← {"jsonrpc":"2.0","id":2,"result":{"added":42,...}}
+//! ```
+
+pub mod methods;
+pub mod protocol;
+pub mod server;
+pub mod session;
+pub mod transport;
+pub mod types;
+
+pub use server::Server;
+#[cfg(unix)]
+pub use transport::serve_unix;
+pub use transport::serve_stdio;
+
+/// Version of the wire API, independent of the crate version. Clients
+/// pass it to `initialize`; a different major version is rejected.
+pub const API_VERSION: &str = "1.0.0";
@@ -0,0 +1,44 @@
+//! `drift-rpc` — serve the JSON-RPC API on stdio, or on a Unix socket
+//! with `--socket PATH`. `--schema` prints the `rpc.discover` document.
+
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: drift-rpc [--socket PATH] [--schema] [--version]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] => drift_rpc::serve_stdio(),
+        ["--socket", path] => serve_socket(path),
+        ["--schema"] => {
+            let document = drift_rpc::methods::describe();
+            println!("{}", serde_json::to_string_pretty(&document).unwrap_or_default());
+            Ok(())
+        }
+        ["--version"] => {
+            println!("drift-rpc {} (API {})", env!("CARGO_PKG_VERSION"), drift_rpc::API_VERSION);
+            Ok(())
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("drift-rpc: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(unix)]
+fn serve_socket(path: &str) -> std::io::Result<()> {
+    drift_rpc::serve_unix(std::path::Path::new(path))
+}
+
+#[cfg(not(unix))]
+fn serve_socket(_path: &str) -> std::io::Result<()> {
+    Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "--socket requires a Unix platform"))
+}
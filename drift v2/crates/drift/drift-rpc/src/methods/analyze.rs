@@ -0,0 +1,137 @@
+//! `analyze`: the per-file detection phase of `driftAnalyze`.
+//!
+//! Cross-file phases (call graph, taint, structural) are not run here;
+//! the result is what `query.detections`, `query.functions` and
+//! `enforcement.check` read.
+
+use std::path::Path;
+use std::time::Instant;
+
+use drift_analysis::engine::types::PatternMatch;
+use drift_analysis::engine::{AnalysisPipeline, DetectionEngine, ResolutionIndex, VisitorRegistry};
+use drift_analysis::frameworks::{FrameworkMatcher, FrameworkPackRegistry};
+use drift_analysis::parsers::ParserManager;
+use drift_core::errors::DriftErrorCode;
+use drift_storage::batch::commands::{BatchCommand, DetectionRow, FunctionRow};
+use drift_storage::queries::{detections, files, functions};
+
+use super::RequestContext;
+use crate::protocol::RpcError;
+use crate::types::{AnalyzeParams, AnalyzeResult, FileError};
+
+/// Progress is reported every this many files, and on the last one.
+const PROGRESS_INTERVAL: usize = 100;
+
+pub fn analyze(ctx: &RequestContext, params: AnalyzeParams) -> Result<AnalyzeResult, RpcError> {
+    let started = Instant::now();
+    let session = &ctx.session;
+    let paths = match params.files {
+        Some(paths) => paths,
+        None => session
+            .storage
+            .with_reader(files::load_all_file_metadata)?
+            .into_iter()
+            .filter(|f| f.language.is_some())
+            .map(|f| f.path)
+            .collect(),
+    };
+
+    let parser = ParserManager::new();
+    let mut pipeline = AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new()));
+    let mut matcher = FrameworkMatcher::new(FrameworkPackRegistry::for_project(&session.root).into_packs());
+
+    let mut analyzed = Vec::new();
+    let mut detection_rows = Vec::new();
+    let mut function_rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, path) in paths.iter().enumerate() {
+        ctx.check_cancelled()?;
+        if i % PROGRESS_INTERVAL == 0 {
+            ctx.progress("analyzing", i, paths.len());
+        }
+
+        let source = match std::fs::read(session.root.join(path)) {
+            Ok(source) => source,
+            Err(e) => {
+                errors.push(FileError { path: path.clone(), error: e.to_string() });
+                continue;
+            }
+        };
+        let (parse_result, tree) = match parser.parse_returning_tree(&source, Path::new(path)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                errors.push(FileError { path: path.clone(), error: e.napi_string() });
+                continue;
+            }
+        };
+
+        let mut resolution_index = ResolutionIndex::new();
+        let matches = pipeline
+            .analyze_file_with_frameworks(&parse_result, &source, &tree, &mut resolution_index, &mut matcher)
+            .matches;
+
+        detection_rows.extend(matches.iter().map(detection_row));
+        function_rows.extend(parse_result.functions.iter().map(|func| FunctionRow {
+            file: parse_result.file.clone(),
+            name: func.name.clone(),
+            qualified_name: func.qualified_name.clone(),
+            language: parse_result.language.name().to_string(),
+            line: func.line as i64,
+            end_line: func.end_line as i64,
+            parameter_count: func.parameters.len() as i64,
+            return_type: func.return_type.clone(),
+            is_exported: func.is_exported,
+            is_async: func.is_async,
+            body_hash: func.body_hash.to_le_bytes().to_vec(),
+            signature_hash: func.signature_hash.to_le_bytes().to_vec(),
+        }));
+        analyzed.push(parse_result.file);
+    }
+    ctx.check_cancelled()?;
+    ctx.progress("analyzing", paths.len(), paths.len());
+
+    // Replace, rather than append to, what earlier runs stored for these files.
+    session.storage.with_writer(|conn| {
+        for file in &analyzed {
+            detections::delete_detections_by_file(conn, file)?;
+            functions::delete_functions_by_file(conn, file)?;
+        }
+        Ok(())
+    })?;
+    let (detection_count, function_count) = (detection_rows.len(), function_rows.len());
+    if !detection_rows.is_empty() {
+        session.storage.send_batch(BatchCommand::InsertDetections(detection_rows))?;
+    }
+    if !function_rows.is_empty() {
+        session.storage.send_batch(BatchCommand::InsertFunctions(function_rows))?;
+    }
+    session.storage.flush_batch_sync()?;
+
+    Ok(AnalyzeResult {
+        files_analyzed: analyzed.len(),
+        detections: detection_count,
+        functions: function_count,
+        errors,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+fn detection_row(m: &PatternMatch) -> DetectionRow {
+    DetectionRow {
+        file: m.file.clone(),
+        line: m.line as i64,
+        column_num: m.column as i64,
+        pattern_id: m.pattern_id.clone(),
+        category: format!("{:?}", m.category),
+        confidence: m.confidence as f64,
+        detection_method: format!("{:?}", m.detection_method),
+        cwe_ids: if m.cwe_ids.is_empty() {
+            None
+        } else {
+            Some(m.cwe_ids.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(","))
+        },
+        owasp: m.owasp.clone(),
+        matched_text: Some(m.matched_text.clone()),
+    }
+}
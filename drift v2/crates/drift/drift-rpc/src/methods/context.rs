@@ -0,0 +1,96 @@
+//! `context.generate`: intent-weighted context from drift.db plus
+//! caller-supplied sections.
+
+use std::collections::BTreeMap;
+
+use drift_context::generation::builder::{AnalysisData, ContextDepth, ContextEngine};
+use drift_context::generation::intent::ContextIntent;
+use drift_storage::queries::{detections, files};
+
+use super::RequestContext;
+use crate::protocol::RpcError;
+use crate::session::Session;
+use crate::types::{ContextParams, ContextResult, ContextSection, Depth, Intent};
+
+/// Patterns listed in the generated `conventions` section.
+const TOP_PATTERNS: usize = 20;
+
+pub fn generate_context(ctx: &RequestContext, params: ContextParams) -> Result<ContextResult, RpcError> {
+    let mut data = AnalysisData::new();
+    if !params.sections.contains_key("overview") {
+        if let Some(overview) = overview_section(&ctx.session)? {
+            data.add_section("overview", overview);
+        }
+    }
+    if !params.sections.contains_key("conventions") {
+        if let Some(conventions) = conventions_section(&ctx.session)? {
+            data.add_section("conventions", conventions);
+        }
+    }
+    for (name, content) in params.sections {
+        data.add_section(name, content);
+    }
+
+    let intent = match params.intent {
+        Intent::FixBug => ContextIntent::FixBug,
+        Intent::AddFeature => ContextIntent::AddFeature,
+        Intent::UnderstandCode => ContextIntent::UnderstandCode,
+        Intent::SecurityAudit => ContextIntent::SecurityAudit,
+        Intent::GenerateSpec => ContextIntent::GenerateSpec,
+    };
+    let depth = match params.depth {
+        Depth::Overview => ContextDepth::Overview,
+        Depth::Standard => ContextDepth::Standard,
+        Depth::Deep => ContextDepth::Deep,
+    };
+    let output = ContextEngine::new().generate(intent, depth, &data)?;
+
+    Ok(ContextResult {
+        intent: output.intent.name().to_string(),
+        depth: output.depth.name().to_string(),
+        token_count: output.token_count,
+        sections: output
+            .sections
+            .into_iter()
+            .map(|(name, content)| ContextSection { name, content })
+            .collect(),
+    })
+}
+
+/// File count per language from the last scan.
+fn overview_section(session: &Session) -> Result<Option<String>, RpcError> {
+    let records = session.storage.with_reader(files::load_all_file_metadata)?;
+    if records.is_empty() {
+        return Ok(None);
+    }
+    let mut by_language: BTreeMap<&str, usize> = BTreeMap::new();
+    for record in &records {
+        *by_language.entry(record.language.as_deref().unwrap_or("other")).or_default() += 1;
+    }
+    let mut out = format!("Project {} — {} files:\n", session.root.display(), records.len());
+    for (language, count) in by_language {
+        out.push_str(&format!("- {language}: {count}\n"));
+    }
+    Ok(Some(out))
+}
+
+/// The most frequent detected patterns.
+fn conventions_section(session: &Session) -> Result<Option<String>, RpcError> {
+    let stored = session
+        .storage
+        .with_reader(|conn| detections::query_all_detections(conn, i64::MAX as usize))?;
+    if stored.is_empty() {
+        return Ok(None);
+    }
+    let mut by_pattern: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+    for d in &stored {
+        *by_pattern.entry((&d.pattern_id, &d.category)).or_default() += 1;
+    }
+    let mut ranked: Vec<_> = by_pattern.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut out = String::from("Detected patterns (most frequent first):\n");
+    for ((pattern, category), count) in ranked.into_iter().take(TOP_PATTERNS) {
+        out.push_str(&format!("- {pattern} ({category}) — {count} occurrences\n"));
+    }
+    Ok(Some(out))
+}
@@ -0,0 +1,166 @@
+//! `enforcement.check`: quality gates over the stored detections.
+
+use drift_analysis::enforcement::codeowners::{is_owned_by, CodeOwners};
+use drift_analysis::enforcement::gates::{self, GateInputBuilder, GateOrchestrator};
+use drift_analysis::enforcement::reporters::{available_formats, create_reporter};
+use drift_analysis::enforcement::rules::types::{PatternInfo, PatternLocation};
+use drift_core::errors::{error_code, GateError};
+use drift_storage::batch::commands::{BatchCommand, GateResultInsertRow, ViolationInsertRow};
+use drift_storage::queries::detections::{self, DetectionRecord};
+use drift_storage::queries::files;
+
+use super::RequestContext;
+use crate::protocol::RpcError;
+use crate::types::{CheckParams, CheckResult, GateResult, Violation};
+
+pub fn check(ctx: &RequestContext, params: CheckParams) -> Result<CheckResult, RpcError> {
+    let reporter = match params.report.as_deref() {
+        Some(format) => Some(create_reporter(format).ok_or_else(|| {
+            RpcError::drift(
+                error_code::CONFIG_ERROR,
+                format!("unknown report format '{format}' (expected one of: {})", available_formats().join(", ")),
+            )
+        })?),
+        None => None,
+    };
+
+    let session = &ctx.session;
+    let (file_list, stored) = session.storage.with_reader(|conn| {
+        let file_list = files::load_all_file_metadata(conn)?.into_iter().map(|f| f.path).collect();
+        Ok((file_list, detections::query_all_detections(conn, i64::MAX as usize)?))
+    })?;
+    ctx.check_cancelled()?;
+
+    let input = GateInputBuilder::new().files(file_list).patterns(pattern_infos(&stored)).build();
+    let mut results = GateOrchestrator::new()
+        .execute(&input)
+        .map_err(GateError::EvaluationFailed)?;
+    if let Some(codeowners) = CodeOwners::discover(&session.root) {
+        codeowners.assign(&session.root, &mut results);
+    }
+    ctx.check_cancelled()?;
+
+    persist(ctx, &results)?;
+
+    let report = match reporter {
+        Some(reporter) => Some(reporter.generate(&results).map_err(|e| {
+            RpcError::drift(error_code::GATE_FAILED, format!("{} report failed: {e}", reporter.name()))
+        })?),
+        None => None,
+    };
+    Ok(CheckResult {
+        passed: results.iter().all(|r| r.passed),
+        violations: results
+            .iter()
+            .flat_map(|r| &r.violations)
+            .filter(|v| params.owner.as_deref().map_or(true, |owner| is_owned_by(&v.owners, owner)))
+            .map(violation)
+            .collect(),
+        gates: results.iter().map(gate_result).collect(),
+        report,
+    })
+}
+
+/// Group stored detections by pattern for the gates.
+fn pattern_infos(stored: &[DetectionRecord]) -> Vec<PatternInfo> {
+    PatternInfo::group(stored.iter().map(|d| PatternInfo {
+        pattern_id: d.pattern_id.clone(),
+        category: d.category.clone(),
+        confidence: d.confidence,
+        locations: vec![PatternLocation {
+            file: d.file.clone(),
+            line: d.line as u32,
+            column: Some(d.column_num as u32),
+        }],
+        outliers: Vec::new(),
+        cwe_ids: d
+            .cwe_ids
+            .as_deref()
+            .map(|ids| ids.split(',').filter_map(|id| id.trim().parse().ok()).collect())
+            .unwrap_or_default(),
+        owasp_categories: d.owasp.iter().cloned().collect(),
+    }))
+}
+
+/// Store violations and gate results the way `driftAnalyze` does.
+fn persist(ctx: &RequestContext, results: &[gates::GateResult]) -> Result<(), RpcError> {
+    let mut violation_rows = Vec::new();
+    let mut gate_rows = Vec::new();
+    for gr in results {
+        gate_rows.push(GateResultInsertRow {
+            gate_id: gr.gate_id.to_string(),
+            status: format!("{:?}", gr.status).to_lowercase(),
+            passed: gr.passed,
+            score: gr.score,
+            summary: gr.summary.clone(),
+            violation_count: gr.violations.len() as i64,
+            warning_count: gr.warnings.len() as i64,
+            execution_time_ms: gr.execution_time_ms as i64,
+            details: if gr.details.is_null() { None } else { Some(gr.details.to_string()) },
+            error: gr.error.clone(),
+        });
+        for v in &gr.violations {
+            violation_rows.push(ViolationInsertRow {
+                id: v.id.clone(),
+                file: v.file.clone(),
+                line: v.line as i64,
+                column_num: v.column.map(|c| c as i64),
+                end_line: v.end_line.map(|l| l as i64),
+                end_column: v.end_column.map(|c| c as i64),
+                severity: format!("{:?}", v.severity).to_lowercase(),
+                pattern_id: v.pattern_id.clone(),
+                rule_id: v.rule_id.clone(),
+                message: v.message.clone(),
+                quick_fix_strategy: v.quick_fix.as_ref().map(|qf| format!("{:?}", qf.strategy).to_lowercase()),
+                quick_fix_description: v.quick_fix.as_ref().map(|qf| qf.description.clone()),
+                cwe_id: v.cwe_id.map(|c| c as i64),
+                owasp_category: v.owasp_category.clone(),
+                suppressed: v.suppressed,
+                is_new: v.is_new,
+                owners: v.owners.clone(),
+            });
+        }
+    }
+
+    let storage = &ctx.session.storage;
+    if !violation_rows.is_empty() {
+        storage.send_batch(BatchCommand::InsertViolations(violation_rows))?;
+    }
+    if !gate_rows.is_empty() {
+        storage.send_batch(BatchCommand::InsertGateResults(gate_rows))?;
+    }
+    storage.flush_batch_sync()?;
+    Ok(())
+}
+
+fn gate_result(gr: &gates::GateResult) -> GateResult {
+    GateResult {
+        gate_id: gr.gate_id.to_string(),
+        status: format!("{:?}", gr.status).to_lowercase(),
+        passed: gr.passed,
+        score: gr.score,
+        summary: gr.summary.clone(),
+        violation_count: gr.violations.len() as u32,
+        warning_count: gr.warnings.len() as u32,
+        execution_time_ms: gr.execution_time_ms,
+        error: gr.error.clone(),
+    }
+}
+
+fn violation(v: &drift_analysis::enforcement::rules::Violation) -> Violation {
+    Violation {
+        id: v.id.clone(),
+        file: v.file.clone(),
+        line: v.line,
+        column: v.column,
+        severity: format!("{:?}", v.severity).to_lowercase(),
+        pattern_id: v.pattern_id.clone(),
+        rule_id: v.rule_id.clone(),
+        message: v.message.clone(),
+        cwe_id: v.cwe_id,
+        owasp_category: v.owasp_category.clone(),
+        suppressed: v.suppressed,
+        is_new: v.is_new,
+        owners: v.owners.clone(),
+    }
+}
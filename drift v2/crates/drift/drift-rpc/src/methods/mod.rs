@@ -0,0 +1,207 @@
+//! Method table and the per-request context handlers run with.
+//!
+//! Lifecycle methods (`initialize`, `rpc.discover`, `shutdown`) are
+//! handled by the server itself; everything in [`METHODS`] runs on its own
+//! thread once the session is initialized.
+
+mod analyze;
+mod context;
+mod enforcement;
+mod query;
+mod scan;
+
+use std::sync::Arc;
+
+use drift_analysis::scanner::cancellation::ScanCancellation;
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::protocol::{Notification, RpcError};
+use crate::server::Output;
+use crate::session::Session;
+use crate::types::{
+    CancelParams, DiscoverResult, EmptyParams, InitializeParams, InitializeResult, MethodDescriptor,
+    NotificationDescriptor, ProgressParams,
+};
+use crate::API_VERSION;
+
+pub use analyze::analyze;
+pub use context::generate_context;
+pub use enforcement::check;
+pub use query::{query_detections, query_files, query_functions, query_gates, query_scan_history, query_violations};
+pub use scan::scan;
+
+/// The session, cancellation handle and progress sink of one request.
+pub struct RequestContext {
+    pub session: Arc<Session>,
+    pub cancellation: ScanCancellation,
+    progress: Option<(Value, Output)>,
+}
+
+impl RequestContext {
+    /// A context without progress reporting, for calling handlers directly.
+    pub fn new(session: Arc<Session>, cancellation: ScanCancellation) -> Self {
+        Self { session, cancellation, progress: None }
+    }
+
+    pub(crate) fn with_progress(session: Arc<Session>, cancellation: ScanCancellation, id: Value, output: Output) -> Self {
+        Self { session, cancellation, progress: Some((id, output)) }
+    }
+
+    /// Send a `$/progress` notification for this request.
+    pub fn progress(&self, phase: &str, processed: usize, total: usize) {
+        if let Some((id, output)) = &self.progress {
+            let params = ProgressParams { request_id: id.clone(), phase: phase.to_string(), processed, total };
+            output.notify(&Notification::new(
+                "$/progress",
+                serde_json::to_value(params).unwrap_or(Value::Null),
+            ));
+        }
+    }
+
+    /// `Err(cancelled)` once `$/cancelRequest` has been received.
+    pub fn check_cancelled(&self) -> Result<(), RpcError> {
+        if self.cancellation.is_cancelled() {
+            Err(RpcError::cancelled())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+type Handler = fn(&RequestContext, Option<Value>) -> Result<Value, RpcError>;
+type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+/// A method callable after `initialize`.
+pub struct Method {
+    pub name: &'static str,
+    pub summary: &'static str,
+    /// Whether the handler polls for cancellation.
+    pub cancellable: bool,
+    pub(crate) handler: Handler,
+    params: SchemaFn,
+    result: SchemaFn,
+}
+
+macro_rules! method {
+    ($name:literal, $summary:literal, cancellable: $cancellable:literal, $handler:path, $params:ty => $result:ty) => {{
+        fn handle(ctx: &RequestContext, params: Option<Value>) -> Result<Value, RpcError> {
+            dispatch::<$params, $result>(ctx, params, $handler)
+        }
+        Method {
+            name: $name,
+            summary: $summary,
+            cancellable: $cancellable,
+            handler: handle,
+            params: |gen| gen.subschema_for::<$params>(),
+            result: |gen| gen.subschema_for::<$result>(),
+        }
+    }};
+}
+
+pub static METHODS: &[Method] = &[
+    method!("scan", "Scan the project root and record file metadata in drift.db.",
+        cancellable: true, scan, crate::types::ScanParams => crate::types::ScanResult),
+    method!("analyze", "Parse and detect scanned files, replacing their stored detections and functions.",
+        cancellable: true, analyze, crate::types::AnalyzeParams => crate::types::AnalyzeResult),
+    method!("query.files", "Files recorded by the last scan.",
+        cancellable: false, query_files, crate::types::FilesParams => Vec<crate::types::FileInfo>),
+    method!("query.detections", "Stored detections, by file or category.",
+        cancellable: false, query_detections, crate::types::DetectionsParams => Vec<crate::types::Detection>),
+    method!("query.functions", "Stored functions of one file.",
+        cancellable: false, query_functions, crate::types::FunctionsParams => Vec<crate::types::Function>),
+    method!("query.violations", "Stored violations, by file or CODEOWNERS owner.",
+        cancellable: false, query_violations, crate::types::ViolationsParams => Vec<crate::types::Violation>),
+    method!("query.gates", "Stored quality gate results.",
+        cancellable: false, query_gates, EmptyParams => Vec<crate::types::GateResult>),
+    method!("query.scanHistory", "Recent scans, newest first.",
+        cancellable: false, query_scan_history, crate::types::ScanHistoryParams => Vec<crate::types::ScanHistoryEntry>),
+    method!("enforcement.check", "Run the quality gates over stored detections and persist the results.",
+        cancellable: true, check, crate::types::CheckParams => crate::types::CheckResult),
+    method!("context.generate", "Generate intent-weighted context within the depth's token budget.",
+        cancellable: false, generate_context, crate::types::ContextParams => crate::types::ContextResult),
+];
+
+pub fn find(name: &str) -> Option<&'static Method> {
+    METHODS.iter().find(|m| m.name == name)
+}
+
+/// Names of every method, lifecycle ones first.
+pub fn method_names() -> Vec<String> {
+    ["initialize", "rpc.discover", "shutdown"]
+        .into_iter()
+        .chain(METHODS.iter().map(|m| m.name))
+        .map(str::to_string)
+        .collect()
+}
+
+fn dispatch<P: DeserializeOwned, R: Serialize>(
+    ctx: &RequestContext,
+    params: Option<Value>,
+    handler: fn(&RequestContext, P) -> Result<R, RpcError>,
+) -> Result<Value, RpcError> {
+    let params = serde_json::from_value(params.unwrap_or_else(|| Value::Object(Default::default())))
+        .map_err(RpcError::invalid_params)?;
+    let result = handler(ctx, params)?;
+    serde_json::to_value(result).map_err(|e| RpcError::internal(format!("cannot serialize result: {e}")))
+}
+
+/// The `rpc.discover` document: every method and notification with its
+/// params and result schemas.
+pub fn describe() -> DiscoverResult {
+    let mut gen = SchemaSettings::draft07().into_generator();
+    let mut methods = vec![
+        lifecycle::<InitializeParams, InitializeResult>(&mut gen, "initialize", "Bind the connection to a project root and open its drift.db."),
+        lifecycle::<EmptyParams, DiscoverResult>(&mut gen, "rpc.discover", "Describe the API. Callable before initialize."),
+        lifecycle::<EmptyParams, Option<()>>(&mut gen, "shutdown", "Cancel in-flight requests, flush storage and close the connection."),
+    ];
+    for method in METHODS {
+        let params = (method.params)(&mut gen);
+        let result = (method.result)(&mut gen);
+        methods.push(MethodDescriptor {
+            name: method.name.to_string(),
+            summary: method.summary.to_string(),
+            cancellable: method.cancellable,
+            params: to_value(&params),
+            result: to_value(&result),
+        });
+    }
+    let notifications = vec![
+        NotificationDescriptor {
+            name: "$/cancelRequest".to_string(),
+            summary: "Cancel an in-flight request; it fails with code -32800.".to_string(),
+            direction: "client".to_string(),
+            params: to_value(&gen.subschema_for::<CancelParams>()),
+        },
+        NotificationDescriptor {
+            name: "$/progress".to_string(),
+            summary: "Progress of a long-running request.".to_string(),
+            direction: "server".to_string(),
+            params: to_value(&gen.subschema_for::<ProgressParams>()),
+        },
+    ];
+    DiscoverResult {
+        api_version: API_VERSION.to_string(),
+        methods,
+        notifications,
+        definitions: gen.take_definitions().into_iter().map(|(name, schema)| (name, to_value(&schema))).collect(),
+    }
+}
+
+fn lifecycle<P: JsonSchema, R: JsonSchema>(gen: &mut SchemaGenerator, name: &str, summary: &str) -> MethodDescriptor {
+    MethodDescriptor {
+        name: name.to_string(),
+        summary: summary.to_string(),
+        cancellable: false,
+        params: to_value(&gen.subschema_for::<P>()),
+        result: to_value(&gen.subschema_for::<R>()),
+    }
+}
+
+fn to_value(schema: &Schema) -> Value {
+    serde_json::to_value(schema).unwrap_or(Value::Null)
+}
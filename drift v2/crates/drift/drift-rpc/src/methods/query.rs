@@ -0,0 +1,156 @@
+//! `query.*`: read-only views over drift.db.
+
+use drift_analysis::enforcement::codeowners::is_owned_by;
+use drift_storage::queries::detections::{self, DetectionRecord};
+use drift_storage::queries::enforcement::{self, GateResultRow, ViolationRow};
+use drift_storage::queries::{files, functions, scan_history};
+
+use super::RequestContext;
+use crate::protocol::RpcError;
+use crate::types::{
+    Detection, DetectionsParams, EmptyParams, FileInfo, FilesParams, Function, FunctionsParams, GateResult,
+    ScanHistoryEntry, ScanHistoryParams, Violation, ViolationsParams,
+};
+
+const DEFAULT_DETECTION_LIMIT: usize = 1000;
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+
+pub fn query_files(ctx: &RequestContext, params: FilesParams) -> Result<Vec<FileInfo>, RpcError> {
+    let records = ctx.session.storage.with_reader(files::load_all_file_metadata)?;
+    Ok(records
+        .into_iter()
+        .filter(|f| params.language.is_none() || f.language == params.language)
+        .take(params.limit.unwrap_or(usize::MAX))
+        .map(|f| FileInfo {
+            path: f.path,
+            language: f.language,
+            file_size: f.file_size,
+            last_scanned_at: f.last_scanned_at,
+        })
+        .collect())
+}
+
+pub fn query_detections(ctx: &RequestContext, params: DetectionsParams) -> Result<Vec<Detection>, RpcError> {
+    let limit = params.limit.unwrap_or(DEFAULT_DETECTION_LIMIT);
+    let records = ctx.session.storage.with_reader(|conn| match (&params.file, &params.category) {
+        (Some(file), _) => detections::get_detections_by_file(conn, file),
+        (None, Some(category)) => detections::get_detections_by_category(conn, category),
+        (None, None) => detections::query_all_detections(conn, limit),
+    })?;
+    Ok(records
+        .into_iter()
+        .filter(|d| params.category.is_none() || params.category.as_ref() == Some(&d.category))
+        .take(limit)
+        .map(detection)
+        .collect())
+}
+
+pub fn query_functions(ctx: &RequestContext, params: FunctionsParams) -> Result<Vec<Function>, RpcError> {
+    let records = ctx.session.storage.with_reader(|conn| functions::get_functions_by_file(conn, &params.file))?;
+    Ok(records
+        .into_iter()
+        .map(|f| Function {
+            file: f.file,
+            name: f.name,
+            qualified_name: f.qualified_name,
+            language: f.language,
+            line: f.line,
+            end_line: f.end_line,
+            parameter_count: f.parameter_count,
+            return_type: f.return_type,
+            is_exported: f.is_exported,
+            is_async: f.is_async,
+        })
+        .collect())
+}
+
+pub fn query_violations(ctx: &RequestContext, params: ViolationsParams) -> Result<Vec<Violation>, RpcError> {
+    let rows = ctx.session.storage.with_reader(|conn| match (&params.file, &params.owner) {
+        (Some(file), _) => enforcement::query_violations_by_file(conn, file),
+        (None, Some(owner)) => enforcement::query_violations_by_owner(conn, owner),
+        (None, None) => enforcement::query_all_violations(conn),
+    })?;
+    let owned = |v: &ViolationRow| match (&params.file, &params.owner) {
+        (Some(_), Some(owner)) => is_owned_by(&v.owners, owner),
+        _ => true,
+    };
+    Ok(rows.into_iter().filter(owned).map(violation).collect())
+}
+
+pub fn query_gates(ctx: &RequestContext, _params: EmptyParams) -> Result<Vec<GateResult>, RpcError> {
+    let rows = ctx.session.storage.with_reader(enforcement::query_gate_results)?;
+    Ok(rows.into_iter().map(gate_result).collect())
+}
+
+pub fn query_scan_history(ctx: &RequestContext, params: ScanHistoryParams) -> Result<Vec<ScanHistoryEntry>, RpcError> {
+    let limit = params.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    let rows = ctx.session.storage.with_reader(|conn| scan_history::query_recent(conn, limit))?;
+    Ok(rows
+        .into_iter()
+        .map(|r| ScanHistoryEntry {
+            id: r.id,
+            started_at: r.started_at,
+            completed_at: r.completed_at,
+            root_path: r.root_path,
+            total_files: r.total_files,
+            added_files: r.added_files,
+            modified_files: r.modified_files,
+            removed_files: r.removed_files,
+            unchanged_files: r.unchanged_files,
+            duration_ms: r.duration_ms,
+            status: r.status,
+            error: r.error,
+        })
+        .collect())
+}
+
+fn detection(d: DetectionRecord) -> Detection {
+    Detection {
+        file: d.file,
+        line: d.line,
+        column: d.column_num,
+        pattern_id: d.pattern_id,
+        category: d.category,
+        confidence: d.confidence,
+        detection_method: d.detection_method,
+        cwe_ids: d
+            .cwe_ids
+            .as_deref()
+            .map(|ids| ids.split(',').filter_map(|id| id.trim().parse().ok()).collect())
+            .unwrap_or_default(),
+        owasp: d.owasp,
+        matched_text: d.matched_text,
+    }
+}
+
+fn violation(v: ViolationRow) -> Violation {
+    Violation {
+        id: v.id,
+        file: v.file,
+        line: v.line,
+        column: v.column,
+        severity: v.severity,
+        pattern_id: v.pattern_id,
+        rule_id: v.rule_id,
+        message: v.message,
+        cwe_id: v.cwe_id,
+        owasp_category: v.owasp_category,
+        suppressed: v.suppressed,
+        is_new: v.is_new,
+        owners: v.owners,
+    }
+}
+
+fn gate_result(g: GateResultRow) -> GateResult {
+    GateResult {
+        gate_id: g.gate_id,
+        status: g.status,
+        passed: g.passed,
+        score: g.score,
+        summary: g.summary,
+        violation_count: g.violation_count,
+        warning_count: g.warning_count,
+        execution_time_ms: g.execution_time_ms,
+        error: g.error,
+    }
+}
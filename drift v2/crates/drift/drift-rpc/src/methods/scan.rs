@@ -0,0 +1,150 @@
+//! `scan`: incremental scan of the session root, persisted like `driftScan`.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use drift_analysis::scanner::language_detect::Language;
+use drift_analysis::scanner::types::{CachedFileMetadata, ScanDiff};
+use drift_analysis::scanner::Scanner;
+use drift_core::config::ScanConfig;
+use drift_core::events::handler::DriftEventHandler;
+use drift_core::events::types::ScanProgressEvent;
+use drift_core::types::collections::FxHashMap;
+use drift_storage::batch::commands::{BatchCommand, FileMetadataRow};
+use drift_storage::queries::{files, scan_history};
+
+use super::RequestContext;
+use crate::protocol::RpcError;
+use crate::session::Session;
+use crate::types::{ScanParams, ScanResult};
+
+pub fn scan(ctx: &RequestContext, params: ScanParams) -> Result<ScanResult, RpcError> {
+    let session = &ctx.session;
+    let cached = load_cached_metadata(session)?;
+    let scanner = Scanner::with_cancellation(scan_config(&session.config.scan, params), ctx.cancellation.clone());
+
+    // `Scanner::scan` resets the handle, so honour a cancel that arrived first.
+    ctx.check_cancelled()?;
+    let diff = scanner.scan(&session.root, &cached, &ProgressHandler(ctx))?;
+    // A cancelled scan returns an empty diff that would mark every cached
+    // file as removed; never persist it.
+    ctx.check_cancelled()?;
+
+    persist_scan_diff(session, &diff)?;
+
+    let mut languages = BTreeMap::new();
+    for (language, count) in &diff.stats.languages_found {
+        languages.insert(language.name().to_string(), *count);
+    }
+    Ok(ScanResult {
+        added: diff.added.len(),
+        modified: diff.modified.len(),
+        removed: diff.removed.len(),
+        unchanged: diff.unchanged.len(),
+        total_files: diff.stats.total_files,
+        total_size_bytes: diff.stats.total_size_bytes,
+        languages,
+        duration_ms: diff.stats.discovery_ms + diff.stats.hashing_ms + diff.stats.diff_ms,
+    })
+}
+
+struct ProgressHandler<'a>(&'a RequestContext);
+
+impl DriftEventHandler for ProgressHandler<'_> {
+    fn on_scan_progress(&self, event: &ScanProgressEvent) {
+        self.0.progress("scanning", event.processed, event.total);
+    }
+}
+
+fn scan_config(base: &ScanConfig, params: ScanParams) -> ScanConfig {
+    let mut config = base.clone();
+    if params.force_full.is_some() {
+        config.force_full_scan = params.force_full;
+    }
+    if params.max_file_size.is_some() {
+        config.max_file_size = params.max_file_size;
+    }
+    if params.follow_symlinks.is_some() {
+        config.follow_symlinks = params.follow_symlinks;
+    }
+    config.include.extend(params.include);
+    config.extra_ignore.extend(params.extra_ignore);
+    config
+}
+
+fn load_cached_metadata(session: &Session) -> Result<FxHashMap<PathBuf, CachedFileMetadata>, RpcError> {
+    let records = session.storage.with_reader(files::load_all_file_metadata)?;
+    let mut cached = FxHashMap::default();
+    for record in records {
+        let path = PathBuf::from(&record.path);
+        let content_hash = <[u8; 8]>::try_from(record.content_hash.as_slice()).map(u64::from_le_bytes).unwrap_or(0);
+        cached.insert(
+            path.clone(),
+            CachedFileMetadata {
+                path,
+                content_hash,
+                mtime_secs: record.mtime_secs,
+                mtime_nanos: record.mtime_nanos as u32,
+                file_size: record.file_size as u64,
+                language: record.language.as_deref().and_then(Language::from_name),
+            },
+        );
+    }
+    Ok(cached)
+}
+
+/// Upsert scanned files, drop removed ones and record the scan in
+/// scan_history, then flush so a following `analyze` sees the rows.
+fn persist_scan_diff(session: &Session, diff: &ScanDiff) -> Result<(), RpcError> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as i64;
+    let rows: Vec<FileMetadataRow> = diff
+        .entries
+        .values()
+        .map(|entry| FileMetadataRow {
+            path: entry.path.to_string_lossy().to_string(),
+            language: entry.language.map(|l| l.to_string()),
+            file_size: entry.file_size as i64,
+            content_hash: entry.content_hash.to_le_bytes().to_vec(),
+            mtime_secs: entry.mtime_secs,
+            mtime_nanos: entry.mtime_nanos as i64,
+            last_scanned_at: now,
+            scan_duration_us: Some(entry.scan_duration_us as i64),
+        })
+        .collect();
+    if !rows.is_empty() {
+        session.storage.send_batch(BatchCommand::UpsertFileMetadata(rows))?;
+    }
+    if !diff.removed.is_empty() {
+        let paths = diff.removed.iter().map(|p| p.to_string_lossy().to_string()).collect();
+        session.storage.send_batch(BatchCommand::DeleteFileMetadata(paths))?;
+    }
+
+    let (added, modified, removed, unchanged) = (
+        diff.added.len() as i64,
+        diff.modified.len() as i64,
+        diff.removed.len() as i64,
+        diff.unchanged.len() as i64,
+    );
+    let duration_ms = (diff.stats.discovery_ms + diff.stats.hashing_ms + diff.stats.diff_ms) as i64;
+    let root = session.root.to_string_lossy().to_string();
+    session.storage.with_writer(|conn| {
+        let scan_id = scan_history::insert_scan_start(conn, now, &root)?;
+        scan_history::update_scan_complete(
+            conn,
+            scan_id,
+            now,
+            added + modified + removed + unchanged,
+            added,
+            modified,
+            removed,
+            unchanged,
+            duration_ms,
+            "completed",
+            None,
+        )
+    })?;
+
+    session.storage.flush_batch_sync()?;
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! JSON-RPC 2.0 framing: requests, responses, notifications and errors.
+//!
+//! Messages are newline-delimited JSON objects. Batch requests are not
+//! supported and are answered with `INVALID_REQUEST`.
+
+use drift_core::errors::{error_code, DriftErrorCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Standard and server-defined error codes.
+pub mod codes {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// An engine error; `data.code` holds the Drift error code.
+    pub const DRIFT_ERROR: i64 = -32000;
+    /// The client asked for an incompatible major API version.
+    pub const VERSION_MISMATCH: i64 = -32001;
+    /// A method other than `initialize` or `rpc.discover` was called first.
+    pub const NOT_INITIALIZED: i64 = -32002;
+    /// The request was cancelled with `$/cancelRequest`.
+    pub const REQUEST_CANCELLED: i64 = -32800;
+}
+
+/// An incoming request, or a notification when `id` is absent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Message {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Response {
+    pub jsonrpc: &'static str,
+    pub id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl Response {
+    pub fn new(id: Value, result: Result<Value, RpcError>) -> Self {
+        match result {
+            Ok(result) => Self { jsonrpc: JSONRPC_VERSION, id, result: Some(result), error: None },
+            Err(error) => Self { jsonrpc: JSONRPC_VERSION, id, result: None, error: Some(error) },
+        }
+    }
+}
+
+/// A server-to-client notification.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub jsonrpc: &'static str,
+    pub method: &'static str,
+    pub params: Value,
+}
+
+impl Notification {
+    pub fn new(method: &'static str, params: Value) -> Self {
+        Self { jsonrpc: JSONRPC_VERSION, method, params }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self { code, message: message.into(), data: None }
+    }
+
+    pub fn parse_error(message: impl std::fmt::Display) -> Self {
+        Self::new(codes::PARSE_ERROR, format!("parse error: {message}"))
+    }
+
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(codes::INVALID_REQUEST, message)
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(codes::METHOD_NOT_FOUND, format!("unknown method '{method}'"))
+    }
+
+    pub fn invalid_params(message: impl std::fmt::Display) -> Self {
+        Self::new(codes::INVALID_PARAMS, format!("invalid params: {message}"))
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(codes::INTERNAL_ERROR, message)
+    }
+
+    pub fn not_initialized() -> Self {
+        Self::new(codes::NOT_INITIALIZED, "server not initialized; call 'initialize' first")
+    }
+
+    pub fn cancelled() -> Self {
+        Self::drift(error_code::CANCELLED, "request cancelled").with_code(codes::REQUEST_CANCELLED)
+    }
+
+    /// An engine error in the `[ERROR_CODE] message` format the other
+    /// bindings use, with the Drift code repeated in `data.code`.
+    pub fn drift(code: &str, message: impl std::fmt::Display) -> Self {
+        Self {
+            code: codes::DRIFT_ERROR,
+            message: format!("[{code}] {message}"),
+            data: Some(serde_json::json!({ "code": code })),
+        }
+    }
+
+    fn with_code(mut self, code: i64) -> Self {
+        self.code = code;
+        self
+    }
+}
+
+/// Typed Drift errors map to `DRIFT_ERROR`, except cancellation.
+impl<E: DriftErrorCode + std::fmt::Display> From<E> for RpcError {
+    fn from(err: E) -> Self {
+        if err.error_code() == error_code::CANCELLED {
+            return Self::cancelled();
+        }
+        Self {
+            code: codes::DRIFT_ERROR,
+            message: err.napi_string(),
+            data: Some(serde_json::json!({ "code": err.error_code() })),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Connection loop: reads newline-delimited requests, runs each method on
+//! its own thread and writes responses and notifications as they complete.
+//!
+//! Responses may arrive out of order; clients match them by `id`. Each
+//! in-flight request has a [`ScanCancellation`] that `$/cancelRequest`
+//! trips. `shutdown`, or the input closing, cancels and joins whatever is
+//! still running and flushes the batch writer before returning.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use drift_analysis::scanner::cancellation::ScanCancellation;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::methods::{self, RequestContext};
+use crate::protocol::{Message, Response, RpcError, JSONRPC_VERSION};
+use crate::session::Session;
+use crate::types::{CancelParams, InitializeParams, InitializeResult};
+use crate::API_VERSION;
+
+/// Shared, line-buffered writer for responses and notifications.
+#[derive(Clone)]
+pub(crate) struct Output {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl Output {
+    fn new(writer: impl Write + Send + 'static) -> Self {
+        Self { writer: Arc::new(Mutex::new(Box::new(writer))) }
+    }
+
+    pub(crate) fn notify(&self, message: &impl Serialize) {
+        // A client that went away cannot be told; the read side sees EOF.
+        if let Err(e) = self.write(message) {
+            tracing::debug!(error = %e, "drift-rpc: dropped outgoing message");
+        }
+    }
+
+    fn write(&self, message: &impl Serialize) -> io::Result<()> {
+        let mut line = serde_json::to_vec(message)?;
+        line.push(b'\n');
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        writer.write_all(&line)?;
+        writer.flush()
+    }
+}
+
+/// One client connection and, after `initialize`, its project session.
+#[derive(Default)]
+pub struct Server {
+    session: Option<Arc<Session>>,
+    in_flight: Arc<Mutex<HashMap<String, ScanCancellation>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+enum Flow {
+    Continue,
+    Shutdown,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve requests from `input` until `shutdown` or end of input.
+    pub fn serve(mut self, input: impl BufRead, output: impl Write + Send + 'static) -> io::Result<()> {
+        let output = Output::new(output);
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Flow::Shutdown = self.handle_line(&line, &output) {
+                break;
+            }
+            self.workers.retain(|w| !w.is_finished());
+        }
+        self.shutdown();
+        Ok(())
+    }
+
+    fn handle_line(&mut self, line: &str, output: &Output) -> Flow {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(e) => {
+                output.notify(&Response::new(Value::Null, Err(RpcError::parse_error(e))));
+                return Flow::Continue;
+            }
+        };
+        if value.is_array() {
+            output.notify(&Response::new(Value::Null, Err(RpcError::invalid_request("batch requests are not supported"))));
+            return Flow::Continue;
+        }
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        let message = match serde_json::from_value::<Message>(value) {
+            Ok(message) if message.jsonrpc == JSONRPC_VERSION => message,
+            Ok(_) => {
+                output.notify(&Response::new(id, Err(RpcError::invalid_request("jsonrpc must be \"2.0\""))));
+                return Flow::Continue;
+            }
+            Err(e) => {
+                output.notify(&Response::new(id, Err(RpcError::invalid_request(e.to_string()))));
+                return Flow::Continue;
+            }
+        };
+
+        let Some(id) = message.id else {
+            self.handle_notification(&message.method, message.params);
+            return Flow::Continue;
+        };
+
+        match message.method.as_str() {
+            "initialize" => output.notify(&Response::new(id, self.initialize(message.params))),
+            "rpc.discover" => output.notify(&Response::new(id, to_value(methods::describe()))),
+            "shutdown" => {
+                self.shutdown();
+                output.notify(&Response::new(id, Ok(Value::Null)));
+                return Flow::Shutdown;
+            }
+            name => {
+                if let Err(error) = self.spawn(name, id.clone(), message.params, output) {
+                    output.notify(&Response::new(id, Err(error)));
+                }
+            }
+        }
+        Flow::Continue
+    }
+
+    fn handle_notification(&mut self, method: &str, params: Option<Value>) {
+        if method == "$/cancelRequest" {
+            let Some(params) = params.and_then(|p| serde_json::from_value::<CancelParams>(p).ok()) else {
+                return;
+            };
+            if let Some(cancellation) = self.lock_in_flight().get(&id_key(&params.id)) {
+                cancellation.cancel();
+            }
+        }
+        // Other notifications are ignored, as JSON-RPC requires.
+    }
+
+    fn initialize(&mut self, params: Option<Value>) -> Result<Value, RpcError> {
+        if self.session.is_some() {
+            return Err(RpcError::invalid_request("already initialized"));
+        }
+        let params: InitializeParams = serde_json::from_value(params.unwrap_or(Value::Null))
+            .map_err(RpcError::invalid_params)?;
+        let session = Session::open(&params)?;
+        let result = InitializeResult {
+            api_version: API_VERSION.to_string(),
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            root: session.root.to_string_lossy().to_string(),
+            db_path: session.db_path.to_string_lossy().to_string(),
+            methods: methods::method_names(),
+        };
+        self.session = Some(Arc::new(session));
+        to_value(result)
+    }
+
+    fn spawn(&mut self, name: &str, id: Value, params: Option<Value>, output: &Output) -> Result<(), RpcError> {
+        let method = methods::find(name).ok_or_else(|| RpcError::method_not_found(name))?;
+        let session = self.session.clone().ok_or_else(RpcError::not_initialized)?;
+
+        let key = id_key(&id);
+        let cancellation = ScanCancellation::new();
+        {
+            let mut in_flight = self.lock_in_flight();
+            if in_flight.contains_key(&key) {
+                return Err(RpcError::invalid_request(format!("request id {key} is already in flight")));
+            }
+            in_flight.insert(key.clone(), cancellation.clone());
+        }
+
+        let in_flight = self.in_flight.clone();
+        let worker_key = key.clone();
+        let output = output.clone();
+        let ctx = RequestContext::with_progress(session, cancellation, id.clone(), output.clone());
+        let worker = std::thread::Builder::new()
+            .name(format!("drift-rpc-{}", method.name))
+            .spawn(move || {
+                let result = (method.handler)(&ctx, params);
+                in_flight.lock().unwrap_or_else(|e| e.into_inner()).remove(&worker_key);
+                output.notify(&Response::new(id, result));
+            })
+            .map_err(|e| {
+                self.lock_in_flight().remove(&key);
+                RpcError::internal(format!("cannot start request thread: {e}"))
+            })?;
+        self.workers.push(worker);
+        Ok(())
+    }
+
+    /// Cancel and join in-flight requests, then flush pending writes.
+    fn shutdown(&mut self) {
+        for cancellation in self.lock_in_flight().values() {
+            cancellation.cancel();
+        }
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+        if let Some(session) = &self.session {
+            if let Err(e) = session.storage.flush_batch_sync() {
+                tracing::warn!(error = %e, "drift-rpc: flush on shutdown failed");
+            }
+        }
+    }
+
+    fn lock_in_flight(&self) -> std::sync::MutexGuard<'_, HashMap<String, ScanCancellation>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Request ids are strings or numbers; key them by their JSON text.
+fn id_key(id: &Value) -> String {
+    id.to_string()
+}
+
+fn to_value(result: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(result).map_err(|e| RpcError::internal(format!("cannot serialize result: {e}")))
+}
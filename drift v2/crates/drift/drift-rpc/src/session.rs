@@ -0,0 +1,75 @@
+//! The project a connection is bound to by `initialize`.
+
+use std::path::PathBuf;
+
+use drift_core::config::DriftConfig;
+use drift_core::errors::error_code;
+use drift_storage::connection::encryption::DatabaseKey;
+use drift_storage::DriftStorageEngine;
+
+use crate::protocol::{codes, RpcError};
+use crate::types::InitializeParams;
+use crate::API_VERSION;
+
+/// Configuration and storage for one project root.
+pub struct Session {
+    pub root: PathBuf,
+    pub db_path: PathBuf,
+    pub config: DriftConfig,
+    pub storage: DriftStorageEngine,
+}
+
+impl Session {
+    /// Resolve configuration and open drift.db the way the N-API runtime
+    /// does: inline TOML, else the project's drift.toml, else defaults.
+    pub fn open(params: &InitializeParams) -> Result<Self, RpcError> {
+        if let Some(requested) = &params.api_version {
+            check_api_version(requested)?;
+        }
+
+        let root = PathBuf::from(&params.root);
+        if !root.is_dir() {
+            return Err(RpcError::drift(
+                error_code::CONFIG_ERROR,
+                format!("project root '{}' is not a directory", root.display()),
+            ));
+        }
+
+        let config = match &params.config_toml {
+            Some(toml) => DriftConfig::from_toml(toml)?,
+            None => DriftConfig::load(&root, None).unwrap_or_default(),
+        };
+
+        let db_path = params
+            .db_path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| root.join(".drift").join("drift.db"));
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                RpcError::drift(error_code::STORAGE_ERROR, format!("cannot create database directory: {e}"))
+            })?;
+        }
+        let storage = DatabaseKey::from_env().and_then(|key| match key {
+            Some(key) => DriftStorageEngine::open_encrypted(&db_path, key),
+            None => DriftStorageEngine::open(&db_path),
+        })?;
+
+        Ok(Self { root, db_path, config, storage })
+    }
+}
+
+/// Clients may be older within the same major version; any other major
+/// version is rejected.
+fn check_api_version(requested: &str) -> Result<(), RpcError> {
+    let major = |v: &str| v.split('.').next().and_then(|m| m.parse::<u64>().ok());
+    match major(requested) {
+        Some(m) if Some(m) == major(API_VERSION) => Ok(()),
+        Some(_) => Err(RpcError {
+            code: codes::VERSION_MISMATCH,
+            message: format!("API version {requested} is not supported (server implements {API_VERSION})"),
+            data: Some(serde_json::json!({ "supported": API_VERSION })),
+        }),
+        None => Err(RpcError::invalid_params(format!("malformed apiVersion '{requested}'"))),
+    }
+}
@@ -0,0 +1,72 @@
+//! Stdio and Unix socket transports. Both carry the same newline-delimited
+//! JSON-RPC stream; each socket connection gets its own [`Server`] and so
+//! its own session.
+
+use std::io::{self, BufReader};
+
+use crate::server::Server;
+
+/// Serve one client on stdin/stdout until `shutdown` or EOF.
+pub fn serve_stdio() -> io::Result<()> {
+    Server::new().serve(io::stdin().lock(), io::stdout())
+}
+
+/// Listen on a Unix socket at `path`, one thread per connection. A stale
+/// socket file left by an earlier run is replaced; anything else at `path`,
+/// including a socket another server still answers on, is an error.
+#[cfg(unix)]
+pub fn serve_unix(path: &std::path::Path) -> io::Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    remove_stale_socket(path)?;
+    let listener = UnixListener::bind(path)?;
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                tracing::warn!(error = %e, "drift-rpc: failed to accept connection");
+                continue;
+            }
+        };
+        let reader = match stream.try_clone() {
+            Ok(clone) => BufReader::new(clone),
+            Err(e) => {
+                tracing::warn!(error = %e, "drift-rpc: failed to set up connection");
+                continue;
+            }
+        };
+        std::thread::spawn(move || {
+            if let Err(e) = Server::new().serve(reader, stream) {
+                tracing::warn!(error = %e, "drift-rpc: connection closed with error");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Remove `path` only if it is a socket nobody is listening on.
+#[cfg(unix)]
+fn remove_stale_socket(path: &std::path::Path) -> io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixStream;
+
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        ));
+    }
+    match UnixStream::connect(path) {
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("another server is listening on {}", path.display()),
+        )),
+        Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => std::fs::remove_file(path),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,346 @@
+//! Method params and results. These are the wire types of the versioned
+//! API: fields are camelCase, and every type derives `JsonSchema` so
+//! `rpc.discover` and `drift-rpc --schema` describe exactly what is sent.
+//!
+//! Adding an optional field is a minor version bump; renaming or removing
+//! one is a major bump of [`crate::API_VERSION`].
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// ─── Lifecycle ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeParams {
+    /// Project root. Scanned paths are recorded under it as given.
+    pub root: String,
+    /// drift.db location. Defaults to `<root>/.drift/drift.db`.
+    #[serde(default)]
+    pub db_path: Option<String>,
+    /// Inline drift.toml. Defaults to the project's configuration.
+    #[serde(default)]
+    pub config_toml: Option<String>,
+    /// API version the client was written against. Rejected when its
+    /// major version differs from the server's.
+    #[serde(default)]
+    pub api_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResult {
+    pub api_version: String,
+    pub server_version: String,
+    pub root: String,
+    pub db_path: String,
+    /// Names of all methods, including lifecycle methods.
+    pub methods: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct EmptyParams {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoverResult {
+    pub api_version: String,
+    pub methods: Vec<MethodDescriptor>,
+    pub notifications: Vec<NotificationDescriptor>,
+    /// Shared schema definitions referenced by `#/definitions/...`.
+    pub definitions: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodDescriptor {
+    pub name: String,
+    pub summary: String,
+    /// Whether `$/cancelRequest` can stop the method early.
+    pub cancellable: bool,
+    pub params: Value,
+    pub result: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDescriptor {
+    pub name: String,
+    pub summary: String,
+    /// `"client"` or `"server"`: who sends it.
+    pub direction: String,
+    pub params: Value,
+}
+
+/// `$/cancelRequest` params.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CancelParams {
+    /// Id of the request to cancel.
+    pub id: Value,
+}
+
+/// `$/progress` params.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressParams {
+    /// Id of the request reporting progress.
+    pub request_id: Value,
+    /// `"scanning"` or `"analyzing"`.
+    pub phase: String,
+    pub processed: usize,
+    pub total: usize,
+}
+
+// ─── scan / analyze ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScanParams {
+    /// Rehash every file instead of trusting cached mtimes.
+    pub force_full: Option<bool>,
+    pub max_file_size: Option<u64>,
+    /// Extra include globs, merged with the configured ones.
+    pub include: Vec<String>,
+    /// Extra ignore globs, merged with the configured ones.
+    pub extra_ignore: Vec<String>,
+    pub follow_symlinks: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanResult {
+    pub added: usize,
+    pub modified: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+    pub total_files: usize,
+    pub total_size_bytes: u64,
+    /// File count per language.
+    pub languages: BTreeMap<String, usize>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AnalyzeParams {
+    /// Files to analyze, as `query.files` lists them or relative to the
+    /// root. Defaults to every file the last scan recorded.
+    pub files: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeResult {
+    pub files_analyzed: usize,
+    pub detections: usize,
+    pub functions: usize,
+    /// Files that could not be read or parsed.
+    pub errors: Vec<FileError>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FileError {
+    pub path: String,
+    pub error: String,
+}
+
+// ─── query.* ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FilesParams {
+    pub language: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileInfo {
+    pub path: String,
+    pub language: Option<String>,
+    pub file_size: i64,
+    pub last_scanned_at: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectionsParams {
+    pub file: Option<String>,
+    /// Detection category, e.g. `"Security"`.
+    pub category: Option<String>,
+    /// Maximum rows, highest confidence first. Defaults to 1000.
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Detection {
+    pub file: String,
+    pub line: i64,
+    pub column: i64,
+    pub pattern_id: String,
+    pub category: String,
+    pub confidence: f64,
+    pub detection_method: String,
+    pub cwe_ids: Vec<u32>,
+    pub owasp: Option<String>,
+    pub matched_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionsParams {
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Function {
+    pub file: String,
+    pub name: String,
+    pub qualified_name: Option<String>,
+    pub language: String,
+    pub line: i64,
+    pub end_line: i64,
+    pub parameter_count: i64,
+    pub return_type: Option<String>,
+    pub is_exported: bool,
+    pub is_async: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ViolationsParams {
+    pub file: Option<String>,
+    /// CODEOWNERS owner, or `"unowned"`.
+    pub owner: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub id: String,
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+    pub severity: String,
+    pub pattern_id: String,
+    pub rule_id: String,
+    pub message: String,
+    pub cwe_id: Option<u32>,
+    pub owasp_category: Option<String>,
+    pub suppressed: bool,
+    pub is_new: bool,
+    pub owners: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GateResult {
+    pub gate_id: String,
+    pub status: String,
+    pub passed: bool,
+    pub score: f64,
+    pub summary: String,
+    pub violation_count: u32,
+    pub warning_count: u32,
+    pub execution_time_ms: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ScanHistoryParams {
+    /// Defaults to 20.
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanHistoryEntry {
+    pub id: i64,
+    pub started_at: i64,
+    pub completed_at: Option<i64>,
+    pub root_path: String,
+    pub total_files: Option<i64>,
+    pub added_files: Option<i64>,
+    pub modified_files: Option<i64>,
+    pub removed_files: Option<i64>,
+    pub unchanged_files: Option<i64>,
+    pub duration_ms: Option<i64>,
+    pub status: String,
+    pub error: Option<String>,
+}
+
+// ─── enforcement.check ───────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CheckParams {
+    /// Only return violations owned by this CODEOWNERS owner, or
+    /// `"unowned"`. Gates are still evaluated over the whole project.
+    pub owner: Option<String>,
+    /// Also render a report: `"sarif"`, `"json"`, `"junit"`, ...
+    pub report: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    pub passed: bool,
+    pub gates: Vec<GateResult>,
+    pub violations: Vec<Violation>,
+    pub report: Option<String>,
+}
+
+// ─── context.generate ────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Intent {
+    FixBug,
+    AddFeature,
+    UnderstandCode,
+    SecurityAudit,
+    GenerateSpec,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Depth {
+    Overview,
+    #[default]
+    Standard,
+    Deep,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextParams {
+    pub intent: Intent,
+    #[serde(default)]
+    pub depth: Depth,
+    /// Extra sections by name (`"call_graph"`, `"change_history"`, ...).
+    /// `"overview"` and `"conventions"` are filled from drift.db unless
+    /// given here.
+    #[serde(default)]
+    pub sections: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextResult {
+    pub intent: String,
+    pub depth: String,
+    pub token_count: usize,
+    pub sections: Vec<ContextSection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ContextSection {
+    pub name: String,
+    pub content: String,
+}
@@ -0,0 +1,273 @@
+//! JSON-RPC server — lifecycle, methods, progress and cancellation over a
+//! socket pair.
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use drift_analysis::scanner::cancellation::ScanCancellation;
+use drift_rpc::methods::{self, RequestContext};
+use drift_rpc::protocol::codes;
+use drift_rpc::session::Session;
+use drift_rpc::types::{AnalyzeParams, InitializeParams, ScanParams};
+use drift_rpc::Server;
+use serde_json::{json, Value};
+
+const APP: &str = r#"import express from "express";
+
+export function getUser(req, res) {
+  const id = req.query.id;
+  return db.query("SELECT * FROM users WHERE id = " + id);
+}
+
+export async function listUsers() {
+  return db.query("SELECT * FROM users");
+}
+"#;
+
+struct Client {
+    writer: UnixStream,
+    reader: BufReader<UnixStream>,
+    server: Option<JoinHandle<()>>,
+    /// Notifications received while waiting for responses.
+    notifications: Vec<Value>,
+}
+
+impl Client {
+    fn start() -> Self {
+        let (client, server) = UnixStream::pair().unwrap();
+        let server_reader = BufReader::new(server.try_clone().unwrap());
+        let handle = std::thread::spawn(move || Server::new().serve(server_reader, server).unwrap());
+        Self {
+            reader: BufReader::new(client.try_clone().unwrap()),
+            writer: client,
+            server: Some(handle),
+            notifications: Vec::new(),
+        }
+    }
+
+    fn send(&mut self, message: Value) {
+        let mut line = serde_json::to_vec(&message).unwrap();
+        line.push(b'\n');
+        self.writer.write_all(&line).unwrap();
+    }
+
+    fn send_raw(&mut self, line: &str) -> Value {
+        self.writer.write_all(line.as_bytes()).unwrap();
+        self.writer.write_all(b"\n").unwrap();
+        self.next_response()
+    }
+
+    /// Send a request and wait for its response.
+    fn call(&mut self, id: u64, method: &str, params: Value) -> Value {
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        let response = self.next_response();
+        assert_eq!(response["id"], id, "unexpected response {response}");
+        response
+    }
+
+    fn next_response(&mut self) -> Value {
+        loop {
+            let mut line = String::new();
+            assert!(self.reader.read_line(&mut line).unwrap() > 0, "server closed the connection");
+            let message: Value = serde_json::from_str(&line).unwrap();
+            if message.get("method").is_some() {
+                self.notifications.push(message);
+            } else {
+                return message;
+            }
+        }
+    }
+
+    fn shutdown(mut self) {
+        let response = self.call(u64::MAX, "shutdown", json!(null));
+        assert_eq!(response["result"], Value::Null);
+        self.server.take().unwrap().join().unwrap();
+    }
+}
+
+fn project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/users.js"), APP).unwrap();
+    std::fs::write(dir.path().join("CODEOWNERS"), "*.js @web-team\n").unwrap();
+    dir
+}
+
+/// Keep drift.db outside the root so scans only see the project files.
+fn initialize(client: &mut Client, root: &std::path::Path, db_dir: &std::path::Path) -> Value {
+    client.call(
+        1,
+        "initialize",
+        json!({
+            "root": root.to_string_lossy(),
+            "dbPath": db_dir.join("drift.db").to_string_lossy(),
+            "apiVersion": "1.0",
+        }),
+    )
+}
+
+#[test]
+fn discover_describes_methods_before_initialize() {
+    let mut client = Client::start();
+    let response = client.call(1, "rpc.discover", json!(null));
+    let document = &response["result"];
+    assert_eq!(document["apiVersion"], drift_rpc::API_VERSION);
+
+    let methods = document["methods"].as_array().unwrap();
+    let scan = methods.iter().find(|m| m["name"] == "scan").expect("scan is described");
+    assert_eq!(scan["cancellable"], true);
+    assert_eq!(scan["params"]["$ref"], "#/definitions/ScanParams");
+    assert!(document["definitions"]["ScanResult"]["properties"]["totalFiles"].is_object());
+    assert!(methods.iter().any(|m| m["name"] == "initialize"));
+    assert!(document["notifications"].as_array().unwrap().iter().any(|n| n["name"] == "$/progress"));
+    client.shutdown();
+}
+
+#[test]
+fn protocol_errors() {
+    let mut client = Client::start();
+    assert_eq!(client.send_raw("{not json")["error"]["code"], codes::PARSE_ERROR);
+    assert_eq!(client.send_raw(r#"[{"jsonrpc":"2.0","id":1,"method":"scan"}]"#)["error"]["code"], codes::INVALID_REQUEST);
+    assert_eq!(client.send_raw(r#"{"jsonrpc":"1.0","id":1,"method":"scan"}"#)["error"]["code"], codes::INVALID_REQUEST);
+    assert_eq!(client.call(2, "scan", json!({}))["error"]["code"], codes::NOT_INITIALIZED);
+    assert_eq!(client.call(3, "no.such.method", json!({}))["error"]["code"], codes::METHOD_NOT_FOUND);
+
+    let dir = project();
+    let response = client.call(4, "initialize", json!({ "root": dir.path().to_string_lossy(), "apiVersion": "2.0.0" }));
+    assert_eq!(response["error"]["code"], codes::VERSION_MISMATCH);
+    let response = client.call(5, "initialize", json!({ "root": dir.path().join("missing").to_string_lossy() }));
+    assert_eq!(response["error"]["code"], codes::DRIFT_ERROR);
+    assert_eq!(response["error"]["data"]["code"], "CONFIG_ERROR");
+    client.shutdown();
+}
+
+#[test]
+fn scan_analyze_query_check_and_context() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let mut client = Client::start();
+    let init = initialize(&mut client, dir.path(), db_dir.path());
+    assert_eq!(init["result"]["apiVersion"], drift_rpc::API_VERSION);
+    assert!(init["result"]["methods"].as_array().unwrap().contains(&json!("enforcement.check")));
+    assert_eq!(client.call(2, "initialize", json!({ "root": dir.path().to_string_lossy() }))["error"]["code"], codes::INVALID_REQUEST);
+
+    let scan = client.call(3, "scan", json!({}));
+    assert_eq!(scan["result"]["added"], 2, "{scan}");
+    assert_eq!(scan["result"]["languages"], json!({ "JavaScript": 1 }));
+    assert!(client
+        .notifications
+        .iter()
+        .any(|n| n["method"] == "$/progress" && n["params"]["requestId"] == 3 && n["params"]["phase"] == "scanning"));
+
+    let users = dir.path().join("src/users.js").to_string_lossy().to_string();
+    let files = client.call(4, "query.files", json!({ "language": "JavaScript" }));
+    assert_eq!(files["result"][0]["path"], users.as_str(), "{files}");
+
+    let analyze = client.call(5, "analyze", json!({}));
+    assert_eq!(analyze["result"]["filesAnalyzed"], 1, "{analyze}");
+    assert_eq!(analyze["result"]["functions"], 2);
+    // Re-analyzing replaces the stored rows instead of duplicating them.
+    client.call(6, "analyze", json!({ "files": [users] }));
+    let functions = client.call(7, "query.functions", json!({ "file": users }));
+    let names: Vec<&str> = functions["result"].as_array().unwrap().iter().map(|f| f["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["getUser", "listUsers"]);
+    let detections = client.call(8, "query.detections", json!({ "file": users }));
+    assert_eq!(
+        detections["result"].as_array().unwrap().len(),
+        analyze["result"]["detections"].as_u64().unwrap() as usize
+    );
+
+    let check = client.call(9, "enforcement.check", json!({ "report": "sarif" }));
+    let result = &check["result"];
+    assert!(!result["gates"].as_array().unwrap().is_empty(), "{check}");
+    assert!(result["report"].as_str().unwrap().contains("\"version\""));
+    for violation in result["violations"].as_array().unwrap() {
+        assert_eq!(violation["owners"], json!(["@web-team"]));
+    }
+    let gates = client.call(10, "query.gates", json!(null));
+    assert_eq!(gates["result"].as_array().unwrap().len(), result["gates"].as_array().unwrap().len());
+    let unowned = client.call(11, "query.violations", json!({ "owner": "unowned" }));
+    assert_eq!(unowned["result"], json!([]));
+    let bad_report = client.call(12, "enforcement.check", json!({ "report": "pdf" }));
+    assert_eq!(bad_report["error"]["data"]["code"], "CONFIG_ERROR");
+
+    let context = client.call(13, "context.generate", json!({ "intent": "understand_code", "depth": "overview" }));
+    let sections = context["result"]["sections"].as_array().unwrap();
+    assert!(sections.iter().any(|s| s["name"] == "overview" && s["content"].as_str().unwrap().contains("JavaScript")));
+    assert_eq!(client.call(14, "context.generate", json!({ "intent": "refactor" }))["error"]["code"], codes::INVALID_PARAMS);
+
+    let history = client.call(15, "query.scanHistory", json!({ "limit": 5 }));
+    assert_eq!(history["result"][0]["status"], "completed");
+    client.shutdown();
+}
+
+#[test]
+fn cancelled_requests_fail_without_persisting() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let session = Arc::new(
+        Session::open(&InitializeParams {
+            root: dir.path().to_string_lossy().to_string(),
+            db_path: Some(db_dir.path().join("drift.db").to_string_lossy().to_string()),
+            ..Default::default()
+        })
+        .unwrap(),
+    );
+    let cancellation = ScanCancellation::new();
+    cancellation.cancel();
+    let ctx = RequestContext::new(session.clone(), cancellation);
+
+    assert_eq!(methods::scan(&ctx, ScanParams::default()).unwrap_err().code, codes::REQUEST_CANCELLED);
+    let error = methods::analyze(&ctx, AnalyzeParams { files: Some(vec!["src/users.js".to_string()]) }).unwrap_err();
+    assert_eq!(error.code, codes::REQUEST_CANCELLED);
+    assert_eq!(error.data.unwrap()["code"], "CANCELLED");
+
+    let ctx = RequestContext::new(session, ScanCancellation::new());
+    let files = methods::query_files(&ctx, Default::default()).unwrap();
+    assert!(files.is_empty());
+    let detections = methods::query_detections(&ctx, Default::default()).unwrap();
+    assert!(detections.is_empty());
+}
+
+#[test]
+fn cancel_request_notification_reaches_in_flight_requests() {
+    let (dir, db_dir) = (project(), tempfile::tempdir().unwrap());
+    let mut client = Client::start();
+    initialize(&mut client, dir.path(), db_dir.path());
+    // Cancelling an unknown id is ignored, as is any other notification.
+    client.send(json!({ "jsonrpc": "2.0", "method": "$/cancelRequest", "params": { "id": 99 } }));
+    client.send(json!({ "jsonrpc": "2.0", "method": "$/somethingElse" }));
+
+    client.send(json!({ "jsonrpc": "2.0", "id": 2, "method": "scan", "params": {} }));
+    client.send(json!({ "jsonrpc": "2.0", "method": "$/cancelRequest", "params": { "id": 2 } }));
+    let response = client.next_response();
+    assert_eq!(response["id"], 2);
+    // The scan may finish before the cancel arrives; either outcome is a
+    // complete response, and a cancelled one carries the cancel code.
+    if let Some(error) = response.get("error") {
+        assert_eq!(error["code"], codes::REQUEST_CANCELLED);
+    } else {
+        assert_eq!(response["result"]["added"], 2);
+    }
+    client.shutdown();
+}
+
+#[test]
+fn serve_unix_only_replaces_stale_sockets() {
+    let dir = tempfile::tempdir().unwrap();
+
+    // A regular file is never removed.
+    let file = dir.path().join("not-a-socket");
+    std::fs::write(&file, "keep").unwrap();
+    let err = drift_rpc::serve_unix(&file).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "keep");
+
+    // Neither is a socket another server is still listening on.
+    let live = dir.path().join("live.sock");
+    let _listener = std::os::unix::net::UnixListener::bind(&live).unwrap();
+    let err = drift_rpc::serve_unix(&live).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+    assert!(live.exists());
+}
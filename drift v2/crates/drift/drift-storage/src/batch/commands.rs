@@ -320,6 +320,7 @@ pub struct ViolationInsertRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    pub owners: Vec<String>,
 }
 
 /// A row for the gate_results table (batch insert).
@@ -840,8 +840,8 @@ fn insert_violations(
             "INSERT OR REPLACE INTO violations
              (id, file, line, column_num, end_line, end_column, severity,
               pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description,
-              cwe_id, owasp_category, suppressed, is_new)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+              cwe_id, owasp_category, suppressed, is_new, owners)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
         )
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
 
@@ -852,6 +852,7 @@ fn insert_violations(
             row.pattern_id, row.rule_id, row.message,
             row.quick_fix_strategy, row.quick_fix_description,
             row.cwe_id, row.owasp_category, row.suppressed as i32, row.is_new as i32,
+            serde_json::to_string(&row.owners).unwrap_or_else(|_| "[]".to_string()),
         ])
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
     }
@@ -0,0 +1,9 @@
+//! Page-level encryption at rest via SQLCipher.
+//!
+//! The key type, keying and rotation live in
+//! `drift_core::workspace::encryption` so that workspace backups and restores
+//! open drift.db with the same key as the connection pool does.
+
+pub use drift_core::workspace::encryption::{
+    apply_key, cipher_available, open_keyed, rotate_key, DatabaseKey, KEYFILE_ENV, KEY_ENV,
+};
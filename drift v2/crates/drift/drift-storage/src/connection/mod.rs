@@ -1,5 +1,6 @@
 //! Connection management: write-serialized + read-pooled.
 
+pub mod encryption;
 pub mod pragmas;
 pub mod writer;
 pub mod pool;
@@ -10,6 +11,7 @@ use std::sync::Mutex;
 use drift_core::errors::StorageError;
 use rusqlite::Connection;
 
+use self::encryption::{apply_key, DatabaseKey};
 use self::pool::ReadPool;
 use self::pragmas::apply_pragmas;
 use crate::migrations;
@@ -19,6 +21,8 @@ pub struct DatabaseManager {
     writer: Mutex<Connection>,
     readers: ReadPool,
     path: Option<PathBuf>,
+    /// Key applied to every connection opened against an encrypted database.
+    key: Option<DatabaseKey>,
 }
 
 impl DatabaseManager {
@@ -36,6 +40,27 @@ impl DatabaseManager {
             writer: Mutex::new(writer),
             readers,
             path: Some(path.to_path_buf()),
+            key: None,
+        })
+    }
+
+    /// Open an encrypted database. The key is applied to the writer, every
+    /// reader, and later batch connections before anything else runs.
+    pub fn open_encrypted(path: &Path, key: DatabaseKey) -> Result<Self, StorageError> {
+        let writer = Connection::open(path).map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+        apply_key(&writer, &key)?;
+        apply_pragmas(&writer)?;
+        migrations::run_migrations(&writer)?;
+
+        let readers = ReadPool::open_encrypted(path, ReadPool::default_size(), &key)?;
+
+        Ok(Self {
+            writer: Mutex::new(writer),
+            readers,
+            path: Some(path.to_path_buf()),
+            key: Some(key),
         })
     }
 
@@ -55,6 +80,7 @@ impl DatabaseManager {
             writer: Mutex::new(writer),
             readers,
             path: None,
+            key: None,
         })
     }
 
@@ -87,6 +113,11 @@ impl DatabaseManager {
         })
     }
 
+    /// Whether this database is encrypted at rest.
+    pub fn is_encrypted(&self) -> bool {
+        self.key.is_some()
+    }
+
     /// Get the database file path (None for in-memory).
     pub fn path(&self) -> Option<&Path> {
         self.path.as_deref()
@@ -105,6 +136,9 @@ impl DatabaseManager {
                 message: format!("open in-memory batch connection: {e}"),
             })?,
         };
+        if let Some(key) = &self.key {
+            apply_key(&conn, key)?;
+        }
         apply_pragmas(&conn)?;
         Ok(conn)
     }
@@ -7,6 +7,7 @@ use std::sync::Mutex;
 use drift_core::errors::StorageError;
 use rusqlite::Connection;
 
+use super::encryption::{apply_key, DatabaseKey};
 use super::pragmas::apply_read_pragmas;
 
 const DEFAULT_POOL_SIZE: usize = 4;
@@ -21,6 +22,23 @@ pub struct ReadPool {
 impl ReadPool {
     /// Open a pool of read-only connections to the given database path.
     pub fn open(path: &Path, pool_size: usize) -> Result<Self, StorageError> {
+        Self::open_with_key(path, pool_size, None)
+    }
+
+    /// Open a pool of read-only connections to an encrypted database.
+    pub fn open_encrypted(
+        path: &Path,
+        pool_size: usize,
+        key: &DatabaseKey,
+    ) -> Result<Self, StorageError> {
+        Self::open_with_key(path, pool_size, Some(key))
+    }
+
+    fn open_with_key(
+        path: &Path,
+        pool_size: usize,
+        key: Option<&DatabaseKey>,
+    ) -> Result<Self, StorageError> {
         let size = pool_size.clamp(1, MAX_POOL_SIZE);
         let mut connections = Vec::with_capacity(size);
         for _ in 0..size {
@@ -32,6 +50,9 @@ impl ReadPool {
             .map_err(|e| StorageError::SqliteError {
                 message: e.to_string(),
             })?;
+            if let Some(key) = key {
+                apply_key(&conn, key)?;
+            }
             apply_read_pragmas(&conn)?;
             connections.push(Mutex::new(conn));
         }
@@ -37,6 +37,7 @@ use drift_core::traits::storage::drift_reader::IDriftReader;
 
 use crate::batch::commands::BatchCommand;
 use crate::batch::BatchWriter;
+use crate::connection::encryption::DatabaseKey;
 use crate::connection::DatabaseManager;
 use crate::queries;
 
@@ -60,6 +61,14 @@ impl DriftStorageEngine {
         Ok(Self { db, batch })
     }
 
+    /// Open a file-backed storage engine encrypted at rest with `key`.
+    pub fn open_encrypted(path: &Path, key: DatabaseKey) -> Result<Self, StorageError> {
+        let db = DatabaseManager::open_encrypted(path, key)?;
+        let batch_conn = db.open_batch_connection()?;
+        let batch = BatchWriter::new(batch_conn);
+        Ok(Self { db, batch })
+    }
+
     /// Open an in-memory storage engine (for testing).
     pub fn open_in_memory() -> Result<Self, StorageError> {
         let db = DatabaseManager::open_in_memory()?;
@@ -1530,7 +1539,7 @@ fn to_storage_violation(v: &ViolationRow) -> queries::enforcement::ViolationRow
         pattern_id: v.pattern_id.clone(), rule_id: v.rule_id.clone(), message: v.message.clone(),
         quick_fix_strategy: v.quick_fix_strategy.clone(), quick_fix_description: v.quick_fix_description.clone(),
         cwe_id: v.cwe_id, owasp_category: v.owasp_category.clone(),
-        suppressed: v.suppressed, is_new: v.is_new,
+        suppressed: v.suppressed, is_new: v.is_new, owners: v.owners.clone(),
     }
 }
 
@@ -1541,7 +1550,7 @@ fn from_storage_violation(r: queries::enforcement::ViolationRow) -> ViolationRow
         pattern_id: r.pattern_id, rule_id: r.rule_id, message: r.message,
         quick_fix_strategy: r.quick_fix_strategy, quick_fix_description: r.quick_fix_description,
         cwe_id: r.cwe_id, owasp_category: r.owasp_category,
-        suppressed: r.suppressed, is_new: r.is_new,
+        suppressed: r.suppressed, is_new: r.is_new, owners: r.owners,
     }
 }
 
@@ -1661,6 +1670,13 @@ impl IDriftEnforcement for DriftStorageEngine {
         })
     }
 
+    fn query_violations_by_owner(&self, owner: &str) -> Result<Vec<ViolationRow>, StorageError> {
+        self.db.with_reader(|conn| {
+            let rows = queries::enforcement::query_violations_by_owner(conn, owner)?;
+            Ok(rows.into_iter().map(from_storage_violation).collect())
+        })
+    }
+
     fn insert_gate_result(&self, g: &GateResultRow) -> Result<(), StorageError> {
         let sg = to_storage_gate(g);
         self.db.with_writer(|conn| queries::enforcement::insert_gate_result(conn, &sg))
@@ -9,6 +9,13 @@ pub mod v006_enforcement;
 pub mod v007_advanced;
 pub mod v008_enforcement_fixes;
 pub mod v009_pattern_status;
+pub mod v010_dependencies;
+pub mod v011_complexity;
+pub mod v012_history;
+pub mod v013_ownership;
+pub mod v014_schema_drift;
+pub mod v015_policy_scopes;
+pub mod v016_backfilled_trends;
 
 use drift_core::errors::StorageError;
 use rusqlite::Connection;
@@ -32,6 +39,13 @@ pub fn run_migrations(conn: &Connection) -> Result<(), StorageError> {
         (v007_advanced::MIGRATION_SQL, 7),
         (v008_enforcement_fixes::MIGRATION_SQL, 8),
         (v009_pattern_status::MIGRATION_SQL, 9),
+        (v010_dependencies::MIGRATION_SQL, 10),
+        (v011_complexity::MIGRATION_SQL, 11),
+        (v012_history::MIGRATION_SQL, 12),
+        (v013_ownership::MIGRATION_SQL, 13),
+        (v014_schema_drift::MIGRATION_SQL, 14),
+        (v015_policy_scopes::MIGRATION_SQL, 15),
+        (v016_backfilled_trends::MIGRATION_SQL, 16),
     ];
 
     for (sql, version) in migrations {
@@ -0,0 +1,27 @@
+//! V010 migration: Dependency inventory.
+//!
+//! One row per resolved dependency of each workspace package, as produced
+//! from lockfiles and manifests. Rows are replaced wholesale on each
+//! inventory run.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS dependencies (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    package TEXT NOT NULL,
+    package_path TEXT NOT NULL,
+    manager TEXT NOT NULL,
+    lockfile TEXT,
+    name TEXT NOT NULL,
+    version TEXT,
+    purl TEXT NOT NULL,
+    direct INTEGER NOT NULL DEFAULT 0,
+    dev INTEGER NOT NULL DEFAULT 0,
+    checksum TEXT,
+    depends_on TEXT NOT NULL DEFAULT '[]',
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_dependencies_package ON dependencies(package_path, manager);
+CREATE INDEX IF NOT EXISTS idx_dependencies_name ON dependencies(manager, name);
+CREATE INDEX IF NOT EXISTS idx_dependencies_purl ON dependencies(purl);
+"#;
@@ -0,0 +1,29 @@
+//! V011 migration: Function complexity metrics.
+//!
+//! One row per parsed function: cyclomatic, cognitive, nesting depth,
+//! Halstead measures and maintainability index. Rows are replaced
+//! wholesale on each analysis run.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS function_complexity (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    file TEXT NOT NULL,
+    name TEXT NOT NULL,
+    qualified_name TEXT,
+    line INTEGER NOT NULL,
+    end_line INTEGER NOT NULL,
+    cyclomatic INTEGER NOT NULL,
+    cognitive INTEGER NOT NULL,
+    max_nesting INTEGER NOT NULL,
+    loc INTEGER NOT NULL,
+    halstead_volume REAL NOT NULL DEFAULT 0,
+    halstead_difficulty REAL NOT NULL DEFAULT 0,
+    halstead_effort REAL NOT NULL DEFAULT 0,
+    halstead_bugs REAL NOT NULL DEFAULT 0,
+    maintainability_index REAL NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_function_complexity_file ON function_complexity(file, line);
+CREATE INDEX IF NOT EXISTS idx_function_complexity_cognitive ON function_complexity(cognitive DESC);
+"#;
@@ -0,0 +1,61 @@
+//! V012 migration: Git history analytics.
+//!
+//! File and function churn, change coupling between files, and module
+//! ownership with bus factor. Hotspot scores live on `file_churn` alongside
+//! the complexity they were computed from. All tables are replaced
+//! wholesale on each analysis run.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS file_churn (
+    file TEXT PRIMARY KEY,
+    commits INTEGER NOT NULL,
+    insertions INTEGER NOT NULL DEFAULT 0,
+    deletions INTEGER NOT NULL DEFAULT 0,
+    authors INTEGER NOT NULL DEFAULT 0,
+    top_author TEXT,
+    top_author_share REAL NOT NULL DEFAULT 0,
+    first_changed_at INTEGER NOT NULL,
+    last_changed_at INTEGER NOT NULL,
+    complexity INTEGER NOT NULL DEFAULT 0,
+    hotspot_score REAL NOT NULL DEFAULT 0,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_file_churn_hotspot ON file_churn(hotspot_score DESC);
+
+CREATE TABLE IF NOT EXISTS function_churn (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    file TEXT NOT NULL,
+    name TEXT NOT NULL,
+    line INTEGER NOT NULL,
+    end_line INTEGER NOT NULL,
+    commits INTEGER NOT NULL,
+    lines_added INTEGER NOT NULL DEFAULT 0,
+    lines_deleted INTEGER NOT NULL DEFAULT 0,
+    last_changed_at INTEGER NOT NULL DEFAULT 0
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_function_churn_file ON function_churn(file, line);
+
+CREATE TABLE IF NOT EXISTS change_coupling (
+    file_a TEXT NOT NULL,
+    file_b TEXT NOT NULL,
+    co_changes INTEGER NOT NULL,
+    revisions_a INTEGER NOT NULL,
+    revisions_b INTEGER NOT NULL,
+    degree REAL NOT NULL,
+    PRIMARY KEY (file_a, file_b)
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_change_coupling_b ON change_coupling(file_b);
+
+CREATE TABLE IF NOT EXISTS module_ownership (
+    module TEXT PRIMARY KEY,
+    primary_owner TEXT,
+    primary_share REAL NOT NULL DEFAULT 0,
+    bus_factor INTEGER NOT NULL,
+    contributors INTEGER NOT NULL,
+    total_changes INTEGER NOT NULL,
+    authors TEXT NOT NULL DEFAULT '[]'
+) STRICT;
+"#;
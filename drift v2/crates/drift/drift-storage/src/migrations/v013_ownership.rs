@@ -0,0 +1,8 @@
+//! V013 migration: Code ownership on violations.
+//!
+//! Adds the CODEOWNERS owners of each violation's file as a JSON array, so
+//! violations can be routed and filtered per owner.
+
+pub const MIGRATION_SQL: &str = r#"
+ALTER TABLE violations ADD COLUMN owners TEXT NOT NULL DEFAULT '[]';
+"#;
@@ -0,0 +1,20 @@
+//! V014 migration: Schema drift.
+//!
+//! Mismatches between ORM models and the schema reconstructed from
+//! migration files. Replaced wholesale on each analysis run.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS schema_drift (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL,
+    model_name TEXT NOT NULL,
+    table_name TEXT NOT NULL,
+    column_name TEXT,
+    file TEXT NOT NULL,
+    line INTEGER NOT NULL,
+    message TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_schema_drift_file ON schema_drift(file);
+"#;
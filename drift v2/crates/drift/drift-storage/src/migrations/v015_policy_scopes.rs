@@ -0,0 +1,30 @@
+//! V015 migration: Scoped policy results.
+//!
+//! Per-scope policy evaluation and the scope and policy governing each
+//! violation, from `quality_gates.policy_overrides`. Replaced wholesale on
+//! each analysis run; empty when no overrides are configured.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS policy_scopes (
+    scope TEXT PRIMARY KEY,
+    policy_name TEXT NOT NULL,
+    aggregation_mode TEXT NOT NULL,
+    overall_passed INTEGER NOT NULL,
+    overall_score REAL NOT NULL,
+    file_count INTEGER NOT NULL,
+    gates_passed INTEGER NOT NULL,
+    gates_failed INTEGER NOT NULL,
+    details TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS governed_violations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    violation_id TEXT NOT NULL,
+    gate_id TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    policy_name TEXT NOT NULL
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_governed_violations_violation ON governed_violations(violation_id);
+"#;
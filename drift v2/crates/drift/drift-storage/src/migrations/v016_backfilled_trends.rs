@@ -0,0 +1,9 @@
+//! V016 migration: Backfilled health trends.
+//!
+//! Marks trend points replayed from past commits by `drift_backfill()`. They
+//! are recorded at commit times, so they predate any retention cutoff by
+//! design; retention only ages out points recorded by live runs.
+
+pub const MIGRATION_SQL: &str = r#"
+ALTER TABLE health_trends ADD COLUMN backfilled INTEGER NOT NULL DEFAULT 0;
+"#;
@@ -163,3 +163,73 @@ pub fn count_boundaries(conn: &Connection) -> Result<i64, StorageError> {
     conn.query_row("SELECT COUNT(*) FROM boundaries", [], |row| row.get(0))
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
+
+/// A mismatch between an ORM model and the migration schema.
+#[derive(Debug, Clone)]
+pub struct SchemaDriftRow {
+    pub kind: String,
+    pub model_name: String,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub file: String,
+    pub line: i64,
+    pub message: String,
+}
+
+/// Replace all stored schema drift with `rows`.
+pub fn replace_schema_drift(
+    conn: &Connection,
+    rows: &[SchemaDriftRow],
+) -> Result<usize, StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute("DELETE FROM schema_drift", [])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO schema_drift
+                 (kind, model_name, table_name, column_name, file, line, message)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for d in rows {
+            stmt.execute(params![
+                d.kind, d.model_name, d.table_name, d.column_name,
+                d.file, d.line, d.message,
+            ])
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    Ok(rows.len())
+}
+
+/// Get all stored schema drift, ordered by file and line.
+pub fn get_schema_drift(conn: &Connection) -> Result<Vec<SchemaDriftRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT kind, model_name, table_name, column_name, file, line, message
+             FROM schema_drift ORDER BY file, line, id",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(SchemaDriftRow {
+                kind: row.get(0)?,
+                model_name: row.get(1)?,
+                table_name: row.get(2)?,
+                column_name: row.get(3)?,
+                file: row.get(4)?,
+                line: row.get(5)?,
+                message: row.get(6)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
@@ -0,0 +1,148 @@
+//! Queries for the function_complexity table — per-function complexity metrics.
+
+use drift_core::errors::StorageError;
+use rusqlite::{params, params_from_iter, Connection};
+
+/// Complexity metrics of one function.
+#[derive(Debug, Clone)]
+pub struct FunctionComplexityRow {
+    pub id: i64,
+    pub file: String,
+    pub name: String,
+    pub qualified_name: Option<String>,
+    pub line: i64,
+    pub end_line: i64,
+    pub cyclomatic: i64,
+    pub cognitive: i64,
+    pub max_nesting: i64,
+    pub loc: i64,
+    pub halstead_volume: f64,
+    pub halstead_difficulty: f64,
+    pub halstead_effort: f64,
+    pub halstead_bugs: f64,
+    pub maintainability_index: f64,
+    pub created_at: i64,
+}
+
+const COLUMNS: &str = "id, file, name, qualified_name, line, end_line, cyclomatic, cognitive, max_nesting, loc, \
+    halstead_volume, halstead_difficulty, halstead_effort, halstead_bugs, maintainability_index, created_at";
+
+/// Replace all stored metrics with `rows`.
+pub fn replace_all(conn: &Connection, rows: &[FunctionComplexityRow]) -> Result<usize, StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute("DELETE FROM function_complexity", [])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    let count = insert_batch(&tx, rows)?;
+    tx.commit().map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    Ok(count)
+}
+
+/// Insert a batch of function metrics.
+pub fn insert_batch(conn: &Connection, rows: &[FunctionComplexityRow]) -> Result<usize, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "INSERT INTO function_complexity
+             (file, name, qualified_name, line, end_line, cyclomatic, cognitive, max_nesting, loc,
+              halstead_volume, halstead_difficulty, halstead_effort, halstead_bugs, maintainability_index)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    for row in rows {
+        stmt.execute(params![
+            row.file,
+            row.name,
+            row.qualified_name,
+            row.line,
+            row.end_line,
+            row.cyclomatic,
+            row.cognitive,
+            row.max_nesting,
+            row.loc,
+            row.halstead_volume,
+            row.halstead_difficulty,
+            row.halstead_effort,
+            row.halstead_bugs,
+            row.maintainability_index,
+        ])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    }
+    Ok(rows.len())
+}
+
+/// Metrics of the functions in `file`, in source order.
+pub fn query_by_file(conn: &Connection, file: &str) -> Result<Vec<FunctionComplexityRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {COLUMNS} FROM function_complexity WHERE file = ?1 ORDER BY line"),
+        params![file],
+    )
+}
+
+/// Metrics of the functions in any of `files`.
+pub fn query_by_files(conn: &Connection, files: &[String]) -> Result<Vec<FunctionComplexityRow>, StorageError> {
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    let placeholders = vec!["?"; files.len()].join(", ");
+    let sql = format!("SELECT {COLUMNS} FROM function_complexity WHERE file IN ({placeholders}) ORDER BY file, line");
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    let rows = stmt
+        .query_map(params_from_iter(files.iter()), map_row)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// The `limit` functions with the highest cognitive complexity.
+pub fn query_most_complex(conn: &Connection, limit: usize) -> Result<Vec<FunctionComplexityRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {COLUMNS} FROM function_complexity ORDER BY cognitive DESC, cyclomatic DESC, file, line LIMIT ?1"),
+        params![limit as i64],
+    )
+}
+
+/// Count stored function metrics.
+pub fn count(conn: &Connection) -> Result<i64, StorageError> {
+    conn.query_row("SELECT COUNT(*) FROM function_complexity", [], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+fn query(conn: &Connection, sql: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<FunctionComplexityRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(sql)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params, map_row)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+fn map_row(row: &rusqlite::Row) -> rusqlite::Result<FunctionComplexityRow> {
+    Ok(FunctionComplexityRow {
+        id: row.get(0)?,
+        file: row.get(1)?,
+        name: row.get(2)?,
+        qualified_name: row.get(3)?,
+        line: row.get(4)?,
+        end_line: row.get(5)?,
+        cyclomatic: row.get(6)?,
+        cognitive: row.get(7)?,
+        max_nesting: row.get(8)?,
+        loc: row.get(9)?,
+        halstead_volume: row.get(10)?,
+        halstead_difficulty: row.get(11)?,
+        halstead_effort: row.get(12)?,
+        halstead_bugs: row.get(13)?,
+        maintainability_index: row.get(14)?,
+        created_at: row.get(15)?,
+    })
+}
@@ -0,0 +1,127 @@
+//! Queries for the dependencies table — resolved dependency inventory.
+
+use drift_core::errors::StorageError;
+use rusqlite::{params, Connection};
+
+/// A resolved dependency of one workspace package.
+#[derive(Debug, Clone)]
+pub struct DependencyRow {
+    pub id: i64,
+    pub package: String,
+    pub package_path: String,
+    pub manager: String,
+    pub lockfile: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub purl: String,
+    pub direct: bool,
+    pub dev: bool,
+    pub checksum: Option<String>,
+    /// purls of this dependency's own dependencies.
+    pub depends_on: Vec<String>,
+    pub created_at: i64,
+}
+
+const COLUMNS: &str = "id, package, package_path, manager, lockfile, name, version, purl, direct, dev, checksum, depends_on, created_at";
+
+/// Replace the whole inventory with `rows`.
+pub fn replace_all(conn: &Connection, rows: &[DependencyRow]) -> Result<usize, StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute("DELETE FROM dependencies", [])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    let count = insert_batch(&tx, rows)?;
+    tx.commit().map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    Ok(count)
+}
+
+/// Insert a batch of dependency records.
+pub fn insert_batch(conn: &Connection, rows: &[DependencyRow]) -> Result<usize, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "INSERT INTO dependencies
+             (package, package_path, manager, lockfile, name, version, purl, direct, dev, checksum, depends_on)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    for row in rows {
+        stmt.execute(params![
+            row.package,
+            row.package_path,
+            row.manager,
+            row.lockfile,
+            row.name,
+            row.version,
+            row.purl,
+            row.direct as i32,
+            row.dev as i32,
+            row.checksum,
+            serde_json::to_string(&row.depends_on).unwrap_or_else(|_| "[]".to_string()),
+        ])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    }
+    Ok(rows.len())
+}
+
+/// All dependency records, ordered by package then purl.
+pub fn query_all(conn: &Connection) -> Result<Vec<DependencyRow>, StorageError> {
+    query(conn, &format!("SELECT {COLUMNS} FROM dependencies ORDER BY package_path, manager, purl"), params![])
+}
+
+/// Dependencies of the workspace package at `package_path`.
+pub fn query_by_package(conn: &Connection, package_path: &str) -> Result<Vec<DependencyRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {COLUMNS} FROM dependencies WHERE package_path = ?1 ORDER BY manager, purl"),
+        params![package_path],
+    )
+}
+
+/// Every resolved occurrence of a package across the workspace.
+pub fn query_by_name(conn: &Connection, manager: &str, name: &str) -> Result<Vec<DependencyRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {COLUMNS} FROM dependencies WHERE manager = ?1 AND name = ?2 ORDER BY package_path"),
+        params![manager, name],
+    )
+}
+
+/// Count total dependency records.
+pub fn count(conn: &Connection) -> Result<i64, StorageError> {
+    conn.query_row("SELECT COUNT(*) FROM dependencies", [], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+fn query(conn: &Connection, sql: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<DependencyRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(sql)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params, map_row)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+fn map_row(row: &rusqlite::Row) -> rusqlite::Result<DependencyRow> {
+    let depends_on: String = row.get(11)?;
+    Ok(DependencyRow {
+        id: row.get(0)?,
+        package: row.get(1)?,
+        package_path: row.get(2)?,
+        manager: row.get(3)?,
+        lockfile: row.get(4)?,
+        name: row.get(5)?,
+        version: row.get(6)?,
+        purl: row.get(7)?,
+        direct: row.get::<_, i32>(8)? != 0,
+        dev: row.get::<_, i32>(9)? != 0,
+        checksum: row.get(10)?,
+        depends_on: serde_json::from_str(&depends_on).unwrap_or_default(),
+        created_at: row.get(12)?,
+    })
+}
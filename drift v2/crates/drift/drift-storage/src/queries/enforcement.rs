@@ -25,6 +25,8 @@ pub struct ViolationRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// CODEOWNERS owners of the file; stored as a JSON array.
+    pub owners: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -76,15 +78,19 @@ pub struct FeedbackRow {
 
 // ─── Violations ──────────────────────────────────────────────────────
 
+const VIOLATION_COLUMNS: &str = "id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new, owners";
+
 #[allow(clippy::too_many_arguments)]
 pub fn insert_violation(
     conn: &Connection,
     v: &ViolationRow,
 ) -> Result<(), StorageError> {
     conn.execute(
-        "INSERT OR REPLACE INTO violations (id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
-        params![v.id, v.file, v.line, v.column, v.end_line, v.end_column, v.severity, v.pattern_id, v.rule_id, v.message, v.quick_fix_strategy, v.quick_fix_description, v.cwe_id, v.owasp_category, v.suppressed as i32, v.is_new as i32],
+        &format!(
+            "INSERT OR REPLACE INTO violations ({VIOLATION_COLUMNS})
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)"
+        ),
+        params![v.id, v.file, v.line, v.column, v.end_line, v.end_column, v.severity, v.pattern_id, v.rule_id, v.message, v.quick_fix_strategy, v.quick_fix_description, v.cwe_id, v.owasp_category, v.suppressed as i32, v.is_new as i32, serde_json::to_string(&v.owners).unwrap_or_else(|_| "[]".to_string())],
     ).map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
     Ok(())
 }
@@ -93,50 +99,57 @@ pub fn query_violations_by_file(
     conn: &Connection,
     file: &str,
 ) -> Result<Vec<ViolationRow>, StorageError> {
-    let mut stmt = conn
-        .prepare_cached(
-            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new
-             FROM violations WHERE file = ?1 ORDER BY line",
-        )
-        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    query_violations(
+        conn,
+        &format!("SELECT {VIOLATION_COLUMNS} FROM violations WHERE file = ?1 ORDER BY line"),
+        params![file],
+    )
+}
 
-    let rows = stmt
-        .query_map(params![file], |row| {
-            Ok(ViolationRow {
-                id: row.get(0)?,
-                file: row.get(1)?,
-                line: row.get(2)?,
-                column: row.get(3)?,
-                end_line: row.get(4)?,
-                end_column: row.get(5)?,
-                severity: row.get(6)?,
-                pattern_id: row.get(7)?,
-                rule_id: row.get(8)?,
-                message: row.get(9)?,
-                quick_fix_strategy: row.get(10)?,
-                quick_fix_description: row.get(11)?,
-                cwe_id: row.get(12)?,
-                owasp_category: row.get(13)?,
-                suppressed: row.get::<_, i32>(14)? != 0,
-                is_new: row.get::<_, i32>(15).unwrap_or(0) != 0,
-            })
-        })
-        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+pub fn query_all_violations(conn: &Connection) -> Result<Vec<ViolationRow>, StorageError> {
+    query_violations(
+        conn,
+        &format!("SELECT {VIOLATION_COLUMNS} FROM violations ORDER BY file, line"),
+        [],
+    )
+}
 
-    rows.collect::<Result<Vec<_>, _>>()
-        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+/// Violations owned by `owner` (case-insensitive). `"unowned"` selects
+/// violations without owners.
+pub fn query_violations_by_owner(
+    conn: &Connection,
+    owner: &str,
+) -> Result<Vec<ViolationRow>, StorageError> {
+    if owner == "unowned" {
+        return query_violations(
+            conn,
+            &format!("SELECT {VIOLATION_COLUMNS} FROM violations WHERE owners = '[]' ORDER BY file, line"),
+            [],
+        );
+    }
+    query_violations(
+        conn,
+        &format!(
+            "SELECT {VIOLATION_COLUMNS} FROM violations
+             WHERE EXISTS (SELECT 1 FROM json_each(violations.owners) WHERE value = ?1 COLLATE NOCASE)
+             ORDER BY file, line"
+        ),
+        params![owner],
+    )
 }
 
-pub fn query_all_violations(conn: &Connection) -> Result<Vec<ViolationRow>, StorageError> {
+fn query_violations(
+    conn: &Connection,
+    sql: &str,
+    params: impl rusqlite::Params,
+) -> Result<Vec<ViolationRow>, StorageError> {
     let mut stmt = conn
-        .prepare_cached(
-            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new
-             FROM violations ORDER BY file, line",
-        )
+        .prepare_cached(sql)
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
 
     let rows = stmt
-        .query_map([], |row| {
+        .query_map(params, |row| {
+            let owners: Option<String> = row.get(16)?;
             Ok(ViolationRow {
                 id: row.get(0)?,
                 file: row.get(1)?,
@@ -154,6 +167,9 @@ pub fn query_all_violations(conn: &Connection) -> Result<Vec<ViolationRow>, Stor
                 owasp_category: row.get(13)?,
                 suppressed: row.get::<_, i32>(14)? != 0,
                 is_new: row.get::<_, i32>(15).unwrap_or(0) != 0,
+                owners: owners
+                    .and_then(|o| serde_json::from_str(&o).ok())
+                    .unwrap_or_default(),
             })
         })
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
@@ -305,6 +321,39 @@ pub fn insert_health_trend(
     Ok(())
 }
 
+/// Insert trend points recorded at historical times (e.g. backfilled
+/// commit timestamps). A point already stored for the same metric and time
+/// is skipped, so re-running a backfill adds nothing. The points are marked
+/// `backfilled`, which exempts them from retention. Returns the number of
+/// points inserted.
+pub fn insert_health_trends_at(
+    conn: &Connection,
+    rows: &[HealthTrendRow],
+) -> Result<usize, StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    let mut inserted = 0;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO health_trends (metric_name, metric_value, recorded_at, backfilled)
+                 SELECT ?1, ?2, ?3, 1
+                 WHERE NOT EXISTS (
+                     SELECT 1 FROM health_trends WHERE metric_name = ?1 AND recorded_at = ?3
+                 )",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in rows {
+            inserted += stmt
+                .execute(params![row.metric_name, row.metric_value, row.recorded_at as i64])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit().map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    Ok(inserted)
+}
+
 pub fn query_health_trends(
     conn: &Connection,
     metric_name: &str,
@@ -580,6 +629,127 @@ pub fn query_recent_policy_results(
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
 
+// ─── Scoped Policy ──────────────────────────────────────────────────
+
+/// Policy evaluation for one scope of a scoped policy.
+#[derive(Debug, Clone)]
+pub struct PolicyScopeRow {
+    pub scope: String,
+    pub policy_name: String,
+    pub aggregation_mode: String,
+    pub overall_passed: bool,
+    pub overall_score: f64,
+    pub file_count: i64,
+    pub gates_passed: i64,
+    pub gates_failed: i64,
+    pub details: String,
+}
+
+/// The scope and policy that governed a violation.
+#[derive(Debug, Clone)]
+pub struct GovernedViolationRow {
+    pub violation_id: String,
+    pub gate_id: String,
+    pub scope: String,
+    pub policy_name: String,
+}
+
+/// Replace the stored scoped policy evaluation. Pass empty slices to clear
+/// it when no policy overrides are configured.
+pub fn replace_scoped_policy(
+    conn: &Connection,
+    scopes: &[PolicyScopeRow],
+    violations: &[GovernedViolationRow],
+) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute_batch("DELETE FROM policy_scopes; DELETE FROM governed_violations;")
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO policy_scopes
+                 (scope, policy_name, aggregation_mode, overall_passed, overall_score,
+                  file_count, gates_passed, gates_failed, details)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for s in scopes {
+            stmt.execute(params![
+                s.scope, s.policy_name, s.aggregation_mode, s.overall_passed as i32,
+                s.overall_score, s.file_count, s.gates_passed, s.gates_failed, s.details,
+            ])
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO governed_violations (violation_id, gate_id, scope, policy_name)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for v in violations {
+            stmt.execute(params![v.violation_id, v.gate_id, v.scope, v.policy_name])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Stored scope results, the root scope first.
+pub fn query_policy_scopes(conn: &Connection) -> Result<Vec<PolicyScopeRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT scope, policy_name, aggregation_mode, overall_passed, overall_score,
+                    file_count, gates_passed, gates_failed, details
+             FROM policy_scopes ORDER BY scope != 'root', scope",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(PolicyScopeRow {
+                scope: row.get(0)?,
+                policy_name: row.get(1)?,
+                aggregation_mode: row.get(2)?,
+                overall_passed: row.get::<_, i32>(3)? != 0,
+                overall_score: row.get(4)?,
+                file_count: row.get(5)?,
+                gates_passed: row.get(6)?,
+                gates_failed: row.get(7)?,
+                details: row.get(8)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Stored governing scopes, in evaluation order.
+pub fn query_governed_violations(conn: &Connection) -> Result<Vec<GovernedViolationRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT violation_id, gate_id, scope, policy_name FROM governed_violations ORDER BY id",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(GovernedViolationRow {
+                violation_id: row.get(0)?,
+                gate_id: row.get(1)?,
+                scope: row.get(2)?,
+                policy_name: row.get(3)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
 // ─── Degradation Alerts ─────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,330 @@
+//! Queries for git history analytics — file_churn, function_churn,
+//! change_coupling and module_ownership.
+
+use drift_core::errors::StorageError;
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Churn of one file, with its hotspot score.
+#[derive(Debug, Clone)]
+pub struct FileChurnRow {
+    pub file: String,
+    pub commits: i64,
+    pub insertions: i64,
+    pub deletions: i64,
+    pub authors: i64,
+    pub top_author: Option<String>,
+    pub top_author_share: f64,
+    pub first_changed_at: i64,
+    pub last_changed_at: i64,
+    pub complexity: i64,
+    pub hotspot_score: f64,
+    pub updated_at: i64,
+}
+
+/// Churn of one function at HEAD.
+#[derive(Debug, Clone)]
+pub struct FunctionChurnRow {
+    pub id: i64,
+    pub file: String,
+    pub name: String,
+    pub line: i64,
+    pub end_line: i64,
+    pub commits: i64,
+    pub lines_added: i64,
+    pub lines_deleted: i64,
+    pub last_changed_at: i64,
+}
+
+/// Two files that change together.
+#[derive(Debug, Clone)]
+pub struct ChangeCouplingRow {
+    pub file_a: String,
+    pub file_b: String,
+    pub co_changes: i64,
+    pub revisions_a: i64,
+    pub revisions_b: i64,
+    pub degree: f64,
+}
+
+/// Ownership of one module. `authors` is a JSON array of
+/// `{author, changes, share}` objects.
+#[derive(Debug, Clone)]
+pub struct ModuleOwnershipRow {
+    pub module: String,
+    pub primary_owner: Option<String>,
+    pub primary_share: f64,
+    pub bus_factor: i64,
+    pub contributors: i64,
+    pub total_changes: i64,
+    pub authors: String,
+}
+
+const FILE_COLUMNS: &str = "file, commits, insertions, deletions, authors, top_author, top_author_share, \
+    first_changed_at, last_changed_at, complexity, hotspot_score, updated_at";
+const FUNCTION_COLUMNS: &str = "id, file, name, line, end_line, commits, lines_added, lines_deleted, last_changed_at";
+const COUPLING_COLUMNS: &str = "file_a, file_b, co_changes, revisions_a, revisions_b, degree";
+const OWNERSHIP_COLUMNS: &str = "module, primary_owner, primary_share, bus_factor, contributors, total_changes, authors";
+
+fn sql_err(e: rusqlite::Error) -> StorageError {
+    StorageError::SqliteError { message: e.to_string() }
+}
+
+/// Replace all stored file churn with `rows`.
+pub fn replace_file_churn(conn: &Connection, rows: &[FileChurnRow]) -> Result<usize, StorageError> {
+    let tx = conn.unchecked_transaction().map_err(sql_err)?;
+    tx.execute("DELETE FROM file_churn", []).map_err(sql_err)?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO file_churn
+                 (file, commits, insertions, deletions, authors, top_author, top_author_share,
+                  first_changed_at, last_changed_at, complexity, hotspot_score)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            )
+            .map_err(sql_err)?;
+        for row in rows {
+            stmt.execute(params![
+                row.file,
+                row.commits,
+                row.insertions,
+                row.deletions,
+                row.authors,
+                row.top_author,
+                row.top_author_share,
+                row.first_changed_at,
+                row.last_changed_at,
+                row.complexity,
+                row.hotspot_score,
+            ])
+            .map_err(sql_err)?;
+        }
+    }
+    tx.commit().map_err(sql_err)?;
+    Ok(rows.len())
+}
+
+/// Replace all stored function churn with `rows`.
+pub fn replace_function_churn(conn: &Connection, rows: &[FunctionChurnRow]) -> Result<usize, StorageError> {
+    let tx = conn.unchecked_transaction().map_err(sql_err)?;
+    tx.execute("DELETE FROM function_churn", []).map_err(sql_err)?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO function_churn
+                 (file, name, line, end_line, commits, lines_added, lines_deleted, last_changed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )
+            .map_err(sql_err)?;
+        for row in rows {
+            stmt.execute(params![
+                row.file,
+                row.name,
+                row.line,
+                row.end_line,
+                row.commits,
+                row.lines_added,
+                row.lines_deleted,
+                row.last_changed_at,
+            ])
+            .map_err(sql_err)?;
+        }
+    }
+    tx.commit().map_err(sql_err)?;
+    Ok(rows.len())
+}
+
+/// Replace all stored change coupling with `rows`.
+pub fn replace_coupling(conn: &Connection, rows: &[ChangeCouplingRow]) -> Result<usize, StorageError> {
+    let tx = conn.unchecked_transaction().map_err(sql_err)?;
+    tx.execute("DELETE FROM change_coupling", []).map_err(sql_err)?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO change_coupling (file_a, file_b, co_changes, revisions_a, revisions_b, degree)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )
+            .map_err(sql_err)?;
+        for row in rows {
+            stmt.execute(params![row.file_a, row.file_b, row.co_changes, row.revisions_a, row.revisions_b, row.degree])
+                .map_err(sql_err)?;
+        }
+    }
+    tx.commit().map_err(sql_err)?;
+    Ok(rows.len())
+}
+
+/// Replace all stored module ownership with `rows`.
+pub fn replace_ownership(conn: &Connection, rows: &[ModuleOwnershipRow]) -> Result<usize, StorageError> {
+    let tx = conn.unchecked_transaction().map_err(sql_err)?;
+    tx.execute("DELETE FROM module_ownership", []).map_err(sql_err)?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT INTO module_ownership
+                 (module, primary_owner, primary_share, bus_factor, contributors, total_changes, authors)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )
+            .map_err(sql_err)?;
+        for row in rows {
+            stmt.execute(params![
+                row.module,
+                row.primary_owner,
+                row.primary_share,
+                row.bus_factor,
+                row.contributors,
+                row.total_changes,
+                row.authors,
+            ])
+            .map_err(sql_err)?;
+        }
+    }
+    tx.commit().map_err(sql_err)?;
+    Ok(rows.len())
+}
+
+/// The `limit` files with the highest hotspot score.
+pub fn query_hotspots(conn: &Connection, limit: usize) -> Result<Vec<FileChurnRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {FILE_COLUMNS} FROM file_churn WHERE hotspot_score > 0 ORDER BY hotspot_score DESC, file LIMIT ?1"),
+        params![limit as i64],
+        map_file_row,
+    )
+}
+
+/// Churn of one file.
+pub fn query_file_churn(conn: &Connection, file: &str) -> Result<Option<FileChurnRow>, StorageError> {
+    conn.query_row(&format!("SELECT {FILE_COLUMNS} FROM file_churn WHERE file = ?1"), params![file], map_file_row)
+        .optional()
+        .map_err(sql_err)
+}
+
+/// Churn of the functions in `file`, in source order.
+pub fn query_function_churn_by_file(conn: &Connection, file: &str) -> Result<Vec<FunctionChurnRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {FUNCTION_COLUMNS} FROM function_churn WHERE file = ?1 ORDER BY line"),
+        params![file],
+        map_function_row,
+    )
+}
+
+/// All stored function churn.
+pub fn query_all_function_churn(conn: &Connection) -> Result<Vec<FunctionChurnRow>, StorageError> {
+    query(conn, &format!("SELECT {FUNCTION_COLUMNS} FROM function_churn ORDER BY file, line"), [], map_function_row)
+}
+
+/// Files coupled to `file`, by descending degree.
+pub fn query_coupled_files(conn: &Connection, file: &str, limit: usize) -> Result<Vec<ChangeCouplingRow>, StorageError> {
+    query(
+        conn,
+        &format!(
+            "SELECT {COUPLING_COLUMNS} FROM change_coupling WHERE file_a = ?1 OR file_b = ?1
+             ORDER BY degree DESC, co_changes DESC LIMIT ?2"
+        ),
+        params![file, limit as i64],
+        map_coupling_row,
+    )
+}
+
+/// The `limit` most strongly coupled pairs.
+pub fn query_top_coupling(conn: &Connection, limit: usize) -> Result<Vec<ChangeCouplingRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {COUPLING_COLUMNS} FROM change_coupling ORDER BY degree DESC, co_changes DESC LIMIT ?1"),
+        params![limit as i64],
+        map_coupling_row,
+    )
+}
+
+/// Ownership of one module.
+pub fn query_ownership(conn: &Connection, module: &str) -> Result<Option<ModuleOwnershipRow>, StorageError> {
+    conn.query_row(
+        &format!("SELECT {OWNERSHIP_COLUMNS} FROM module_ownership WHERE module = ?1"),
+        params![module],
+        map_ownership_row,
+    )
+    .optional()
+    .map_err(sql_err)
+}
+
+/// Ownership of every module, lowest bus factor first.
+pub fn query_all_ownership(conn: &Connection) -> Result<Vec<ModuleOwnershipRow>, StorageError> {
+    query(
+        conn,
+        &format!("SELECT {OWNERSHIP_COLUMNS} FROM module_ownership ORDER BY bus_factor, total_changes DESC, module"),
+        [],
+        map_ownership_row,
+    )
+}
+
+/// Count files with recorded churn.
+pub fn count_file_churn(conn: &Connection) -> Result<i64, StorageError> {
+    conn.query_row("SELECT COUNT(*) FROM file_churn", [], |row| row.get(0))
+        .map_err(sql_err)
+}
+
+fn query<T, P: rusqlite::Params>(
+    conn: &Connection,
+    sql: &str,
+    params: P,
+    map: fn(&rusqlite::Row) -> rusqlite::Result<T>,
+) -> Result<Vec<T>, StorageError> {
+    let mut stmt = conn.prepare_cached(sql).map_err(sql_err)?;
+    let rows = stmt.query_map(params, map).map_err(sql_err)?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(sql_err)
+}
+
+fn map_file_row(row: &rusqlite::Row) -> rusqlite::Result<FileChurnRow> {
+    Ok(FileChurnRow {
+        file: row.get(0)?,
+        commits: row.get(1)?,
+        insertions: row.get(2)?,
+        deletions: row.get(3)?,
+        authors: row.get(4)?,
+        top_author: row.get(5)?,
+        top_author_share: row.get(6)?,
+        first_changed_at: row.get(7)?,
+        last_changed_at: row.get(8)?,
+        complexity: row.get(9)?,
+        hotspot_score: row.get(10)?,
+        updated_at: row.get(11)?,
+    })
+}
+
+fn map_function_row(row: &rusqlite::Row) -> rusqlite::Result<FunctionChurnRow> {
+    Ok(FunctionChurnRow {
+        id: row.get(0)?,
+        file: row.get(1)?,
+        name: row.get(2)?,
+        line: row.get(3)?,
+        end_line: row.get(4)?,
+        commits: row.get(5)?,
+        lines_added: row.get(6)?,
+        lines_deleted: row.get(7)?,
+        last_changed_at: row.get(8)?,
+    })
+}
+
+fn map_coupling_row(row: &rusqlite::Row) -> rusqlite::Result<ChangeCouplingRow> {
+    Ok(ChangeCouplingRow {
+        file_a: row.get(0)?,
+        file_b: row.get(1)?,
+        co_changes: row.get(2)?,
+        revisions_a: row.get(3)?,
+        revisions_b: row.get(4)?,
+        degree: row.get(5)?,
+    })
+}
+
+fn map_ownership_row(row: &rusqlite::Row) -> rusqlite::Result<ModuleOwnershipRow> {
+    Ok(ModuleOwnershipRow {
+        module: row.get(0)?,
+        primary_owner: row.get(1)?,
+        primary_share: row.get(2)?,
+        bus_factor: row.get(3)?,
+        contributors: row.get(4)?,
+        total_changes: row.get(5)?,
+        authors: row.get(6)?,
+    })
+}
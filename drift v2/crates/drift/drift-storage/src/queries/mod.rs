@@ -16,3 +16,6 @@ pub mod scan_history;
 pub mod data_access;
 pub mod constants;
 pub mod env_variables;
+pub mod dependencies;
+pub mod complexity;
+pub mod history;
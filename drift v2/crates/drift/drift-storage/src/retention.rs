@@ -123,7 +123,8 @@ fn apply_retention_inner(
 
     cleanup_by_time(conn, "scan_history", "started_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "audit_snapshots", "created_at", medium_cutoff, report)?;
-    cleanup_by_time(conn, "health_trends", "recorded_at", medium_cutoff, report)?;
+    // Backfilled points sit at past commit times and would all be aged out.
+    cleanup_by_time_where(conn, "health_trends", "recorded_at", medium_cutoff, "backfilled = 0", report)?;
     cleanup_by_time(conn, "feedback", "created_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "constraint_verifications", "verified_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "contract_mismatches", "created_at", medium_cutoff, report)?;
@@ -178,7 +179,19 @@ fn cleanup_by_time(
     cutoff: i64,
     report: &mut RetentionReport,
 ) -> Result<(), StorageError> {
-    let sql = format!("DELETE FROM {table} WHERE {time_column} < ?1");
+    cleanup_by_time_where(conn, table, time_column, cutoff, "1", report)
+}
+
+/// Delete rows from `table` where `time_column` < `cutoff` and `filter` holds.
+fn cleanup_by_time_where(
+    conn: &Connection,
+    table: &str,
+    time_column: &str,
+    cutoff: i64,
+    filter: &str,
+    report: &mut RetentionReport,
+) -> Result<(), StorageError> {
+    let sql = format!("DELETE FROM {table} WHERE {time_column} < ?1 AND {filter}");
     let deleted = conn
         .execute(&sql, params![cutoff])
         .map_err(|e| StorageError::SqliteError {
@@ -218,7 +231,7 @@ mod tests {
              CREATE TABLE degradation_alerts (id INTEGER PRIMARY KEY, created_at INTEGER DEFAULT 0);
              CREATE TABLE policy_results (id INTEGER PRIMARY KEY, run_at INTEGER DEFAULT 0);
              CREATE TABLE audit_snapshots (id INTEGER PRIMARY KEY, created_at INTEGER DEFAULT 0);
-             CREATE TABLE health_trends (id INTEGER PRIMARY KEY, recorded_at INTEGER DEFAULT 0);
+             CREATE TABLE health_trends (id INTEGER PRIMARY KEY, recorded_at INTEGER DEFAULT 0, backfilled INTEGER DEFAULT 0);
              CREATE TABLE constraint_verifications (id INTEGER PRIMARY KEY, verified_at INTEGER DEFAULT 0);
              CREATE TABLE contract_mismatches (id INTEGER PRIMARY KEY, created_at INTEGER DEFAULT 0);
              CREATE TABLE dna_mutations (id TEXT PRIMARY KEY, detected_at INTEGER DEFAULT 0);
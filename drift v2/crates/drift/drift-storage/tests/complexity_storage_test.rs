@@ -0,0 +1,63 @@
+//! Function complexity storage — v011 migration and round-trip queries.
+
+use drift_storage::migrations;
+use drift_storage::queries::complexity::{self, FunctionComplexityRow};
+use rusqlite::Connection;
+
+fn setup_db() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    migrations::run_migrations(&conn).unwrap();
+    conn
+}
+
+fn row(file: &str, name: &str, line: i64, cyclomatic: i64, cognitive: i64) -> FunctionComplexityRow {
+    FunctionComplexityRow {
+        id: 0,
+        file: file.to_string(),
+        name: name.to_string(),
+        qualified_name: None,
+        line,
+        end_line: line + 10,
+        cyclomatic,
+        cognitive,
+        max_nesting: 2,
+        loc: 11,
+        halstead_volume: 120.5,
+        halstead_difficulty: 6.25,
+        halstead_effort: 753.1,
+        halstead_bugs: 0.04,
+        maintainability_index: 61.3,
+        created_at: 0,
+    }
+}
+
+#[test]
+fn roundtrip_function_complexity() {
+    let conn = setup_db();
+    let mut handler = row("src/api.ts", "handle", 12, 7, 11);
+    handler.qualified_name = Some("Api.handle".to_string());
+    complexity::insert_batch(&conn, &[row("src/api.ts", "parse", 1, 2, 1), handler, row("src/db.ts", "query", 4, 3, 4)]).unwrap();
+
+    let api = complexity::query_by_file(&conn, "src/api.ts").unwrap();
+    assert_eq!(api.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), ["parse", "handle"]);
+    assert_eq!(api[1].qualified_name.as_deref(), Some("Api.handle"));
+    assert_eq!((api[1].cyclomatic, api[1].cognitive, api[1].max_nesting), (7, 11, 2));
+    assert_eq!(api[1].halstead_difficulty, 6.25);
+    assert_eq!(api[1].maintainability_index, 61.3);
+
+    let most_complex = complexity::query_most_complex(&conn, 2).unwrap();
+    assert_eq!(most_complex.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), ["handle", "query"]);
+
+    let both = complexity::query_by_files(&conn, &["src/db.ts".to_string(), "src/api.ts".to_string()]).unwrap();
+    assert_eq!(both.len(), 3);
+    assert!(complexity::query_by_files(&conn, &[]).unwrap().is_empty());
+}
+
+#[test]
+fn replace_all_swaps_metrics() {
+    let conn = setup_db();
+    complexity::insert_batch(&conn, &[row("src/old.ts", "gone", 1, 5, 5)]).unwrap();
+    complexity::replace_all(&conn, &[row("src/new.ts", "fresh", 1, 1, 0)]).unwrap();
+    assert_eq!(complexity::count(&conn).unwrap(), 1);
+    assert!(complexity::query_by_file(&conn, "src/old.ts").unwrap().is_empty());
+}
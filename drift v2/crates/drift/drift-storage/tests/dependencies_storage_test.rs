@@ -0,0 +1,67 @@
+//! Dependency inventory storage — v010 migration and round-trip queries.
+
+use drift_storage::migrations;
+use drift_storage::queries::dependencies::{self, DependencyRow};
+use rusqlite::Connection;
+
+fn setup_db() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    migrations::run_migrations(&conn).unwrap();
+    conn
+}
+
+fn row(package_path: &str, name: &str, version: &str, direct: bool) -> DependencyRow {
+    DependencyRow {
+        id: 0,
+        package: format!("pkg-{package_path}"),
+        package_path: package_path.to_string(),
+        manager: "npm".to_string(),
+        lockfile: Some("package-lock.json".to_string()),
+        name: name.to_string(),
+        version: Some(version.to_string()),
+        purl: format!("pkg:npm/{name}@{version}"),
+        direct,
+        dev: false,
+        checksum: None,
+        depends_on: Vec::new(),
+        created_at: 0,
+    }
+}
+
+#[test]
+fn roundtrip_dependencies() {
+    let conn = setup_db();
+    let mut express = row(".", "express", "4.18.2", true);
+    express.depends_on = vec!["pkg:npm/debug@2.6.9".to_string()];
+    express.checksum = Some("sha512-abc".to_string());
+    let mut debug = row(".", "debug", "2.6.9", false);
+    debug.dev = true;
+    dependencies::insert_batch(&conn, &[express, debug, row("packages/web", "react", "18.2.0", true)]).unwrap();
+
+    assert_eq!(dependencies::count(&conn).unwrap(), 3);
+
+    let root = dependencies::query_by_package(&conn, ".").unwrap();
+    assert_eq!(root.len(), 2);
+    let express = root.iter().find(|r| r.name == "express").unwrap();
+    assert!(express.direct);
+    assert_eq!(express.depends_on, vec!["pkg:npm/debug@2.6.9".to_string()]);
+    assert_eq!(express.checksum.as_deref(), Some("sha512-abc"));
+    let debug = root.iter().find(|r| r.name == "debug").unwrap();
+    assert!(!debug.direct && debug.dev);
+    assert!(debug.depends_on.is_empty());
+
+    let react = dependencies::query_by_name(&conn, "npm", "react").unwrap();
+    assert_eq!(react.len(), 1);
+    assert_eq!(react[0].package_path, "packages/web");
+}
+
+#[test]
+fn replace_all_discards_previous_inventory() {
+    let conn = setup_db();
+    dependencies::insert_batch(&conn, &[row(".", "lodash", "4.17.20", true)]).unwrap();
+    dependencies::replace_all(&conn, &[row(".", "lodash", "4.17.21", true), row(".", "ms", "2.1.3", false)]).unwrap();
+
+    let all = dependencies::query_all(&conn).unwrap();
+    assert_eq!(all.len(), 2);
+    assert!(all.iter().all(|r| r.version.as_deref() != Some("4.17.20")));
+}
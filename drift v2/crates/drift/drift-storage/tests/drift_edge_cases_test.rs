@@ -27,6 +27,7 @@ fn sql_injection_in_violation_id() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // Table still exists and violation was inserted
@@ -46,6 +47,7 @@ fn sql_injection_in_file_path() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let results = enforcement::query_violations_by_file(&conn, &malicious_file).unwrap();
@@ -93,6 +95,7 @@ fn unicode_in_violation_message() {
         rule_id: "r".into(), message: unicode_msg.clone(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -146,6 +149,7 @@ fn empty_string_violation_fields() {
         quick_fix_strategy: Some("".into()),
         quick_fix_description: Some("".into()),
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -181,6 +185,7 @@ fn huge_violation_message() {
         rule_id: "r".into(), message: huge_msg.clone(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -217,6 +222,7 @@ fn max_integer_values() {
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(u32::MAX), owasp_category: None,
         suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -272,6 +278,7 @@ fn special_characters_in_file_paths() {
             rule_id: "r".into(), message: "m".into(),
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+            owners: Vec::new(),
         }).unwrap();
     }
 
@@ -90,6 +90,7 @@ fn violations_survive_restart() {
             owasp_category: None,
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         }).unwrap();
     }
 
@@ -256,6 +257,7 @@ fn integrity_check_passes_after_heavy_writes() {
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None,
             suppressed: false, is_new: false,
+            owners: Vec::new(),
         }).unwrap();
     }
 
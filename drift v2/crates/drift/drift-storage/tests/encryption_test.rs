@@ -0,0 +1,76 @@
+//! Encryption at rest for drift.db — key parsing, refusal without SQLCipher,
+//! and (with the `encryption` feature) keyed round-trips and rotation.
+
+use drift_storage::connection::encryption::DatabaseKey;
+use drift_storage::DatabaseManager;
+use tempfile::TempDir;
+
+const RAW_HEX: &str = "ffeeddccbbaa99887766554433221100ffeeddccbbaa99887766554433221100";
+
+#[test]
+fn parse_distinguishes_raw_and_passphrase() {
+    assert!(matches!(DatabaseKey::parse(RAW_HEX).unwrap(), DatabaseKey::Raw(ref b) if b.len() == 32));
+    assert_eq!(
+        DatabaseKey::parse(&format!("x'{RAW_HEX}'")).unwrap(),
+        DatabaseKey::parse(RAW_HEX).unwrap()
+    );
+    assert!(matches!(
+        DatabaseKey::parse("not hex at all").unwrap(),
+        DatabaseKey::Passphrase(_)
+    ));
+    assert!(DatabaseKey::parse("").is_err());
+}
+
+#[test]
+fn debug_is_redacted() {
+    let printed = format!("{:?}", DatabaseKey::parse("s3cret").unwrap());
+    assert!(!printed.contains("s3cret"));
+}
+
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn open_encrypted_refuses_without_sqlcipher() {
+    let dir = TempDir::new().unwrap();
+    let db_path = dir.path().join("enc.db");
+    let result = DatabaseManager::open_encrypted(&db_path, DatabaseKey::parse(RAW_HEX).unwrap());
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypted_round_trip_and_rotation() {
+    let dir = TempDir::new().unwrap();
+    let db_path = dir.path().join("enc.db");
+    {
+        let db = DatabaseManager::open_encrypted(&db_path, DatabaseKey::parse("one").unwrap())
+            .unwrap();
+        assert!(db.is_encrypted());
+        db.with_writer(|conn| {
+            conn.execute_batch("CREATE TABLE t (v TEXT); INSERT INTO t VALUES ('x');")
+                .unwrap();
+            Ok(())
+        })
+        .unwrap();
+        db.checkpoint().unwrap();
+    }
+    assert!(DatabaseManager::open(&db_path).is_err());
+
+    drift_storage::connection::encryption::rotate_key(
+        &db_path,
+        Some(&DatabaseKey::parse("one").unwrap()),
+        &DatabaseKey::parse(RAW_HEX).unwrap(),
+    )
+    .unwrap();
+
+    assert!(DatabaseManager::open_encrypted(&db_path, DatabaseKey::parse("one").unwrap()).is_err());
+    let db = DatabaseManager::open_encrypted(&db_path, DatabaseKey::parse(RAW_HEX).unwrap())
+        .unwrap();
+    let count: i64 = db
+        .with_reader(|conn| {
+            Ok(conn
+                .query_row("SELECT count(*) FROM t", [], |row| row.get(0))
+                .unwrap())
+        })
+        .unwrap();
+    assert_eq!(count, 1);
+}
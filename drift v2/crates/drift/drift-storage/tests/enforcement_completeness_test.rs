@@ -1,6 +1,7 @@
 //! Tests for the untested enforcement query functions:
 //! audit_snapshots, health_trends, feedback_by_pattern, feedback_adjustments,
-//! policy_results, degradation_alerts_by_type, violations_by_file, get_violation_pattern_id.
+//! policy_results, scoped policy, degradation_alerts_by_type, violations_by_file, violations_by_owner,
+//! get_violation_pattern_id.
 
 use drift_storage::migrations::run_migrations;
 use drift_storage::queries::enforcement::*;
@@ -83,6 +84,23 @@ fn health_trends_limit_respected() {
     assert_eq!(results.len(), 3);
 }
 
+#[test]
+fn health_trends_backfilled_at_commit_times() {
+    let conn = setup_db();
+    let point = |value: f64, recorded_at: u64| HealthTrendRow {
+        metric_name: "health_score".to_string(),
+        metric_value: value,
+        recorded_at,
+    };
+    let rows = [point(61.0, 1_700_000_000), point(64.5, 1_700_086_400)];
+    assert_eq!(insert_health_trends_at(&conn, &rows).unwrap(), 2);
+    assert_eq!(insert_health_trends_at(&conn, &rows).unwrap(), 0, "re-running adds nothing");
+
+    let trend = query_health_trends(&conn, "health_score", 10).unwrap();
+    assert_eq!(trend.iter().map(|r| r.recorded_at).collect::<Vec<_>>(), [1_700_086_400, 1_700_000_000]);
+    assert_eq!(trend[0].metric_value, 64.5);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // FEEDBACK BY PATTERN + ADJUSTMENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -98,6 +116,7 @@ fn feedback_by_pattern_roundtrip() {
         rule_id: "r1".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     insert_feedback(&conn, &FeedbackRow {
@@ -127,6 +146,7 @@ fn feedback_adjustments_bayesian_deltas() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // fix → (1.0, 0.0)
@@ -178,6 +198,30 @@ fn policy_result_insert_and_query() {
     assert_eq!(results[0].gates_failed, 2);
 }
 
+#[test]
+fn scoped_policy_replaced_wholesale() {
+    let conn = setup_db();
+    let scope = |scope: &str, passed: bool| PolicyScopeRow {
+        scope: scope.into(), policy_name: "strict".into(), aggregation_mode: "AllMustPass".into(),
+        overall_passed: passed, overall_score: 80.0, file_count: 3,
+        gates_passed: 5, gates_failed: i64::from(!passed), details: "details".into(),
+    };
+    let governed = GovernedViolationRow {
+        violation_id: "v1".into(), gate_id: "pattern-compliance".into(),
+        scope: "glob:core/**".into(), policy_name: "strict".into(),
+    };
+    replace_scoped_policy(&conn, &[scope("glob:core/**", false), scope("root", true)], &[governed]).unwrap();
+
+    let scopes = query_policy_scopes(&conn).unwrap();
+    assert_eq!(scopes.iter().map(|s| s.scope.as_str()).collect::<Vec<_>>(), ["root", "glob:core/**"]);
+    assert!(!scopes[1].overall_passed);
+    assert_eq!(query_governed_violations(&conn).unwrap()[0].scope, "glob:core/**");
+
+    replace_scoped_policy(&conn, &[], &[]).unwrap();
+    assert!(query_policy_scopes(&conn).unwrap().is_empty());
+    assert!(query_governed_violations(&conn).unwrap().is_empty());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DEGRADATION ALERTS BY TYPE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -215,6 +259,7 @@ fn violations_by_file() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(95), owasp_category: None, suppressed: false, is_new: true,
+        owners: Vec::new(),
     }).unwrap();
     insert_violation(&conn, &ViolationRow {
         id: "vf-2".into(), file: "src/db.ts".into(), line: 5,
@@ -223,6 +268,7 @@ fn violations_by_file() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(89), owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let auth = query_violations_by_file(&conn, "src/auth.ts").unwrap();
@@ -231,6 +277,35 @@ fn violations_by_file() {
     assert!(auth[0].is_new);
 }
 
+#[test]
+fn violations_by_owner() {
+    let conn = setup_db();
+    let owned = [
+        ("vo-1", "src/auth.ts", vec!["@org/security".to_string()]),
+        ("vo-2", "src/db.ts", vec!["@org/security".to_string(), "@alice".to_string()]),
+        ("vo-3", "README.md", Vec::new()),
+    ];
+    for (id, file, owners) in owned {
+        insert_violation(&conn, &ViolationRow {
+            id: id.into(), file: file.into(), line: 1,
+            column: None, end_line: None, end_column: None,
+            severity: "warning".into(), pattern_id: "p".into(),
+            rule_id: "r".into(), message: "m".into(),
+            quick_fix_strategy: None, quick_fix_description: None,
+            cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+            owners,
+        }).unwrap();
+    }
+
+    let security = query_violations_by_owner(&conn, "@ORG/Security").unwrap();
+    assert_eq!(security.iter().map(|v| v.id.as_str()).collect::<Vec<_>>(), ["vo-1", "vo-2"]);
+    assert_eq!(security[1].owners, ["@org/security", "@alice"]);
+
+    let unowned = query_violations_by_owner(&conn, "unowned").unwrap();
+    assert_eq!(unowned.len(), 1);
+    assert_eq!(unowned[0].id, "vo-3");
+}
+
 #[test]
 fn get_violation_pattern_id_found() {
     let conn = setup_db();
@@ -241,6 +316,7 @@ fn get_violation_pattern_id_found() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let pid = get_violation_pattern_id(&conn, "vp-1").unwrap();
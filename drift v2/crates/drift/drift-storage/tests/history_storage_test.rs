@@ -0,0 +1,101 @@
+//! Git history analytics storage — v012 migration and round-trip queries.
+
+use drift_storage::migrations;
+use drift_storage::queries::history::{self, ChangeCouplingRow, FileChurnRow, FunctionChurnRow, ModuleOwnershipRow};
+use rusqlite::Connection;
+
+fn setup_db() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    migrations::run_migrations(&conn).unwrap();
+    conn
+}
+
+fn file(path: &str, commits: i64, hotspot_score: f64) -> FileChurnRow {
+    FileChurnRow {
+        file: path.to_string(),
+        commits,
+        insertions: commits * 10,
+        deletions: commits * 3,
+        authors: 2,
+        top_author: Some("alice".to_string()),
+        top_author_share: 0.75,
+        first_changed_at: 1_700_000_000,
+        last_changed_at: 1_700_100_000,
+        complexity: 12,
+        hotspot_score,
+        updated_at: 0,
+    }
+}
+
+fn coupling(a: &str, b: &str, degree: f64) -> ChangeCouplingRow {
+    ChangeCouplingRow {
+        file_a: a.to_string(),
+        file_b: b.to_string(),
+        co_changes: 4,
+        revisions_a: 5,
+        revisions_b: 6,
+        degree,
+    }
+}
+
+#[test]
+fn roundtrip_churn_and_hotspots() {
+    let conn = setup_db();
+    history::replace_file_churn(&conn, &[file("src/a.ts", 9, 1.0), file("src/b.ts", 4, 0.3), file("README.md", 7, 0.0)])
+        .unwrap();
+
+    let hotspots = history::query_hotspots(&conn, 10).unwrap();
+    assert_eq!(hotspots.iter().map(|r| r.file.as_str()).collect::<Vec<_>>(), ["src/a.ts", "src/b.ts"]);
+    let a = history::query_file_churn(&conn, "src/a.ts").unwrap().unwrap();
+    assert_eq!((a.commits, a.insertions, a.top_author.as_deref()), (9, 90, Some("alice")));
+    assert!(history::query_file_churn(&conn, "missing.ts").unwrap().is_none());
+
+    history::replace_file_churn(&conn, &[file("src/c.ts", 1, 1.0)]).unwrap();
+    assert_eq!(history::count_file_churn(&conn).unwrap(), 1);
+
+    let functions = [("parse", 1), ("render", 20)].map(|(name, line)| FunctionChurnRow {
+        id: 0,
+        file: "src/c.ts".to_string(),
+        name: name.to_string(),
+        line,
+        end_line: line + 5,
+        commits: 2,
+        lines_added: 8,
+        lines_deleted: 1,
+        last_changed_at: 1_700_100_000,
+    });
+    history::replace_function_churn(&conn, &functions).unwrap();
+    let rows = history::query_function_churn_by_file(&conn, "src/c.ts").unwrap();
+    assert_eq!(rows.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), ["parse", "render"]);
+    assert_eq!(history::query_all_function_churn(&conn).unwrap().len(), 2);
+}
+
+#[test]
+fn roundtrip_coupling_and_ownership() {
+    let conn = setup_db();
+    history::replace_coupling(
+        &conn,
+        &[coupling("src/a.ts", "src/b.ts", 0.8), coupling("src/a.ts", "src/c.ts", 0.4), coupling("src/d.ts", "src/e.ts", 0.9)],
+    )
+    .unwrap();
+
+    let coupled = history::query_coupled_files(&conn, "src/a.ts", 5).unwrap();
+    assert_eq!(coupled.iter().map(|r| r.file_b.as_str()).collect::<Vec<_>>(), ["src/b.ts", "src/c.ts"]);
+    assert_eq!(history::query_top_coupling(&conn, 1).unwrap()[0].file_a, "src/d.ts");
+
+    let module = |name: &str, bus_factor| ModuleOwnershipRow {
+        module: name.to_string(),
+        primary_owner: Some("alice".to_string()),
+        primary_share: 0.9,
+        bus_factor,
+        contributors: 3,
+        total_changes: 120,
+        authors: r#"[{"author":"alice","changes":108,"share":0.9}]"#.to_string(),
+    };
+    history::replace_ownership(&conn, &[module("src/api", 2), module("src/core", 1)]).unwrap();
+    let all = history::query_all_ownership(&conn).unwrap();
+    assert_eq!(all.iter().map(|r| r.module.as_str()).collect::<Vec<_>>(), ["src/core", "src/api"]);
+    let api = history::query_ownership(&conn, "src/api").unwrap().unwrap();
+    assert_eq!((api.bus_factor, api.contributors), (2, 3));
+    assert!(api.authors.contains("alice"));
+}
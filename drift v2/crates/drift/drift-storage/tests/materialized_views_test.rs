@@ -46,6 +46,7 @@ fn status_view_with_data() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
     // Insert suppressed violation (should NOT count)
     insert_violation(&conn, &ViolationRow {
@@ -55,6 +56,7 @@ fn status_view_with_data() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: true, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // Insert gate results
@@ -102,6 +104,7 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(89), owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // High: warning + cwe_id + not suppressed
@@ -112,6 +115,7 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(79), owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // Suppressed security (should NOT count)
@@ -122,6 +126,7 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(95), owasp_category: None, suppressed: true, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     // Non-security (no cwe_id, should NOT count)
@@ -132,6 +137,7 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let s = security::refresh_security(&conn).unwrap();
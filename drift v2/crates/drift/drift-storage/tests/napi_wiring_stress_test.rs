@@ -56,6 +56,7 @@ fn stress_enforcement_full_pipeline() {
                 owasp_category: if i % 3 == 0 { Some("A01:2021".to_string()) } else { None },
                 suppressed: i % 7 == 0,
                 is_new: false,
+                owners: Vec::new(),
             })?;
         }
         Ok(())
@@ -782,6 +783,7 @@ fn stress_concurrent_reads_and_writes() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             })?;
         }
         Ok(())
@@ -832,6 +834,7 @@ fn stress_concurrent_reads_and_writes() {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
                 })
             }).unwrap();
         }
@@ -875,6 +878,7 @@ fn stress_edge_cases() {
             owasp_category: Some("A03:2021-注入".to_string()),
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         })
     }).unwrap();
 
@@ -926,6 +930,7 @@ fn stress_edge_cases() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         })
     }).unwrap();
 
@@ -954,6 +959,7 @@ fn stress_edge_cases() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         })
     }).unwrap();
 
@@ -1072,6 +1078,7 @@ fn stress_wal_checkpoint() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
             })?;
         }
         Ok(())
@@ -1103,6 +1110,7 @@ fn stress_wal_checkpoint() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
         })
     }).unwrap();
 
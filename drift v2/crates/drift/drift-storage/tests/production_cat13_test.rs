@@ -434,6 +434,15 @@ fn t13_04_tier_assignment_coverage() {
         "contracts",
         "dna_genes",
         "pattern_status",
+        "dependencies",
+        "function_complexity",
+        "file_churn",
+        "function_churn",
+        "change_coupling",
+        "module_ownership",
+        "schema_drift",
+        "policy_scopes",
+        "governed_violations",
     ]
     .into_iter()
     .collect();
@@ -476,8 +485,8 @@ fn t13_04_tier_assignment_coverage() {
     // ── Verify expected table count ──
     assert_eq!(
         all_tables.len(),
-        46,
-        "Expected 46 tables after all migrations, got {}. Tables: {:?}",
+        55,
+        "Expected 55 tables after all migrations, got {}. Tables: {:?}",
         all_tables.len(),
         all_tables
     );
@@ -319,6 +319,7 @@ fn t7_01_all_33_data_commands_round_trip() {
             owasp_category: Some("A03:2021".into()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
         }]))
         .unwrap();
 
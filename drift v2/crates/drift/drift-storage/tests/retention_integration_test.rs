@@ -99,6 +99,7 @@ fn short_tier_violations_cleaned() {
         rule_id: "r".into(), message: "old".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
     // Backdate it
     conn.execute(
@@ -114,6 +115,7 @@ fn short_tier_violations_cleaned() {
         rule_id: "r".into(), message: "new".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
 
     let report = apply_retention(&conn, &RetentionPolicy { short_days: 30, medium_days: 90, long_days: 365 }).unwrap();
@@ -144,6 +146,32 @@ fn medium_tier_scan_history_cleaned() {
     assert_eq!(scans[0].total_files, Some(60));
 }
 
+#[test]
+fn medium_tier_keeps_backfilled_health_trends() {
+    let conn = setup_db();
+    let now = epoch_now();
+
+    // Backfill replays commits from a year ago; a live run recorded a stale point too.
+    let point = |value: f64, days_ago: i64| enforcement::HealthTrendRow {
+        metric_name: "health_score".into(),
+        metric_value: value,
+        recorded_at: (now - days_ago * 86400) as u64,
+    };
+    let backfilled = [point(58.0, 365), point(61.0, 200), point(64.0, 120)];
+    assert_eq!(enforcement::insert_health_trends_at(&conn, &backfilled).unwrap(), 3);
+    conn.execute(
+        "INSERT INTO health_trends (metric_name, metric_value, recorded_at) VALUES ('health_score', 66.0, ?1)",
+        params![now - 100 * 86400],
+    ).unwrap();
+    enforcement::insert_health_trend(&conn, "health_score", 70.0).unwrap();
+
+    apply_retention(&conn, &RetentionPolicy { short_days: 30, medium_days: 90, long_days: 365 }).unwrap();
+
+    let trend = enforcement::query_health_trends(&conn, "health_score", 10).unwrap();
+    let values: Vec<f64> = trend.iter().map(|r| r.metric_value).collect();
+    assert_eq!(values, [70.0, 64.0, 61.0, 58.0], "only the stale live point is aged out");
+}
+
 #[test]
 fn long_tier_parse_cache_cleaned() {
     let conn = setup_db();
@@ -192,6 +220,7 @@ fn retention_atomicity_all_or_nothing() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Vec::new(),
     }).unwrap();
     conn.execute("UPDATE violations SET created_at = ?1 WHERE id = 'v1'", params![now - 60 * 86400]).unwrap();
 
@@ -223,6 +252,7 @@ fn retention_report_tracks_per_table() {
             rule_id: "r".into(), message: "m".into(),
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+            owners: Vec::new(),
         }).unwrap();
     }
     conn.execute("UPDATE violations SET created_at = ?1", params![now - 60 * 86400]).unwrap();
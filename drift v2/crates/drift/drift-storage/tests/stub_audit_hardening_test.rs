@@ -366,6 +366,31 @@ fn boundary_table_name_null_for_models_without_table() {
     assert_eq!(results[0].table_name, None);
 }
 
+#[test]
+fn schema_drift_is_replaced_wholesale() {
+    use drift_storage::queries::boundaries::*;
+    let conn = setup_db();
+
+    let drift = |kind: &str, column: Option<&str>, line: i64| SchemaDriftRow {
+        kind: kind.to_string(),
+        model_name: "User".to_string(),
+        table_name: "users".to_string(),
+        column_name: column.map(str::to_string),
+        file: "models/user.py".to_string(),
+        line,
+        message: format!("{kind} on users"),
+    };
+    replace_schema_drift(&conn, &[drift("missing_column", Some("nickname"), 12), drift("missing_table", None, 3)]).unwrap();
+
+    let stored = get_schema_drift(&conn).unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored[0].kind, "missing_table", "ordered by file and line");
+    assert_eq!(stored[1].column_name.as_deref(), Some("nickname"));
+
+    replace_schema_drift(&conn, &[]).unwrap();
+    assert!(get_schema_drift(&conn).unwrap().is_empty(), "a clean run must clear stale drift");
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Helpers
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,157 @@
+//! # drift-wasi
+//!
+//! The parsing and detection core behind a stdin/stdout JSON interface, so
+//! rule evaluation can run sandboxed (wasmtime, plugin hosts) without native
+//! dependencies. Builds against `drift-analysis` without its `native`
+//! feature: no filesystem walking, git, SQLite or threads.
+//!
+//! The host sends the file contents; nothing is read from disk. See
+//! [`protocol`] for the request and response shapes.
+//!
+//! ## Building
+//!
+//! The tree-sitter grammars are C, so the wasm build needs wasi-sdk:
+//!
+//! ```text
+//! CC_wasm32_wasip1=$WASI_SDK_PATH/bin/clang \
+//! AR_wasm32_wasip1=$WASI_SDK_PATH/bin/llvm-ar \
+//! CFLAGS_wasm32_wasip1=--sysroot=$WASI_SDK_PATH/share/wasi-sysroot \
+//!     cargo build -p drift-wasi --target wasm32-wasip1 --release
+//! echo '{"files":[...]}' | wasmtime target/wasm32-wasip1/release/drift-wasi.wasm
+//! ```
+
+pub mod protocol;
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use drift_analysis::engine::gast::normalizers::normalizer_for;
+use drift_analysis::engine::{AnalysisPipeline, DetectionEngine, ResolutionIndex, VisitorRegistry};
+use drift_analysis::enforcement::gates::{GateInputBuilder, GateOrchestrator};
+use drift_analysis::enforcement::reporters::{available_formats, create_reporter};
+use drift_analysis::frameworks::{CompiledFrameworkPack, FrameworkMatcher, FrameworkPackRegistry};
+use drift_analysis::graph::taint::{analyze_intraprocedural, TaintRegistry};
+use drift_analysis::language_provider::embedded_sql::register_taint_sinks;
+use drift_analysis::parsers::ParserManager;
+use drift_core::errors::{error_code, DriftErrorCode, GateError};
+
+use protocol::{ErrorResponse, FileError, Request, Response, PROTOCOL_VERSION};
+
+/// Read one request from `input`, evaluate it and write the response to
+/// `output`. A request that cannot be read or evaluated is written as an
+/// [`ErrorResponse`] and returned as `Err`.
+pub fn run(mut input: impl Read, mut output: impl Write) -> Result<(), String> {
+    let result = read_request(&mut input).and_then(|request| evaluate(&request));
+    let written = match &result {
+        Ok(response) => serde_json::to_writer(&mut output, response),
+        Err(error) => serde_json::to_writer(
+            &mut output,
+            &ErrorResponse { version: PROTOCOL_VERSION, error: error.clone() },
+        ),
+    };
+    written
+        .map_err(|e| format!("[{}] cannot write response: {e}", error_code::PIPELINE_ERROR))
+        .and_then(|_| output.flush().map_err(|e| format!("[{}] cannot write response: {e}", error_code::PIPELINE_ERROR)))?;
+    result.map(|_| ())
+}
+
+fn read_request(input: &mut impl Read) -> Result<Request, String> {
+    let mut buf = String::new();
+    input
+        .read_to_string(&mut buf)
+        .map_err(|e| format!("[{}] cannot read request: {e}", error_code::CONFIG_ERROR))?;
+    serde_json::from_str(&buf).map_err(|e| format!("[{}] invalid request: {e}", error_code::CONFIG_ERROR))
+}
+
+/// Parse and detect every file in `request`, then run the gates and
+/// reporter if asked. Per-file failures are reported in
+/// [`Response::errors`]; only a bad pack or report format fails the request.
+pub fn evaluate(request: &Request) -> Result<Response, String> {
+    let reporter = match request.report.as_deref() {
+        Some(format) => Some(create_reporter(format).ok_or_else(|| {
+            format!(
+                "[{}] unknown report format '{format}' (expected one of: {})",
+                error_code::CONFIG_ERROR,
+                available_formats().join(", "),
+            )
+        })?),
+        None => None,
+    };
+
+    let parser = ParserManager::new();
+    let mut pipeline = AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new()));
+    let mut matcher = FrameworkMatcher::new(framework_packs(request)?);
+    let mut taint_registry = TaintRegistry::with_defaults();
+
+    let mut response = Response {
+        version: PROTOCOL_VERSION,
+        matches: Vec::new(),
+        parse_results: Vec::new(),
+        gast: BTreeMap::new(),
+        gates: Vec::new(),
+        report: None,
+        errors: Vec::new(),
+    };
+    let mut flows = Vec::new();
+
+    for file in &request.files {
+        let source = file.source.as_bytes();
+        let (parse_result, tree) = match parser.parse_returning_tree(source, Path::new(&file.path)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                response.errors.push(FileError { path: file.path.clone(), error: e.napi_string() });
+                continue;
+            }
+        };
+
+        let mut resolution_index = ResolutionIndex::new();
+        let result = pipeline.analyze_file_with_frameworks(&parse_result, source, &tree, &mut resolution_index, &mut matcher);
+        response.matches.extend(result.matches);
+
+        if request.gates || reporter.is_some() {
+            register_taint_sinks(&mut taint_registry, &result.embedded_sql.injection_candidates);
+            flows.extend(analyze_intraprocedural(&parse_result, &taint_registry));
+        }
+        if request.include_gast {
+            let gast = normalizer_for(parse_result.language).normalize(&tree, source);
+            response.gast.insert(file.path.clone(), gast);
+        }
+        if request.include_parse_results {
+            response.parse_results.push(parse_result);
+        }
+    }
+
+    if request.gates || reporter.is_some() {
+        let input = GateInputBuilder::new()
+            .files(request.files.iter().map(|f| f.path.clone()).collect())
+            .patterns_from_matches(&response.matches)
+            .security_findings_from_taint_flows(&flows)
+            .build();
+        response.gates = GateOrchestrator::new()
+            .execute(&input)
+            .map_err(|e| GateError::EvaluationFailed(e).napi_string())?;
+    }
+    if let Some(reporter) = reporter {
+        response.report = Some(
+            reporter
+                .generate(&response.gates)
+                .map_err(|e| format!("[{}] {} report failed: {e}", error_code::GATE_FAILED, reporter.name()))?,
+        );
+    }
+    Ok(response)
+}
+
+/// Built-in packs (unless disabled) plus the request's packs. A pack that
+/// fails to load fails the request, since its rules would silently not run.
+fn framework_packs(request: &Request) -> Result<Vec<CompiledFrameworkPack>, String> {
+    let mut packs = if request.builtin_packs {
+        FrameworkPackRegistry::with_builtins().into_packs()
+    } else {
+        Vec::new()
+    };
+    for toml in &request.packs {
+        packs.push(FrameworkPackRegistry::load_single(toml).map_err(|e| e.napi_string())?);
+    }
+    Ok(packs)
+}
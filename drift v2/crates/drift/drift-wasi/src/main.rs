@@ -0,0 +1,16 @@
+//! `drift-wasi` — reads one JSON request from stdin and writes one JSON
+//! response to stdout. Exits 1 when the request fails; the response then
+//! carries the error.
+
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match drift_wasi::run(io::stdin().lock(), io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Request and response shapes. One request is read from stdin and one
+//! response written to stdout per run.
+
+use std::collections::BTreeMap;
+
+use drift_analysis::engine::gast::GASTNode;
+use drift_analysis::engine::types::PatternMatch;
+use drift_analysis::enforcement::gates::GateResult;
+use drift_analysis::parsers::types::ParseResult;
+use serde::{Deserialize, Serialize};
+
+/// Bumped on any incompatible change to [`Request`] or [`Response`].
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Files to evaluate and what to return for them.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Request {
+    pub files: Vec<SourceFile>,
+    /// Extra framework packs, as TOML.
+    #[serde(default)]
+    pub packs: Vec<String>,
+    /// Load the built-in framework packs alongside `packs`.
+    #[serde(default = "default_true")]
+    pub builtin_packs: bool,
+    #[serde(default)]
+    pub include_parse_results: bool,
+    /// Include each file's normalized GAST.
+    #[serde(default)]
+    pub include_gast: bool,
+    /// Run the quality gates over the matches.
+    #[serde(default)]
+    pub gates: bool,
+    /// Render the gate results with this reporter (implies `gates`).
+    #[serde(default)]
+    pub report: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A file's path (used for language detection and in results) and content.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SourceFile {
+    pub path: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Response {
+    pub version: u32,
+    pub matches: Vec<PatternMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parse_results: Vec<ParseResult>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub gast: BTreeMap<String, GASTNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub gates: Vec<GateResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<String>,
+    /// Files that could not be parsed, including unsupported languages.
+    pub errors: Vec<FileError>,
+}
+
+/// Why a file produced no results. `error` is `[ERROR_CODE] message`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileError {
+    pub path: String,
+    pub error: String,
+}
+
+/// Written instead of a [`Response`] when the request itself fails.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub version: u32,
+    pub error: String,
+}
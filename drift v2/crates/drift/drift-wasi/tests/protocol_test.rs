@@ -0,0 +1,97 @@
+//! stdin/stdout protocol — requests in, responses (or errors) out.
+
+use serde_json::{json, Value};
+
+const APP: &str = r#"import passport from "passport";
+
+export function handler(req) {
+  const id = req.query.id;
+  return db.query("SELECT * FROM users WHERE id = " + id);
+}
+"#;
+
+const PACK: &str = r#"
+[framework]
+name = "plugin-rules"
+languages = ["typescript", "javascript"]
+
+[[patterns]]
+id = "PLUGIN-AUTH-001"
+category = "auth"
+[patterns.match]
+imports = ["passport"]
+"#;
+
+fn call(request: Value) -> (Result<(), String>, Value) {
+    let input = serde_json::to_vec(&request).unwrap();
+    let mut output = Vec::new();
+    let result = drift_wasi::run(input.as_slice(), &mut output);
+    (result, serde_json::from_slice(&output).expect("response is JSON"))
+}
+
+#[test]
+fn custom_pack_rules_match() {
+    let (result, response) = call(json!({
+        "files": [{ "path": "src/app.js", "source": APP }],
+        "packs": [PACK],
+        "builtin_packs": false,
+    }));
+    result.unwrap();
+    assert_eq!(response["version"], 1);
+    let ids: Vec<&str> = response["matches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["pattern_id"].as_str().unwrap())
+        .collect();
+    assert!(ids.contains(&"PLUGIN-AUTH-001"), "{ids:?}");
+    assert!(response.get("gates").is_none());
+    assert!(response.get("parse_results").is_none());
+}
+
+#[test]
+fn unsupported_files_are_reported_not_fatal() {
+    let (result, response) = call(json!({
+        "files": [
+            { "path": "notes.unknownext", "source": "text" },
+            { "path": "lib.py", "source": "def f(x):\n    return x\n" },
+        ],
+        "include_parse_results": true,
+        "include_gast": true,
+    }));
+    result.unwrap();
+    let errors = response["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["path"], "notes.unknownext");
+    assert!(errors[0]["error"].as_str().unwrap().starts_with("[UNSUPPORTED_LANGUAGE]"));
+    assert_eq!(response["parse_results"][0]["functions"][0]["name"], "f");
+    assert!(response["gast"]["lib.py"].is_object());
+}
+
+#[test]
+fn report_runs_gates() {
+    let (result, response) = call(json!({
+        "files": [{ "path": "src/app.js", "source": APP }],
+        "report": "sarif",
+    }));
+    result.unwrap();
+    let gates = response["gates"].as_array().unwrap();
+    assert!(gates.iter().any(|g| g["gate_id"] == "pattern-compliance"), "{gates:?}");
+    let sarif: Value = serde_json::from_str(response["report"].as_str().unwrap()).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+}
+
+#[test]
+fn bad_requests_fail_with_error_codes() {
+    let (result, response) = call(json!({ "files": [], "report": "pdf" }));
+    let error = result.unwrap_err();
+    assert!(error.starts_with("[CONFIG_ERROR] unknown report format 'pdf'"), "{error}");
+    assert_eq!(response["error"], error);
+
+    let (result, _) = call(json!({ "files": [], "packs": ["not = [valid"] }));
+    assert!(result.unwrap_err().starts_with("[DETECTION_ERROR]"));
+
+    let (result, response) = call(json!({ "file": [] }));
+    assert!(result.unwrap_err().starts_with("[CONFIG_ERROR] invalid request"));
+    assert_eq!(response["version"], 1);
+}
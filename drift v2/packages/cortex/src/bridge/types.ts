@@ -928,7 +928,8 @@ export type ProvenanceAction =
   | "used_in_decision"
   | "corrected_by"
   | "reclassified_from"
-  | "retracted";
+  | "retracted"
+  | "imported_from";
 
 /** A single hop in the provenance chain. */
 export interface ProvenanceHop {
@@ -1055,6 +1056,7 @@ export const CortexErrorCode = {
   CONFIG_ERROR: "CONFIG_ERROR",
   DEGRADED_MODE: "DEGRADED_MODE",
   RUNTIME_NOT_INITIALIZED: "RUNTIME_NOT_INITIALIZED",
+  COMPLETION_ERROR: "COMPLETION_ERROR",
 } as const;
 
 export type CortexErrorCodeType = (typeof CortexErrorCode)[keyof typeof CortexErrorCode];
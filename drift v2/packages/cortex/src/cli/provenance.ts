@@ -31,6 +31,8 @@ function formatAction(action: string): string {
       return "Corrected by";
     case "reclassified_from":
       return "Reclassified from";
+    case "imported_from":
+      return "Imported from";
     default:
       return action;
   }
@@ -177,6 +177,7 @@ describe('PH6-01: Enforcement pass uses real gate results', () => {
             totalViolations: 5,
             gates: [],
             sarif: null,
+            owners: [],
           };
         },
       }),
@@ -199,6 +200,7 @@ describe('PH6-01: Enforcement pass uses real gate results', () => {
             totalViolations: 0,
             gates: [],
             sarif: null,
+            owners: [],
           };
         },
       }),
@@ -5,16 +5,16 @@
  * `crates/drift/drift-napi/src/bindings/*.rs`. Function names and parameter
  * types MUST match Rust exactly. When Rust disagrees with TypeScript, Rust wins.
  *
- * 66 methods total, grouped by Rust binding module:
+ * 69 methods total, grouped by Rust binding module:
  * - Lifecycle (4): lifecycle.rs
  * - Scanner (3): scanner.rs
- * - Analysis (4): analysis.rs
+ * - Analysis (5): analysis.rs
  * - Patterns (4): patterns.rs
  * - Graph (5): graph.rs
  * - Structural (9): structural.rs
  * - Enforcement (7): enforcement.rs
  * - Feedback (3): feedback.rs
- * - Advanced (4): advanced.rs
+ * - Advanced (6): advanced.rs
  * - Bridge (21): bridge.rs
  * - Cloud (2): cloud.rs
  */
@@ -26,6 +26,7 @@ import type {
   JsCallGraphResult,
   JsBoundaryResult,
   JsValidatePackResult,
+  JsBackfillResult,
 } from './types/analysis.js';
 import type {
   PatternsResult,
@@ -121,7 +122,7 @@ export interface DriftNapi {
   // Rust: driftCancelScan()
   driftCancelScan(): void;
 
-  // ─── Analysis (3) — analysis.rs ──────────────────────────────────────
+  // ─── Analysis (5) — analysis.rs ──────────────────────────────────────
   // Rust: drift_analyze(max_phase: Option<u32>) -> Vec<JsAnalysisResult>
   driftAnalyze(maxPhase?: number): Promise<JsAnalysisResult[]>;
 
@@ -134,6 +135,9 @@ export interface DriftNapi {
   // Rust: drift_validate_pack(toml_content: String) -> JsValidatePackResult
   driftValidatePack(tomlContent: string): JsValidatePackResult;
 
+  // Rust: drift_backfill(max_commits: Option<u32>) -> JsBackfillResult
+  driftBackfill(maxCommits?: number): Promise<JsBackfillResult>;
+
   // ─── Patterns (4) — patterns.rs ──────────────────────────────────────
   // Rust: drift_patterns(category: Option<String>, after_id: Option<String>, limit: Option<u32>)
   driftPatterns(
@@ -217,14 +221,14 @@ export interface DriftNapi {
   // Rust: drift_audit(_root: String)
   driftAudit(root: string): JsAuditResult;
 
-  // Rust: drift_violations(_root: String) -> Vec<JsViolation>
-  driftViolations(root: string): JsViolation[];
+  // Rust: drift_violations(_root: String, owner: Option<String>) -> Vec<JsViolation>
+  driftViolations(root: string, owner?: string): JsViolation[];
 
   // Rust: drift_gates(_root: String) -> Vec<JsGateResult>
   driftGates(root: string): JsGateResult[];
 
-  // Rust: drift_report(format: String) -> String
-  driftReport(format: string): string;
+  // Rust: drift_report(format: String, owner: Option<String>) -> String
+  driftReport(format: string, owner?: string): string;
 
   // Rust: drift_approve_pattern(pattern_id: String, status: String, reason: Option<String>)
   driftApprovePattern(
@@ -251,7 +255,7 @@ export interface DriftNapi {
     reason: string,
   ): JsFeedbackResult;
 
-  // ─── Advanced (4) — advanced.rs ──────────────────────────────────────
+  // ─── Advanced (6) — advanced.rs ──────────────────────────────────────
   // Rust: drift_simulate(task_category: String, task_description: String, context_json: String)
   driftSimulate(
     taskCategory: string,
@@ -275,6 +279,17 @@ export interface DriftNapi {
     migrationPathJson?: string,
   ): Promise<string>;
 
+  // Rust: drift_sbom(format: String)
+  driftSbom(format: string): string;
+
+  // Rust: drift_repo_map(intent: String, depth: String, format: String, focus: Option<String>)
+  driftRepoMap(
+    intent: string,
+    depth: string,
+    format: string,
+    focus?: string,
+  ): Promise<string>;
+
   // ─── Bridge (20) — bridge.rs ────────────────────────────────────────
   // Rust: drift_bridge_status()
   driftBridgeStatus(): BridgeStatusResult;
@@ -353,7 +368,7 @@ export interface DriftNapi {
 }
 
 /** Total number of methods in the DriftNapi interface. */
-export const DRIFT_NAPI_METHOD_COUNT = 66;
+export const DRIFT_NAPI_METHOD_COUNT = 69;
 
 /** All method names in the DriftNapi interface, for runtime validation. */
 export const DRIFT_NAPI_METHOD_NAMES: ReadonlyArray<keyof DriftNapi> = [
@@ -366,11 +381,12 @@ export const DRIFT_NAPI_METHOD_NAMES: ReadonlyArray<keyof DriftNapi> = [
   'driftScan',
   'driftScanWithProgress',
   'driftCancelScan',
-  // Analysis (4)
+  // Analysis (5)
   'driftAnalyze',
   'driftCallGraph',
   'driftBoundaries',
   'driftValidatePack',
+  'driftBackfill',
   // Patterns (4)
   'driftPatterns',
   'driftConfidence',
@@ -404,11 +420,13 @@ export const DRIFT_NAPI_METHOD_NAMES: ReadonlyArray<keyof DriftNapi> = [
   'driftDismissViolation',
   'driftFixViolation',
   'driftSuppressViolation',
-  // Advanced (4)
+  // Advanced (6)
   'driftSimulate',
   'driftDecisions',
   'driftContext',
   'driftGenerateSpec',
+  'driftSbom',
+  'driftRepoMap',
   // Bridge (20)
   'driftBridgeStatus',
   'driftBridgeGroundMemory',
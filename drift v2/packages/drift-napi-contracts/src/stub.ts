@@ -14,6 +14,7 @@ import type {
   JsCallGraphResult,
   JsBoundaryResult,
   JsValidatePackResult,
+  JsBackfillResult,
 } from './types/analysis.js';
 import type {
   PatternsResult,
@@ -120,7 +121,7 @@ export function createStubNapi(): DriftNapi {
       // no-op
     },
 
-    // ─── Analysis (4) ────────────────────────────────────────────────
+    // ─── Analysis (5) ────────────────────────────────────────────────
     async driftAnalyze(_maxPhase?: number): Promise<JsAnalysisResult[]> {
       return [];
     },
@@ -140,6 +141,7 @@ export function createStubNapi(): DriftNapi {
         models: [],
         sensitiveFields: [],
         frameworksDetected: [],
+        schemaDrift: [],
       };
     },
 
@@ -154,6 +156,10 @@ export function createStubNapi(): DriftNapi {
       };
     },
 
+    async driftBackfill(_maxCommits?: number): Promise<JsBackfillResult> {
+      return { commitsAnalyzed: 0, filesAnalyzed: 0, pointsWritten: 0, points: [] };
+    },
+
     // ─── Patterns (4) ────────────────────────────────────────────────
     driftPatterns(
       _category?: string,
@@ -351,6 +357,8 @@ export function createStubNapi(): DriftNapi {
         totalViolations: 0,
         gates: [],
         sarif: null,
+        policy: null,
+        owners: [],
       };
     },
 
@@ -372,7 +380,7 @@ export function createStubNapi(): DriftNapi {
       };
     },
 
-    driftViolations(_root: string): JsViolation[] {
+    driftViolations(_root: string, _owner?: string): JsViolation[] {
       return [];
     },
 
@@ -380,7 +388,7 @@ export function createStubNapi(): DriftNapi {
       return [];
     },
 
-    driftReport(_format: string): string {
+    driftReport(_format: string, _owner?: string): string {
       return '';
     },
 
@@ -424,7 +432,7 @@ export function createStubNapi(): DriftNapi {
       return { success: true, message: 'Stub: violation suppressed' };
     },
 
-    // ─── Advanced (4) ────────────────────────────────────────────────
+    // ─── Advanced (6) ────────────────────────────────────────────────
     async driftSimulate(
       _taskCategory: string,
       _taskDescription: string,
@@ -466,6 +474,23 @@ export function createStubNapi(): DriftNapi {
       });
     },
 
+    driftSbom(format: string): string {
+      return JSON.stringify(
+        format === 'spdx'
+          ? { spdxVersion: 'SPDX-2.3', packages: [], relationships: [] }
+          : { bomFormat: 'CycloneDX', specVersion: '1.5', components: [], dependencies: [] },
+      );
+    },
+
+    async driftRepoMap(
+      intent: string,
+      depth: string,
+      _format: string,
+      _focus?: string,
+    ): Promise<string> {
+      return `# Context: ${intent} (${depth})\n\n*Token count: 0*\n\n`;
+    },
+
     // ─── Bridge (20) ──────────────────────────────────────────────────
 
     driftBridgeStatus() {
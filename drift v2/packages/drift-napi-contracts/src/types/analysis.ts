@@ -34,6 +34,25 @@ export interface JsValidatePackResult {
   error: string | null;
 }
 
+/** Aligned to Rust JsBackfillResult (#[napi(object)]). */
+export interface JsBackfillResult {
+  commitsAnalyzed: number;
+  filesAnalyzed: number;
+  pointsWritten: number;
+  points: JsBackfillPoint[];
+}
+
+/** Aligned to Rust JsBackfillPoint (#[napi(object)]). */
+export interface JsBackfillPoint {
+  sha: string;
+  timestamp: number;
+  files: number;
+  filesAnalyzed: number;
+  patternCount: number;
+  violationCount: number;
+  healthScore: number;
+}
+
 /** Aligned to Rust JsCallGraphResult (#[napi(object)]). */
 export interface JsCallGraphResult {
   totalFunctions: number;
@@ -48,6 +67,7 @@ export interface JsBoundaryResult {
   models: JsModelResult[];
   sensitiveFields: JsSensitiveField[];
   frameworksDetected: string[];
+  schemaDrift: JsSchemaDrift[];
 }
 
 /** Aligned to Rust JsModelResult (#[napi(object)]). */
@@ -68,3 +88,14 @@ export interface JsSensitiveField {
   sensitivity: string;
   confidence: number;
 }
+
+/** Aligned to Rust JsSchemaDrift (#[napi(object)]). */
+export interface JsSchemaDrift {
+  kind: string;
+  modelName: string;
+  tableName: string;
+  columnName: string | null;
+  file: string;
+  line: number;
+  message: string;
+}
@@ -23,6 +23,8 @@ export interface JsViolation {
   owaspCategory: string | null;
   suppressed: boolean;
   isNew: boolean;
+  /** CODEOWNERS owners of the file. */
+  owners: string[];
 }
 
 // ─── Gate Result Types ───────────────────────────────────────────────
@@ -49,6 +51,52 @@ export interface JsCheckResult {
   totalViolations: number;
   gates: JsGateResult[];
   sarif: string | null;
+  /** Scoped policy evaluation, when `quality_gates.policy_overrides` is set. */
+  policy?: JsScopedPolicyReport | null;
+  /** Violations per CODEOWNERS owner, most first (`"unowned"` for the rest). */
+  owners: JsOwnerSummary[];
+}
+
+/** Aligned to Rust JsOwnerSummary (#[napi(object)]). */
+export interface JsOwnerSummary {
+  owner: string;
+  violations: number;
+  errors: number;
+  warnings: number;
+  newViolations: number;
+  /** Gates with at least one of the owner's violations. */
+  gates: string[];
+  files: string[];
+}
+
+// ─── Scoped Policy Types ─────────────────────────────────────────────
+
+/** Aligned to Rust JsPolicyScope (#[napi(object)]). */
+export interface JsPolicyScope {
+  scope: string;
+  policyName: string;
+  aggregationMode: string;
+  overallPassed: boolean;
+  overallScore: number;
+  fileCount: number;
+  gatesPassed: number;
+  gatesFailed: number;
+  details: string;
+}
+
+/** Aligned to Rust JsGovernedViolation (#[napi(object)]). */
+export interface JsGovernedViolation {
+  violationId: string;
+  gateId: string;
+  scope: string;
+  policyName: string;
+}
+
+/** Aligned to Rust JsScopedPolicyReport (#[napi(object)]). */
+export interface JsScopedPolicyReport {
+  overallPassed: boolean;
+  scopes: JsPolicyScope[];
+  violations: JsGovernedViolation[];
 }
 
 // ─── Audit Types ─────────────────────────────────────────────────────
@@ -12,6 +12,9 @@ export type {
   JsBoundaryResult,
   JsModelResult,
   JsSensitiveField,
+  JsSchemaDrift,
+  JsBackfillResult,
+  JsBackfillPoint,
 } from './analysis.js';
 export type {
   PatternsResult,
@@ -67,6 +70,10 @@ export type {
   JsViolation,
   JsGateResult,
   JsCheckResult,
+  JsPolicyScope,
+  JsGovernedViolation,
+  JsScopedPolicyReport,
+  JsOwnerSummary,
   JsHealthBreakdown,
   JsAuditResult,
   JsPatternStatusEntry,
@@ -26,7 +26,7 @@ describe('Interface Alignment — Contract Integrity', () => {
       // scanner.rs
       'driftScan', 'driftScanWithProgress', 'driftCancelScan',
       // analysis.rs
-      'driftAnalyze', 'driftCallGraph', 'driftBoundaries', 'driftValidatePack',
+      'driftAnalyze', 'driftCallGraph', 'driftBoundaries', 'driftValidatePack', 'driftBackfill',
       // patterns.rs
       'driftPatterns', 'driftConfidence', 'driftOutliers', 'driftConventions',
       // graph.rs
@@ -45,6 +45,7 @@ describe('Interface Alignment — Contract Integrity', () => {
       'driftDismissViolation', 'driftFixViolation', 'driftSuppressViolation',
       // advanced.rs
       'driftSimulate', 'driftDecisions', 'driftContext', 'driftGenerateSpec',
+      'driftSbom', 'driftRepoMap',
       // bridge.rs
       'driftBridgeStatus', 'driftBridgeGroundMemory', 'driftBridgeGroundAll',
       'driftBridgeGroundingHistory', 'driftBridgeTranslateLink',
@@ -70,14 +71,14 @@ describe('Interface Alignment — Contract Integrity', () => {
     }
   });
 
-  // TH-NAPI-03: DriftNapi has exactly 66 functions (43 drift + 21 bridge + 2 cloud)
-  it('TH-NAPI-03: DriftNapi has exactly 66 functions — prevents accidental add/remove', () => {
-    expect(DRIFT_NAPI_METHOD_COUNT).toBe(66);
-    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(66);
+  // TH-NAPI-03: DriftNapi has exactly 69 functions (46 drift + 21 bridge + 2 cloud)
+  it('TH-NAPI-03: DriftNapi has exactly 69 functions — prevents accidental add/remove', () => {
+    expect(DRIFT_NAPI_METHOD_COUNT).toBe(69);
+    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(69);
 
     // Also verify no duplicates
     const unique = new Set(DRIFT_NAPI_METHOD_NAMES);
-    expect(unique.size).toBe(66);
+    expect(unique.size).toBe(69);
   });
 
   // TH-NAPI-04: No function uses `any` type